@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+
+/// Statement-like view of an account over a period, possibly assembled from several read backends.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountActivity {
+    pub account: LedgerAccount,
+    /// Start of the period (exclusive). Openings are the totals at this time.
+    pub from_time: DateTime<Utc>,
+    pub to_time: DateTime<Utc>,
+    pub opening_debit: BigDecimal,
+    pub opening_credit: BigDecimal,
+    /// Lines posted in the period, oldest first.
+    pub lines: Vec<PostingLine>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    /// Names of the backends the lines were read from.
+    pub sources: Vec<String>,
+}
+
+impl AccountActivity {
+    pub fn new(
+        account: LedgerAccount,
+        period: (DateTime<Utc>, DateTime<Utc>),
+        opening: (BigDecimal, BigDecimal),
+        lines: Vec<PostingLine>,
+        sources: Vec<String>,
+    ) -> Self {
+        let (opening_debit, opening_credit) = opening;
+        let (total_debit, total_credit) = lines
+            .iter()
+            .fold((opening_debit.clone(), opening_credit.clone()), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        Self {
+            account,
+            from_time: period.0,
+            to_time: period.1,
+            opening_debit,
+            opening_credit,
+            lines,
+            total_debit,
+            total_credit,
+            sources,
+        }
+    }
+}
+
+/// Merges the lines read from several backends. A line held by more than one backend, e.g. while it is
+/// being archived, is kept once, from the first backend listing it. The result is ordered by posting
+/// time, then record time.
+pub fn merge_lines(parts: Vec<Vec<PostingLine>>) -> Vec<PostingLine> {
+    let mut seen = HashSet::new();
+    let mut lines: Vec<PostingLine> = parts.into_iter().flatten().filter(|l| seen.insert(l.id)).collect();
+    lines.sort_by(|a, b| a.pst_time.cmp(&b.pst_time).then(a.record_time.cmp(&b.record_time)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+    use crate::domain::posting_status::PostingStatus;
+    use crate::domain::posting_type::PostingType;
+
+    fn account() -> LedgerAccount {
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        LedgerAccount {
+            id: Uuid::nil(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            currency: None,
+        }
+    }
+
+    fn line(debit: i64, pst_time: DateTime<Utc>) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(0),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: pst_time,
+            opr_id: [1; 34],
+            opr_src: None,
+            pst_time,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+            currency: None,
+            fx: None,
+            category: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_lines_deduplicates_and_orders_by_posting_time() {
+        let now = Utc::now();
+        let archived = line(1, now - Duration::days(400));
+        let moving = line(2, now - Duration::days(300));
+        let hot = line(3, now);
+
+        let merged = merge_lines(vec![vec![hot.clone(), moving.clone()], vec![moving.clone(), archived.clone()]]);
+
+        assert_eq!(merged.iter().map(|l| l.id).collect::<Vec<_>>(), vec![archived.id, moving.id, hot.id]);
+    }
+
+    #[test]
+    fn test_totals_include_openings() {
+        let now = Utc::now();
+        let activity = AccountActivity::new(
+            account(),
+            (now - Duration::days(1), now),
+            (BigDecimal::from(100), BigDecimal::from(40)),
+            vec![line(5, now), line(7, now)],
+            vec!["postgres".to_string()],
+        );
+
+        assert_eq!(activity.total_debit, BigDecimal::from(112));
+        assert_eq!(activity.total_credit, BigDecimal::from(40));
+    }
+}
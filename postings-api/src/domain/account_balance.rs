@@ -0,0 +1,56 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Totals of an account at a point in time, computed without creating a statement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountBalance {
+    pub account: LedgerAccount,
+    /// Posting time the totals are computed at.
+    pub ref_time: DateTime<Utc>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    /// Record time the totals were known at, `None` when they include everything recorded so far.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub known_at: Option<DateTime<Utc>>,
+}
+
+impl AccountBalance {
+    pub fn debit_balance(&self) -> BigDecimal {
+        self.total_debit.clone() - self.total_credit.clone()
+    }
+
+    pub fn credit_balance(&self) -> BigDecimal {
+        self.total_credit.clone() - self.total_debit.clone()
+    }
+}
+
+/// Whether a line recorded at `record_time` and discarded at `discarded_time` was part of the
+/// books as known at `known_at`: it was already recorded and not yet discarded. Back-dated lines
+/// recorded later are excluded, lines discarded since are still included.
+pub fn was_booked_at(record_time: DateTime<Utc>, discarded_time: Option<DateTime<Utc>>, known_at: DateTime<Utc>) -> bool {
+    record_time <= known_at && discarded_time.is_none_or(|discarded| discarded > known_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_was_booked_at() {
+        let known_at = Utc::now();
+        let before = known_at - Duration::days(1);
+        let after = known_at + Duration::days(1);
+
+        assert!(was_booked_at(before, None, known_at));
+        assert!(was_booked_at(known_at, None, known_at));
+        // Back-dated entry recorded after the point of knowledge
+        assert!(!was_booked_at(after, None, known_at));
+        // Discarded after the point of knowledge, still valid then
+        assert!(was_booked_at(before, Some(after), known_at));
+        assert!(!was_booked_at(before, Some(known_at), known_at));
+        assert!(!was_booked_at(before, Some(before), known_at));
+    }
+}
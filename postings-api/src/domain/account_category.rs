@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use crate::domain::balance_side::BalanceSide;
 use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AccountCategory {
     RE, // Revenue
     EX, // Expense
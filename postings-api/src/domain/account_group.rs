@@ -0,0 +1,107 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::account_category::AccountCategory;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Named set of accounts of a ledger reported on together, e.g. a customer's portfolio. An
+/// account belongs to the group if it is of `category` or listed in `member_ids`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountGroup {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// Unique within the ledger.
+    pub name: String,
+    pub category: Option<AccountCategory>,
+    pub member_ids: Vec<Uuid>,
+    pub created: DateTime<Utc>,
+}
+
+impl AccountGroup {
+    pub fn contains(&self, account: &LedgerAccount) -> bool {
+        account.ledger.id == self.ledger.id
+            && (self.category.as_ref() == Some(&account.category) || self.member_ids.contains(&account.id))
+    }
+}
+
+/// Balances of the accounts of a group and their sum.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountGroupBalance {
+    pub group: AccountGroup,
+    pub ref_time: DateTime<Utc>,
+    pub balances: Vec<AccountBalance>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl AccountGroupBalance {
+    pub fn new(group: AccountGroup, ref_time: DateTime<Utc>, balances: Vec<AccountBalance>) -> Self {
+        let total_debit = balances.iter().map(|b| b.total_debit.clone()).sum();
+        let total_credit = balances.iter().map(|b| b.total_credit.clone()).sum();
+        Self { group, ref_time, balances, total_debit, total_credit }
+    }
+
+    pub fn debit_balance(&self) -> BigDecimal {
+        self.total_debit.clone() - self.total_credit.clone()
+    }
+
+    pub fn credit_balance(&self) -> BigDecimal {
+        self.total_credit.clone() - self.total_debit.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn account(ledger: &Ledger, category: AccountCategory) -> LedgerAccount {
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side: BalanceSide::Dr,
+            category,
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn test_membership_by_category_and_by_listing() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let asset = account(&ledger, AccountCategory::AS);
+        let listed = account(&ledger, AccountCategory::LI);
+        let other = account(&ledger, AccountCategory::LI);
+        let foreign = account(&Ledger { id: Uuid::new_v4(), coa: ledger.coa.clone() }, AccountCategory::AS);
+        let group = AccountGroup {
+            id: Uuid::new_v4(),
+            ledger,
+            name: "treasury".to_string(),
+            category: Some(AccountCategory::AS),
+            member_ids: vec![listed.id],
+            created: Utc::now(),
+        };
+
+        assert!(group.contains(&asset));
+        assert!(group.contains(&listed));
+        assert!(!group.contains(&other));
+        assert!(!group.contains(&foreign));
+
+        let ref_time = Utc::now();
+        let balance = |account: LedgerAccount, debit: i64, credit: i64| AccountBalance {
+            account,
+            ref_time,
+            total_debit: BigDecimal::from(debit),
+            total_credit: BigDecimal::from(credit),
+            known_at: None,
+        };
+        let group_balance = AccountGroupBalance::new(group, ref_time, vec![balance(asset, 70, 20), balance(listed, 5, 40)]);
+        assert_eq!(group_balance.total_debit, BigDecimal::from(75));
+        assert_eq!(group_balance.credit_balance(), BigDecimal::from(-15));
+    }
+}
@@ -0,0 +1,104 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::balance_side::BalanceSide;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Limits enforced on an account when postings are validated. Unset limits are not checked.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountLimit {
+    pub account: LedgerAccount,
+    /// Maximum of debits minus credits.
+    pub max_debit_balance: Option<BigDecimal>,
+    /// How far the balance may fall below zero on the account's balance side.
+    pub overdraft_limit: Option<BigDecimal>,
+    /// Maximum sum of debit amounts posted to the account per posting day.
+    pub daily_turnover_limit: Option<BigDecimal>,
+    pub updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LimitType {
+    MaxDebitBalance,
+    Overdraft,
+    DailyTurnover,
+}
+
+impl AccountLimit {
+    /// Checks the account totals resulting from a posting against the configured limits.
+    pub fn check(&self, debit_total: &BigDecimal, credit_total: &BigDecimal, daily_debit_turnover: &BigDecimal) -> Result<(), LimitType> {
+        let debit_balance = debit_total - credit_total;
+        if let Some(max) = &self.max_debit_balance {
+            if &debit_balance > max {
+                return Err(LimitType::MaxDebitBalance);
+            }
+        }
+        if let Some(overdraft) = &self.overdraft_limit {
+            let balance = match self.account.balance_side {
+                BalanceSide::Cr => -debit_balance,
+                _ => debit_balance,
+            };
+            if balance < -overdraft.clone() {
+                return Err(LimitType::Overdraft);
+            }
+        }
+        if let Some(turnover) = &self.daily_turnover_limit {
+            if daily_debit_turnover > turnover {
+                return Err(LimitType::DailyTurnover);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    fn dec(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    fn create_test_limit(balance_side: BalanceSide) -> AccountLimit {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: coa.clone() },
+            parent: None,
+            coa,
+            balance_side,
+            category: AccountCategory::LI,
+            currency: None,
+        };
+        AccountLimit {
+            account,
+            max_debit_balance: None,
+            overdraft_limit: None,
+            daily_turnover_limit: None,
+            updated: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_overdraft_on_credit_account() {
+        let mut limit = create_test_limit(BalanceSide::Cr);
+        limit.overdraft_limit = Some(dec("100"));
+        assert_eq!(limit.check(&dec("150"), &dec("50"), &dec("0")), Ok(()));
+        assert_eq!(limit.check(&dec("150.01"), &dec("50"), &dec("0")), Err(LimitType::Overdraft));
+    }
+
+    #[test]
+    fn test_max_debit_balance_and_turnover() {
+        let mut limit = create_test_limit(BalanceSide::Dr);
+        limit.max_debit_balance = Some(dec("1000"));
+        limit.daily_turnover_limit = Some(dec("500"));
+        assert_eq!(limit.check(&dec("1000"), &dec("0"), &dec("500")), Ok(()));
+        assert_eq!(limit.check(&dec("1200"), &dec("100"), &dec("0")), Err(LimitType::MaxDebitBalance));
+        assert_eq!(limit.check(&dec("100"), &dec("0"), &dec("500.5")), Err(LimitType::DailyTurnover));
+    }
+}
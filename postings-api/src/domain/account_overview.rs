@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::account_stmt::AccountStmt;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+
+/// Statement, activity and balance of an account, read in one call.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountOverview {
+    pub account: LedgerAccount,
+    /// Last statement closed before the reference time, without its posting and traces.
+    pub last_closed_stmt: Option<AccountStmt>,
+    /// Exclusive start of the activity.
+    pub activity_from: DateTime<Utc>,
+    /// Lines posted after `activity_from` up to the reference time, latest first.
+    pub activity: Vec<PostingLine>,
+    /// Totals at the reference time, continued from the last closed statement.
+    pub balance: AccountBalance,
+    /// Whether all figures were read from one database snapshot, so they agree with each other
+    /// even while postings are recorded concurrently.
+    pub consistent: bool,
+}
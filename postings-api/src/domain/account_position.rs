@@ -0,0 +1,58 @@
+use bigdecimal::BigDecimal;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::domain::account_category::AccountCategory;
+use crate::domain::balance_side::BalanceSide;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Position of a correspondent account for one value date.
+///
+/// Booked amounts come from posted lines, pending amounts from deferred or proposed lines
+/// whose posting time (the value date of the line) is on or before `value_date`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountPosition {
+    pub account: LedgerAccount,
+    pub position_type: PositionType,
+    pub value_date: NaiveDate,
+    pub booked_debit: BigDecimal,
+    pub booked_credit: BigDecimal,
+    pub pending_debit: BigDecimal,
+    pub pending_credit: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PositionType {
+    /// Our account held with a correspondent
+    Nostro,
+    /// A correspondent's account held with us
+    Vostro,
+}
+
+impl PositionType {
+    pub fn of(account: &LedgerAccount) -> PositionType {
+        match account.category {
+            AccountCategory::LI => PositionType::Vostro,
+            _ => PositionType::Nostro,
+        }
+    }
+}
+
+impl AccountPosition {
+    pub fn booked_balance(&self) -> BigDecimal {
+        self.side_balance(self.booked_debit.clone(), self.booked_credit.clone())
+    }
+
+    pub fn projected_balance(&self) -> BigDecimal {
+        self.side_balance(
+            self.booked_debit.clone() + self.pending_debit.clone(),
+            self.booked_credit.clone() + self.pending_credit.clone(),
+        )
+    }
+
+    fn side_balance(&self, debit: BigDecimal, credit: BigDecimal) -> BigDecimal {
+        match self.account.balance_side {
+            BalanceSide::Cr => credit - debit,
+            _ => debit - credit,
+        }
+    }
+}
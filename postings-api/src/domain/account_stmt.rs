@@ -1,8 +1,11 @@
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
+use crate::domain::currency::CurrencyTotal;
 use crate::domain::financial_stmt::FinancialStmt;
 use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::posting_trace::PostingTrace;
+use crate::domain::stmt_annotation::StmtAnnotation;
+use crate::domain::stmt_signature::StmtSignature;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AccountStmt {
@@ -12,6 +15,21 @@ pub struct AccountStmt {
     pub youngest_pst: Option<PostingTrace>,
     pub total_debit: BigDecimal,
     pub total_credit: BigDecimal,
+    /// Totals carried over from the previous closed statement.
+    pub opening_debit: BigDecimal,
+    pub opening_credit: BigDecimal,
+    /// Number of posting lines added since the previous closed statement.
+    pub line_count: i64,
+    /// Totals per line currency, ordered by currency. On multi-currency accounts `total_debit`
+    /// and `total_credit` mix currencies, so these are the meaningful totals there.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub currency_totals: Vec<CurrencyTotal>,
+    /// Reviewer notes on the statement, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<StmtAnnotation>,
+    /// Set on statements closed with a signer configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<StmtSignature>,
 }
 
 impl AccountStmt {
@@ -22,6 +40,14 @@ impl AccountStmt {
     pub fn credit_balance(&self) -> BigDecimal {
         self.total_credit.clone() - self.total_debit.clone()
     }
+
+    pub fn opening_debit_balance(&self) -> BigDecimal {
+        self.opening_debit.clone() - self.opening_credit.clone()
+    }
+
+    pub fn currency_total(&self, currency: &str) -> Option<&CurrencyTotal> {
+        self.currency_totals.iter().find(|t| t.currency == currency)
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +81,7 @@ mod tests {
             coa: coa.clone(),
             balance_side: BalanceSide::Dr,
             category: AccountCategory::AS,
+            currency: None,
         };
 
         let financial_stmt = FinancialStmt {
@@ -72,6 +99,12 @@ mod tests {
             youngest_pst: None,
             total_debit: BigDecimal::from_str(total_debit).unwrap(),
             total_credit: BigDecimal::from_str(total_credit).unwrap(),
+            opening_debit: BigDecimal::from(0),
+            opening_credit: BigDecimal::from(0),
+            line_count: 0,
+            currency_totals: vec![],
+            annotations: vec![],
+            signature: None,
         }
     }
 
@@ -93,6 +126,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_opening_debit_balance() {
+        let mut stmt = create_test_account_stmt("100.00", "50.00");
+        stmt.opening_debit = BigDecimal::from_str("40.00").unwrap();
+        stmt.opening_credit = BigDecimal::from_str("10.00").unwrap();
+        assert_eq!(
+            stmt.opening_debit_balance(),
+            BigDecimal::from_str("30.00").unwrap()
+        );
+    }
+
     #[test]
     fn test_zero_balance() {
         let stmt = create_test_account_stmt("100.00", "100.00");
@@ -0,0 +1,33 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+
+/// Posting activity of an account after a previously retrieved statement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountStmtDelta {
+    pub account: LedgerAccount,
+    pub since_stmt_id: Uuid,
+    /// Posting time of the statement the delta starts from (exclusive).
+    pub from_time: DateTime<Utc>,
+    pub to_time: DateTime<Utc>,
+    /// Lines posted in the interval, oldest first.
+    pub lines: Vec<PostingLine>,
+    pub debit_change: BigDecimal,
+    pub credit_change: BigDecimal,
+}
+
+impl AccountStmtDelta {
+    pub fn new(account: LedgerAccount, since_stmt_id: Uuid, from_time: DateTime<Utc>, to_time: DateTime<Utc>, lines: Vec<PostingLine>) -> Self {
+        let debit_change = lines.iter().map(|l| l.debit_amount.clone()).sum();
+        let credit_change = lines.iter().map(|l| l.credit_amount.clone()).sum();
+        Self { account, since_stmt_id, from_time, to_time, lines, debit_change, credit_change }
+    }
+
+    /// Change of the debit balance (debits minus credits) since the statement.
+    pub fn debit_balance_change(&self) -> BigDecimal {
+        self.debit_change.clone() - self.credit_change.clone()
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+/// Credential of a service account. Only a hash of the secret is stored; `key_prefix` identifies
+/// the key when a secret is presented.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiKey {
+    pub id: Uuid,
+    /// Name of the service account, e.g. `payment-engine`.
+    pub name: String,
+    pub tenant_id: Uuid,
+    /// Ledger the key is restricted to; `None` for every ledger of the tenant.
+    pub ledger_id: Option<Uuid>,
+    pub roles: Vec<ApiRole>,
+    pub key_prefix: String,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub key_hash: [u8; 34],
+    pub created: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+    pub revoked_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ApiRole {
+    /// Queries of accounts, postings and statements.
+    Read,
+    /// Recording postings.
+    Post,
+    /// Statement generation and closing.
+    Stmt,
+    /// Ledger configuration; implies every other role.
+    Admin,
+}
+
+impl ApiRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiRole::Read => "READ",
+            ApiRole::Post => "POST",
+            ApiRole::Stmt => "STMT",
+            ApiRole::Admin => "ADMIN",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "READ" => Some(ApiRole::Read),
+            "POST" => Some(ApiRole::Post),
+            "STMT" => Some(ApiRole::Stmt),
+            "ADMIN" => Some(ApiRole::Admin),
+            _ => None,
+        }
+    }
+
+    /// Comma separated form used for persistence.
+    pub fn join(roles: &[ApiRole]) -> String {
+        roles.iter().map(ApiRole::as_str).collect::<Vec<_>>().join(",")
+    }
+
+    /// Parses the persisted form, ignoring unknown names.
+    pub fn split(value: &str) -> Vec<ApiRole> {
+        value.split(',').filter_map(|r| ApiRole::parse(r.trim())).collect()
+    }
+}
+
+impl ApiKey {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_time.is_none() && self.expires.is_none_or(|expires| now < expires)
+    }
+
+    /// Whether the key may act with `role` on the ledger of the tenant.
+    pub fn grants(&self, tenant_id: Uuid, ledger_id: Uuid, role: ApiRole) -> bool {
+        self.tenant_id == tenant_id
+            && self.ledger_id.is_none_or(|id| id == ledger_id)
+            && (self.roles.contains(&role) || self.roles.contains(&ApiRole::Admin))
+    }
+}
+
+/// Newly issued key. The secret is only available at this point.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IssuedApiKey {
+    pub key: ApiKey,
+    pub secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(ledger_id: Option<Uuid>, roles: Vec<ApiRole>) -> ApiKey {
+        ApiKey {
+            id: Uuid::new_v4(),
+            name: "payment-engine".to_string(),
+            tenant_id: Uuid::nil(),
+            ledger_id,
+            roles,
+            key_prefix: "abcd1234".to_string(),
+            key_hash: [0; 34],
+            created: Utc::now(),
+            expires: None,
+            revoked_time: None,
+        }
+    }
+
+    #[test]
+    fn test_roles_round_trip() {
+        let roles = vec![ApiRole::Read, ApiRole::Post];
+        assert_eq!(ApiRole::join(&roles), "READ,POST");
+        assert_eq!(ApiRole::split("READ,POST,UNKNOWN"), roles);
+    }
+
+    #[test]
+    fn test_grants_checks_scope_and_role() {
+        let ledger_id = Uuid::new_v4();
+        let scoped = key(Some(ledger_id), vec![ApiRole::Read]);
+        assert!(scoped.grants(Uuid::nil(), ledger_id, ApiRole::Read));
+        assert!(!scoped.grants(Uuid::nil(), ledger_id, ApiRole::Post));
+        assert!(!scoped.grants(Uuid::nil(), Uuid::new_v4(), ApiRole::Read));
+        assert!(!scoped.grants(Uuid::new_v4(), ledger_id, ApiRole::Read));
+
+        let admin = key(None, vec![ApiRole::Admin]);
+        assert!(admin.grants(Uuid::nil(), Uuid::new_v4(), ApiRole::Post));
+    }
+
+    #[test]
+    fn test_revoked_and_expired_keys_are_inactive() {
+        let now = Utc::now();
+        let mut revoked = key(None, vec![ApiRole::Read]);
+        revoked.revoked_time = Some(now);
+        assert!(!revoked.is_active(now));
+
+        let mut expired = key(None, vec![ApiRole::Read]);
+        expired.expires = Some(now);
+        assert!(!expired.is_active(now));
+        assert!(expired.is_active(now - chrono::Duration::seconds(1)));
+    }
+}
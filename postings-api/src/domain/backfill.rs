@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+
+/// Population of derived data (running balances, aggregates, snapshots...) for the historical records of a ledger.
+/// The job is processed in batches; `cursor` is the checkpoint the next batch resumes from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackfillJob {
+    pub id: Uuid,
+    pub task_name: String,
+    pub ledger: Ledger,
+    pub status: BackfillStatus,
+    pub batch_size: u32,
+    /// Opaque position of the last processed record, as returned by the task.
+    pub cursor: Option<String>,
+    pub processed: u64,
+    /// Number of records to process, estimated when the job was started.
+    pub total: u64,
+    pub error_message: Option<String>,
+    pub started: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BackfillStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+impl BackfillJob {
+    /// Share of the records processed, between 0 and 1.
+    pub fn progress(&self) -> f64 {
+        if self.status == BackfillStatus::Completed {
+            return 1.0;
+        }
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.processed as f64 / self.total as f64).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn job(processed: u64, total: u64, status: BackfillStatus) -> BackfillJob {
+        let now = Utc::now();
+        BackfillJob {
+            id: Uuid::new_v4(),
+            task_name: "stmt_opening_balances".to_string(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } },
+            status,
+            batch_size: 100,
+            cursor: None,
+            processed,
+            total,
+            error_message: None,
+            started: now,
+            updated: now,
+            finished: None,
+        }
+    }
+
+    #[test]
+    fn progress_is_share_of_processed_records() {
+        assert_eq!(job(25, 100, BackfillStatus::Running).progress(), 0.25);
+        assert_eq!(job(0, 0, BackfillStatus::Running).progress(), 0.0);
+        // Records added after the estimate was taken never report more than complete
+        assert_eq!(job(120, 100, BackfillStatus::Running).progress(), 1.0);
+        assert_eq!(job(0, 0, BackfillStatus::Completed).progress(), 1.0);
+    }
+}
@@ -0,0 +1,145 @@
+use bigdecimal::BigDecimal;
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::balance_side::BalanceSide;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Day-by-day projection of an account's balance on its balance side.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceForecast {
+    pub account: LedgerAccount,
+    pub points: Vec<ForecastPoint>,
+}
+
+/// Projected balances at the end of `date`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForecastPoint {
+    pub date: NaiveDate,
+    pub booked_balance: BigDecimal,
+    /// Booked balance minus holds still blocking at the end of the day.
+    pub available_balance: BigDecimal,
+    pub items: Vec<ForecastItem>,
+}
+
+/// Expected balance movement on a given day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForecastItem {
+    pub date: NaiveDate,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+    pub source: ForecastSource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ForecastSource {
+    /// Line already recorded with a future posting time.
+    ScheduledLine(Uuid),
+    StandingOrder(Uuid),
+}
+
+/// Amount blocked on the account until `release_date`, or indefinitely.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForecastHold {
+    pub amount: BigDecimal,
+    pub release_date: Option<NaiveDate>,
+}
+
+impl BalanceForecast {
+    /// Projects `opening_balance` over `days` days starting at `from`.
+    pub fn project(
+        account: LedgerAccount,
+        opening_balance: BigDecimal,
+        holds: &[ForecastHold],
+        items: Vec<ForecastItem>,
+        from: NaiveDate,
+        days: u32,
+    ) -> Self {
+        let mut balance = opening_balance;
+        let mut points = Vec::with_capacity(days as usize);
+        for offset in 0..days {
+            let date = from + Days::new(offset as u64);
+            let day_items: Vec<ForecastItem> = items.iter().filter(|i| i.date == date).cloned().collect();
+            for item in &day_items {
+                let debit_change = item.debit_amount.clone() - item.credit_amount.clone();
+                balance += match account.balance_side {
+                    BalanceSide::Cr => -debit_change,
+                    _ => debit_change,
+                };
+            }
+            let blocked: BigDecimal = holds
+                .iter()
+                .filter(|h| h.release_date.map_or(true, |release| release > date))
+                .map(|h| h.amount.clone())
+                .sum();
+            points.push(ForecastPoint {
+                date,
+                booked_balance: balance.clone(),
+                available_balance: balance.clone() - blocked,
+                items: day_items,
+            });
+        }
+        Self { account, points }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 8, d).unwrap()
+    }
+
+    fn account(balance_side: BalanceSide) -> LedgerAccount {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: coa.clone() },
+            parent: None,
+            coa,
+            balance_side,
+            category: AccountCategory::LI,
+            currency: None,
+        }
+    }
+
+    fn item(d: u32, debit: i64, credit: i64) -> ForecastItem {
+        ForecastItem {
+            date: date(d),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            source: ForecastSource::StandingOrder(Uuid::nil()),
+        }
+    }
+
+    #[test]
+    fn test_project_applies_items_on_balance_side() {
+        let forecast = BalanceForecast::project(
+            account(BalanceSide::Cr),
+            BigDecimal::from(100),
+            &[],
+            vec![item(2, 30, 0), item(3, 0, 50)],
+            date(1),
+            4,
+        );
+        let balances: Vec<BigDecimal> = forecast.points.iter().map(|p| p.booked_balance.clone()).collect();
+        assert_eq!(balances, vec![BigDecimal::from(100), BigDecimal::from(70), BigDecimal::from(120), BigDecimal::from(120)]);
+        assert_eq!(forecast.points[1].items.len(), 1);
+    }
+
+    #[test]
+    fn test_holds_reduce_available_balance_until_release() {
+        let holds = vec![
+            ForecastHold { amount: BigDecimal::from(20), release_date: Some(date(2)) },
+            ForecastHold { amount: BigDecimal::from(5), release_date: None },
+        ];
+        let forecast = BalanceForecast::project(account(BalanceSide::Dr), BigDecimal::from(100), &holds, vec![], date(1), 3);
+        assert_eq!(forecast.points[0].available_balance, BigDecimal::from(75));
+        assert_eq!(forecast.points[1].available_balance, BigDecimal::from(95));
+        assert_eq!(forecast.points[2].available_balance, BigDecimal::from(95));
+    }
+}
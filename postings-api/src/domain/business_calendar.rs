@@ -0,0 +1,107 @@
+use std::collections::BTreeSet;
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Business-day calendar of a ledger: weekends plus the ledger's holidays are non-business days.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BusinessCalendar {
+    pub holidays: BTreeSet<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    pub fn new(holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self { holidays: holidays.into_iter().collect() }
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// First business day strictly after `date`.
+    pub fn next_business_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut next = date + Days::new(1);
+        while !self.is_business_day(next) {
+            next = next + Days::new(1);
+        }
+        next
+    }
+
+    /// Last business day strictly before `date`.
+    pub fn previous_business_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut previous = date - Days::new(1);
+        while !self.is_business_day(previous) {
+            previous = previous - Days::new(1);
+        }
+        previous
+    }
+
+    /// `date` itself if it is a business day, otherwise the next one (following convention).
+    pub fn adjust_following(&self, date: NaiveDate) -> NaiveDate {
+        if self.is_business_day(date) { date } else { self.next_business_day(date) }
+    }
+
+    /// `date` itself if it is a business day, otherwise the previous one (preceding convention).
+    pub fn adjust_preceding(&self, date: NaiveDate) -> NaiveDate {
+        if self.is_business_day(date) { date } else { self.previous_business_day(date) }
+    }
+
+    /// Moves `days` business days forward (or backward for negative values).
+    pub fn add_business_days(&self, date: NaiveDate, days: i32) -> NaiveDate {
+        let mut result = date;
+        for _ in 0..days.unsigned_abs() {
+            result = if days >= 0 { self.next_business_day(result) } else { self.previous_business_day(result) };
+        }
+        result
+    }
+
+    /// Number of business days in the half-open interval `(from, to]`.
+    pub fn business_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        from.iter_days()
+            .skip(1)
+            .take_while(|d| *d <= to)
+            .filter(|d| self.is_business_day(*d))
+            .count() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn calendar() -> BusinessCalendar {
+        // Friday 2025-04-18 and Monday 2025-04-21 (Easter)
+        BusinessCalendar::new([date(2025, 4, 18), date(2025, 4, 21)])
+    }
+
+    #[test]
+    fn test_is_business_day() {
+        let cal = calendar();
+        assert!(cal.is_business_day(date(2025, 4, 17)));
+        assert!(!cal.is_business_day(date(2025, 4, 18)));
+        assert!(!cal.is_business_day(date(2025, 4, 19)));
+        assert!(!cal.is_business_day(date(2025, 4, 20)));
+    }
+
+    #[test]
+    fn test_next_and_previous_business_day() {
+        let cal = calendar();
+        assert_eq!(cal.next_business_day(date(2025, 4, 17)), date(2025, 4, 22));
+        assert_eq!(cal.previous_business_day(date(2025, 4, 22)), date(2025, 4, 17));
+        assert_eq!(cal.adjust_following(date(2025, 4, 19)), date(2025, 4, 22));
+        assert_eq!(cal.adjust_preceding(date(2025, 4, 19)), date(2025, 4, 17));
+        assert_eq!(cal.adjust_following(date(2025, 4, 17)), date(2025, 4, 17));
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let cal = calendar();
+        assert_eq!(cal.add_business_days(date(2025, 4, 16), 2), date(2025, 4, 22));
+        assert_eq!(cal.add_business_days(date(2025, 4, 22), -1), date(2025, 4, 17));
+        assert_eq!(cal.add_business_days(date(2025, 4, 22), 0), date(2025, 4, 22));
+        assert_eq!(cal.business_days_between(date(2025, 4, 16), date(2025, 4, 22)), 2);
+    }
+}
@@ -0,0 +1,150 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::posting_line::PostingLine;
+
+/// Assigns `category` to posting lines matching all set criteria. Rules of a ledger are evaluated
+/// by ascending priority; the first match wins.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CategoryRule {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub category: String,
+    pub priority: i32,
+    pub account_id: Option<Uuid>,
+    /// 32-byte hash of the line details, e.g. a merchant reference.
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub details: Option<[u8; 34]>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub src_account: Option<[u8; 34]>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_src: Option<[u8; 34]>,
+    /// Bounds on the line amount, whichever side it is booked on.
+    pub min_amount: Option<BigDecimal>,
+    pub max_amount: Option<BigDecimal>,
+    pub created: DateTime<Utc>,
+}
+
+impl CategoryRule {
+    pub fn matches(&self, line: &PostingLine) -> bool {
+        if self.account_id.is_some_and(|id| id != line.account.id) {
+            return false;
+        }
+        if self.details.is_some() && self.details != line.details {
+            return false;
+        }
+        if self.src_account.is_some() && self.src_account != line.src_account {
+            return false;
+        }
+        if self.opr_src.is_some() && self.opr_src != line.opr_src {
+            return false;
+        }
+        let amount = &line.debit_amount + &line.credit_amount;
+        if self.min_amount.as_ref().is_some_and(|min| &amount < min) {
+            return false;
+        }
+        if self.max_amount.as_ref().is_some_and(|max| &amount > max) {
+            return false;
+        }
+        true
+    }
+}
+
+/// Category of the first matching rule by priority.
+pub fn categorize(rules: &[CategoryRule], line: &PostingLine) -> Option<String> {
+    rules
+        .iter()
+        .filter(|r| r.matches(line))
+        .min_by_key(|r| r.priority)
+        .map(|r| r.category.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger_account::LedgerAccount;
+    use crate::domain::posting_status::PostingStatus;
+    use crate::domain::posting_type::PostingType;
+
+    fn ledger() -> Ledger {
+        Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } }
+    }
+
+    fn line(details: Option<[u8; 34]>, debit: i64) -> PostingLine {
+        let ledger = ledger();
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: LedgerAccount {
+                id: Uuid::nil(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: ledger.coa,
+                balance_side: BalanceSide::Dr,
+                category: AccountCategory::AS,
+                currency: None,
+            },
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(0),
+            details,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+            category: None,
+            currency: None,
+            fx: None,
+        }
+    }
+
+    fn rule(category: &str, priority: i32) -> CategoryRule {
+        CategoryRule {
+            id: Uuid::new_v4(),
+            ledger: ledger(),
+            category: category.to_string(),
+            priority,
+            account_id: None,
+            details: None,
+            src_account: None,
+            opr_src: None,
+            min_amount: None,
+            max_amount: None,
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_by_priority_wins() {
+        let merchant = [7u8; 34];
+        let mut groceries = rule("GROCERIES", 10);
+        groceries.details = Some(merchant);
+        let fallback = rule("OTHER", 100);
+
+        let rules = vec![fallback, groceries];
+        assert_eq!(categorize(&rules, &line(Some(merchant), 20)).as_deref(), Some("GROCERIES"));
+        assert_eq!(categorize(&rules, &line(None, 20)).as_deref(), Some("OTHER"));
+    }
+
+    #[test]
+    fn test_amount_bounds() {
+        let mut large = rule("LARGE", 1);
+        large.min_amount = Some(BigDecimal::from(1000));
+        assert!(!large.matches(&line(None, 999)));
+        assert!(large.matches(&line(None, 1000)));
+        assert_eq!(categorize(&[large], &line(None, 5)), None);
+    }
+}
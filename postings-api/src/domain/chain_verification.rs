@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Why a posting breaks the hash chain of its ledger.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChainBreak {
+    /// The posting carries no hash.
+    MissingHash,
+    /// The hash recomputed from the stored posting differs from its recorded hash.
+    HashMismatch,
+    /// The recorded antecedent hash differs from the hash of the antecedent posting.
+    AntecedentHashMismatch,
+    /// Another posting already follows the same antecedent.
+    Fork,
+    /// The posting cannot be reached from the first posting of the ledger.
+    Unlinked,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BrokenLink {
+    pub posting_id: Uuid,
+    pub antecedent_id: Option<Uuid>,
+    pub reason: ChainBreak,
+}
+
+/// Outcome of walking a ledger's hash chain. Verification stops at the first broken link.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChainVerification {
+    pub ledger_id: Uuid,
+    /// Number of postings verified before the walk ended.
+    pub verified: u64,
+    pub last_verified_id: Option<Uuid>,
+    pub broken_link: Option<BrokenLink>,
+}
+
+impl ChainVerification {
+    pub fn is_intact(&self) -> bool {
+        self.broken_link.is_none()
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::ledger_event::LedgerEvent;
+
+/// Changes after a replica's sync position, in sequence order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChangeSet {
+    /// Position the changes were read after.
+    pub after_seq: i64,
+    /// Position to pass to the next request. Moves past events left out by the entity type
+    /// filter, so it can be ahead of the last returned change.
+    pub next_seq: i64,
+    pub changes: Vec<LedgerEvent>,
+    /// Settled events follow `next_seq`; the replica should request again right away.
+    pub has_more: bool,
+}
@@ -0,0 +1,45 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Totals of a closed statement. Ledgers configured for it record the hash of this summary as the
+/// `opr_details` of the closing posting, so the closed totals are covered by the hash chain.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ClosingSummary {
+    pub stmt_id: Uuid,
+    /// Account of an account statement, `None` for ledger statements.
+    pub account_id: Option<Uuid>,
+    pub pst_time: DateTime<Utc>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl ClosingSummary {
+    /// Amounts are normalized, so totals read back with another scale hash the same.
+    pub fn new(stmt_id: Uuid, account_id: Option<Uuid>, pst_time: DateTime<Utc>, total_debit: &BigDecimal, total_credit: &BigDecimal) -> Self {
+        Self {
+            stmt_id,
+            account_id,
+            pst_time,
+            total_debit: total_debit.normalized(),
+            total_credit: total_credit.normalized(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_new_normalizes_amounts() {
+        let stmt_id = Uuid::new_v4();
+        let now = Utc::now();
+        let scaled = ClosingSummary::new(stmt_id, None, now, &BigDecimal::from_str("100.00").unwrap(), &BigDecimal::from_str("0.0").unwrap());
+        let plain = ClosingSummary::new(stmt_id, None, now, &BigDecimal::from(100), &BigDecimal::from(0));
+        assert_eq!(scaled.total_debit.to_string(), plain.total_debit.to_string());
+        assert_eq!(scaled.total_credit.to_string(), plain.total_credit.to_string());
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use crate::domain::account_category::AccountCategory;
+use crate::domain::balance_side::BalanceSide;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Separates the account names of a parent path, e.g. `Assets/Current Assets`.
+pub const PATH_SEPARATOR: char = '/';
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CoaImportFormat {
+    Csv,
+    Yaml,
+}
+
+/// Declarative chart of accounts to create as a new ledger.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaImportRequest {
+    /// Name given to the chart of accounts and to its ledger.
+    pub name: String,
+    /// Language of every name created by the import.
+    pub language: String,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub user_details: [u8; 34],
+    pub format: CoaImportFormat,
+    pub source: String,
+}
+
+/// One account of the chart. `parent` is the path of names leading to the parent account;
+/// top-level accounts have none. The balance side defaults to the one of the category.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaImportRow {
+    pub name: String,
+    pub category: AccountCategory,
+    #[serde(default)]
+    pub balance_side: Option<BalanceSide>,
+    #[serde(default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+/// A row that passed validation, with the normalized paths of the account and its parent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoaImportEntry {
+    pub record: usize,
+    pub path: String,
+    pub parent_path: Option<String>,
+    pub row: CoaImportRow,
+}
+
+/// Why a row was rejected. `record` is the 1-based line for CSV and the 1-based entry for YAML.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaImportError {
+    pub record: usize,
+    pub name: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportedAccount {
+    pub path: String,
+    pub account: LedgerAccount,
+}
+
+/// Outcome of an import. Nothing is written unless every row is valid, so `ledger` is only
+/// set when `errors` is empty.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaImportReport {
+    pub ledger: Option<Ledger>,
+    pub accounts: Vec<ImportedAccount>,
+    pub errors: Vec<CoaImportError>,
+}
+
+impl CoaImportReport {
+    pub fn rejected(errors: Vec<CoaImportError>) -> Self {
+        CoaImportReport { ledger: None, accounts: Vec::new(), errors }
+    }
+
+    pub fn is_imported(&self) -> bool {
+        self.ledger.is_some()
+    }
+}
+
+impl CoaImportRow {
+    /// Parses `name,category,balance_side,parent,currency` lines; the last three fields may be
+    /// empty or left out. Blank lines and a leading header line are skipped.
+    pub fn parse_csv(csv: &str) -> (Vec<(usize, CoaImportRow)>, Vec<CoaImportError>) {
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+        for (idx, raw) in csv.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match Self::parse_line(line) {
+                Ok(row) => rows.push((idx + 1, row)),
+                Err(_) if idx == 0 => continue,
+                Err(reason) => errors.push(CoaImportError {
+                    record: idx + 1,
+                    name: line.split(',').next().map(|n| n.trim().to_string()),
+                    reason,
+                }),
+            }
+        }
+        (rows, errors)
+    }
+
+    /// Parses a single CSV record.
+    pub fn parse_line(line: &str) -> Result<CoaImportRow, String> {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() < 2 || fields.len() > 5 {
+            return Err(format!("expected 2 to 5 fields, found {}", fields.len()));
+        }
+        let optional = |idx: usize| fields.get(idx).filter(|f| !f.is_empty()).map(|f| f.to_string());
+        let category = parse_category(fields[1]).ok_or_else(|| format!("unknown category {}", fields[1]))?;
+        let balance_side = match optional(2) {
+            Some(side) => Some(parse_balance_side(&side).ok_or_else(|| format!("unknown balance side {side}"))?),
+            None => None,
+        };
+        Ok(CoaImportRow {
+            name: fields[0].to_string(),
+            category,
+            balance_side,
+            parent: optional(3),
+            currency: optional(4),
+        })
+    }
+
+    pub fn effective_balance_side(&self) -> BalanceSide {
+        self.balance_side.clone().unwrap_or_else(|| self.category.default_bs())
+    }
+}
+
+/// Validates the rows as a whole and orders them so that every parent precedes its children.
+/// Returns every problem found rather than stopping at the first one.
+pub fn plan(rows: Vec<(usize, CoaImportRow)>) -> Result<Vec<CoaImportEntry>, Vec<CoaImportError>> {
+    let mut errors = Vec::new();
+    let mut entries = Vec::new();
+    let mut paths = HashSet::new();
+    for (record, row) in rows {
+        let reject = |reason: String| CoaImportError { record, name: Some(row.name.clone()), reason };
+        let name = row.name.trim();
+        if name.is_empty() || name.contains(PATH_SEPARATOR) {
+            errors.push(reject(format!("name must be non-empty and must not contain '{PATH_SEPARATOR}'")));
+            continue;
+        }
+        if let Some(currency) = row.currency.as_deref() {
+            if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+                errors.push(reject(format!("{currency} is not an ISO 4217 currency code")));
+                continue;
+            }
+        }
+        let parent_path = row.parent.as_deref().map(normalize_path).filter(|p| !p.is_empty());
+        let path = match &parent_path {
+            Some(parent) => format!("{parent}{PATH_SEPARATOR}{name}"),
+            None => name.to_string(),
+        };
+        if !paths.insert(path.clone()) {
+            errors.push(reject(format!("duplicate account {path}")));
+            continue;
+        }
+        entries.push(CoaImportEntry { record, path, parent_path, row });
+    }
+    for entry in entries.iter() {
+        if let Some(parent) = entry.parent_path.as_ref().filter(|p| !paths.contains(*p)) {
+            errors.push(CoaImportError {
+                record: entry.record,
+                name: Some(entry.row.name.clone()),
+                reason: format!("unknown parent {parent}"),
+            });
+        }
+    }
+    if !errors.is_empty() {
+        errors.sort_by_key(|e| e.record);
+        return Err(errors);
+    }
+    entries.sort_by_key(|e| e.path.matches(PATH_SEPARATOR).count());
+    Ok(entries)
+}
+
+fn normalize_path(path: &str) -> String {
+    path.split(PATH_SEPARATOR)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(&PATH_SEPARATOR.to_string())
+}
+
+fn parse_category(s: &str) -> Option<AccountCategory> {
+    [
+        AccountCategory::RE,
+        AccountCategory::EX,
+        AccountCategory::AS,
+        AccountCategory::LI,
+        AccountCategory::EQ,
+        AccountCategory::NOOP,
+        AccountCategory::NORE,
+        AccountCategory::NOEX,
+    ]
+    .into_iter()
+    .find(|c| c.to_string().eq_ignore_ascii_case(s))
+}
+
+fn parse_balance_side(s: &str) -> Option<BalanceSide> {
+    [BalanceSide::Dr, BalanceSide::Cr, BalanceSide::DrCr]
+        .into_iter()
+        .find(|b| b.to_string().eq_ignore_ascii_case(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_with_header_and_defaults() {
+        let csv = "name,category,balance_side,parent,currency\nAssets,AS\nCash,as,,Assets,EUR\n\nFees,RE,DrCr, Assets / Cash \n";
+        let (rows, errors) = CoaImportRow::parse_csv(csv);
+        assert!(errors.is_empty());
+        assert_eq!(rows.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![2, 3, 5]);
+        assert_eq!(rows[0].1.effective_balance_side(), BalanceSide::Dr);
+        assert_eq!(rows[1].1.currency.as_deref(), Some("EUR"));
+        assert_eq!(rows[2].1.effective_balance_side(), BalanceSide::DrCr);
+    }
+
+    #[test]
+    fn test_parse_csv_reports_invalid_lines() {
+        let (rows, errors) = CoaImportRow::parse_csv("Assets,AS\nCash,XX\nBank,AS,Up\n");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(errors.iter().map(|e| e.record).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(errors[0].name.as_deref(), Some("Cash"));
+    }
+
+    #[test]
+    fn test_plan_orders_parents_first() {
+        let (rows, _) = CoaImportRow::parse_csv("Cash,AS,,Assets/Current\nCurrent,AS,,Assets\nAssets,AS\n");
+        let entries = plan(rows).unwrap();
+        let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["Assets", "Assets/Current", "Assets/Current/Cash"]);
+    }
+
+    #[test]
+    fn test_plan_collects_every_error() {
+        let (rows, _) = CoaImportRow::parse_csv("Assets,AS\nAssets,AS\nCash,AS,,Missing\nBank,AS,,Assets,eur\n");
+        let errors = plan(rows).unwrap_err();
+        assert_eq!(errors.iter().map(|e| e.record).collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+}
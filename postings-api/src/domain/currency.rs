@@ -0,0 +1,64 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+
+/// Conversion of a posting line into the base currency its posting is balanced in.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FxDetails {
+    /// ISO 4217 code of the currency the posting balances in.
+    pub base_currency: String,
+    /// Units of `base_currency` per unit of the line's currency.
+    pub rate: BigDecimal,
+}
+
+/// Debit and credit totals of a statement for one currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CurrencyTotal {
+    pub currency: String,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl CurrencyTotal {
+    pub fn debit_balance(&self) -> BigDecimal {
+        self.total_debit.clone() - self.total_credit.clone()
+    }
+
+    pub fn credit_balance(&self) -> BigDecimal {
+        self.total_credit.clone() - self.total_debit.clone()
+    }
+
+    /// Adds amounts to the total of `currency`, keeping `totals` ordered by currency.
+    pub fn accumulate(totals: &mut Vec<CurrencyTotal>, currency: &str, debit: &BigDecimal, credit: &BigDecimal) {
+        match totals.binary_search_by(|t| t.currency.as_str().cmp(currency)) {
+            Ok(idx) => {
+                totals[idx].total_debit += debit.clone();
+                totals[idx].total_credit += credit.clone();
+            }
+            Err(idx) => totals.insert(
+                idx,
+                CurrencyTotal {
+                    currency: currency.to_string(),
+                    total_debit: debit.clone(),
+                    total_credit: credit.clone(),
+                },
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulate_keeps_currencies_ordered() {
+        let mut totals = Vec::new();
+        CurrencyTotal::accumulate(&mut totals, "USD", &BigDecimal::from(10), &BigDecimal::from(0));
+        CurrencyTotal::accumulate(&mut totals, "EUR", &BigDecimal::from(0), &BigDecimal::from(5));
+        CurrencyTotal::accumulate(&mut totals, "USD", &BigDecimal::from(2), &BigDecimal::from(3));
+
+        assert_eq!(totals.iter().map(|t| t.currency.as_str()).collect::<Vec<_>>(), vec!["EUR", "USD"]);
+        assert_eq!(totals[0].credit_balance(), BigDecimal::from(5));
+        assert_eq!(totals[1].debit_balance(), BigDecimal::from(9));
+    }
+}
@@ -0,0 +1,84 @@
+use bigdecimal::{BigDecimal, Zero};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::domain::currency::CurrencyTotal;
+use crate::domain::ledger::Ledger;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PositionDirection {
+    /// More of the currency is held than owed.
+    Long,
+    /// More of the currency is owed than held.
+    Short,
+    Flat,
+}
+
+/// Net position of a ledger in one currency: booked debits less booked credits of all accounts.
+/// Postings exchanging currencies balance in their base currency only, so the nets of the
+/// exchanged currencies are what treasury has to cover.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CurrencyPosition {
+    pub currency: String,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub net: BigDecimal,
+    pub direction: PositionDirection,
+}
+
+impl From<CurrencyTotal> for CurrencyPosition {
+    fn from(total: CurrencyTotal) -> Self {
+        let net = total.debit_balance();
+        let direction = if net.is_zero() {
+            PositionDirection::Flat
+        } else if net > BigDecimal::zero() {
+            PositionDirection::Long
+        } else {
+            PositionDirection::Short
+        };
+        Self { currency: total.currency, total_debit: total.total_debit, total_credit: total.total_credit, net, direction }
+    }
+}
+
+/// Net positions of a ledger per currency at the end of a value date, ordered by currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CurrencyPositionReport {
+    pub ledger: Ledger,
+    pub value_date: NaiveDate,
+    pub positions: Vec<CurrencyPosition>,
+}
+
+impl CurrencyPositionReport {
+    /// `totals` as accumulated by [`CurrencyTotal::accumulate`].
+    pub fn from_totals(ledger: Ledger, value_date: NaiveDate, totals: Vec<CurrencyTotal>) -> Self {
+        Self { ledger, value_date, positions: totals.into_iter().map(CurrencyPosition::from).collect() }
+    }
+
+    pub fn position(&self, currency: &str) -> Option<&CurrencyPosition> {
+        self.positions.iter().find(|p| p.currency == currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    #[test]
+    fn test_exchange_leaves_long_and_short_positions() {
+        // Buying 100 USD for 90 EUR
+        let mut totals = Vec::new();
+        CurrencyTotal::accumulate(&mut totals, "USD", &BigDecimal::from(100), &BigDecimal::from(0));
+        CurrencyTotal::accumulate(&mut totals, "EUR", &BigDecimal::from(0), &BigDecimal::from(90));
+        CurrencyTotal::accumulate(&mut totals, "CHF", &BigDecimal::from(20), &BigDecimal::from(20));
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        let report = CurrencyPositionReport::from_totals(ledger, NaiveDate::from_ymd_opt(2025, 8, 1).unwrap(), totals);
+
+        assert_eq!(report.position("USD").unwrap().direction, PositionDirection::Long);
+        let eur = report.position("EUR").unwrap();
+        assert_eq!(eur.direction, PositionDirection::Short);
+        assert_eq!(eur.net, BigDecimal::from(-90));
+        assert_eq!(report.position("CHF").unwrap().direction, PositionDirection::Flat);
+        assert!(report.position("GBP").is_none());
+    }
+}
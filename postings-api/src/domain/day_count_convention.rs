@@ -0,0 +1,86 @@
+use bigdecimal::BigDecimal;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Day-count conventions used to turn a date interval into a year fraction for interest accrual.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DayCountConvention {
+    Act360,
+    Act365Fixed,
+    ActActIsda,
+    Thirty360,
+}
+
+impl DayCountConvention {
+    /// Number of days counted between `from` (inclusive) and `to` (exclusive).
+    pub fn day_count(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        match self {
+            DayCountConvention::Thirty360 => {
+                let d1 = from.day().min(30) as i64;
+                let d2 = if d1 == 30 { to.day().min(30) } else { to.day() } as i64;
+                360 * (to.year() - from.year()) as i64
+                    + 30 * (to.month() as i64 - from.month() as i64)
+                    + (d2 - d1)
+            }
+            _ => (to - from).num_days(),
+        }
+    }
+
+    pub fn year_fraction(&self, from: NaiveDate, to: NaiveDate) -> BigDecimal {
+        match self {
+            DayCountConvention::Act360 | DayCountConvention::Thirty360 => {
+                BigDecimal::from(self.day_count(from, to)) / BigDecimal::from(360)
+            }
+            DayCountConvention::Act365Fixed => BigDecimal::from(self.day_count(from, to)) / BigDecimal::from(365),
+            DayCountConvention::ActActIsda => {
+                if to < from {
+                    return -self.year_fraction(to, from);
+                }
+                // Each calendar year contributes its days over its own length
+                let mut fraction = BigDecimal::from(0);
+                let mut start = from;
+                while start < to {
+                    let next_year = NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap();
+                    let end = next_year.min(to);
+                    let days_in_year = if start.leap_year() { 366 } else { 365 };
+                    fraction += BigDecimal::from((end - start).num_days()) / BigDecimal::from(days_in_year);
+                    start = end;
+                }
+                fraction
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_day_count() {
+        assert_eq!(DayCountConvention::Act360.day_count(date(2025, 1, 31), date(2025, 3, 1)), 29);
+        assert_eq!(DayCountConvention::Thirty360.day_count(date(2025, 1, 31), date(2025, 3, 1)), 31);
+        assert_eq!(DayCountConvention::Thirty360.day_count(date(2025, 1, 30), date(2025, 3, 31)), 60);
+    }
+
+    #[test]
+    fn test_year_fraction() {
+        assert_eq!(
+            DayCountConvention::Act360.year_fraction(date(2025, 1, 1), date(2025, 4, 1)),
+            BigDecimal::from_str("0.25").unwrap()
+        );
+        assert_eq!(
+            DayCountConvention::Act365Fixed.year_fraction(date(2025, 1, 1), date(2026, 1, 1)),
+            BigDecimal::from(1)
+        );
+        assert_eq!(
+            DayCountConvention::ActActIsda.year_fraction(date(2023, 7, 1), date(2024, 7, 1)),
+            BigDecimal::from(184) / BigDecimal::from(365) + BigDecimal::from(182) / BigDecimal::from(366)
+        );
+    }
+}
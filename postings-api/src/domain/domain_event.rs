@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::posting::Posting;
+
+/// Change of the ledger published once it is committed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum DomainEvent {
+    /// A posting was recorded with its lines.
+    PostingCreated(Posting),
+    /// `reversal` discarded the posting `reversed_id`.
+    PostingReversed { reversed_id: Uuid, reversal: Posting },
+    /// An account statement, or a ledger statement when `account_id` is `None`, was closed by
+    /// the posting `posting_id`.
+    StatementClosed {
+        stmt_id: Uuid,
+        ledger_id: Uuid,
+        account_id: Option<Uuid>,
+        pst_time: DateTime<Utc>,
+        posting_id: Uuid,
+    },
+}
@@ -0,0 +1,45 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Amount blocked on an account. Reduces the available balance without posting anything.
+/// Works as an authorization hold: it can be increased and captured in several partial amounts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Earmark {
+    pub id: Uuid,
+    pub account: LedgerAccount,
+    /// Total authorized amount, including incremental authorizations.
+    pub amount: BigDecimal,
+    pub captured_amount: BigDecimal,
+    pub reason: String,
+    pub created: DateTime<Utc>,
+    pub expiry: Option<DateTime<Utc>>,
+    pub status: EarmarkStatus,
+    pub closed_time: Option<DateTime<Utc>>,
+    /// Posting that booked the latest capture.
+    pub posting_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EarmarkStatus {
+    Active,
+    Released,
+    Consumed,
+    Expired,
+}
+
+impl Earmark {
+    /// Authorized amount not captured yet.
+    pub fn remaining_amount(&self) -> BigDecimal {
+        self.amount.clone() - self.captured_amount.clone()
+    }
+
+    /// Whether the earmark still blocks its amount at `ref_time`.
+    pub fn is_blocking(&self, ref_time: DateTime<Utc>) -> bool {
+        self.status == EarmarkStatus::Active
+            && self.created <= ref_time
+            && self.expiry.map_or(true, |expiry| ref_time < expiry)
+    }
+}
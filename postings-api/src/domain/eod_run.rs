@@ -0,0 +1,33 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+
+/// One end-of-day run of a ledger for a business date, with a checkpoint per completed step.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EodRun {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub business_date: NaiveDate,
+    pub status: EodStatus,
+    pub started: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+    pub steps: Vec<EodStepResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EodStepResult {
+    pub step_seq: i32,
+    pub step_name: String,
+    pub status: EodStatus,
+    pub message: Option<String>,
+    pub started: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EodStatus {
+    Running,
+    Completed,
+    Failed,
+}
@@ -0,0 +1,44 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Two-phase transfer: funds are parked on `escrow_account` on initiation, then either released to
+/// `target_account` or returned to `source_account`. All postings of an escrow share its id as `opr_src`.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Escrow {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub source_account: LedgerAccount,
+    pub escrow_account: LedgerAccount,
+    pub target_account: LedgerAccount,
+    pub amount: BigDecimal,
+    /// 32-byte hash of the operation type used for the generated postings
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    pub status: EscrowStatus,
+    pub timeout: DateTime<Utc>,
+    /// Resolution applied when the escrow is still pending at `timeout`.
+    pub timeout_action: EscrowTimeoutAction,
+    pub initiation_posting_id: Option<Uuid>,
+    pub resolution_posting_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Pending,
+    Released,
+    Returned,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EscrowTimeoutAction {
+    Release,
+    Return,
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Object holding the ledger events with sequence numbers `first_seq..=last_seq`, as gzip
+/// compressed JSON lines. Segments are written once and never replaced.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventLogSegment {
+    pub key: String,
+    pub first_seq: i64,
+    pub last_seq: i64,
+    pub event_count: u64,
+    /// Multihash of the stored object.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub hash: [u8; 34],
+    /// Hash of the preceding segment, `None` for the first one.
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub previous_hash: Option<[u8; 34]>,
+    pub created: DateTime<Utc>,
+}
+
+/// Index of an event log backup: its segments in sequence order. The backup contains exactly
+/// the segments listed here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EventLogManifest {
+    pub segments: Vec<EventLogSegment>,
+}
+
+impl EventLogManifest {
+    /// Sequence number of the last exported event.
+    pub fn last_seq(&self) -> Option<i64> {
+        self.segments.last().map(|s| s.last_seq)
+    }
+
+    /// Whether every segment links to its predecessor and follows it in sequence order, i.e. no
+    /// segment was removed, replaced or reordered.
+    pub fn is_chained(&self) -> bool {
+        let mut previous: Option<&EventLogSegment> = None;
+        for segment in &self.segments {
+            let linked = match previous {
+                Some(previous) => segment.previous_hash == Some(previous.hash) && segment.first_seq > previous.last_seq,
+                None => segment.previous_hash.is_none(),
+            };
+            if !linked || segment.first_seq > segment.last_seq {
+                return false;
+            }
+            previous = Some(segment);
+        }
+        true
+    }
+
+    /// Segments holding events in `from_seq..=to_seq`.
+    pub fn segments_between(&self, from_seq: i64, to_seq: i64) -> impl Iterator<Item = &EventLogSegment> {
+        self.segments.iter().filter(move |s| s.last_seq >= from_seq && s.first_seq <= to_seq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(first_seq: i64, last_seq: i64, hash: u8, previous_hash: Option<u8>) -> EventLogSegment {
+        EventLogSegment {
+            key: format!("segments/{first_seq}"),
+            first_seq,
+            last_seq,
+            event_count: (last_seq - first_seq + 1) as u64,
+            hash: [hash; 34],
+            previous_hash: previous_hash.map(|h| [h; 34]),
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_chain_detects_removed_segments() {
+        let mut manifest = EventLogManifest {
+            segments: vec![segment(1, 10, 1, None), segment(11, 20, 2, Some(1)), segment(23, 30, 3, Some(2))],
+        };
+        assert!(manifest.is_chained());
+        assert_eq!(manifest.last_seq(), Some(30));
+        assert_eq!(manifest.segments_between(15, 23).map(|s| s.first_seq).collect::<Vec<_>>(), vec![11, 23]);
+
+        manifest.segments.remove(1);
+        assert!(!manifest.is_chained());
+    }
+}
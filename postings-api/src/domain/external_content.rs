@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Reference to operation details kept in object storage. The content hash is what a posting
+/// carries as `opr_details`, so the posting hash chain also covers the external content.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExternalContent {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub content_hash: [u8; 34],
+    pub object_key: String,
+    pub size: i64,
+    pub media_type: String,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Identifier of a posting or account in another system, e.g. the reference of the core banking
+/// system or of a payment engine, for correlating records across systems.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExternalRef {
+    pub entity_type: ExternalRefEntity,
+    pub entity_id: Uuid,
+    pub ledger_id: Uuid,
+    /// Name of the other system. An entity carries at most one reference per system.
+    pub system: String,
+    pub reference: String,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExternalRefEntity {
+    Posting,
+    LedgerAccount,
+}
+
+/// How many entities of a type may carry the same reference of a system.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExternalRefUniqueness {
+    /// Any number, e.g. a batch reference shared by the postings of the batch.
+    Shared,
+    /// At most one per ledger.
+    #[default]
+    PerLedger,
+    /// At most one over all ledgers.
+    Global,
+}
@@ -0,0 +1,138 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Fee charged automatically on postings of one operation type. The fee is debited to the
+/// account of the first debit line of the originating posting and credited to `fee_account`.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeeSchedule {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// 32-byte hash of the operation type the schedule applies to
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    pub fee_type: FeeType,
+    pub flat_amount: Option<BigDecimal>,
+    /// Percentage expressed as a fraction, e.g. 0.015 for 1.5%.
+    pub rate: Option<BigDecimal>,
+    pub tiers: Vec<FeeTier>,
+    pub min_fee: Option<BigDecimal>,
+    pub max_fee: Option<BigDecimal>,
+    pub fee_account: LedgerAccount,
+    /// Product the schedule belongs to; `None` for the ledger-wide default of the operation type.
+    pub product_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FeeType {
+    Flat,
+    Percentage,
+    Tiered,
+}
+
+/// Tier applying to amounts from `from_amount` up to the next tier.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeeTier {
+    pub from_amount: BigDecimal,
+    pub flat_amount: BigDecimal,
+    pub rate: BigDecimal,
+}
+
+impl FeeSchedule {
+    /// Fee for an operation of `amount`, bounded by the minimum and maximum fee and rounded to cents.
+    pub fn calculate(&self, amount: &BigDecimal) -> BigDecimal {
+        let zero = BigDecimal::from(0);
+        let fee = match self.fee_type {
+            FeeType::Flat => self.flat_amount.clone().unwrap_or(zero.clone()),
+            FeeType::Percentage => amount * self.rate.clone().unwrap_or(zero.clone()),
+            FeeType::Tiered => self
+                .tiers
+                .iter()
+                .filter(|t| &t.from_amount <= amount)
+                .max_by(|a, b| a.from_amount.cmp(&b.from_amount))
+                .map(|t| t.flat_amount.clone() + amount * t.rate.clone())
+                .unwrap_or(zero.clone()),
+        };
+        let fee = match &self.min_fee {
+            Some(min) if &fee < min => min.clone(),
+            _ => fee,
+        };
+        let fee = match &self.max_fee {
+            Some(max) if &fee > max => max.clone(),
+            _ => fee,
+        };
+        fee.round(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    fn create_test_schedule(fee_type: FeeType) -> FeeSchedule {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+        FeeSchedule {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            opr_type: [0; 34],
+            fee_type,
+            flat_amount: None,
+            rate: None,
+            tiers: vec![],
+            min_fee: None,
+            max_fee: None,
+            fee_account: LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger,
+                parent: None,
+                coa,
+                balance_side: BalanceSide::Cr,
+                category: AccountCategory::RE,
+                currency: None,
+            },
+            product_id: None,
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_flat_and_percentage_fee() {
+        let mut flat = create_test_schedule(FeeType::Flat);
+        flat.flat_amount = Some(dec("2.50"));
+        assert_eq!(flat.calculate(&dec("1000")), dec("2.50"));
+
+        let mut percentage = create_test_schedule(FeeType::Percentage);
+        percentage.rate = Some(dec("0.015"));
+        percentage.min_fee = Some(dec("1.00"));
+        percentage.max_fee = Some(dec("10.00"));
+        assert_eq!(percentage.calculate(&dec("333.33")), dec("5.00"));
+        assert_eq!(percentage.calculate(&dec("10")), dec("1.00"));
+        assert_eq!(percentage.calculate(&dec("5000")), dec("10.00"));
+    }
+
+    #[test]
+    fn test_tiered_fee() {
+        let mut tiered = create_test_schedule(FeeType::Tiered);
+        tiered.tiers = vec![
+            FeeTier { from_amount: dec("1000"), flat_amount: dec("5"), rate: dec("0.001") },
+            FeeTier { from_amount: dec("0"), flat_amount: dec("1"), rate: dec("0") },
+        ];
+        assert_eq!(tiered.calculate(&dec("999.99")), dec("1.00"));
+        assert_eq!(tiered.calculate(&dec("2000")), dec("7.00"));
+    }
+}
@@ -0,0 +1,128 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Accounts the revaluation postings of a ledger are booked to, in the functional currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RevaluationAccounts {
+    /// Income account credited with unrealized gains.
+    pub gain_account: LedgerAccount,
+    /// Expense account debited with unrealized losses.
+    pub loss_account: LedgerAccount,
+    /// Balance sheet account carrying the revaluations of the foreign currency accounts.
+    pub adjustment_account: LedgerAccount,
+}
+
+/// Revaluation of one foreign currency account at the closing rate of a valuation date.
+/// Values are debit balances, negative for credit balances.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountRevaluation {
+    pub id: Uuid,
+    pub account: LedgerAccount,
+    /// Currency of the account.
+    pub currency: String,
+    pub valuation_time: DateTime<Utc>,
+    pub foreign_balance: BigDecimal,
+    /// Units of the functional currency per unit of `currency`.
+    pub rate: BigDecimal,
+    /// Value in the functional currency before the revaluation: the lines at the rates they were
+    /// booked at plus the earlier revaluations of the account.
+    pub carrying_value: BigDecimal,
+    /// `foreign_balance` at `rate`. Both values are rounded to cents like posting amounts.
+    pub revalued_value: BigDecimal,
+    /// Revaluation posting, `None` while not booked or when there was nothing to book.
+    pub posting_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+}
+
+impl AccountRevaluation {
+    pub fn new(account: LedgerAccount, currency: String, valuation_time: DateTime<Utc>, foreign_balance: BigDecimal, rate: BigDecimal, carrying_value: BigDecimal) -> Self {
+        let revalued_value = (&foreign_balance * &rate).round(2);
+        Self {
+            id: Uuid::new_v4(),
+            account,
+            currency,
+            valuation_time,
+            foreign_balance,
+            rate,
+            carrying_value: carrying_value.round(2),
+            revalued_value,
+            posting_id: None,
+            created: Utc::now(),
+        }
+    }
+
+    /// Unrealized gain, negative for a loss.
+    pub fn gain_loss(&self) -> BigDecimal {
+        self.revalued_value.clone() - self.carrying_value.clone()
+    }
+}
+
+/// Revaluation of the foreign currency accounts of a ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FxRevaluation {
+    pub ledger: Ledger,
+    pub functional_currency: String,
+    pub valuation_time: DateTime<Utc>,
+    pub revaluations: Vec<AccountRevaluation>,
+    pub total_gain: BigDecimal,
+    pub total_loss: BigDecimal,
+}
+
+impl FxRevaluation {
+    pub fn new(ledger: Ledger, functional_currency: String, valuation_time: DateTime<Utc>, revaluations: Vec<AccountRevaluation>) -> Self {
+        let zero = BigDecimal::from(0);
+        let (mut total_gain, mut total_loss) = (zero.clone(), zero.clone());
+        for gain_loss in revaluations.iter().map(AccountRevaluation::gain_loss) {
+            if gain_loss >= zero {
+                total_gain += gain_loss;
+            } else {
+                total_loss -= gain_loss;
+            }
+        }
+        Self { ledger, functional_currency, valuation_time, revaluations, total_gain, total_loss }
+    }
+
+    /// Net unrealized result, negative for a net loss.
+    pub fn net_gain_loss(&self) -> BigDecimal {
+        self.total_gain.clone() - self.total_loss.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn account(ledger: &Ledger, category: AccountCategory) -> LedgerAccount {
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side: category.default_bs(),
+            category,
+            currency: Some("USD".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rising_rate_gains_on_assets_and_loses_on_liabilities() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let rate = "0.95".parse::<BigDecimal>().unwrap();
+        // 100 USD booked at 0.90 on either side
+        let asset = AccountRevaluation::new(account(&ledger, AccountCategory::AS), "USD".to_string(), Utc::now(), BigDecimal::from(100), rate.clone(), BigDecimal::from(90));
+        let liability = AccountRevaluation::new(account(&ledger, AccountCategory::LI), "USD".to_string(), Utc::now(), BigDecimal::from(-100), rate, BigDecimal::from(-90));
+        assert_eq!(asset.gain_loss(), BigDecimal::from(5));
+        assert_eq!(liability.gain_loss(), BigDecimal::from(-5));
+
+        let revaluation = FxRevaluation::new(ledger, "EUR".to_string(), Utc::now(), vec![asset, liability]);
+        assert_eq!(revaluation.total_gain, BigDecimal::from(5));
+        assert_eq!(revaluation.total_loss, BigDecimal::from(5));
+        assert_eq!(revaluation.net_gain_loss(), BigDecimal::from(0));
+    }
+}
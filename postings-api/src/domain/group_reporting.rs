@@ -0,0 +1,144 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::account_group::AccountGroup;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::trial_balance::CATEGORY_ORDER;
+
+/// Rate a balance is translated into the reporting currency at.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TranslationRate {
+    /// Rate at the report's `ref_time`.
+    Closing,
+    /// Average rate from the start of the reporting period up to `ref_time`.
+    PeriodAverage,
+}
+
+/// Balance of one account in one currency and its translation into the reporting currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TranslatedLine {
+    pub account: LedgerAccount,
+    pub currency: String,
+    pub debit_balance: BigDecimal,
+    pub credit_balance: BigDecimal,
+    pub rate_type: TranslationRate,
+    /// Units of the reporting currency per unit of `currency`.
+    pub rate: BigDecimal,
+    pub translated_debit: BigDecimal,
+    pub translated_credit: BigDecimal,
+}
+
+impl TranslatedLine {
+    pub fn new(account: LedgerAccount, currency: String, net_debit: BigDecimal, rate_type: TranslationRate, rate: BigDecimal) -> Self {
+        let zero = BigDecimal::from(0);
+        let translated = &net_debit * &rate;
+        let (debit_balance, credit_balance) = if net_debit >= zero { (net_debit, zero.clone()) } else { (zero.clone(), -net_debit) };
+        let (translated_debit, translated_credit) = if translated >= zero { (translated, zero) } else { (zero, -translated) };
+        Self { account, currency, debit_balance, credit_balance, rate_type, rate, translated_debit, translated_credit }
+    }
+}
+
+/// Trial balance of an account group translated into the group reporting currency. Balances
+/// translated at different rates no longer add up; the difference is reported as translation
+/// difference on the short side, so the report balances.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TranslatedTrialBalance {
+    pub group: AccountGroup,
+    pub reporting_currency: String,
+    pub period_start: DateTime<Utc>,
+    pub ref_time: DateTime<Utc>,
+    /// Ordered by account category like a trial balance.
+    pub lines: Vec<TranslatedLine>,
+    pub translation_debit: BigDecimal,
+    pub translation_credit: BigDecimal,
+    /// Translated balances including the translation difference.
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl TranslatedTrialBalance {
+    pub fn new(
+        group: AccountGroup,
+        reporting_currency: String,
+        period_start: DateTime<Utc>,
+        ref_time: DateTime<Utc>,
+        mut lines: Vec<TranslatedLine>,
+    ) -> Self {
+        lines.sort_by_key(|l| CATEGORY_ORDER.iter().position(|c| *c == l.account.category));
+        let zero = BigDecimal::from(0);
+        let debit: BigDecimal = lines.iter().map(|l| l.translated_debit.clone()).sum();
+        let credit: BigDecimal = lines.iter().map(|l| l.translated_credit.clone()).sum();
+        let (translation_debit, translation_credit) = if debit >= credit {
+            (zero, debit.clone() - credit.clone())
+        } else {
+            (credit.clone() - debit.clone(), zero)
+        };
+        Self {
+            group,
+            reporting_currency,
+            period_start,
+            ref_time,
+            lines,
+            total_debit: debit + translation_debit.clone(),
+            total_credit: credit + translation_credit.clone(),
+            translation_debit,
+            translation_credit,
+        }
+    }
+
+    /// Translation difference, positive when it is reported on the credit side.
+    pub fn translation_difference(&self) -> BigDecimal {
+        self.translation_credit.clone() - self.translation_debit.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+    use uuid::Uuid;
+
+    fn account(ledger: &Ledger, category: AccountCategory) -> LedgerAccount {
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side: category.default_bs(),
+            category,
+            currency: Some("USD".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rate_differences_are_balanced_by_translation_difference() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let group = AccountGroup {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            name: "subsidiary".to_string(),
+            category: None,
+            member_ids: vec![],
+            created: Utc::now(),
+        };
+        let closing = "0.9".parse::<BigDecimal>().unwrap();
+        let average = "0.8".parse::<BigDecimal>().unwrap();
+        let report = TranslatedTrialBalance::new(
+            group,
+            "EUR".to_string(),
+            Utc::now(),
+            Utc::now(),
+            vec![
+                TranslatedLine::new(account(&ledger, AccountCategory::RE), "USD".to_string(), BigDecimal::from(-100), TranslationRate::PeriodAverage, average),
+                TranslatedLine::new(account(&ledger, AccountCategory::AS), "USD".to_string(), BigDecimal::from(100), TranslationRate::Closing, closing),
+            ],
+        );
+
+        assert_eq!(report.lines[0].account.category, AccountCategory::AS);
+        assert_eq!(report.lines[1].translated_credit, BigDecimal::from(80));
+        assert_eq!(report.translation_difference(), BigDecimal::from(10));
+        assert_eq!(report.total_debit, report.total_credit);
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use uuid::Uuid;
+use crate::domain::hashing_profile::HashedField;
+use crate::ServiceError;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -10,4 +12,90 @@ pub struct HashRecord {
     pub antecedent_hash: Option<[u8; 34]>,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
     pub hash: Option<[u8; 34]>,
+    /// Fields left out of `hash` by the ledger's hashing profile at recording time.
+    /// Omitted when empty so hashes of postings without a profile are unchanged.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_fields: Vec<HashedField>,
+    /// Rules `hash` was computed with. Omitted for legacy hashes so they are unchanged.
+    #[serde(default, skip_serializing_if = "HashVersion::is_legacy")]
+    pub version: HashVersion,
+    /// Function `hash` was computed with. Omitted for SHA-256 so earlier hashes are unchanged.
+    #[serde(default, skip_serializing_if = "HashAlgorithm::is_default")]
+    pub algorithm: HashAlgorithm,
+}
+
+/// How the posting hash is computed. Stored with every posting so verification recomputes
+/// a hash by the rules it was recorded with.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum HashVersion {
+    /// The posting as it was at hash time, lines in submission order. Closing postings
+    /// recorded under this version were hashed without a hashing view.
+    #[default]
+    Legacy,
+    /// The hashing view of the posting with its lines ordered by id, so the hash can be
+    /// recomputed from lines read back in any order.
+    Canonical,
+    /// The canonical view in the form the posting is stored in, see
+    /// [`crate::domain::hashing_profile::stored_view`], so the hash can be recomputed from the
+    /// database.
+    Stored,
+}
+
+impl HashVersion {
+    pub const CURRENT: HashVersion = HashVersion::Stored;
+
+    pub fn is_legacy(&self) -> bool {
+        *self == HashVersion::Legacy
+    }
+
+    /// Number used for persistence.
+    pub fn number(&self) -> i16 {
+        match self {
+            HashVersion::Legacy => 1,
+            HashVersion::Canonical => 2,
+            HashVersion::Stored => 3,
+        }
+    }
+
+    /// Unknown numbers are read as legacy.
+    pub fn from_number(number: i16) -> Self {
+        match number {
+            2 => HashVersion::Canonical,
+            3 => HashVersion::Stored,
+            _ => HashVersion::Legacy,
+        }
+    }
+}
+
+/// Hash function of a posting hash, chosen per ledger by its hashing profile. Only functions
+/// with 256 bit digests are offered, as hashes are stored as 34-byte multihashes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn is_default(&self) -> bool {
+        *self == HashAlgorithm::Sha256
+    }
+
+    /// Name used for persistence.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "SHA2_256",
+            HashAlgorithm::Blake3 => "BLAKE3",
+        }
+    }
+
+    /// Fails for unknown names, so a hash is never computed with a function it was not
+    /// recorded with.
+    pub fn parse(value: &str) -> Result<Self, ServiceError> {
+        match value {
+            "SHA2_256" => Ok(HashAlgorithm::Sha256),
+            "BLAKE3" => Ok(HashAlgorithm::Blake3),
+            _ => Err(ServiceError::UnknownHashAlgorithm { name: value.to_string() }),
+        }
+    }
 }
@@ -0,0 +1,360 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, SubsecRound, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::currency::FxDetails;
+use crate::domain::hash_record::{HashAlgorithm, HashRecord, HashVersion};
+use crate::domain::ledger::Ledger;
+use crate::domain::posting::Posting;
+use crate::domain::posting_line::PostingLine;
+use crate::domain::posting_status::PostingStatus;
+use crate::domain::posting_type::PostingType;
+
+/// How a ledger's postings are hashed: the hash function and the fields that are left out of
+/// the hash, so they can later be redacted without breaking chain verification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HashingProfile {
+    pub ledger: Ledger,
+    pub excluded_fields: Vec<HashedField>,
+    #[serde(default)]
+    pub algorithm: HashAlgorithm,
+    pub updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HashedField {
+    OprDetails,
+    OprSrc,
+    LineDetails,
+    LineSrcAccount,
+    LineAdditionalInformation,
+}
+
+impl HashedField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashedField::OprDetails => "OPR_DETAILS",
+            HashedField::OprSrc => "OPR_SRC",
+            HashedField::LineDetails => "LINE_DETAILS",
+            HashedField::LineSrcAccount => "LINE_SRC_ACCOUNT",
+            HashedField::LineAdditionalInformation => "LINE_ADDITIONAL_INFORMATION",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "OPR_DETAILS" => Some(HashedField::OprDetails),
+            "OPR_SRC" => Some(HashedField::OprSrc),
+            "LINE_DETAILS" => Some(HashedField::LineDetails),
+            "LINE_SRC_ACCOUNT" => Some(HashedField::LineSrcAccount),
+            "LINE_ADDITIONAL_INFORMATION" => Some(HashedField::LineAdditionalInformation),
+            _ => None,
+        }
+    }
+
+    /// Comma separated form used for persistence, `None` for an empty list.
+    pub fn join(fields: &[HashedField]) -> Option<String> {
+        if fields.is_empty() {
+            return None;
+        }
+        Some(fields.iter().map(HashedField::as_str).collect::<Vec<_>>().join(","))
+    }
+
+    /// Parses the persisted form, ignoring unknown names.
+    pub fn split(value: Option<&str>) -> Vec<HashedField> {
+        value
+            .map(|v| v.split(',').filter_map(|f| HashedField::parse(f.trim())).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// The posting as it enters the hash: fields listed in its hash record's `excluded_fields`
+/// are blanked, line categories and the hash itself are cleared. From
+/// [`HashVersion::Canonical`] on lines are ordered by id.
+pub fn hashed_view(posting: &Posting) -> Posting {
+    let mut view = posting.clone();
+    view.hash_record.hash = None;
+    view.lines.iter_mut().for_each(|l| l.category = None);
+    if view.hash_record.version != HashVersion::Legacy {
+        view.lines.sort_by_key(|l| l.id);
+    }
+    for field in &posting.hash_record.excluded_fields {
+        match field {
+            HashedField::OprDetails => view.opr_details = None,
+            HashedField::OprSrc => view.opr_src = None,
+            HashedField::LineDetails => view.lines.iter_mut().for_each(|l| l.details = None),
+            HashedField::LineSrcAccount => view.lines.iter_mut().for_each(|l| l.src_account = None),
+            HashedField::LineAdditionalInformation => view.lines.iter_mut().for_each(|l| l.additional_information = None),
+        }
+    }
+    view
+}
+
+/// Posting as it enters a [`HashVersion::Stored`] hash: the [`hashed_view`] in the form it is
+/// read back from the database. Times are cut to microseconds and amounts normalized, accounts
+/// are referenced by id, and line fields that are not stored are left out.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StoredView {
+    pub id: Uuid,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub record_user: [u8; 34],
+    pub record_time: DateTime<Utc>,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_id: [u8; 34],
+    pub opr_time: DateTime<Utc>,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_details: Option<[u8; 34]>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_src: Option<[u8; 34]>,
+    pub pst_time: DateTime<Utc>,
+    pub pst_type: PostingType,
+    pub pst_status: PostingStatus,
+    pub ledger_id: Uuid,
+    pub val_time: Option<DateTime<Utc>>,
+    pub lines: Vec<StoredLineView>,
+    #[serde(flatten)]
+    pub hash_record: HashRecord,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StoredLineView {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub details: Option<[u8; 34]>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub src_account: Option<[u8; 34]>,
+    pub base_line: Option<Uuid>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub sub_opr_src_id: Option<[u8; 34]>,
+    pub record_time: DateTime<Utc>,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_id: [u8; 34],
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_src: Option<[u8; 34]>,
+    pub pst_time: DateTime<Utc>,
+    pub pst_type: PostingType,
+    pub pst_status: PostingStatus,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub hash: Option<[u8; 34]>,
+    pub currency: Option<String>,
+    pub fx: Option<FxDetails>,
+}
+
+/// Database timestamps keep microseconds.
+fn stored_time(time: DateTime<Utc>) -> DateTime<Utc> {
+    time.trunc_subsecs(6)
+}
+
+impl From<&PostingLine> for StoredLineView {
+    fn from(line: &PostingLine) -> Self {
+        StoredLineView {
+            id: line.id,
+            account_id: line.account.id,
+            debit_amount: line.debit_amount.normalized(),
+            credit_amount: line.credit_amount.normalized(),
+            details: line.details,
+            src_account: line.src_account,
+            base_line: line.base_line,
+            sub_opr_src_id: line.sub_opr_src_id,
+            record_time: stored_time(line.record_time),
+            opr_id: line.opr_id,
+            opr_src: line.opr_src,
+            pst_time: stored_time(line.pst_time),
+            pst_type: line.pst_type.clone(),
+            pst_status: line.pst_status.clone(),
+            hash: line.hash,
+            currency: line.currency.clone(),
+            fx: line.fx.as_ref().map(|fx| FxDetails { base_currency: fx.base_currency.clone(), rate: fx.rate.normalized() }),
+        }
+    }
+}
+
+/// The [`StoredView`] of a posting, with the excluded fields of its hash record blanked.
+pub fn stored_view(posting: &Posting) -> StoredView {
+    let view = hashed_view(posting);
+    StoredView {
+        id: view.id,
+        record_user: view.record_user,
+        record_time: stored_time(view.record_time),
+        opr_id: view.opr_id,
+        opr_time: stored_time(view.opr_time),
+        opr_type: view.opr_type,
+        opr_details: view.opr_details,
+        opr_src: view.opr_src,
+        pst_time: stored_time(view.pst_time),
+        pst_type: view.pst_type,
+        pst_status: view.pst_status,
+        ledger_id: view.ledger.id,
+        val_time: view.val_time.map(stored_time),
+        lines: view.lines.iter().map(StoredLineView::from).collect(),
+        hash_record: view.hash_record,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bigdecimal::BigDecimal;
+    use uuid::Uuid;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::hash_record::HashRecord;
+    use crate::domain::ledger_account::LedgerAccount;
+    use crate::domain::posting_line::PostingLine;
+    use crate::domain::posting_status::PostingStatus;
+    use crate::domain::posting_type::PostingType;
+
+    fn line(debit: i64, credit: i64) -> PostingLine {
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: LedgerAccount {
+                id: Uuid::nil(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: ledger.coa,
+                balance_side: BalanceSide::Dr,
+                category: AccountCategory::AS,
+                currency: None,
+            },
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: Some([3; 34]),
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [1; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+            category: Some("GROCERIES".to_string()),
+            currency: None,
+            fx: None,
+        }
+    }
+
+    fn posting(lines: Vec<PostingLine>, version: HashVersion) -> Posting {
+        let now = Utc::now();
+        Posting {
+            id: Uuid::new_v4(),
+            record_user: [0; 34],
+            record_time: now,
+            opr_id: [1; 34],
+            opr_time: now,
+            opr_type: [2; 34],
+            opr_details: Some([4; 34]),
+            opr_src: None,
+            pst_time: now,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } },
+            val_time: None,
+            lines,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: HashRecord { hash: Some([8; 34]), version, ..HashRecord::default() },
+        }
+    }
+
+    #[test]
+    fn test_canonical_view_ignores_line_order() {
+        let lines = vec![line(10, 0), line(0, 4), line(0, 6)];
+        let submitted = posting(lines.clone(), HashVersion::Canonical);
+        let mut read_back = submitted.clone();
+        read_back.lines.reverse();
+
+        let view = hashed_view(&submitted);
+        assert_eq!(view, hashed_view(&read_back));
+        assert!(view.lines.windows(2).all(|w| w[0].id <= w[1].id));
+        assert_eq!(view.hash_record.hash, None);
+        assert!(view.lines.iter().all(|l| l.category.is_none()));
+    }
+
+    #[test]
+    fn test_legacy_view_keeps_line_order() {
+        let submitted = posting(vec![line(10, 0), line(0, 4), line(0, 6)], HashVersion::Legacy);
+        let mut read_back = submitted.clone();
+        read_back.lines.reverse();
+
+        let view = hashed_view(&submitted);
+        let ids: Vec<Uuid> = submitted.lines.iter().map(|l| l.id).collect();
+        assert_eq!(view.lines.iter().map(|l| l.id).collect::<Vec<_>>(), ids);
+        assert_ne!(view, hashed_view(&read_back));
+    }
+
+    #[test]
+    fn test_view_blanks_excluded_fields() {
+        let mut recorded = posting(vec![line(10, 0), line(0, 10)], HashVersion::Canonical);
+        recorded.hash_record.excluded_fields = vec![HashedField::OprDetails, HashedField::LineDetails];
+
+        let view = hashed_view(&recorded);
+        assert_eq!(view.opr_details, None);
+        assert!(view.lines.iter().all(|l| l.details.is_none()));
+        assert_eq!(view.hash_record.excluded_fields, recorded.hash_record.excluded_fields);
+    }
+
+    #[test]
+    fn test_stored_view_matches_posting_read_back() {
+        let mut submitted = posting(vec![line(10, 0), line(0, 10)], HashVersion::Stored);
+        submitted.lines[0].additional_information = Some("fee".to_string());
+        let mut read_back = submitted.clone();
+        read_back.record_time = submitted.record_time.trunc_subsecs(6);
+        read_back.lines.reverse();
+        for line in read_back.lines.iter_mut() {
+            line.debit_amount = line.debit_amount.with_scale(2);
+            line.pst_time = line.pst_time.trunc_subsecs(6);
+            line.additional_information = None;
+            line.account.parent = Some(Box::new(line.account.clone()));
+        }
+
+        assert_eq!(stored_view(&submitted), stored_view(&read_back));
+        assert!(stored_view(&submitted).lines.iter().all(|l| l.account_id == Uuid::nil()));
+    }
+
+    #[test]
+    fn test_hash_version_numbers() {
+        assert_eq!(HashVersion::from_number(HashVersion::CURRENT.number()), HashVersion::CURRENT);
+        assert_eq!(HashVersion::from_number(HashVersion::Legacy.number()), HashVersion::Legacy);
+        assert_eq!(HashVersion::from_number(HashVersion::Canonical.number()), HashVersion::Canonical);
+        assert_eq!(HashVersion::from_number(99), HashVersion::Legacy);
+    }
+
+    #[test]
+    fn test_hash_algorithm_names() {
+        for algorithm in [HashAlgorithm::Sha256, HashAlgorithm::Blake3] {
+            assert_eq!(HashAlgorithm::parse(algorithm.as_str()).unwrap(), algorithm);
+        }
+        assert!(matches!(HashAlgorithm::parse("MD5"), Err(crate::ServiceError::UnknownHashAlgorithm { name }) if name == "MD5"));
+        assert!(HashAlgorithm::parse("SHA2_512").is_err());
+    }
+
+    #[test]
+    fn test_join_and_split_round_trip() {
+        let fields = vec![HashedField::OprDetails, HashedField::LineAdditionalInformation];
+        let joined = HashedField::join(&fields);
+        assert_eq!(joined.as_deref(), Some("OPR_DETAILS,LINE_ADDITIONAL_INFORMATION"));
+        assert_eq!(HashedField::split(joined.as_deref()), fields);
+    }
+
+    #[test]
+    fn test_empty_and_unknown_fields() {
+        assert_eq!(HashedField::join(&[]), None);
+        assert!(HashedField::split(None).is_empty());
+        assert_eq!(HashedField::split(Some("OPR_SRC,MEMO")), vec![HashedField::OprSrc]);
+    }
+}
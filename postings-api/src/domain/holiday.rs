@@ -0,0 +1,11 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::domain::ledger::Ledger;
+
+/// A non-business day in the calendar of a ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Holiday {
+    pub ledger: Ledger,
+    pub date: NaiveDate,
+    pub name: String,
+}
@@ -13,4 +13,8 @@ pub struct LedgerAccount {
     pub coa: ChartOfAccount,
     pub balance_side: BalanceSide,
     pub category: AccountCategory,
+    /// ISO 4217 code all lines of the account are booked in. `None` for accounts holding
+    /// several currencies, and for ledgers that do not track currencies at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
 }
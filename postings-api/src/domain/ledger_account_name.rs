@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use type_rules::prelude::*;
+use uuid::Uuid;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Human readable name and number of a ledger account for a validity period, e.g. number
+/// `1.1.2.3` and name `Cash`. Within a ledger, a name or number identifies at most one account
+/// at any time; a renamed account gets a new entry from the day of the change.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validator)]
+pub struct LedgerAccountName {
+    pub id: Uuid,
+    pub account: LedgerAccount,
+    #[rule(Opt(MaxLength(64)))]
+    pub number: Option<String>,
+    #[rule(MaxLength(255))]
+    pub name: String,
+    pub valid_from: DateTime<Utc>,
+    /// Exclusive end of the validity, `None` while the name is in use.
+    pub valid_to: Option<DateTime<Utc>>,
+    pub created: DateTime<Utc>,
+}
+
+impl LedgerAccountName {
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.valid_from <= at && self.valid_to.is_none_or(|to| at < to)
+    }
+
+    /// Whether the validity period has a time in common with `valid_from..valid_to`.
+    pub fn overlaps(&self, valid_from: DateTime<Utc>, valid_to: Option<DateTime<Utc>>) -> bool {
+        self.valid_to.is_none_or(|to| valid_from < to) && valid_to.is_none_or(|to| self.valid_from < to)
+    }
+
+    /// Number and name as usually displayed, e.g. `1.1.2.3 Cash`.
+    pub fn label(&self) -> String {
+        match &self.number {
+            Some(number) => format!("{number} {}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+
+    fn name(valid_from: DateTime<Utc>, valid_to: Option<DateTime<Utc>>) -> LedgerAccountName {
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        LedgerAccountName {
+            id: Uuid::new_v4(),
+            account: LedgerAccount {
+                id: Uuid::nil(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: ledger.coa,
+                balance_side: BalanceSide::Dr,
+                category: AccountCategory::AS,
+                currency: None,
+            },
+            number: Some("1.1.2.3".to_string()),
+            name: "Cash".to_string(),
+            valid_from,
+            valid_to,
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_validity_periods_end_exclusively() {
+        let start = Utc::now();
+        let renamed = start + Duration::days(30);
+        let old = name(start, Some(renamed));
+        let new = name(renamed, None);
+
+        assert!(old.is_valid_at(start));
+        assert!(!old.is_valid_at(renamed));
+        assert!(new.is_valid_at(renamed + Duration::days(365)));
+        assert!(!old.overlaps(new.valid_from, new.valid_to));
+        assert!(old.overlaps(start + Duration::days(29), None));
+        assert_eq!(old.label(), "1.1.2.3 Cash");
+    }
+}
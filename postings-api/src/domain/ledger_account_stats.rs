@@ -0,0 +1,42 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Overview of an account gathered in one call, for support tooling and account screens.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerAccountStats {
+    pub account: LedgerAccount,
+    /// Number of current, non discarded lines of the account.
+    pub line_count: u64,
+    pub first_pst_time: Option<DateTime<Utc>>,
+    pub last_pst_time: Option<DateTime<Utc>>,
+    /// Totals including everything posted so far.
+    pub balance: AccountBalance,
+    pub last_stmt: Option<StmtSummary>,
+    pub chain_position: Option<ChainPosition>,
+}
+
+/// The last closed statement of the account.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtSummary {
+    pub id: Uuid,
+    pub pst_time: DateTime<Utc>,
+    pub stmt_seq_nbr: i32,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub line_count: i64,
+}
+
+/// The posting that last touched the account, where it sits in the ledger's hash chain.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChainPosition {
+    pub posting_id: Uuid,
+    pub record_time: DateTime<Utc>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub hash: Option<[u8; 34]>,
+}
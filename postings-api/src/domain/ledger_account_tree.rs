@@ -0,0 +1,86 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use crate::domain::account_balance::AccountBalance;
+
+/// An account with its own balance and the balances of the accounts below it, rolled up.
+/// Totals of accounts in different currencies are added as they are, without conversion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerAccountTree {
+    /// Totals of the lines booked on the account itself.
+    pub balance: AccountBalance,
+    /// Own totals plus those of all descendants.
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub children: Vec<LedgerAccountTree>,
+}
+
+impl LedgerAccountTree {
+    /// Rolls the totals of `children` up into the node of `balance`.
+    pub fn new(balance: AccountBalance, children: Vec<LedgerAccountTree>) -> Self {
+        let (total_debit, total_credit) = children.iter().fold(
+            (balance.total_debit.clone(), balance.total_credit.clone()),
+            |(d, c), child| (d + child.total_debit.clone(), c + child.total_credit.clone()),
+        );
+        Self { balance, total_debit, total_credit, children }
+    }
+
+    pub fn debit_balance(&self) -> BigDecimal {
+        self.total_debit.clone() - self.total_credit.clone()
+    }
+
+    pub fn credit_balance(&self) -> BigDecimal {
+        self.total_credit.clone() - self.total_debit.clone()
+    }
+
+    /// Number of accounts in the tree, this one included.
+    pub fn size(&self) -> usize {
+        1 + self.children.iter().map(LedgerAccountTree::size).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+    use crate::domain::ledger_account::LedgerAccount;
+
+    fn leaf(debit: i64, credit: i64) -> LedgerAccountTree {
+        let coa = ChartOfAccount { id: Uuid::nil() };
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::nil(), coa: coa.clone() },
+            parent: None,
+            coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            currency: None,
+        };
+        let balance = AccountBalance {
+            account,
+            ref_time: Utc::now(),
+            total_debit: BigDecimal::from(debit),
+            total_credit: BigDecimal::from(credit),
+            known_at: None,
+        };
+        LedgerAccountTree::new(balance, Vec::new())
+    }
+
+    #[test]
+    fn test_totals_roll_up_through_levels() {
+        let child = LedgerAccountTree::new(leaf(10, 0).balance, vec![leaf(100, 30), leaf(5, 5)]);
+        let root = LedgerAccountTree::new(leaf(1, 2).balance, vec![child, leaf(0, 50)]);
+
+        assert_eq!(root.children[0].debit_balance(), BigDecimal::from(85));
+        assert_eq!(root.total_debit, BigDecimal::from(116));
+        assert_eq!(root.total_credit, BigDecimal::from(87));
+        assert_eq!(root.debit_balance(), BigDecimal::from(29));
+        // Own totals stay as booked
+        assert_eq!(root.balance.debit_balance(), BigDecimal::from(-1));
+        assert_eq!(root.size(), 5);
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+
+/// Lock of a ledger, or of one of its accounts, against postings before a cut-off posting time.
+/// Closures are never lifted; the latest cut-off of a ledger or account is the effective one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerClosure {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// Locked account, the whole ledger when unset.
+    pub account_id: Option<Uuid>,
+    pub cut_off: DateTime<Utc>,
+    /// Whether privileged adjustments may still be posted before the cut-off.
+    pub allow_adjustments: bool,
+    pub closed_by: String,
+    pub reason: String,
+    pub created: DateTime<Utc>,
+}
+
+impl LedgerClosure {
+    /// Whether the closure rejects a posting at `pst_time` with lines on `account_ids`.
+    /// Adjustments pass closures that allow them.
+    pub fn rejects(&self, pst_time: DateTime<Utc>, mut account_ids: impl Iterator<Item = Uuid>, adjustment: bool) -> bool {
+        if pst_time >= self.cut_off || (adjustment && self.allow_adjustments) {
+            return false;
+        }
+        match self.account_id {
+            Some(locked) => account_ids.any(|id| id == locked),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn closure(account_id: Option<Uuid>, allow_adjustments: bool) -> LedgerClosure {
+        LedgerClosure {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } },
+            account_id,
+            cut_off: Utc::now(),
+            allow_adjustments,
+            closed_by: "controller".to_string(),
+            reason: "Month end".to_string(),
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_rejects_postings_before_cut_off() {
+        let (locked, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let ledger_closure = closure(None, false);
+        let before = ledger_closure.cut_off - Duration::seconds(1);
+        assert!(ledger_closure.rejects(before, [other].into_iter(), false));
+        assert!(ledger_closure.rejects(before, [other].into_iter(), true));
+        assert!(!ledger_closure.rejects(ledger_closure.cut_off, [other].into_iter(), false));
+
+        let account_closure = closure(Some(locked), true);
+        assert!(account_closure.rejects(before, [other, locked].into_iter(), false));
+        assert!(!account_closure.rejects(before, [other].into_iter(), false));
+        assert!(!account_closure.rejects(before, [locked].into_iter(), true));
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// State of one account at a date. Accounts are matched across ledgers by `key`
+/// (the account name, or its id when the account is unnamed).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountSnapshot {
+    pub key: String,
+    pub balance: BigDecimal,
+    pub line_count: i64,
+}
+
+/// Account balances of a ledger at the end of `as_of`, either computed or imported from another system.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerSnapshot {
+    pub label: String,
+    pub as_of: NaiveDate,
+    pub accounts: Vec<AccountSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiffKind {
+    MissingLeft,
+    MissingRight,
+    BalanceMismatch,
+    CountMismatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountDiff {
+    pub key: String,
+    pub kinds: Vec<DiffKind>,
+    pub left: Option<AccountSnapshot>,
+    pub right: Option<AccountSnapshot>,
+    /// Right balance minus left balance, when the account exists on both sides.
+    pub balance_difference: Option<BigDecimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerDiff {
+    pub left: String,
+    pub right: String,
+    pub as_of: NaiveDate,
+    pub compared_accounts: usize,
+    pub differences: Vec<AccountDiff>,
+}
+
+impl LedgerDiff {
+    pub fn is_identical(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl LedgerSnapshot {
+    /// Account-by-account comparison; only accounts with at least one difference are reported.
+    pub fn diff(&self, right: &LedgerSnapshot) -> LedgerDiff {
+        let left_accounts: BTreeMap<&str, &AccountSnapshot> = self.accounts.iter().map(|a| (a.key.as_str(), a)).collect();
+        let right_accounts: BTreeMap<&str, &AccountSnapshot> = right.accounts.iter().map(|a| (a.key.as_str(), a)).collect();
+        let mut keys: Vec<&str> = left_accounts.keys().chain(right_accounts.keys()).copied().collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut differences = Vec::new();
+        for key in keys.iter() {
+            let l = left_accounts.get(key).copied();
+            let r = right_accounts.get(key).copied();
+            let mut kinds = Vec::new();
+            let mut balance_difference = None;
+            match (l, r) {
+                (None, Some(_)) => kinds.push(DiffKind::MissingLeft),
+                (Some(_), None) => kinds.push(DiffKind::MissingRight),
+                (Some(l), Some(r)) => {
+                    if l.balance != r.balance {
+                        kinds.push(DiffKind::BalanceMismatch);
+                        balance_difference = Some(r.balance.clone() - l.balance.clone());
+                    }
+                    if l.line_count != r.line_count {
+                        kinds.push(DiffKind::CountMismatch);
+                    }
+                }
+                (None, None) => {}
+            }
+            if !kinds.is_empty() {
+                differences.push(AccountDiff {
+                    key: key.to_string(),
+                    kinds,
+                    left: l.cloned(),
+                    right: r.cloned(),
+                    balance_difference,
+                });
+            }
+        }
+
+        LedgerDiff {
+            left: self.label.clone(),
+            right: right.label.clone(),
+            as_of: self.as_of,
+            compared_accounts: keys.len(),
+            differences,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(key: &str, balance: i64, line_count: i64) -> AccountSnapshot {
+        AccountSnapshot { key: key.to_string(), balance: BigDecimal::from(balance), line_count }
+    }
+
+    fn snapshot(label: &str, accounts: Vec<AccountSnapshot>) -> LedgerSnapshot {
+        LedgerSnapshot { label: label.to_string(), as_of: NaiveDate::from_ymd_opt(2025, 6, 30).unwrap(), accounts }
+    }
+
+    #[test]
+    fn test_diff_reports_all_kinds() {
+        let left = snapshot("java", vec![account("1000", 100, 2), account("2000", 50, 1), account("3000", 7, 1)]);
+        let right = snapshot("rust", vec![account("1000", 100, 2), account("2000", 40, 3), account("4000", 1, 1)]);
+        let diff = left.diff(&right);
+
+        assert_eq!(diff.compared_accounts, 4);
+        assert_eq!(diff.differences.len(), 3);
+        assert_eq!(diff.differences[0].kinds, vec![DiffKind::BalanceMismatch, DiffKind::CountMismatch]);
+        assert_eq!(diff.differences[0].balance_difference, Some(BigDecimal::from(-10)));
+        assert_eq!(diff.differences[1].kinds, vec![DiffKind::MissingRight]);
+        assert_eq!(diff.differences[2].kinds, vec![DiffKind::MissingLeft]);
+    }
+
+    #[test]
+    fn test_identical_snapshots() {
+        let left = snapshot("a", vec![account("1000", 100, 2)]);
+        assert!(left.diff(&left.clone()).is_identical());
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Change of a row of the core ledger tables, recorded by the database in the transaction of the
+/// change. `seq` orders the events of all tables.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerEvent {
+    pub seq: i64,
+    /// Table of the changed row, e.g. `posting`.
+    pub entity_type: String,
+    pub entity_id: String,
+    pub operation: LedgerEventOperation,
+    /// JSON of the row after the change, or of the deleted row. Hashes are hex encoded.
+    pub payload: String,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LedgerEventOperation {
+    Insert,
+    Update,
+    Delete,
+}
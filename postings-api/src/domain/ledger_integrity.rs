@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::chain_verification::ChainVerification;
+use crate::domain::stmt_repair::StmtDrift;
+
+/// Why an account breaks the account hierarchy of its ledger.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HierarchyBreak {
+    /// The parent account does not exist.
+    MissingParent,
+    /// The parent account belongs to another ledger.
+    ParentOutsideLedger,
+    /// The account is its own ancestor.
+    Cycle,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HierarchyIssue {
+    pub account_id: Uuid,
+    pub parent_id: Uuid,
+    pub reason: HierarchyBreak,
+}
+
+/// Current posting whose lines do not balance.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnbalancedPosting {
+    pub posting_id: Uuid,
+    pub message: String,
+}
+
+/// Outcome of all integrity checks of a ledger. Unlike the chain walk, the other checks report
+/// every finding rather than stopping at the first one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerIntegrityReport {
+    pub ledger_id: Uuid,
+    pub checked_at: DateTime<Utc>,
+    pub chain: ChainVerification,
+    pub stmt_drifts: Vec<StmtDrift>,
+    pub hierarchy_issues: Vec<HierarchyIssue>,
+    pub unbalanced_postings: Vec<UnbalancedPosting>,
+}
+
+impl LedgerIntegrityReport {
+    pub fn is_intact(&self) -> bool {
+        self.chain.is_intact()
+            && self.stmt_drifts.is_empty()
+            && self.hierarchy_issues.is_empty()
+            && self.unbalanced_postings.is_empty()
+    }
+}
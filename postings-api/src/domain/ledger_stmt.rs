@@ -1,10 +1,21 @@
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use crate::domain::financial_stmt::FinancialStmt;
 use crate::domain::ledger::Ledger;
 
+/// Ledger-level statement: the totals of all accounts of a ledger at a point in time.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LedgerStmt {
     #[serde(flatten)]
     pub financial_stmt: FinancialStmt,
     pub ledger: Ledger,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl LedgerStmt {
+    /// A consistent ledger has equal debit and credit totals.
+    pub fn is_balanced(&self) -> bool {
+        self.total_debit == self.total_credit
+    }
 }
@@ -1,16 +1,79 @@
+pub mod account_activity;
+pub mod account_balance;
 pub mod account_category;
+pub mod account_group;
+pub mod account_limit;
+pub mod account_overview;
+pub mod account_position;
 pub mod account_stmt;
+pub mod account_stmt_delta;
+pub mod api_key;
+pub mod backfill;
+pub mod balance_forecast;
 pub mod balance_side;
+pub mod batch_status;
+pub mod business_calendar;
+pub mod category_rule;
+pub mod chain_verification;
+pub mod change_set;
 pub mod chart_of_account;
+pub mod closing_summary;
+pub mod coa_import;
+pub mod currency;
+pub mod currency_position;
+pub mod day_count_convention;
+pub mod domain_event;
+pub mod earmark;
+pub mod eod_run;
+pub mod escrow;
+pub mod event_log_backup;
+pub mod external_content;
+pub mod external_ref;
+pub mod fee_schedule;
 pub mod financial_stmt;
+pub mod fx_revaluation;
+pub mod group_reporting;
 pub mod hash_record;
+pub mod hashing_profile;
+pub mod holiday;
 pub mod ledger;
 pub mod ledger_account;
+pub mod ledger_account_name;
+pub mod ledger_account_stats;
+pub mod ledger_account_tree;
+pub mod ledger_closure;
+pub mod ledger_comparison;
+pub mod ledger_event;
+pub mod ledger_integrity;
 pub mod ledger_stmt;
 pub mod named;
+pub mod opening_balance;
+pub mod operation_history;
 pub mod posting;
 pub mod posting_line;
+pub mod posting_search;
 pub mod posting_status;
 pub mod posting_trace;
 pub mod posting_type;
+pub mod prepared_posting;
+pub mod privileged_context;
+pub mod product;
+pub mod quarantined_entry;
+pub mod report_schedule;
+pub mod reversal_policy;
+pub mod scheduled_posting;
+pub mod settlement_batch;
+pub mod shadow_posting;
+pub mod standing_order;
+pub mod stmt_annotation;
+pub mod stmt_delivery;
+pub mod stmt_diagnostics;
+pub mod stmt_job;
+pub mod stmt_period;
+pub mod stmt_repair;
+pub mod stmt_signature;
 pub mod stmt_status;
+pub mod stmt_template;
+pub mod tenant_quota;
+pub mod trial_balance;
+pub mod youngest_pst;
@@ -0,0 +1,126 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::quarantined_entry::QuarantinedEntry;
+use crate::ServiceError;
+
+/// Closing balance of an account in the source system, expressed on the account's balance side.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpeningBalanceRow {
+    pub account_id: Uuid,
+    pub balance: BigDecimal,
+}
+
+impl OpeningBalanceRow {
+    /// Parses `account_id,balance` lines. Blank lines and a leading header line are skipped.
+    pub fn parse_csv(csv: &str) -> Result<Vec<OpeningBalanceRow>, ServiceError> {
+        let (rows, rejected) = Self::parse_csv_lenient(csv);
+        match rejected.first() {
+            Some((line, _)) => Err(ServiceError::InvalidImportRecord { line: *line }),
+            None => Ok(rows.into_iter().map(|(_, row)| row).collect()),
+        }
+    }
+
+    /// Like [`OpeningBalanceRow::parse_csv`], but returns invalid lines (with their line number)
+    /// next to the valid rows instead of failing.
+    pub fn parse_csv_lenient(csv: &str) -> (Vec<(usize, OpeningBalanceRow)>, Vec<(usize, String)>) {
+        let mut rows = Vec::new();
+        let mut rejected = Vec::new();
+        for (idx, raw) in csv.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match Self::parse_line(line) {
+                Some(row) => rows.push((idx + 1, row)),
+                None if idx == 0 => continue,
+                None => rejected.push((idx + 1, line.to_string())),
+            }
+        }
+        (rows, rejected)
+    }
+
+    /// Parses a single `account_id,balance` record.
+    pub fn parse_line(line: &str) -> Option<OpeningBalanceRow> {
+        let mut fields = line.split(',').map(str::trim);
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(account), Some(balance), None) => match (Uuid::parse_str(account), BigDecimal::from_str(balance)) {
+                (Ok(account_id), Ok(balance)) => Some(OpeningBalanceRow { account_id, balance }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Result of loading one account: what the source said and what the ledger holds afterwards.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpeningBalanceCheck {
+    pub account_id: Uuid,
+    pub source_balance: BigDecimal,
+    pub loaded_balance: Option<BigDecimal>,
+    pub posting_id: Option<Uuid>,
+    pub status: OpeningBalanceStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OpeningBalanceStatus {
+    Matched,
+    Mismatch,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpeningBalanceReport {
+    pub ledger: Ledger,
+    pub migration_account: LedgerAccount,
+    pub checks: Vec<OpeningBalanceCheck>,
+    /// Records set aside by a lenient import.
+    pub quarantined: Vec<QuarantinedEntry>,
+    /// First line of this run when it resumed an interrupted import of the same file. Checks and
+    /// quarantined records then cover this run only.
+    pub resumed_from_line: Option<usize>,
+}
+
+impl OpeningBalanceReport {
+    pub fn is_verified(&self) -> bool {
+        self.quarantined.is_empty() && self.checks.iter().all(|c| c.status == OpeningBalanceStatus::Matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_with_header() {
+        let id = Uuid::new_v4();
+        let csv = format!("account_id,balance\n{id},1250.50\n\n{id}, -10\n");
+        let rows = OpeningBalanceRow::parse_csv(&csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].balance, BigDecimal::from_str("1250.50").unwrap());
+        assert_eq!(rows[1].balance, BigDecimal::from(-10));
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_invalid_line() {
+        let csv = format!("{},12\nnot-an-id,3\n", Uuid::new_v4());
+        assert!(matches!(
+            OpeningBalanceRow::parse_csv(&csv),
+            Err(ServiceError::InvalidImportRecord { line: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_parse_csv_lenient_keeps_valid_rows() {
+        let id = Uuid::new_v4();
+        let csv = format!("account_id,balance\n{id},12\n{id},abc\n\n{id},5,extra\n{id},7\n");
+        let (rows, rejected) = OpeningBalanceRow::parse_csv_lenient(&csv);
+        assert_eq!(rows.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![2, 6]);
+        assert_eq!(rejected, vec![(3, format!("{id},abc")), (5, format!("{id},5,extra"))]);
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use crate::domain::posting::Posting;
+use crate::domain::posting_line::PostingLine;
+
+/// Every version of an operation, as recorded over time.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OperationHistory {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_id: [u8; 34],
+    /// Posting versions ordered by record time.
+    pub versions: Vec<OperationVersion>,
+    /// Legs of all versions ordered by record time.
+    pub lines: Vec<PostingLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OperationVersion {
+    pub posting: Posting,
+    pub role: VersionRole,
+    /// Whether a later version discarded this one.
+    pub superseded: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VersionRole {
+    /// First recording of the operation.
+    Original,
+    /// Recorded to replace (discard) an earlier version.
+    Correction,
+}
+
+impl OperationVersion {
+    pub fn of(posting: Posting) -> Self {
+        let role = if posting.discarded_id.is_some() { VersionRole::Correction } else { VersionRole::Original };
+        let superseded = posting.discarding_id.is_some() || posting.discarded_time.is_some();
+        OperationVersion { posting, role, superseded }
+    }
+}
+
+impl OperationHistory {
+    /// The version currently in effect, if any.
+    pub fn current(&self) -> Option<&OperationVersion> {
+        self.versions.iter().rev().find(|v| !v.superseded)
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, BTreeSet};
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -8,6 +10,7 @@ use crate::domain::ledger::Ledger;
 use crate::domain::posting_line::PostingLine;
 use crate::domain::posting_status::PostingStatus;
 use crate::domain::posting_type::PostingType;
+use crate::ServiceError;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validator)]
@@ -43,3 +46,314 @@ pub struct Posting {
     pub hash_record: HashRecord,
 }
 
+
+impl Posting {
+    /// Checks that debits equal credits per currency, lines without a currency counting as one.
+    /// When the currencies do not balance on their own, e.g. in a conversion, amounts are compared
+    /// in the one FX base currency of the posting: each line not booked in that currency must
+    /// carry FX details into it.
+    pub fn check_balanced(&self) -> Result<(), ServiceError> {
+        let zero = BigDecimal::from(0);
+        let mut imbalances: BTreeMap<Option<&str>, BigDecimal> = BTreeMap::new();
+        for line in &self.lines {
+            *imbalances.entry(line.currency.as_deref()).or_insert_with(|| zero.clone()) +=
+                line.debit_amount.clone() - line.credit_amount.clone();
+        }
+        let (currency, imbalance) = match imbalances.iter().find(|(_, imbalance)| **imbalance != zero) {
+            Some(unbalanced) => unbalanced,
+            None => return Ok(()),
+        };
+        if imbalances.len() == 1 {
+            return Err(ServiceError::DoubledEntryViolation { currency: currency.map(str::to_string), imbalance: imbalance.clone() });
+        }
+
+        let mut bases = self
+            .lines
+            .iter()
+            .filter_map(|l| l.fx.as_ref())
+            .map(|fx| fx.base_currency.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter();
+        let base = match (bases.next(), bases.next()) {
+            (Some(base), None) => base,
+            _ => return Err(ServiceError::CurrencyMismatch),
+        };
+        let mut base_imbalance = zero.clone();
+        for line in &self.lines {
+            let rate = match (&line.fx, line.currency.as_deref()) {
+                (Some(fx), _) if fx.rate > zero => fx.rate.clone(),
+                (None, Some(currency)) if currency == base => BigDecimal::from(1),
+                _ => return Err(ServiceError::CurrencyMismatch),
+            };
+            base_imbalance += (line.debit_amount.clone() - line.credit_amount.clone()) * rate;
+        }
+        if base_imbalance != zero {
+            return Err(ServiceError::DoubledEntryViolation { currency: Some(base.to_string()), imbalance: base_imbalance });
+        }
+        Ok(())
+    }
+
+    /// Posting undoing this one: every line is booked with debit and credit swapped and references
+    /// the original line through `base_line`, and `opr_src` carries the original operation id.
+    /// Ids, record time and hashes are assigned when the compensation is recorded.
+    pub fn compensation(&self, opr_id: [u8; 34], opr_type: [u8; 34], pst_time: DateTime<Utc>) -> Posting {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| PostingLine {
+                id: Uuid::new_v4(),
+                debit_amount: line.credit_amount.clone(),
+                credit_amount: line.debit_amount.clone(),
+                base_line: Some(line.id),
+                record_time: pst_time,
+                opr_id,
+                opr_src: Some(self.opr_id),
+                pst_time,
+                hash: None,
+                discarded_time: None,
+                category: None,
+                ..line.clone()
+            })
+            .collect();
+        Posting {
+            id: Uuid::nil(),
+            record_time: pst_time,
+            opr_id,
+            opr_time: pst_time,
+            opr_type,
+            opr_src: Some(self.opr_id),
+            pst_time,
+            lines,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: HashRecord::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether this submission is a retry of `recorded`, the posting already recorded under the
+    /// same operation id: same ledger, operation type, posting time and every submitted line
+    /// booked the same way. Lines added when recording, such as fees, are not compared.
+    pub fn is_retry_of(&self, recorded: &Posting) -> bool {
+        if self.opr_id != recorded.opr_id
+            || self.ledger.id != recorded.ledger.id
+            || self.opr_type != recorded.opr_type
+            || self.pst_time != recorded.pst_time
+        {
+            return false;
+        }
+        let mut unmatched: Vec<&PostingLine> = recorded.lines.iter().collect();
+        self.lines.iter().all(|line| {
+            let found = unmatched.iter().position(|r| {
+                r.account.id == line.account.id && r.debit_amount == line.debit_amount && r.credit_amount == line.credit_amount
+            });
+            found.map(|i| unmatched.swap_remove(i)).is_some()
+        })
+    }
+
+    /// Storno of this posting at `reversal_time`: a [`Self::compensation`] that also records this
+    /// posting as the one it discards. The reversed posting is marked as discarded by the reversal
+    /// when it is recorded. Lines of both stay booked, so they cancel each other out in balances,
+    /// and no discarded time is set on either.
+    pub fn reversal(&self, opr_id: [u8; 34], opr_type: [u8; 34], reversal_time: DateTime<Utc>) -> Posting {
+        Posting {
+            discarded_id: Some(self.id),
+            ..self.compensation(opr_id, opr_type, reversal_time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::account_category::AccountCategory;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::currency::FxDetails;
+    use crate::domain::ledger_account::LedgerAccount;
+    use std::str::FromStr;
+
+    fn line(debit: i64, credit: i64) -> PostingLine {
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: ledger.coa,
+                balance_side: BalanceSide::Dr,
+                category: AccountCategory::AS,
+                currency: None,
+            },
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: Some([3; 34]),
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [1; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: Some([9; 34]),
+            additional_information: None,
+            discarded_time: None,
+            category: Some("GROCERIES".to_string()),
+            currency: None,
+            fx: None,
+        }
+    }
+
+    fn in_currency(line: PostingLine, currency: &str, fx: Option<(&str, &str)>) -> PostingLine {
+        PostingLine {
+            currency: Some(currency.to_string()),
+            fx: fx.map(|(base_currency, rate)| FxDetails {
+                base_currency: base_currency.to_string(),
+                rate: BigDecimal::from_str(rate).unwrap(),
+            }),
+            ..line
+        }
+    }
+
+    fn posting(lines: Vec<PostingLine>) -> Posting {
+        let now = Utc::now();
+        Posting {
+            id: Uuid::new_v4(),
+            record_user: [0; 34],
+            record_time: now,
+            opr_id: [1; 34],
+            opr_time: now,
+            opr_type: [2; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: now,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } },
+            val_time: None,
+            lines,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: HashRecord { hash: Some([8; 34]), ..HashRecord::default() },
+        }
+    }
+
+    #[test]
+    fn test_compensation_mirrors_lines() {
+        let now = Utc::now();
+        let original = posting(vec![line(10, 0), line(0, 10)]);
+
+        let compensation = original.compensation([5; 34], [6; 34], now);
+        assert_eq!(compensation.opr_src, Some(original.opr_id));
+        assert_eq!(compensation.hash_record, HashRecord::default());
+        for (mirrored, line) in compensation.lines.iter().zip(original.lines.iter()) {
+            assert_eq!(mirrored.debit_amount, line.credit_amount);
+            assert_eq!(mirrored.credit_amount, line.debit_amount);
+            assert_eq!(mirrored.account, line.account);
+            assert_eq!(mirrored.base_line, Some(line.id));
+            assert_eq!(mirrored.opr_id, [5; 34]);
+            assert_eq!(mirrored.details, line.details);
+            assert_eq!(mirrored.hash, None);
+            assert_eq!(mirrored.category, None);
+        }
+    }
+
+    #[test]
+    fn test_reversal_discards_original() {
+        let now = Utc::now();
+        let original = posting(vec![line(10, 0), line(0, 10)]);
+
+        let reversal = original.reversal([5; 34], [6; 34], now);
+        assert_eq!(reversal.discarded_id, Some(original.id));
+        assert_eq!(reversal.discarded_time, None);
+        assert_eq!(reversal.discarding_id, None);
+        assert_eq!(reversal.pst_time, now);
+        assert!(reversal.check_balanced().is_ok());
+        for (mirrored, line) in reversal.lines.iter().zip(original.lines.iter()) {
+            assert_eq!(mirrored.debit_amount, line.credit_amount);
+            assert_eq!(mirrored.credit_amount, line.debit_amount);
+            assert_eq!(mirrored.base_line, Some(line.id));
+        }
+    }
+
+    #[test]
+    fn test_is_retry_of() {
+        let recorded = posting(vec![line(10, 0), line(0, 10)]);
+        let mut retry = recorded.clone();
+        retry.id = Uuid::nil();
+        retry.lines.reverse();
+        assert!(retry.is_retry_of(&recorded));
+
+        let mut with_fee = recorded.clone();
+        with_fee.lines.push(line(1, 0));
+        assert!(retry.is_retry_of(&with_fee));
+
+        let mut other_amount = retry.clone();
+        other_amount.lines[0].debit_amount = BigDecimal::from(11);
+        assert!(!other_amount.is_retry_of(&recorded));
+
+        let mut other_time = retry.clone();
+        other_time.pst_time = recorded.pst_time + chrono::Duration::seconds(1);
+        assert!(!other_time.is_retry_of(&recorded));
+    }
+
+    #[test]
+    fn test_check_balanced_single_currency() {
+        assert!(posting(vec![line(10, 0), line(0, 10)]).check_balanced().is_ok());
+        assert!(posting(vec![in_currency(line(10, 0), "EUR", None), in_currency(line(0, 10), "EUR", None)]).check_balanced().is_ok());
+        assert!(matches!(
+            posting(vec![line(10, 0), line(0, 9)]).check_balanced(),
+            Err(ServiceError::DoubledEntryViolation { currency: None, imbalance }) if imbalance == BigDecimal::from(1)
+        ));
+        assert!(matches!(
+            posting(vec![in_currency(line(10, 0), "EUR", None), in_currency(line(0, 12), "EUR", None)]).check_balanced(),
+            Err(ServiceError::DoubledEntryViolation { currency: Some(currency), imbalance }) if currency == "EUR" && imbalance == BigDecimal::from(-2)
+        ));
+    }
+
+    #[test]
+    fn test_check_balanced_per_currency() {
+        let balanced = posting(vec![
+            in_currency(line(100, 0), "USD", None),
+            in_currency(line(0, 100), "USD", None),
+            in_currency(line(5, 0), "EUR", None),
+            in_currency(line(0, 5), "EUR", None),
+        ]);
+        assert!(balanced.check_balanced().is_ok());
+    }
+
+    #[test]
+    fn test_check_balanced_converts_into_base_currency() {
+        let balanced = posting(vec![
+            in_currency(line(100, 0), "USD", Some(("EUR", "0.92"))),
+            in_currency(line(0, 92), "EUR", None),
+        ]);
+        assert!(balanced.check_balanced().is_ok());
+
+        let unbalanced = posting(vec![
+            in_currency(line(100, 0), "USD", Some(("EUR", "0.92"))),
+            in_currency(line(0, 100), "EUR", None),
+        ]);
+        assert!(matches!(
+            unbalanced.check_balanced(),
+            Err(ServiceError::DoubledEntryViolation { currency: Some(currency), imbalance }) if currency == "EUR" && imbalance == BigDecimal::from(-8)
+        ));
+    }
+
+    #[test]
+    fn test_check_balanced_rejects_mixed_currencies_without_fx() {
+        let without_fx = posting(vec![in_currency(line(100, 0), "USD", None), in_currency(line(0, 100), "EUR", None)]);
+        assert!(matches!(without_fx.check_balanced(), Err(ServiceError::CurrencyMismatch)));
+
+        let two_bases = posting(vec![
+            in_currency(line(100, 0), "USD", Some(("EUR", "0.92"))),
+            in_currency(line(0, 92), "EUR", Some(("CHF", "1"))),
+        ]);
+        assert!(matches!(two_bases.check_balanced(), Err(ServiceError::CurrencyMismatch)));
+    }
+}
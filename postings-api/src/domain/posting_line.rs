@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use type_rules::prelude::*;
 use uuid::Uuid;
+use crate::domain::currency::FxDetails;
 use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::posting_status::PostingStatus;
 use crate::domain::posting_type::PostingType;
@@ -35,4 +36,14 @@ pub struct PostingLine {
     #[rule(Opt(MaxLength(1024)))]
     pub additional_information: Option<String>,
     pub discarded_time: Option<DateTime<Utc>>,
+    /// ISO 4217 code of the amounts. Defaults to the account's currency when the posting is recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Required when the lines of a posting are in different currencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fx: Option<FxDetails>,
+    /// Label assigned by the ledger's categorization rules when the line is read. Not persisted
+    /// and never part of the posting hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
 }
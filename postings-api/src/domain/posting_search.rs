@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::posting_status::PostingStatus;
+
+/// Criteria of a posting search. Unset criteria match every posting, set ones must all match.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostingSearchFilter {
+    pub ledger_id: Option<Uuid>,
+    /// Postings with a line on the account.
+    pub account_id: Option<Uuid>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_id: Option<[u8; 34]>,
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub opr_type: Option<[u8; 34]>,
+    pub pst_status: Option<PostingStatus>,
+    /// Inclusive lower bound of the posting time.
+    pub pst_time_from: Option<DateTime<Utc>>,
+    /// Inclusive upper bound of the posting time.
+    pub pst_time_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub sort: PostingSort,
+    pub page: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PostingSort {
+    #[default]
+    RecordTimeAsc,
+    RecordTimeDesc,
+    PstTimeAsc,
+    PstTimeDesc,
+}
+
+impl PostingSearchFilter {
+    /// A filter matching every posting, returning the given page.
+    pub fn new(page: usize, size: usize) -> Self {
+        Self {
+            ledger_id: None,
+            account_id: None,
+            opr_id: None,
+            opr_type: None,
+            pst_status: None,
+            pst_time_from: None,
+            pst_time_to: None,
+            sort: PostingSort::default(),
+            page,
+            size,
+        }
+    }
+}
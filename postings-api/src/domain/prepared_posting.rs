@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::posting::Posting;
+
+/// Posting validated in the first phase of an externally coordinated transaction. Nothing is
+/// written to the ledger until the coordinator commits; unresolved postings are aborted once `expires` is reached.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PreparedPosting {
+    pub id: Uuid,
+    /// Posting as validated on prepare, fee lines included. Id, record time and hash are assigned on commit.
+    pub posting: Posting,
+    pub status: PreparedPostingStatus,
+    /// Reference of the saga or transaction in the coordinating system.
+    pub coordinator_ref: String,
+    pub expires: DateTime<Utc>,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+    /// Id of the posting recorded on commit.
+    pub posting_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PreparedPostingStatus {
+    Prepared,
+    Committed,
+    Aborted,
+}
+
+impl PreparedPosting {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == PreparedPostingStatus::Prepared && now >= self.expires
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::Utc;
+use crate::domain::api_key::{ApiKey, ApiRole};
+use crate::ServiceError;
+
+/// Caller context required for operations that bypass regular controls, e.g. account limit overrides.
+/// It is built from the caller's authenticated API key, so principals and roles cannot be claimed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrivilegedContext {
+    principal: String,
+    reason: String,
+    roles: Vec<ApiRole>,
+}
+
+impl PrivilegedContext {
+    /// Context of the holder of `key`, an authenticated key. Fails with `Forbidden` for revoked or
+    /// expired keys.
+    pub fn new(key: &ApiKey, reason: impl Into<String>) -> Result<Self, ServiceError> {
+        if !key.is_active(Utc::now()) {
+            return Err(ServiceError::Forbidden);
+        }
+        Ok(Self { principal: key.name.clone(), reason: reason.into(), roles: key.roles.clone() })
+    }
+
+    /// Name of the service account holding the key.
+    pub fn principal(&self) -> &str {
+        &self.principal
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Roles granted by the key.
+    pub fn roles(&self) -> &[ApiRole] {
+        &self.roles
+    }
+
+    /// Fails with `NotEnoughInfo` without a principal or reason to log, and with `Forbidden` unless
+    /// the principal holds the `Admin` role.
+    pub fn authorize(&self) -> Result<(), ServiceError> {
+        if self.principal.trim().is_empty() || self.reason.trim().is_empty() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        if !self.roles.contains(&ApiRole::Admin) {
+            return Err(ServiceError::Forbidden);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn key(name: &str, roles: Vec<ApiRole>) -> ApiKey {
+        ApiKey {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            tenant_id: Uuid::nil(),
+            ledger_id: None,
+            roles,
+            key_prefix: "abcd1234".to_string(),
+            key_hash: [0; 34],
+            created: Utc::now(),
+            expires: None,
+            revoked_time: None,
+        }
+    }
+
+    fn context(principal: &str, reason: &str, roles: Vec<ApiRole>) -> PrivilegedContext {
+        PrivilegedContext::new(&key(principal, roles), reason).unwrap()
+    }
+
+    #[test]
+    fn test_authorize() {
+        assert!(context("ops", "Margin call", vec![ApiRole::Admin]).authorize().is_ok());
+        assert!(matches!(context("ops", "Margin call", vec![ApiRole::Post]).authorize(), Err(ServiceError::Forbidden)));
+        assert!(matches!(context(" ", "Margin call", vec![ApiRole::Admin]).authorize(), Err(ServiceError::NotEnoughInfo)));
+        assert!(matches!(context("ops", "", vec![ApiRole::Admin]).authorize(), Err(ServiceError::NotEnoughInfo)));
+    }
+
+    #[test]
+    fn test_inactive_keys_get_no_context() {
+        let mut revoked = key("ops", vec![ApiRole::Admin]);
+        revoked.revoked_time = Some(Utc::now());
+        assert!(matches!(PrivilegedContext::new(&revoked, "Margin call"), Err(ServiceError::Forbidden)));
+    }
+}
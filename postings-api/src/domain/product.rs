@@ -0,0 +1,38 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::day_count_convention::DayCountConvention;
+use crate::domain::ledger::Ledger;
+
+/// Account product carrying the interest terms of its accounts. Fee schedules saved with the
+/// product's id override the ledger-wide schedules for accounts linked to the product.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Product {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub name: String,
+    /// Annual rate expressed as a fraction, e.g. 0.025 for 2.5%.
+    pub interest_rate: Option<BigDecimal>,
+    pub day_count_convention: Option<DayCountConvention>,
+    pub created: DateTime<Utc>,
+}
+
+/// Interest parameters resolved for an account.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterestTerms {
+    pub product_id: Uuid,
+    pub rate: BigDecimal,
+    pub day_count_convention: DayCountConvention,
+}
+
+impl Product {
+    /// Interest terms of the product, if both rate and convention are configured.
+    pub fn interest_terms(&self) -> Option<InterestTerms> {
+        Some(InterestTerms {
+            product_id: self.id,
+            rate: self.interest_rate.clone()?,
+            day_count_convention: self.day_count_convention.clone()?,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+
+/// Import record that failed validation in lenient mode, kept for correction and resubmission.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuarantinedEntry {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// Kind of import that produced the entry, e.g. `OPENING_BALANCE`.
+    pub import_type: String,
+    /// 1-based line of the record in the original import.
+    pub line_number: i64,
+    /// Raw record as received.
+    pub payload: String,
+    pub reason: String,
+    pub status: QuarantineStatus,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum QuarantineStatus {
+    Pending,
+    Resubmitted,
+    Discarded,
+}
+
+/// How an import reacts to invalid records.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ImportMode {
+    /// The first invalid record aborts the import.
+    Strict,
+    /// Invalid records are quarantined and the rest of the import proceeds.
+    Lenient,
+}
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::posting_line::PostingLine;
+use crate::domain::trial_balance::TrialBalance;
+
+/// Generation of a report at the end of every period, e.g. the trial balance at month end.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportSchedule {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub frequency: ReportFrequency,
+    /// End of the next period to report on. The report is generated once this time has passed.
+    pub next_ref_time: DateTime<Utc>,
+    pub status: ReportScheduleStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReportType {
+    TrialBalance,
+    /// Lines posted on the ledger during the day ending at the reference time.
+    DailyJournal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    Pdf,
+}
+
+/// Periods are days and months in UTC.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReportFrequency {
+    Daily,
+    MonthEnd,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReportScheduleStatus {
+    Active,
+    Cancelled,
+}
+
+/// One stored version of a generated report. The content is kept in the object store under
+/// `object_key`; a report generated again for the same reference time gets the next version.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportArtifact {
+    pub id: Uuid,
+    /// Schedule that generated the report, `None` for reports generated on request.
+    pub schedule_id: Option<Uuid>,
+    pub ledger_id: Uuid,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub ref_time: DateTime<Utc>,
+    pub version: i32,
+    pub object_key: String,
+    /// Multihash of the stored content.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub checksum: [u8; 34],
+    pub size: i64,
+    pub created: DateTime<Utc>,
+}
+
+/// Lines posted on a ledger in the period `from` (exclusive) to `to` (inclusive), by posting time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyJournal {
+    pub ledger: Ledger,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub lines: Vec<PostingLine>,
+}
+
+/// A computed report, ready to be rendered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum Report {
+    TrialBalance(TrialBalance),
+    DailyJournal(DailyJournal),
+}
+
+impl ReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Csv => "csv",
+            ReportFormat::Pdf => "pdf",
+        }
+    }
+
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "application/json",
+            ReportFormat::Csv => "text/csv",
+            ReportFormat::Pdf => "application/pdf",
+        }
+    }
+}
+
+impl ReportFrequency {
+    /// Last instant of the period containing `time`. Reports include postings up to and
+    /// including their reference time, so the period ends a microsecond before the next begins.
+    pub fn period_end(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let date = time.date_naive();
+        let next_start = match self {
+            ReportFrequency::Daily => date + Days::new(1),
+            ReportFrequency::MonthEnd => date.with_day(1).expect("first day of the month") + Months::new(1),
+        };
+        next_start.and_time(NaiveTime::MIN).and_utc() - Duration::microseconds(1)
+    }
+
+    /// Reference time of the period following the one ending at `ref_time`.
+    pub fn next_ref_time(&self, ref_time: DateTime<Utc>) -> DateTime<Utc> {
+        self.period_end(ref_time + Duration::microseconds(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_period_ends_do_not_drift() {
+        let mid_january = Utc.with_ymd_and_hms(2025, 1, 15, 10, 30, 0).unwrap();
+        let end_of_january = ReportFrequency::MonthEnd.period_end(mid_january);
+        assert_eq!(end_of_january, Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap() - Duration::microseconds(1));
+        let end_of_february = ReportFrequency::MonthEnd.next_ref_time(end_of_january);
+        assert_eq!(end_of_february, Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap() - Duration::microseconds(1));
+        assert_eq!(
+            ReportFrequency::MonthEnd.next_ref_time(Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap() + Duration::microseconds(999_999)),
+            end_of_january,
+        );
+
+        let end_of_day = ReportFrequency::Daily.period_end(mid_january);
+        assert_eq!(end_of_day, Utc.with_ymd_and_hms(2025, 1, 16, 0, 0, 0).unwrap() - Duration::microseconds(1));
+        assert_eq!(ReportFrequency::Daily.next_ref_time(end_of_day), end_of_day + Duration::days(1));
+        // A period end is its own period's end
+        assert_eq!(ReportFrequency::Daily.period_end(end_of_day), end_of_day);
+    }
+}
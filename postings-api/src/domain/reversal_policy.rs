@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::api_key::{ApiKey, ApiRole};
+use crate::domain::ledger::Ledger;
+use crate::domain::posting::Posting;
+use crate::ServiceError;
+
+/// Who may reverse a ledger's postings, depending on how many calendar days passed since the
+/// posting time. Ledgers without a policy use [`ReversalPolicy::same_day`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReversalPolicy {
+    pub ledger: Ledger,
+    /// Days after the posting date during which tellers may reverse; 0 for same-day reversals only.
+    pub teller_window_days: u32,
+    /// Days after the posting date during which supervisors may still correct; `None` for no limit.
+    pub supervisor_window_days: Option<u32>,
+    pub updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ReversalRole {
+    Teller,
+    Supervisor,
+}
+
+impl ReversalRole {
+    /// Role granted by an API key: `Admin` acts as supervisor, `Post` as teller.
+    pub fn for_key(key: &ApiKey) -> Option<Self> {
+        if key.roles.contains(&ApiRole::Admin) {
+            Some(ReversalRole::Supervisor)
+        } else if key.roles.contains(&ApiRole::Post) {
+            Some(ReversalRole::Teller)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReversalMode {
+    /// Storno linked to the reversed posting, which is marked as discarded.
+    Storno,
+    /// Compensating posting at the reversal time; the reversed posting stays current.
+    Correction,
+}
+
+/// Caller of a reversal, logged with the resulting posting. Its role is the one granted by the
+/// caller's authenticated API key, so callers cannot claim a role of their own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReversalContext {
+    key: ApiKey,
+    role: ReversalRole,
+    reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Reversal {
+    pub mode: ReversalMode,
+    pub reversed_id: Uuid,
+    pub posting: Posting,
+}
+
+impl ReversalContext {
+    /// Context of the holder of `key`, an authenticated key. Fails with `Forbidden` for inactive
+    /// keys and keys that grant no reversal role.
+    pub fn new(key: &ApiKey, reason: impl Into<String>) -> Result<Self, ServiceError> {
+        if !key.is_active(Utc::now()) {
+            return Err(ServiceError::Forbidden);
+        }
+        let role = ReversalRole::for_key(key).ok_or(ServiceError::Forbidden)?;
+        Ok(Self { key: key.clone(), role, reason: reason.into() })
+    }
+
+    pub fn principal(&self) -> &str {
+        &self.key.name
+    }
+
+    pub fn role(&self) -> ReversalRole {
+        self.role
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Key the caller authenticated with.
+    pub fn key(&self) -> &ApiKey {
+        &self.key
+    }
+}
+
+impl ReversalPolicy {
+    pub fn same_day(ledger: Ledger) -> Self {
+        Self { ledger, teller_window_days: 0, supervisor_window_days: None, updated: Utc::now() }
+    }
+
+    /// How a posting made at `pst_time` may be reversed at `reversal_time` by `role`. Within the
+    /// teller window any role records a storno; after it only supervisors may, and they record a
+    /// correction instead.
+    pub fn authorize(&self, role: ReversalRole, pst_time: DateTime<Utc>, reversal_time: DateTime<Utc>) -> Result<ReversalMode, ServiceError> {
+        let age_days = (reversal_time.date_naive() - pst_time.date_naive()).num_days();
+        if age_days < 0 {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        if age_days <= i64::from(self.teller_window_days) {
+            return Ok(ReversalMode::Storno);
+        }
+        if role != ReversalRole::Supervisor {
+            return Err(ServiceError::Forbidden);
+        }
+        if self.supervisor_window_days.is_some_and(|days| age_days > i64::from(days)) {
+            return Err(ServiceError::ReversalWindowExpired);
+        }
+        Ok(ReversalMode::Correction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn policy(teller_window_days: u32, supervisor_window_days: Option<u32>) -> ReversalPolicy {
+        ReversalPolicy { teller_window_days, supervisor_window_days, ..ReversalPolicy::same_day(Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } }) }
+    }
+
+    #[test]
+    fn test_same_day_reversal_is_a_storno() {
+        let pst_time = Utc.with_ymd_and_hms(2025, 8, 30, 9, 0, 0).unwrap();
+        let mode = policy(0, None).authorize(ReversalRole::Teller, pst_time, pst_time + Duration::hours(10));
+        assert_eq!(mode.unwrap(), ReversalMode::Storno);
+    }
+
+    #[test]
+    fn test_older_reversal_requires_supervisor() {
+        let pst_time = Utc.with_ymd_and_hms(2025, 8, 30, 23, 0, 0).unwrap();
+        let reversal_time = pst_time + Duration::hours(2);
+        let policy = policy(0, Some(30));
+        assert!(matches!(policy.authorize(ReversalRole::Teller, pst_time, reversal_time), Err(ServiceError::Forbidden)));
+        assert_eq!(policy.authorize(ReversalRole::Supervisor, pst_time, reversal_time).unwrap(), ReversalMode::Correction);
+    }
+
+    #[test]
+    fn test_supervisor_window_expires() {
+        let pst_time = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+        let policy = policy(1, Some(30));
+        assert_eq!(policy.authorize(ReversalRole::Supervisor, pst_time, pst_time + Duration::days(30)).unwrap(), ReversalMode::Correction);
+        assert!(matches!(policy.authorize(ReversalRole::Supervisor, pst_time, pst_time + Duration::days(31)), Err(ServiceError::ReversalWindowExpired)));
+    }
+
+    #[test]
+    fn test_reversal_before_posting_is_rejected() {
+        let pst_time = Utc.with_ymd_and_hms(2025, 8, 30, 12, 0, 0).unwrap();
+        assert!(matches!(policy(0, None).authorize(ReversalRole::Supervisor, pst_time, pst_time - Duration::days(1)), Err(ServiceError::NotEnoughInfo)));
+    }
+
+    #[test]
+    fn test_context_role_comes_from_the_key() {
+        let mut key = ApiKey {
+            id: Uuid::new_v4(),
+            name: "teller-desk".to_string(),
+            tenant_id: Uuid::nil(),
+            ledger_id: None,
+            roles: vec![ApiRole::Post],
+            key_prefix: "abcd1234".to_string(),
+            key_hash: [0; 34],
+            created: Utc::now(),
+            expires: None,
+            revoked_time: None,
+        };
+        let context = ReversalContext::new(&key, "Duplicate").unwrap();
+        assert_eq!(context.role(), ReversalRole::Teller);
+        assert_eq!(context.principal(), "teller-desk");
+
+        key.roles = vec![ApiRole::Read];
+        assert!(matches!(ReversalContext::new(&key, "Duplicate"), Err(ServiceError::Forbidden)));
+        key.roles = vec![ApiRole::Admin];
+        key.revoked_time = Some(Utc::now());
+        assert!(matches!(ReversalContext::new(&key, "Duplicate"), Err(ServiceError::Forbidden)));
+    }
+}
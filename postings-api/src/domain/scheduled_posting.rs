@@ -0,0 +1,137 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::business_calendar::BusinessCalendar;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_type::PostingType;
+use crate::domain::standing_order::Frequency;
+
+/// Template of a posting the scheduler books once at a future posting time or recurrently, e.g.
+/// a monthly interest accrual or the release of a deferred income over a number of periods.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledPosting {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// 32-byte hash of the operation type used for the generated postings
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    pub pst_type: PostingType,
+    pub lines: Vec<ScheduledLine>,
+    /// Recurrence of the posting, a single future-dated posting when unset.
+    pub frequency: Option<Frequency>,
+    /// Posting time of the first occurrence. Later occurrences keep its time of day.
+    pub start_time: DateTime<Utc>,
+    /// Latest posting time an occurrence may have.
+    pub end_time: Option<DateTime<Utc>>,
+    /// Number of occurrences after which the schedule completes, e.g. the periods of a deferral.
+    pub max_occurrences: Option<i32>,
+    pub next_pst_time: DateTime<Utc>,
+    /// Occurrences already booked.
+    pub occurrence: i32,
+    pub status: ScheduledPostingStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledLine {
+    pub account: LedgerAccount,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ScheduledPostingStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+impl ScheduledPosting {
+    /// Posting time of the `n`-th occurrence, counted from the start so month-end clamping does not drift.
+    pub fn nth_pst_time(&self, n: u32) -> DateTime<Utc> {
+        match &self.frequency {
+            Some(frequency) => frequency.nth_date(self.start_time.date_naive(), n).and_time(self.start_time.time()).and_utc(),
+            None => self.start_time,
+        }
+    }
+
+    /// Posting time the `n`-th occurrence is booked at: its nominal time, moved to the same time of
+    /// the following business day when the ledger has a calendar.
+    pub fn nth_booking_time(&self, n: u32, calendar: Option<&BusinessCalendar>) -> DateTime<Utc> {
+        let pst_time = self.nth_pst_time(n);
+        match calendar {
+            Some(calendar) => calendar.adjust_following(pst_time.date_naive()).and_time(pst_time.time()).and_utc(),
+            None => pst_time,
+        }
+    }
+
+    /// Moves the schedule to its next occurrence after one was booked, and completes it when an
+    /// end condition is reached. The end time bounds the nominal times, so an occurrence moved past
+    /// it is still booked.
+    pub fn advance(&mut self, calendar: Option<&BusinessCalendar>) {
+        self.occurrence += 1;
+        self.next_pst_time = self.nth_booking_time(self.occurrence as u32, calendar);
+        let past_end = self.end_time.is_some_and(|end| self.nth_pst_time(self.occurrence as u32) > end);
+        let exhausted = self.max_occurrences.is_some_and(|max| self.occurrence >= max);
+        if self.frequency.is_none() || past_end || exhausted {
+            self.status = ScheduledPostingStatus::Completed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+    use crate::domain::chart_of_account::ChartOfAccount;
+
+    fn schedule(frequency: Option<Frequency>, max_occurrences: Option<i32>) -> ScheduledPosting {
+        ScheduledPosting {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } },
+            opr_type: [0; 34],
+            pst_type: PostingType::BusiTx,
+            lines: Vec::new(),
+            frequency,
+            start_time: Utc.with_ymd_and_hms(2025, 1, 31, 23, 0, 0).unwrap(),
+            end_time: None,
+            max_occurrences,
+            next_pst_time: Utc.with_ymd_and_hms(2025, 1, 31, 23, 0, 0).unwrap(),
+            occurrence: 0,
+            status: ScheduledPostingStatus::Active,
+            created: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_recurring_schedule_completes_after_max_occurrences() {
+        let mut deferral = schedule(Some(Frequency::Monthly), Some(3));
+        deferral.advance(None);
+        assert_eq!(deferral.next_pst_time, Utc.with_ymd_and_hms(2025, 2, 28, 23, 0, 0).unwrap());
+        deferral.advance(None);
+        assert_eq!(deferral.next_pst_time, Utc.with_ymd_and_hms(2025, 3, 31, 23, 0, 0).unwrap());
+        assert_eq!(deferral.status, ScheduledPostingStatus::Active);
+        deferral.advance(None);
+        assert_eq!(deferral.status, ScheduledPostingStatus::Completed);
+
+        let mut future_dated = schedule(None, None);
+        future_dated.advance(None);
+        assert_eq!(future_dated.status, ScheduledPostingStatus::Completed);
+    }
+
+    #[test]
+    fn test_occurrences_on_non_business_days_are_booked_on_the_following_one() {
+        // 2025-02-28 is a holiday, 2025-03-31 a Monday
+        let calendar = BusinessCalendar::new([NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()]);
+        let mut deferral = schedule(Some(Frequency::Monthly), None);
+        deferral.advance(Some(&calendar));
+        assert_eq!(deferral.next_pst_time, Utc.with_ymd_and_hms(2025, 3, 3, 23, 0, 0).unwrap());
+        deferral.advance(Some(&calendar));
+        assert_eq!(deferral.next_pst_time, Utc.with_ymd_and_hms(2025, 3, 31, 23, 0, 0).unwrap());
+    }
+}
@@ -0,0 +1,85 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::batch_status::BatchStatus;
+use crate::domain::ledger::Ledger;
+
+/// Groups postings that are settled together (e.g. a card scheme settlement file).
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettlementBatch {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    /// 32-byte hash of the external batch reference
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub batch_ref: [u8; 34],
+    /// Amount the sum of the assigned postings must match before the batch can be closed.
+    pub control_amount: BigDecimal,
+    /// Optional number of postings expected in the batch.
+    pub control_count: Option<i32>,
+    pub status: BatchStatus,
+    pub created: DateTime<Utc>,
+    pub closed_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettlementBatchTotals {
+    pub batch_id: Uuid,
+    pub posting_count: i64,
+    pub total_amount: BigDecimal,
+}
+
+impl SettlementBatch {
+    pub fn matches_control(&self, totals: &SettlementBatchTotals) -> bool {
+        let count_ok = match self.control_count {
+            Some(count) => i64::from(count) == totals.posting_count,
+            None => true,
+        };
+        count_ok && self.control_amount == totals.total_amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use std::str::FromStr;
+
+    fn create_test_batch(control_amount: &str, control_count: Option<i32>) -> SettlementBatch {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        SettlementBatch {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa },
+            batch_ref: [0; 34],
+            control_amount: BigDecimal::from_str(control_amount).unwrap(),
+            control_count,
+            status: BatchStatus::Open,
+            created: Utc::now(),
+            closed_time: None,
+        }
+    }
+
+    fn totals(batch: &SettlementBatch, count: i64, amount: &str) -> SettlementBatchTotals {
+        SettlementBatchTotals {
+            batch_id: batch.id,
+            posting_count: count,
+            total_amount: BigDecimal::from_str(amount).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_matches_control_amount_only() {
+        let batch = create_test_batch("150.00", None);
+        assert!(batch.matches_control(&totals(&batch, 3, "150.00")));
+        assert!(!batch.matches_control(&totals(&batch, 3, "149.99")));
+    }
+
+    #[test]
+    fn test_matches_control_amount_and_count() {
+        let batch = create_test_batch("150.00", Some(2));
+        assert!(batch.matches_control(&totals(&batch, 2, "150.00")));
+        assert!(!batch.matches_control(&totals(&batch, 3, "150.00")));
+    }
+}
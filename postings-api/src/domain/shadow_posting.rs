@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::posting::Posting;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ShadowDifference {
+    /// The shadow backend rejected the posting that the primary accepted.
+    ShadowFailed(String),
+    Status,
+    PostingType,
+    LineCount,
+    /// Debit or credit totals differ for at least one account.
+    AccountTotals,
+}
+
+/// Result of comparing a primary posting with its shadow copy.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShadowComparison {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_id: [u8; 34],
+    pub primary_posting_id: Uuid,
+    pub shadow_posting_id: Option<Uuid>,
+    pub differences: Vec<ShadowDifference>,
+}
+
+impl ShadowComparison {
+    pub fn compare(primary: &Posting, shadow: Result<&Posting, String>) -> Self {
+        let shadow = match shadow {
+            Ok(shadow) => shadow,
+            Err(reason) => {
+                return ShadowComparison {
+                    opr_id: primary.opr_id,
+                    primary_posting_id: primary.id,
+                    shadow_posting_id: None,
+                    differences: vec![ShadowDifference::ShadowFailed(reason)],
+                }
+            }
+        };
+
+        let mut differences = Vec::new();
+        if primary.pst_status != shadow.pst_status {
+            differences.push(ShadowDifference::Status);
+        }
+        if primary.pst_type != shadow.pst_type {
+            differences.push(ShadowDifference::PostingType);
+        }
+        if primary.lines.len() != shadow.lines.len() {
+            differences.push(ShadowDifference::LineCount);
+        }
+        if account_totals(primary) != account_totals(shadow) {
+            differences.push(ShadowDifference::AccountTotals);
+        }
+
+        ShadowComparison {
+            opr_id: primary.opr_id,
+            primary_posting_id: primary.id,
+            shadow_posting_id: Some(shadow.id),
+            differences,
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+fn account_totals(posting: &Posting) -> BTreeMap<Uuid, (BigDecimal, BigDecimal)> {
+    let mut totals: BTreeMap<Uuid, (BigDecimal, BigDecimal)> = BTreeMap::new();
+    for line in posting.lines.iter() {
+        let entry = totals.entry(line.account.id).or_insert((BigDecimal::from(0), BigDecimal::from(0)));
+        entry.0 += line.debit_amount.clone();
+        entry.1 += line.credit_amount.clone();
+    }
+    totals
+}
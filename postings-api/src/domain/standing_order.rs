@@ -0,0 +1,234 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Days, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+use crate::domain::business_calendar::BusinessCalendar;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Recurring transfer of a fixed amount from a source to a target account.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StandingOrder {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub source_account: LedgerAccount,
+    pub target_account: LedgerAccount,
+    pub amount: BigDecimal,
+    /// 32-byte hash of the operation type used for the generated postings
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    pub frequency: Frequency,
+    pub start_date: NaiveDate,
+    /// Last date on which the order may execute.
+    pub end_date: Option<NaiveDate>,
+    /// Number of successful executions after which the order completes.
+    pub max_executions: Option<i32>,
+    /// Failed attempts tolerated per occurrence before the occurrence is skipped.
+    pub max_retries: i32,
+    pub next_execution_date: NaiveDate,
+    /// Occurrences already passed, executed or skipped.
+    pub occurrence: i32,
+    pub execution_count: i32,
+    pub status: StandingOrderStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StandingOrderStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+/// Outcome of one attempt to execute a standing order occurrence.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StandingOrderExecution {
+    pub id: Uuid,
+    pub order_id: Uuid,
+    pub execution_date: NaiveDate,
+    pub attempt: i32,
+    pub status: ExecutionStatus,
+    pub posting_id: Option<Uuid>,
+    pub message: Option<String>,
+    pub executed_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExecutionStatus {
+    Executed,
+    Failed,
+    Skipped,
+}
+
+impl Frequency {
+    /// Date of the `n`-th occurrence counted from `start`. Month based frequencies are computed
+    /// from the start date so that month-end clamping does not drift.
+    pub fn nth_date(&self, start: NaiveDate, n: u32) -> NaiveDate {
+        match self {
+            Frequency::Daily => start + Days::new(n as u64),
+            Frequency::Weekly => start + Days::new(7 * n as u64),
+            Frequency::Monthly => start + Months::new(n),
+            Frequency::Quarterly => start + Months::new(3 * n),
+            Frequency::Yearly => start + Months::new(12 * n),
+        }
+    }
+}
+
+impl StandingOrder {
+    /// Execution date of the `n`-th occurrence: its nominal date, moved to the following business
+    /// day when the ledger has a calendar.
+    pub fn nth_execution_date(&self, n: u32, calendar: Option<&BusinessCalendar>) -> NaiveDate {
+        let date = self.frequency.nth_date(self.start_date, n);
+        calendar.map_or(date, |calendar| calendar.adjust_following(date))
+    }
+
+    /// Moves the order to its next occurrence and completes it when an end condition is reached.
+    /// Occurrences moved onto the date just passed are merged into it, so a daily order executes
+    /// once per business day. The end date bounds the nominal dates, so an occurrence moved past
+    /// it still executes.
+    pub fn advance(&mut self, calendar: Option<&BusinessCalendar>) {
+        let passed = self.next_execution_date;
+        while self.next_execution_date <= passed {
+            self.occurrence += 1;
+            self.next_execution_date = self.nth_execution_date(self.occurrence as u32, calendar);
+        }
+        let nominal_date = self.frequency.nth_date(self.start_date, self.occurrence as u32);
+        let past_end = self.end_date.is_some_and(|end| nominal_date > end);
+        let exhausted = self.max_executions.is_some_and(|max| self.execution_count >= max);
+        if past_end || exhausted {
+            self.status = StandingOrderStatus::Completed;
+        }
+    }
+
+    /// Execution dates still to come up to and including `until`, assuming every occurrence executes.
+    pub fn upcoming_dates(&self, until: NaiveDate, calendar: Option<&BusinessCalendar>) -> Vec<NaiveDate> {
+        let mut order = self.clone();
+        let mut dates = Vec::new();
+        while order.status == StandingOrderStatus::Active && order.next_execution_date <= until {
+            dates.push(order.next_execution_date);
+            order.execution_count += 1;
+            order.advance(calendar);
+        }
+        dates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_nth_date_does_not_drift_at_month_end() {
+        let start = date(2025, 1, 31);
+        assert_eq!(Frequency::Monthly.nth_date(start, 1), date(2025, 2, 28));
+        assert_eq!(Frequency::Monthly.nth_date(start, 2), date(2025, 3, 31));
+        assert_eq!(Frequency::Quarterly.nth_date(start, 1), date(2025, 4, 30));
+        assert_eq!(Frequency::Weekly.nth_date(start, 2), date(2025, 2, 14));
+        assert_eq!(Frequency::Yearly.nth_date(date(2024, 2, 29), 1), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_upcoming_dates_respect_end_conditions() {
+        use crate::domain::account_category::AccountCategory;
+        use crate::domain::balance_side::BalanceSide;
+        use crate::domain::chart_of_account::ChartOfAccount;
+
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            currency: None,
+        };
+        let mut order = StandingOrder {
+            id: Uuid::new_v4(),
+            ledger,
+            source_account: account.clone(),
+            target_account: account,
+            amount: BigDecimal::from(10),
+            opr_type: [0; 34],
+            frequency: Frequency::Weekly,
+            start_date: date(2025, 1, 1),
+            end_date: None,
+            max_executions: Some(3),
+            max_retries: 0,
+            next_execution_date: date(2025, 1, 8),
+            occurrence: 1,
+            execution_count: 1,
+            status: StandingOrderStatus::Active,
+            created: Utc::now(),
+        };
+        assert_eq!(order.upcoming_dates(date(2025, 3, 1), None), vec![date(2025, 1, 8), date(2025, 1, 15)]);
+        assert_eq!(order.upcoming_dates(date(2025, 1, 10), None), vec![date(2025, 1, 8)]);
+
+        order.max_executions = None;
+        order.end_date = Some(date(2025, 1, 22));
+        assert_eq!(order.upcoming_dates(date(2025, 3, 1), None), vec![date(2025, 1, 8), date(2025, 1, 15), date(2025, 1, 22)]);
+    }
+
+    #[test]
+    fn test_occurrences_move_to_the_following_business_day() {
+        use crate::domain::account_category::AccountCategory;
+        use crate::domain::balance_side::BalanceSide;
+        use crate::domain::chart_of_account::ChartOfAccount;
+
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            currency: None,
+        };
+        // Monthly on the 18th: 2025-04-18 is a holiday, 2025-05-18 a Sunday
+        let calendar = BusinessCalendar::new([date(2025, 4, 18), date(2025, 4, 21)]);
+        let order = StandingOrder {
+            id: Uuid::new_v4(),
+            ledger,
+            source_account: account.clone(),
+            target_account: account,
+            amount: BigDecimal::from(10),
+            opr_type: [0; 34],
+            frequency: Frequency::Monthly,
+            start_date: date(2025, 3, 18),
+            end_date: Some(date(2025, 5, 18)),
+            max_executions: None,
+            max_retries: 0,
+            next_execution_date: date(2025, 3, 18),
+            occurrence: 0,
+            execution_count: 0,
+            status: StandingOrderStatus::Active,
+            created: Utc::now(),
+        };
+        assert_eq!(
+            order.upcoming_dates(date(2025, 12, 31), Some(&calendar)),
+            vec![date(2025, 3, 18), date(2025, 4, 22), date(2025, 5, 19)]
+        );
+        assert_eq!(
+            order.upcoming_dates(date(2025, 12, 31), None),
+            vec![date(2025, 3, 18), date(2025, 4, 18), date(2025, 5, 18)]
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Reviewer note on an account statement, for audit workflows. Notes can be added before and
+/// after the statement is closed and never alter the statement itself.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtAnnotation {
+    pub id: Uuid,
+    pub stmt_id: Uuid,
+    pub author: String,
+    pub text: String,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+/// Manifest of a statement document rendered for delivery. Recipients verify a delivered file
+/// by recomputing its checksum and checking the detached signature against `key_id`.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtDelivery {
+    pub id: Uuid,
+    pub stmt_id: Uuid,
+    pub format: StmtDocumentFormat,
+    /// Multihash of the delivered document bytes.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub checksum: [u8; 34],
+    /// Detached signature over the document bytes, if signing was requested.
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    pub signature: Option<Vec<u8>>,
+    /// Identifies the key that produced `signature`.
+    pub key_id: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StmtDocumentFormat {
+    Csv,
+    Json,
+    Pdf,
+}
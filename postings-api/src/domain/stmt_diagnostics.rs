@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Statement generation figures of one account over a time window.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountStmtStats {
+    pub account_id: Uuid,
+    /// Number of statements generated in the window.
+    pub generations: u64,
+    pub total_duration_ms: u64,
+    pub max_duration_ms: u64,
+    /// Largest number of lines read since the previous closed statement. A high count points to
+    /// an account whose statements should be closed, or snapshotted, more often.
+    pub max_lines: u64,
+}
+
+impl AccountStmtStats {
+    pub fn avg_duration_ms(&self) -> u64 {
+        self.total_duration_ms.checked_div(self.generations).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avg_duration() {
+        let stats = AccountStmtStats { account_id: Uuid::nil(), generations: 4, total_duration_ms: 100, max_duration_ms: 70, max_lines: 12 };
+        assert_eq!(stats.avg_duration_ms(), 25);
+        assert_eq!(AccountStmtStats { generations: 0, ..stats }.avg_duration_ms(), 0);
+    }
+}
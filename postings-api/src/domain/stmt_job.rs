@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::ledger_account::LedgerAccount;
+
+/// Statement generation requested for later execution.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtJob {
+    pub id: Uuid,
+    pub account: LedgerAccount,
+    pub ref_time: DateTime<Utc>,
+    pub status: StmtJobStatus,
+    /// Statement created by the job, once completed.
+    pub stmt_id: Option<Uuid>,
+    pub error_message: Option<String>,
+    pub created: DateTime<Utc>,
+    pub completed_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StmtJobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Datelike, Days, Months, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Calendar period an account statement covers. At most one statement of an account can be
+/// closed per period.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StatementPeriod {
+    Daily,
+    Monthly,
+}
+
+impl StatementPeriod {
+    /// First instant (UTC) of the period containing `time`.
+    pub fn start_of(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let date = time.date_naive();
+        let start = match self {
+            StatementPeriod::Daily => date,
+            StatementPeriod::Monthly => date.with_day(1).expect("first day of the month"),
+        };
+        start.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// First instant of the period following the one containing `time`.
+    pub fn next_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let start = self.start_of(time).date_naive();
+        let next = match self {
+            StatementPeriod::Daily => start + Days::new(1),
+            StatementPeriod::Monthly => start + Months::new(1),
+        };
+        next.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    pub fn contains(&self, period_time: DateTime<Utc>, time: DateTime<Utc>) -> bool {
+        self.start_of(period_time) <= time && time < self.next_start(period_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_period_bounds() {
+        let time = Utc.with_ymd_and_hms(2025, 1, 31, 18, 45, 0).unwrap();
+        assert_eq!(StatementPeriod::Daily.start_of(time), Utc.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap());
+        assert_eq!(StatementPeriod::Daily.next_start(time), Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap());
+        assert_eq!(StatementPeriod::Monthly.start_of(time), Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(StatementPeriod::Monthly.next_start(time), Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap());
+        assert!(StatementPeriod::Monthly.contains(time, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()));
+        assert!(!StatementPeriod::Monthly.contains(time, Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap()));
+    }
+}
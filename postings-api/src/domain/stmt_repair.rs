@@ -0,0 +1,136 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::currency::CurrencyTotal;
+
+/// Figures of a closed statement that are derived from the account's posting lines.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtFigures {
+    pub opening_debit: BigDecimal,
+    pub opening_credit: BigDecimal,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub line_count: i64,
+    pub currency_totals: Vec<CurrencyTotal>,
+}
+
+impl StmtFigures {
+    /// Figures of an account without any earlier closed statement.
+    pub fn empty() -> Self {
+        StmtFigures {
+            opening_debit: BigDecimal::from(0),
+            opening_credit: BigDecimal::from(0),
+            total_debit: BigDecimal::from(0),
+            total_credit: BigDecimal::from(0),
+            line_count: 0,
+            currency_totals: Vec::new(),
+        }
+    }
+
+    /// Starting figures of the next statement: this statement's totals become its openings.
+    pub fn carry_over(&self) -> Self {
+        StmtFigures {
+            opening_debit: self.total_debit.clone(),
+            opening_credit: self.total_credit.clone(),
+            total_debit: self.total_debit.clone(),
+            total_credit: self.total_credit.clone(),
+            line_count: 0,
+            currency_totals: self.currency_totals.clone(),
+        }
+    }
+
+    pub fn add_line(&mut self, debit: &BigDecimal, credit: &BigDecimal, currency: Option<&str>) {
+        self.total_debit += debit.clone();
+        self.total_credit += credit.clone();
+        if let Some(currency) = currency {
+            CurrencyTotal::accumulate(&mut self.currency_totals, currency, debit, credit);
+        }
+        self.line_count += 1;
+    }
+}
+
+/// Closed statement whose stored figures differ from the figures recomputed from the raw lines.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtDrift {
+    pub stmt_id: Uuid,
+    pub account_id: Uuid,
+    pub pst_time: DateTime<Utc>,
+    pub recorded: StmtFigures,
+    pub expected: StmtFigures,
+}
+
+impl StmtDrift {
+    /// Names of the figures that differ.
+    pub fn drifted_fields(&self) -> Vec<&'static str> {
+        let (r, e) = (&self.recorded, &self.expected);
+        let mut fields = Vec::new();
+        if r.opening_debit != e.opening_debit {
+            fields.push("opening_debit");
+        }
+        if r.opening_credit != e.opening_credit {
+            fields.push("opening_credit");
+        }
+        if r.total_debit != e.total_debit {
+            fields.push("total_debit");
+        }
+        if r.total_credit != e.total_credit {
+            fields.push("total_credit");
+        }
+        if r.line_count != e.line_count {
+            fields.push("line_count");
+        }
+        if r.currency_totals != e.currency_totals {
+            fields.push("currency_totals");
+        }
+        fields
+    }
+}
+
+/// Audit record of a statement whose figures were replaced by the recomputed ones.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtRepair {
+    pub id: Uuid,
+    pub drift: StmtDrift,
+    pub principal: String,
+    pub reason: String,
+    pub repaired_time: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_carry_over_opens_with_previous_totals() {
+        let mut first = StmtFigures::empty();
+        first.add_line(&BigDecimal::from(100), &BigDecimal::from(0), Some("EUR"));
+        first.add_line(&BigDecimal::from(0), &BigDecimal::from(30), Some("EUR"));
+
+        let mut second = first.carry_over();
+        second.add_line(&BigDecimal::from(5), &BigDecimal::from(0), Some("EUR"));
+
+        assert_eq!(second.opening_debit, BigDecimal::from(100));
+        assert_eq!(second.opening_credit, BigDecimal::from(30));
+        assert_eq!(second.total_debit, BigDecimal::from(105));
+        assert_eq!(second.line_count, 1);
+        assert_eq!(second.currency_totals[0].total_debit, BigDecimal::from(105));
+    }
+
+    #[test]
+    fn test_drifted_fields_lists_differences_only() {
+        let mut expected = StmtFigures::empty();
+        expected.add_line(&BigDecimal::from(10), &BigDecimal::from(0), None);
+        let mut recorded = expected.clone();
+        recorded.line_count = 0;
+        let drift = StmtDrift {
+            stmt_id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            pst_time: Utc::now(),
+            recorded,
+            expected,
+        };
+
+        assert_eq!(drift.drifted_fields(), vec!["line_count"]);
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Ed25519 signature of a closed account statement, made when the statement was closed.
+/// Verifiers recompute the canonical form of the statement and check it against the public key
+/// published under `key_id`.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtSignature {
+    pub key_id: String,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub signature: Vec<u8>,
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::account_category::AccountCategory;
+use crate::domain::account_stmt_delta::AccountStmtDelta;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+
+/// Section of a rendered statement listing the lines labeled with one of `line_categories` by the
+/// ledger's categorization rules. A section without categories takes every line not claimed by an
+/// earlier section.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtSection {
+    pub title: String,
+    pub line_categories: Vec<String>,
+}
+
+impl StmtSection {
+    pub fn new(title: &str, line_categories: &[&str]) -> Self {
+        Self {
+            title: title.to_string(),
+            line_categories: line_categories.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn claims(&self, line: &PostingLine) -> bool {
+        self.line_categories.is_empty()
+            || line.category.as_ref().is_some_and(|c| self.line_categories.contains(c))
+    }
+}
+
+/// Ordered sections of a statement, e.g. principal and interest for loan accounts or fees and
+/// transactions for current accounts. Lines claimed by no section are left out.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StmtTemplate {
+    pub name: String,
+    pub sections: Vec<StmtSection>,
+}
+
+impl Default for StmtTemplate {
+    /// Single section listing all lines.
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            sections: vec![StmtSection::new("Transactions", &[])],
+        }
+    }
+}
+
+impl StmtTemplate {
+    /// Distributes the delta's lines over the sections, each line going to the first section claiming it.
+    pub fn render(&self, delta: &AccountStmtDelta) -> RenderedStmt {
+        let mut sections: Vec<RenderedSection> = self
+            .sections
+            .iter()
+            .map(|s| RenderedSection {
+                title: s.title.clone(),
+                lines: Vec::new(),
+                debit_total: BigDecimal::from(0),
+                credit_total: BigDecimal::from(0),
+            })
+            .collect();
+        for line in delta.lines.iter() {
+            if let Some(idx) = self.sections.iter().position(|s| s.claims(line)) {
+                let section = &mut sections[idx];
+                section.debit_total += line.debit_amount.clone();
+                section.credit_total += line.credit_amount.clone();
+                section.lines.push(line.clone());
+            }
+        }
+        RenderedStmt {
+            account: delta.account.clone(),
+            template: self.name.clone(),
+            since_stmt_id: delta.since_stmt_id,
+            from_time: delta.from_time,
+            to_time: delta.to_time,
+            sections,
+            debit_change: delta.debit_change.clone(),
+            credit_change: delta.credit_change.clone(),
+        }
+    }
+}
+
+/// Statement templates by account category, falling back to a default template.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StmtTemplateRegistry {
+    templates: HashMap<AccountCategory, StmtTemplate>,
+    default_template: StmtTemplate,
+}
+
+impl StmtTemplateRegistry {
+    pub fn new(default_template: StmtTemplate) -> Self {
+        Self { templates: HashMap::new(), default_template }
+    }
+
+    /// Registers the template of a category, replacing any previous one.
+    pub fn register(mut self, category: AccountCategory, template: StmtTemplate) -> Self {
+        self.templates.insert(category, template);
+        self
+    }
+
+    pub fn template_for(&self, category: &AccountCategory) -> &StmtTemplate {
+        self.templates.get(category).unwrap_or(&self.default_template)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RenderedSection {
+    pub title: String,
+    pub lines: Vec<PostingLine>,
+    pub debit_total: BigDecimal,
+    pub credit_total: BigDecimal,
+}
+
+/// Statement delta laid out by the template of the account's category. Totals cover all lines of
+/// the delta, including lines left out by the template.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RenderedStmt {
+    pub account: LedgerAccount,
+    pub template: String,
+    pub since_stmt_id: Uuid,
+    pub from_time: DateTime<Utc>,
+    pub to_time: DateTime<Utc>,
+    pub sections: Vec<RenderedSection>,
+    pub debit_change: BigDecimal,
+    pub credit_change: BigDecimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::balance_side::BalanceSide;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use crate::domain::ledger::Ledger;
+    use crate::domain::posting_status::PostingStatus;
+    use crate::domain::posting_type::PostingType;
+
+    fn account(category: AccountCategory) -> LedgerAccount {
+        let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: Uuid::nil() } };
+        LedgerAccount {
+            id: Uuid::nil(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa,
+            balance_side: category.default_bs(),
+            category,
+            currency: None,
+        }
+    }
+
+    fn line(category: Option<&str>, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account(AccountCategory::AS),
+            debit_amount: BigDecimal::from(0),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+            category: category.map(str::to_string),
+            currency: None,
+            fx: None,
+        }
+    }
+
+    fn delta(lines: Vec<PostingLine>) -> AccountStmtDelta {
+        AccountStmtDelta::new(account(AccountCategory::AS), Uuid::nil(), Utc::now(), Utc::now(), lines)
+    }
+
+    #[test]
+    fn test_lines_go_to_first_claiming_section() {
+        let loan = StmtTemplate {
+            name: "loan".to_string(),
+            sections: vec![
+                StmtSection::new("Interest", &["INTEREST"]),
+                StmtSection::new("Principal", &["PRINCIPAL"]),
+                StmtSection::new("Other", &[]),
+            ],
+        };
+        let rendered = loan.render(&delta(vec![
+            line(Some("PRINCIPAL"), 100),
+            line(Some("INTEREST"), 5),
+            line(None, 1),
+        ]));
+        let titles: Vec<_> = rendered.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Interest", "Principal", "Other"]);
+        assert_eq!(rendered.sections[0].credit_total, BigDecimal::from(5));
+        assert_eq!(rendered.sections[1].credit_total, BigDecimal::from(100));
+        assert_eq!(rendered.sections[2].lines.len(), 1);
+        assert_eq!(rendered.credit_change, BigDecimal::from(106));
+    }
+
+    #[test]
+    fn test_unclaimed_lines_are_left_out() {
+        let fees_only = StmtTemplate { name: "fees".to_string(), sections: vec![StmtSection::new("Fees", &["FEE"])] };
+        let rendered = fees_only.render(&delta(vec![line(Some("FEE"), 2), line(None, 50)]));
+        assert_eq!(rendered.sections[0].lines.len(), 1);
+        assert_eq!(rendered.credit_change, BigDecimal::from(52));
+    }
+
+    #[test]
+    fn test_registry_falls_back_to_default() {
+        let loan = StmtTemplate { name: "loan".to_string(), sections: vec![] };
+        let registry = StmtTemplateRegistry::default().register(AccountCategory::AS, loan);
+        assert_eq!(registry.template_for(&AccountCategory::AS).name, "loan");
+        assert_eq!(registry.template_for(&AccountCategory::LI).name, "default");
+    }
+}
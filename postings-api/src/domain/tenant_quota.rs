@@ -0,0 +1,109 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Posting submission quota of a tenant. Unset windows are not limited.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TenantQuota {
+    pub postings_per_minute: Option<u64>,
+    pub postings_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum QuotaWindow {
+    Minute,
+    Day,
+}
+
+/// Postings accepted and rejected for a tenant in the current windows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuotaUsage {
+    pub minute_start: DateTime<Utc>,
+    pub minute_count: u64,
+    pub day: NaiveDate,
+    pub day_count: u64,
+    /// Submissions rejected since the usage was first tracked.
+    pub rejected: u64,
+}
+
+impl QuotaUsage {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { minute_start: now, minute_count: 0, day: now.date_naive(), day_count: 0, rejected: 0 }
+    }
+
+    /// Starts new windows once `now` has left the current minute or day.
+    pub fn roll(&mut self, now: DateTime<Utc>) {
+        if now - self.minute_start >= Duration::minutes(1) {
+            self.minute_start = now;
+            self.minute_count = 0;
+        }
+        if now.date_naive() != self.day {
+            self.day = now.date_naive();
+            self.day_count = 0;
+        }
+    }
+}
+
+impl TenantQuota {
+    /// Checks whether one more posting fits into the current windows.
+    /// On failure returns the exhausted window and its limit.
+    pub fn check(&self, usage: &QuotaUsage) -> Result<(), (QuotaWindow, u64)> {
+        if let Some(limit) = self.postings_per_minute {
+            if usage.minute_count >= limit {
+                return Err((QuotaWindow::Minute, limit));
+            }
+        }
+        if let Some(limit) = self.postings_per_day {
+            if usage.day_count >= limit {
+                return Err((QuotaWindow::Day, limit));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 8, 16, h, m, s).unwrap()
+    }
+
+    #[test]
+    fn test_check_reports_exhausted_window() {
+        let quota = TenantQuota { postings_per_minute: Some(2), postings_per_day: Some(3) };
+        let mut usage = QuotaUsage::new(at(10, 0, 0));
+        assert!(quota.check(&usage).is_ok());
+
+        usage.minute_count = 2;
+        usage.day_count = 2;
+        assert_eq!(quota.check(&usage), Err((QuotaWindow::Minute, 2)));
+
+        usage.minute_count = 0;
+        usage.day_count = 3;
+        assert_eq!(quota.check(&usage), Err((QuotaWindow::Day, 3)));
+    }
+
+    #[test]
+    fn test_unset_quota_is_unlimited() {
+        let mut usage = QuotaUsage::new(at(10, 0, 0));
+        usage.minute_count = 1_000_000;
+        usage.day_count = 1_000_000;
+        assert!(TenantQuota::default().check(&usage).is_ok());
+    }
+
+    #[test]
+    fn test_roll_resets_elapsed_windows() {
+        let mut usage = QuotaUsage::new(at(23, 59, 30));
+        usage.minute_count = 5;
+        usage.day_count = 7;
+
+        usage.roll(at(23, 59, 50));
+        assert_eq!((usage.minute_count, usage.day_count), (5, 7));
+
+        usage.roll(at(23, 59, 30) + Duration::seconds(60));
+        assert_eq!(usage.minute_count, 0);
+        assert_eq!(usage.day_count, 0);
+    }
+}
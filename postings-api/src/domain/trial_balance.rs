@@ -0,0 +1,164 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::account_category::AccountCategory;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+use uuid::Uuid;
+
+/// Order in which categories are listed on a trial balance.
+pub(crate) const CATEGORY_ORDER: [AccountCategory; 8] = [
+    AccountCategory::AS,
+    AccountCategory::LI,
+    AccountCategory::EQ,
+    AccountCategory::RE,
+    AccountCategory::EX,
+    AccountCategory::NORE,
+    AccountCategory::NOEX,
+    AccountCategory::NOOP,
+];
+
+/// Net balance of one account, shown in the debit or the credit column.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrialBalanceLine {
+    pub account: LedgerAccount,
+    pub debit_balance: BigDecimal,
+    pub credit_balance: BigDecimal,
+}
+
+impl From<AccountBalance> for TrialBalanceLine {
+    fn from(balance: AccountBalance) -> Self {
+        let zero = BigDecimal::from(0);
+        let net = balance.debit_balance();
+        let (debit_balance, credit_balance) = if net >= zero { (net, zero) } else { (zero, -net) };
+        TrialBalanceLine { account: balance.account, debit_balance, credit_balance }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrialBalanceGroup {
+    pub category: AccountCategory,
+    pub lines: Vec<TrialBalanceLine>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+/// Balances of all accounts of a ledger at `ref_time`, grouped by account category.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrialBalance {
+    pub ledger: Ledger,
+    pub ref_time: DateTime<Utc>,
+    pub groups: Vec<TrialBalanceGroup>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    /// Whether the debit column adds up to the credit column.
+    pub balanced: bool,
+}
+
+impl TrialBalance {
+    /// Builds the report from the balances of the ledger's accounts. Categories without accounts are
+    /// left out; accounts keep their relative order within a category.
+    pub fn from_balances(ledger: Ledger, ref_time: DateTime<Utc>, balances: Vec<AccountBalance>) -> Self {
+        let mut lines: Vec<TrialBalanceLine> = balances.into_iter().map(TrialBalanceLine::from).collect();
+        let mut groups = Vec::new();
+        for category in CATEGORY_ORDER {
+            let (in_category, rest): (Vec<_>, Vec<_>) = lines.into_iter().partition(|l| l.account.category == category);
+            lines = rest;
+            if in_category.is_empty() {
+                continue;
+            }
+            let total_debit = in_category.iter().map(|l| l.debit_balance.clone()).sum();
+            let total_credit = in_category.iter().map(|l| l.credit_balance.clone()).sum();
+            groups.push(TrialBalanceGroup { category, lines: in_category, total_debit, total_credit });
+        }
+        let total_debit: BigDecimal = groups.iter().map(|g| g.total_debit.clone()).sum();
+        let total_credit: BigDecimal = groups.iter().map(|g| g.total_credit.clone()).sum();
+        TrialBalance { ledger, ref_time, groups, balanced: total_debit == total_credit, total_debit, total_credit }
+    }
+
+    pub fn line(&self, account_id: Uuid) -> Option<&TrialBalanceLine> {
+        self.groups.iter().flat_map(|g| &g.lines).find(|l| l.account.id == account_id)
+    }
+}
+
+/// Activity behind one line of a trial balance: the account's totals at the start of the period
+/// and one page of the lines posted from then up to the report's `ref_time`, latest first.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrialBalanceDrillDown {
+    pub line: TrialBalanceLine,
+    /// Start of the period (exclusive).
+    pub from_time: DateTime<Utc>,
+    pub to_time: DateTime<Utc>,
+    pub opening_debit: BigDecimal,
+    pub opening_credit: BigDecimal,
+    pub lines: Vec<PostingLine>,
+    pub page: usize,
+    pub size: usize,
+    /// Number of lines in the whole period.
+    pub total_elements: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use uuid::Uuid;
+
+    fn balance(ledger: &Ledger, category: AccountCategory, debit: i64, credit: i64) -> AccountBalance {
+        AccountBalance {
+            account: LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: ledger.coa.clone(),
+                balance_side: category.default_bs(),
+                category,
+                currency: None,
+            },
+            ref_time: Utc::now(),
+            total_debit: BigDecimal::from(debit),
+            total_credit: BigDecimal::from(credit),
+            known_at: None,
+        }
+    }
+
+    #[test]
+    fn test_groups_by_category_and_balances() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let report = TrialBalance::from_balances(
+            ledger.clone(),
+            Utc::now(),
+            vec![
+                balance(&ledger, AccountCategory::RE, 0, 70),
+                balance(&ledger, AccountCategory::AS, 120, 20),
+                balance(&ledger, AccountCategory::LI, 10, 40),
+            ],
+        );
+
+        assert_eq!(report.groups.iter().map(|g| g.category.clone()).collect::<Vec<_>>(), vec![AccountCategory::AS, AccountCategory::LI, AccountCategory::RE]);
+        assert_eq!(report.groups[1].total_credit, BigDecimal::from(30));
+        assert_eq!(report.total_debit, BigDecimal::from(100));
+        assert_eq!(report.total_credit, BigDecimal::from(100));
+        assert!(report.balanced);
+    }
+
+    #[test]
+    fn test_detects_unbalanced_ledger() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let report = TrialBalance::from_balances(ledger.clone(), Utc::now(), vec![balance(&ledger, AccountCategory::AS, 10, 0)]);
+        assert!(!report.balanced);
+    }
+
+    #[test]
+    fn test_line_is_found_across_groups() {
+        let ledger = Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } };
+        let liability = balance(&ledger, AccountCategory::LI, 10, 40);
+        let account_id = liability.account.id;
+        let report = TrialBalance::from_balances(ledger.clone(), Utc::now(), vec![balance(&ledger, AccountCategory::AS, 30, 0), liability]);
+
+        assert_eq!(report.line(account_id).map(|l| l.credit_balance.clone()), Some(BigDecimal::from(30)));
+        assert!(report.line(Uuid::new_v4()).is_none());
+    }
+}
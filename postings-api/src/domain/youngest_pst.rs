@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Time by which the youngest posting of an account statement is picked.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum YoungestPstStrategy {
+    /// Latest posting time; record time and line id break ties.
+    #[default]
+    PostingTime,
+    /// Latest record time; posting time and line id break ties.
+    RecordTime,
+}
+
+impl YoungestPstStrategy {
+    /// Sort key of a line, the youngest line having the greatest one. Keys are total, so the
+    /// pick does not depend on the order lines are read in.
+    pub fn key(&self, pst_time: DateTime<Utc>, record_time: DateTime<Utc>, line_id: Uuid) -> (DateTime<Utc>, DateTime<Utc>, Uuid) {
+        match self {
+            YoungestPstStrategy::PostingTime => (pst_time, record_time, line_id),
+            YoungestPstStrategy::RecordTime => (record_time, pst_time, line_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_keys_follow_strategy() {
+        let t = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+        let backdated = (t - Duration::days(1), t + Duration::hours(1), Uuid::new_v4());
+        let current = (t, t, Uuid::new_v4());
+        let key = |s: YoungestPstStrategy, (pst_time, record_time, id): (DateTime<Utc>, DateTime<Utc>, Uuid)| s.key(pst_time, record_time, id);
+
+        assert!(key(YoungestPstStrategy::PostingTime, current) > key(YoungestPstStrategy::PostingTime, backdated));
+        assert!(key(YoungestPstStrategy::RecordTime, backdated) > key(YoungestPstStrategy::RecordTime, current));
+    }
+
+    #[test]
+    fn test_equal_times_tie_on_line_id() {
+        let t = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+        let (low, high) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        for strategy in [YoungestPstStrategy::PostingTime, YoungestPstStrategy::RecordTime] {
+            assert!(strategy.key(t, t, high) > strategy.key(t, t, low));
+        }
+    }
+}
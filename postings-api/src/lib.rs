@@ -1,7 +1,12 @@
 pub mod domain;
 pub mod service;
 
+use bigdecimal::BigDecimal;
 use thiserror::Error;
+use uuid::Uuid;
+use crate::domain::account_limit::LimitType;
+use crate::domain::group_reporting::TranslationRate;
+use crate::domain::tenant_quota::QuotaWindow;
 
 #[derive(Error, Debug)]
 pub enum ServiceError {
@@ -19,8 +24,11 @@ pub enum ServiceError {
     LedgerNotFound,
     #[error("Posting not found")]
     PostingNotFound,
-    #[error("Double entry error: debits do not equal credits")]
-    DoubleEntry,
+    #[error("Posting line not found")]
+    PostingLineNotFound,
+    /// `imbalance` is debits minus credits, in `currency` or in amounts without one.
+    #[error("Double entry violation: debits and credits differ by {imbalance} ({currency:?})")]
+    DoubledEntryViolation { currency: Option<String>, imbalance: BigDecimal },
     #[error("Posting time is before last closing")]
     BaselineTime,
     #[error("Posting time is missing")]
@@ -31,4 +39,126 @@ pub enum ServiceError {
     StatementNotFound,
     #[error("Statement is already closed")]
     StatementAlreadyClosed,
+    #[error("Statement is not closed")]
+    StatementNotClosed,
+    #[error("Statement is not signed")]
+    StatementNotSigned,
+    #[error("Statement period overlaps an already closed statement")]
+    StatementPeriodOverlap,
+    #[error("Posting time is before the cut-off of a closed period")]
+    PeriodClosed,
+    #[error("Settlement batch not found")]
+    BatchNotFound,
+    #[error("Settlement batch status does not allow this operation")]
+    BatchStatusInvalid,
+    #[error("Settlement batch totals do not match the control amount")]
+    BatchControlMismatch,
+    #[error("Posting is already assigned to a settlement batch")]
+    PostingAlreadyBatched,
+    #[error("Reversals cannot be assigned to a settlement batch")]
+    ReversalNotBatchable,
+    #[error("Earmark not found")]
+    EarmarkNotFound,
+    #[error("Earmark is no longer active")]
+    EarmarkNotActive,
+    #[error("Capture exceeds the remaining earmarked amount")]
+    EarmarkAmountExceeded,
+    #[error("Standing order not found")]
+    StandingOrderNotFound,
+    #[error("Standing order is no longer active")]
+    StandingOrderNotActive,
+    #[error("Scheduled posting not found")]
+    ScheduledPostingNotFound,
+    #[error("Scheduled posting is no longer active")]
+    ScheduledPostingNotActive,
+    #[error("Escrow not found")]
+    EscrowNotFound,
+    #[error("Escrow is already resolved")]
+    EscrowAlreadyResolved,
+    #[error("Available balance is insufficient")]
+    InsufficientAvailableBalance,
+    #[error("Invalid import record at line {line}")]
+    InvalidImportRecord { line: usize },
+    #[error("Quarantined entry not found")]
+    QuarantinedEntryNotFound,
+    #[error("Quarantined entry is already resolved")]
+    QuarantinedEntryResolved,
+    #[error("Object storage error")]
+    ObjectStore,
+    #[error("Writing the export failed")]
+    ExportFailed,
+    #[error("Publishing events failed")]
+    EventPublication,
+    #[error("External content not found")]
+    ExternalContentNotFound,
+    #[error("External content does not match its hash")]
+    ExternalContentHashMismatch,
+    #[error("Report schedule not found")]
+    ReportScheduleNotFound,
+    #[error("Report artifact not found")]
+    ReportArtifactNotFound,
+    #[error("Report artifact does not match its checksum")]
+    ReportArtifactHashMismatch,
+    #[error("Event log backup does not match the hashes of its manifest")]
+    EventLogSegmentHashMismatch,
+    #[error("No renderer configured for this report format")]
+    ReportRendererNotConfigured,
+    #[error("Account group not found")]
+    AccountGroupNotFound,
+    #[error("The ledger already has an account group of this name")]
+    AccountGroupNameTaken,
+    #[error("Asynchronous statement generation is not configured")]
+    StmtJobsDisabled,
+    #[error("Statement annotations are not configured")]
+    StmtAnnotationsDisabled,
+    #[error("Ledger is read-only")]
+    ReadOnly,
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Category rule not found")]
+    CategoryRuleNotFound,
+    #[error("No document signer configured")]
+    SignerNotConfigured,
+    #[error("Two-phase posting is not configured")]
+    TwoPhaseDisabled,
+    #[error("Prepared posting not found")]
+    PreparedPostingNotFound,
+    #[error("Prepared posting has expired")]
+    PreparedPostingExpired,
+    #[error("Prepared posting is already resolved")]
+    PreparedPostingResolved,
+    #[error("API key not found")]
+    ApiKeyNotFound,
+    #[error("API key is invalid, expired or revoked")]
+    ApiKeyInvalid,
+    #[error("API key does not grant this operation")]
+    Forbidden,
+    #[error("Posting lines mix currencies without matching FX details")]
+    CurrencyMismatch,
+    #[error("No backfill task registered under this name")]
+    BackfillTaskNotFound,
+    #[error("Backfill job not found")]
+    BackfillJobNotFound,
+    #[error("Posting is already discarded")]
+    PostingAlreadyDiscarded,
+    #[error("Posting is older than the reversal policy of its ledger allows")]
+    ReversalWindowExpired,
+    #[error("Posting quota of {limit} per {window:?} exceeded for tenant {tenant_id}")]
+    QuotaExceeded { tenant_id: Uuid, window: QuotaWindow, limit: u64 },
+    #[error("Limit {limit_type:?} exceeded on account {account_id}")]
+    LimitExceeded { account_id: Uuid, limit_type: LimitType },
+    #[error("Operation is already recorded as posting {posting_id} with different content")]
+    DuplicateOperation { posting_id: Uuid },
+    #[error("External reference is already in use")]
+    ExternalRefTaken,
+    #[error("Account name not found")]
+    AccountNameNotFound,
+    #[error("Account name or number is already in use in the ledger")]
+    AccountNameTaken,
+    #[error("No {rate_type:?} rate from {currency} to the reporting currency")]
+    FxRateNotFound { currency: String, rate_type: TranslationRate },
+    #[error("Account is already revalued at a later valuation time")]
+    RevaluationSuperseded,
+    #[error("Unknown hash algorithm {name}")]
+    UnknownHashAlgorithm { name: String },
 }
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::account_group::{AccountGroup, AccountGroupBalance};
+use crate::domain::account_stmt::AccountStmt;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::trial_balance::TrialBalance;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountGroupService {
+    /// Fails with `AccountGroupNameTaken` if the ledger has a group of that name, and with
+    /// `LedgerAccountNotFound` if a listed member is not an account of the ledger.
+    async fn create_group(&self, group: AccountGroup) -> Result<AccountGroup, ServiceError>;
+    async fn find_group_by_id(&self, group_id: Uuid) -> Result<Option<AccountGroup>, ServiceError>;
+    async fn find_group_by_name(&self, ledger: &Ledger, name: &str) -> Result<Option<AccountGroup>, ServiceError>;
+    async fn find_groups(&self, ledger: &Ledger) -> Result<Vec<AccountGroup>, ServiceError>;
+    async fn add_member(&self, group_id: Uuid, account_id: Uuid) -> Result<AccountGroup, ServiceError>;
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<AccountGroup, ServiceError>;
+    /// The accounts currently in the group, ordered by id.
+    async fn accounts(&self, group_id: Uuid) -> Result<Vec<LedgerAccount>, ServiceError>;
+    async fn balance(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<AccountGroupBalance, ServiceError>;
+    /// The statement of each account of the group at `ref_time`, as `read_stmt` returns them.
+    async fn read_stmts(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<AccountStmt>, ServiceError>;
+    /// Trial balance restricted to the accounts of the group.
+    async fn trial_balance(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<TrialBalance, ServiceError>;
+}
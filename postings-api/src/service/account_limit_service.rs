@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::account_limit::AccountLimit;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait AccountLimitService {
+    async fn set_limit(&self, limit: AccountLimit) -> Result<AccountLimit, ServiceError>;
+    async fn find_limit(&self, ledger_account: LedgerAccount) -> Result<Option<AccountLimit>, ServiceError>;
+}
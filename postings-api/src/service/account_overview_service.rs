@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::account_overview::AccountOverview;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait AccountOverviewService {
+    /// The account's last closed statement, its lines posted after `activity_from` and its
+    /// balance, all at posting time `ref_time`.
+    async fn account_overview(&self, ledger_account: LedgerAccount, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountOverview, ServiceError>;
+}
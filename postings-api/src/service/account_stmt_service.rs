@@ -1,12 +1,47 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use crate::domain::account_stmt::AccountStmt;
+use crate::domain::account_stmt_delta::AccountStmtDelta;
+use crate::domain::ledger::Ledger;
 use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::stmt_annotation::StmtAnnotation;
+use crate::domain::stmt_job::StmtJob;
+use crate::domain::stmt_template::RenderedStmt;
 use crate::ServiceError;
+use uuid::Uuid;
 
 #[async_trait]
 pub trait AccountStmtService {
+    /// Computes the statement at `ref_time` without storing anything, posting traces included.
     async fn read_stmt(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountStmt, ServiceError>;
+    /// Stores the statement at `ref_time` together with the posting traces of its lines.
     async fn create_stmt(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountStmt, ServiceError>;
     async fn close_stmt(&self, stmt: AccountStmt) -> Result<AccountStmt, ServiceError>;
+    /// Deletes persisted simulated statements of the ledger that expired on or before `as_of`,
+    /// together with their posting traces. Returns the number of deleted statements.
+    async fn purge_expired_simulated_stmts(&self, ledger: Ledger, as_of: DateTime<Utc>) -> Result<u64, ServiceError>;
+    /// Lines posted to the account after the posting time of statement `since_stmt_id`, with their totals.
+    /// Lines are in the order they are applied to statements.
+    async fn read_delta(&self, ledger_account: LedgerAccount, since_stmt_id: Uuid) -> Result<AccountStmtDelta, ServiceError>;
+    /// `read_delta` laid out by the statement template registered for the account's category.
+    async fn render_delta(&self, ledger_account: LedgerAccount, since_stmt_id: Uuid) -> Result<RenderedStmt, ServiceError>;
+    /// Queues statement creation and returns the job id to poll with `find_stmt_job`.
+    async fn create_stmt_async(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<Uuid, ServiceError>;
+    async fn find_stmt_job(&self, job_id: Uuid) -> Result<Option<StmtJob>, ServiceError>;
+    /// Executes up to `limit` pending jobs in creation order. Returns the number of processed jobs.
+    async fn run_stmt_jobs(&self, limit: i64) -> Result<usize, ServiceError>;
+    /// Records a reviewer note on a persisted statement, open or closed. The statement itself is
+    /// left untouched, so notes are also accepted on read-only ledgers.
+    async fn annotate_stmt(&self, stmt_id: Uuid, author: String, text: String) -> Result<StmtAnnotation, ServiceError>;
+    /// Notes on the statement, oldest first.
+    async fn find_stmt_annotations(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, ServiceError>;
+    /// Checks the signature of a closed statement against the statement as stored. `false` means
+    /// the statement was altered after it was signed.
+    async fn verify_stmt_signature(&self, stmt_id: Uuid) -> Result<bool, ServiceError>;
+}
+
+/// Notified when a statement job completes or fails.
+#[async_trait]
+pub trait StmtJobListener {
+    async fn on_job_finished(&self, job: &StmtJob);
 }
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::api_key::{ApiKey, ApiRole, IssuedApiKey};
+use crate::ServiceError;
+use uuid::Uuid;
+
+/// Built-in credential store for deployments without an external identity provider.
+#[async_trait]
+pub trait ApiKeyService {
+    /// Creates a key for a service account. The returned secret is not stored and cannot be retrieved again.
+    async fn issue_key(&self, name: String, tenant_id: Uuid, ledger_id: Option<Uuid>, roles: Vec<ApiRole>, expires: Option<DateTime<Utc>>) -> Result<IssuedApiKey, ServiceError>;
+    /// Resolves an active key from its secret.
+    async fn authenticate(&self, secret: &str) -> Result<ApiKey, ServiceError>;
+    /// Authenticates the secret and checks that it grants `role` on the ledger.
+    async fn authorize(&self, secret: &str, tenant_id: Uuid, ledger_id: Uuid, role: ApiRole) -> Result<ApiKey, ServiceError>;
+    async fn revoke_key(&self, key_id: Uuid) -> Result<ApiKey, ServiceError>;
+    async fn find_keys_by_tenant(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, ServiceError>;
+}
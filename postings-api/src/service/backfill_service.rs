@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::backfill::BackfillJob;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+/// Outcome of one batch. A `None` cursor means the task has nothing left to process.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackfillBatch {
+    pub processed: u64,
+    pub cursor: Option<String>,
+}
+
+/// Populates a derived column or table for the historical records of a ledger.
+/// Batches must be idempotent: a batch interrupted before its checkpoint was saved is run again.
+#[async_trait]
+pub trait BackfillTask {
+    fn name(&self) -> &str;
+    /// Number of records the task will visit, used for progress reporting.
+    async fn count(&self, ledger: &Ledger) -> Result<u64, ServiceError>;
+    /// Processes up to `batch_size` records following `cursor` (from the start when `None`).
+    async fn run_batch(&self, ledger: &Ledger, cursor: Option<&str>, batch_size: u32) -> Result<BackfillBatch, ServiceError>;
+}
+
+#[async_trait]
+pub trait BackfillService {
+    /// Creates a job for the registered task, or returns the unfinished job already started for this task and ledger.
+    async fn start_backfill(&self, task_name: &str, ledger: Ledger, batch_size: u32) -> Result<BackfillJob, ServiceError>;
+    /// Runs at most `max_batches` batches of the job from its last checkpoint. Failed jobs are resumed.
+    async fn run_backfill(&self, job_id: Uuid, max_batches: u32) -> Result<BackfillJob, ServiceError>;
+    async fn find_backfill(&self, job_id: Uuid) -> Result<Option<BackfillJob>, ServiceError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::balance_forecast::BalanceForecast;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait BalanceForecastService {
+    /// Projects the account's balance over `days` days starting at `from`, using future-dated
+    /// lines, active standing orders and active holds.
+    async fn forecast(&self, ledger_account: LedgerAccount, from: NaiveDate, days: u32) -> Result<BalanceForecast, ServiceError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::account_balance::AccountBalance;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait BalanceService {
+    /// Totals of each of the accounts at `ref_time`, in the order of `account_ids`, read with one
+    /// grouped query instead of one per account. Accounts without lines get zero totals; an
+    /// unknown account fails the whole call with `LedgerAccountNotFound`.
+    async fn balances(&self, account_ids: Vec<Uuid>, ref_time: DateTime<Utc>) -> Result<Vec<AccountBalance>, ServiceError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::business_calendar::BusinessCalendar;
+use crate::domain::holiday::Holiday;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait CalendarService {
+    async fn add_holiday(&self, holiday: Holiday) -> Result<Holiday, ServiceError>;
+    async fn remove_holiday(&self, ledger: Ledger, date: NaiveDate) -> Result<(), ServiceError>;
+    async fn find_holidays(&self, ledger: Ledger, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, ServiceError>;
+    /// Calendar of the ledger used for value dating, interest accrual and scheduled postings.
+    async fn load_calendar(&self, ledger: Ledger) -> Result<BusinessCalendar, ServiceError>;
+}
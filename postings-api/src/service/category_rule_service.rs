@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::domain::category_rule::CategoryRule;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CategoryRuleService {
+    async fn save_category_rule(&self, rule: CategoryRule) -> Result<CategoryRule, ServiceError>;
+    async fn find_category_rules(&self, ledger: Ledger) -> Result<Vec<CategoryRule>, ServiceError>;
+    async fn delete_category_rule(&self, rule_id: Uuid) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::coa_import::{CoaImportReport, CoaImportRequest};
+use crate::ServiceError;
+
+#[async_trait]
+pub trait ChartOfAccountImportService {
+    /// Creates a chart of accounts, its ledger and the declared account tree in one transaction.
+    /// Every row is validated first; if any is rejected nothing is written and the report lists
+    /// the per-row errors instead of a ledger.
+    async fn import(&self, request: CoaImportRequest) -> Result<CoaImportReport, ServiceError>;
+}
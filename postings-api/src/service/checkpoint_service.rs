@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+/// Periodic balance checkpoints, so statements of accounts with long histories are generated
+/// from the last checkpoint instead of the last closed statement.
+#[async_trait]
+pub trait CheckpointService {
+    /// Stores a checkpoint at posting time `as_of` for every account of the ledger, continuing
+    /// from the previous checkpoint of the account where possible. An existing checkpoint at
+    /// `as_of` is recomputed. Returns the number of stored checkpoints.
+    ///
+    /// `as_of` must not be in the future. A posting booked at or before `as_of` later drops the
+    /// checkpoint of its accounts when the posting service is configured with the checkpoint
+    /// repository, so statements generated from checkpoints see back-dated lines.
+    async fn create_checkpoints(&self, ledger: Ledger, as_of: DateTime<Utc>) -> Result<u64, ServiceError>;
+}
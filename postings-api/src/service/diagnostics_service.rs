@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::stmt_diagnostics::AccountStmtStats;
+use crate::ServiceError;
+
+/// Statement generation figures recorded by the statement service, to guide partitioning and
+/// statement closing.
+#[async_trait]
+pub trait DiagnosticsService {
+    /// Accounts of the ledger with the slowest statement generation between `from` and `to`,
+    /// slowest first.
+    async fn slowest_accounts(&self, ledger: Ledger, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> Result<Vec<AccountStmtStats>, ServiceError>;
+    /// Accounts of the ledger whose statements read the most lines between `from` and `to`,
+    /// hottest first.
+    async fn hottest_accounts(&self, ledger: Ledger, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> Result<Vec<AccountStmtStats>, ServiceError>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::ServiceError;
+
+/// Produces detached signatures for delivered documents, e.g. backed by an HSM or KMS.
+#[async_trait]
+pub trait DocumentSigner {
+    /// Identifier of the signing key, published so recipients can pick the verification key.
+    fn key_id(&self) -> String;
+    async fn sign(&self, content: &[u8]) -> Result<Vec<u8>, ServiceError>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use crate::domain::earmark::Earmark;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EarmarkService {
+    async fn create_earmark(&self, earmark: Earmark) -> Result<Earmark, ServiceError>;
+    async fn find_earmark_by_id(&self, earmark_id: Uuid) -> Result<Option<Earmark>, ServiceError>;
+    async fn find_active_earmarks(&self, ledger_account: LedgerAccount) -> Result<Vec<Earmark>, ServiceError>;
+    /// Lifts the block without using the amount.
+    async fn release_earmark(&self, earmark_id: Uuid) -> Result<Earmark, ServiceError>;
+    /// Captures the whole remaining amount, see [`Self::capture_earmark`].
+    async fn consume_earmark(&self, earmark_id: Uuid, counter_account: LedgerAccount) -> Result<Earmark, ServiceError>;
+    /// Captures part of the remaining amount: books it from the earmarked account to `counter_account`
+    /// and records the posting on the earmark, both or neither. The earmark is consumed once nothing remains.
+    async fn capture_earmark(&self, earmark_id: Uuid, amount: BigDecimal, counter_account: LedgerAccount) -> Result<Earmark, ServiceError>;
+    /// Incremental authorization: raises the blocked amount and optionally extends the expiry.
+    async fn increase_earmark(&self, earmark_id: Uuid, amount: BigDecimal, expiry: Option<DateTime<Utc>>) -> Result<Earmark, ServiceError>;
+    /// Expires active earmarks whose expiry is on or before `as_of`, releasing any uncaptured remainder. Called by the scheduler.
+    async fn expire_earmarks(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, ServiceError>;
+    /// Booked balance on the account's balance side minus the remaining amount of all earmarks blocking at `ref_time`.
+    async fn available_balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<BigDecimal, ServiceError>;
+}
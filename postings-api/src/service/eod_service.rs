@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::eod_run::EodRun;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+/// A single step of the end-of-day pipeline (cutoff, interest accrual, statements, period lock, exports...).
+/// Returns an optional human readable summary recorded with the step result.
+#[async_trait]
+pub trait EodStep {
+    fn name(&self) -> &str;
+    async fn run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<String>, ServiceError>;
+}
+
+#[async_trait]
+pub trait EodService {
+    /// Runs (or resumes) the step sequence configured for the ledger. Steps already completed for this business date are skipped.
+    async fn run_eod(&self, ledger: Ledger, business_date: NaiveDate) -> Result<EodRun, ServiceError>;
+    async fn find_eod_run(&self, ledger: Ledger, business_date: NaiveDate) -> Result<Option<EodRun>, ServiceError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::escrow::Escrow;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EscrowService {
+    /// Books the transfer from the source to the escrow account.
+    async fn initiate_escrow(&self, escrow: Escrow) -> Result<Escrow, ServiceError>;
+    async fn find_escrow_by_id(&self, escrow_id: Uuid) -> Result<Option<Escrow>, ServiceError>;
+    /// Releases the escrowed funds to the target account. An escrow is released or returned once,
+    /// later and concurrent calls fail with `ServiceError::EscrowAlreadyResolved`.
+    async fn confirm_escrow(&self, escrow_id: Uuid) -> Result<Escrow, ServiceError>;
+    /// Returns the escrowed funds to the source account.
+    async fn cancel_escrow(&self, escrow_id: Uuid) -> Result<Escrow, ServiceError>;
+    /// Applies the timeout action of every escrow still pending at `as_of`. Called by the scheduler.
+    async fn resolve_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, ServiceError>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::domain_event::DomainEvent;
+use crate::ServiceError;
+
+/// Receives the events of committed changes, e.g. to forward them to a message broker.
+/// The change is stored already, so an error is only logged.
+#[async_trait]
+pub trait EventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ServiceError>;
+}
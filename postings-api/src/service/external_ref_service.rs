@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::external_ref::{ExternalRef, ExternalRefEntity};
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting::Posting;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait ExternalRefService {
+    /// Attaches the reference of `system` to the posting. Attaching the same reference again
+    /// returns the stored one; a different reference of the same system, or a reference already
+    /// taken under the uniqueness of the system, fails with [`ServiceError::ExternalRefTaken`].
+    async fn attach_posting_ref(&self, posting_id: Uuid, system: &str, reference: &str) -> Result<ExternalRef, ServiceError>;
+    /// Same as [`Self::attach_posting_ref`] for a ledger account.
+    async fn attach_account_ref(&self, account_id: Uuid, system: &str, reference: &str) -> Result<ExternalRef, ServiceError>;
+    /// References of all systems attached to the entity.
+    async fn find_refs(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, ServiceError>;
+    /// Postings of the ledger carrying the reference, ordered by record time.
+    async fn find_postings_by_ref(&self, ledger: Ledger, system: &str, reference: &str) -> Result<Vec<Posting>, ServiceError>;
+    /// Accounts of the ledger carrying the reference.
+    async fn find_accounts_by_ref(&self, ledger: Ledger, system: &str, reference: &str) -> Result<Vec<LedgerAccount>, ServiceError>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::account_activity::AccountActivity;
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting_line::PostingLine;
+use crate::ServiceError;
+
+/// Read-only store of historical posting lines, e.g. a Parquet export or a data warehouse.
+#[async_trait]
+pub trait ReadBackend {
+    fn name(&self) -> &str;
+    /// Lines posted before this time may be held by the backend. Later periods are never requested from it.
+    fn covered_until(&self) -> DateTime<Utc>;
+    /// Non discarded lines of the account posted after `from` (from the beginning when `None`) and up to `to`.
+    async fn find_lines(&self, account: &LedgerAccount, from: Option<DateTime<Utc>>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError>;
+}
+
+/// Balance and statement queries answered from the ledger database together with the registered
+/// read backends, for reports spanning current and historical data.
+#[async_trait]
+pub trait FederatedReadService {
+    async fn balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountBalance, ServiceError>;
+    /// Openings at `from`, lines posted in `(from, to]` and closing totals of the account.
+    async fn activity(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<AccountActivity, ServiceError>;
+}
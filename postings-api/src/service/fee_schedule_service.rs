@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use crate::domain::fee_schedule::FeeSchedule;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait FeeScheduleService {
+    async fn save_fee_schedule(&self, schedule: FeeSchedule) -> Result<FeeSchedule, ServiceError>;
+    async fn find_fee_schedule(&self, ledger: Ledger, opr_type: &[u8; 34]) -> Result<Option<FeeSchedule>, ServiceError>;
+    /// Fee that would be charged for an operation of `amount`, if a schedule applies.
+    async fn calculate_fee(&self, ledger: Ledger, opr_type: &[u8; 34], amount: BigDecimal) -> Result<Option<BigDecimal>, ServiceError>;
+}
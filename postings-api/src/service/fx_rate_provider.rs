@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use crate::ServiceError;
+
+/// Source of exchange rates for translating balances, e.g. a market data feed or a rate table
+/// maintained by treasury. Rates are units of `to` per unit of `from`; `None` if unknown.
+#[async_trait]
+pub trait FxRateProvider {
+    async fn closing_rate(&self, from: &str, to: &str, at: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError>;
+    async fn average_rate(&self, from: &str, to: &str, period_start: DateTime<Utc>, period_end: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError>;
+}
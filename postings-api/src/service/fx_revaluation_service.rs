@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::fx_revaluation::{AccountRevaluation, FxRevaluation, RevaluationAccounts};
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait FxRevaluationService {
+    /// Revaluation of every account of the ledger kept in another currency than the functional
+    /// one, at the closing rates of `valuation_time`, without booking it. Fails with
+    /// `FxRateNotFound` when a rate is unknown.
+    async fn compute_revaluation(&self, ledger: Ledger, valuation_time: DateTime<Utc>) -> Result<FxRevaluation, ServiceError>;
+    /// Computes the revaluation and books the gain or loss of each account as a posting between
+    /// the adjustment account and the gain or loss account. Repeating a valuation date returns the
+    /// revaluations booked before; an account revalued at a later date fails with
+    /// `RevaluationSuperseded`.
+    async fn revalue(&self, ledger: Ledger, valuation_time: DateTime<Utc>, accounts: RevaluationAccounts) -> Result<FxRevaluation, ServiceError>;
+    /// Booked revaluations of the account, latest first.
+    async fn find_revaluations(&self, ledger_account: LedgerAccount) -> Result<Vec<AccountRevaluation>, ServiceError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::group_reporting::TranslatedTrialBalance;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait GroupReportingService {
+    /// Trial balance of the group at `ref_time` in the group reporting currency. Each account's
+    /// balance is split by currency and translated at the closing or the period average rate
+    /// configured for its category. Fails with `FxRateNotFound` when a rate is unknown.
+    async fn translated_trial_balance(&self, group_id: Uuid, period_start: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<TranslatedTrialBalance, ServiceError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::chain_verification::ChainVerification;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait HashChainVerifier {
+    /// Walks the postings of the ledger from its first posting along their antecedent links,
+    /// recomputes each hash and reports the first broken link.
+    async fn verify_chain(&self, ledger: Ledger) -> Result<ChainVerification, ServiceError>;
+}
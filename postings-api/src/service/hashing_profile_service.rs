@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::hashing_profile::HashingProfile;
+use crate::domain::ledger::Ledger;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait HashingProfileService {
+    /// Applies to postings recorded after the change; existing hashes keep their recorded exclusions.
+    async fn save_hashing_profile(&self, profile: HashingProfile) -> Result<HashingProfile, ServiceError>;
+    async fn find_hashing_profile(&self, ledger: Ledger) -> Result<Option<HashingProfile>, ServiceError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::ledger_account_name::LedgerAccountName;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait LedgerAccountNameService {
+    /// Registers a name for the account. Fails with `AccountNameTaken` when the name or the number
+    /// identifies another account of the ledger during the validity period.
+    async fn register_name(&self, name: LedgerAccountName) -> Result<LedgerAccountName, ServiceError>;
+    /// Ends the validity of the name at `valid_to`, e.g. before renaming the account.
+    async fn end_name(&self, name_id: Uuid, valid_to: DateTime<Utc>) -> Result<LedgerAccountName, ServiceError>;
+    /// Account identified at `at` by `label`: a number, a name, or both as in `1.1.2.3 Cash`.
+    async fn resolve(&self, ledger: Ledger, label: &str, at: DateTime<Utc>) -> Result<Option<LedgerAccount>, ServiceError>;
+    /// Names of the account over time, latest first.
+    async fn find_names(&self, ledger_account: LedgerAccount) -> Result<Vec<LedgerAccountName>, ServiceError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::ledger_account_stats::LedgerAccountStats;
+use crate::domain::ledger_account_tree::LedgerAccountTree;
+use crate::service::posting_service::Page;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait LedgerAccountService {
+    /// Totals of the account at `ref_time`, equal to those of the statement `read_stmt` would
+    /// return. Read-only: no statement or posting trace is written.
+    async fn balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountBalance, ServiceError>;
+    /// Totals of the account at posting time `ref_time` as they were known at record time `known_at`:
+    /// lines recorded later, such as back-dated entries, are left out and lines discarded since
+    /// are still counted. [`Self::balance`] answers the same question as known now.
+    async fn balance_as_at(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<AccountBalance, ServiceError>;
+    /// Line count, posting time range, current balance, last closed statement and the posting
+    /// that last touched the account, in one call.
+    async fn stats(&self, account_id: Uuid) -> Result<LedgerAccountStats, ServiceError>;
+    /// One page of the accounts of the ledger, ordered by id.
+    async fn find_ledger_accounts_paged(&self, ledger: Ledger, page: usize, size: usize) -> Result<Page<LedgerAccount>, ServiceError>;
+    /// All accounts below the account, at any depth, ordered by id.
+    async fn find_descendants(&self, account_id: Uuid) -> Result<Vec<LedgerAccount>, ServiceError>;
+    /// The account and its descendants at `ref_time`, each with its own totals and the totals of
+    /// its subtree. Children are ordered by id.
+    async fn tree(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<LedgerAccountTree, ServiceError>;
+}
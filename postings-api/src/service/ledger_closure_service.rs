@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::ledger_closure::LedgerClosure;
+use crate::domain::privileged_context::PrivilegedContext;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait LedgerClosureService {
+    /// Locks the ledger: postings before `cut_off` are then rejected with
+    /// [`ServiceError::PeriodClosed`]. With `allow_adjustments`, privileged adjustments may still
+    /// be posted before the cut-off. `context` records who closed the period and why.
+    async fn close_ledger(&self, ledger: Ledger, cut_off: DateTime<Utc>, allow_adjustments: bool, context: PrivilegedContext) -> Result<LedgerClosure, ServiceError>;
+    /// Same as [`Self::close_ledger`] for the postings with a line on the account.
+    async fn close_account(&self, account: LedgerAccount, cut_off: DateTime<Utc>, allow_adjustments: bool, context: PrivilegedContext) -> Result<LedgerClosure, ServiceError>;
+    /// Closures of the ledger and its accounts, latest cut-off first.
+    async fn find_closures(&self, ledger: Ledger) -> Result<Vec<LedgerClosure>, ServiceError>;
+}
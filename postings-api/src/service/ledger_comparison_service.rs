@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_comparison::{LedgerDiff, LedgerSnapshot};
+use crate::ServiceError;
+
+#[async_trait]
+pub trait LedgerComparisonService {
+    async fn snapshot(&self, ledger: Ledger, as_of: NaiveDate) -> Result<LedgerSnapshot, ServiceError>;
+    async fn compare_ledgers(&self, left: Ledger, right: Ledger, as_of: NaiveDate) -> Result<LedgerDiff, ServiceError>;
+    /// Compares a ledger against a snapshot imported from another system, at the snapshot's date.
+    async fn compare_with_snapshot(&self, ledger: Ledger, snapshot: LedgerSnapshot) -> Result<LedgerDiff, ServiceError>;
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use crate::domain::ledger_event::LedgerEvent;
+use crate::ServiceError;
+
+/// Read side of the change event table, for consumers polling instead of tailing the database log.
+#[async_trait]
+pub trait LedgerEventService {
+    /// Up to `limit` events with a sequence number greater than `after_seq`, in sequence order.
+    /// Sequence numbers are taken when a row changes, so a slower concurrent transaction can still
+    /// commit a lower number: pollers should re-read from their last fully settled position.
+    async fn read_events(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, ServiceError>;
+    /// Highest sequence number recorded so far.
+    async fn latest_seq(&self) -> Result<Option<i64>, ServiceError>;
+    /// Records that `consumer` has processed every event up to `seq`. Events processed by all
+    /// registered consumers count as dispatched.
+    async fn acknowledge(&self, consumer: &str, seq: i64) -> Result<(), ServiceError>;
+    /// Last sequence number acknowledged by `consumer`, `None` for a new consumer.
+    async fn consumer_position(&self, consumer: &str) -> Result<Option<i64>, ServiceError>;
+    /// Moves dispatched events older than the retention period to the archive. Returns the number of moved events.
+    async fn compact(&self) -> Result<u64, ServiceError>;
+    /// Publishes the events with sequence numbers in `from_seq..=to_seq`, archived ones included,
+    /// in sequence order. Returns the number of published events.
+    async fn replay(&self, from_seq: i64, to_seq: i64, sink: &(dyn LedgerEventSink + Send + Sync)) -> Result<u64, ServiceError>;
+}
+
+/// Destination of replayed events, e.g. a newly added downstream consumer.
+#[async_trait]
+pub trait LedgerEventSink {
+    async fn publish(&self, events: &[LedgerEvent]) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::ledger_integrity::LedgerIntegrityReport;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait LedgerIntegrityService {
+    /// Runs the hash chain verification, the statement drift check of every account, the account
+    /// hierarchy validation and the double-entry check of every current posting of the ledger.
+    async fn verify_ledger(&self, ledger_id: Uuid) -> Result<LedgerIntegrityReport, ServiceError>;
+}
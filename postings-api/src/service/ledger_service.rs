@@ -15,5 +15,14 @@ pub trait LedgerService {
     async fn find_ledger_account_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, ServiceError>;
     async fn find_ledger_account_by_name(&self, ledger: &Ledger, name: &str) -> Result<Vec<LedgerAccount>, ServiceError>;
     async fn check_if_ledger_account_exist(&self, ledger: &Ledger, name: &str) -> Result<bool, ServiceError>;
+    /// Switches the ledger into or out of read-only mode. Reads keep working while it is set.
+    async fn set_read_only(&self, ledger_id: Uuid, read_only: bool) -> Result<(), ServiceError>;
+    async fn is_read_only(&self, ledger_id: Uuid) -> Result<bool, ServiceError>;
+    /// Marks the ledger as a memo ledger, whose postings are not required to balance.
+    async fn set_memo(&self, ledger_id: Uuid, memo: bool) -> Result<(), ServiceError>;
+    async fn is_memo(&self, ledger_id: Uuid) -> Result<bool, ServiceError>;
+    /// Lets closing postings of the ledger's statements record the hash of the closed totals, see
+    /// [`crate::domain::closing_summary::ClosingSummary`].
+    async fn set_closing_summary(&self, ledger_id: Uuid, closing_summary: bool) -> Result<(), ServiceError>;
     async fn find_ledger_accounts_by_ibans(&self, ibans: Vec<String>, ledger: &Ledger) -> Result<HashMap<String, Vec<LedgerAccount>>, ServiceError>;
 }
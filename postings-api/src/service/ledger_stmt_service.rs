@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_stmt::LedgerStmt;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait LedgerStmtService {
+    async fn read_stmt(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<LedgerStmt, ServiceError>;
+    async fn create_stmt(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<LedgerStmt, ServiceError>;
+    async fn close_stmt(&self, stmt: LedgerStmt) -> Result<LedgerStmt, ServiceError>;
+}
@@ -1,4 +1,54 @@
+pub mod account_group_service;
+pub mod account_limit_service;
+pub mod account_overview_service;
 pub mod account_stmt_service;
+pub mod api_key_service;
+pub mod backfill_service;
+pub mod balance_forecast_service;
+pub mod balance_service;
+pub mod calendar_service;
+pub mod category_rule_service;
+pub mod chart_of_account_import_service;
 pub mod chart_of_account_service;
+pub mod checkpoint_service;
+pub mod diagnostics_service;
+pub mod document_signer;
+pub mod earmark_service;
+pub mod eod_service;
+pub mod escrow_service;
+pub mod event_publisher;
+pub mod external_ref_service;
+pub mod federated_read_service;
+pub mod fee_schedule_service;
+pub mod fx_rate_provider;
+pub mod fx_revaluation_service;
+pub mod group_reporting_service;
+pub mod hash_chain_verifier;
+pub mod hashing_profile_service;
+pub mod ledger_account_name_service;
+pub mod ledger_account_service;
+pub mod ledger_closure_service;
+pub mod ledger_comparison_service;
+pub mod ledger_event_service;
+pub mod ledger_integrity_service;
 pub mod ledger_service;
+pub mod ledger_stmt_service;
+pub mod object_store;
+pub mod opening_balance_service;
+pub mod operation_details_service;
+pub mod position_service;
+pub mod posting_query_service;
+pub mod posting_search_service;
 pub mod posting_service;
+pub mod product_service;
+pub mod report_renderer;
+pub mod report_schedule_service;
+pub mod reversal_service;
+pub mod scheduled_posting_service;
+pub mod settlement_batch_service;
+pub mod standing_order_service;
+pub mod stmt_delivery_service;
+pub mod stmt_repair_service;
+pub mod sync_service;
+pub mod trial_balance_service;
+pub mod two_phase_posting_service;
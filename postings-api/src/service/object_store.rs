@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::ServiceError;
+
+/// Minimal S3-style object storage used for payloads too large to keep in the database.
+#[async_trait]
+pub trait ObjectStore {
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<(), ServiceError>;
+    /// Returns `None` if no object is stored under `key`.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ServiceError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::opening_balance::{OpeningBalanceCheck, OpeningBalanceReport};
+use crate::domain::quarantined_entry::{ImportMode, QuarantinedEntry};
+use crate::ServiceError;
+
+#[async_trait]
+pub trait OpeningBalanceService {
+    /// Books one balanced posting per CSV row against `migration_account` at `pst_time`,
+    /// then verifies the resulting balances against the source.
+    async fn load_opening_balances(&self, ledger: Ledger, migration_account: LedgerAccount, csv: &str, pst_time: DateTime<Utc>) -> Result<OpeningBalanceReport, ServiceError>;
+    /// Same as `load_opening_balances`; in [`ImportMode::Lenient`] unparsable rows and rows that fail
+    /// to book are quarantined instead of aborting or failing the import. With checkpoints enabled,
+    /// loading a file again skips the lines an earlier, interrupted run of the same file completed.
+    async fn load_opening_balances_with_mode(&self, ledger: Ledger, migration_account: LedgerAccount, csv: &str, pst_time: DateTime<Utc>, mode: ImportMode) -> Result<OpeningBalanceReport, ServiceError>;
+    async fn find_quarantined(&self, ledger: Ledger) -> Result<Vec<QuarantinedEntry>, ServiceError>;
+    /// Loads the corrected record of a pending quarantined entry. On success the entry is marked resubmitted;
+    /// otherwise it stays pending with the new payload and reason.
+    async fn resubmit_quarantined(&self, entry_id: Uuid, corrected_payload: String, migration_account: LedgerAccount, pst_time: DateTime<Utc>) -> Result<OpeningBalanceCheck, ServiceError>;
+    async fn discard_quarantined(&self, entry_id: Uuid) -> Result<QuarantinedEntry, ServiceError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::domain::external_content::ExternalContent;
+use crate::domain::posting::Posting;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait OperationDetailsService {
+    /// Stores the content in object storage and records a reference to it. Storing identical content twice is a no-op.
+    async fn store_details(&self, content: Vec<u8>, media_type: &str) -> Result<ExternalContent, ServiceError>;
+    /// Stores the content, sets its hash as the posting's `opr_details` and records the posting.
+    async fn new_posting_with_details(&self, posting: Posting, content: Vec<u8>, media_type: &str) -> Result<Posting, ServiceError>;
+    /// Loads externally stored details and verifies them against the hash.
+    async fn load_details(&self, content_hash: &[u8; 34]) -> Result<Vec<u8>, ServiceError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::account_position::AccountPosition;
+use crate::domain::currency_position::CurrencyPositionReport;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait PositionService {
+    async fn position(&self, ledger_account: LedgerAccount, value_date: NaiveDate) -> Result<AccountPosition, ServiceError>;
+    /// Position at the end of each day of `from..=to`, lines counted from the value time of their
+    /// posting, or their posting time without one. Lines posted after `to` are left out.
+    async fn position_ladder(&self, ledger_account: LedgerAccount, from: NaiveDate, to: NaiveDate) -> Result<Vec<AccountPosition>, ServiceError>;
+    /// Net booked position of the ledger per currency at the end of `value_date`. Lines without
+    /// a currency count in the currency of their account, lines of accounts without one are left out.
+    async fn currency_positions(&self, ledger: Ledger, value_date: NaiveDate) -> Result<CurrencyPositionReport, ServiceError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::domain::ledger::Ledger;
+use crate::domain::operation_history::OperationHistory;
+use crate::domain::posting::Posting;
+use crate::service::posting_service::Page;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait PostingQueryService {
+    /// Returns all versions of an operation (original, corrections, discarded ones) ordered by record time.
+    async fn operation_history(&self, opr_id: &[u8; 34]) -> Result<OperationHistory, ServiceError>;
+    /// One page of the postings of the ledger, discarded ones included, ordered by record time.
+    /// Postings are returned without their lines.
+    async fn find_postings_by_ledger_paged(&self, ledger: Ledger, page: usize, size: usize) -> Result<Page<Posting>, ServiceError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::domain::posting::Posting;
+use crate::domain::posting_search::PostingSearchFilter;
+use crate::service::posting_service::Page;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait PostingSearchService {
+    /// One page of the postings matching the filter, discarded ones included.
+    /// Postings are returned without their lines.
+    async fn search(&self, filter: PostingSearchFilter) -> Result<Page<Posting>, ServiceError>;
+}
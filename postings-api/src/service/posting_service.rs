@@ -1,21 +1,61 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::posting::Posting;
 use crate::domain::posting_line::PostingLine;
+use crate::domain::privileged_context::PrivilegedContext;
 use crate::ServiceError;
 use uuid::Uuid;
 
+/// Zero-based page `page` of `size` elements, with the number of elements matching overall.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Page<T> {
     pub content: Vec<T>,
+    pub page: usize,
+    pub size: usize,
     pub total_elements: u64,
 }
 
 #[async_trait]
 pub trait PostingService {
+    /// The operation id is the idempotency key: resubmitting a recorded operation returns the
+    /// recorded posting, while different content under the same operation id fails with
+    /// `DuplicateOperation`.
     async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError>;
+    /// Validates and records the postings atomically: either all of them are recorded or none.
+    /// Postings are chained in the given order. Account limits are checked per posting against
+    /// the balances recorded before the batch. Operations already recorded are returned as in
+    /// [`Self::new_posting`]; operation ids must be unique within the batch.
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError>;
+    /// Records a posting without enforcing account limits. The context must name an `Admin`
+    /// principal and a reason, which are logged with the override.
+    async fn new_posting_with_limit_override(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError>;
+    /// Records a back-dated adjustment of posting type `AdjTx`, which passes the period closures
+    /// that allow adjustments. Postings before other closures still fail with
+    /// [`ServiceError::PeriodClosed`]. The context is authorized and logged as for limit overrides.
+    async fn new_adjustment_posting(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError>;
+    /// Records the compensation of the current posting of `opr_id`: mirrored lines referencing the
+    /// original ones, booked under an operation id derived from `opr_id`. Calling it again for an
+    /// already compensated operation returns the existing compensation.
+    async fn compensate_posting(&self, opr_id: &[u8; 34]) -> Result<Posting, ServiceError>;
+    /// Records the storno of posting `posting_id` at `reversal_time`, chained like any new posting.
+    /// The reversal references the posting through `discarded_id` and the posting is marked as
+    /// discarded by the reversal in the same transaction. Fails with `PostingAlreadyDiscarded`
+    /// if the posting was already reversed or replaced.
+    async fn reverse_posting(&self, posting_id: Uuid, reversal_time: DateTime<Utc>) -> Result<Posting, ServiceError>;
+    /// All versions of an operation with their lines, oldest record time first.
     async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError>;
+    /// Latest first: descending posting time, then record time, then line id.
     async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError>;
+    /// Lines of [`Self::find_postings_by_dates`] delivered as they are read, for exports of large accounts.
+    /// Oldest first: ascending posting time, then record time, then line id.
+    async fn stream_postings_by_dates<'a>(&'a self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<BoxStream<'a, Result<PostingLine, ServiceError>>, ServiceError>;
+    /// One page of [`Self::find_postings_by_dates`], in the same order.
     async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError>;
     async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError>;
+    /// Finds a posting line by its id alone, whatever account it belongs to.
+    async fn find_posting_line(&self, line_id: Uuid) -> Result<PostingLine, ServiceError>;
+    /// Every leg of an operation across all its versions, discarded ones included, ordered by record time.
+    async fn find_posting_lines_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<PostingLine>, ServiceError>;
 }
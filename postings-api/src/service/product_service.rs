@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::domain::fee_schedule::FeeSchedule;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::product::{InterestTerms, Product};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ProductService {
+    async fn save_product(&self, product: Product) -> Result<Product, ServiceError>;
+    async fn find_product_by_id(&self, product_id: Uuid) -> Result<Option<Product>, ServiceError>;
+    /// Links the account to a product of its ledger, replacing any previous link.
+    async fn link_account(&self, ledger_account: LedgerAccount, product_id: Uuid) -> Result<Product, ServiceError>;
+    async fn find_account_product(&self, ledger_account: LedgerAccount) -> Result<Option<Product>, ServiceError>;
+    /// Fee schedule applying to operations of `opr_type` debiting the account: the product's, else the ledger default.
+    async fn resolve_fee_schedule(&self, ledger_account: LedgerAccount, opr_type: &[u8; 34]) -> Result<Option<FeeSchedule>, ServiceError>;
+    async fn resolve_interest_terms(&self, ledger_account: LedgerAccount) -> Result<Option<InterestTerms>, ServiceError>;
+}
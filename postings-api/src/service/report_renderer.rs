@@ -0,0 +1,7 @@
+use crate::domain::report_schedule::Report;
+use crate::ServiceError;
+
+/// Lays out a report as a document, for formats not rendered by the library itself such as PDF.
+pub trait ReportRenderer {
+    fn render(&self, report: &Report) -> Result<Vec<u8>, ServiceError>;
+}
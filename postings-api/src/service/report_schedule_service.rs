@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::report_schedule::{ReportArtifact, ReportFormat, ReportSchedule, ReportType};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ReportScheduleService {
+    /// Stores the schedule with its `next_ref_time` moved to the end of the period containing it.
+    async fn create_schedule(&self, schedule: ReportSchedule) -> Result<ReportSchedule, ServiceError>;
+    async fn find_schedule_by_id(&self, schedule_id: Uuid) -> Result<Option<ReportSchedule>, ServiceError>;
+    async fn cancel_schedule(&self, schedule_id: Uuid) -> Result<ReportSchedule, ServiceError>;
+    /// Generates the report of every period ended on or before `as_of`. Called by the scheduler.
+    async fn run_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportArtifact>, ServiceError>;
+    /// Generates the report now and stores it as the next version for its reference time.
+    async fn generate(&self, ledger: Ledger, report_type: ReportType, format: ReportFormat, ref_time: DateTime<Utc>) -> Result<ReportArtifact, ServiceError>;
+    /// All stored formats and versions of the report, oldest version first.
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, ServiceError>;
+    /// The stored report content, checked against the artifact's checksum.
+    async fn load_artifact(&self, artifact_id: Uuid) -> Result<(ReportArtifact, Vec<u8>), ServiceError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::ledger::Ledger;
+use crate::domain::reversal_policy::{Reversal, ReversalContext, ReversalPolicy};
+use crate::ServiceError;
+
+/// Reversals of recorded postings governed by the reversal policy of their ledger.
+#[async_trait]
+pub trait ReversalService {
+    /// Reverses the posting as allowed for the caller's role: a storno within the teller window,
+    /// a correction posting afterwards. Fails with `Forbidden` or `ReversalWindowExpired` otherwise,
+    /// and with `Forbidden` when the caller's key is restricted to another ledger.
+    async fn reverse(&self, posting_id: Uuid, reversal_time: DateTime<Utc>, context: ReversalContext) -> Result<Reversal, ServiceError>;
+    async fn save_reversal_policy(&self, policy: ReversalPolicy) -> Result<ReversalPolicy, ServiceError>;
+    /// The ledger's policy, or [`ReversalPolicy::same_day`] if none was saved.
+    async fn find_reversal_policy(&self, ledger: Ledger) -> Result<ReversalPolicy, ServiceError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::posting::Posting;
+use crate::domain::scheduled_posting::ScheduledPosting;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScheduledPostingService {
+    /// Registers the template. Its lines must balance like the postings generated from it.
+    async fn create_schedule(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, ServiceError>;
+    async fn find_schedule_by_id(&self, schedule_id: Uuid) -> Result<Option<ScheduledPosting>, ServiceError>;
+    async fn cancel_schedule(&self, schedule_id: Uuid) -> Result<ScheduledPosting, ServiceError>;
+    /// Books every occurrence with a posting time up to `as_of`, in posting time order over all
+    /// schedules. Called by the runner.
+    async fn run_due(&self, as_of: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError>;
+}
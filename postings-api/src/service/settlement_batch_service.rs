@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use crate::domain::posting::Posting;
+use crate::domain::settlement_batch::{SettlementBatch, SettlementBatchTotals};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SettlementBatchService {
+    async fn open_batch(&self, batch: SettlementBatch) -> Result<SettlementBatch, ServiceError>;
+    async fn find_batch_by_id(&self, batch_id: Uuid) -> Result<Option<SettlementBatch>, ServiceError>;
+    /// Adds the current version of a recorded posting to an open batch. Fails with
+    /// `PostingAlreadyDiscarded` for discarded postings and with `ReversalNotBatchable` for reversals.
+    async fn assign_posting(&self, batch_id: Uuid, posting: &Posting) -> Result<SettlementBatchTotals, ServiceError>;
+    async fn batch_totals(&self, batch_id: Uuid) -> Result<SettlementBatchTotals, ServiceError>;
+    async fn find_batch_postings(&self, batch_id: Uuid) -> Result<Vec<Uuid>, ServiceError>;
+    async fn close_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError>;
+    async fn settle_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError>;
+    async fn reject_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::standing_order::{StandingOrder, StandingOrderExecution};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StandingOrderService {
+    async fn create_standing_order(&self, order: StandingOrder) -> Result<StandingOrder, ServiceError>;
+    async fn find_standing_order_by_id(&self, order_id: Uuid) -> Result<Option<StandingOrder>, ServiceError>;
+    async fn cancel_standing_order(&self, order_id: Uuid) -> Result<StandingOrder, ServiceError>;
+    async fn find_executions(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, ServiceError>;
+    /// Executes every occurrence due on or before `as_of`, each booked at the start of its execution
+    /// date. Called by the scheduler. An occurrence failing for lack of available balance is retried
+    /// by the next run and skipped after `max_retries` retries. Any other failure is recorded as a
+    /// failed execution and leaves the occurrence due, without holding up the other orders.
+    async fn run_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrderExecution>, ServiceError>;
+}
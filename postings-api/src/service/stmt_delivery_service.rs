@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::domain::account_stmt::AccountStmt;
+use crate::domain::stmt_delivery::{StmtDelivery, StmtDocumentFormat};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtDeliveryService {
+    /// Records the checksum of a rendered statement document and, if `sign` is set, a detached signature.
+    async fn record_delivery(&self, stmt: AccountStmt, format: StmtDocumentFormat, document: &[u8], sign: bool) -> Result<StmtDelivery, ServiceError>;
+    async fn find_deliveries(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, ServiceError>;
+    /// Returns the manifest recorded for the statement whose checksum matches `document`, if any.
+    async fn verify_delivery(&self, stmt_id: Uuid, document: &[u8]) -> Result<Option<StmtDelivery>, ServiceError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::privileged_context::PrivilegedContext;
+use crate::domain::stmt_repair::{StmtDrift, StmtRepair};
+use crate::ServiceError;
+
+/// Detects and repairs closed statements whose stored figures no longer match their posting lines.
+#[async_trait]
+pub trait StmtRepairService {
+    /// Recomputes the closed statements of the account posted in `from..=to` and returns those that drifted.
+    async fn detect_drift(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<StmtDrift>, ServiceError>;
+    /// Replaces the figures of the drifted statements in `from..=to` by the recomputed ones, recording
+    /// one audit entry per repaired statement under the caller's context.
+    async fn repair(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>, context: PrivilegedContext) -> Result<Vec<StmtRepair>, ServiceError>;
+    /// Repairs recorded for the account, most recent first.
+    async fn find_repairs(&self, ledger_account: LedgerAccount) -> Result<Vec<StmtRepair>, ServiceError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use crate::domain::change_set::ChangeSet;
+use crate::ServiceError;
+
+/// Incremental sync for downstream read replicas and caches, over the change events of the core
+/// ledger tables (postings, posting lines, statements, accounts, ...).
+#[async_trait]
+pub trait SyncService {
+    /// Up to `limit` changes with a sequence number greater than `after_seq`, in sequence order,
+    /// archived ones included. Only settled changes are returned, so a replica syncing from
+    /// `next_seq` never skips a change committed late with a lower sequence number.
+    ///
+    /// `entity_types` restricts the changes to the given tables, e.g. `posting` or
+    /// `account_stmt`; empty returns all.
+    async fn changes_since(&self, after_seq: i64, limit: i64, entity_types: &[String]) -> Result<ChangeSet, ServiceError>;
+}
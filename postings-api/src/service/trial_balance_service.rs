@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::ledger::Ledger;
+use crate::domain::trial_balance::{TrialBalance, TrialBalanceDrillDown};
+use uuid::Uuid;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait TrialBalanceService {
+    /// Balances of all accounts of the ledger at `ref_time`, grouped by account category. An
+    /// unbalanced ledger is reported through [`TrialBalance::balanced`], not as an error.
+    async fn trial_balance(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<TrialBalance, ServiceError>;
+    /// Page `page` of the lines behind the report's line of `account_id`, posted after `from`
+    /// up to the report's `ref_time`. Fails with `LedgerAccountNotFound` when the report has no
+    /// line for the account.
+    async fn drill_down(&self, trial_balance: &TrialBalance, account_id: Uuid, from: DateTime<Utc>, page: usize, size: usize) -> Result<TrialBalanceDrillDown, ServiceError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::posting::Posting;
+use crate::domain::prepared_posting::PreparedPosting;
+use crate::ServiceError;
+use uuid::Uuid;
+
+/// Posting persistence split into prepare and commit/abort phases, for ledgers taking part in
+/// sagas orchestrated by an external system such as a payment engine.
+#[async_trait]
+pub trait TwoPhasePostingService {
+    /// Runs every check of `new_posting` and persists the posting in the prepared state. Nothing is
+    /// posted to the ledger; the prepared posting is aborted automatically after `timeout`.
+    async fn prepare_posting(&self, posting: Posting, coordinator_ref: String, timeout: Duration) -> Result<PreparedPosting, ServiceError>;
+    /// Records the prepared posting. Account limits are checked again against the current balances.
+    async fn commit_prepared(&self, prepared_id: Uuid) -> Result<Posting, ServiceError>;
+    /// Aborts a prepared posting. Aborting an already aborted posting is a no-op.
+    async fn abort_prepared(&self, prepared_id: Uuid) -> Result<PreparedPosting, ServiceError>;
+    async fn find_prepared(&self, prepared_id: Uuid) -> Result<Option<PreparedPosting>, ServiceError>;
+    /// Aborts up to `limit` prepared postings that expired on or before `as_of`. Returns the number of aborted postings.
+    async fn abort_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<usize, ServiceError>;
+}
+
+/// Callbacks around the phases of prepared postings, e.g. to notify the coordinator.
+#[async_trait]
+pub trait TwoPhaseHook {
+    /// Called before the prepared posting is persisted. An error vetoes the prepare.
+    async fn on_prepare(&self, prepared: &PreparedPosting) -> Result<(), ServiceError>;
+    async fn on_commit(&self, prepared: &PreparedPosting, posting: &Posting);
+    async fn on_abort(&self, prepared: &PreparedPosting);
+}
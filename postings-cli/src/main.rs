@@ -0,0 +1,74 @@
+//! Operator commands run against a Postgres ledger database.
+
+use std::process::ExitCode;
+use std::sync::Arc;
+use clap::{Parser, Subcommand};
+use postings_api::service::ledger_integrity_service::LedgerIntegrityService;
+use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+use postings_db_postgres::repositories::stmt_repair_repository::PostgresStmtRepairRepository;
+use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
+use postings_logic::services::ledger_integrity_service::LedgerIntegrityServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use postings_logic::services::stmt_repair_service::StmtRepairServiceImpl;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Parser)]
+#[command(name = "postings", about = "Ledger posting operator commands")]
+struct Cli {
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs all integrity checks of a ledger and prints the report as JSON. Exits with 1 when
+    /// any check fails.
+    VerifyLedger { ledger_id: Uuid },
+}
+
+fn create_shared(pool: &PgPool) -> SharedService {
+    SharedService::new(
+        Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+        Arc::new(PostgresLedgerRepository::new(pool.clone())),
+        Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+        Arc::new(PostgresNamedRepository::new(pool.clone())),
+        Arc::new(PostgresPostingRepository::new(pool.clone())),
+        Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+        Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+        Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+    )
+}
+
+async fn verify_ledger(pool: &PgPool, ledger_id: Uuid) -> anyhow::Result<bool> {
+    let shared = create_shared(pool);
+    let service = LedgerIntegrityServiceImpl::new(
+        shared.clone(),
+        Arc::new(HashChainVerifierImpl::new(shared.clone())),
+        Arc::new(StmtRepairServiceImpl::new(shared, Arc::new(PostgresStmtRepairRepository::new(pool.clone())))),
+    );
+    let report = service.verify_ledger(ledger_id).await?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(report.is_intact())
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<ExitCode> {
+    dotenvy::dotenv().ok();
+    env_logger::init();
+    let cli = Cli::parse();
+    let pool = PgPool::connect(&cli.database_url).await?;
+    let intact = match cli.command {
+        Command::VerifyLedger { ledger_id } => verify_ledger(&pool, ledger_id).await?,
+    };
+    Ok(if intact { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
@@ -0,0 +1,202 @@
+//! Deterministic exploration of the interleavings of concurrent service calls.
+//!
+//! The `Interleaved*` repository decorators suspend the calling task before every repository
+//! call, making each call a point where another task may run first. An [`Explorer`] runs the
+//! tasks of a [`Scenario`] on the current thread and decides at each such point which task
+//! continues, enumerating the schedules with up to a bounded number of preemptions. A run that
+//! breaks the scenario's invariant is reported with its [`Schedule`], and [`replay`] repeats
+//! exactly that run.
+//!
+//! Tasks must only suspend at yield points; there is no runtime driving timers or I/O.
+
+mod repositories;
+
+pub use repositories::{InterleavedAccountStmtRepository, InterleavedPostingRepository, InterleavedUnitOfWorkRepository};
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::executor::block_on;
+use futures::future::LocalBoxFuture;
+use futures::task::{waker, ArcWake};
+
+pub type Task = LocalBoxFuture<'static, ()>;
+
+/// Tasks to run concurrently and the invariant to check once all of them are done.
+pub struct Scenario {
+    pub tasks: Vec<Task>,
+    pub check: LocalBoxFuture<'static, Result<(), String>>,
+}
+
+/// Index of the task run at each step of a run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule(pub Vec<usize>);
+
+/// A run whose scenario check failed.
+#[derive(Debug)]
+pub struct Failure {
+    pub schedule: Schedule,
+    pub message: String,
+}
+
+/// Suspends the calling task once, so the scheduler may run another task first.
+pub async fn yield_point() {
+    YieldNow { yielded: false }.await
+}
+
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+struct Wakeup(AtomicBool);
+
+impl ArcWake for Wakeup {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+pub struct Explorer {
+    max_preemptions: usize,
+    max_runs: usize,
+}
+
+impl Default for Explorer {
+    fn default() -> Self {
+        Self { max_preemptions: 2, max_runs: 100_000 }
+    }
+}
+
+impl Explorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bounds how often a run switches away from a task that could continue, 2 by default.
+    /// Most races show with one or two such switches, and the number of runs grows steeply with
+    /// the bound.
+    pub fn with_max_preemptions(mut self, max_preemptions: usize) -> Self {
+        self.max_preemptions = max_preemptions;
+        self
+    }
+
+    /// Stops exploring after `max_runs` runs.
+    pub fn with_max_runs(mut self, max_runs: usize) -> Self {
+        self.max_runs = max_runs;
+        self
+    }
+
+    /// Runs a fresh scenario under each schedule within the bounds, depth first, until one fails
+    /// its check. Returns the number of runs if none did.
+    pub fn explore<S, F>(&self, mut scenario: S) -> Result<usize, Failure>
+    where
+        S: FnMut() -> F,
+        F: Future<Output = Scenario>,
+    {
+        // Index into the options of each step, the current task coming first
+        let mut forced: Vec<usize> = Vec::new();
+        let mut runs = 0;
+        loop {
+            let mut decisions: Vec<(usize, usize)> = Vec::new();
+            let mut preemptions = 0;
+            let (schedule, result) = run(block_on(scenario()), |current, runnable| {
+                let options: Vec<usize> = match current.filter(|task| runnable.contains(task)) {
+                    Some(current) if preemptions < self.max_preemptions => std::iter::once(current)
+                        .chain(runnable.iter().copied().filter(|&task| task != current))
+                        .collect(),
+                    Some(current) => vec![current],
+                    None => runnable.to_vec(),
+                };
+                let choice = forced.get(decisions.len()).copied().unwrap_or(0);
+                decisions.push((choice, options.len()));
+                if current.is_some_and(|current| runnable.contains(&current) && current != options[choice]) {
+                    preemptions += 1;
+                }
+                options[choice]
+            });
+            runs += 1;
+            if let Err(message) = result {
+                return Err(Failure { schedule, message });
+            }
+            while decisions.last().is_some_and(|&(choice, options)| choice + 1 == options) {
+                decisions.pop();
+            }
+            let Some(last) = decisions.last_mut() else {
+                return Ok(runs);
+            };
+            if runs == self.max_runs {
+                return Ok(runs);
+            }
+            last.0 += 1;
+            forced = decisions.iter().map(|&(choice, _)| choice).collect();
+        }
+    }
+}
+
+/// Runs a fresh scenario under `schedule`, e.g. one reported by [`Explorer::explore`]. Past the
+/// end of the schedule, the running task continues.
+pub fn replay<F>(scenario: F, schedule: &Schedule) -> Result<(), String>
+where
+    F: Future<Output = Scenario>,
+{
+    let mut step = 0;
+    let (_, result) = run(block_on(scenario), |current, runnable| {
+        let next = schedule.0
+            .get(step)
+            .copied()
+            .filter(|task| runnable.contains(task))
+            .or(current.filter(|task| runnable.contains(task)))
+            .unwrap_or(runnable[0]);
+        step += 1;
+        next
+    });
+    result
+}
+
+/// Polls the task `choose` picks among the runnable ones until all tasks are done, then checks
+/// the scenario.
+fn run(scenario: Scenario, mut choose: impl FnMut(Option<usize>, &[usize]) -> usize) -> (Schedule, Result<(), String>) {
+    let mut tasks: Vec<Option<Task>> = scenario.tasks.into_iter().map(Some).collect();
+    let wakeups: Vec<Arc<Wakeup>> = tasks.iter().map(|_| Arc::new(Wakeup(AtomicBool::new(true)))).collect();
+    let mut steps = Vec::new();
+    let mut current = None;
+    loop {
+        let runnable: Vec<usize> = (0..tasks.len())
+            .filter(|&task| tasks[task].is_some() && wakeups[task].0.load(Ordering::SeqCst))
+            .collect();
+        if runnable.is_empty() {
+            if tasks.iter().any(Option::is_some) {
+                return (Schedule(steps), Err("A task suspended outside of a yield point".to_string()));
+            }
+            break;
+        }
+        let next = choose(current, &runnable);
+        wakeups[next].0.store(false, Ordering::SeqCst);
+        let task_waker = waker(wakeups[next].clone());
+        let mut cx = Context::from_waker(&task_waker);
+        if let Some(task) = tasks[next].as_mut() {
+            if task.as_mut().poll(&mut cx).is_ready() {
+                tasks[next] = None;
+            }
+        }
+        steps.push(next);
+        current = Some(next);
+    }
+    (Schedule(steps), block_on(scenario.check))
+}
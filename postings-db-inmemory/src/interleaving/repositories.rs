@@ -0,0 +1,168 @@
+//! Repository decorators with a yield point before every call.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_filter::PostingFilter;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::page::{Page, PageRequest};
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::{Committed, UnitOfWork};
+use postings_db::DbError;
+use uuid::Uuid;
+
+use crate::interleaving::yield_point;
+
+/// Posting repository whose calls are scheduling points, covering the chain-head path.
+pub struct InterleavedPostingRepository {
+    inner: Arc<dyn PostingRepository + Send + Sync>,
+}
+
+impl InterleavedPostingRepository {
+    pub fn new(inner: Arc<dyn PostingRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl PostingRepository for InterleavedPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_opr_id_and_discarding_id_is_null(opr_id).await
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_opr_id(opr_id).await
+    }
+
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_first_by_ledger_order_by_record_time_desc(ledger_id).await
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        yield_point().await;
+        self.inner.save(posting).await
+    }
+
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        yield_point().await;
+        self.inner.save_batch(postings).await
+    }
+
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        yield_point().await;
+        self.inner.save_reversal(reversal, lines, reversed_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_ledger_id_paged(ledger_id, page).await
+    }
+
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        yield_point().await;
+        self.inner.find_by_filter_paged(filter, page).await
+    }
+}
+
+/// Statement repository whose calls are scheduling points, covering statement creation and closing.
+pub struct InterleavedAccountStmtRepository {
+    inner: Arc<dyn AccountStmtRepository + Send + Sync>,
+}
+
+impl InterleavedAccountStmtRepository {
+    pub fn new(inner: Arc<dyn AccountStmtRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl AccountStmtRepository for InterleavedAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_first_by_account_and_status_and_pst_time_less_than_ordered(account_id, status, ref_time).await
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, status, ref_time).await
+    }
+
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_last_closed_by_account_and_pst_time_less_than(account_id, ref_time).await
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        yield_point().await;
+        self.inner.delete_expired_simulated_by_ledger_id(ledger_id, as_of).await
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_closed_by_account_and_pst_time_between(account_id, from, to).await
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_closed_by_ledger_id_after(ledger_id, after, limit).await
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        yield_point().await;
+        self.inner.count_closed_by_ledger_id(ledger_id).await
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        yield_point().await;
+        self.inner.update_opening_totals(id, opening_debit, opening_credit, line_count).await
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        yield_point().await;
+        self.inner.save(stmt).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        yield_point().await;
+        self.inner.find_by_id(id).await
+    }
+}
+
+/// Unit-of-work repository whose commits are scheduling points.
+pub struct InterleavedUnitOfWorkRepository {
+    inner: Arc<dyn UnitOfWorkRepository + Send + Sync>,
+}
+
+impl InterleavedUnitOfWorkRepository {
+    pub fn new(inner: Arc<dyn UnitOfWorkRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for InterleavedUnitOfWorkRepository {
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        yield_point().await;
+        self.inner.commit(work).await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod interleaving;
+pub mod repositories;
+pub mod store;
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::account_group_repository::AccountGroupRepository;
+use postings_db::models::account_group::{AccountGroup, AccountGroupMember};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryAccountGroupRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryAccountGroupRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl AccountGroupRepository for InMemoryAccountGroupRepository {
+    async fn save(&self, group: AccountGroup) -> Result<AccountGroup, DbError> {
+        let mut tables = self.store.write();
+        if tables.account_group.values().any(|g| g.ledger_id == group.ledger_id && g.name == group.name) {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.account_group.insert(group.id, group.clone())?;
+        Ok(group)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountGroup>, DbError> {
+        Ok(self.store.read().account_group.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_id_and_name(&self, ledger_id: Uuid, name: &str) -> Result<Option<AccountGroup>, DbError> {
+        Ok(self.store.read().account_group
+            .values()
+            .find(|g| g.ledger_id == ledger_id && g.name == name)
+            .cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<AccountGroup>, DbError> {
+        let mut groups: Vec<AccountGroup> = self.store.read().account_group
+            .values()
+            .filter(|g| g.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(groups)
+    }
+
+    async fn add_member(&self, member: AccountGroupMember) -> Result<(), DbError> {
+        self.store.write().account_group_member.upsert((member.group_id, member.account_id), member);
+        Ok(())
+    }
+
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<bool, DbError> {
+        Ok(self.store.write().account_group_member.remove(&(group_id, account_id)).is_some())
+    }
+
+    async fn find_member_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>, DbError> {
+        let mut ids: Vec<Uuid> = self.store.read().account_group_member
+            .values()
+            .filter(|m| m.group_id == group_id)
+            .map(|m| m.account_id)
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::account_limit_repository::AccountLimitRepository;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryAccountLimitRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryAccountLimitRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl AccountLimitRepository for InMemoryAccountLimitRepository {
+    async fn save(&self, limit: AccountLimit) -> Result<AccountLimit, DbError> {
+        self.store.write().account_limit.upsert(limit.account_id, limit.clone());
+        Ok(limit)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        Ok(self.store.read().account_limit.get(&account_id).cloned())
+    }
+}
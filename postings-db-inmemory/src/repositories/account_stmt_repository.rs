@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryAccountStmtRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryAccountStmtRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    /// Latest statement matching the filter, by posting time then sequence number.
+    fn find_latest(&self, filter: impl Fn(&AccountStmt) -> bool) -> Option<AccountStmt> {
+        self.store.read().account_stmt
+            .values()
+            .filter(|s| filter(s))
+            .max_by(|a, b| (a.pst_time, a.stmt_seq_nbr).cmp(&(b.pst_time, b.stmt_seq_nbr)))
+            .cloned()
+    }
+
+    fn find_closed_by_ledger(&self, ledger_id: Uuid) -> Vec<AccountStmt> {
+        let tables = self.store.read();
+        let accounts = tables.account_ids_of_ledger(ledger_id);
+        tables.account_stmt
+            .values()
+            .filter(|s| s.stmt_status == StmtStatus::Closed && accounts.contains(&s.account_id))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Sets the balance the databases compute from the totals.
+fn with_closing_balance(mut stmt: AccountStmt) -> AccountStmt {
+    stmt.closing_balance = &stmt.total_debit - &stmt.total_credit;
+    stmt
+}
+
+#[async_trait]
+impl AccountStmtRepository for InMemoryAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        Ok(self.find_latest(|s| s.account_id == account_id && s.stmt_status == status && s.pst_time < ref_time))
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        Ok(self.store.read().account_stmt
+            .values()
+            .filter(|s| s.account_id == account_id && s.stmt_status == status && s.pst_time >= ref_time)
+            .min_by(|a, b| (a.pst_time, a.stmt_seq_nbr).cmp(&(b.pst_time, b.stmt_seq_nbr)))
+            .cloned())
+    }
+
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        Ok(self.find_latest(|s| s.account_id == account_id && s.stmt_status == StmtStatus::Closed && s.pst_time < ref_time))
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        let mut tables = self.store.write();
+        let accounts = tables.account_ids_of_ledger(ledger_id);
+        Ok(tables.account_stmt.retain(|s| {
+            !(accounts.contains(&s.account_id)
+                && s.stmt_status == StmtStatus::Simulated
+                && s.expiry.is_some_and(|expiry| expiry <= as_of))
+        }))
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        let mut stmts: Vec<AccountStmt> = self.store.read().account_stmt
+            .values()
+            .filter(|s| s.account_id == account_id && s.stmt_status == StmtStatus::Closed && s.pst_time >= from && s.pst_time <= to)
+            .cloned()
+            .collect();
+        stmts.sort_by_key(|s| (s.pst_time, s.stmt_seq_nbr));
+        Ok(stmts)
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        let mut stmts: Vec<AccountStmt> = self.find_closed_by_ledger(ledger_id)
+            .into_iter()
+            .filter(|s| after.is_none_or(|after| s.id > after))
+            .collect();
+        stmts.sort_by_key(|s| s.id);
+        stmts.truncate(limit.max(0) as usize);
+        Ok(stmts)
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        Ok(self.find_closed_by_ledger(ledger_id).len() as i64)
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        if let Some(stmt) = self.store.write().account_stmt.get_mut(&id) {
+            stmt.opening_debit = opening_debit;
+            stmt.opening_credit = opening_credit;
+            stmt.line_count = line_count;
+        }
+        Ok(())
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        Ok(upsert_account_stmt(&mut self.store.write(), stmt))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        Ok(self.store.read().account_stmt.get(&id).cloned())
+    }
+}
+
+pub(crate) fn upsert_account_stmt(tables: &mut Tables, stmt: AccountStmt) -> AccountStmt {
+    let stmt = with_closing_balance(stmt);
+    tables.account_stmt.upsert(stmt.id, stmt.clone());
+    stmt
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::api_key_repository::ApiKeyRepository;
+use postings_db::models::api_key::ApiKey;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryApiKeyRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryApiKeyRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn save(&self, key: ApiKey) -> Result<ApiKey, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.api_key.get_mut(&key.id) {
+            stored.roles = key.roles;
+            stored.expires = key.expires;
+            stored.revoked_time = key.revoked_time;
+            return Ok(stored.clone());
+        }
+        if tables.api_key.values().any(|k| k.key_prefix == key.key_prefix) {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.api_key.insert(key.id, key.clone())?;
+        Ok(key)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, DbError> {
+        Ok(self.store.read().api_key.get(&id).cloned())
+    }
+
+    async fn find_by_key_prefix(&self, key_prefix: &str) -> Result<Option<ApiKey>, DbError> {
+        Ok(self.store.read().api_key.values().find(|k| k.key_prefix == key_prefix).cloned())
+    }
+
+    async fn find_by_tenant_id(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, DbError> {
+        let mut keys: Vec<ApiKey> = self.store.read().api_key
+            .values()
+            .filter(|k| k.tenant_id == tenant_id)
+            .cloned()
+            .collect();
+        keys.sort_by_key(|k| k.created);
+        Ok(keys)
+    }
+}
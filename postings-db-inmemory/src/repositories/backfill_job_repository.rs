@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::backfill_job_repository::BackfillJobRepository;
+use postings_db::models::backfill_job::{BackfillJob, BackfillStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryBackfillJobRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryBackfillJobRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl BackfillJobRepository for InMemoryBackfillJobRepository {
+    async fn save(&self, job: BackfillJob) -> Result<BackfillJob, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.backfill_job.get_mut(&job.id) {
+            stored.status = job.status;
+            stored.checkpoint = job.checkpoint;
+            stored.processed = job.processed;
+            stored.error_message = job.error_message;
+            stored.updated = job.updated;
+            stored.finished = job.finished;
+            return Ok(stored.clone());
+        }
+        tables.backfill_job.insert(job.id, job.clone())?;
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        Ok(self.store.read().backfill_job.get(&id).cloned())
+    }
+
+    async fn find_unfinished_by_task_and_ledger(&self, task_name: &str, ledger_id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        Ok(self.store.read().backfill_job
+            .values()
+            .filter(|j| j.task_name == task_name && j.ledger_id == ledger_id && j.status != BackfillStatus::Completed)
+            .max_by_key(|j| j.started)
+            .cloned())
+    }
+}
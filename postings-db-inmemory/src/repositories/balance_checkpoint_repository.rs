@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryBalanceCheckpointRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryBalanceCheckpointRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl BalanceCheckpointRepository for InMemoryBalanceCheckpointRepository {
+    async fn save(&self, checkpoint: &BalanceCheckpoint) -> Result<(), DbError> {
+        self.store.write().balance_checkpoint.upsert((checkpoint.account_id, checkpoint.as_of), checkpoint.clone());
+        Ok(())
+    }
+
+    async fn find_last_by_account_and_as_of_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<BalanceCheckpoint>, DbError> {
+        Ok(self.store.read().balance_checkpoint
+            .values()
+            .filter(|c| c.account_id == account_id && c.as_of <= ref_time)
+            .max_by_key(|c| c.as_of)
+            .cloned())
+    }
+
+    async fn delete_by_account_and_as_of_greater_than_equal(&self, account_id: Uuid, from: DateTime<Utc>) -> Result<u64, DbError> {
+        Ok(self.store.write().balance_checkpoint.retain(|c| c.account_id != account_id || c.as_of < from))
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::models::category_rule::CategoryRule;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryCategoryRuleRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryCategoryRuleRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl CategoryRuleRepository for InMemoryCategoryRuleRepository {
+    async fn save(&self, rule: CategoryRule) -> Result<CategoryRule, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.category_rule.get_mut(&rule.id) {
+            // Ledger and creation time stay as first recorded
+            *stored = CategoryRule { ledger_id: stored.ledger_id, created: stored.created, ..rule };
+            return Ok(stored.clone());
+        }
+        tables.category_rule.insert(rule.id, rule.clone())?;
+        Ok(rule)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<CategoryRule>, DbError> {
+        let mut rules: Vec<CategoryRule> = self.store.read().category_rule
+            .values()
+            .filter(|r| r.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        rules.sort_by_key(|r| (r.priority, r.created));
+        Ok(rules)
+    }
+
+    async fn delete_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        self.store.write().category_rule.remove(&id).map(|_| ()).ok_or(DbError::NotFound)
+    }
+}
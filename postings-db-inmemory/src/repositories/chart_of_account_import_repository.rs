@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::chart_of_account_import_repository::ChartOfAccountImportRepository;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::DbError;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryChartOfAccountImportRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryChartOfAccountImportRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountImportRepository for InMemoryChartOfAccountImportRepository {
+    async fn save_all(&self, coa: &ChartOfAccount, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named]) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        // Check every key before writing so a conflict leaves the store untouched.
+        if tables.chart_of_account.contains_key(&coa.id)
+            || tables.ledger.contains_key(&ledger.id)
+            || accounts.iter().any(|a| tables.ledger_account.contains_key(&a.id))
+            || named.iter().any(|n| tables.named.contains_key(&n.id))
+        {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.chart_of_account.insert(coa.id, coa.clone())?;
+        tables.ledger.insert(ledger.id, ledger.clone())?;
+        for account in accounts {
+            tables.ledger_account.insert(account.id, account.clone())?;
+        }
+        for n in named {
+            tables.named.insert(n.id, n.clone())?;
+        }
+        Ok(())
+    }
+}
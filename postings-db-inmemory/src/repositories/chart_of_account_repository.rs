@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryChartOfAccountRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryChartOfAccountRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountRepository for InMemoryChartOfAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, DbError> {
+        Ok(self.store.read().chart_of_account.get(&id).cloned())
+    }
+
+    async fn save(&self, coa: &ChartOfAccount) -> Result<(), DbError> {
+        self.store.write().chart_of_account.insert(coa.id, coa.clone())
+    }
+}
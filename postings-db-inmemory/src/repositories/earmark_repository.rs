@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::earmark_repository::EarmarkRepository;
+use postings_db::models::earmark::{Earmark, EarmarkStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryEarmarkRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryEarmarkRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl EarmarkRepository for InMemoryEarmarkRepository {
+    async fn save(&self, earmark: Earmark) -> Result<Earmark, DbError> {
+        upsert_earmark(&mut self.store.write(), earmark)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Earmark>, DbError> {
+        Ok(self.store.read().earmark.get(&id).cloned())
+    }
+
+    async fn find_by_account_id_and_status(&self, account_id: Uuid, status: EarmarkStatus) -> Result<Vec<Earmark>, DbError> {
+        let mut earmarks: Vec<Earmark> = self.store.read().earmark
+            .values()
+            .filter(|e| e.account_id == account_id && e.status == status)
+            .cloned()
+            .collect();
+        earmarks.sort_by_key(|e| e.created);
+        Ok(earmarks)
+    }
+
+    async fn find_active_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, DbError> {
+        let mut earmarks: Vec<Earmark> = self.store.read().earmark
+            .values()
+            .filter(|e| e.status == EarmarkStatus::Active && e.expiry.is_some_and(|expiry| expiry <= as_of))
+            .cloned()
+            .collect();
+        earmarks.sort_by_key(|e| e.expiry);
+        Ok(earmarks)
+    }
+}
+
+pub(crate) fn upsert_earmark(tables: &mut Tables, earmark: Earmark) -> Result<Earmark, DbError> {
+    if let Some(stored) = tables.earmark.get_mut(&earmark.id) {
+        stored.amount = earmark.amount;
+        stored.captured_amount = earmark.captured_amount;
+        stored.expiry = earmark.expiry;
+        stored.status = earmark.status;
+        stored.closed_time = earmark.closed_time;
+        stored.posting_id = earmark.posting_id;
+        return Ok(stored.clone());
+    }
+    tables.earmark.insert(earmark.id, earmark.clone())?;
+    Ok(earmark)
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use postings_db::repositories::eod_run_repository::EodRunRepository;
+use postings_db::models::eod_run::{EodRun, EodStepResult};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryEodRunRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryEodRunRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl EodRunRepository for InMemoryEodRunRepository {
+    async fn save_run(&self, run: EodRun) -> Result<EodRun, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.eod_run.get_mut(&run.id) {
+            stored.status = run.status;
+            stored.finished = run.finished;
+            return Ok(stored.clone());
+        }
+        if tables.eod_run.values().any(|r| r.ledger_id == run.ledger_id && r.business_date == run.business_date) {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.eod_run.insert(run.id, run.clone())?;
+        Ok(run)
+    }
+
+    async fn find_run_by_ledger_and_business_date(&self, ledger_id: Uuid, business_date: NaiveDate) -> Result<Option<EodRun>, DbError> {
+        Ok(self.store.read().eod_run
+            .values()
+            .find(|r| r.ledger_id == ledger_id && r.business_date == business_date)
+            .cloned())
+    }
+
+    async fn save_step_result(&self, result: EodStepResult) -> Result<EodStepResult, DbError> {
+        self.store.write().eod_step_result.upsert((result.run_id, result.step_seq), result.clone());
+        Ok(result)
+    }
+
+    async fn find_step_results_by_run_id(&self, run_id: Uuid) -> Result<Vec<EodStepResult>, DbError> {
+        let mut results: Vec<EodStepResult> = self.store.read().eod_step_result
+            .values()
+            .filter(|r| r.run_id == run_id)
+            .cloned()
+            .collect();
+        results.sort_by_key(|r| r.step_seq);
+        Ok(results)
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::escrow_repository::EscrowRepository;
+use postings_db::models::escrow::{Escrow, EscrowStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryEscrowRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryEscrowRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl EscrowRepository for InMemoryEscrowRepository {
+    async fn save(&self, escrow: Escrow) -> Result<Escrow, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.escrow.get_mut(&escrow.id) {
+            stored.status = escrow.status;
+            stored.initiation_posting_id = escrow.initiation_posting_id;
+            stored.resolution_posting_id = escrow.resolution_posting_id;
+            stored.resolved_time = escrow.resolved_time;
+            return Ok(stored.clone());
+        }
+        tables.escrow.insert(escrow.id, escrow.clone())?;
+        Ok(escrow)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Escrow>, DbError> {
+        Ok(self.store.read().escrow.get(&id).cloned())
+    }
+
+    async fn resolve(&self, id: Uuid, status: EscrowStatus, resolved_time: DateTime<Utc>) -> Result<bool, DbError> {
+        let mut tables = self.store.write();
+        match tables.escrow.get_mut(&id) {
+            Some(stored) if stored.status == EscrowStatus::Pending => {
+                stored.status = status;
+                stored.resolved_time = Some(resolved_time);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn find_pending_timed_out(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, DbError> {
+        let mut escrows: Vec<Escrow> = self.store.read().escrow
+            .values()
+            .filter(|e| e.status == EscrowStatus::Pending && e.timeout <= as_of)
+            .cloned()
+            .collect();
+        escrows.sort_by_key(|e| e.timeout);
+        Ok(escrows)
+    }
+}
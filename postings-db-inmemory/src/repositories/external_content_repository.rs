@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::external_content_repository::ExternalContentRepository;
+use postings_db::models::external_content::ExternalContent;
+use postings_db::DbError;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryExternalContentRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryExternalContentRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ExternalContentRepository for InMemoryExternalContentRepository {
+    async fn save(&self, content: ExternalContent) -> Result<ExternalContent, DbError> {
+        let mut tables = self.store.write();
+        if let Some(existing) = tables.external_content.get(&content.content_hash) {
+            return Ok(existing.clone());
+        }
+        tables.external_content.insert(content.content_hash, content.clone())?;
+        Ok(content)
+    }
+
+    async fn find_by_content_hash(&self, content_hash: &[u8]) -> Result<Option<ExternalContent>, DbError> {
+        Ok(self.store.read().external_content
+            .values()
+            .find(|c| c.content_hash.as_slice() == content_hash)
+            .cloned())
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::external_ref::{ExternalRef, ExternalRefEntity};
+use postings_db::repositories::external_ref_repository::ExternalRefRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryExternalRefRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryExternalRefRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ExternalRefRepository for InMemoryExternalRefRepository {
+    async fn save(&self, external_ref: &ExternalRef) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        let taken = tables.external_ref.values().any(|r| {
+            r.entity_type == external_ref.entity_type
+                && ((r.entity_id == external_ref.entity_id && r.ref_system == external_ref.ref_system)
+                    || (r.ref_system == external_ref.ref_system
+                        && r.reference == external_ref.reference
+                        && r.unique_scope.is_some()
+                        && r.unique_scope == external_ref.unique_scope))
+        });
+        if taken {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.external_ref.insert(external_ref.id, external_ref.clone())
+    }
+
+    async fn find_by_entity(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, DbError> {
+        let mut refs: Vec<ExternalRef> = self.store.read().external_ref
+            .values()
+            .filter(|r| r.entity_type == entity_type && r.entity_id == entity_id)
+            .cloned()
+            .collect();
+        refs.sort_by(|a, b| a.ref_system.cmp(&b.ref_system));
+        Ok(refs)
+    }
+
+    async fn find_by_ledger_and_reference(&self, ledger_id: Uuid, entity_type: ExternalRefEntity, ref_system: &str, reference: &str) -> Result<Vec<ExternalRef>, DbError> {
+        let mut refs: Vec<ExternalRef> = self.store.read().external_ref
+            .values()
+            .filter(|r| r.ledger_id == ledger_id && r.entity_type == entity_type && r.ref_system == ref_system && r.reference == reference)
+            .cloned()
+            .collect();
+        refs.sort_by_key(|r| (r.created, r.id));
+        Ok(refs)
+    }
+}
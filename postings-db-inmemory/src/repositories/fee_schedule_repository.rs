@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use postings_db::models::fee_schedule::{FeeSchedule, FeeTier};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryFeeScheduleRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryFeeScheduleRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl FeeScheduleRepository for InMemoryFeeScheduleRepository {
+    async fn save(&self, schedule: FeeSchedule) -> Result<FeeSchedule, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.fee_schedule.get_mut(&schedule.id) {
+            stored.fee_type = schedule.fee_type;
+            stored.flat_amount = schedule.flat_amount;
+            stored.rate = schedule.rate;
+            stored.min_fee = schedule.min_fee;
+            stored.max_fee = schedule.max_fee;
+            stored.fee_account_id = schedule.fee_account_id;
+            return Ok(stored.clone());
+        }
+        // One ledger default and one schedule per product for each operation type
+        let conflicting = tables.fee_schedule.values().any(|s| {
+            s.opr_type == schedule.opr_type && match schedule.product_id {
+                Some(product_id) => s.product_id == Some(product_id),
+                None => s.product_id.is_none() && s.ledger_id == schedule.ledger_id,
+            }
+        });
+        if conflicting {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.fee_schedule.insert(schedule.id, schedule.clone())?;
+        Ok(schedule)
+    }
+
+    async fn find_by_ledger_id_and_opr_type(&self, ledger_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        Ok(self.store.read().fee_schedule
+            .values()
+            .find(|s| s.ledger_id == ledger_id && s.opr_type.as_slice() == opr_type && s.product_id.is_none())
+            .cloned())
+    }
+
+    async fn find_by_product_id_and_opr_type(&self, product_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        Ok(self.store.read().fee_schedule
+            .values()
+            .find(|s| s.product_id == Some(product_id) && s.opr_type.as_slice() == opr_type)
+            .cloned())
+    }
+
+    async fn save_tiers(&self, schedule_id: Uuid, tiers: Vec<FeeTier>) -> Result<Vec<FeeTier>, DbError> {
+        let mut sorted: Vec<FeeTier> = tiers.iter().map(|t| FeeTier { schedule_id, ..t.clone() }).collect();
+        sorted.sort_by(|a, b| a.from_amount.cmp(&b.from_amount));
+        if sorted.windows(2).any(|w| w[0].from_amount == w[1].from_amount) {
+            return Err(DbError::UniqueViolation);
+        }
+        self.store.write().fee_tier.upsert(schedule_id, sorted);
+        Ok(tiers)
+    }
+
+    async fn find_tiers_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<FeeTier>, DbError> {
+        Ok(self.store.read().fee_tier.get(&schedule_id).cloned().unwrap_or_default())
+    }
+}
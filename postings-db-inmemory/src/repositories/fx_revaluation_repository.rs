@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::fx_revaluation::FxRevaluation;
+use postings_db::repositories::fx_revaluation_repository::FxRevaluationRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryFxRevaluationRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryFxRevaluationRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl FxRevaluationRepository for InMemoryFxRevaluationRepository {
+    async fn save(&self, revaluation: &FxRevaluation) -> Result<(), DbError> {
+        self.store.write().fx_revaluation.insert((revaluation.account_id, revaluation.valuation_time), revaluation.clone())
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<FxRevaluation>, DbError> {
+        let mut revaluations: Vec<FxRevaluation> = self.store.read().fx_revaluation
+            .values()
+            .filter(|r| r.account_id == account_id)
+            .cloned()
+            .collect();
+        revaluations.sort_by(|a, b| b.valuation_time.cmp(&a.valuation_time));
+        Ok(revaluations)
+    }
+}
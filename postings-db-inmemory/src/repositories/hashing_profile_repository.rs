@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use postings_db::models::hashing_profile::HashingProfile;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryHashingProfileRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryHashingProfileRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl HashingProfileRepository for InMemoryHashingProfileRepository {
+    async fn save(&self, profile: HashingProfile) -> Result<HashingProfile, DbError> {
+        self.store.write().hashing_profile.upsert(profile.ledger_id, profile.clone());
+        Ok(profile)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<HashingProfile>, DbError> {
+        Ok(self.store.read().hashing_profile.get(&ledger_id).cloned())
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db::models::holiday::Holiday;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryHolidayRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryHolidayRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn find_where(&self, filter: impl Fn(&Holiday) -> bool) -> Vec<Holiday> {
+        let mut holidays: Vec<Holiday> = self.store.read().holiday.values().filter(|h| filter(h)).cloned().collect();
+        holidays.sort_by_key(|h| h.holiday_date);
+        holidays
+    }
+}
+
+#[async_trait]
+impl HolidayRepository for InMemoryHolidayRepository {
+    async fn save(&self, holiday: Holiday) -> Result<Holiday, DbError> {
+        self.store.write().holiday.upsert((holiday.ledger_id, holiday.holiday_date), holiday.clone());
+        Ok(holiday)
+    }
+
+    async fn delete(&self, ledger_id: Uuid, holiday_date: NaiveDate) -> Result<(), DbError> {
+        self.store.write().holiday.remove(&(ledger_id, holiday_date));
+        Ok(())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Holiday>, DbError> {
+        Ok(self.find_where(|h| h.ledger_id == ledger_id))
+    }
+
+    async fn find_by_ledger_id_and_date_between(&self, ledger_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, DbError> {
+        Ok(self.find_where(|h| h.ledger_id == ledger_id && h.holiday_date >= from && h.holiday_date <= to))
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::repositories::import_checkpoint_repository::ImportCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryImportCheckpointRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryImportCheckpointRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ImportCheckpointRepository for InMemoryImportCheckpointRepository {
+    async fn save(&self, checkpoint: &ImportCheckpoint) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.import_checkpoint.get_mut(&checkpoint.id) {
+            stored.next_line = checkpoint.next_line;
+            stored.loaded_count = checkpoint.loaded_count;
+            stored.quarantined_count = checkpoint.quarantined_count;
+            stored.completed = checkpoint.completed;
+            stored.updated = checkpoint.updated;
+            return Ok(());
+        }
+        let taken = tables.import_checkpoint.values().any(|c| {
+            c.ledger_id == checkpoint.ledger_id && c.import_type == checkpoint.import_type && c.source_hash == checkpoint.source_hash
+        });
+        if taken {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.import_checkpoint.insert(checkpoint.id, checkpoint.clone())
+    }
+
+    async fn find_by_source(&self, ledger_id: Uuid, import_type: &str, source_hash: &[u8; 34]) -> Result<Option<ImportCheckpoint>, DbError> {
+        Ok(self.store.read().import_checkpoint
+            .values()
+            .find(|c| c.ledger_id == ledger_id && c.import_type == import_type && &c.source_hash == source_hash)
+            .cloned())
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::ledger_account_name::LedgerAccountName;
+use postings_db::repositories::ledger_account_name_repository::LedgerAccountNameRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryLedgerAccountNameRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerAccountNameRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountNameRepository for InMemoryLedgerAccountNameRepository {
+    async fn save(&self, name: &LedgerAccountName) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.ledger_account_name.get_mut(&name.id) {
+            stored.valid_to = name.valid_to;
+            return Ok(());
+        }
+        tables.ledger_account_name.insert(name.id, name.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccountName>, DbError> {
+        Ok(self.store.read().ledger_account_name.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_and_value(&self, ledger_id: Uuid, value: &str) -> Result<Vec<LedgerAccountName>, DbError> {
+        Ok(self.store.read().ledger_account_name
+            .values()
+            .filter(|n| n.ledger_id == ledger_id && (n.name == value || n.number.as_deref() == Some(value)))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<LedgerAccountName>, DbError> {
+        let mut names: Vec<LedgerAccountName> = self.store.read().ledger_account_name
+            .values()
+            .filter(|n| n.account_id == account_id)
+            .cloned()
+            .collect();
+        names.sort_by(|a, b| b.valid_from.cmp(&a.valid_from));
+        Ok(names)
+    }
+}
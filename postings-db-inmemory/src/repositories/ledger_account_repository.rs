@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryLedgerAccountRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerAccountRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for InMemoryLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        Ok(self.store.read().ledger_account.get(&id).cloned())
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
+        self.store.write().ledger_account.insert(ledger_account.id, ledger_account.clone())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        let mut accounts: Vec<LedgerAccount> = self.store.read().ledger_account
+            .values()
+            .filter(|a| a.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        accounts.sort_by_key(|a| a.id);
+        Ok(accounts)
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError> {
+        Ok(Page::from_all(self.find_by_ledger_id(ledger_id).await?, page))
+    }
+
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        let tables = self.store.read();
+        let mut descendants = Vec::new();
+        let mut parents = vec![id];
+        while let Some(parent_id) = parents.pop() {
+            for account in tables.ledger_account.values().filter(|a| a.parent_id == Some(parent_id)) {
+                parents.push(account.id);
+                descendants.push(account.clone());
+            }
+        }
+        descendants.sort_by_key(|a| a.id);
+        Ok(descendants)
+    }
+}
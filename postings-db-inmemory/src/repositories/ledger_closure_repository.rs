@@ -0,0 +1,35 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::ledger_closure::LedgerClosure;
+use postings_db::repositories::ledger_closure_repository::LedgerClosureRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryLedgerClosureRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerClosureRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerClosureRepository for InMemoryLedgerClosureRepository {
+    async fn save(&self, closure: &LedgerClosure) -> Result<(), DbError> {
+        self.store.write().ledger_closure.insert(closure.id, closure.clone())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerClosure>, DbError> {
+        let mut closures: Vec<LedgerClosure> = self.store.read().ledger_closure
+            .values()
+            .filter(|c| c.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        closures.sort_by_key(|c| (Reverse(c.cut_off), Reverse(c.created)));
+        Ok(closures)
+    }
+}
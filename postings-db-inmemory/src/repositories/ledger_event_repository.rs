@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use postings_db::models::ledger_event::LedgerEvent;
+use postings_db::DbError;
+use crate::store::InMemoryStore;
+
+/// Reads the events appended with [`InMemoryStore::append_event`]; writes through the other
+/// in-memory repositories do not record events.
+pub struct InMemoryLedgerEventRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerEventRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerEventRepository for InMemoryLedgerEventRepository {
+    async fn find_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        Ok(self.store.read().ledger_event
+            .range(after_seq + 1..)
+            .take(limit.max(0) as usize)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn find_max_seq(&self) -> Result<Option<i64>, DbError> {
+        Ok(self.store.read().ledger_event.keys().next_back().copied())
+    }
+
+    async fn find_archived_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        Ok(self.store.read().ledger_event_archive
+            .range(after_seq + 1..)
+            .take(limit.max(0) as usize)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn archive_up_to(&self, up_to_seq: i64, created_before: DateTime<Utc>) -> Result<u64, DbError> {
+        let mut tables = self.store.write();
+        let archived: Vec<i64> = tables.ledger_event
+            .range(..=up_to_seq)
+            .filter(|(_, e)| e.created < created_before)
+            .map(|(seq, _)| *seq)
+            .collect();
+        for seq in archived.iter() {
+            if let Some(event) = tables.ledger_event.remove(seq) {
+                tables.ledger_event_archive.entry(*seq).or_insert(event);
+            }
+        }
+        Ok(archived.len() as u64)
+    }
+
+    async fn save_consumer_position(&self, consumer: &str, last_seq: i64, updated: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        let last_seq = tables.ledger_event_consumer
+            .get(&consumer.to_string())
+            .map_or(last_seq, |(stored, _)| last_seq.max(*stored));
+        tables.ledger_event_consumer.upsert(consumer.to_string(), (last_seq, updated));
+        Ok(())
+    }
+
+    async fn find_consumer_seq(&self, consumer: &str) -> Result<Option<i64>, DbError> {
+        Ok(self.store.read().ledger_event_consumer.get(&consumer.to_string()).map(|(seq, _)| *seq))
+    }
+
+    async fn find_min_consumer_seq(&self) -> Result<Option<i64>, DbError> {
+        Ok(self.store.read().ledger_event_consumer.values().map(|(seq, _)| *seq).min())
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::models::ledger::Ledger;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryLedgerRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for InMemoryLedgerRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
+        Ok(self.store.read().ledger.get(&id).cloned())
+    }
+
+    async fn save(&self, ledger: &Ledger) -> Result<(), DbError> {
+        // Ledgers are created writable, balanced and without closing summaries, as with the column defaults of the databases
+        self.store.write().ledger.insert(ledger.id, Ledger { read_only: false, memo: false, closing_summary: false, ..ledger.clone() })
+    }
+
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        let ledger = tables.ledger.get_mut(&id).ok_or(DbError::NotFound)?;
+        ledger.read_only = read_only;
+        Ok(())
+    }
+
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        let ledger = tables.ledger.get_mut(&id).ok_or(DbError::NotFound)?;
+        ledger.memo = memo;
+        Ok(())
+    }
+
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError> {
+        let mut tables = self.store.write();
+        let ledger = tables.ledger.get_mut(&id).ok_or(DbError::NotFound)?;
+        ledger.closing_summary = closing_summary;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::ledger_stmt_repository::LedgerStmtRepository;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryLedgerStmtRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryLedgerStmtRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerStmtRepository for InMemoryLedgerStmtRepository {
+    async fn find_last_closed_by_ledger_and_pst_time_less_than(&self, ledger_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<LedgerStmt>, DbError> {
+        Ok(self.store.read().ledger_stmt
+            .values()
+            .filter(|s| s.ledger_id == ledger_id && s.stmt_status == StmtStatus::Closed && s.pst_time < ref_time)
+            .max_by(|a, b| (a.pst_time, a.stmt_seq_nbr).cmp(&(b.pst_time, b.stmt_seq_nbr)))
+            .cloned())
+    }
+
+    async fn save(&self, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+        upsert_ledger_stmt(&mut self.store.write(), stmt)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerStmt>, DbError> {
+        Ok(self.store.read().ledger_stmt.get(&id).cloned())
+    }
+}
+
+pub(crate) fn upsert_ledger_stmt(tables: &mut Tables, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+    if let Some(stored) = tables.ledger_stmt.get_mut(&stmt.id) {
+        // The ledger stays as first recorded
+        *stored = LedgerStmt { ledger_id: stored.ledger_id, ..stmt };
+        return Ok(stored.clone());
+    }
+    tables.ledger_stmt.insert(stmt.id, stmt.clone())?;
+    Ok(stmt)
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::models::named::{ContainerType, Named};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryNamedRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryNamedRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn find_where(&self, filter: impl Fn(&Named) -> bool) -> Vec<Named> {
+        self.store.read().named.values().filter(|n| filter(n)).cloned().collect()
+    }
+}
+
+#[async_trait]
+impl NamedRepository for InMemoryNamedRepository {
+    async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<Named>, DbError> {
+        Ok(self.find_where(|n| n.container == container_id))
+    }
+
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, DbError> {
+        Ok(self.find_where(|n| n.name == name && n.container_type == container_type))
+    }
+
+    async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerType, context: Uuid) -> Result<Vec<Named>, DbError> {
+        Ok(self.find_where(|n| n.name == name && n.container_type == container_type && n.context == context))
+    }
+
+    async fn save(&self, named: Named) -> Result<Named, DbError> {
+        self.store.write().named.upsert(named.id, named.clone());
+        Ok(named)
+    }
+}
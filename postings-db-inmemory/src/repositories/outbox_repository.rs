@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::outbox_entry::OutboxEntry;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryOutboxRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryOutboxRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for InMemoryOutboxRepository {
+    async fn save(&self, entry: &OutboxEntry) -> Result<(), DbError> {
+        insert_outbox_entry(&mut self.store.write(), entry)
+    }
+
+    async fn find_undelivered(&self, limit: i64) -> Result<Vec<OutboxEntry>, DbError> {
+        let mut entries: Vec<OutboxEntry> = self.store.read().outbox
+            .values()
+            .filter(|e| e.delivered.is_none())
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| (e.created, e.id));
+        entries.truncate(limit.max(0) as usize);
+        Ok(entries)
+    }
+
+    async fn mark_delivered(&self, id: Uuid, delivered: DateTime<Utc>) -> Result<(), DbError> {
+        if let Some(entry) = self.store.write().outbox.get_mut(&id) {
+            entry.delivered = Some(delivered);
+        }
+        Ok(())
+    }
+
+    async fn record_failure(&self, id: Uuid, error: &str) -> Result<(), DbError> {
+        if let Some(entry) = self.store.write().outbox.get_mut(&id) {
+            entry.attempts += 1;
+            entry.last_error = Some(error.to_string());
+        }
+        Ok(())
+    }
+
+    async fn delete_delivered_before(&self, before: DateTime<Utc>) -> Result<u64, DbError> {
+        let mut tables = self.store.write();
+        let ids: Vec<Uuid> = tables.outbox
+            .values()
+            .filter(|e| e.delivered.is_some_and(|delivered| delivered < before))
+            .map(|e| e.id)
+            .collect();
+        for id in ids.iter() {
+            tables.outbox.remove(id);
+        }
+        Ok(ids.len() as u64)
+    }
+}
+
+pub(crate) fn insert_outbox_entry(tables: &mut Tables, entry: &OutboxEntry) -> Result<(), DbError> {
+    tables.outbox.insert(entry.id, entry.clone())
+}
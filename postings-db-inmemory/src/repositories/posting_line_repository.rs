@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryPostingLineRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryPostingLineRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn find_where(&self, filter: impl Fn(&PostingLine) -> bool) -> Vec<PostingLine> {
+        self.store.read().posting_line.values().filter(|l| filter(l)).cloned().collect()
+    }
+
+    /// Lines matching the filter in `order`, read eagerly and handed out as a stream.
+    fn stream_where(&self, filter: impl Fn(&PostingLine) -> bool, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let tables = self.store.read();
+        let mut lines: Vec<PostingLine> = tables.posting_line.values().filter(|l| filter(l)).cloned().collect();
+        sort_in_order(&tables, &mut lines, order);
+        stream::iter(lines.into_iter().map(Ok)).boxed()
+    }
+}
+
+/// Latest first, as the `find_*_between` queries.
+fn sort_latest_first(lines: &mut [PostingLine]) {
+    lines.sort_by(|a, b| (b.pst_time, b.record_time, b.id).cmp(&(a.pst_time, a.record_time, a.id)));
+}
+
+fn sort_in_order(tables: &Tables, lines: &mut [PostingLine], order: LineOrder) {
+    match order {
+        LineOrder::PostingTime => lines.sort_by_key(|l| (l.pst_time, l.record_time, l.id)),
+        LineOrder::ValueTime => {
+            let val_times: HashMap<[u8; 34], DateTime<Utc>> = tables.posting
+                .values()
+                .filter(|p| p.discarding_id.is_none())
+                .filter_map(|p| p.val_time.map(|val_time| (p.opr_id, val_time)))
+                .collect();
+            lines.sort_by_key(|l| (val_times.get(&l.opr_id).copied().unwrap_or(l.pst_time), l.pst_time, l.record_time, l.id));
+        }
+    }
+}
+
+#[async_trait]
+impl PostingLineRepository for InMemoryPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        insert_posting_line(&mut self.store.write(), &posting_line)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        Ok(self.store.read().posting_line.get(&id).cloned())
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find_where(|l| l.account_id == account_id && l.pst_time > from && l.pst_time <= to && l.discarded_time.is_none());
+        sort_latest_first(&mut lines);
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        Ok(Page::from_all(self.find_by_account_and_pst_time_between(account_id, from, to).await?, page))
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        Ok(self.store.read().posting_line.get(&id).filter(|l| l.account_id == account_id).cloned())
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find_where(|l| l.base_line == Some(base_line) && l.pst_time <= ref_time && l.discarded_time.is_none());
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find_where(|l| l.opr_id.as_slice() == opr_id);
+        lines.sort_by_key(|l| (l.record_time, l.id));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find_where(|l| l.account_id == account_id && l.pst_time <= ref_time && l.discarded_time.is_none());
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find_where(|l| l.account_id == account_id && l.pst_time <= ref_time && l.record_time <= known_at);
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        let lines = self.find_where(|l| l.account_id == account_id && l.discarded_time.is_none());
+        Ok(AccountLineStats {
+            line_count: lines.len() as i64,
+            first_pst_time: lines.iter().map(|l| l.pst_time).min(),
+            last_pst_time: lines.iter().map(|l| l.pst_time).max(),
+            last_opr_id: lines.iter().max_by_key(|l| (l.record_time, l.id)).map(|l| l.opr_id),
+        })
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        let lines = self.find_where(|l| account_ids.contains(&l.account_id) && l.pst_time <= ref_time && l.discarded_time.is_none());
+        let mut totals: Vec<AccountLineTotals> = Vec::new();
+        for line in lines {
+            match totals.iter_mut().find(|t| t.account_id == line.account_id) {
+                Some(t) => {
+                    t.total_debit += line.debit_amount;
+                    t.total_credit += line.credit_amount;
+                }
+                None => totals.push(AccountLineTotals { account_id: line.account_id, total_debit: line.debit_amount, total_credit: line.credit_amount }),
+            }
+        }
+        Ok(totals)
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.stream_where(|l| l.account_id == account_id && l.pst_time > from && l.pst_time <= to && l.discarded_time.is_none(), order)
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.stream_where(|l| l.account_id == account_id && l.pst_time <= ref_time && l.discarded_time.is_none(), order)
+    }
+}
+
+pub(crate) fn insert_posting_line(tables: &mut Tables, posting_line: &PostingLine) -> Result<PostingLine, DbError> {
+    tables.posting_line.insert(posting_line.id, posting_line.clone())?;
+    Ok(posting_line.clone())
+}
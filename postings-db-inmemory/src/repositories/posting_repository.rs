@@ -0,0 +1,164 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_filter::{PostingFilter, PostingOrder};
+use postings_db::models::posting_line::PostingLine;
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::repositories::posting_line_repository::insert_posting_line;
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryPostingRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryPostingRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PostingRepository for InMemoryPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        Ok(self.store.read().posting
+            .values()
+            .find(|p| p.opr_id.as_slice() == opr_id && p.discarding_id.is_none())
+            .cloned())
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        Ok(self.store.read().posting
+            .values()
+            .filter(|p| p.opr_id.as_slice() == opr_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Of postings with the same record time, the one saved last is returned.
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.store.read().posting
+            .values()
+            .filter(|p| p.ledger_id == ledger_id)
+            .max_by_key(|p| p.record_time)
+            .cloned())
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        insert_posting(&mut self.store.write(), posting)
+    }
+
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        insert_all(&mut self.store.write(), postings.iter().map(|(posting, lines)| (posting, lines.as_slice())))
+    }
+
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        let mut tables = self.store.write();
+        if !tables.posting.get(&reversed_id).is_some_and(|p| p.discarding_id.is_none()) {
+            return Ok(false);
+        }
+        insert_all(&mut tables, [(reversal, lines)])?;
+        if let Some(reversed) = tables.posting.get_mut(&reversed_id) {
+            reversed.discarding_id = Some(reversal.id);
+        }
+        Ok(true)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.store.read().posting.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        let mut postings: Vec<Posting> = self.store.read().posting
+            .values()
+            .filter(|p| p.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        postings.sort_by_key(|p| (p.record_time, p.id));
+        Ok(postings)
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        Ok(Page::from_all(self.find_by_ledger_id(ledger_id).await?, page))
+    }
+
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        let tables = self.store.read();
+        let mut postings: Vec<Posting> = tables.posting
+            .values()
+            .filter(|p| filter.ledger_ids.is_empty() || filter.ledger_ids.contains(&p.ledger_id))
+            .filter(|p| filter.account_id.is_none_or(|account_id| {
+                tables.posting_line.values().any(|l| l.opr_id == p.opr_id && l.account_id == account_id)
+            }))
+            .filter(|p| filter.opr_id.is_none_or(|opr_id| p.opr_id == opr_id))
+            .filter(|p| filter.opr_type.is_none_or(|opr_type| p.opr_type == opr_type))
+            .filter(|p| filter.pst_status.as_ref().is_none_or(|pst_status| &p.pst_status == pst_status))
+            .filter(|p| filter.pst_time_from.is_none_or(|from| p.pst_time >= from))
+            .filter(|p| filter.pst_time_to.is_none_or(|to| p.pst_time <= to))
+            .cloned()
+            .collect();
+        match filter.order {
+            PostingOrder::RecordTimeAsc => postings.sort_by_key(|p| (p.record_time, p.id)),
+            PostingOrder::RecordTimeDesc => postings.sort_by(|a, b| (b.record_time, b.id).cmp(&(a.record_time, a.id))),
+            PostingOrder::PstTimeAsc => postings.sort_by_key(|p| (p.pst_time, p.id)),
+            PostingOrder::PstTimeDesc => postings.sort_by(|a, b| (b.pst_time, b.id).cmp(&(a.pst_time, a.id))),
+        }
+        Ok(Page::from_all(postings, page))
+    }
+}
+
+/// Inserts the posting, enforcing the unique `(opr_id, discarding_id)` constraint including the
+/// single current version per operation.
+pub(crate) fn insert_posting(tables: &mut Tables, posting: &Posting) -> Result<(), DbError> {
+    if tables.posting.values().any(|p| p.opr_id == posting.opr_id && p.discarding_id == posting.discarding_id) {
+        return Err(DbError::UniqueViolation);
+    }
+    tables.posting.insert(posting.id, posting.clone())
+}
+
+/// Marks the posting as discarded, failing with [`DbError::NotFound`] if it is already discarded.
+pub(crate) fn discard_posting(tables: &mut Tables, id: Uuid, discarding_id: Uuid) -> Result<(), DbError> {
+    match tables.posting.get_mut(&id) {
+        Some(posting) if posting.discarding_id.is_none() => {
+            posting.discarding_id = Some(discarding_id);
+            Ok(())
+        }
+        _ => Err(DbError::NotFound),
+    }
+}
+
+/// Inserts the postings and their lines in order. If one insert fails, the rows already
+/// inserted are removed again, so nothing is written.
+fn insert_all<'a>(tables: &mut Tables, postings: impl IntoIterator<Item = (&'a Posting, &'a [PostingLine])>) -> Result<(), DbError> {
+    let mut posting_ids = Vec::new();
+    let mut line_ids = Vec::new();
+    let result = insert_each(tables, postings, &mut posting_ids, &mut line_ids);
+    if result.is_err() {
+        for id in posting_ids.iter() {
+            tables.posting.remove(id);
+        }
+        for id in line_ids.iter() {
+            tables.posting_line.remove(id);
+        }
+    }
+    result
+}
+
+fn insert_each<'a>(
+    tables: &mut Tables,
+    postings: impl IntoIterator<Item = (&'a Posting, &'a [PostingLine])>,
+    posting_ids: &mut Vec<Uuid>,
+    line_ids: &mut Vec<Uuid>,
+) -> Result<(), DbError> {
+    for (posting, lines) in postings {
+        insert_posting(tables, posting)?;
+        posting_ids.push(posting.id);
+        for line in lines.iter() {
+            insert_posting_line(tables, line)?;
+            line_ids.push(line.id);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::upsert::Upserted;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryPostingTraceRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryPostingTraceRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PostingTraceRepository for InMemoryPostingTraceRepository {
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError> {
+        let mut tables = self.store.write();
+        let existing = tables.posting_trace
+            .values()
+            .find(|t| t.tgt_pst_id == trace.tgt_pst_id && t.src_pst_id == trace.src_pst_id)
+            .map(|t| t.id);
+        if let Some(stored) = existing.and_then(|id| tables.posting_trace.get_mut(&id)) {
+            stored.src_pst_time = trace.src_pst_time;
+            stored.debit_amount = trace.debit_amount;
+            stored.credit_amount = trace.credit_amount;
+            stored.src_pst_hash = trace.src_pst_hash;
+            return Ok(Upserted::Existing(stored.clone()));
+        }
+        tables.posting_trace.insert(trace.id, trace.clone())?;
+        Ok(Upserted::Inserted(trace))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
+        Ok(self.store.read().posting_trace.get(&id).cloned())
+    }
+
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError> {
+        let mut tables = self.store.write();
+        let accounts = tables.account_ids_of_ledger(ledger_id);
+        let persisted: Vec<Uuid> = tables.posting_trace
+            .values()
+            .filter(|t| tables.account_stmt.contains_key(&t.tgt_pst_id))
+            .map(|t| t.id)
+            .collect();
+        Ok(tables.posting_trace.retain(|t| !accounts.contains(&t.account_id) || persisted.contains(&t.id)))
+    }
+}
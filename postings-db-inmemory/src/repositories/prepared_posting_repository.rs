@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::prepared_posting_repository::PreparedPostingRepository;
+use postings_db::models::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryPreparedPostingRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryPreparedPostingRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PreparedPostingRepository for InMemoryPreparedPostingRepository {
+    async fn save(&self, prepared: PreparedPosting) -> Result<PreparedPosting, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.prepared_posting.get_mut(&prepared.id) {
+            stored.status = prepared.status;
+            stored.resolved_time = prepared.resolved_time;
+            stored.posting_id = prepared.posting_id;
+            return Ok(stored.clone());
+        }
+        tables.prepared_posting.insert(prepared.id, prepared.clone())?;
+        Ok(prepared)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PreparedPosting>, DbError> {
+        Ok(self.store.read().prepared_posting.get(&id).cloned())
+    }
+
+    async fn resolve(&self, id: Uuid, status: PreparedPostingStatus, resolved_time: DateTime<Utc>, posting_id: Option<Uuid>) -> Result<bool, DbError> {
+        let mut tables = self.store.write();
+        match tables.prepared_posting.get_mut(&id) {
+            Some(stored) if stored.status == PreparedPostingStatus::Prepared => {
+                stored.status = status;
+                stored.resolved_time = Some(resolved_time);
+                stored.posting_id = posting_id;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn find_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<Vec<PreparedPosting>, DbError> {
+        let mut expired: Vec<PreparedPosting> = self.store.read().prepared_posting
+            .values()
+            .filter(|p| p.status == PreparedPostingStatus::Prepared && p.expires <= as_of)
+            .cloned()
+            .collect();
+        expired.sort_by_key(|p| p.expires);
+        expired.truncate(limit.max(0) as usize);
+        Ok(expired)
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::product_repository::ProductRepository;
+use postings_db::models::product::{AccountProduct, Product};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryProductRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryProductRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ProductRepository for InMemoryProductRepository {
+    async fn save(&self, product: Product) -> Result<Product, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.product.get_mut(&product.id) {
+            stored.name = product.name;
+            stored.interest_rate = product.interest_rate;
+            stored.day_count_convention = product.day_count_convention;
+            return Ok(stored.clone());
+        }
+        tables.product.insert(product.id, product.clone())?;
+        Ok(product)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, DbError> {
+        Ok(self.store.read().product.get(&id).cloned())
+    }
+
+    async fn save_account_product(&self, link: AccountProduct) -> Result<AccountProduct, DbError> {
+        self.store.write().account_product.upsert(link.account_id, link.clone());
+        Ok(link)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<Product>, DbError> {
+        let tables = self.store.read();
+        Ok(tables.account_product
+            .get(&account_id)
+            .and_then(|link| tables.product.get(&link.product_id))
+            .cloned())
+    }
+}
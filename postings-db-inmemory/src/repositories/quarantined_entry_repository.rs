@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::quarantined_entry_repository::QuarantinedEntryRepository;
+use postings_db::models::quarantined_entry::{QuarantineStatus, QuarantinedEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryQuarantinedEntryRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryQuarantinedEntryRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl QuarantinedEntryRepository for InMemoryQuarantinedEntryRepository {
+    async fn save(&self, entry: QuarantinedEntry) -> Result<QuarantinedEntry, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.quarantined_entry.get_mut(&entry.id) {
+            stored.payload = entry.payload;
+            stored.reason = entry.reason;
+            stored.status = entry.status;
+            stored.resolved_time = entry.resolved_time;
+            return Ok(stored.clone());
+        }
+        tables.quarantined_entry.insert(entry.id, entry.clone())?;
+        Ok(entry)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuarantinedEntry>, DbError> {
+        Ok(self.store.read().quarantined_entry.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_id_and_status(&self, ledger_id: Uuid, status: QuarantineStatus) -> Result<Vec<QuarantinedEntry>, DbError> {
+        let mut entries: Vec<QuarantinedEntry> = self.store.read().quarantined_entry
+            .values()
+            .filter(|e| e.ledger_id == ledger_id && e.status == status)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| (e.created, e.line_number));
+        Ok(entries)
+    }
+}
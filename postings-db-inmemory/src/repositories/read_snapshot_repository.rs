@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::read_snapshot::AccountSnapshot;
+use postings_db::repositories::read_snapshot_repository::ReadSnapshotRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryReadSnapshotRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryReadSnapshotRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ReadSnapshotRepository for InMemoryReadSnapshotRepository {
+    /// All rows are read under one lock of the tables, which writers wait for.
+    async fn read_account(&self, account_id: Uuid, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountSnapshot, DbError> {
+        let tables = self.store.read();
+        let last_closed_stmt = tables.account_stmt
+            .values()
+            .filter(|s| s.account_id == account_id && s.stmt_status == StmtStatus::Closed && s.pst_time < ref_time)
+            .max_by(|a, b| (a.pst_time, a.stmt_seq_nbr).cmp(&(b.pst_time, b.stmt_seq_nbr)))
+            .cloned();
+        let from = last_closed_stmt.as_ref().map(|stmt| stmt.pst_time.min(activity_from));
+        let mut lines: Vec<PostingLine> = tables.posting_line
+            .values()
+            .filter(|l| l.account_id == account_id && from.is_none_or(|from| l.pst_time > from) && l.pst_time <= ref_time && l.discarded_time.is_none())
+            .cloned()
+            .collect();
+        lines.sort_by(|a, b| (b.pst_time, b.record_time, b.id).cmp(&(a.pst_time, a.record_time, a.id)));
+        Ok(AccountSnapshot { last_closed_stmt, lines })
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::report_schedule_repository::ReportScheduleRepository;
+use postings_db::models::report_schedule::{ReportArtifact, ReportFormat, ReportSchedule, ReportScheduleStatus, ReportType};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryReportScheduleRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryReportScheduleRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+/// Position of the format in the database enum, which `ORDER BY format` follows.
+fn format_rank(format: &ReportFormat) -> u8 {
+    match format {
+        ReportFormat::Json => 0,
+        ReportFormat::Csv => 1,
+        ReportFormat::Pdf => 2,
+    }
+}
+
+#[async_trait]
+impl ReportScheduleRepository for InMemoryReportScheduleRepository {
+    async fn save(&self, schedule: ReportSchedule) -> Result<ReportSchedule, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.report_schedule.get_mut(&schedule.id) {
+            stored.next_ref_time = schedule.next_ref_time;
+            stored.status = schedule.status;
+            return Ok(stored.clone());
+        }
+        tables.report_schedule.insert(schedule.id, schedule.clone())?;
+        Ok(schedule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReportSchedule>, DbError> {
+        Ok(self.store.read().report_schedule.get(&id).cloned())
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportSchedule>, DbError> {
+        let mut schedules: Vec<ReportSchedule> = self.store.read().report_schedule
+            .values()
+            .filter(|s| s.status == ReportScheduleStatus::Active && s.next_ref_time <= as_of)
+            .cloned()
+            .collect();
+        schedules.sort_by_key(|s| (s.next_ref_time, s.id));
+        Ok(schedules)
+    }
+
+    async fn save_artifact(&self, artifact: ReportArtifact) -> Result<ReportArtifact, DbError> {
+        let mut tables = self.store.write();
+        let version_taken = tables.report_artifact.values().any(|a| {
+            a.ledger_id == artifact.ledger_id
+                && a.report_type == artifact.report_type
+                && a.ref_time == artifact.ref_time
+                && a.format == artifact.format
+                && a.version == artifact.version
+        });
+        if version_taken {
+            return Err(DbError::UniqueViolation);
+        }
+        tables.report_artifact.insert(artifact.id, artifact.clone())?;
+        Ok(artifact)
+    }
+
+    async fn find_artifact_by_id(&self, id: Uuid) -> Result<Option<ReportArtifact>, DbError> {
+        Ok(self.store.read().report_artifact.get(&id).cloned())
+    }
+
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, DbError> {
+        let mut artifacts: Vec<ReportArtifact> = self.store.read().report_artifact
+            .values()
+            .filter(|a| a.ledger_id == ledger_id && a.report_type == report_type && a.ref_time == ref_time)
+            .cloned()
+            .collect();
+        artifacts.sort_by_key(|a| (format_rank(&a.format), a.version));
+        Ok(artifacts)
+    }
+}
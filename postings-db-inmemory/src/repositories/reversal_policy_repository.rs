@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::reversal_policy_repository::ReversalPolicyRepository;
+use postings_db::models::reversal_policy::ReversalPolicy;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryReversalPolicyRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryReversalPolicyRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ReversalPolicyRepository for InMemoryReversalPolicyRepository {
+    async fn save(&self, policy: ReversalPolicy) -> Result<ReversalPolicy, DbError> {
+        self.store.write().reversal_policy.upsert(policy.ledger_id, policy.clone());
+        Ok(policy)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ReversalPolicy>, DbError> {
+        Ok(self.store.read().reversal_policy.get(&ledger_id).cloned())
+    }
+}
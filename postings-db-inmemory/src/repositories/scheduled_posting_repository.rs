@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::scheduled_posting::{ScheduledPosting, ScheduledPostingStatus};
+use postings_db::repositories::scheduled_posting_repository::ScheduledPostingRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryScheduledPostingRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryScheduledPostingRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ScheduledPostingRepository for InMemoryScheduledPostingRepository {
+    async fn save(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.scheduled_posting.get_mut(&schedule.id) {
+            stored.next_pst_time = schedule.next_pst_time;
+            stored.occurrence = schedule.occurrence;
+            stored.status = schedule.status;
+            return Ok(stored.clone());
+        }
+        tables.scheduled_posting.insert(schedule.id, schedule.clone())?;
+        Ok(schedule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPosting>, DbError> {
+        Ok(self.store.read().scheduled_posting.get(&id).cloned())
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ScheduledPosting>, DbError> {
+        let mut schedules: Vec<ScheduledPosting> = self.store.read().scheduled_posting
+            .values()
+            .filter(|s| s.status == ScheduledPostingStatus::Active && s.next_pst_time <= as_of)
+            .cloned()
+            .collect();
+        schedules.sort_by_key(|s| (s.next_pst_time, s.id));
+        Ok(schedules)
+    }
+}
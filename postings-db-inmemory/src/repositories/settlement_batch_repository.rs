@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::settlement_batch_repository::SettlementBatchRepository;
+use postings_db::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemorySettlementBatchRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemorySettlementBatchRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl SettlementBatchRepository for InMemorySettlementBatchRepository {
+    async fn save(&self, batch: SettlementBatch) -> Result<SettlementBatch, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.settlement_batch.get_mut(&batch.id) {
+            stored.control_amount = batch.control_amount;
+            stored.control_count = batch.control_count;
+            stored.status = batch.status;
+            stored.closed_time = batch.closed_time;
+            return Ok(stored.clone());
+        }
+        tables.settlement_batch.insert(batch.id, batch.clone())?;
+        Ok(batch)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<SettlementBatch>, DbError> {
+        Ok(self.store.read().settlement_batch.get(&id).cloned())
+    }
+
+    async fn save_entry(&self, entry: SettlementBatchEntry) -> Result<SettlementBatchEntry, DbError> {
+        self.store.write().settlement_batch_entry.insert(entry.posting_id, entry.clone())?;
+        Ok(entry)
+    }
+
+    async fn find_entries_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<SettlementBatchEntry>, DbError> {
+        let mut entries: Vec<SettlementBatchEntry> = self.store.read().settlement_batch_entry
+            .values()
+            .filter(|e| e.batch_id == batch_id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| e.assigned_time);
+        Ok(entries)
+    }
+
+    async fn find_entry_by_posting_id(&self, posting_id: Uuid) -> Result<Option<SettlementBatchEntry>, DbError> {
+        Ok(self.store.read().settlement_batch_entry.get(&posting_id).cloned())
+    }
+}
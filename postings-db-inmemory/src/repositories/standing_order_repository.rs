@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use postings_db::repositories::standing_order_repository::StandingOrderRepository;
+use postings_db::models::standing_order::{StandingOrder, StandingOrderExecution, StandingOrderStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStandingOrderRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStandingOrderRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn find_active_where(&self, filter: impl Fn(&StandingOrder) -> bool) -> Vec<StandingOrder> {
+        let mut orders: Vec<StandingOrder> = self.store.read().standing_order
+            .values()
+            .filter(|o| o.status == StandingOrderStatus::Active && filter(o))
+            .cloned()
+            .collect();
+        orders.sort_by_key(|o| (o.next_execution_date, o.id));
+        orders
+    }
+}
+
+#[async_trait]
+impl StandingOrderRepository for InMemoryStandingOrderRepository {
+    async fn save(&self, order: StandingOrder) -> Result<StandingOrder, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.standing_order.get_mut(&order.id) {
+            stored.end_date = order.end_date;
+            stored.max_executions = order.max_executions;
+            stored.next_execution_date = order.next_execution_date;
+            stored.occurrence = order.occurrence;
+            stored.execution_count = order.execution_count;
+            stored.status = order.status;
+            return Ok(stored.clone());
+        }
+        tables.standing_order.insert(order.id, order.clone())?;
+        Ok(order)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StandingOrder>, DbError> {
+        Ok(self.store.read().standing_order.get(&id).cloned())
+    }
+
+    async fn find_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrder>, DbError> {
+        Ok(self.find_active_where(|o| o.next_execution_date <= as_of))
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid) -> Result<Vec<StandingOrder>, DbError> {
+        Ok(self.find_active_where(|o| o.source_account_id == account_id || o.target_account_id == account_id))
+    }
+
+    async fn save_execution(&self, execution: StandingOrderExecution) -> Result<StandingOrderExecution, DbError> {
+        self.store.write().standing_order_execution.insert(execution.id, execution.clone())?;
+        Ok(execution)
+    }
+
+    async fn find_executions_by_order_id(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, DbError> {
+        let mut executions: Vec<StandingOrderExecution> = self.store.read().standing_order_execution
+            .values()
+            .filter(|e| e.order_id == order_id)
+            .cloned()
+            .collect();
+        executions.sort_by_key(|e| (e.execution_date, e.attempt));
+        Ok(executions)
+    }
+}
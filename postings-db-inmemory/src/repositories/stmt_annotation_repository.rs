@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::stmt_annotation_repository::StmtAnnotationRepository;
+use postings_db::models::stmt_annotation::StmtAnnotation;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStmtAnnotationRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStmtAnnotationRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl StmtAnnotationRepository for InMemoryStmtAnnotationRepository {
+    async fn save(&self, annotation: StmtAnnotation) -> Result<StmtAnnotation, DbError> {
+        self.store.write().stmt_annotation.insert(annotation.id, annotation.clone())?;
+        Ok(annotation)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, DbError> {
+        let mut annotations: Vec<StmtAnnotation> = self.store.read().stmt_annotation
+            .values()
+            .filter(|a| a.stmt_id == stmt_id)
+            .cloned()
+            .collect();
+        annotations.sort_by_key(|a| (a.created, a.id));
+        Ok(annotations)
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::stmt_delivery_repository::StmtDeliveryRepository;
+use postings_db::models::stmt_delivery::StmtDelivery;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStmtDeliveryRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStmtDeliveryRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl StmtDeliveryRepository for InMemoryStmtDeliveryRepository {
+    async fn save(&self, delivery: StmtDelivery) -> Result<StmtDelivery, DbError> {
+        self.store.write().stmt_delivery.insert(delivery.id, delivery.clone())?;
+        Ok(delivery)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, DbError> {
+        let mut deliveries: Vec<StmtDelivery> = self.store.read().stmt_delivery
+            .values()
+            .filter(|d| d.stmt_id == stmt_id)
+            .cloned()
+            .collect();
+        deliveries.sort_by_key(|d| d.created);
+        Ok(deliveries)
+    }
+
+    async fn find_by_stmt_id_and_checksum(&self, stmt_id: Uuid, checksum: &[u8]) -> Result<Option<StmtDelivery>, DbError> {
+        Ok(self.store.read().stmt_delivery
+            .values()
+            .filter(|d| d.stmt_id == stmt_id && d.checksum.as_slice() == checksum)
+            .max_by_key(|d| d.created)
+            .cloned())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::stmt_job_repository::StmtJobRepository;
+use postings_db::models::stmt_job::{StmtJob, StmtJobStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStmtJobRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStmtJobRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl StmtJobRepository for InMemoryStmtJobRepository {
+    async fn save(&self, job: StmtJob) -> Result<StmtJob, DbError> {
+        let mut tables = self.store.write();
+        if let Some(stored) = tables.stmt_job.get_mut(&job.id) {
+            stored.status = job.status;
+            stored.stmt_id = job.stmt_id;
+            stored.error_message = job.error_message;
+            stored.completed_time = job.completed_time;
+            return Ok(stored.clone());
+        }
+        tables.stmt_job.insert(job.id, job.clone())?;
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StmtJob>, DbError> {
+        Ok(self.store.read().stmt_job.get(&id).cloned())
+    }
+
+    async fn find_by_status(&self, status: StmtJobStatus, limit: i64) -> Result<Vec<StmtJob>, DbError> {
+        let mut jobs: Vec<StmtJob> = self.store.read().stmt_job
+            .values()
+            .filter(|j| j.status == status)
+            .cloned()
+            .collect();
+        jobs.sort_by_key(|j| j.created);
+        jobs.truncate(limit.max(0) as usize);
+        Ok(jobs)
+    }
+}
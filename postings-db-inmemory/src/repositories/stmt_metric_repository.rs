@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::repositories::stmt_metric_repository::StmtMetricRepository;
+use postings_db::models::stmt_metric::{AccountStmtStats, StmtMetric};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStmtMetricRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStmtMetricRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    /// Per-account aggregates of the ledger's metrics recorded in `[from, to)`, by account id.
+    fn account_stats(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<AccountStmtStats> {
+        let mut stats: BTreeMap<Uuid, AccountStmtStats> = BTreeMap::new();
+        for metric in self.store.read().stmt_metric.values() {
+            if metric.ledger_id != ledger_id || metric.recorded < from || metric.recorded >= to {
+                continue;
+            }
+            let entry = stats.entry(metric.account_id).or_insert(AccountStmtStats {
+                account_id: metric.account_id,
+                generations: 0,
+                total_duration_ms: 0,
+                max_duration_ms: metric.duration_ms,
+                max_lines: metric.line_count,
+            });
+            entry.generations += 1;
+            entry.total_duration_ms += metric.duration_ms;
+            entry.max_duration_ms = entry.max_duration_ms.max(metric.duration_ms);
+            entry.max_lines = entry.max_lines.max(metric.line_count);
+        }
+        stats.into_values().collect()
+    }
+}
+
+#[async_trait]
+impl StmtMetricRepository for InMemoryStmtMetricRepository {
+    async fn save(&self, metric: StmtMetric) -> Result<StmtMetric, DbError> {
+        self.store.write().stmt_metric.insert(metric.id, metric.clone())?;
+        Ok(metric)
+    }
+
+    async fn find_slowest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        let mut stats = self.account_stats(ledger_id, from, to);
+        stats.sort_by(|a, b| b.max_duration_ms.cmp(&a.max_duration_ms).then(a.account_id.cmp(&b.account_id)));
+        stats.truncate(limit.max(0) as usize);
+        Ok(stats)
+    }
+
+    async fn find_hottest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        let mut stats = self.account_stats(ledger_id, from, to);
+        stats.sort_by(|a, b| b.max_lines.cmp(&a.max_lines).then(a.account_id.cmp(&b.account_id)));
+        stats.truncate(limit.max(0) as usize);
+        Ok(stats)
+    }
+}
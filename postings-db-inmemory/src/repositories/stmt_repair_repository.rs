@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::stmt_repair_repository::StmtRepairRepository;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::stmt_repair::StmtRepair;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::InMemoryStore;
+
+pub struct InMemoryStmtRepairRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryStmtRepairRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl StmtRepairRepository for InMemoryStmtRepairRepository {
+    async fn save(&self, repair: StmtRepair, stmt: &AccountStmt) -> Result<StmtRepair, DbError> {
+        let mut tables = self.store.write();
+        if tables.stmt_repair.contains_key(&repair.id) {
+            return Err(DbError::UniqueViolation);
+        }
+        if let Some(stored) = tables.account_stmt.get_mut(&stmt.id) {
+            stored.opening_debit = stmt.opening_debit.clone();
+            stored.opening_credit = stmt.opening_credit.clone();
+            stored.total_debit = stmt.total_debit.clone();
+            stored.total_credit = stmt.total_credit.clone();
+            stored.line_count = stmt.line_count;
+            stored.currency_totals = stmt.currency_totals.clone();
+            stored.closing_balance = &stored.total_debit - &stored.total_credit;
+        }
+        tables.stmt_repair.insert(repair.id, repair.clone())?;
+        Ok(repair)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<StmtRepair>, DbError> {
+        let mut repairs: Vec<StmtRepair> = self.store.read().stmt_repair
+            .values()
+            .filter(|r| r.account_id == account_id)
+            .cloned()
+            .collect();
+        repairs.sort_by(|a, b| b.repaired_time.cmp(&a.repaired_time));
+        Ok(repairs)
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::earmark::Earmark;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::{Committed, RolledBack, UnitOfWork, Write};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::repositories::account_stmt_repository::upsert_account_stmt;
+use crate::repositories::earmark_repository::upsert_earmark;
+use crate::repositories::ledger_stmt_repository::upsert_ledger_stmt;
+use crate::repositories::outbox_repository::insert_outbox_entry;
+use crate::repositories::posting_line_repository::insert_posting_line;
+use crate::repositories::posting_repository::{discard_posting, insert_posting};
+use crate::store::{InMemoryStore, Tables};
+
+pub struct InMemoryUnitOfWorkRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryUnitOfWorkRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+/// Reverts one applied write.
+enum Undo {
+    Posting(Uuid),
+    DiscardPosting(Uuid),
+    PostingLine(Uuid),
+    AccountStmt(Uuid, Option<AccountStmt>),
+    LedgerStmt(Uuid, Option<LedgerStmt>),
+    OutboxEntry(Uuid),
+    Earmark(Uuid, Option<Earmark>),
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for InMemoryUnitOfWorkRepository {
+    /// Applied under the store lock; if a write fails, the writes already applied are reverted.
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        let mut tables = self.store.write();
+        let mut undo = Vec::new();
+        let mut committed = Committed::default();
+        let result = apply_each(&mut tables, work, &mut undo, &mut committed);
+        if result.is_err() {
+            revert(&mut tables, undo);
+        }
+        result.map(|_| committed)
+    }
+}
+
+/// Applies the writes, recording how to revert them in `undo`. Failed savepoints are reverted
+/// right away and recorded in `committed`.
+fn apply_each(tables: &mut Tables, work: UnitOfWork, undo: &mut Vec<Undo>, committed: &mut Committed) -> Result<(), DbError> {
+    for write in work.into_writes() {
+        match write {
+            Write::Posting(posting) => {
+                insert_posting(tables, &posting)?;
+                undo.push(Undo::Posting(posting.id));
+            }
+            Write::DiscardPosting { id, discarding_id } => {
+                discard_posting(tables, id, discarding_id)?;
+                undo.push(Undo::DiscardPosting(id));
+            }
+            Write::PostingLine(line) => {
+                insert_posting_line(tables, &line)?;
+                undo.push(Undo::PostingLine(line.id));
+            }
+            Write::AccountStmt(stmt) => {
+                undo.push(Undo::AccountStmt(stmt.id, tables.account_stmt.get(&stmt.id).cloned()));
+                upsert_account_stmt(tables, stmt);
+            }
+            Write::LedgerStmt(stmt) => {
+                let (id, previous) = (stmt.id, tables.ledger_stmt.get(&stmt.id).cloned());
+                upsert_ledger_stmt(tables, stmt)?;
+                undo.push(Undo::LedgerStmt(id, previous));
+            }
+            Write::OutboxEntry(entry) => {
+                insert_outbox_entry(tables, &entry)?;
+                undo.push(Undo::OutboxEntry(entry.id));
+            }
+            Write::Earmark(earmark) => {
+                let (id, previous) = (earmark.id, tables.earmark.get(&earmark.id).cloned());
+                upsert_earmark(tables, earmark)?;
+                undo.push(Undo::Earmark(id, previous));
+            }
+            Write::Savepoint(savepoint, nested) => {
+                let mut nested_undo = Vec::new();
+                let mut nested_committed = Committed::default();
+                match apply_each(tables, nested, &mut nested_undo, &mut nested_committed) {
+                    Ok(()) => {
+                        undo.append(&mut nested_undo);
+                        committed.rolled_back.append(&mut nested_committed.rolled_back);
+                    }
+                    Err(error) => {
+                        revert(tables, nested_undo);
+                        committed.rolled_back.push(RolledBack { savepoint, error });
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn revert(tables: &mut Tables, undo: Vec<Undo>) {
+    for step in undo.into_iter().rev() {
+        match step {
+            Undo::Posting(id) => {
+                tables.posting.remove(&id);
+            }
+            Undo::DiscardPosting(id) => {
+                if let Some(posting) = tables.posting.get_mut(&id) {
+                    posting.discarding_id = None;
+                }
+            }
+            Undo::PostingLine(id) => {
+                tables.posting_line.remove(&id);
+            }
+            Undo::AccountStmt(id, previous) => match previous {
+                Some(previous) => tables.account_stmt.upsert(id, previous),
+                None => {
+                    tables.account_stmt.remove(&id);
+                }
+            },
+            Undo::LedgerStmt(id, previous) => match previous {
+                Some(previous) => tables.ledger_stmt.upsert(id, previous),
+                None => {
+                    tables.ledger_stmt.remove(&id);
+                }
+            },
+            Undo::OutboxEntry(id) => {
+                tables.outbox.remove(&id);
+            }
+            Undo::Earmark(id, previous) => match previous {
+                Some(previous) => tables.earmark.upsert(id, previous),
+                None => {
+                    tables.earmark.remove(&id);
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use chrono::{DateTime, NaiveDate, Utc};
+use postings_db::models::account_group::{AccountGroup, AccountGroupMember};
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::api_key::ApiKey;
+use postings_db::models::backfill_job::BackfillJob;
+use postings_db::models::category_rule::CategoryRule;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::earmark::Earmark;
+use postings_db::models::eod_run::{EodRun, EodStepResult};
+use postings_db::models::escrow::Escrow;
+use postings_db::models::external_content::ExternalContent;
+use postings_db::models::fee_schedule::{FeeSchedule, FeeTier};
+use postings_db::models::hashing_profile::HashingProfile;
+use postings_db::models::holiday::Holiday;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::models::outbox_entry::OutboxEntry;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::models::external_ref::ExternalRef;
+use postings_db::models::ledger_closure::LedgerClosure;
+use postings_db::models::scheduled_posting::ScheduledPosting;
+use postings_db::models::fx_revaluation::FxRevaluation;
+use postings_db::models::ledger_account_name::LedgerAccountName;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::ledger_event::{LedgerEvent, LedgerEventOperation};
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::models::named::Named;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::models::prepared_posting::PreparedPosting;
+use postings_db::models::product::{AccountProduct, Product};
+use postings_db::models::quarantined_entry::QuarantinedEntry;
+use postings_db::models::report_schedule::{ReportArtifact, ReportSchedule};
+use postings_db::models::reversal_policy::ReversalPolicy;
+use postings_db::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+use postings_db::models::standing_order::{StandingOrder, StandingOrderExecution};
+use postings_db::models::stmt_annotation::StmtAnnotation;
+use postings_db::models::stmt_delivery::StmtDelivery;
+use postings_db::models::stmt_job::StmtJob;
+use postings_db::models::stmt_metric::StmtMetric;
+use postings_db::models::stmt_repair::StmtRepair;
+use postings_db::DbError;
+use uuid::Uuid;
+
+/// Tables shared by the in-memory repositories. Hand the same store to every repository so
+/// lookups joining several tables and multi-table writes see one consistent state.
+///
+/// All tables sit behind a single lock: a write through any repository is atomic, like a
+/// database transaction, and never observed half done.
+#[derive(Default)]
+pub struct InMemoryStore {
+    tables: RwLock<Tables>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, Tables> {
+        self.tables.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn write(&self) -> RwLockWriteGuard<'_, Tables> {
+        self.tables.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Appends a change event and returns its sequence number. The database backends record
+    /// events through triggers; the in-memory tables have none, so tests of event consumers
+    /// feed the event table here.
+    pub fn append_event(&self, entity_type: &str, entity_id: &str, operation: LedgerEventOperation, payload: String, created: DateTime<Utc>) -> i64 {
+        let mut tables = self.write();
+        tables.ledger_event_seq += 1;
+        let seq = tables.ledger_event_seq;
+        tables.ledger_event.insert(seq, LedgerEvent {
+            seq,
+            entity_type: entity_type.to_string(),
+            entity_id: entity_id.to_string(),
+            operation,
+            payload,
+            created,
+        });
+        seq
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Tables {
+    pub chart_of_account: Table<Uuid, ChartOfAccount>,
+    pub ledger: Table<Uuid, Ledger>,
+    pub ledger_account: Table<Uuid, LedgerAccount>,
+    pub named: Table<Uuid, Named>,
+    pub posting: Table<Uuid, Posting>,
+    pub posting_line: Table<Uuid, PostingLine>,
+    pub account_stmt: Table<Uuid, AccountStmt>,
+    pub posting_trace: Table<Uuid, PostingTrace>,
+    pub settlement_batch: Table<Uuid, SettlementBatch>,
+    /// Keyed by posting id: a posting belongs to at most one batch.
+    pub settlement_batch_entry: Table<Uuid, SettlementBatchEntry>,
+    pub eod_run: Table<Uuid, EodRun>,
+    pub eod_step_result: Table<(Uuid, i32), EodStepResult>,
+    pub holiday: Table<(Uuid, NaiveDate), Holiday>,
+    pub account_limit: Table<Uuid, AccountLimit>,
+    pub earmark: Table<Uuid, Earmark>,
+    pub standing_order: Table<Uuid, StandingOrder>,
+    pub standing_order_execution: Table<Uuid, StandingOrderExecution>,
+    pub fee_schedule: Table<Uuid, FeeSchedule>,
+    pub fee_tier: Table<Uuid, Vec<FeeTier>>,
+    pub escrow: Table<Uuid, Escrow>,
+    pub quarantined_entry: Table<Uuid, QuarantinedEntry>,
+    pub ledger_stmt: Table<Uuid, LedgerStmt>,
+    pub external_content: Table<[u8; 34], ExternalContent>,
+    pub stmt_job: Table<Uuid, StmtJob>,
+    pub hashing_profile: Table<Uuid, HashingProfile>,
+    pub stmt_delivery: Table<Uuid, StmtDelivery>,
+    pub category_rule: Table<Uuid, CategoryRule>,
+    pub product: Table<Uuid, Product>,
+    pub account_product: Table<Uuid, AccountProduct>,
+    pub prepared_posting: Table<Uuid, PreparedPosting>,
+    pub ledger_event: BTreeMap<i64, LedgerEvent>,
+    pub ledger_event_archive: BTreeMap<i64, LedgerEvent>,
+    pub ledger_event_seq: i64,
+    pub ledger_event_consumer: Table<String, (i64, DateTime<Utc>)>,
+    pub api_key: Table<Uuid, ApiKey>,
+    pub stmt_metric: Table<Uuid, StmtMetric>,
+    pub backfill_job: Table<Uuid, BackfillJob>,
+    pub stmt_repair: Table<Uuid, StmtRepair>,
+    pub reversal_policy: Table<Uuid, ReversalPolicy>,
+    pub report_schedule: Table<Uuid, ReportSchedule>,
+    pub report_artifact: Table<Uuid, ReportArtifact>,
+    pub account_group: Table<Uuid, AccountGroup>,
+    pub account_group_member: Table<(Uuid, Uuid), AccountGroupMember>,
+    pub stmt_annotation: Table<Uuid, StmtAnnotation>,
+    pub import_checkpoint: Table<Uuid, ImportCheckpoint>,
+    pub outbox: Table<Uuid, OutboxEntry>,
+    pub balance_checkpoint: Table<(Uuid, DateTime<Utc>), BalanceCheckpoint>,
+    pub external_ref: Table<Uuid, ExternalRef>,
+    pub ledger_closure: Table<Uuid, LedgerClosure>,
+    pub scheduled_posting: Table<Uuid, ScheduledPosting>,
+    pub fx_revaluation: Table<(Uuid, DateTime<Utc>), FxRevaluation>,
+    pub ledger_account_name: Table<Uuid, LedgerAccountName>,
+}
+
+impl Tables {
+    /// Ids of the ledger's accounts, for lookups the databases answer by joining `ledger_account`.
+    pub fn account_ids_of_ledger(&self, ledger_id: Uuid) -> Vec<Uuid> {
+        self.ledger_account.values().filter(|a| a.ledger_id == ledger_id).map(|a| a.id).collect()
+    }
+}
+
+/// Rows by primary key. Rows are iterated in insertion order, so results are deterministic
+/// where the SQL queries leave ties unordered; a replaced row keeps its position.
+pub(crate) struct Table<K, V> {
+    rows: HashMap<K, V>,
+    order: Vec<K>,
+}
+
+impl<K, V> Default for Table<K, V> {
+    fn default() -> Self {
+        Self { rows: HashMap::new(), order: Vec::new() }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Table<K, V> {
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.rows.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.rows.get_mut(key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.rows.contains_key(key)
+    }
+
+    /// Inserts a new row, failing like a primary key violation if the key is taken.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), DbError> {
+        if self.rows.contains_key(&key) {
+            return Err(DbError::UniqueViolation);
+        }
+        self.upsert(key, value);
+        Ok(())
+    }
+
+    /// Inserts the row or replaces the row stored under the key.
+    pub fn upsert(&mut self, key: K, value: V) {
+        if self.rows.insert(key.clone(), value).is_none() {
+            self.order.push(key);
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.rows.remove(key);
+        if removed.is_some() {
+            self.order.retain(|k| k != key);
+        }
+        removed
+    }
+
+    /// Removes the rows not matching `keep` and returns how many were removed.
+    pub fn retain(&mut self, mut keep: impl FnMut(&V) -> bool) -> u64 {
+        let rows = &mut self.rows;
+        let before = self.order.len();
+        self.order.retain(|k| {
+            let kept = keep(&rows[k]);
+            if !kept {
+                rows.remove(k);
+            }
+            kept
+        });
+        (before - self.order.len()) as u64
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.order.iter().map(|k| &self.rows[k])
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use chrono::Utc;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::repositories::import_checkpoint_repository::ImportCheckpointRepository;
+use postings_db::DbError;
+use postings_db_inmemory::repositories::import_checkpoint_repository::InMemoryImportCheckpointRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_save_advances_checkpoint_of_source() {
+    let repo = InMemoryImportCheckpointRepository::new(Arc::new(InMemoryStore::new()));
+    let checkpoint = ImportCheckpoint {
+        id: Uuid::new_v4(),
+        ledger_id: Uuid::new_v4(),
+        import_type: "OPENING_BALANCE".to_string(),
+        source_hash: [7; 34],
+        next_line: 1,
+        loaded_count: 0,
+        quarantined_count: 0,
+        completed: false,
+        updated: Utc::now(),
+    };
+    repo.save(&checkpoint).await.unwrap();
+    let advanced = ImportCheckpoint { next_line: 101, loaded_count: 99, quarantined_count: 1, ..checkpoint.clone() };
+    repo.save(&advanced).await.unwrap();
+
+    let found = repo.find_by_source(checkpoint.ledger_id, "OPENING_BALANCE", &[7; 34]).await.unwrap();
+    assert_eq!(found, Some(advanced));
+    assert_eq!(repo.find_by_source(checkpoint.ledger_id, "OPENING_BALANCE", &[8; 34]).await.unwrap(), None);
+
+    // A second checkpoint for the same source is rejected
+    let duplicate = ImportCheckpoint { id: Uuid::new_v4(), ..checkpoint };
+    assert!(matches!(repo.save(&duplicate).await, Err(DbError::UniqueViolation)));
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::DbError;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use uuid::Uuid;
+
+fn create_test_posting(ledger_id: Uuid, opr: u8, record_time: DateTime<Utc>) -> Posting {
+    Posting {
+        id: Uuid::new_v4(),
+        record_user: [0; 34],
+        record_time,
+        opr_id: [opr; 34],
+        opr_time: record_time,
+        opr_type: [0; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time: record_time,
+        pst_type: Default::default(),
+        pst_status: Default::default(),
+        ledger_id,
+        val_time: None,
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        antecedent_id: None,
+        antecedent_hash: None,
+        hash: None,
+        hash_excluded_fields: None,
+        hash_version: 2,
+        hash_algorithm: "SHA2_256".to_string(),
+    }
+}
+
+fn create_test_line(posting: &Posting) -> PostingLine {
+    PostingLine {
+        id: Uuid::new_v4(),
+        opr_id: posting.opr_id,
+        pst_time: posting.pst_time,
+        record_time: posting.record_time,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_latest_posting_prefers_last_saved_on_equal_record_time() {
+    let repo = InMemoryPostingRepository::new(Arc::new(InMemoryStore::new()));
+    let ledger_id = Uuid::new_v4();
+    let record_time = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+    let older = create_test_posting(ledger_id, 1, record_time - Duration::seconds(1));
+    let first = create_test_posting(ledger_id, 2, record_time);
+    let second = create_test_posting(ledger_id, 3, record_time);
+    for posting in [&first, &older, &second] {
+        repo.save(posting).await.unwrap();
+    }
+
+    let latest = repo.find_first_by_ledger_order_by_record_time_desc(ledger_id).await.unwrap();
+    assert_eq!(latest.map(|p| p.id), Some(second.id));
+    assert!(repo.find_first_by_ledger_order_by_record_time_desc(Uuid::new_v4()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_batch_with_recorded_operation_writes_nothing() {
+    let store = Arc::new(InMemoryStore::new());
+    let repo = InMemoryPostingRepository::new(store.clone());
+    let line_repo = InMemoryPostingLineRepository::new(store);
+    let ledger_id = Uuid::new_v4();
+    let now = Utc::now();
+    let recorded = create_test_posting(ledger_id, 1, now);
+    repo.save(&recorded).await.unwrap();
+
+    let fresh = create_test_posting(ledger_id, 2, now);
+    let fresh_line = create_test_line(&fresh);
+    let retry = create_test_posting(ledger_id, 1, now);
+    let result = repo.save_batch(&[(fresh.clone(), vec![fresh_line.clone()]), (retry, vec![])]).await;
+
+    assert!(matches!(result, Err(DbError::UniqueViolation)));
+    assert!(repo.find_by_id(fresh.id).await.unwrap().is_none());
+    assert!(line_repo.find_by_id(fresh_line.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_reversal_discards_the_posting_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let repo = InMemoryPostingRepository::new(store.clone());
+    let line_repo = InMemoryPostingLineRepository::new(store);
+    let ledger_id = Uuid::new_v4();
+    let now = Utc::now();
+    let original = create_test_posting(ledger_id, 1, now);
+    repo.save(&original).await.unwrap();
+
+    let reversal = Posting { discarded_id: Some(original.id), ..create_test_posting(ledger_id, 2, now) };
+    let reversal_line = create_test_line(&reversal);
+    assert!(repo.save_reversal(&reversal, &[reversal_line.clone()], original.id).await.unwrap());
+
+    let discarded = repo.find_by_id(original.id).await.unwrap().unwrap();
+    assert_eq!(discarded.discarding_id, Some(reversal.id));
+    assert!(line_repo.find_by_id(reversal_line.id).await.unwrap().is_some());
+
+    let again = create_test_posting(ledger_id, 3, now);
+    assert!(!repo.save_reversal(&again, &[], original.id).await.unwrap());
+    assert!(repo.find_by_id(again.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_line_stats_skip_discarded_lines() {
+    let line_repo = InMemoryPostingLineRepository::new(Arc::new(InMemoryStore::new()));
+    let account_id = Uuid::new_v4();
+    let start = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+    let first = create_test_posting(Uuid::new_v4(), 1, start);
+    let last = create_test_posting(Uuid::new_v4(), 2, start + Duration::days(1));
+    let discarded = create_test_posting(Uuid::new_v4(), 3, start + Duration::days(2));
+    line_repo.save(PostingLine { account_id, ..create_test_line(&first) }).await.unwrap();
+    line_repo.save(PostingLine { account_id, ..create_test_line(&last) }).await.unwrap();
+    line_repo.save(PostingLine { account_id, discarded_time: Some(start), ..create_test_line(&discarded) }).await.unwrap();
+
+    let stats = line_repo.find_stats_by_account_id(account_id).await.unwrap();
+    assert_eq!(stats.line_count, 2);
+    assert_eq!(stats.first_pst_time, Some(first.pst_time));
+    assert_eq!(stats.last_pst_time, Some(last.pst_time));
+    assert_eq!(stats.last_opr_id, Some(last.opr_id));
+}
+
+#[tokio::test]
+async fn test_totals_are_grouped_by_account() {
+    let line_repo = InMemoryPostingLineRepository::new(Arc::new(InMemoryStore::new()));
+    let (cash, bank, other) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let now = Utc::now();
+    let posting = create_test_posting(Uuid::new_v4(), 1, now);
+    let later = create_test_posting(Uuid::new_v4(), 2, now + Duration::days(1));
+    line_repo.save(PostingLine { account_id: cash, debit_amount: BigDecimal::from(10), ..create_test_line(&posting) }).await.unwrap();
+    line_repo.save(PostingLine { account_id: cash, debit_amount: BigDecimal::from(5), ..create_test_line(&posting) }).await.unwrap();
+    line_repo.save(PostingLine { account_id: cash, debit_amount: BigDecimal::from(7), ..create_test_line(&later) }).await.unwrap();
+    line_repo.save(PostingLine { account_id: bank, credit_amount: BigDecimal::from(15), ..create_test_line(&posting) }).await.unwrap();
+    line_repo.save(PostingLine { account_id: other, credit_amount: BigDecimal::from(1), ..create_test_line(&posting) }).await.unwrap();
+
+    let totals = line_repo.sum_by_account_ids_and_pst_time_less_than_equal(&[cash, bank, Uuid::new_v4()], now).await.unwrap();
+    assert_eq!(totals.len(), 2);
+    let cash_totals = totals.iter().find(|t| t.account_id == cash).unwrap();
+    assert_eq!(cash_totals.total_debit, BigDecimal::from(15));
+    let bank_totals = totals.iter().find(|t| t.account_id == bank).unwrap();
+    assert_eq!(bank_totals.total_credit, BigDecimal::from(15));
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::upsert::Upserted;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_upsert_reports_existing_trace() {
+    let repo = InMemoryPostingTraceRepository::new(Arc::new(InMemoryStore::new()));
+    let trace = PostingTrace {
+        id: Uuid::new_v4(),
+        tgt_pst_id: Uuid::new_v4(),
+        src_pst_time: Utc::now(),
+        src_pst_id: Uuid::new_v4(),
+        src_opr_id: [1; 34],
+        account_id: Uuid::new_v4(),
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        src_pst_hash: None,
+    };
+    assert_eq!(repo.upsert(trace.clone()).await.unwrap(), Upserted::Inserted(trace.clone()));
+
+    // A retry with a new id refreshes the stored trace
+    let retried = PostingTrace { id: Uuid::new_v4(), debit_amount: BigDecimal::from(20), ..trace.clone() };
+    let refreshed = PostingTrace { debit_amount: BigDecimal::from(20), ..trace.clone() };
+    assert_eq!(repo.upsert(retried.clone()).await.unwrap(), Upserted::Existing(refreshed.clone()));
+    assert_eq!(repo.find_by_id(trace.id).await.unwrap(), Some(refreshed));
+    assert_eq!(repo.find_by_id(retried.id).await.unwrap(), None);
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::ledger_stmt_repository::LedgerStmtRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::UnitOfWork;
+use postings_db::DbError;
+use postings_db_inmemory::repositories::ledger_stmt_repository::InMemoryLedgerStmtRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use uuid::Uuid;
+
+fn create_test_posting(ledger_id: Uuid, opr: u8) -> Posting {
+    let now = Utc::now();
+    Posting {
+        id: Uuid::new_v4(),
+        record_user: [0; 34],
+        record_time: now,
+        opr_id: [opr; 34],
+        opr_time: now,
+        opr_type: [0; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time: now,
+        pst_type: Default::default(),
+        pst_status: Default::default(),
+        ledger_id,
+        val_time: None,
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        antecedent_id: None,
+        antecedent_hash: None,
+        hash: None,
+        hash_excluded_fields: None,
+        hash_version: 2,
+        hash_algorithm: "SHA2_256".to_string(),
+    }
+}
+
+fn create_test_stmt(ledger_id: Uuid) -> LedgerStmt {
+    LedgerStmt {
+        id: Uuid::new_v4(),
+        ledger_id,
+        total_debit: BigDecimal::from(10),
+        total_credit: BigDecimal::from(10),
+        posting_id: None,
+        pst_time: Utc::now(),
+        stmt_status: StmtStatus::Simulated,
+        stmt_seq_nbr: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_commit_stores_all_writes() {
+    let store = Arc::new(InMemoryStore::new());
+    let ledger_id = Uuid::new_v4();
+    let posting = create_test_posting(ledger_id, 1);
+    let line = PostingLine { id: Uuid::new_v4(), opr_id: posting.opr_id, ..Default::default() };
+    let stmt = LedgerStmt { posting_id: Some(posting.id), stmt_status: StmtStatus::Closed, ..create_test_stmt(ledger_id) };
+
+    let mut work = UnitOfWork::new();
+    work.save_posting(posting.clone()).save_posting_line(line.clone()).save_ledger_stmt(stmt.clone());
+    InMemoryUnitOfWorkRepository::new(store.clone()).commit(work).await.unwrap();
+
+    assert_eq!(InMemoryPostingRepository::new(store.clone()).find_by_id(posting.id).await.unwrap(), Some(posting));
+    assert_eq!(InMemoryPostingLineRepository::new(store.clone()).find_by_id(line.id).await.unwrap(), Some(line));
+    assert_eq!(InMemoryLedgerStmtRepository::new(store).find_by_id(stmt.id).await.unwrap(), Some(stmt));
+}
+
+#[tokio::test]
+async fn test_failed_commit_reverts_earlier_writes() {
+    let store = Arc::new(InMemoryStore::new());
+    let ledger_id = Uuid::new_v4();
+    let stmt_repo = InMemoryLedgerStmtRepository::new(store.clone());
+    let open = stmt_repo.save(create_test_stmt(ledger_id)).await.unwrap();
+    let taken = PostingLine { id: Uuid::new_v4(), ..Default::default() };
+    InMemoryPostingLineRepository::new(store.clone()).save(taken.clone()).await.unwrap();
+
+    let posting = create_test_posting(ledger_id, 2);
+    let closed = LedgerStmt { posting_id: Some(posting.id), stmt_status: StmtStatus::Closed, ..open.clone() };
+    let mut work = UnitOfWork::new();
+    // The line id is taken, so the last write fails
+    work.save_posting(posting.clone()).save_ledger_stmt(closed).save_posting_line(taken);
+    let result = InMemoryUnitOfWorkRepository::new(store.clone()).commit(work).await;
+
+    assert!(matches!(result, Err(DbError::UniqueViolation)));
+    assert_eq!(InMemoryPostingRepository::new(store).find_by_id(posting.id).await.unwrap(), None);
+    assert_eq!(stmt_repo.find_by_id(open.id).await.unwrap(), Some(open));
+}
+
+#[tokio::test]
+async fn test_failed_savepoint_rolls_back_alone() {
+    let store = Arc::new(InMemoryStore::new());
+    let ledger_id = Uuid::new_v4();
+    let taken = PostingLine { id: Uuid::new_v4(), ..Default::default() };
+    InMemoryPostingLineRepository::new(store.clone()).save(taken.clone()).await.unwrap();
+
+    let first = create_test_posting(ledger_id, 3);
+    let failing = create_test_posting(ledger_id, 4);
+    let last = create_test_posting(ledger_id, 5);
+    let mut failing_work = UnitOfWork::new();
+    // The line id is taken, so the savepoint fails after its posting was written
+    failing_work.save_posting(failing.clone()).save_posting_line(taken);
+    let mut ok_work = UnitOfWork::new();
+    ok_work.save_posting(last.clone());
+    let mut work = UnitOfWork::new();
+    work.save_posting(first.clone()).savepoint("failing", failing_work).savepoint("ok", ok_work);
+    let committed = InMemoryUnitOfWorkRepository::new(store.clone()).commit(work).await.unwrap();
+
+    assert_eq!(committed.rolled_back.len(), 1);
+    assert_eq!(committed.rolled_back[0].savepoint, "failing");
+    assert!(matches!(committed.rolled_back[0].error, DbError::UniqueViolation));
+    let posting_repo = InMemoryPostingRepository::new(store);
+    assert_eq!(posting_repo.find_by_id(first.id).await.unwrap(), Some(first));
+    assert_eq!(posting_repo.find_by_id(failing.id).await.unwrap(), None);
+    assert_eq!(posting_repo.find_by_id(last.id).await.unwrap(), Some(last));
+}
@@ -0,0 +1,29 @@
+-- =============================================================================
+-- SETTLEMENT BATCHES
+-- =============================================================================
+
+CREATE TABLE settlement_batch (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    batch_ref BLOB NOT NULL,          -- Binary hash
+    control_amount DECIMAL(19, 2) NOT NULL,
+    control_count INT,
+    status ENUM('OPEN', 'CLOSED', 'SETTLED', 'REJECTED') NOT NULL,
+    created TIMESTAMP NOT NULL,
+    closed_time TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE TABLE settlement_batch_entry (
+    batch_id CHAR(36) NOT NULL,
+    posting_id CHAR(36) NOT NULL,
+    amount DECIMAL(19, 2) NOT NULL,
+    assigned_time TIMESTAMP NOT NULL,
+    PRIMARY KEY (batch_id, posting_id),
+    UNIQUE KEY unique_batch_posting (posting_id),
+    FOREIGN KEY (batch_id) REFERENCES settlement_batch(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_settlement_batch_ledger_id ON settlement_batch(ledger_id);
+CREATE INDEX idx_settlement_batch_batch_ref ON settlement_batch(batch_ref(34));
@@ -0,0 +1,27 @@
+-- =============================================================================
+-- END-OF-DAY PROCESSING
+-- =============================================================================
+
+CREATE TABLE eod_run (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    business_date DATE NOT NULL,
+    status ENUM('RUNNING', 'COMPLETED', 'FAILED') NOT NULL,
+    started TIMESTAMP NOT NULL,
+    finished TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    UNIQUE KEY unique_eod_run (ledger_id, business_date)
+) ENGINE=InnoDB;
+
+-- One row per executed step; completed rows act as checkpoints for resumed runs
+CREATE TABLE eod_step_result (
+    run_id CHAR(36) NOT NULL,
+    step_seq INT NOT NULL,
+    step_name VARCHAR(255) NOT NULL,
+    status ENUM('RUNNING', 'COMPLETED', 'FAILED') NOT NULL,
+    message VARCHAR(2048),
+    started TIMESTAMP NOT NULL,
+    finished TIMESTAMP NULL,
+    PRIMARY KEY (run_id, step_seq),
+    FOREIGN KEY (run_id) REFERENCES eod_run(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,11 @@
+-- =============================================================================
+-- LEDGER HOLIDAY CALENDAR
+-- =============================================================================
+
+CREATE TABLE ledger_holiday (
+    ledger_id CHAR(36) NOT NULL,
+    holiday_date DATE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    PRIMARY KEY (ledger_id, holiday_date),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
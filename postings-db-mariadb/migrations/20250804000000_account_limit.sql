@@ -0,0 +1,12 @@
+-- =============================================================================
+-- ACCOUNT LIMITS
+-- =============================================================================
+
+CREATE TABLE account_limit (
+    account_id CHAR(36) PRIMARY KEY,
+    max_debit_balance DECIMAL(19, 2),
+    overdraft_limit DECIMAL(19, 2),
+    daily_turnover_limit DECIMAL(19, 2),
+    updated TIMESTAMP NOT NULL,
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
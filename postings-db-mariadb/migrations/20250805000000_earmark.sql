@@ -0,0 +1,17 @@
+-- =============================================================================
+-- EARMARKS (BLOCKED AMOUNTS)
+-- =============================================================================
+
+CREATE TABLE earmark (
+    id CHAR(36) PRIMARY KEY,
+    account_id CHAR(36) NOT NULL,
+    amount DECIMAL(19, 2) NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    created TIMESTAMP NOT NULL,
+    expiry TIMESTAMP NULL,
+    status ENUM('ACTIVE', 'RELEASED', 'CONSUMED', 'EXPIRED') NOT NULL,
+    closed_time TIMESTAMP NULL,
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_earmark_account_status ON earmark(account_id, status);
@@ -0,0 +1,41 @@
+-- =============================================================================
+-- STANDING ORDERS
+-- =============================================================================
+
+CREATE TABLE standing_order (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    source_account_id CHAR(36) NOT NULL,
+    target_account_id CHAR(36) NOT NULL,
+    amount DECIMAL(19, 2) NOT NULL,
+    opr_type BLOB NOT NULL,           -- Binary hash
+    frequency ENUM('DAILY', 'WEEKLY', 'MONTHLY', 'QUARTERLY', 'YEARLY') NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    max_executions INT,
+    max_retries INT NOT NULL,
+    next_execution_date DATE NOT NULL,
+    occurrence INT NOT NULL,
+    execution_count INT NOT NULL,
+    status ENUM('ACTIVE', 'COMPLETED', 'CANCELLED') NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (source_account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (target_account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE TABLE standing_order_execution (
+    id CHAR(36) PRIMARY KEY,
+    order_id CHAR(36) NOT NULL,
+    execution_date DATE NOT NULL,
+    attempt INT NOT NULL,
+    status ENUM('EXECUTED', 'FAILED', 'SKIPPED') NOT NULL,
+    posting_id CHAR(36),
+    message VARCHAR(2048),
+    executed_time TIMESTAMP NOT NULL,
+    FOREIGN KEY (order_id) REFERENCES standing_order(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_standing_order_due ON standing_order(status, next_execution_date);
+CREATE INDEX idx_standing_order_execution_order ON standing_order_execution(order_id);
@@ -0,0 +1,29 @@
+-- =============================================================================
+-- FEE SCHEDULES
+-- =============================================================================
+
+CREATE TABLE fee_schedule (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    opr_type BLOB NOT NULL,           -- Binary hash
+    fee_type ENUM('FLAT', 'PERCENTAGE', 'TIERED') NOT NULL,
+    flat_amount DECIMAL(19, 2),
+    rate DECIMAL(19, 8),
+    min_fee DECIMAL(19, 2),
+    max_fee DECIMAL(19, 2),
+    fee_account_id CHAR(36) NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (fee_account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE TABLE fee_tier (
+    schedule_id CHAR(36) NOT NULL,
+    from_amount DECIMAL(19, 2) NOT NULL,
+    flat_amount DECIMAL(19, 2) NOT NULL,
+    rate DECIMAL(19, 8) NOT NULL,
+    PRIMARY KEY (schedule_id, from_amount),
+    FOREIGN KEY (schedule_id) REFERENCES fee_schedule(id)
+) ENGINE=InnoDB;
+
+CREATE UNIQUE INDEX idx_fee_schedule_opr_type ON fee_schedule(ledger_id, opr_type(34));
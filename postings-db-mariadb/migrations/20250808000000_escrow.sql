@@ -0,0 +1,28 @@
+-- =============================================================================
+-- ESCROW (TWO-PHASE POSTINGS)
+-- =============================================================================
+
+CREATE TABLE escrow (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    source_account_id CHAR(36) NOT NULL,
+    escrow_account_id CHAR(36) NOT NULL,
+    target_account_id CHAR(36) NOT NULL,
+    amount DECIMAL(19, 2) NOT NULL,
+    opr_type BLOB NOT NULL,           -- Binary hash
+    status ENUM('PENDING', 'RELEASED', 'RETURNED') NOT NULL,
+    timeout TIMESTAMP NOT NULL,
+    timeout_action ENUM('RELEASE', 'RETURN') NOT NULL,
+    initiation_posting_id CHAR(36),
+    resolution_posting_id CHAR(36),
+    created TIMESTAMP NOT NULL,
+    resolved_time TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (source_account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (escrow_account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (target_account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (initiation_posting_id) REFERENCES posting(id),
+    FOREIGN KEY (resolution_posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_escrow_pending_timeout ON escrow(status, timeout);
@@ -0,0 +1,18 @@
+-- =============================================================================
+-- IMPORT QUARANTINE
+-- =============================================================================
+
+CREATE TABLE quarantined_entry (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    import_type VARCHAR(64) NOT NULL,
+    line_number BIGINT NOT NULL,
+    payload TEXT NOT NULL,
+    reason VARCHAR(1024) NOT NULL,
+    status ENUM('PENDING', 'RESUBMITTED', 'DISCARDED') NOT NULL,
+    created TIMESTAMP NOT NULL,
+    resolved_time TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_quarantined_entry_ledger_status ON quarantined_entry(ledger_id, status);
@@ -0,0 +1,7 @@
+-- =============================================================================
+-- SIMULATED STATEMENT EXPIRY
+-- =============================================================================
+
+ALTER TABLE account_stmt ADD COLUMN expiry TIMESTAMP NULL;
+
+CREATE INDEX idx_account_stmt_status_expiry ON account_stmt(stmt_status, expiry);
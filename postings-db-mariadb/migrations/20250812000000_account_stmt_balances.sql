@@ -0,0 +1,8 @@
+-- =============================================================================
+-- STATEMENT OPENING BALANCE, LINE COUNT AND CLOSING BALANCE
+-- =============================================================================
+
+ALTER TABLE account_stmt ADD COLUMN opening_debit DECIMAL(19, 2) NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN opening_credit DECIMAL(19, 2) NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN line_count BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN closing_balance DECIMAL(19, 2) AS (total_debit - total_credit) STORED;
@@ -0,0 +1,18 @@
+-- =============================================================================
+-- LEDGER STATEMENTS
+-- =============================================================================
+
+CREATE TABLE ledger_stmt (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    total_debit DECIMAL(19, 2) NOT NULL,
+    total_credit DECIMAL(19, 2) NOT NULL,
+    posting_id CHAR(36),
+    pst_time TIMESTAMP NOT NULL,
+    stmt_status ENUM('SIMULATED', 'CLOSED') NOT NULL,
+    stmt_seq_nbr INT NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_ledger_stmt_ledger_pst_time ON ledger_stmt(ledger_id, pst_time);
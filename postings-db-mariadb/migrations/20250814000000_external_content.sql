@@ -0,0 +1,11 @@
+-- =============================================================================
+-- EXTERNALLY STORED OPERATION DETAILS
+-- =============================================================================
+
+CREATE TABLE external_content (
+    content_hash VARBINARY(34) PRIMARY KEY, -- Binary hash
+    object_key VARCHAR(1024) NOT NULL,
+    size BIGINT NOT NULL,
+    media_type VARCHAR(255) NOT NULL,
+    created TIMESTAMP NOT NULL
+) ENGINE=InnoDB;
@@ -0,0 +1,17 @@
+-- =============================================================================
+-- ASYNCHRONOUS STATEMENT JOBS
+-- =============================================================================
+
+CREATE TABLE stmt_job (
+    id CHAR(36) PRIMARY KEY,
+    account_id CHAR(36) NOT NULL,
+    ref_time TIMESTAMP NOT NULL,
+    status ENUM('PENDING', 'RUNNING', 'COMPLETED', 'FAILED') NOT NULL,
+    stmt_id CHAR(36),
+    error_message VARCHAR(1024),
+    created TIMESTAMP NOT NULL,
+    completed_time TIMESTAMP NULL,
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_stmt_job_status_created ON stmt_job(status, created);
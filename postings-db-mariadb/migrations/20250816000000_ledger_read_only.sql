@@ -0,0 +1,5 @@
+-- =============================================================================
+-- LEDGER READ-ONLY MODE
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN read_only BOOLEAN NOT NULL DEFAULT FALSE;
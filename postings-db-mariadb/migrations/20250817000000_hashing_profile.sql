@@ -0,0 +1,12 @@
+-- =============================================================================
+-- HASHING PROFILES
+-- =============================================================================
+
+CREATE TABLE hashing_profile (
+    ledger_id CHAR(36) PRIMARY KEY,
+    excluded_fields VARCHAR(255) NOT NULL,
+    updated TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+ALTER TABLE posting ADD COLUMN hash_excluded_fields VARCHAR(255);
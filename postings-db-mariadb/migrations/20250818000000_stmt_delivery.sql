@@ -0,0 +1,16 @@
+-- =============================================================================
+-- STATEMENT DELIVERY MANIFESTS
+-- =============================================================================
+
+CREATE TABLE stmt_delivery (
+    id CHAR(36) PRIMARY KEY,
+    stmt_id CHAR(36) NOT NULL,
+    format ENUM('CSV', 'JSON', 'PDF') NOT NULL,
+    checksum VARBINARY(34) NOT NULL, -- Binary hash
+    signature BLOB,
+    key_id VARCHAR(255),
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (stmt_id) REFERENCES account_stmt(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_stmt_delivery_stmt_checksum ON stmt_delivery(stmt_id, checksum);
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- POSTING LINE CATEGORIZATION RULES
+-- =============================================================================
+
+CREATE TABLE category_rule (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    category VARCHAR(255) NOT NULL,
+    priority INT NOT NULL,
+    account_id CHAR(36),
+    details VARBINARY(34), -- Binary hash
+    src_account VARBINARY(34), -- Binary hash
+    opr_src VARBINARY(34), -- Binary hash
+    min_amount DECIMAL(19, 2),
+    max_amount DECIMAL(19, 2),
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_category_rule_ledger_priority ON category_rule(ledger_id, priority);
@@ -0,0 +1,31 @@
+-- =============================================================================
+-- ACCOUNT PRODUCTS
+-- =============================================================================
+
+CREATE TABLE product (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    interest_rate DECIMAL(19, 8),
+    day_count_convention ENUM('ACT360', 'ACT365FIXED', 'ACTACTISDA', 'THIRTY360'),
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE TABLE account_product (
+    account_id CHAR(36) PRIMARY KEY,
+    product_id CHAR(36) NOT NULL,
+    linked TIMESTAMP NOT NULL,
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (product_id) REFERENCES product(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_product_ledger_id ON product(ledger_id);
+CREATE INDEX idx_account_product_product_id ON account_product(product_id);
+
+-- Fee schedules of a product override the ledger-wide schedule of the same operation type
+ALTER TABLE fee_schedule ADD COLUMN product_id CHAR(36) NULL;
+ALTER TABLE fee_schedule ADD FOREIGN KEY (product_id) REFERENCES product(id);
+DROP INDEX idx_fee_schedule_opr_type ON fee_schedule;
+CREATE INDEX idx_fee_schedule_opr_type ON fee_schedule(ledger_id, opr_type(34));
+CREATE UNIQUE INDEX idx_fee_schedule_product ON fee_schedule(product_id, opr_type(34));
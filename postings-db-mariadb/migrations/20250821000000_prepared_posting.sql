@@ -0,0 +1,19 @@
+-- =============================================================================
+-- TWO-PHASE POSTINGS
+-- =============================================================================
+
+CREATE TABLE prepared_posting (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    opr_id VARBINARY(34) NOT NULL,     -- Binary hash
+    payload LONGTEXT NOT NULL,
+    status ENUM('PREPARED', 'COMMITTED', 'ABORTED') NOT NULL,
+    coordinator_ref VARCHAR(255) NOT NULL,
+    expires TIMESTAMP NOT NULL,
+    created TIMESTAMP NOT NULL,
+    resolved_time TIMESTAMP NULL,
+    posting_id CHAR(36),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_prepared_posting_status_expires ON prepared_posting(status, expires);
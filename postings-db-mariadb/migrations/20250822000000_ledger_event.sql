@@ -0,0 +1,355 @@
+-- =============================================================================
+-- CHANGE DATA CAPTURE EVENTS
+-- =============================================================================
+
+-- Append-only; written by triggers in the transaction of the mutation itself
+CREATE TABLE ledger_event (
+    seq BIGINT AUTO_INCREMENT PRIMARY KEY,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255) NOT NULL,
+    operation ENUM('INSERT', 'UPDATE', 'DELETE') NOT NULL,
+    payload LONGTEXT NOT NULL,        -- JSON of the row, the deleted row for DELETE
+    created TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_ledger_event_entity ON ledger_event(entity_type, entity_id);
+
+CREATE TRIGGER trg_ledger_event_no_update BEFORE UPDATE ON ledger_event
+    FOR EACH ROW SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'ledger_event is append-only';
+CREATE TRIGGER trg_ledger_event_no_delete BEFORE DELETE ON ledger_event
+    FOR EACH ROW SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'ledger_event is append-only';
+
+-- Binary hashes are rendered as hex in the payloads
+
+CREATE TRIGGER trg_chart_of_account_cdc_insert AFTER INSERT ON chart_of_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('chart_of_account', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id));
+
+CREATE TRIGGER trg_chart_of_account_cdc_update AFTER UPDATE ON chart_of_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('chart_of_account', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id));
+
+CREATE TRIGGER trg_chart_of_account_cdc_delete AFTER DELETE ON chart_of_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('chart_of_account', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id));
+
+CREATE TRIGGER trg_ledger_cdc_insert AFTER INSERT ON ledger
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'coa_id', NEW.coa_id,
+            'read_only', NEW.read_only));
+
+CREATE TRIGGER trg_ledger_cdc_update AFTER UPDATE ON ledger
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'coa_id', NEW.coa_id,
+            'read_only', NEW.read_only));
+
+CREATE TRIGGER trg_ledger_cdc_delete AFTER DELETE ON ledger
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'coa_id', OLD.coa_id,
+            'read_only', OLD.read_only));
+
+CREATE TRIGGER trg_ledger_account_cdc_insert AFTER INSERT ON ledger_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger_account', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'ledger_id', NEW.ledger_id,
+            'parent_id', NEW.parent_id,
+            'coa_id', NEW.coa_id,
+            'balance_side', NEW.balance_side,
+            'category', NEW.category));
+
+CREATE TRIGGER trg_ledger_account_cdc_update AFTER UPDATE ON ledger_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger_account', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'ledger_id', NEW.ledger_id,
+            'parent_id', NEW.parent_id,
+            'coa_id', NEW.coa_id,
+            'balance_side', NEW.balance_side,
+            'category', NEW.category));
+
+CREATE TRIGGER trg_ledger_account_cdc_delete AFTER DELETE ON ledger_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger_account', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'ledger_id', OLD.ledger_id,
+            'parent_id', OLD.parent_id,
+            'coa_id', OLD.coa_id,
+            'balance_side', OLD.balance_side,
+            'category', OLD.category));
+
+CREATE TRIGGER trg_named_cdc_insert AFTER INSERT ON named
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('named', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'container', NEW.container,
+            'context', NEW.context,
+            'name', NEW.name,
+            'language', NEW.language,
+            'created', NEW.created,
+            'user_details', HEX(NEW.user_details),
+            'short_desc', NEW.short_desc,
+            'long_desc', NEW.long_desc,
+            'container_type', NEW.container_type));
+
+CREATE TRIGGER trg_named_cdc_update AFTER UPDATE ON named
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('named', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'container', NEW.container,
+            'context', NEW.context,
+            'name', NEW.name,
+            'language', NEW.language,
+            'created', NEW.created,
+            'user_details', HEX(NEW.user_details),
+            'short_desc', NEW.short_desc,
+            'long_desc', NEW.long_desc,
+            'container_type', NEW.container_type));
+
+CREATE TRIGGER trg_named_cdc_delete AFTER DELETE ON named
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('named', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'container', OLD.container,
+            'context', OLD.context,
+            'name', OLD.name,
+            'language', OLD.language,
+            'created', OLD.created,
+            'user_details', HEX(OLD.user_details),
+            'short_desc', OLD.short_desc,
+            'long_desc', OLD.long_desc,
+            'container_type', OLD.container_type));
+
+CREATE TRIGGER trg_posting_cdc_insert AFTER INSERT ON posting
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'record_user', HEX(NEW.record_user),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_time', NEW.opr_time,
+            'opr_type', HEX(NEW.opr_type),
+            'opr_details', HEX(NEW.opr_details),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'ledger_id', NEW.ledger_id,
+            'val_time', NEW.val_time,
+            'discarded_id', NEW.discarded_id,
+            'discarded_time', NEW.discarded_time,
+            'discarding_id', NEW.discarding_id,
+            'antecedent_id', NEW.antecedent_id,
+            'antecedent_hash', HEX(NEW.antecedent_hash),
+            'hash', HEX(NEW.hash),
+            'hash_excluded_fields', NEW.hash_excluded_fields));
+
+CREATE TRIGGER trg_posting_cdc_update AFTER UPDATE ON posting
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'record_user', HEX(NEW.record_user),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_time', NEW.opr_time,
+            'opr_type', HEX(NEW.opr_type),
+            'opr_details', HEX(NEW.opr_details),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'ledger_id', NEW.ledger_id,
+            'val_time', NEW.val_time,
+            'discarded_id', NEW.discarded_id,
+            'discarded_time', NEW.discarded_time,
+            'discarding_id', NEW.discarding_id,
+            'antecedent_id', NEW.antecedent_id,
+            'antecedent_hash', HEX(NEW.antecedent_hash),
+            'hash', HEX(NEW.hash),
+            'hash_excluded_fields', NEW.hash_excluded_fields));
+
+CREATE TRIGGER trg_posting_cdc_delete AFTER DELETE ON posting
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'record_user', HEX(OLD.record_user),
+            'record_time', OLD.record_time,
+            'opr_id', HEX(OLD.opr_id),
+            'opr_time', OLD.opr_time,
+            'opr_type', HEX(OLD.opr_type),
+            'opr_details', HEX(OLD.opr_details),
+            'opr_src', HEX(OLD.opr_src),
+            'pst_time', OLD.pst_time,
+            'pst_type', OLD.pst_type,
+            'pst_status', OLD.pst_status,
+            'ledger_id', OLD.ledger_id,
+            'val_time', OLD.val_time,
+            'discarded_id', OLD.discarded_id,
+            'discarded_time', OLD.discarded_time,
+            'discarding_id', OLD.discarding_id,
+            'antecedent_id', OLD.antecedent_id,
+            'antecedent_hash', HEX(OLD.antecedent_hash),
+            'hash', HEX(OLD.hash),
+            'hash_excluded_fields', OLD.hash_excluded_fields));
+
+CREATE TRIGGER trg_posting_line_cdc_insert AFTER INSERT ON posting_line
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_line', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'details', HEX(NEW.details),
+            'src_account', HEX(NEW.src_account),
+            'base_line', NEW.base_line,
+            'sub_opr_src_id', HEX(NEW.sub_opr_src_id),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'hash', HEX(NEW.hash),
+            'discarded_time', NEW.discarded_time));
+
+CREATE TRIGGER trg_posting_line_cdc_update AFTER UPDATE ON posting_line
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_line', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'details', HEX(NEW.details),
+            'src_account', HEX(NEW.src_account),
+            'base_line', NEW.base_line,
+            'sub_opr_src_id', HEX(NEW.sub_opr_src_id),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'hash', HEX(NEW.hash),
+            'discarded_time', NEW.discarded_time));
+
+CREATE TRIGGER trg_posting_line_cdc_delete AFTER DELETE ON posting_line
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_line', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'account_id', OLD.account_id,
+            'debit_amount', OLD.debit_amount,
+            'credit_amount', OLD.credit_amount,
+            'details', HEX(OLD.details),
+            'src_account', HEX(OLD.src_account),
+            'base_line', OLD.base_line,
+            'sub_opr_src_id', HEX(OLD.sub_opr_src_id),
+            'record_time', OLD.record_time,
+            'opr_id', HEX(OLD.opr_id),
+            'opr_src', HEX(OLD.opr_src),
+            'pst_time', OLD.pst_time,
+            'pst_type', OLD.pst_type,
+            'pst_status', OLD.pst_status,
+            'hash', HEX(OLD.hash),
+            'discarded_time', OLD.discarded_time));
+
+CREATE TRIGGER trg_account_stmt_cdc_insert AFTER INSERT ON account_stmt
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('account_stmt', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'youngest_pst_id', NEW.youngest_pst_id,
+            'total_debit', NEW.total_debit,
+            'total_credit', NEW.total_credit,
+            'posting_id', NEW.posting_id,
+            'pst_time', NEW.pst_time,
+            'stmt_status', NEW.stmt_status,
+            'latest_pst_id', NEW.latest_pst_id,
+            'stmt_seq_nbr', NEW.stmt_seq_nbr,
+            'expiry', NEW.expiry,
+            'opening_debit', NEW.opening_debit,
+            'opening_credit', NEW.opening_credit,
+            'line_count', NEW.line_count));
+
+CREATE TRIGGER trg_account_stmt_cdc_update AFTER UPDATE ON account_stmt
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('account_stmt', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'youngest_pst_id', NEW.youngest_pst_id,
+            'total_debit', NEW.total_debit,
+            'total_credit', NEW.total_credit,
+            'posting_id', NEW.posting_id,
+            'pst_time', NEW.pst_time,
+            'stmt_status', NEW.stmt_status,
+            'latest_pst_id', NEW.latest_pst_id,
+            'stmt_seq_nbr', NEW.stmt_seq_nbr,
+            'expiry', NEW.expiry,
+            'opening_debit', NEW.opening_debit,
+            'opening_credit', NEW.opening_credit,
+            'line_count', NEW.line_count));
+
+CREATE TRIGGER trg_account_stmt_cdc_delete AFTER DELETE ON account_stmt
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('account_stmt', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'account_id', OLD.account_id,
+            'youngest_pst_id', OLD.youngest_pst_id,
+            'total_debit', OLD.total_debit,
+            'total_credit', OLD.total_credit,
+            'posting_id', OLD.posting_id,
+            'pst_time', OLD.pst_time,
+            'stmt_status', OLD.stmt_status,
+            'latest_pst_id', OLD.latest_pst_id,
+            'stmt_seq_nbr', OLD.stmt_seq_nbr,
+            'expiry', OLD.expiry,
+            'opening_debit', OLD.opening_debit,
+            'opening_credit', OLD.opening_credit,
+            'line_count', OLD.line_count));
+
+CREATE TRIGGER trg_posting_trace_cdc_insert AFTER INSERT ON posting_trace
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_trace', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'tgt_pst_id', NEW.tgt_pst_id,
+            'src_pst_time', NEW.src_pst_time,
+            'src_pst_id', NEW.src_pst_id,
+            'src_opr_id', HEX(NEW.src_opr_id),
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'src_pst_hash', HEX(NEW.src_pst_hash)));
+
+CREATE TRIGGER trg_posting_trace_cdc_update AFTER UPDATE ON posting_trace
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_trace', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'tgt_pst_id', NEW.tgt_pst_id,
+            'src_pst_time', NEW.src_pst_time,
+            'src_pst_id', NEW.src_pst_id,
+            'src_opr_id', HEX(NEW.src_opr_id),
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'src_pst_hash', HEX(NEW.src_pst_hash)));
+
+CREATE TRIGGER trg_posting_trace_cdc_delete AFTER DELETE ON posting_trace
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_trace', OLD.id, 'DELETE', JSON_OBJECT(
+            'id', OLD.id,
+            'tgt_pst_id', OLD.tgt_pst_id,
+            'src_pst_time', OLD.src_pst_time,
+            'src_pst_id', OLD.src_pst_id,
+            'src_opr_id', HEX(OLD.src_opr_id),
+            'account_id', OLD.account_id,
+            'debit_amount', OLD.debit_amount,
+            'credit_amount', OLD.credit_amount,
+            'src_pst_hash', HEX(OLD.src_pst_hash)));
@@ -0,0 +1,22 @@
+-- =============================================================================
+-- CHANGE EVENT COMPACTION
+-- =============================================================================
+
+-- Positions acknowledged by downstream consumers; events below all of them are dispatched
+CREATE TABLE ledger_event_consumer (
+    name VARCHAR(255) PRIMARY KEY,
+    last_seq BIGINT NOT NULL,
+    updated TIMESTAMP NOT NULL
+) ENGINE=InnoDB;
+
+CREATE TABLE ledger_event_archive (
+    seq BIGINT PRIMARY KEY,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255) NOT NULL,
+    operation ENUM('INSERT', 'UPDATE', 'DELETE') NOT NULL,
+    payload LONGTEXT NOT NULL,
+    created TIMESTAMP(6) NOT NULL
+) ENGINE=InnoDB;
+
+-- Dispatched events may now be moved to the archive; rows stay immutable
+DROP TRIGGER trg_ledger_event_no_delete;
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- API KEYS
+-- =============================================================================
+
+CREATE TABLE api_key (
+    id CHAR(36) PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    tenant_id CHAR(36) NOT NULL,
+    ledger_id CHAR(36),
+    roles VARCHAR(255) NOT NULL,
+    key_prefix VARCHAR(16) NOT NULL,
+    key_hash VARBINARY(34) NOT NULL,  -- Binary hash
+    created TIMESTAMP NOT NULL,
+    expires TIMESTAMP NULL,
+    revoked_time TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    UNIQUE KEY unique_api_key_prefix (key_prefix)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_api_key_tenant_id ON api_key(tenant_id);
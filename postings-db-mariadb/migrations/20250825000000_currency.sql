@@ -0,0 +1,129 @@
+-- =============================================================================
+-- MULTI-CURRENCY ACCOUNTS AND POSTING LINES
+-- =============================================================================
+
+-- NULL on accounts holding several currencies and on ledgers without currencies
+ALTER TABLE ledger_account ADD COLUMN currency CHAR(3) NULL;
+
+ALTER TABLE posting_line ADD COLUMN currency CHAR(3) NULL;
+ALTER TABLE posting_line ADD COLUMN fx_base_currency CHAR(3) NULL;
+ALTER TABLE posting_line ADD COLUMN fx_rate DECIMAL(19, 10) NULL;
+
+ALTER TABLE posting_line ADD CONSTRAINT chk_posting_line_fx
+    CHECK ((fx_base_currency IS NULL) = (fx_rate IS NULL));
+
+-- JSON array of {currency, total_debit, total_credit}
+ALTER TABLE account_stmt ADD COLUMN currency_totals LONGTEXT NULL;
+
+-- Change events carry the new columns
+DROP TRIGGER trg_ledger_account_cdc_insert;
+CREATE TRIGGER trg_ledger_account_cdc_insert AFTER INSERT ON ledger_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger_account', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'ledger_id', NEW.ledger_id,
+            'parent_id', NEW.parent_id,
+            'coa_id', NEW.coa_id,
+            'balance_side', NEW.balance_side,
+            'category', NEW.category,
+            'currency', NEW.currency));
+
+DROP TRIGGER trg_ledger_account_cdc_update;
+CREATE TRIGGER trg_ledger_account_cdc_update AFTER UPDATE ON ledger_account
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('ledger_account', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'ledger_id', NEW.ledger_id,
+            'parent_id', NEW.parent_id,
+            'coa_id', NEW.coa_id,
+            'balance_side', NEW.balance_side,
+            'category', NEW.category,
+            'currency', NEW.currency));
+
+DROP TRIGGER trg_posting_line_cdc_insert;
+CREATE TRIGGER trg_posting_line_cdc_insert AFTER INSERT ON posting_line
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_line', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'details', HEX(NEW.details),
+            'src_account', HEX(NEW.src_account),
+            'base_line', NEW.base_line,
+            'sub_opr_src_id', HEX(NEW.sub_opr_src_id),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'hash', HEX(NEW.hash),
+            'discarded_time', NEW.discarded_time,
+            'currency', NEW.currency,
+            'fx_base_currency', NEW.fx_base_currency,
+            'fx_rate', NEW.fx_rate));
+
+DROP TRIGGER trg_posting_line_cdc_update;
+CREATE TRIGGER trg_posting_line_cdc_update AFTER UPDATE ON posting_line
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('posting_line', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'debit_amount', NEW.debit_amount,
+            'credit_amount', NEW.credit_amount,
+            'details', HEX(NEW.details),
+            'src_account', HEX(NEW.src_account),
+            'base_line', NEW.base_line,
+            'sub_opr_src_id', HEX(NEW.sub_opr_src_id),
+            'record_time', NEW.record_time,
+            'opr_id', HEX(NEW.opr_id),
+            'opr_src', HEX(NEW.opr_src),
+            'pst_time', NEW.pst_time,
+            'pst_type', NEW.pst_type,
+            'pst_status', NEW.pst_status,
+            'hash', HEX(NEW.hash),
+            'discarded_time', NEW.discarded_time,
+            'currency', NEW.currency,
+            'fx_base_currency', NEW.fx_base_currency,
+            'fx_rate', NEW.fx_rate));
+
+DROP TRIGGER trg_account_stmt_cdc_insert;
+CREATE TRIGGER trg_account_stmt_cdc_insert AFTER INSERT ON account_stmt
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('account_stmt', NEW.id, 'INSERT', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'youngest_pst_id', NEW.youngest_pst_id,
+            'total_debit', NEW.total_debit,
+            'total_credit', NEW.total_credit,
+            'posting_id', NEW.posting_id,
+            'pst_time', NEW.pst_time,
+            'stmt_status', NEW.stmt_status,
+            'latest_pst_id', NEW.latest_pst_id,
+            'stmt_seq_nbr', NEW.stmt_seq_nbr,
+            'expiry', NEW.expiry,
+            'opening_debit', NEW.opening_debit,
+            'opening_credit', NEW.opening_credit,
+            'line_count', NEW.line_count,
+            'currency_totals', NEW.currency_totals));
+
+DROP TRIGGER trg_account_stmt_cdc_update;
+CREATE TRIGGER trg_account_stmt_cdc_update AFTER UPDATE ON account_stmt
+    FOR EACH ROW INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES ('account_stmt', NEW.id, 'UPDATE', JSON_OBJECT(
+            'id', NEW.id,
+            'account_id', NEW.account_id,
+            'youngest_pst_id', NEW.youngest_pst_id,
+            'total_debit', NEW.total_debit,
+            'total_credit', NEW.total_credit,
+            'posting_id', NEW.posting_id,
+            'pst_time', NEW.pst_time,
+            'stmt_status', NEW.stmt_status,
+            'latest_pst_id', NEW.latest_pst_id,
+            'stmt_seq_nbr', NEW.stmt_seq_nbr,
+            'expiry', NEW.expiry,
+            'opening_debit', NEW.opening_debit,
+            'opening_credit', NEW.opening_credit,
+            'line_count', NEW.line_count,
+            'currency_totals', NEW.currency_totals));
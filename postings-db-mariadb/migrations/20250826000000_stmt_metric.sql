@@ -0,0 +1,16 @@
+-- =============================================================================
+-- STATEMENT GENERATION METRICS
+-- =============================================================================
+
+CREATE TABLE stmt_metric (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    recorded TIMESTAMP NOT NULL,
+    duration_ms BIGINT NOT NULL,
+    line_count BIGINT NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_stmt_metric_ledger_recorded ON stmt_metric(ledger_id, recorded);
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- BACKFILL JOBS
+-- =============================================================================
+
+CREATE TABLE backfill_job (
+    id CHAR(36) PRIMARY KEY,
+    task_name VARCHAR(255) NOT NULL,
+    ledger_id CHAR(36) NOT NULL,
+    status ENUM('RUNNING', 'COMPLETED', 'FAILED') NOT NULL,
+    batch_size INT NOT NULL,
+    checkpoint VARCHAR(255),
+    processed BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    error_message VARCHAR(1024),
+    started TIMESTAMP NOT NULL,
+    updated TIMESTAMP NOT NULL,
+    finished TIMESTAMP NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_backfill_job_task_ledger ON backfill_job(task_name, ledger_id, status);
@@ -0,0 +1,19 @@
+-- =============================================================================
+-- STATEMENT REPAIRS
+-- =============================================================================
+
+CREATE TABLE stmt_repair (
+    id CHAR(36) PRIMARY KEY,
+    stmt_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    pst_time TIMESTAMP NOT NULL,
+    recorded TEXT NOT NULL,
+    expected TEXT NOT NULL,
+    principal VARCHAR(255) NOT NULL,
+    reason VARCHAR(1024) NOT NULL,
+    repaired_time TIMESTAMP NOT NULL,
+    FOREIGN KEY (stmt_id) REFERENCES account_stmt(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_stmt_repair_account_repaired ON stmt_repair(account_id, repaired_time);
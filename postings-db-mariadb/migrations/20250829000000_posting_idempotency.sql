@@ -0,0 +1,9 @@
+-- =============================================================================
+-- POSTING IDEMPOTENCY
+-- =============================================================================
+
+-- UNIQUE(opr_id, discarding_id) does not apply while discarding_id is NULL, so an operation
+-- could be recorded twice. Only one current version of an operation is allowed.
+ALTER TABLE posting
+    ADD COLUMN current_opr_id VARBINARY(34) AS (IF(discarding_id IS NULL, opr_id, NULL)) STORED,
+    ADD UNIQUE KEY uq_posting_current_opr_id (current_opr_id);
@@ -0,0 +1,11 @@
+-- =============================================================================
+-- REVERSAL POLICIES
+-- =============================================================================
+
+CREATE TABLE reversal_policy (
+    ledger_id CHAR(36) PRIMARY KEY,
+    teller_window_days INT NOT NULL,
+    supervisor_window_days INT,
+    updated TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,34 @@
+-- =============================================================================
+-- SCHEDULED REPORTS
+-- =============================================================================
+
+CREATE TABLE report_schedule (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    report_type ENUM('TRIAL_BALANCE', 'DAILY_JOURNAL') NOT NULL,
+    format ENUM('JSON', 'CSV', 'PDF') NOT NULL,
+    frequency ENUM('DAILY', 'MONTH_END') NOT NULL,
+    next_ref_time TIMESTAMP(6) NOT NULL,
+    status ENUM('ACTIVE', 'CANCELLED') NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE TABLE report_artifact (
+    id CHAR(36) PRIMARY KEY,
+    schedule_id CHAR(36),
+    ledger_id CHAR(36) NOT NULL,
+    report_type ENUM('TRIAL_BALANCE', 'DAILY_JOURNAL') NOT NULL,
+    format ENUM('JSON', 'CSV', 'PDF') NOT NULL,
+    ref_time TIMESTAMP(6) NOT NULL,
+    version INT NOT NULL,
+    object_key VARCHAR(512) NOT NULL,
+    checksum BLOB NOT NULL,           -- Binary multihash of the content
+    size BIGINT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (schedule_id) REFERENCES report_schedule(id),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    UNIQUE (ledger_id, report_type, ref_time, format, version)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_report_schedule_due ON report_schedule(status, next_ref_time);
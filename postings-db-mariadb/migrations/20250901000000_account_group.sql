@@ -0,0 +1,21 @@
+-- =============================================================================
+-- ACCOUNT GROUPS
+-- =============================================================================
+
+CREATE TABLE account_group (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    category ENUM('RE', 'EX', 'AS', 'LI', 'EQ', 'NOOP', 'NORE', 'NOEX'),
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    UNIQUE (ledger_id, name)
+) ENGINE=InnoDB;
+
+CREATE TABLE account_group_member (
+    group_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    PRIMARY KEY (group_id, account_id),
+    FOREIGN KEY (group_id) REFERENCES account_group(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
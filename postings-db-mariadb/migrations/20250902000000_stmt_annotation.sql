@@ -0,0 +1,14 @@
+-- =============================================================================
+-- STATEMENT ANNOTATIONS
+-- =============================================================================
+
+-- No foreign key on stmt_id: notes on simulated statements stay on record after
+-- the statements themselves are purged.
+CREATE TABLE stmt_annotation (
+    id CHAR(36) PRIMARY KEY,
+    stmt_id CHAR(36) NOT NULL,
+    author VARCHAR(255) NOT NULL,
+    text TEXT NOT NULL,
+    created TIMESTAMP(6) NOT NULL,
+    INDEX idx_stmt_annotation_stmt (stmt_id, created)
+) ENGINE=InnoDB;
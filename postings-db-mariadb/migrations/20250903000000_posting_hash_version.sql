@@ -0,0 +1,6 @@
+-- =============================================================================
+-- POSTING HASH VERSION
+-- =============================================================================
+
+-- Postings recorded before this migration were hashed by the legacy rules (1).
+ALTER TABLE posting ADD COLUMN hash_version SMALLINT NOT NULL DEFAULT 1;
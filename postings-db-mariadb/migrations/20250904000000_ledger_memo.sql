@@ -0,0 +1,5 @@
+-- =============================================================================
+-- MEMO LEDGERS
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN memo BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,5 @@
+-- =============================================================================
+-- CLOSING SUMMARIES
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN closing_summary BOOLEAN NOT NULL DEFAULT FALSE;
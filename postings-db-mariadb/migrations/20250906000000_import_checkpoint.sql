@@ -0,0 +1,17 @@
+-- =============================================================================
+-- IMPORT CHECKPOINTS
+-- =============================================================================
+
+CREATE TABLE import_checkpoint (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    import_type VARCHAR(64) NOT NULL,
+    source_hash VARBINARY(34) NOT NULL,
+    next_line BIGINT NOT NULL,
+    loaded_count BIGINT NOT NULL,
+    quarantined_count BIGINT NOT NULL,
+    completed BOOLEAN NOT NULL,
+    updated TIMESTAMP NOT NULL,
+    UNIQUE (ledger_id, import_type, source_hash),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,15 @@
+-- =============================================================================
+-- OUTBOX
+-- =============================================================================
+
+CREATE TABLE outbox (
+    id CHAR(36) PRIMARY KEY,
+    event_type VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    delivered TIMESTAMP NULL
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_outbox_delivered_created ON outbox(delivered, created, id);
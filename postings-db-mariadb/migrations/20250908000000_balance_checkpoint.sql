@@ -0,0 +1,18 @@
+-- =============================================================================
+-- BALANCE CHECKPOINTS
+-- =============================================================================
+
+CREATE TABLE balance_checkpoint (
+    id CHAR(36) PRIMARY KEY,
+    account_id CHAR(36) NOT NULL,
+    as_of TIMESTAMP NOT NULL,
+    base_stmt_id CHAR(36),
+    total_debit DECIMAL(19, 2) NOT NULL,
+    total_credit DECIMAL(19, 2) NOT NULL,
+    line_count BIGINT NOT NULL,
+    currency_totals TEXT,
+    created TIMESTAMP NOT NULL,
+    UNIQUE (account_id, as_of),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (base_stmt_id) REFERENCES account_stmt(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- EXTERNAL REFERENCES
+-- =============================================================================
+
+CREATE TABLE external_ref (
+    id CHAR(36) PRIMARY KEY,
+    entity_type ENUM('POSTING', 'LEDGER_ACCOUNT') NOT NULL,
+    entity_id CHAR(36) NOT NULL,
+    ledger_id CHAR(36) NOT NULL,
+    ref_system VARCHAR(64) NOT NULL,
+    reference VARCHAR(255) NOT NULL,
+    unique_scope VARCHAR(36),
+    created TIMESTAMP NOT NULL,
+    UNIQUE (entity_type, entity_id, ref_system),
+    -- NULL scopes never conflict, so shared references are not constrained
+    UNIQUE (entity_type, ref_system, reference, unique_scope),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_external_ref_lookup ON external_ref(ledger_id, entity_type, ref_system, reference);
@@ -0,0 +1,7 @@
+-- =============================================================================
+-- HASH ALGORITHM
+-- =============================================================================
+
+-- Postings recorded before this migration were hashed with SHA-256.
+ALTER TABLE posting ADD COLUMN hash_algorithm VARCHAR(16) NOT NULL DEFAULT 'SHA2_256';
+ALTER TABLE hashing_profile ADD COLUMN hash_algorithm VARCHAR(16) NOT NULL DEFAULT 'SHA2_256';
@@ -0,0 +1,7 @@
+-- =============================================================================
+-- STATEMENT SIGNATURES
+-- =============================================================================
+
+-- Set when a statement is closed with a signer configured
+ALTER TABLE account_stmt ADD COLUMN signature VARBINARY(64);
+ALTER TABLE account_stmt ADD COLUMN signing_key_id VARCHAR(255);
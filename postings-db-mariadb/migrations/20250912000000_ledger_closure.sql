@@ -0,0 +1,18 @@
+-- =============================================================================
+-- LEDGER CLOSURES
+-- =============================================================================
+
+CREATE TABLE ledger_closure (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    account_id CHAR(36),
+    cut_off TIMESTAMP(6) NOT NULL,
+    allow_adjustments BOOLEAN NOT NULL DEFAULT FALSE,
+    closed_by VARCHAR(255) NOT NULL,
+    reason TEXT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_ledger_closure_ledger ON ledger_closure(ledger_id, cut_off);
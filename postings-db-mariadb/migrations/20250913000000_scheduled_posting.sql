@@ -0,0 +1,22 @@
+-- =============================================================================
+-- SCHEDULED POSTINGS
+-- =============================================================================
+
+CREATE TABLE scheduled_posting (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    opr_type BLOB NOT NULL,           -- Binary hash
+    pst_type ENUM('BUSI_TX', 'ADJ_TX', 'BAL_STMT', 'PNL_STMT', 'BS_STMT', 'LDG_CLSNG') NOT NULL,
+    lines TEXT NOT NULL,              -- JSON of the line templates
+    frequency ENUM('DAILY', 'WEEKLY', 'MONTHLY', 'QUARTERLY', 'YEARLY'),
+    start_time TIMESTAMP(6) NOT NULL,
+    end_time TIMESTAMP(6) NULL,
+    max_occurrences INT,
+    next_pst_time TIMESTAMP(6) NOT NULL,
+    occurrence INT NOT NULL,
+    status ENUM('ACTIVE', 'COMPLETED', 'CANCELLED') NOT NULL,
+    created TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_scheduled_posting_due ON scheduled_posting(status, next_pst_time);
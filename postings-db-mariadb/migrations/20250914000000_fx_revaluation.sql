@@ -0,0 +1,23 @@
+-- =============================================================================
+-- FX REVALUATIONS
+-- =============================================================================
+
+CREATE TABLE fx_revaluation (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    currency CHAR(3) NOT NULL,
+    valuation_time TIMESTAMP(6) NOT NULL,
+    foreign_balance DECIMAL(19, 2) NOT NULL,
+    rate DECIMAL(19, 10) NOT NULL,
+    carrying_value DECIMAL(19, 2) NOT NULL,
+    revalued_value DECIMAL(19, 2) NOT NULL,
+    posting_id CHAR(36),
+    created TIMESTAMP NOT NULL,
+    UNIQUE (account_id, valuation_time),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_fx_revaluation_ledger ON fx_revaluation(ledger_id, valuation_time);
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- LEDGER ACCOUNT NAMES
+-- =============================================================================
+
+CREATE TABLE ledger_account_name (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    number VARCHAR(64),
+    name VARCHAR(255) NOT NULL,
+    valid_from TIMESTAMP(6) NOT NULL,
+    valid_to TIMESTAMP(6) NULL,
+    created TIMESTAMP NOT NULL,
+    CHECK (valid_to IS NULL OR valid_to > valid_from),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_ledger_account_name_name ON ledger_account_name(ledger_id, name);
+CREATE INDEX idx_ledger_account_name_number ON ledger_account_name(ledger_id, number);
+CREATE INDEX idx_ledger_account_name_account ON ledger_account_name(account_id);
@@ -0,0 +1,17 @@
+-- =============================================================================
+-- POSTING TIME PRECISION
+-- =============================================================================
+
+-- Posting hashes cover times at microsecond precision, as PostgreSQL stores them. The
+-- explicit default keeps record_time from being updated on every change of the row.
+ALTER TABLE posting
+    MODIFY record_time TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    MODIFY opr_time TIMESTAMP(6) NOT NULL,
+    MODIFY pst_time TIMESTAMP(6) NOT NULL,
+    MODIFY val_time TIMESTAMP(6) NULL,
+    MODIFY discarded_time TIMESTAMP(6) NULL;
+
+ALTER TABLE posting_line
+    MODIFY record_time TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    MODIFY pst_time TIMESTAMP(6) NOT NULL,
+    MODIFY discarded_time TIMESTAMP(6) NULL;
@@ -0,0 +1,6 @@
+-- =============================================================================
+-- EARMARK CAPTURE POSTING
+-- =============================================================================
+
+ALTER TABLE earmark ADD COLUMN posting_id CHAR(36),
+    ADD FOREIGN KEY (posting_id) REFERENCES posting(id);
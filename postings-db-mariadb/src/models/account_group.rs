@@ -0,0 +1,54 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::account_group::AccountGroup;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountGroupDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub name: String,
+    pub category: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+fn category_to_db(category: &AccountCategory) -> String {
+    format!("{category:?}")
+}
+
+fn category_from_db(category: &str) -> AccountCategory {
+    match category {
+        "RE" => AccountCategory::RE,
+        "EX" => AccountCategory::EX,
+        "AS" => AccountCategory::AS,
+        "LI" => AccountCategory::LI,
+        "EQ" => AccountCategory::EQ,
+        "NORE" => AccountCategory::NORE,
+        "NOEX" => AccountCategory::NOEX,
+        _ => AccountCategory::NOOP,
+    }
+}
+
+impl From<AccountGroupDb> for AccountGroup {
+    fn from(g: AccountGroupDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&g.id).unwrap(),
+            ledger_id: Uuid::parse_str(&g.ledger_id).unwrap(),
+            name: g.name,
+            category: g.category.as_deref().map(category_from_db),
+            created: g.created,
+        }
+    }
+}
+
+impl From<AccountGroup> for AccountGroupDb {
+    fn from(g: AccountGroup) -> Self {
+        Self {
+            id: g.id.to_string(),
+            ledger_id: g.ledger_id.to_string(),
+            name: g.name,
+            category: g.category.as_ref().map(category_to_db),
+            created: g.created,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::account_limit::AccountLimit;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLimitDb {
+    pub account_id: String,
+    pub max_debit_balance: Option<BigDecimal>,
+    pub overdraft_limit: Option<BigDecimal>,
+    pub daily_turnover_limit: Option<BigDecimal>,
+    pub updated: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AccountLimitDb> for AccountLimit {
+    fn from(l: AccountLimitDb) -> Self {
+        Self {
+            account_id: Uuid::parse_str(&l.account_id).unwrap(),
+            max_debit_balance: l.max_debit_balance,
+            overdraft_limit: l.overdraft_limit,
+            daily_turnover_limit: l.daily_turnover_limit,
+            updated: l.updated,
+        }
+    }
+}
+
+impl From<AccountLimit> for AccountLimitDb {
+    fn from(l: AccountLimit) -> Self {
+        Self {
+            account_id: l.account_id.to_string(),
+            max_debit_balance: l.max_debit_balance,
+            overdraft_limit: l.overdraft_limit,
+            daily_turnover_limit: l.daily_turnover_limit,
+            updated: l.updated,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::api_key::ApiKey;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ApiKeyDb {
+    pub id: String,
+    pub name: String,
+    pub tenant_id: String,
+    pub ledger_id: Option<String>,
+    pub roles: String,
+    pub key_prefix: String,
+    pub key_hash: Vec<u8>,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub expires: Option<chrono::DateTime<chrono::Utc>>,
+    pub revoked_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<ApiKeyDb> for ApiKey {
+    fn from(k: ApiKeyDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&k.id).unwrap(),
+            name: k.name,
+            tenant_id: Uuid::parse_str(&k.tenant_id).unwrap(),
+            ledger_id: k.ledger_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            roles: k.roles,
+            key_prefix: k.key_prefix,
+            key_hash: k.key_hash.try_into().unwrap(),
+            created: k.created,
+            expires: k.expires,
+            revoked_time: k.revoked_time,
+        }
+    }
+}
+
+impl From<ApiKey> for ApiKeyDb {
+    fn from(k: ApiKey) -> Self {
+        Self {
+            id: k.id.to_string(),
+            name: k.name,
+            tenant_id: k.tenant_id.to_string(),
+            ledger_id: k.ledger_id.map(|id| id.to_string()),
+            roles: k.roles,
+            key_prefix: k.key_prefix,
+            key_hash: k.key_hash.to_vec(),
+            created: k.created,
+            expires: k.expires,
+            revoked_time: k.revoked_time,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::backfill_job::{BackfillJob, BackfillStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct BackfillJobDb {
+    pub id: String,
+    pub task_name: String,
+    pub ledger_id: String,
+    pub status: String,
+    pub batch_size: i32,
+    pub checkpoint: Option<String>,
+    pub processed: i64,
+    pub total: i64,
+    pub error_message: Option<String>,
+    pub started: chrono::DateTime<chrono::Utc>,
+    pub updated: chrono::DateTime<chrono::Utc>,
+    pub finished: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub fn status_to_db(status: &BackfillStatus) -> String {
+    match status {
+        BackfillStatus::Running => "RUNNING".to_string(),
+        BackfillStatus::Completed => "COMPLETED".to_string(),
+        BackfillStatus::Failed => "FAILED".to_string(),
+    }
+}
+
+impl From<BackfillJobDb> for BackfillJob {
+    fn from(j: BackfillJobDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&j.id).unwrap(),
+            task_name: j.task_name,
+            ledger_id: Uuid::parse_str(&j.ledger_id).unwrap(),
+            status: match j.status.as_str() {
+                "RUNNING" => BackfillStatus::Running,
+                "COMPLETED" => BackfillStatus::Completed,
+                _ => BackfillStatus::Failed,
+            },
+            batch_size: j.batch_size,
+            checkpoint: j.checkpoint,
+            processed: j.processed,
+            total: j.total,
+            error_message: j.error_message,
+            started: j.started,
+            updated: j.updated,
+            finished: j.finished,
+        }
+    }
+}
+
+impl From<BackfillJob> for BackfillJobDb {
+    fn from(j: BackfillJob) -> Self {
+        Self {
+            id: j.id.to_string(),
+            task_name: j.task_name,
+            ledger_id: j.ledger_id.to_string(),
+            status: status_to_db(&j.status),
+            batch_size: j.batch_size,
+            checkpoint: j.checkpoint,
+            processed: j.processed,
+            total: j.total,
+            error_message: j.error_message,
+            started: j.started,
+            updated: j.updated,
+            finished: j.finished,
+        }
+    }
+}
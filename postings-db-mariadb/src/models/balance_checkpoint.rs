@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct BalanceCheckpointDb {
+    pub id: String,
+    pub account_id: String,
+    pub as_of: chrono::DateTime<chrono::Utc>,
+    pub base_stmt_id: Option<String>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub line_count: i64,
+    pub currency_totals: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<BalanceCheckpointDb> for BalanceCheckpoint {
+    fn from(c: BalanceCheckpointDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&c.id).unwrap(),
+            account_id: Uuid::parse_str(&c.account_id).unwrap(),
+            as_of: c.as_of,
+            base_stmt_id: c.base_stmt_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            total_debit: c.total_debit,
+            total_credit: c.total_credit,
+            line_count: c.line_count,
+            currency_totals: c.currency_totals,
+            created: c.created,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use bigdecimal::BigDecimal;
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::category_rule::CategoryRule;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct CategoryRuleDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub category: String,
+    pub priority: i32,
+    pub account_id: Option<String>,
+    pub details: Option<Vec<u8>>,
+    pub src_account: Option<Vec<u8>>,
+    pub opr_src: Option<Vec<u8>>,
+    pub min_amount: Option<BigDecimal>,
+    pub max_amount: Option<BigDecimal>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<CategoryRuleDb> for CategoryRule {
+    fn from(r: CategoryRuleDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&r.id).unwrap(),
+            ledger_id: Uuid::parse_str(&r.ledger_id).unwrap(),
+            category: r.category,
+            priority: r.priority,
+            account_id: r.account_id.map(|s| Uuid::parse_str(&s).unwrap()),
+            details: r.details.map(|v| v.try_into().unwrap_or([0u8; 34])),
+            src_account: r.src_account.map(|v| v.try_into().unwrap_or([0u8; 34])),
+            opr_src: r.opr_src.map(|v| v.try_into().unwrap_or([0u8; 34])),
+            min_amount: r.min_amount,
+            max_amount: r.max_amount,
+            created: r.created,
+        }
+    }
+}
+
+impl From<CategoryRule> for CategoryRuleDb {
+    fn from(r: CategoryRule) -> Self {
+        Self {
+            id: r.id.to_string(),
+            ledger_id: r.ledger_id.to_string(),
+            category: r.category,
+            priority: r.priority,
+            account_id: r.account_id.map(|uuid| uuid.to_string()),
+            details: r.details.map(|v| v.to_vec()),
+            src_account: r.src_account.map(|v| v.to_vec()),
+            opr_src: r.opr_src.map(|v| v.to_vec()),
+            min_amount: r.min_amount,
+            max_amount: r.max_amount,
+            created: r.created,
+        }
+    }
+}
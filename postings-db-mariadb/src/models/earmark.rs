@@ -0,0 +1,66 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::earmark::{Earmark, EarmarkStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EarmarkDb {
+    pub id: String,
+    pub account_id: String,
+    pub amount: BigDecimal,
+    pub captured_amount: BigDecimal,
+    pub reason: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub expiry: Option<chrono::DateTime<chrono::Utc>>,
+    pub status: String,
+    pub closed_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub posting_id: Option<String>,
+}
+
+pub fn status_to_db(status: &EarmarkStatus) -> String {
+    match status {
+        EarmarkStatus::Active => "ACTIVE".to_string(),
+        EarmarkStatus::Released => "RELEASED".to_string(),
+        EarmarkStatus::Consumed => "CONSUMED".to_string(),
+        EarmarkStatus::Expired => "EXPIRED".to_string(),
+    }
+}
+
+impl From<EarmarkDb> for Earmark {
+    fn from(e: EarmarkDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&e.id).unwrap(),
+            account_id: Uuid::parse_str(&e.account_id).unwrap(),
+            amount: e.amount,
+            captured_amount: e.captured_amount,
+            reason: e.reason,
+            created: e.created,
+            expiry: e.expiry,
+            status: match e.status.as_str() {
+                "ACTIVE" => EarmarkStatus::Active,
+                "RELEASED" => EarmarkStatus::Released,
+                "CONSUMED" => EarmarkStatus::Consumed,
+                _ => EarmarkStatus::Expired,
+            },
+            closed_time: e.closed_time,
+            posting_id: e.posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+        }
+    }
+}
+
+impl From<Earmark> for EarmarkDb {
+    fn from(e: Earmark) -> Self {
+        Self {
+            id: e.id.to_string(),
+            account_id: e.account_id.to_string(),
+            amount: e.amount,
+            captured_amount: e.captured_amount,
+            reason: e.reason,
+            created: e.created,
+            expiry: e.expiry,
+            status: status_to_db(&e.status),
+            closed_time: e.closed_time,
+            posting_id: e.posting_id.map(|id| id.to_string()),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use chrono::NaiveDate;
+use postings_db::models::eod_run::{EodRun, EodStatus, EodStepResult};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EodRunDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub business_date: NaiveDate,
+    pub status: String,
+    pub started: chrono::DateTime<chrono::Utc>,
+    pub finished: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EodStepResultDb {
+    pub run_id: String,
+    pub step_seq: i32,
+    pub step_name: String,
+    pub status: String,
+    pub message: Option<String>,
+    pub started: chrono::DateTime<chrono::Utc>,
+    pub finished: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+fn status_from_db(status: &str) -> EodStatus {
+    match status {
+        "RUNNING" => EodStatus::Running,
+        "COMPLETED" => EodStatus::Completed,
+        _ => EodStatus::Failed,
+    }
+}
+
+fn status_to_db(status: EodStatus) -> String {
+    match status {
+        EodStatus::Running => "RUNNING".to_string(),
+        EodStatus::Completed => "COMPLETED".to_string(),
+        EodStatus::Failed => "FAILED".to_string(),
+    }
+}
+
+impl From<EodRunDb> for EodRun {
+    fn from(r: EodRunDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&r.id).unwrap(),
+            ledger_id: Uuid::parse_str(&r.ledger_id).unwrap(),
+            business_date: r.business_date,
+            status: status_from_db(&r.status),
+            started: r.started,
+            finished: r.finished,
+        }
+    }
+}
+
+impl From<EodRun> for EodRunDb {
+    fn from(r: EodRun) -> Self {
+        Self {
+            id: r.id.to_string(),
+            ledger_id: r.ledger_id.to_string(),
+            business_date: r.business_date,
+            status: status_to_db(r.status),
+            started: r.started,
+            finished: r.finished,
+        }
+    }
+}
+
+impl From<EodStepResultDb> for EodStepResult {
+    fn from(r: EodStepResultDb) -> Self {
+        Self {
+            run_id: Uuid::parse_str(&r.run_id).unwrap(),
+            step_seq: r.step_seq,
+            step_name: r.step_name,
+            status: status_from_db(&r.status),
+            message: r.message,
+            started: r.started,
+            finished: r.finished,
+        }
+    }
+}
+
+impl From<EodStepResult> for EodStepResultDb {
+    fn from(r: EodStepResult) -> Self {
+        Self {
+            run_id: r.run_id.to_string(),
+            step_seq: r.step_seq,
+            step_name: r.step_name,
+            status: status_to_db(r.status),
+            message: r.message,
+            started: r.started,
+            finished: r.finished,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::escrow::{Escrow, EscrowStatus, EscrowTimeoutAction};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EscrowDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub source_account_id: String,
+    pub escrow_account_id: String,
+    pub target_account_id: String,
+    pub amount: BigDecimal,
+    pub opr_type: Vec<u8>,
+    pub status: String,
+    pub timeout: chrono::DateTime<chrono::Utc>,
+    pub timeout_action: String,
+    pub initiation_posting_id: Option<String>,
+    pub resolution_posting_id: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub resolved_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub fn status_to_db(status: &EscrowStatus) -> String {
+    match status {
+        EscrowStatus::Pending => "PENDING".to_string(),
+        EscrowStatus::Released => "RELEASED".to_string(),
+        EscrowStatus::Returned => "RETURNED".to_string(),
+    }
+}
+
+impl From<EscrowDb> for Escrow {
+    fn from(e: EscrowDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&e.id).unwrap(),
+            ledger_id: Uuid::parse_str(&e.ledger_id).unwrap(),
+            source_account_id: Uuid::parse_str(&e.source_account_id).unwrap(),
+            escrow_account_id: Uuid::parse_str(&e.escrow_account_id).unwrap(),
+            target_account_id: Uuid::parse_str(&e.target_account_id).unwrap(),
+            amount: e.amount,
+            opr_type: e.opr_type.try_into().unwrap_or([0u8; 34]),
+            status: match e.status.as_str() {
+                "PENDING" => EscrowStatus::Pending,
+                "RELEASED" => EscrowStatus::Released,
+                _ => EscrowStatus::Returned,
+            },
+            timeout: e.timeout,
+            timeout_action: match e.timeout_action.as_str() {
+                "RELEASE" => EscrowTimeoutAction::Release,
+                _ => EscrowTimeoutAction::Return,
+            },
+            initiation_posting_id: e.initiation_posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            resolution_posting_id: e.resolution_posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            created: e.created,
+            resolved_time: e.resolved_time,
+        }
+    }
+}
+
+impl From<Escrow> for EscrowDb {
+    fn from(e: Escrow) -> Self {
+        Self {
+            id: e.id.to_string(),
+            ledger_id: e.ledger_id.to_string(),
+            source_account_id: e.source_account_id.to_string(),
+            escrow_account_id: e.escrow_account_id.to_string(),
+            target_account_id: e.target_account_id.to_string(),
+            amount: e.amount,
+            opr_type: e.opr_type.to_vec(),
+            status: status_to_db(&e.status),
+            timeout: e.timeout,
+            timeout_action: match e.timeout_action {
+                EscrowTimeoutAction::Release => "RELEASE".to_string(),
+                EscrowTimeoutAction::Return => "RETURN".to_string(),
+            },
+            initiation_posting_id: e.initiation_posting_id.map(|id| id.to_string()),
+            resolution_posting_id: e.resolution_posting_id.map(|id| id.to_string()),
+            created: e.created,
+            resolved_time: e.resolved_time,
+        }
+    }
+}
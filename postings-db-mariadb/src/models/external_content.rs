@@ -0,0 +1,35 @@
+use sqlx::FromRow;
+use postings_db::models::external_content::ExternalContent;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ExternalContentDb {
+    pub content_hash: Vec<u8>,
+    pub object_key: String,
+    pub size: i64,
+    pub media_type: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ExternalContentDb> for ExternalContent {
+    fn from(c: ExternalContentDb) -> Self {
+        Self {
+            content_hash: c.content_hash.try_into().unwrap_or([0u8; 34]),
+            object_key: c.object_key,
+            size: c.size,
+            media_type: c.media_type,
+            created: c.created,
+        }
+    }
+}
+
+impl From<ExternalContent> for ExternalContentDb {
+    fn from(c: ExternalContent) -> Self {
+        Self {
+            content_hash: c.content_hash.to_vec(),
+            object_key: c.object_key,
+            size: c.size,
+            media_type: c.media_type,
+            created: c.created,
+        }
+    }
+}
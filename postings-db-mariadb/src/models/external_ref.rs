@@ -0,0 +1,40 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::external_ref::{ExternalRef, ExternalRefEntity};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ExternalRefDb {
+    pub id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub ledger_id: String,
+    pub ref_system: String,
+    pub reference: String,
+    pub unique_scope: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn entity_type_to_db(entity_type: ExternalRefEntity) -> &'static str {
+    match entity_type {
+        ExternalRefEntity::Posting => "POSTING",
+        ExternalRefEntity::LedgerAccount => "LEDGER_ACCOUNT",
+    }
+}
+
+impl From<ExternalRefDb> for ExternalRef {
+    fn from(r: ExternalRefDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&r.id).unwrap(),
+            entity_type: match r.entity_type.as_str() {
+                "POSTING" => ExternalRefEntity::Posting,
+                _ => ExternalRefEntity::LedgerAccount,
+            },
+            entity_id: Uuid::parse_str(&r.entity_id).unwrap(),
+            ledger_id: Uuid::parse_str(&r.ledger_id).unwrap(),
+            ref_system: r.ref_system,
+            reference: r.reference,
+            unique_scope: r.unique_scope,
+            created: r.created,
+        }
+    }
+}
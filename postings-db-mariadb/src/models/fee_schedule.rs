@@ -0,0 +1,82 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::fee_schedule::{FeeSchedule, FeeTier, FeeType};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FeeScheduleDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub opr_type: Vec<u8>,
+    pub fee_type: String,
+    pub flat_amount: Option<BigDecimal>,
+    pub rate: Option<BigDecimal>,
+    pub min_fee: Option<BigDecimal>,
+    pub max_fee: Option<BigDecimal>,
+    pub fee_account_id: String,
+    pub product_id: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FeeTierDb {
+    pub schedule_id: String,
+    pub from_amount: BigDecimal,
+    pub flat_amount: BigDecimal,
+    pub rate: BigDecimal,
+}
+
+impl From<FeeScheduleDb> for FeeSchedule {
+    fn from(s: FeeScheduleDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&s.id).unwrap(),
+            ledger_id: Uuid::parse_str(&s.ledger_id).unwrap(),
+            opr_type: s.opr_type.try_into().unwrap_or([0u8; 34]),
+            fee_type: match s.fee_type.as_str() {
+                "FLAT" => FeeType::Flat,
+                "PERCENTAGE" => FeeType::Percentage,
+                _ => FeeType::Tiered,
+            },
+            flat_amount: s.flat_amount,
+            rate: s.rate,
+            min_fee: s.min_fee,
+            max_fee: s.max_fee,
+            fee_account_id: Uuid::parse_str(&s.fee_account_id).unwrap(),
+            product_id: s.product_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            created: s.created,
+        }
+    }
+}
+
+impl From<FeeSchedule> for FeeScheduleDb {
+    fn from(s: FeeSchedule) -> Self {
+        Self {
+            id: s.id.to_string(),
+            ledger_id: s.ledger_id.to_string(),
+            opr_type: s.opr_type.to_vec(),
+            fee_type: match s.fee_type {
+                FeeType::Flat => "FLAT".to_string(),
+                FeeType::Percentage => "PERCENTAGE".to_string(),
+                FeeType::Tiered => "TIERED".to_string(),
+            },
+            flat_amount: s.flat_amount,
+            rate: s.rate,
+            min_fee: s.min_fee,
+            max_fee: s.max_fee,
+            fee_account_id: s.fee_account_id.to_string(),
+            product_id: s.product_id.map(|id| id.to_string()),
+            created: s.created,
+        }
+    }
+}
+
+impl From<FeeTierDb> for FeeTier {
+    fn from(t: FeeTierDb) -> Self {
+        Self {
+            schedule_id: Uuid::parse_str(&t.schedule_id).unwrap(),
+            from_amount: t.from_amount,
+            flat_amount: t.flat_amount,
+            rate: t.rate,
+        }
+    }
+}
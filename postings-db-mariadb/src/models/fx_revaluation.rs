@@ -0,0 +1,37 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::fx_revaluation::FxRevaluation;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FxRevaluationDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub account_id: String,
+    pub currency: String,
+    pub valuation_time: chrono::DateTime<chrono::Utc>,
+    pub foreign_balance: BigDecimal,
+    pub rate: BigDecimal,
+    pub carrying_value: BigDecimal,
+    pub revalued_value: BigDecimal,
+    pub posting_id: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<FxRevaluationDb> for FxRevaluation {
+    fn from(r: FxRevaluationDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&r.id).unwrap(),
+            ledger_id: Uuid::parse_str(&r.ledger_id).unwrap(),
+            account_id: Uuid::parse_str(&r.account_id).unwrap(),
+            currency: r.currency,
+            valuation_time: r.valuation_time,
+            foreign_balance: r.foreign_balance,
+            rate: r.rate,
+            carrying_value: r.carrying_value,
+            revalued_value: r.revalued_value,
+            posting_id: r.posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            created: r.created,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::hashing_profile::HashingProfile;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct HashingProfileDb {
+    pub ledger_id: String,
+    pub excluded_fields: String,
+    pub hash_algorithm: String,
+    pub updated: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<HashingProfileDb> for HashingProfile {
+    fn from(p: HashingProfileDb) -> Self {
+        Self {
+            ledger_id: Uuid::parse_str(&p.ledger_id).unwrap(),
+            excluded_fields: p.excluded_fields,
+            hash_algorithm: p.hash_algorithm,
+            updated: p.updated,
+        }
+    }
+}
+
+impl From<HashingProfile> for HashingProfileDb {
+    fn from(p: HashingProfile) -> Self {
+        Self {
+            ledger_id: p.ledger_id.to_string(),
+            excluded_fields: p.excluded_fields,
+            hash_algorithm: p.hash_algorithm,
+            updated: p.updated,
+        }
+    }
+}
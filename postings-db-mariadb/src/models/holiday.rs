@@ -0,0 +1,31 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use chrono::NaiveDate;
+use postings_db::models::holiday::Holiday;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct HolidayDb {
+    pub ledger_id: String,
+    pub holiday_date: NaiveDate,
+    pub name: String,
+}
+
+impl From<HolidayDb> for Holiday {
+    fn from(h: HolidayDb) -> Self {
+        Self {
+            ledger_id: Uuid::parse_str(&h.ledger_id).unwrap(),
+            holiday_date: h.holiday_date,
+            name: h.name,
+        }
+    }
+}
+
+impl From<Holiday> for HolidayDb {
+    fn from(h: Holiday) -> Self {
+        Self {
+            ledger_id: h.ledger_id.to_string(),
+            holiday_date: h.holiday_date,
+            name: h.name,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ImportCheckpointDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub import_type: String,
+    pub source_hash: Vec<u8>,
+    pub next_line: i64,
+    pub loaded_count: i64,
+    pub quarantined_count: i64,
+    pub completed: bool,
+    pub updated: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ImportCheckpointDb> for ImportCheckpoint {
+    fn from(c: ImportCheckpointDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&c.id).unwrap(),
+            ledger_id: Uuid::parse_str(&c.ledger_id).unwrap(),
+            import_type: c.import_type,
+            source_hash: c.source_hash.try_into().unwrap_or([0u8; 34]),
+            next_line: c.next_line,
+            loaded_count: c.loaded_count,
+            quarantined_count: c.quarantined_count,
+            completed: c.completed,
+            updated: c.updated,
+        }
+    }
+}
@@ -4,4 +4,7 @@ use sqlx::FromRow;
 pub struct Ledger {
     pub id: String,
     pub coa_id: String,
+    pub read_only: bool,
+    pub memo: bool,
+    pub closing_summary: bool,
 }
\ No newline at end of file
@@ -0,0 +1,30 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::ledger_account_name::LedgerAccountName;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerAccountNameDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub account_id: String,
+    pub number: Option<String>,
+    pub name: String,
+    pub valid_from: chrono::DateTime<chrono::Utc>,
+    pub valid_to: Option<chrono::DateTime<chrono::Utc>>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<LedgerAccountNameDb> for LedgerAccountName {
+    fn from(n: LedgerAccountNameDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&n.id).unwrap(),
+            ledger_id: Uuid::parse_str(&n.ledger_id).unwrap(),
+            account_id: Uuid::parse_str(&n.account_id).unwrap(),
+            number: n.number,
+            name: n.name,
+            valid_from: n.valid_from,
+            valid_to: n.valid_to,
+            created: n.created,
+        }
+    }
+}
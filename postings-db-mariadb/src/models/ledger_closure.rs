@@ -0,0 +1,30 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::ledger_closure::LedgerClosure;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerClosureDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub account_id: Option<String>,
+    pub cut_off: chrono::DateTime<chrono::Utc>,
+    pub allow_adjustments: bool,
+    pub closed_by: String,
+    pub reason: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<LedgerClosureDb> for LedgerClosure {
+    fn from(c: LedgerClosureDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&c.id).unwrap(),
+            ledger_id: Uuid::parse_str(&c.ledger_id).unwrap(),
+            account_id: c.account_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            cut_off: c.cut_off,
+            allow_adjustments: c.allow_adjustments,
+            closed_by: c.closed_by,
+            reason: c.reason,
+            created: c.created,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use sqlx::FromRow;
+use postings_db::models::ledger_event::{LedgerEvent, LedgerEventOperation};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerEventDb {
+    pub seq: i64,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub operation: String,
+    pub payload: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<LedgerEventDb> for LedgerEvent {
+    fn from(e: LedgerEventDb) -> Self {
+        Self {
+            seq: e.seq,
+            entity_type: e.entity_type,
+            entity_id: e.entity_id,
+            operation: match e.operation.as_str() {
+                "INSERT" => LedgerEventOperation::Insert,
+                "UPDATE" => LedgerEventOperation::Update,
+                _ => LedgerEventOperation::Delete,
+            },
+            payload: e.payload,
+            created: e.created,
+        }
+    }
+}
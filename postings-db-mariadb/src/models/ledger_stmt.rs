@@ -0,0 +1,53 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::models::stmt_status::StmtStatus;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerStmtDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub posting_id: Option<String>,
+    pub pst_time: chrono::DateTime<chrono::Utc>,
+    pub stmt_status: String,
+    pub stmt_seq_nbr: i32,
+}
+
+impl From<LedgerStmtDb> for LedgerStmt {
+    fn from(s: LedgerStmtDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&s.id).unwrap(),
+            ledger_id: Uuid::parse_str(&s.ledger_id).unwrap(),
+            total_debit: s.total_debit,
+            total_credit: s.total_credit,
+            posting_id: s.posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            pst_time: s.pst_time,
+            stmt_status: match s.stmt_status.as_str() {
+                "CLOSED" => StmtStatus::Closed,
+                _ => StmtStatus::Simulated,
+            },
+            stmt_seq_nbr: s.stmt_seq_nbr,
+        }
+    }
+}
+
+impl From<LedgerStmt> for LedgerStmtDb {
+    fn from(s: LedgerStmt) -> Self {
+        Self {
+            id: s.id.to_string(),
+            ledger_id: s.ledger_id.to_string(),
+            total_debit: s.total_debit,
+            total_credit: s.total_credit,
+            posting_id: s.posting_id.map(|id| id.to_string()),
+            pst_time: s.pst_time,
+            stmt_status: match s.stmt_status {
+                StmtStatus::Simulated => "SIMULATED".to_string(),
+                StmtStatus::Closed => "CLOSED".to_string(),
+            },
+            stmt_seq_nbr: s.stmt_seq_nbr,
+        }
+    }
+}
@@ -4,3 +4,37 @@ pub mod posting_trace;
 pub mod named;
 pub mod chart_of_account;
 pub mod ledger;
+pub mod settlement_batch;
+pub mod eod_run;
+pub mod holiday;
+pub mod account_limit;
+pub mod earmark;
+pub mod standing_order;
+pub mod fee_schedule;
+pub mod escrow;
+pub mod quarantined_entry;
+pub mod ledger_stmt;
+pub mod external_content;
+pub mod stmt_job;
+pub mod hashing_profile;
+pub mod stmt_delivery;
+pub mod category_rule;
+pub mod product;
+pub mod prepared_posting;
+pub mod ledger_event;
+pub mod api_key;
+pub mod stmt_metric;
+pub mod backfill_job;
+pub mod stmt_repair;
+pub mod reversal_policy;
+pub mod report_schedule;
+pub mod account_group;
+pub mod stmt_annotation;
+pub mod import_checkpoint;
+pub mod outbox_entry;
+pub mod balance_checkpoint;
+pub mod external_ref;
+pub mod ledger_closure;
+pub mod scheduled_posting;
+pub mod fx_revaluation;
+pub mod ledger_account_name;
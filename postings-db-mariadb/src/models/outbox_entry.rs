@@ -0,0 +1,28 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::outbox_entry::OutboxEntry;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct OutboxEntryDb {
+    pub id: String,
+    pub event_type: String,
+    pub payload: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub delivered: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<OutboxEntryDb> for OutboxEntry {
+    fn from(e: OutboxEntryDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&e.id).unwrap(),
+            event_type: e.event_type,
+            payload: e.payload,
+            created: e.created,
+            attempts: e.attempts,
+            last_error: e.last_error,
+            delivered: e.delivered,
+        }
+    }
+}
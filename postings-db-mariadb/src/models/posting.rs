@@ -25,6 +25,9 @@ pub struct PostingDb {
     pub antecedent_id: Option<String>,
     pub antecedent_hash: Option<Vec<u8>>,
     pub hash: Option<Vec<u8>>,
+    pub hash_excluded_fields: Option<String>,
+    pub hash_version: i16,
+    pub hash_algorithm: String,
 }
 
 impl From<PostingDb> for Posting {
@@ -66,6 +69,9 @@ impl From<PostingDb> for Posting {
             antecedent_id: p.antecedent_id.map(|s| Uuid::parse_str(&s).unwrap()),
             antecedent_hash: p.antecedent_hash.map(|v| v.try_into().unwrap_or([0u8; 34])),
             hash: p.hash.map(|v| v.try_into().unwrap_or([0u8; 34])),
+            hash_excluded_fields: p.hash_excluded_fields,
+            hash_version: p.hash_version,
+            hash_algorithm: p.hash_algorithm,
         }
     }
 }
@@ -109,6 +115,9 @@ impl From<Posting> for PostingDb {
             antecedent_id: p.antecedent_id.map(|uuid| uuid.to_string()),
             antecedent_hash: p.antecedent_hash.map(|v| v.to_vec()),
             hash: p.hash.map(|v| v.to_vec()),
+            hash_excluded_fields: p.hash_excluded_fields,
+            hash_version: p.hash_version,
+            hash_algorithm: p.hash_algorithm,
         }
     }
 }
@@ -3,7 +3,7 @@ use sqlx::FromRow;
 use bigdecimal::BigDecimal;
 use postings_db::models::posting_type::PostingType;
 use postings_db::models::posting_status::PostingStatus;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
 
 #[derive(Debug, Clone, FromRow, PartialEq)]
 pub struct PostingLineDb {
@@ -23,6 +23,9 @@ pub struct PostingLineDb {
     pub pst_status: String,
     pub hash: Option<Vec<u8>>,
     pub discarded_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub currency: Option<String>,
+    pub fx_base_currency: Option<String>,
+    pub fx_rate: Option<BigDecimal>,
 }
 
 impl From<PostingLineDb> for PostingLine {
@@ -61,6 +64,9 @@ impl From<PostingLineDb> for PostingLine {
             },
             hash: p.hash.map(|v| v.try_into().unwrap_or([0u8; 34])),
             discarded_time: p.discarded_time,
+            currency: p.currency,
+            fx_base_currency: p.fx_base_currency,
+            fx_rate: p.fx_rate,
         }
     }
 }
@@ -101,6 +107,44 @@ impl From<PostingLine> for PostingLineDb {
             },
             hash: p.hash.map(|v| v.to_vec()),
             discarded_time: p.discarded_time,
+            currency: p.currency,
+            fx_base_currency: p.fx_base_currency,
+            fx_rate: p.fx_rate,
         }
     }
-}
\ No newline at end of file
+}
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLineStatsDb {
+    pub line_count: i64,
+    pub first_pst_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_pst_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_opr_id: Option<Vec<u8>>,
+}
+
+impl From<AccountLineStatsDb> for AccountLineStats {
+    fn from(s: AccountLineStatsDb) -> Self {
+        Self {
+            line_count: s.line_count,
+            first_pst_time: s.first_pst_time,
+            last_pst_time: s.last_pst_time,
+            last_opr_id: s.last_opr_id.and_then(|id| id.try_into().ok()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLineTotalsDb {
+    pub account_id: String,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
+
+impl From<AccountLineTotalsDb> for AccountLineTotals {
+    fn from(t: AccountLineTotalsDb) -> Self {
+        Self {
+            account_id: Uuid::parse_str(&t.account_id).unwrap(),
+            total_debit: t.total_debit,
+            total_credit: t.total_credit,
+        }
+    }
+}
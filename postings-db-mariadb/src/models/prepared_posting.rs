@@ -0,0 +1,63 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PreparedPostingDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub opr_id: Vec<u8>,
+    pub payload: String,
+    pub status: String,
+    pub coordinator_ref: String,
+    pub expires: chrono::DateTime<chrono::Utc>,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub resolved_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub posting_id: Option<String>,
+}
+
+pub fn status_to_db(status: &PreparedPostingStatus) -> String {
+    match status {
+        PreparedPostingStatus::Prepared => "PREPARED".to_string(),
+        PreparedPostingStatus::Committed => "COMMITTED".to_string(),
+        PreparedPostingStatus::Aborted => "ABORTED".to_string(),
+    }
+}
+
+impl From<PreparedPostingDb> for PreparedPosting {
+    fn from(p: PreparedPostingDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&p.id).unwrap(),
+            ledger_id: Uuid::parse_str(&p.ledger_id).unwrap(),
+            opr_id: p.opr_id.try_into().unwrap(),
+            payload: p.payload,
+            status: match p.status.as_str() {
+                "PREPARED" => PreparedPostingStatus::Prepared,
+                "COMMITTED" => PreparedPostingStatus::Committed,
+                _ => PreparedPostingStatus::Aborted,
+            },
+            coordinator_ref: p.coordinator_ref,
+            expires: p.expires,
+            created: p.created,
+            resolved_time: p.resolved_time,
+            posting_id: p.posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+        }
+    }
+}
+
+impl From<PreparedPosting> for PreparedPostingDb {
+    fn from(p: PreparedPosting) -> Self {
+        Self {
+            id: p.id.to_string(),
+            ledger_id: p.ledger_id.to_string(),
+            opr_id: p.opr_id.to_vec(),
+            payload: p.payload,
+            status: status_to_db(&p.status),
+            coordinator_ref: p.coordinator_ref,
+            expires: p.expires,
+            created: p.created,
+            resolved_time: p.resolved_time,
+            posting_id: p.posting_id.map(|id| id.to_string()),
+        }
+    }
+}
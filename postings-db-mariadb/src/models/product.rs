@@ -0,0 +1,77 @@
+use bigdecimal::BigDecimal;
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::product::{AccountProduct, DayCountConvention, Product};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ProductDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub name: String,
+    pub interest_rate: Option<BigDecimal>,
+    pub day_count_convention: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountProductDb {
+    pub account_id: String,
+    pub product_id: String,
+    pub linked: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ProductDb> for Product {
+    fn from(p: ProductDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&p.id).unwrap(),
+            ledger_id: Uuid::parse_str(&p.ledger_id).unwrap(),
+            name: p.name,
+            interest_rate: p.interest_rate,
+            day_count_convention: p.day_count_convention.map(|c| match c.as_str() {
+                "ACT360" => DayCountConvention::Act360,
+                "ACT365FIXED" => DayCountConvention::Act365Fixed,
+                "ACTACTISDA" => DayCountConvention::ActActIsda,
+                _ => DayCountConvention::Thirty360,
+            }),
+            created: p.created,
+        }
+    }
+}
+
+impl From<Product> for ProductDb {
+    fn from(p: Product) -> Self {
+        Self {
+            id: p.id.to_string(),
+            ledger_id: p.ledger_id.to_string(),
+            name: p.name,
+            interest_rate: p.interest_rate,
+            day_count_convention: p.day_count_convention.map(|c| match c {
+                DayCountConvention::Act360 => "ACT360".to_string(),
+                DayCountConvention::Act365Fixed => "ACT365FIXED".to_string(),
+                DayCountConvention::ActActIsda => "ACTACTISDA".to_string(),
+                DayCountConvention::Thirty360 => "THIRTY360".to_string(),
+            }),
+            created: p.created,
+        }
+    }
+}
+
+impl From<AccountProductDb> for AccountProduct {
+    fn from(l: AccountProductDb) -> Self {
+        Self {
+            account_id: Uuid::parse_str(&l.account_id).unwrap(),
+            product_id: Uuid::parse_str(&l.product_id).unwrap(),
+            linked: l.linked,
+        }
+    }
+}
+
+impl From<AccountProduct> for AccountProductDb {
+    fn from(l: AccountProduct) -> Self {
+        Self {
+            account_id: l.account_id.to_string(),
+            product_id: l.product_id.to_string(),
+            linked: l.linked,
+        }
+    }
+}
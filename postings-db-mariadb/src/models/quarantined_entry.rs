@@ -0,0 +1,60 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::quarantined_entry::{QuarantineStatus, QuarantinedEntry};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct QuarantinedEntryDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub import_type: String,
+    pub line_number: i64,
+    pub payload: String,
+    pub reason: String,
+    pub status: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub resolved_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub fn status_to_db(status: &QuarantineStatus) -> String {
+    match status {
+        QuarantineStatus::Pending => "PENDING".to_string(),
+        QuarantineStatus::Resubmitted => "RESUBMITTED".to_string(),
+        QuarantineStatus::Discarded => "DISCARDED".to_string(),
+    }
+}
+
+impl From<QuarantinedEntryDb> for QuarantinedEntry {
+    fn from(e: QuarantinedEntryDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&e.id).unwrap(),
+            ledger_id: Uuid::parse_str(&e.ledger_id).unwrap(),
+            import_type: e.import_type,
+            line_number: e.line_number,
+            payload: e.payload,
+            reason: e.reason,
+            status: match e.status.as_str() {
+                "PENDING" => QuarantineStatus::Pending,
+                "RESUBMITTED" => QuarantineStatus::Resubmitted,
+                _ => QuarantineStatus::Discarded,
+            },
+            created: e.created,
+            resolved_time: e.resolved_time,
+        }
+    }
+}
+
+impl From<QuarantinedEntry> for QuarantinedEntryDb {
+    fn from(e: QuarantinedEntry) -> Self {
+        Self {
+            id: e.id.to_string(),
+            ledger_id: e.ledger_id.to_string(),
+            import_type: e.import_type,
+            line_number: e.line_number,
+            payload: e.payload,
+            reason: e.reason,
+            status: status_to_db(&e.status),
+            created: e.created,
+            resolved_time: e.resolved_time,
+        }
+    }
+}
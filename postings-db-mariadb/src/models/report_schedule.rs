@@ -0,0 +1,138 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::report_schedule::{ReportArtifact, ReportFormat, ReportFrequency, ReportSchedule, ReportScheduleStatus, ReportType};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReportScheduleDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub report_type: String,
+    pub format: String,
+    pub frequency: String,
+    pub next_ref_time: chrono::DateTime<chrono::Utc>,
+    pub status: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReportArtifactDb {
+    pub id: String,
+    pub schedule_id: Option<String>,
+    pub ledger_id: String,
+    pub report_type: String,
+    pub format: String,
+    pub ref_time: chrono::DateTime<chrono::Utc>,
+    pub version: i32,
+    pub object_key: String,
+    pub checksum: Vec<u8>,
+    pub size: i64,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn report_type_to_db(report_type: &ReportType) -> String {
+    match report_type {
+        ReportType::TrialBalance => "TRIAL_BALANCE".to_string(),
+        ReportType::DailyJournal => "DAILY_JOURNAL".to_string(),
+    }
+}
+
+fn report_type_from_db(report_type: &str) -> ReportType {
+    match report_type {
+        "TRIAL_BALANCE" => ReportType::TrialBalance,
+        _ => ReportType::DailyJournal,
+    }
+}
+
+fn format_to_db(format: &ReportFormat) -> String {
+    match format {
+        ReportFormat::Json => "JSON".to_string(),
+        ReportFormat::Csv => "CSV".to_string(),
+        ReportFormat::Pdf => "PDF".to_string(),
+    }
+}
+
+fn format_from_db(format: &str) -> ReportFormat {
+    match format {
+        "JSON" => ReportFormat::Json,
+        "CSV" => ReportFormat::Csv,
+        _ => ReportFormat::Pdf,
+    }
+}
+
+impl From<ReportScheduleDb> for ReportSchedule {
+    fn from(s: ReportScheduleDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&s.id).unwrap(),
+            ledger_id: Uuid::parse_str(&s.ledger_id).unwrap(),
+            report_type: report_type_from_db(&s.report_type),
+            format: format_from_db(&s.format),
+            frequency: match s.frequency.as_str() {
+                "DAILY" => ReportFrequency::Daily,
+                _ => ReportFrequency::MonthEnd,
+            },
+            next_ref_time: s.next_ref_time,
+            status: match s.status.as_str() {
+                "ACTIVE" => ReportScheduleStatus::Active,
+                _ => ReportScheduleStatus::Cancelled,
+            },
+            created: s.created,
+        }
+    }
+}
+
+impl From<ReportSchedule> for ReportScheduleDb {
+    fn from(s: ReportSchedule) -> Self {
+        Self {
+            id: s.id.to_string(),
+            ledger_id: s.ledger_id.to_string(),
+            report_type: report_type_to_db(&s.report_type),
+            format: format_to_db(&s.format),
+            frequency: match s.frequency {
+                ReportFrequency::Daily => "DAILY".to_string(),
+                ReportFrequency::MonthEnd => "MONTH_END".to_string(),
+            },
+            next_ref_time: s.next_ref_time,
+            status: match s.status {
+                ReportScheduleStatus::Active => "ACTIVE".to_string(),
+                ReportScheduleStatus::Cancelled => "CANCELLED".to_string(),
+            },
+            created: s.created,
+        }
+    }
+}
+
+impl From<ReportArtifactDb> for ReportArtifact {
+    fn from(a: ReportArtifactDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&a.id).unwrap(),
+            schedule_id: a.schedule_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            ledger_id: Uuid::parse_str(&a.ledger_id).unwrap(),
+            report_type: report_type_from_db(&a.report_type),
+            format: format_from_db(&a.format),
+            ref_time: a.ref_time,
+            version: a.version,
+            object_key: a.object_key,
+            checksum: a.checksum.try_into().unwrap_or([0u8; 34]),
+            size: a.size,
+            created: a.created,
+        }
+    }
+}
+
+impl From<ReportArtifact> for ReportArtifactDb {
+    fn from(a: ReportArtifact) -> Self {
+        Self {
+            id: a.id.to_string(),
+            schedule_id: a.schedule_id.map(|id| id.to_string()),
+            ledger_id: a.ledger_id.to_string(),
+            report_type: report_type_to_db(&a.report_type),
+            format: format_to_db(&a.format),
+            ref_time: a.ref_time,
+            version: a.version,
+            object_key: a.object_key,
+            checksum: a.checksum.to_vec(),
+            size: a.size,
+            created: a.created,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::reversal_policy::ReversalPolicy;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReversalPolicyDb {
+    pub ledger_id: String,
+    pub teller_window_days: i32,
+    pub supervisor_window_days: Option<i32>,
+    pub updated: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ReversalPolicyDb> for ReversalPolicy {
+    fn from(p: ReversalPolicyDb) -> Self {
+        Self {
+            ledger_id: Uuid::parse_str(&p.ledger_id).unwrap(),
+            teller_window_days: p.teller_window_days,
+            supervisor_window_days: p.supervisor_window_days,
+            updated: p.updated,
+        }
+    }
+}
+
+impl From<ReversalPolicy> for ReversalPolicyDb {
+    fn from(p: ReversalPolicy) -> Self {
+        Self {
+            ledger_id: p.ledger_id.to_string(),
+            teller_window_days: p.teller_window_days,
+            supervisor_window_days: p.supervisor_window_days,
+            updated: p.updated,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::posting_type::PostingType;
+use postings_db::models::scheduled_posting::{ScheduledPosting, ScheduledPostingStatus};
+use postings_db::models::standing_order::Frequency;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduledPostingDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub opr_type: Vec<u8>,
+    pub pst_type: String,
+    pub lines: String,
+    pub frequency: Option<String>,
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub max_occurrences: Option<i32>,
+    pub next_pst_time: chrono::DateTime<chrono::Utc>,
+    pub occurrence: i32,
+    pub status: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ScheduledPostingDb> for ScheduledPosting {
+    fn from(s: ScheduledPostingDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&s.id).unwrap(),
+            ledger_id: Uuid::parse_str(&s.ledger_id).unwrap(),
+            opr_type: s.opr_type.try_into().unwrap_or([0u8; 34]),
+            pst_type: match s.pst_type.as_str() {
+                "BUSI_TX" => PostingType::BusiTx,
+                "ADJ_TX" => PostingType::AdjTx,
+                "BAL_STMT" => PostingType::BalStmt,
+                "PNL_STMT" => PostingType::PnlStmt,
+                "BS_STMT" => PostingType::BsStmt,
+                "LDG_CLSNG" => PostingType::LdgClsng,
+                _ => PostingType::Unknown,
+            },
+            lines: s.lines,
+            frequency: s.frequency.map(|frequency| match frequency.as_str() {
+                "DAILY" => Frequency::Daily,
+                "WEEKLY" => Frequency::Weekly,
+                "MONTHLY" => Frequency::Monthly,
+                "QUARTERLY" => Frequency::Quarterly,
+                _ => Frequency::Yearly,
+            }),
+            start_time: s.start_time,
+            end_time: s.end_time,
+            max_occurrences: s.max_occurrences,
+            next_pst_time: s.next_pst_time,
+            occurrence: s.occurrence,
+            status: match s.status.as_str() {
+                "ACTIVE" => ScheduledPostingStatus::Active,
+                "COMPLETED" => ScheduledPostingStatus::Completed,
+                _ => ScheduledPostingStatus::Cancelled,
+            },
+            created: s.created,
+        }
+    }
+}
+
+impl From<ScheduledPosting> for ScheduledPostingDb {
+    fn from(s: ScheduledPosting) -> Self {
+        Self {
+            id: s.id.to_string(),
+            ledger_id: s.ledger_id.to_string(),
+            opr_type: s.opr_type.to_vec(),
+            pst_type: match s.pst_type {
+                PostingType::BusiTx => "BUSI_TX".to_string(),
+                PostingType::AdjTx => "ADJ_TX".to_string(),
+                PostingType::BalStmt => "BAL_STMT".to_string(),
+                PostingType::PnlStmt => "PNL_STMT".to_string(),
+                PostingType::BsStmt => "BS_STMT".to_string(),
+                PostingType::LdgClsng => "LDG_CLSNG".to_string(),
+                PostingType::Unknown => "UNKNOWN".to_string(),
+            },
+            lines: s.lines,
+            frequency: s.frequency.map(|frequency| match frequency {
+                Frequency::Daily => "DAILY".to_string(),
+                Frequency::Weekly => "WEEKLY".to_string(),
+                Frequency::Monthly => "MONTHLY".to_string(),
+                Frequency::Quarterly => "QUARTERLY".to_string(),
+                Frequency::Yearly => "YEARLY".to_string(),
+            }),
+            start_time: s.start_time,
+            end_time: s.end_time,
+            max_occurrences: s.max_occurrences,
+            next_pst_time: s.next_pst_time,
+            occurrence: s.occurrence,
+            status: match s.status {
+                ScheduledPostingStatus::Active => "ACTIVE".to_string(),
+                ScheduledPostingStatus::Completed => "COMPLETED".to_string(),
+                ScheduledPostingStatus::Cancelled => "CANCELLED".to_string(),
+            },
+            created: s.created,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use postings_db::models::batch_status::BatchStatus;
+use postings_db::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SettlementBatchDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub batch_ref: Vec<u8>,
+    pub control_amount: BigDecimal,
+    pub control_count: Option<i32>,
+    pub status: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub closed_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SettlementBatchEntryDb {
+    pub batch_id: String,
+    pub posting_id: String,
+    pub amount: BigDecimal,
+    pub assigned_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<SettlementBatchDb> for SettlementBatch {
+    fn from(b: SettlementBatchDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&b.id).unwrap(),
+            ledger_id: Uuid::parse_str(&b.ledger_id).unwrap(),
+            batch_ref: b.batch_ref.try_into().unwrap_or([0u8; 34]),
+            control_amount: b.control_amount,
+            control_count: b.control_count,
+            status: match b.status.as_str() {
+                "OPEN" => BatchStatus::Open,
+                "CLOSED" => BatchStatus::Closed,
+                "SETTLED" => BatchStatus::Settled,
+                _ => BatchStatus::Rejected,
+            },
+            created: b.created,
+            closed_time: b.closed_time,
+        }
+    }
+}
+
+impl From<SettlementBatch> for SettlementBatchDb {
+    fn from(b: SettlementBatch) -> Self {
+        Self {
+            id: b.id.to_string(),
+            ledger_id: b.ledger_id.to_string(),
+            batch_ref: b.batch_ref.to_vec(),
+            control_amount: b.control_amount,
+            control_count: b.control_count,
+            status: match b.status {
+                BatchStatus::Open => "OPEN".to_string(),
+                BatchStatus::Closed => "CLOSED".to_string(),
+                BatchStatus::Settled => "SETTLED".to_string(),
+                BatchStatus::Rejected => "REJECTED".to_string(),
+            },
+            created: b.created,
+            closed_time: b.closed_time,
+        }
+    }
+}
+
+impl From<SettlementBatchEntryDb> for SettlementBatchEntry {
+    fn from(e: SettlementBatchEntryDb) -> Self {
+        Self {
+            batch_id: Uuid::parse_str(&e.batch_id).unwrap(),
+            posting_id: Uuid::parse_str(&e.posting_id).unwrap(),
+            amount: e.amount,
+            assigned_time: e.assigned_time,
+        }
+    }
+}
+
+impl From<SettlementBatchEntry> for SettlementBatchEntryDb {
+    fn from(e: SettlementBatchEntry) -> Self {
+        Self {
+            batch_id: e.batch_id.to_string(),
+            posting_id: e.posting_id.to_string(),
+            amount: e.amount,
+            assigned_time: e.assigned_time,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDate;
+use postings_db::models::standing_order::{ExecutionStatus, Frequency, StandingOrder, StandingOrderExecution, StandingOrderStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StandingOrderDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub source_account_id: String,
+    pub target_account_id: String,
+    pub amount: BigDecimal,
+    pub opr_type: Vec<u8>,
+    pub frequency: String,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub max_executions: Option<i32>,
+    pub max_retries: i32,
+    pub next_execution_date: NaiveDate,
+    pub occurrence: i32,
+    pub execution_count: i32,
+    pub status: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StandingOrderExecutionDb {
+    pub id: String,
+    pub order_id: String,
+    pub execution_date: NaiveDate,
+    pub attempt: i32,
+    pub status: String,
+    pub posting_id: Option<String>,
+    pub message: Option<String>,
+    pub executed_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<StandingOrderDb> for StandingOrder {
+    fn from(o: StandingOrderDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&o.id).unwrap(),
+            ledger_id: Uuid::parse_str(&o.ledger_id).unwrap(),
+            source_account_id: Uuid::parse_str(&o.source_account_id).unwrap(),
+            target_account_id: Uuid::parse_str(&o.target_account_id).unwrap(),
+            amount: o.amount,
+            opr_type: o.opr_type.try_into().unwrap_or([0u8; 34]),
+            frequency: match o.frequency.as_str() {
+                "DAILY" => Frequency::Daily,
+                "WEEKLY" => Frequency::Weekly,
+                "MONTHLY" => Frequency::Monthly,
+                "QUARTERLY" => Frequency::Quarterly,
+                _ => Frequency::Yearly,
+            },
+            start_date: o.start_date,
+            end_date: o.end_date,
+            max_executions: o.max_executions,
+            max_retries: o.max_retries,
+            next_execution_date: o.next_execution_date,
+            occurrence: o.occurrence,
+            execution_count: o.execution_count,
+            status: match o.status.as_str() {
+                "ACTIVE" => StandingOrderStatus::Active,
+                "COMPLETED" => StandingOrderStatus::Completed,
+                _ => StandingOrderStatus::Cancelled,
+            },
+            created: o.created,
+        }
+    }
+}
+
+impl From<StandingOrder> for StandingOrderDb {
+    fn from(o: StandingOrder) -> Self {
+        Self {
+            id: o.id.to_string(),
+            ledger_id: o.ledger_id.to_string(),
+            source_account_id: o.source_account_id.to_string(),
+            target_account_id: o.target_account_id.to_string(),
+            amount: o.amount,
+            opr_type: o.opr_type.to_vec(),
+            frequency: match o.frequency {
+                Frequency::Daily => "DAILY".to_string(),
+                Frequency::Weekly => "WEEKLY".to_string(),
+                Frequency::Monthly => "MONTHLY".to_string(),
+                Frequency::Quarterly => "QUARTERLY".to_string(),
+                Frequency::Yearly => "YEARLY".to_string(),
+            },
+            start_date: o.start_date,
+            end_date: o.end_date,
+            max_executions: o.max_executions,
+            max_retries: o.max_retries,
+            next_execution_date: o.next_execution_date,
+            occurrence: o.occurrence,
+            execution_count: o.execution_count,
+            status: match o.status {
+                StandingOrderStatus::Active => "ACTIVE".to_string(),
+                StandingOrderStatus::Completed => "COMPLETED".to_string(),
+                StandingOrderStatus::Cancelled => "CANCELLED".to_string(),
+            },
+            created: o.created,
+        }
+    }
+}
+
+impl From<StandingOrderExecutionDb> for StandingOrderExecution {
+    fn from(e: StandingOrderExecutionDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&e.id).unwrap(),
+            order_id: Uuid::parse_str(&e.order_id).unwrap(),
+            execution_date: e.execution_date,
+            attempt: e.attempt,
+            status: match e.status.as_str() {
+                "EXECUTED" => ExecutionStatus::Executed,
+                "FAILED" => ExecutionStatus::Failed,
+                _ => ExecutionStatus::Skipped,
+            },
+            posting_id: e.posting_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            message: e.message,
+            executed_time: e.executed_time,
+        }
+    }
+}
+
+impl From<StandingOrderExecution> for StandingOrderExecutionDb {
+    fn from(e: StandingOrderExecution) -> Self {
+        Self {
+            id: e.id.to_string(),
+            order_id: e.order_id.to_string(),
+            execution_date: e.execution_date,
+            attempt: e.attempt,
+            status: match e.status {
+                ExecutionStatus::Executed => "EXECUTED".to_string(),
+                ExecutionStatus::Failed => "FAILED".to_string(),
+                ExecutionStatus::Skipped => "SKIPPED".to_string(),
+            },
+            posting_id: e.posting_id.map(|id| id.to_string()),
+            message: e.message,
+            executed_time: e.executed_time,
+        }
+    }
+}
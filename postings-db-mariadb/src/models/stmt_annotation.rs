@@ -0,0 +1,36 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::stmt_annotation::StmtAnnotation;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtAnnotationDb {
+    pub id: String,
+    pub stmt_id: String,
+    pub author: String,
+    pub text: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<StmtAnnotationDb> for StmtAnnotation {
+    fn from(a: StmtAnnotationDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&a.id).unwrap(),
+            stmt_id: Uuid::parse_str(&a.stmt_id).unwrap(),
+            author: a.author,
+            text: a.text,
+            created: a.created,
+        }
+    }
+}
+
+impl From<StmtAnnotation> for StmtAnnotationDb {
+    fn from(a: StmtAnnotation) -> Self {
+        Self {
+            id: a.id.to_string(),
+            stmt_id: a.stmt_id.to_string(),
+            author: a.author,
+            text: a.text,
+            created: a.created,
+        }
+    }
+}
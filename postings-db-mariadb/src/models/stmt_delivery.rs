@@ -0,0 +1,50 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::stmt_delivery::{StmtDelivery, StmtDocumentFormat};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtDeliveryDb {
+    pub id: String,
+    pub stmt_id: String,
+    pub format: String,
+    pub checksum: Vec<u8>,
+    pub signature: Option<Vec<u8>>,
+    pub key_id: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<StmtDeliveryDb> for StmtDelivery {
+    fn from(d: StmtDeliveryDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&d.id).unwrap(),
+            stmt_id: Uuid::parse_str(&d.stmt_id).unwrap(),
+            format: match d.format.as_str() {
+                "CSV" => StmtDocumentFormat::Csv,
+                "JSON" => StmtDocumentFormat::Json,
+                _ => StmtDocumentFormat::Pdf,
+            },
+            checksum: d.checksum.try_into().unwrap_or([0u8; 34]),
+            signature: d.signature,
+            key_id: d.key_id,
+            created: d.created,
+        }
+    }
+}
+
+impl From<StmtDelivery> for StmtDeliveryDb {
+    fn from(d: StmtDelivery) -> Self {
+        Self {
+            id: d.id.to_string(),
+            stmt_id: d.stmt_id.to_string(),
+            format: match d.format {
+                StmtDocumentFormat::Csv => "CSV".to_string(),
+                StmtDocumentFormat::Json => "JSON".to_string(),
+                StmtDocumentFormat::Pdf => "PDF".to_string(),
+            },
+            checksum: d.checksum.to_vec(),
+            signature: d.signature,
+            key_id: d.key_id,
+            created: d.created,
+        }
+    }
+}
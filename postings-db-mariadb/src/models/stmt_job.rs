@@ -0,0 +1,59 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::stmt_job::{StmtJob, StmtJobStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtJobDb {
+    pub id: String,
+    pub account_id: String,
+    pub ref_time: chrono::DateTime<chrono::Utc>,
+    pub status: String,
+    pub stmt_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub completed_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub fn status_to_db(status: &StmtJobStatus) -> String {
+    match status {
+        StmtJobStatus::Pending => "PENDING".to_string(),
+        StmtJobStatus::Running => "RUNNING".to_string(),
+        StmtJobStatus::Completed => "COMPLETED".to_string(),
+        StmtJobStatus::Failed => "FAILED".to_string(),
+    }
+}
+
+impl From<StmtJobDb> for StmtJob {
+    fn from(j: StmtJobDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&j.id).unwrap(),
+            account_id: Uuid::parse_str(&j.account_id).unwrap(),
+            ref_time: j.ref_time,
+            status: match j.status.as_str() {
+                "PENDING" => StmtJobStatus::Pending,
+                "RUNNING" => StmtJobStatus::Running,
+                "COMPLETED" => StmtJobStatus::Completed,
+                _ => StmtJobStatus::Failed,
+            },
+            stmt_id: j.stmt_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            error_message: j.error_message,
+            created: j.created,
+            completed_time: j.completed_time,
+        }
+    }
+}
+
+impl From<StmtJob> for StmtJobDb {
+    fn from(j: StmtJob) -> Self {
+        Self {
+            id: j.id.to_string(),
+            account_id: j.account_id.to_string(),
+            ref_time: j.ref_time,
+            status: status_to_db(&j.status),
+            stmt_id: j.stmt_id.map(|id| id.to_string()),
+            error_message: j.error_message,
+            created: j.created,
+            completed_time: j.completed_time,
+        }
+    }
+}
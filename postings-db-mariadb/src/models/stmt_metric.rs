@@ -0,0 +1,24 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::stmt_metric::AccountStmtStats;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountStmtStatsDb {
+    pub account_id: String,
+    pub generations: i64,
+    pub total_duration_ms: i64,
+    pub max_duration_ms: i64,
+    pub max_lines: i64,
+}
+
+impl From<AccountStmtStatsDb> for AccountStmtStats {
+    fn from(s: AccountStmtStatsDb) -> Self {
+        Self {
+            account_id: Uuid::parse_str(&s.account_id).unwrap(),
+            generations: s.generations,
+            total_duration_ms: s.total_duration_ms,
+            max_duration_ms: s.max_duration_ms,
+            max_lines: s.max_lines,
+        }
+    }
+}
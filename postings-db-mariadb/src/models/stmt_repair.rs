@@ -0,0 +1,32 @@
+use uuid::Uuid;
+use sqlx::FromRow;
+use postings_db::models::stmt_repair::StmtRepair;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtRepairDb {
+    pub id: String,
+    pub stmt_id: String,
+    pub account_id: String,
+    pub pst_time: chrono::DateTime<chrono::Utc>,
+    pub recorded: String,
+    pub expected: String,
+    pub principal: String,
+    pub reason: String,
+    pub repaired_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<StmtRepairDb> for StmtRepair {
+    fn from(r: StmtRepairDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&r.id).unwrap(),
+            stmt_id: Uuid::parse_str(&r.stmt_id).unwrap(),
+            account_id: Uuid::parse_str(&r.account_id).unwrap(),
+            pst_time: r.pst_time,
+            recorded: r.recorded,
+            expected: r.expected,
+            principal: r.principal,
+            reason: r.reason,
+            repaired_time: r.repaired_time,
+        }
+    }
+}
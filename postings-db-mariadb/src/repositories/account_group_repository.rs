@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::account_group_repository::AccountGroupRepository;
+use postings_db::models::account_group::{AccountGroup, AccountGroupMember};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::account_group::AccountGroupDb;
+
+pub struct MariaDbAccountGroupRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbAccountGroupRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountGroupRepository for MariaDbAccountGroupRepository {
+    async fn save(&self, group: AccountGroup) -> Result<AccountGroup, DbError> {
+        let db_model = AccountGroupDb::from(group.clone());
+        sqlx::query("INSERT INTO account_group (id, ledger_id, name, category, created) VALUES (?, ?, ?, ?, ?)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.name)
+            .bind(&db_model.category)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(group)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountGroup>, DbError> {
+        let group_db = sqlx::query_as::<_, AccountGroupDb>("SELECT * FROM account_group WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(group_db.map(Into::into))
+    }
+
+    async fn find_by_ledger_id_and_name(&self, ledger_id: Uuid, name: &str) -> Result<Option<AccountGroup>, DbError> {
+        let group_db = sqlx::query_as::<_, AccountGroupDb>("SELECT * FROM account_group WHERE ledger_id = ? AND name = ?")
+            .bind(ledger_id.to_string())
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(group_db.map(Into::into))
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<AccountGroup>, DbError> {
+        let groups_db = sqlx::query_as::<_, AccountGroupDb>("SELECT * FROM account_group WHERE ledger_id = ? ORDER BY name")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(groups_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn add_member(&self, member: AccountGroupMember) -> Result<(), DbError> {
+        sqlx::query("INSERT IGNORE INTO account_group_member (group_id, account_id) VALUES (?, ?)")
+            .bind(member.group_id.to_string())
+            .bind(member.account_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<bool, DbError> {
+        let result = sqlx::query("DELETE FROM account_group_member WHERE group_id = ? AND account_id = ?")
+            .bind(group_id.to_string())
+            .bind(account_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_member_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>, DbError> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT account_id FROM account_group_member WHERE group_id = ? ORDER BY account_id")
+            .bind(group_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(ids.iter().map(|id| Uuid::parse_str(id).unwrap()).collect())
+    }
+}
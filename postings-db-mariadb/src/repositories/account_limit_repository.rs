@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::account_limit_repository::AccountLimitRepository;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::account_limit::AccountLimitDb;
+
+pub struct MariaDbAccountLimitRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbAccountLimitRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountLimitRepository for MariaDbAccountLimitRepository {
+    async fn save(&self, limit: AccountLimit) -> Result<AccountLimit, DbError> {
+        let db_model = AccountLimitDb::from(limit.clone());
+        sqlx::query(
+            "INSERT INTO account_limit (account_id, max_debit_balance, overdraft_limit, daily_turnover_limit, updated)
+             VALUES (?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                max_debit_balance = VALUES(max_debit_balance),
+                overdraft_limit = VALUES(overdraft_limit),
+                daily_turnover_limit = VALUES(daily_turnover_limit),
+                updated = VALUES(updated)")
+            .bind(&db_model.account_id)
+            .bind(&db_model.max_debit_balance)
+            .bind(&db_model.overdraft_limit)
+            .bind(&db_model.daily_turnover_limit)
+            .bind(db_model.updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(limit)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        let limit_db = sqlx::query_as::<_, AccountLimitDb>("SELECT * FROM account_limit WHERE account_id = ?")
+            .bind(account_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(limit_db.map(Into::into))
+    }
+}
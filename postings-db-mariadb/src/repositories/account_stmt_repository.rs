@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use sqlx::{MySqlExecutor, MySqlPool};
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::models::account_stmt::AccountStmt;
 use postings_db::models::stmt_status::StmtStatus;
 use postings_db::DbError;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
@@ -39,21 +40,80 @@ impl AccountStmtRepository for MariaDbAccountStmtRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
-        sqlx::query("INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(stmt.id.to_string())
-            .bind(stmt.account_id.to_string())
-            .bind(stmt.youngest_pst_id.map(|u| u.to_string()))
-            .bind(&stmt.total_debit)
-            .bind(&stmt.total_credit)
-            .bind(stmt.posting_id.map(|u| u.to_string()))
-            .bind(stmt.pst_time)
-            .bind(&stmt.stmt_status)
-            .bind(stmt.latest_pst_id.map(|u| u.to_string()))
-            .bind(stmt.stmt_seq_nbr)
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM account_stmt WHERE account_id = ? AND stmt_status = 'CLOSED' AND pst_time < ? ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1")
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            "DELETE s FROM account_stmt s
+             JOIN ledger_account a ON s.account_id = a.id
+             WHERE a.ledger_id = ?
+               AND s.stmt_status = 'SIMULATED' AND s.expiry <= ?")
+            .bind(ledger_id.to_string())
+            .bind(as_of)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM account_stmt WHERE account_id = ? AND stmt_status = 'CLOSED' AND pst_time >= ? AND pst_time <= ? ORDER BY pst_time, stmt_seq_nbr")
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        sqlx::query_as(
+            "SELECT s.* FROM account_stmt s
+             JOIN ledger_account a ON s.account_id = a.id
+             WHERE a.ledger_id = ? AND s.stmt_status = 'CLOSED' AND (? IS NULL OR s.id > ?)
+             ORDER BY s.id LIMIT ?")
+            .bind(ledger_id.to_string())
+            .bind(after.map(|u| u.to_string()))
+            .bind(after.map(|u| u.to_string()))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM account_stmt s
+             JOIN ledger_account a ON s.account_id = a.id
+             WHERE a.ledger_id = ? AND s.stmt_status = 'CLOSED'")
+            .bind(ledger_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        sqlx::query("UPDATE account_stmt SET opening_debit = ?, opening_credit = ?, line_count = ? WHERE id = ?")
+            .bind(opening_debit)
+            .bind(opening_credit)
+            .bind(line_count)
+            .bind(id.to_string())
             .execute(&self.pool)
-            .await?;
-        Ok(stmt)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        insert_account_stmt(&self.pool, stmt).await
+
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
@@ -63,4 +123,28 @@ impl AccountStmtRepository for MariaDbAccountStmtRepository {
             .await
             .map_err(DbError::from)
     }
-}
\ No newline at end of file
+}
+
+pub(crate) async fn insert_account_stmt<'e, E: MySqlExecutor<'e>>(executor: E, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+    sqlx::query("INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr, expiry, opening_debit, opening_credit, line_count, currency_totals, signature, signing_key_id) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(stmt.id.to_string())
+        .bind(stmt.account_id.to_string())
+        .bind(stmt.youngest_pst_id.map(|u| u.to_string()))
+        .bind(&stmt.total_debit)
+        .bind(&stmt.total_credit)
+        .bind(stmt.posting_id.map(|u| u.to_string()))
+        .bind(stmt.pst_time)
+        .bind(&stmt.stmt_status)
+        .bind(stmt.latest_pst_id.map(|u| u.to_string()))
+        .bind(stmt.stmt_seq_nbr)
+        .bind(stmt.expiry)
+        .bind(&stmt.opening_debit)
+        .bind(&stmt.opening_credit)
+        .bind(stmt.line_count)
+        .bind(&stmt.currency_totals)
+        .bind(&stmt.signature)
+        .bind(&stmt.signing_key_id)
+        .execute(executor)
+        .await?;
+    Ok(AccountStmt { closing_balance: stmt.total_debit.clone() - stmt.total_credit.clone(), ..stmt })
+}
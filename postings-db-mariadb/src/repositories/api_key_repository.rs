@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::api_key_repository::ApiKeyRepository;
+use postings_db::models::api_key::ApiKey;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::api_key::ApiKeyDb;
+
+pub struct MariaDbApiKeyRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbApiKeyRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for MariaDbApiKeyRepository {
+    async fn save(&self, key: ApiKey) -> Result<ApiKey, DbError> {
+        let db_model = ApiKeyDb::from(key.clone());
+        sqlx::query(
+            "INSERT INTO api_key (id, name, tenant_id, ledger_id, roles, key_prefix, key_hash, created, expires, revoked_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                roles = VALUES(roles),
+                expires = VALUES(expires),
+                revoked_time = VALUES(revoked_time)")
+            .bind(&db_model.id)
+            .bind(&db_model.name)
+            .bind(&db_model.tenant_id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.roles)
+            .bind(&db_model.key_prefix)
+            .bind(&db_model.key_hash)
+            .bind(db_model.created)
+            .bind(db_model.expires)
+            .bind(db_model.revoked_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(key)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, DbError> {
+        let key_db = sqlx::query_as::<_, ApiKeyDb>("SELECT * FROM api_key WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(key_db.map(Into::into))
+    }
+
+    async fn find_by_key_prefix(&self, key_prefix: &str) -> Result<Option<ApiKey>, DbError> {
+        let key_db = sqlx::query_as::<_, ApiKeyDb>("SELECT * FROM api_key WHERE key_prefix = ?")
+            .bind(key_prefix)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(key_db.map(Into::into))
+    }
+
+    async fn find_by_tenant_id(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, DbError> {
+        let keys_db = sqlx::query_as::<_, ApiKeyDb>("SELECT * FROM api_key WHERE tenant_id = ? ORDER BY created")
+            .bind(tenant_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(keys_db.into_iter().map(Into::into).collect())
+    }
+}
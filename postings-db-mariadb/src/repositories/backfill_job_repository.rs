@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::backfill_job_repository::BackfillJobRepository;
+use postings_db::models::backfill_job::BackfillJob;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::backfill_job::BackfillJobDb;
+
+pub struct MariaDbBackfillJobRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbBackfillJobRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BackfillJobRepository for MariaDbBackfillJobRepository {
+    async fn save(&self, job: BackfillJob) -> Result<BackfillJob, DbError> {
+        let db_model = BackfillJobDb::from(job.clone());
+        sqlx::query(
+            "INSERT INTO backfill_job (id, task_name, ledger_id, status, batch_size, checkpoint, processed, total, error_message, started, updated, finished)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                checkpoint = VALUES(checkpoint),
+                processed = VALUES(processed),
+                error_message = VALUES(error_message),
+                updated = VALUES(updated),
+                finished = VALUES(finished)")
+            .bind(&db_model.id)
+            .bind(&db_model.task_name)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.status)
+            .bind(db_model.batch_size)
+            .bind(&db_model.checkpoint)
+            .bind(db_model.processed)
+            .bind(db_model.total)
+            .bind(&db_model.error_message)
+            .bind(db_model.started)
+            .bind(db_model.updated)
+            .bind(db_model.finished)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        let job_db = sqlx::query_as::<_, BackfillJobDb>("SELECT * FROM backfill_job WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(job_db.map(Into::into))
+    }
+
+    async fn find_unfinished_by_task_and_ledger(&self, task_name: &str, ledger_id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        let job_db = sqlx::query_as::<_, BackfillJobDb>("SELECT * FROM backfill_job WHERE task_name = ? AND ledger_id = ? AND status <> 'COMPLETED' ORDER BY started DESC LIMIT 1")
+            .bind(task_name)
+            .bind(ledger_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(job_db.map(Into::into))
+    }
+}
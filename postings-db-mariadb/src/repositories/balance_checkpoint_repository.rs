@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::balance_checkpoint::BalanceCheckpointDb;
+
+pub struct MariaDbBalanceCheckpointRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbBalanceCheckpointRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BalanceCheckpointRepository for MariaDbBalanceCheckpointRepository {
+    async fn save(&self, checkpoint: &BalanceCheckpoint) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO balance_checkpoint (id, account_id, as_of, base_stmt_id, total_debit, total_credit, line_count, currency_totals, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                id = VALUES(id),
+                base_stmt_id = VALUES(base_stmt_id),
+                total_debit = VALUES(total_debit),
+                total_credit = VALUES(total_credit),
+                line_count = VALUES(line_count),
+                currency_totals = VALUES(currency_totals),
+                created = VALUES(created)")
+            .bind(checkpoint.id.to_string())
+            .bind(checkpoint.account_id.to_string())
+            .bind(checkpoint.as_of)
+            .bind(checkpoint.base_stmt_id.map(|id| id.to_string()))
+            .bind(&checkpoint.total_debit)
+            .bind(&checkpoint.total_credit)
+            .bind(checkpoint.line_count)
+            .bind(&checkpoint.currency_totals)
+            .bind(checkpoint.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_last_by_account_and_as_of_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<BalanceCheckpoint>, DbError> {
+        let checkpoint_db = sqlx::query_as::<_, BalanceCheckpointDb>("SELECT * FROM balance_checkpoint WHERE account_id = ? AND as_of <= ? ORDER BY as_of DESC LIMIT 1")
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(checkpoint_db.map(Into::into))
+    }
+
+    async fn delete_by_account_and_as_of_greater_than_equal(&self, account_id: Uuid, from: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("DELETE FROM balance_checkpoint WHERE account_id = ? AND as_of >= ?")
+            .bind(account_id.to_string())
+            .bind(from)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::models::category_rule::CategoryRule;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::category_rule::CategoryRuleDb;
+
+pub struct MariaDbCategoryRuleRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbCategoryRuleRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRuleRepository for MariaDbCategoryRuleRepository {
+    async fn save(&self, rule: CategoryRule) -> Result<CategoryRule, DbError> {
+        let db_model = CategoryRuleDb::from(rule.clone());
+        sqlx::query(
+            "INSERT INTO category_rule (id, ledger_id, category, priority, account_id, details, src_account, opr_src, min_amount, max_amount, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                category = VALUES(category),
+                priority = VALUES(priority),
+                account_id = VALUES(account_id),
+                details = VALUES(details),
+                src_account = VALUES(src_account),
+                opr_src = VALUES(opr_src),
+                min_amount = VALUES(min_amount),
+                max_amount = VALUES(max_amount)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.category)
+            .bind(db_model.priority)
+            .bind(&db_model.account_id)
+            .bind(&db_model.details)
+            .bind(&db_model.src_account)
+            .bind(&db_model.opr_src)
+            .bind(&db_model.min_amount)
+            .bind(&db_model.max_amount)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(rule)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<CategoryRule>, DbError> {
+        let rules_db = sqlx::query_as::<_, CategoryRuleDb>("SELECT * FROM category_rule WHERE ledger_id = ? ORDER BY priority, created")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(rules_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query("DELETE FROM category_rule WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlExecutor, MySqlPool};
+use postings_db::repositories::earmark_repository::EarmarkRepository;
+use postings_db::models::earmark::{Earmark, EarmarkStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::earmark::{status_to_db, EarmarkDb};
+
+pub struct MariaDbEarmarkRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbEarmarkRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EarmarkRepository for MariaDbEarmarkRepository {
+    async fn save(&self, earmark: Earmark) -> Result<Earmark, DbError> {
+        upsert_earmark(&self.pool, earmark).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Earmark>, DbError> {
+        let earmark_db = sqlx::query_as::<_, EarmarkDb>("SELECT * FROM earmark WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(earmark_db.map(Into::into))
+    }
+
+    async fn find_by_account_id_and_status(&self, account_id: Uuid, status: EarmarkStatus) -> Result<Vec<Earmark>, DbError> {
+        let earmarks_db = sqlx::query_as::<_, EarmarkDb>("SELECT * FROM earmark WHERE account_id = ? AND status = ? ORDER BY created")
+            .bind(account_id.to_string())
+            .bind(status_to_db(&status))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(earmarks_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, DbError> {
+        let earmarks_db = sqlx::query_as::<_, EarmarkDb>("SELECT * FROM earmark WHERE status = 'ACTIVE' AND expiry <= ? ORDER BY expiry")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(earmarks_db.into_iter().map(Into::into).collect())
+    }
+}
+
+pub(crate) async fn upsert_earmark<'e, E: MySqlExecutor<'e>>(executor: E, earmark: Earmark) -> Result<Earmark, DbError> {
+    let db_model = EarmarkDb::from(earmark.clone());
+    sqlx::query(
+        "INSERT INTO earmark (id, account_id, amount, captured_amount, reason, created, expiry, status, closed_time, posting_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON DUPLICATE KEY UPDATE
+            amount = VALUES(amount),
+            captured_amount = VALUES(captured_amount),
+            expiry = VALUES(expiry),
+            status = VALUES(status),
+            closed_time = VALUES(closed_time),
+            posting_id = VALUES(posting_id)")
+        .bind(&db_model.id)
+        .bind(&db_model.account_id)
+        .bind(&db_model.amount)
+        .bind(&db_model.captured_amount)
+        .bind(&db_model.reason)
+        .bind(db_model.created)
+        .bind(db_model.expiry)
+        .bind(&db_model.status)
+        .bind(db_model.closed_time)
+        .bind(&db_model.posting_id)
+        .execute(executor)
+        .await
+        .map_err(DbError::from)?;
+    Ok(earmark)
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::MySqlPool;
+use postings_db::repositories::eod_run_repository::EodRunRepository;
+use postings_db::models::eod_run::{EodRun, EodStepResult};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::eod_run::{EodRunDb, EodStepResultDb};
+
+pub struct MariaDbEodRunRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbEodRunRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EodRunRepository for MariaDbEodRunRepository {
+    async fn save_run(&self, run: EodRun) -> Result<EodRun, DbError> {
+        let db_model = EodRunDb::from(run.clone());
+        sqlx::query(
+            "INSERT INTO eod_run (id, ledger_id, business_date, status, started, finished)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                finished = VALUES(finished)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(db_model.business_date)
+            .bind(&db_model.status)
+            .bind(db_model.started)
+            .bind(db_model.finished)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(run)
+    }
+
+    async fn find_run_by_ledger_and_business_date(&self, ledger_id: Uuid, business_date: NaiveDate) -> Result<Option<EodRun>, DbError> {
+        let run_db = sqlx::query_as::<_, EodRunDb>("SELECT * FROM eod_run WHERE ledger_id = ? AND business_date = ?")
+            .bind(ledger_id.to_string())
+            .bind(business_date)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(run_db.map(Into::into))
+    }
+
+    async fn save_step_result(&self, result: EodStepResult) -> Result<EodStepResult, DbError> {
+        let db_model = EodStepResultDb::from(result.clone());
+        sqlx::query(
+            "INSERT INTO eod_step_result (run_id, step_seq, step_name, status, message, started, finished)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                step_name = VALUES(step_name),
+                status = VALUES(status),
+                message = VALUES(message),
+                started = VALUES(started),
+                finished = VALUES(finished)")
+            .bind(&db_model.run_id)
+            .bind(db_model.step_seq)
+            .bind(&db_model.step_name)
+            .bind(&db_model.status)
+            .bind(&db_model.message)
+            .bind(db_model.started)
+            .bind(db_model.finished)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result)
+    }
+
+    async fn find_step_results_by_run_id(&self, run_id: Uuid) -> Result<Vec<EodStepResult>, DbError> {
+        let results_db = sqlx::query_as::<_, EodStepResultDb>("SELECT * FROM eod_step_result WHERE run_id = ? ORDER BY step_seq")
+            .bind(run_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(results_db.into_iter().map(Into::into).collect())
+    }
+}
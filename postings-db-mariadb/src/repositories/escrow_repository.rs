@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::escrow_repository::EscrowRepository;
+use postings_db::models::escrow::{Escrow, EscrowStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::escrow::{status_to_db, EscrowDb};
+
+pub struct MariaDbEscrowRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbEscrowRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EscrowRepository for MariaDbEscrowRepository {
+    async fn save(&self, escrow: Escrow) -> Result<Escrow, DbError> {
+        let db_model = EscrowDb::from(escrow.clone());
+        sqlx::query(
+            "INSERT INTO escrow (id, ledger_id, source_account_id, escrow_account_id, target_account_id, amount, opr_type,
+                status, timeout, timeout_action, initiation_posting_id, resolution_posting_id, created, resolved_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                initiation_posting_id = VALUES(initiation_posting_id),
+                resolution_posting_id = VALUES(resolution_posting_id),
+                resolved_time = VALUES(resolved_time)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.source_account_id)
+            .bind(&db_model.escrow_account_id)
+            .bind(&db_model.target_account_id)
+            .bind(&db_model.amount)
+            .bind(&db_model.opr_type)
+            .bind(&db_model.status)
+            .bind(db_model.timeout)
+            .bind(&db_model.timeout_action)
+            .bind(&db_model.initiation_posting_id)
+            .bind(&db_model.resolution_posting_id)
+            .bind(db_model.created)
+            .bind(db_model.resolved_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(escrow)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Escrow>, DbError> {
+        let escrow_db = sqlx::query_as::<_, EscrowDb>("SELECT * FROM escrow WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(escrow_db.map(Into::into))
+    }
+
+    async fn resolve(&self, id: Uuid, status: EscrowStatus, resolved_time: DateTime<Utc>) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            "UPDATE escrow SET status = ?, resolved_time = ?
+             WHERE id = ? AND status = 'PENDING'")
+            .bind(status_to_db(&status))
+            .bind(resolved_time)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_pending_timed_out(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, DbError> {
+        let escrows_db = sqlx::query_as::<_, EscrowDb>("SELECT * FROM escrow WHERE status = 'PENDING' AND timeout <= ? ORDER BY timeout")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(escrows_db.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::external_content_repository::ExternalContentRepository;
+use postings_db::models::external_content::ExternalContent;
+use postings_db::DbError;
+use crate::models::external_content::ExternalContentDb;
+
+pub struct MariaDbExternalContentRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbExternalContentRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExternalContentRepository for MariaDbExternalContentRepository {
+    async fn save(&self, content: ExternalContent) -> Result<ExternalContent, DbError> {
+        let db_model = ExternalContentDb::from(content.clone());
+        sqlx::query(
+            "INSERT IGNORE INTO external_content (content_hash, object_key, size, media_type, created)
+             VALUES (?, ?, ?, ?, ?)")
+            .bind(&db_model.content_hash)
+            .bind(&db_model.object_key)
+            .bind(db_model.size)
+            .bind(&db_model.media_type)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        self.find_by_content_hash(&content.content_hash)
+            .await?
+            .ok_or(DbError::NotFound)
+    }
+
+    async fn find_by_content_hash(&self, content_hash: &[u8]) -> Result<Option<ExternalContent>, DbError> {
+        let content_db = sqlx::query_as::<_, ExternalContentDb>("SELECT * FROM external_content WHERE content_hash = ?")
+            .bind(content_hash)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(content_db.map(Into::into))
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::models::external_ref::{ExternalRef, ExternalRefEntity};
+use postings_db::repositories::external_ref_repository::ExternalRefRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::external_ref::{entity_type_to_db, ExternalRefDb};
+
+pub struct MariaDbExternalRefRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbExternalRefRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExternalRefRepository for MariaDbExternalRefRepository {
+    async fn save(&self, external_ref: &ExternalRef) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO external_ref (id, entity_type, entity_id, ledger_id, ref_system, reference, unique_scope, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(external_ref.id.to_string())
+            .bind(entity_type_to_db(external_ref.entity_type))
+            .bind(external_ref.entity_id.to_string())
+            .bind(external_ref.ledger_id.to_string())
+            .bind(&external_ref.ref_system)
+            .bind(&external_ref.reference)
+            .bind(&external_ref.unique_scope)
+            .bind(external_ref.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_entity(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, DbError> {
+        let refs = sqlx::query_as::<_, ExternalRefDb>("SELECT * FROM external_ref WHERE entity_type = ? AND entity_id = ? ORDER BY ref_system")
+            .bind(entity_type_to_db(entity_type))
+            .bind(entity_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(refs.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_and_reference(&self, ledger_id: Uuid, entity_type: ExternalRefEntity, ref_system: &str, reference: &str) -> Result<Vec<ExternalRef>, DbError> {
+        let refs = sqlx::query_as::<_, ExternalRefDb>("SELECT * FROM external_ref WHERE ledger_id = ? AND entity_type = ? AND ref_system = ? AND reference = ? ORDER BY created, id")
+            .bind(ledger_id.to_string())
+            .bind(entity_type_to_db(entity_type))
+            .bind(ref_system)
+            .bind(reference)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(refs.into_iter().map(Into::into).collect())
+    }
+}
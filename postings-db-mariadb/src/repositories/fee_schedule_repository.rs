@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use postings_db::models::fee_schedule::{FeeSchedule, FeeTier};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::fee_schedule::{FeeScheduleDb, FeeTierDb};
+
+pub struct MariaDbFeeScheduleRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbFeeScheduleRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeeScheduleRepository for MariaDbFeeScheduleRepository {
+    async fn save(&self, schedule: FeeSchedule) -> Result<FeeSchedule, DbError> {
+        let db_model = FeeScheduleDb::from(schedule.clone());
+        sqlx::query(
+            "INSERT INTO fee_schedule (id, ledger_id, opr_type, fee_type, flat_amount, rate, min_fee, max_fee, fee_account_id, product_id, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                fee_type = VALUES(fee_type),
+                flat_amount = VALUES(flat_amount),
+                rate = VALUES(rate),
+                min_fee = VALUES(min_fee),
+                max_fee = VALUES(max_fee),
+                fee_account_id = VALUES(fee_account_id)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.opr_type)
+            .bind(&db_model.fee_type)
+            .bind(&db_model.flat_amount)
+            .bind(&db_model.rate)
+            .bind(&db_model.min_fee)
+            .bind(&db_model.max_fee)
+            .bind(&db_model.fee_account_id)
+            .bind(&db_model.product_id)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule)
+    }
+
+    async fn find_by_ledger_id_and_opr_type(&self, ledger_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        let schedule_db = sqlx::query_as::<_, FeeScheduleDb>("SELECT * FROM fee_schedule WHERE ledger_id = ? AND opr_type = ? AND product_id IS NULL")
+            .bind(ledger_id.to_string())
+            .bind(opr_type)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule_db.map(Into::into))
+    }
+
+    async fn find_by_product_id_and_opr_type(&self, product_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        let schedule_db = sqlx::query_as::<_, FeeScheduleDb>("SELECT * FROM fee_schedule WHERE product_id = ? AND opr_type = ?")
+            .bind(product_id.to_string())
+            .bind(opr_type)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule_db.map(Into::into))
+    }
+
+    async fn save_tiers(&self, schedule_id: Uuid, tiers: Vec<FeeTier>) -> Result<Vec<FeeTier>, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query("DELETE FROM fee_tier WHERE schedule_id = ?")
+            .bind(schedule_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        for tier in tiers.iter() {
+            sqlx::query("INSERT INTO fee_tier (schedule_id, from_amount, flat_amount, rate) VALUES (?, ?, ?, ?)")
+                .bind(schedule_id.to_string())
+                .bind(&tier.from_amount)
+                .bind(&tier.flat_amount)
+                .bind(&tier.rate)
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::from)?;
+        }
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(tiers)
+    }
+
+    async fn find_tiers_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<FeeTier>, DbError> {
+        let tiers_db = sqlx::query_as::<_, FeeTierDb>("SELECT * FROM fee_tier WHERE schedule_id = ? ORDER BY from_amount")
+            .bind(schedule_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(tiers_db.into_iter().map(Into::into).collect())
+    }
+}
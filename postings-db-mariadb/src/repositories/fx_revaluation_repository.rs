@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::models::fx_revaluation::FxRevaluation;
+use postings_db::repositories::fx_revaluation_repository::FxRevaluationRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::fx_revaluation::FxRevaluationDb;
+
+pub struct MariaDbFxRevaluationRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbFxRevaluationRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FxRevaluationRepository for MariaDbFxRevaluationRepository {
+    async fn save(&self, revaluation: &FxRevaluation) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO fx_revaluation (id, ledger_id, account_id, currency, valuation_time, foreign_balance, rate, carrying_value, revalued_value, posting_id, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(revaluation.id.to_string())
+            .bind(revaluation.ledger_id.to_string())
+            .bind(revaluation.account_id.to_string())
+            .bind(&revaluation.currency)
+            .bind(revaluation.valuation_time)
+            .bind(&revaluation.foreign_balance)
+            .bind(&revaluation.rate)
+            .bind(&revaluation.carrying_value)
+            .bind(&revaluation.revalued_value)
+            .bind(revaluation.posting_id.map(|id| id.to_string()))
+            .bind(revaluation.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<FxRevaluation>, DbError> {
+        let revaluations = sqlx::query_as::<_, FxRevaluationDb>("SELECT * FROM fx_revaluation WHERE account_id = ? ORDER BY valuation_time DESC")
+            .bind(account_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(revaluations.into_iter().map(Into::into).collect())
+    }
+}
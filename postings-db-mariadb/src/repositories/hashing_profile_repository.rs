@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use postings_db::models::hashing_profile::HashingProfile;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::hashing_profile::HashingProfileDb;
+
+pub struct MariaDbHashingProfileRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbHashingProfileRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HashingProfileRepository for MariaDbHashingProfileRepository {
+    async fn save(&self, profile: HashingProfile) -> Result<HashingProfile, DbError> {
+        let db_model = HashingProfileDb::from(profile.clone());
+        sqlx::query(
+            "INSERT INTO hashing_profile (ledger_id, excluded_fields, hash_algorithm, updated) VALUES (?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                excluded_fields = VALUES(excluded_fields),
+                hash_algorithm = VALUES(hash_algorithm),
+                updated = VALUES(updated)")
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.excluded_fields)
+            .bind(&db_model.hash_algorithm)
+            .bind(db_model.updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(profile)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<HashingProfile>, DbError> {
+        let profile_db = sqlx::query_as::<_, HashingProfileDb>("SELECT * FROM hashing_profile WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(profile_db.map(Into::into))
+    }
+}
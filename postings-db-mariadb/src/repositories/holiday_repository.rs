@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::MySqlPool;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db::models::holiday::Holiday;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::holiday::HolidayDb;
+
+pub struct MariaDbHolidayRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbHolidayRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HolidayRepository for MariaDbHolidayRepository {
+    async fn save(&self, holiday: Holiday) -> Result<Holiday, DbError> {
+        let db_model = HolidayDb::from(holiday.clone());
+        sqlx::query(
+            "INSERT INTO ledger_holiday (ledger_id, holiday_date, name) VALUES (?, ?, ?)
+             ON DUPLICATE KEY UPDATE name = VALUES(name)")
+            .bind(&db_model.ledger_id)
+            .bind(db_model.holiday_date)
+            .bind(&db_model.name)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(holiday)
+    }
+
+    async fn delete(&self, ledger_id: Uuid, holiday_date: NaiveDate) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM ledger_holiday WHERE ledger_id = ? AND holiday_date = ?")
+            .bind(ledger_id.to_string())
+            .bind(holiday_date)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Holiday>, DbError> {
+        let holidays_db = sqlx::query_as::<_, HolidayDb>("SELECT * FROM ledger_holiday WHERE ledger_id = ? ORDER BY holiday_date")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(holidays_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_id_and_date_between(&self, ledger_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, DbError> {
+        let holidays_db = sqlx::query_as::<_, HolidayDb>("SELECT * FROM ledger_holiday WHERE ledger_id = ? AND holiday_date BETWEEN ? AND ? ORDER BY holiday_date")
+            .bind(ledger_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(holidays_db.into_iter().map(Into::into).collect())
+    }
+}
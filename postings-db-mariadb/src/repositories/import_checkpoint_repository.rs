@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::repositories::import_checkpoint_repository::ImportCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::import_checkpoint::ImportCheckpointDb;
+
+pub struct MariaDbImportCheckpointRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbImportCheckpointRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImportCheckpointRepository for MariaDbImportCheckpointRepository {
+    async fn save(&self, checkpoint: &ImportCheckpoint) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO import_checkpoint (id, ledger_id, import_type, source_hash, next_line, loaded_count, quarantined_count, completed, updated)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                next_line = VALUES(next_line),
+                loaded_count = VALUES(loaded_count),
+                quarantined_count = VALUES(quarantined_count),
+                completed = VALUES(completed),
+                updated = VALUES(updated)")
+            .bind(checkpoint.id.to_string())
+            .bind(checkpoint.ledger_id.to_string())
+            .bind(&checkpoint.import_type)
+            .bind(checkpoint.source_hash.as_slice())
+            .bind(checkpoint.next_line)
+            .bind(checkpoint.loaded_count)
+            .bind(checkpoint.quarantined_count)
+            .bind(checkpoint.completed)
+            .bind(checkpoint.updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_source(&self, ledger_id: Uuid, import_type: &str, source_hash: &[u8; 34]) -> Result<Option<ImportCheckpoint>, DbError> {
+        let checkpoint_db = sqlx::query_as::<_, ImportCheckpointDb>("SELECT * FROM import_checkpoint WHERE ledger_id = ? AND import_type = ? AND source_hash = ?")
+            .bind(ledger_id.to_string())
+            .bind(import_type)
+            .bind(source_hash.as_slice())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(checkpoint_db.map(Into::into))
+    }
+}
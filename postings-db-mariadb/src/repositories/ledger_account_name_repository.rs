@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::models::ledger_account_name::LedgerAccountName;
+use postings_db::repositories::ledger_account_name_repository::LedgerAccountNameRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::ledger_account_name::LedgerAccountNameDb;
+
+pub struct MariaDbLedgerAccountNameRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbLedgerAccountNameRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountNameRepository for MariaDbLedgerAccountNameRepository {
+    async fn save(&self, name: &LedgerAccountName) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_account_name (id, ledger_id, account_id, number, name, valid_from, valid_to, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE valid_to = VALUES(valid_to)")
+            .bind(name.id.to_string())
+            .bind(name.ledger_id.to_string())
+            .bind(name.account_id.to_string())
+            .bind(&name.number)
+            .bind(&name.name)
+            .bind(name.valid_from)
+            .bind(name.valid_to)
+            .bind(name.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccountName>, DbError> {
+        let name = sqlx::query_as::<_, LedgerAccountNameDb>("SELECT * FROM ledger_account_name WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(name.map(Into::into))
+    }
+
+    async fn find_by_ledger_and_value(&self, ledger_id: Uuid, value: &str) -> Result<Vec<LedgerAccountName>, DbError> {
+        let names = sqlx::query_as::<_, LedgerAccountNameDb>("SELECT * FROM ledger_account_name WHERE ledger_id = ? AND (name = ? OR number = ?)")
+            .bind(ledger_id.to_string())
+            .bind(value)
+            .bind(value)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(names.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<LedgerAccountName>, DbError> {
+        let names = sqlx::query_as::<_, LedgerAccountNameDb>("SELECT * FROM ledger_account_name WHERE account_id = ? ORDER BY valid_from DESC")
+            .bind(account_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(names.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::models::ledger_closure::LedgerClosure;
+use postings_db::repositories::ledger_closure_repository::LedgerClosureRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::ledger_closure::LedgerClosureDb;
+
+pub struct MariaDbLedgerClosureRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbLedgerClosureRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerClosureRepository for MariaDbLedgerClosureRepository {
+    async fn save(&self, closure: &LedgerClosure) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_closure (id, ledger_id, account_id, cut_off, allow_adjustments, closed_by, reason, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(closure.id.to_string())
+            .bind(closure.ledger_id.to_string())
+            .bind(closure.account_id.map(|id| id.to_string()))
+            .bind(closure.cut_off)
+            .bind(closure.allow_adjustments)
+            .bind(&closure.closed_by)
+            .bind(&closure.reason)
+            .bind(closure.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerClosure>, DbError> {
+        let closures = sqlx::query_as::<_, LedgerClosureDb>("SELECT * FROM ledger_closure WHERE ledger_id = ? ORDER BY cut_off DESC, created DESC")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(closures.into_iter().map(Into::into).collect())
+    }
+}
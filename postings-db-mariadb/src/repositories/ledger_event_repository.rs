@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use postings_db::models::ledger_event::LedgerEvent;
+use postings_db::DbError;
+use crate::models::ledger_event::LedgerEventDb;
+
+pub struct MariaDbLedgerEventRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbLedgerEventRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerEventRepository for MariaDbLedgerEventRepository {
+    async fn find_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        let events_db = sqlx::query_as::<_, LedgerEventDb>("SELECT * FROM ledger_event WHERE seq > ? ORDER BY seq LIMIT ?")
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(events_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_max_seq(&self) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT MAX(seq) FROM ledger_event")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_archived_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        let events_db = sqlx::query_as::<_, LedgerEventDb>("SELECT * FROM ledger_event_archive WHERE seq > ? ORDER BY seq LIMIT ?")
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(events_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn archive_up_to(&self, up_to_seq: i64, created_before: DateTime<Utc>) -> Result<u64, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query(
+            "INSERT IGNORE INTO ledger_event_archive (seq, entity_type, entity_id, operation, payload, created)
+             SELECT seq, entity_type, entity_id, operation, payload, created FROM ledger_event
+             WHERE seq <= ? AND created < ?")
+            .bind(up_to_seq)
+            .bind(created_before)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        let result = sqlx::query("DELETE FROM ledger_event WHERE seq <= ? AND created < ?")
+            .bind(up_to_seq)
+            .bind(created_before)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn save_consumer_position(&self, consumer: &str, last_seq: i64, updated: DateTime<Utc>) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_event_consumer (name, last_seq, updated) VALUES (?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                last_seq = GREATEST(last_seq, VALUES(last_seq)),
+                updated = VALUES(updated)")
+            .bind(consumer)
+            .bind(last_seq)
+            .bind(updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn find_consumer_seq(&self, consumer: &str) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT last_seq FROM ledger_event_consumer WHERE name = ?")
+            .bind(consumer)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_min_consumer_seq(&self) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT MIN(last_seq) FROM ledger_event_consumer")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -18,6 +18,9 @@ impl MariaDbLedgerRepository {
         DbLedger {
             id: Uuid::parse_str(&mariadb_ledger.id).unwrap(),
             coa_id: Uuid::parse_str(&mariadb_ledger.coa_id).unwrap(),
+            read_only: mariadb_ledger.read_only,
+            memo: mariadb_ledger.memo,
+            closing_summary: mariadb_ledger.closing_summary,
         }
     }
 
@@ -25,6 +28,9 @@ impl MariaDbLedgerRepository {
         MariaDbLedger {
             id: db_ledger.id.to_string(),
             coa_id: db_ledger.coa_id.to_string(),
+            read_only: db_ledger.read_only,
+            memo: db_ledger.memo,
+            closing_summary: db_ledger.closing_summary,
         }
     }
 }
@@ -52,4 +58,40 @@ impl LedgerRepository for MariaDbLedgerRepository {
             .await?;
         Ok(())
     }
+
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET read_only = ? WHERE id = ?")
+            .bind(read_only)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET memo = ? WHERE id = ?")
+            .bind(memo)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET closing_summary = ? WHERE id = ?")
+            .bind(closing_summary)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlExecutor, MySqlPool};
+use postings_db::repositories::ledger_stmt_repository::LedgerStmtRepository;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::ledger_stmt::LedgerStmtDb;
+
+pub struct MariaDbLedgerStmtRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbLedgerStmtRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerStmtRepository for MariaDbLedgerStmtRepository {
+    async fn find_last_closed_by_ledger_and_pst_time_less_than(&self, ledger_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<LedgerStmt>, DbError> {
+        let stmt_db = sqlx::query_as::<_, LedgerStmtDb>("SELECT * FROM ledger_stmt WHERE ledger_id = ? AND stmt_status = 'CLOSED' AND pst_time < ? ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1")
+            .bind(ledger_id.to_string())
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(stmt_db.map(Into::into))
+    }
+
+    async fn save(&self, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+        upsert_ledger_stmt(&self.pool, stmt).await
+
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerStmt>, DbError> {
+        let stmt_db = sqlx::query_as::<_, LedgerStmtDb>("SELECT * FROM ledger_stmt WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(stmt_db.map(Into::into))
+    }
+}
+
+/// Inserts the statement or replaces the statement stored under its id.
+pub(crate) async fn upsert_ledger_stmt<'e, E: MySqlExecutor<'e>>(executor: E, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+    let db_model = LedgerStmtDb::from(stmt.clone());
+    sqlx::query(
+        "INSERT INTO ledger_stmt (id, ledger_id, total_debit, total_credit, posting_id, pst_time, stmt_status, stmt_seq_nbr)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+         ON DUPLICATE KEY UPDATE
+            total_debit = VALUES(total_debit),
+            total_credit = VALUES(total_credit),
+            posting_id = VALUES(posting_id),
+            pst_time = VALUES(pst_time),
+            stmt_status = VALUES(stmt_status),
+            stmt_seq_nbr = VALUES(stmt_seq_nbr)")
+        .bind(&db_model.id)
+        .bind(&db_model.ledger_id)
+        .bind(&db_model.total_debit)
+        .bind(&db_model.total_credit)
+        .bind(&db_model.posting_id)
+        .bind(db_model.pst_time)
+        .bind(&db_model.stmt_status)
+        .bind(db_model.stmt_seq_nbr)
+        .execute(executor)
+        .await
+        .map_err(DbError::from)?;
+    Ok(stmt)
+}
@@ -35,7 +35,7 @@ impl MariaDbNamedRepository {
         }
     }
 
-    fn from_domain(domain_named: DomainNamed) -> MariaDbNamed {
+    pub(crate) fn from_domain(domain_named: DomainNamed) -> MariaDbNamed {
         MariaDbNamed {
             id: domain_named.id.to_string(),
             container: domain_named.container.to_string(),
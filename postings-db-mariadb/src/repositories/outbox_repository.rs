@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlExecutor, MySqlPool};
+use postings_db::models::outbox_entry::OutboxEntry;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::outbox_entry::OutboxEntryDb;
+
+pub struct MariaDbOutboxRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbOutboxRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for MariaDbOutboxRepository {
+    async fn save(&self, entry: &OutboxEntry) -> Result<(), DbError> {
+        insert_outbox_entry(&self.pool, entry).await
+    }
+
+    async fn find_undelivered(&self, limit: i64) -> Result<Vec<OutboxEntry>, DbError> {
+        let entries_db = sqlx::query_as::<_, OutboxEntryDb>("SELECT * FROM outbox WHERE delivered IS NULL ORDER BY created, id LIMIT ?")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entries_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn mark_delivered(&self, id: Uuid, delivered: DateTime<Utc>) -> Result<(), DbError> {
+        sqlx::query("UPDATE outbox SET delivered = ? WHERE id = ?")
+            .bind(delivered)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn record_failure(&self, id: Uuid, error: &str) -> Result<(), DbError> {
+        sqlx::query("UPDATE outbox SET attempts = attempts + 1, last_error = ? WHERE id = ?")
+            .bind(error)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn delete_delivered_before(&self, before: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("DELETE FROM outbox WHERE delivered < ?")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+}
+
+pub(crate) async fn insert_outbox_entry<'e, E: MySqlExecutor<'e>>(executor: E, entry: &OutboxEntry) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO outbox (id, event_type, payload, created, attempts, last_error, delivered) VALUES (?, ?, ?, ?, ?, ?, ?)")
+        .bind(entry.id.to_string())
+        .bind(&entry.event_type)
+        .bind(&entry.payload)
+        .bind(entry.created)
+        .bind(entry.attempts)
+        .bind(&entry.last_error)
+        .bind(entry.delivered)
+        .execute(executor)
+        .await
+        .map_err(DbError::on_insert)?;
+    Ok(())
+}
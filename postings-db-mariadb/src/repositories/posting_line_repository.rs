@@ -1,11 +1,14 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use sqlx::{MySqlExecutor, MySqlPool};
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::page::{Page, PageRequest};
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, StreamExt};
 use uuid::Uuid;
-use crate::models::posting_line::PostingLineDb;
+use crate::models::posting_line::{AccountLineStatsDb, AccountLineTotalsDb, PostingLineDb};
 
 pub struct MariaDbPostingLineRepository {
     pool: MySqlPool,
@@ -20,30 +23,7 @@ impl MariaDbPostingLineRepository {
 #[async_trait]
 impl PostingLineRepository for MariaDbPostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
-        let db_model = PostingLineDb::from(posting_line.clone());
-        
-        sqlx::query("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(&db_model.id)
-            .bind(&db_model.account_id)
-            .bind(&db_model.debit_amount)
-            .bind(&db_model.credit_amount)
-            .bind(&db_model.details)
-            .bind(&db_model.src_account)
-            .bind(&db_model.base_line)
-            .bind(&db_model.sub_opr_src_id)
-            .bind(db_model.record_time)
-            .bind(&db_model.opr_id)
-            .bind(&db_model.opr_src)
-            .bind(db_model.pst_time)
-            .bind(&db_model.pst_type)
-            .bind(&db_model.pst_status)
-            .bind(&db_model.hash)
-            .bind(db_model.discarded_time)
-            .execute(&self.pool)
-            .await
-            .map_err(DbError::from)?;
-            
-        // Return the saved posting line (use original posting_line since save succeeded)
+        insert_posting_line(&self.pool, &posting_line).await?;
         Ok(posting_line)
     }
 
@@ -57,7 +37,7 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
     }
 
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
-        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL ORDER BY pst_time DESC")
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL ORDER BY pst_time DESC, record_time DESC, id DESC")
             .bind(account_id.to_string())
             .bind(from)
             .bind(to)
@@ -67,6 +47,24 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
         Ok(posting_lines_db.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL")
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch_one(&self.pool)
+            .await?;
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL ORDER BY pst_time DESC, record_time DESC, id DESC LIMIT ? OFFSET ?")
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(to)
+            .bind(page.limit())
+            .bind(page.offset())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(Page::new(posting_lines_db.into_iter().map(Into::into).collect(), page, total as u64))
+    }
+
     async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
         let posting_line_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE id = ? AND account_id = ?")
             .bind(id.to_string())
@@ -87,6 +85,15 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
         Ok(posting_lines_db.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE opr_id = ? ORDER BY record_time, id")
+            .bind(opr_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
     async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
         let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time <= ? AND discarded_time IS NULL ORDER BY record_time DESC")
             .bind(account_id.to_string())
@@ -96,4 +103,114 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
             .map_err(DbError::from)?;
         Ok(posting_lines_db.into_iter().map(Into::into).collect())
     }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time <= ? AND record_time <= ? ORDER BY record_time DESC")
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .bind(known_at)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        let stats_db = sqlx::query_as::<_, AccountLineStatsDb>(
+            "SELECT COUNT(*) AS line_count, MIN(pst_time) AS first_pst_time, MAX(pst_time) AS last_pst_time,
+                (SELECT opr_id FROM posting_line WHERE account_id = ? AND discarded_time IS NULL
+                 ORDER BY record_time DESC, id DESC LIMIT 1) AS last_opr_id
+             FROM posting_line WHERE account_id = ? AND discarded_time IS NULL")
+            .bind(account_id.to_string())
+            .bind(account_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(stats_db.into())
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        // `IN ()` is not valid SQL
+        if account_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; account_ids.len()].join(", ");
+        let sql = format!(
+            "SELECT account_id, SUM(debit_amount) AS total_debit, SUM(credit_amount) AS total_credit
+             FROM posting_line WHERE account_id IN ({placeholders}) AND pst_time <= ? AND discarded_time IS NULL
+             GROUP BY account_id");
+        let mut query = sqlx::query_as::<_, AccountLineTotalsDb>(&sql);
+        for account_id in account_ids {
+            query = query.bind(account_id.to_string());
+        }
+        let totals_db = query
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(totals_db.into_iter().map(Into::into).collect())
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let sql = match order {
+            LineOrder::PostingTime => "SELECT * FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL
+                 ORDER BY pst_time, record_time, id",
+            LineOrder::ValueTime => "SELECT l.* FROM posting_line l
+                 LEFT JOIN posting p ON p.opr_id = l.opr_id AND p.discarding_id IS NULL
+                 WHERE l.account_id = ? AND l.pst_time > ? AND l.pst_time <= ? AND l.discarded_time IS NULL
+                 ORDER BY COALESCE(p.val_time, l.pst_time), l.pst_time, l.record_time, l.id",
+        };
+        sqlx::query_as::<_, PostingLineDb>(sql)
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch(&self.pool)
+            .map(|line| line.map(Into::into).map_err(DbError::from))
+            .boxed()
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let sql = match order {
+            LineOrder::PostingTime => "SELECT * FROM posting_line WHERE account_id = ? AND pst_time <= ? AND discarded_time IS NULL
+                 ORDER BY pst_time, record_time, id",
+            LineOrder::ValueTime => "SELECT l.* FROM posting_line l
+                 LEFT JOIN posting p ON p.opr_id = l.opr_id AND p.discarding_id IS NULL
+                 WHERE l.account_id = ? AND l.pst_time <= ? AND l.discarded_time IS NULL
+                 ORDER BY COALESCE(p.val_time, l.pst_time), l.pst_time, l.record_time, l.id",
+        };
+        sqlx::query_as::<_, PostingLineDb>(sql)
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .fetch(&self.pool)
+            .map(|line| line.map(Into::into).map_err(DbError::from))
+            .boxed()
+    }
+}
+
+pub(crate) async fn insert_posting_line<'e, E: MySqlExecutor<'e>>(executor: E, posting_line: &PostingLine) -> Result<(), DbError> {
+    let db_model = PostingLineDb::from(posting_line.clone());
+    sqlx::query("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, currency, fx_base_currency, fx_rate) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(&db_model.id)
+        .bind(&db_model.account_id)
+        .bind(&db_model.debit_amount)
+        .bind(&db_model.credit_amount)
+        .bind(&db_model.details)
+        .bind(&db_model.src_account)
+        .bind(&db_model.base_line)
+        .bind(&db_model.sub_opr_src_id)
+        .bind(db_model.record_time)
+        .bind(&db_model.opr_id)
+        .bind(&db_model.opr_src)
+        .bind(db_model.pst_time)
+        .bind(&db_model.pst_type)
+        .bind(&db_model.pst_status)
+        .bind(&db_model.hash)
+        .bind(db_model.discarded_time)
+        .bind(&db_model.currency)
+        .bind(&db_model.fx_base_currency)
+        .bind(&db_model.fx_rate)
+        .execute(executor)
+        .await
+        .map_err(DbError::from)?;
+    Ok(())
 }
@@ -1,9 +1,13 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use sqlx::{MySql, MySqlExecutor, MySqlPool, QueryBuilder};
 use postings_db::repositories::posting_repository::PostingRepository;
 use postings_db::models::posting::Posting;
+use postings_db::models::posting_filter::PostingFilter;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::page::{Page, PageRequest};
 use postings_db::DbError;
 use uuid::Uuid;
+use crate::repositories::posting_line_repository::insert_posting_line;
 
 pub struct MariaDbPostingRepository {
     pool: MySqlPool,
@@ -47,31 +51,40 @@ impl PostingRepository for MariaDbPostingRepository {
     }
 
     async fn save(&self, posting: &Posting) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(posting.id.to_string())
-            .bind(posting.record_user.as_ref())
-            .bind(posting.record_time)
-            .bind(posting.opr_id.as_ref())
-            .bind(posting.opr_time)
-            .bind(posting.opr_type.as_ref())
-            .bind(posting.opr_details.as_ref().map(|v| v.as_ref()))
-            .bind(posting.opr_src.as_ref().map(|v| v.as_ref()))
-            .bind(posting.pst_time)
-            .bind(&posting.pst_type)
-            .bind(&posting.pst_status)
-            .bind(posting.ledger_id.to_string())
-            .bind(posting.val_time)
-            .bind(posting.discarded_id.map(|u| u.to_string()))
-            .bind(posting.discarded_time)
-            .bind(posting.discarding_id.map(|u| u.to_string()))
-            .bind(posting.antecedent_id.map(|u| u.to_string()))
-            .bind(posting.antecedent_hash.as_ref().map(|v| v.as_ref()))
-            .bind(posting.hash.as_ref().map(|v| v.as_ref()))
-            .execute(&self.pool)
-            .await?;
+        insert_posting(&self.pool, posting).await
+    }
+
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        for (posting, lines) in postings.iter() {
+            insert_posting(&mut *tx, posting).await?;
+            for line in lines.iter() {
+                insert_posting_line(&mut *tx, line).await?;
+            }
+        }
+        tx.commit().await.map_err(DbError::from)?;
         Ok(())
     }
 
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        let result = sqlx::query("UPDATE posting SET discarding_id = ? WHERE id = ? AND discarding_id IS NULL")
+            .bind(reversal.id.to_string())
+            .bind(reversed_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+        insert_posting(&mut *tx, reversal).await?;
+        for line in lines.iter() {
+            insert_posting_line(&mut *tx, line).await?;
+        }
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(true)
+    }
+
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
         let posting_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE id = ?")
             .bind(id.to_string())
@@ -80,4 +93,122 @@ impl PostingRepository for MariaDbPostingRepository {
             .map_err(DbError::from)?;
         Ok(posting_db.map(Into::into))
     }
-}
\ No newline at end of file
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? ORDER BY record_time, id")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(postings_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posting WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? ORDER BY record_time, id LIMIT ? OFFSET ?")
+            .bind(ledger_id.to_string())
+            .bind(page.limit())
+            .bind(page.offset())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(Page::new(postings_db.into_iter().map(Into::into).collect(), page, total as u64))
+    }
+
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM posting");
+        push_filter(&mut count, filter);
+        let total: i64 = count.build_query_scalar().fetch_one(&self.pool).await?;
+        let mut select = QueryBuilder::new("SELECT * FROM posting");
+        push_filter(&mut select, filter);
+        select
+            .push(" ORDER BY ")
+            .push(filter.order.order_by())
+            .push(" LIMIT ")
+            .push_bind(page.limit())
+            .push(" OFFSET ")
+            .push_bind(page.offset());
+        let postings_db: Vec<PostingDb> = select.build_query_as().fetch_all(&self.pool).await?;
+        Ok(Page::new(postings_db.into_iter().map(Into::into).collect(), page, total as u64))
+    }
+}
+
+/// Appends the `WHERE` clause of the set criteria.
+fn push_filter(query: &mut QueryBuilder<'_, MySql>, filter: &PostingFilter) {
+    query.push(" WHERE TRUE");
+    if !filter.ledger_ids.is_empty() {
+        query.push(" AND ledger_id IN (");
+        let mut ids = query.separated(", ");
+        for ledger_id in filter.ledger_ids.iter() {
+            ids.push_bind(ledger_id.to_string());
+        }
+        query.push(")");
+    }
+    if let Some(account_id) = filter.account_id {
+        query
+            .push(" AND EXISTS (SELECT 1 FROM posting_line l WHERE l.opr_id = posting.opr_id AND l.account_id = ")
+            .push_bind(account_id.to_string())
+            .push(")");
+    }
+    if let Some(opr_id) = filter.opr_id {
+        query.push(" AND opr_id = ").push_bind(opr_id.to_vec());
+    }
+    if let Some(opr_type) = filter.opr_type {
+        query.push(" AND opr_type = ").push_bind(opr_type.to_vec());
+    }
+    if let Some(pst_status) = &filter.pst_status {
+        query.push(" AND pst_status = ").push_bind(pst_status.clone());
+    }
+    if let Some(from) = filter.pst_time_from {
+        query.push(" AND pst_time >= ").push_bind(from);
+    }
+    if let Some(to) = filter.pst_time_to {
+        query.push(" AND pst_time <= ").push_bind(to);
+    }
+}
+
+pub(crate) async fn insert_posting<'e, E: MySqlExecutor<'e>>(executor: E, posting: &Posting) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash, hash_excluded_fields, hash_version, hash_algorithm) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(posting.id.to_string())
+        .bind(posting.record_user.as_ref())
+        .bind(posting.record_time)
+        .bind(posting.opr_id.as_ref())
+        .bind(posting.opr_time)
+        .bind(posting.opr_type.as_ref())
+        .bind(posting.opr_details.as_ref().map(|v| v.as_ref()))
+        .bind(posting.opr_src.as_ref().map(|v| v.as_ref()))
+        .bind(posting.pst_time)
+        .bind(&posting.pst_type)
+        .bind(&posting.pst_status)
+        .bind(posting.ledger_id.to_string())
+        .bind(posting.val_time)
+        .bind(posting.discarded_id.map(|u| u.to_string()))
+        .bind(posting.discarded_time)
+        .bind(posting.discarding_id.map(|u| u.to_string()))
+        .bind(posting.antecedent_id.map(|u| u.to_string()))
+        .bind(posting.antecedent_hash.as_ref().map(|v| v.as_ref()))
+        .bind(posting.hash.as_ref().map(|v| v.as_ref()))
+        .bind(&posting.hash_excluded_fields)
+        .bind(posting.hash_version)
+        .bind(&posting.hash_algorithm)
+        .execute(executor)
+        .await
+        .map_err(DbError::on_insert)?;
+    Ok(())
+}
+
+/// Marks the posting as discarded, failing with [`DbError::NotFound`] if it is already discarded.
+pub(crate) async fn discard_posting<'e, E: MySqlExecutor<'e>>(executor: E, id: Uuid, discarding_id: Uuid) -> Result<(), DbError> {
+    let result = sqlx::query("UPDATE posting SET discarding_id = ? WHERE id = ? AND discarding_id IS NULL")
+        .bind(discarding_id.to_string())
+        .bind(id.to_string())
+        .execute(executor)
+        .await
+        .map_err(DbError::from)?;
+    if result.rows_affected() == 0 {
+        return Err(DbError::NotFound);
+    }
+    Ok(())
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use sqlx::MySqlPool;
 use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
 use postings_db::models::posting_trace::PostingTrace;
+use postings_db::upsert::Upserted;
 use postings_db::DbError;
 use uuid::Uuid;
 use crate::models::posting_trace::PostingTraceDb;
@@ -18,9 +19,16 @@ impl MariaDbPostingTraceRepository {
 
 #[async_trait]
 impl PostingTraceRepository for MariaDbPostingTraceRepository {
-    async fn save(&self, trace: PostingTrace) -> Result<PostingTrace, DbError> {
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError> {
         let trace_db = PostingTraceDb::from(trace.clone());
-        sqlx::query("INSERT INTO posting_trace (id, tgt_pst_id, src_pst_time, src_pst_id, src_opr_id, account_id, debit_amount, credit_amount, src_pst_hash) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        sqlx::query(
+            "INSERT INTO posting_trace (id, tgt_pst_id, src_pst_time, src_pst_id, src_opr_id, account_id, debit_amount, credit_amount, src_pst_hash)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                src_pst_time = VALUES(src_pst_time),
+                debit_amount = VALUES(debit_amount),
+                credit_amount = VALUES(credit_amount),
+                src_pst_hash = VALUES(src_pst_hash)")
             .bind(trace_db.id.to_string())
             .bind(trace_db.tgt_pst_id.to_string())
             .bind(trace_db.src_pst_time)
@@ -32,7 +40,15 @@ impl PostingTraceRepository for MariaDbPostingTraceRepository {
             .bind(&trace_db.src_pst_hash)
             .execute(&self.pool)
             .await?;
-        Ok(trace)
+        // The existing row keeps its id on conflict, which tells both cases apart
+        let trace_db = sqlx::query_as::<_, PostingTraceDb>("SELECT * FROM posting_trace WHERE tgt_pst_id = ? AND src_pst_id = ?")
+            .bind(trace.tgt_pst_id.to_string())
+            .bind(trace.src_pst_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        let stored: PostingTrace = trace_db.into();
+        Ok(if stored.id == trace.id { Upserted::Inserted(stored) } else { Upserted::Existing(stored) })
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
@@ -43,4 +59,17 @@ impl PostingTraceRepository for MariaDbPostingTraceRepository {
             .map_err(DbError::from)?;
         Ok(trace_db.map(Into::into))
     }
-}
\ No newline at end of file
+
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            "DELETE t FROM posting_trace t
+             JOIN ledger_account a ON t.account_id = a.id
+             WHERE a.ledger_id = ?
+               AND NOT EXISTS (SELECT 1 FROM account_stmt s WHERE s.id = t.tgt_pst_id)")
+            .bind(ledger_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::prepared_posting_repository::PreparedPostingRepository;
+use postings_db::models::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::prepared_posting::{status_to_db, PreparedPostingDb};
+
+pub struct MariaDbPreparedPostingRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbPreparedPostingRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PreparedPostingRepository for MariaDbPreparedPostingRepository {
+    async fn save(&self, prepared: PreparedPosting) -> Result<PreparedPosting, DbError> {
+        let db_model = PreparedPostingDb::from(prepared.clone());
+        sqlx::query(
+            "INSERT INTO prepared_posting (id, ledger_id, opr_id, payload, status, coordinator_ref, expires, created, resolved_time, posting_id)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                resolved_time = VALUES(resolved_time),
+                posting_id = VALUES(posting_id)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.opr_id)
+            .bind(&db_model.payload)
+            .bind(&db_model.status)
+            .bind(&db_model.coordinator_ref)
+            .bind(db_model.expires)
+            .bind(db_model.created)
+            .bind(db_model.resolved_time)
+            .bind(&db_model.posting_id)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(prepared)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PreparedPosting>, DbError> {
+        let prepared_db = sqlx::query_as::<_, PreparedPostingDb>("SELECT * FROM prepared_posting WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(prepared_db.map(Into::into))
+    }
+
+    async fn resolve(&self, id: Uuid, status: PreparedPostingStatus, resolved_time: DateTime<Utc>, posting_id: Option<Uuid>) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            "UPDATE prepared_posting SET status = ?, resolved_time = ?, posting_id = ?
+             WHERE id = ? AND status = 'PREPARED'")
+            .bind(status_to_db(&status))
+            .bind(resolved_time)
+            .bind(posting_id.map(|id| id.to_string()))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<Vec<PreparedPosting>, DbError> {
+        let prepared_db = sqlx::query_as::<_, PreparedPostingDb>(
+            "SELECT * FROM prepared_posting WHERE status = 'PREPARED' AND expires <= ? ORDER BY expires LIMIT ?")
+            .bind(as_of)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(prepared_db.into_iter().map(Into::into).collect())
+    }
+}
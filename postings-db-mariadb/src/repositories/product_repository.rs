@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::product_repository::ProductRepository;
+use postings_db::models::product::{AccountProduct, Product};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::product::{AccountProductDb, ProductDb};
+
+pub struct MariaDbProductRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbProductRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProductRepository for MariaDbProductRepository {
+    async fn save(&self, product: Product) -> Result<Product, DbError> {
+        let db_model = ProductDb::from(product.clone());
+        sqlx::query(
+            "INSERT INTO product (id, ledger_id, name, interest_rate, day_count_convention, created)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                name = VALUES(name),
+                interest_rate = VALUES(interest_rate),
+                day_count_convention = VALUES(day_count_convention)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.name)
+            .bind(&db_model.interest_rate)
+            .bind(&db_model.day_count_convention)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(product)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, DbError> {
+        let product_db = sqlx::query_as::<_, ProductDb>("SELECT * FROM product WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(product_db.map(Into::into))
+    }
+
+    async fn save_account_product(&self, link: AccountProduct) -> Result<AccountProduct, DbError> {
+        let db_model = AccountProductDb::from(link.clone());
+        sqlx::query(
+            "INSERT INTO account_product (account_id, product_id, linked) VALUES (?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                product_id = VALUES(product_id),
+                linked = VALUES(linked)")
+            .bind(&db_model.account_id)
+            .bind(&db_model.product_id)
+            .bind(db_model.linked)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(link)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<Product>, DbError> {
+        let product_db = sqlx::query_as::<_, ProductDb>(
+            "SELECT p.* FROM product p
+             JOIN account_product ap ON ap.product_id = p.id
+             WHERE ap.account_id = ?")
+            .bind(account_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(product_db.map(Into::into))
+    }
+}
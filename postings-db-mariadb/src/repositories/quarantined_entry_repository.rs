@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::quarantined_entry_repository::QuarantinedEntryRepository;
+use postings_db::models::quarantined_entry::{QuarantineStatus, QuarantinedEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::quarantined_entry::{status_to_db, QuarantinedEntryDb};
+
+pub struct MariaDbQuarantinedEntryRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbQuarantinedEntryRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuarantinedEntryRepository for MariaDbQuarantinedEntryRepository {
+    async fn save(&self, entry: QuarantinedEntry) -> Result<QuarantinedEntry, DbError> {
+        let db_model = QuarantinedEntryDb::from(entry.clone());
+        sqlx::query(
+            "INSERT INTO quarantined_entry (id, ledger_id, import_type, line_number, payload, reason, status, created, resolved_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                payload = VALUES(payload),
+                reason = VALUES(reason),
+                status = VALUES(status),
+                resolved_time = VALUES(resolved_time)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.import_type)
+            .bind(db_model.line_number)
+            .bind(&db_model.payload)
+            .bind(&db_model.reason)
+            .bind(&db_model.status)
+            .bind(db_model.created)
+            .bind(db_model.resolved_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entry)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuarantinedEntry>, DbError> {
+        let entry_db = sqlx::query_as::<_, QuarantinedEntryDb>("SELECT * FROM quarantined_entry WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entry_db.map(Into::into))
+    }
+
+    async fn find_by_ledger_id_and_status(&self, ledger_id: Uuid, status: QuarantineStatus) -> Result<Vec<QuarantinedEntry>, DbError> {
+        let entries_db = sqlx::query_as::<_, QuarantinedEntryDb>("SELECT * FROM quarantined_entry WHERE ledger_id = ? AND status = ? ORDER BY created, line_number")
+            .bind(ledger_id.to_string())
+            .bind(status_to_db(&status))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entries_db.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, MySqlPool};
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::read_snapshot::AccountSnapshot;
+use postings_db::repositories::read_snapshot_repository::ReadSnapshotRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::posting_line::PostingLineDb;
+
+pub struct MariaDbReadSnapshotRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbReadSnapshotRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReadSnapshotRepository for MariaDbReadSnapshotRepository {
+    async fn read_account(&self, account_id: Uuid, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountSnapshot, DbError> {
+        let mut conn = self.pool.acquire().await.map_err(DbError::from)?;
+        // Applies to the next transaction of the connection only
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *conn)
+            .await
+            .map_err(DbError::from)?;
+        let mut tx = conn.begin().await.map_err(DbError::from)?;
+        let last_closed_stmt: Option<AccountStmt> = sqlx::query_as(
+            "SELECT * FROM account_stmt WHERE account_id = ? AND stmt_status = 'CLOSED' AND pst_time < ? ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1"
+        )
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        // Without closed statement, the balance needs all lines
+        let from = last_closed_stmt.as_ref().map(|stmt| stmt.pst_time.min(activity_from));
+        let lines_db = sqlx::query_as::<_, PostingLineDb>(
+            "SELECT * FROM posting_line WHERE account_id = ? AND (? IS NULL OR pst_time > ?) AND pst_time <= ? AND discarded_time IS NULL
+             ORDER BY pst_time DESC, record_time DESC, id DESC"
+        )
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(from)
+            .bind(ref_time)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(AccountSnapshot { last_closed_stmt, lines: lines_db.into_iter().map(Into::into).collect() })
+    }
+}
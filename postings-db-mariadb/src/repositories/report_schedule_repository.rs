@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::report_schedule_repository::ReportScheduleRepository;
+use postings_db::models::report_schedule::{ReportArtifact, ReportSchedule, ReportType};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::report_schedule::{report_type_to_db, ReportArtifactDb, ReportScheduleDb};
+
+pub struct MariaDbReportScheduleRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbReportScheduleRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReportScheduleRepository for MariaDbReportScheduleRepository {
+    async fn save(&self, schedule: ReportSchedule) -> Result<ReportSchedule, DbError> {
+        let db_model = ReportScheduleDb::from(schedule.clone());
+        sqlx::query(
+            "INSERT INTO report_schedule (id, ledger_id, report_type, format, frequency, next_ref_time, status, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                next_ref_time = VALUES(next_ref_time),
+                status = VALUES(status)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.report_type)
+            .bind(&db_model.format)
+            .bind(&db_model.frequency)
+            .bind(db_model.next_ref_time)
+            .bind(&db_model.status)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReportSchedule>, DbError> {
+        let schedule_db = sqlx::query_as::<_, ReportScheduleDb>("SELECT * FROM report_schedule WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule_db.map(Into::into))
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportSchedule>, DbError> {
+        let schedules_db = sqlx::query_as::<_, ReportScheduleDb>("SELECT * FROM report_schedule WHERE status = 'ACTIVE' AND next_ref_time <= ? ORDER BY next_ref_time, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedules_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_artifact(&self, artifact: ReportArtifact) -> Result<ReportArtifact, DbError> {
+        let db_model = ReportArtifactDb::from(artifact.clone());
+        sqlx::query(
+            "INSERT INTO report_artifact (id, schedule_id, ledger_id, report_type, format, ref_time, version, object_key, checksum, size, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(&db_model.id)
+            .bind(&db_model.schedule_id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.report_type)
+            .bind(&db_model.format)
+            .bind(db_model.ref_time)
+            .bind(db_model.version)
+            .bind(&db_model.object_key)
+            .bind(&db_model.checksum)
+            .bind(db_model.size)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(artifact)
+    }
+
+    async fn find_artifact_by_id(&self, id: Uuid) -> Result<Option<ReportArtifact>, DbError> {
+        let artifact_db = sqlx::query_as::<_, ReportArtifactDb>("SELECT * FROM report_artifact WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(artifact_db.map(Into::into))
+    }
+
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, DbError> {
+        let artifacts_db = sqlx::query_as::<_, ReportArtifactDb>("SELECT * FROM report_artifact WHERE ledger_id = ? AND report_type = ? AND ref_time = ? ORDER BY format, version")
+            .bind(ledger_id.to_string())
+            .bind(report_type_to_db(&report_type))
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(artifacts_db.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::reversal_policy_repository::ReversalPolicyRepository;
+use postings_db::models::reversal_policy::ReversalPolicy;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::reversal_policy::ReversalPolicyDb;
+
+pub struct MariaDbReversalPolicyRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbReversalPolicyRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReversalPolicyRepository for MariaDbReversalPolicyRepository {
+    async fn save(&self, policy: ReversalPolicy) -> Result<ReversalPolicy, DbError> {
+        let db_model = ReversalPolicyDb::from(policy.clone());
+        sqlx::query(
+            "INSERT INTO reversal_policy (ledger_id, teller_window_days, supervisor_window_days, updated) VALUES (?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                teller_window_days = VALUES(teller_window_days),
+                supervisor_window_days = VALUES(supervisor_window_days),
+                updated = VALUES(updated)")
+            .bind(&db_model.ledger_id)
+            .bind(db_model.teller_window_days)
+            .bind(db_model.supervisor_window_days)
+            .bind(db_model.updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(policy)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ReversalPolicy>, DbError> {
+        let policy_db = sqlx::query_as::<_, ReversalPolicyDb>("SELECT * FROM reversal_policy WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(policy_db.map(Into::into))
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::scheduled_posting_repository::ScheduledPostingRepository;
+use postings_db::models::scheduled_posting::ScheduledPosting;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::scheduled_posting::ScheduledPostingDb;
+
+pub struct MariaDbScheduledPostingRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbScheduledPostingRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduledPostingRepository for MariaDbScheduledPostingRepository {
+    async fn save(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, DbError> {
+        let db_model = ScheduledPostingDb::from(schedule.clone());
+        sqlx::query(
+            "INSERT INTO scheduled_posting (id, ledger_id, opr_type, pst_type, lines, frequency, start_time, end_time,
+                max_occurrences, next_pst_time, occurrence, status, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                next_pst_time = VALUES(next_pst_time),
+                occurrence = VALUES(occurrence),
+                status = VALUES(status)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.opr_type)
+            .bind(&db_model.pst_type)
+            .bind(&db_model.lines)
+            .bind(&db_model.frequency)
+            .bind(db_model.start_time)
+            .bind(db_model.end_time)
+            .bind(db_model.max_occurrences)
+            .bind(db_model.next_pst_time)
+            .bind(db_model.occurrence)
+            .bind(&db_model.status)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPosting>, DbError> {
+        let schedule_db = sqlx::query_as::<_, ScheduledPostingDb>("SELECT * FROM scheduled_posting WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedule_db.map(Into::into))
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ScheduledPosting>, DbError> {
+        let schedules_db = sqlx::query_as::<_, ScheduledPostingDb>("SELECT * FROM scheduled_posting WHERE status = 'ACTIVE' AND next_pst_time <= ? ORDER BY next_pst_time, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(schedules_db.into_iter().map(Into::into).collect())
+    }
+}
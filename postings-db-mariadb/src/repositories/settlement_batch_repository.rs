@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::settlement_batch_repository::SettlementBatchRepository;
+use postings_db::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::settlement_batch::{SettlementBatchDb, SettlementBatchEntryDb};
+
+pub struct MariaDbSettlementBatchRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbSettlementBatchRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SettlementBatchRepository for MariaDbSettlementBatchRepository {
+    async fn save(&self, batch: SettlementBatch) -> Result<SettlementBatch, DbError> {
+        let db_model = SettlementBatchDb::from(batch.clone());
+        sqlx::query(
+            "INSERT INTO settlement_batch (id, ledger_id, batch_ref, control_amount, control_count, status, created, closed_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                control_amount = VALUES(control_amount),
+                control_count = VALUES(control_count),
+                status = VALUES(status),
+                closed_time = VALUES(closed_time)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.batch_ref)
+            .bind(&db_model.control_amount)
+            .bind(db_model.control_count)
+            .bind(&db_model.status)
+            .bind(db_model.created)
+            .bind(db_model.closed_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(batch)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<SettlementBatch>, DbError> {
+        let batch_db = sqlx::query_as::<_, SettlementBatchDb>("SELECT * FROM settlement_batch WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(batch_db.map(Into::into))
+    }
+
+    async fn save_entry(&self, entry: SettlementBatchEntry) -> Result<SettlementBatchEntry, DbError> {
+        let db_model = SettlementBatchEntryDb::from(entry.clone());
+        sqlx::query("INSERT INTO settlement_batch_entry (batch_id, posting_id, amount, assigned_time) VALUES (?, ?, ?, ?)")
+            .bind(&db_model.batch_id)
+            .bind(&db_model.posting_id)
+            .bind(&db_model.amount)
+            .bind(db_model.assigned_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(entry)
+    }
+
+    async fn find_entries_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<SettlementBatchEntry>, DbError> {
+        let entries_db = sqlx::query_as::<_, SettlementBatchEntryDb>("SELECT * FROM settlement_batch_entry WHERE batch_id = ? ORDER BY assigned_time")
+            .bind(batch_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entries_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_entry_by_posting_id(&self, posting_id: Uuid) -> Result<Option<SettlementBatchEntry>, DbError> {
+        let entry_db = sqlx::query_as::<_, SettlementBatchEntryDb>("SELECT * FROM settlement_batch_entry WHERE posting_id = ?")
+            .bind(posting_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(entry_db.map(Into::into))
+    }
+}
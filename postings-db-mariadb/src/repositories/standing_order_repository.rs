@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::MySqlPool;
+use postings_db::repositories::standing_order_repository::StandingOrderRepository;
+use postings_db::models::standing_order::{StandingOrder, StandingOrderExecution};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::standing_order::{StandingOrderDb, StandingOrderExecutionDb};
+
+pub struct MariaDbStandingOrderRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStandingOrderRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StandingOrderRepository for MariaDbStandingOrderRepository {
+    async fn save(&self, order: StandingOrder) -> Result<StandingOrder, DbError> {
+        let db_model = StandingOrderDb::from(order.clone());
+        sqlx::query(
+            "INSERT INTO standing_order (id, ledger_id, source_account_id, target_account_id, amount, opr_type, frequency,
+                start_date, end_date, max_executions, max_retries, next_execution_date, occurrence, execution_count, status, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                end_date = VALUES(end_date),
+                max_executions = VALUES(max_executions),
+                next_execution_date = VALUES(next_execution_date),
+                occurrence = VALUES(occurrence),
+                execution_count = VALUES(execution_count),
+                status = VALUES(status)")
+            .bind(&db_model.id)
+            .bind(&db_model.ledger_id)
+            .bind(&db_model.source_account_id)
+            .bind(&db_model.target_account_id)
+            .bind(&db_model.amount)
+            .bind(&db_model.opr_type)
+            .bind(&db_model.frequency)
+            .bind(db_model.start_date)
+            .bind(db_model.end_date)
+            .bind(db_model.max_executions)
+            .bind(db_model.max_retries)
+            .bind(db_model.next_execution_date)
+            .bind(db_model.occurrence)
+            .bind(db_model.execution_count)
+            .bind(&db_model.status)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(order)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StandingOrder>, DbError> {
+        let order_db = sqlx::query_as::<_, StandingOrderDb>("SELECT * FROM standing_order WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(order_db.map(Into::into))
+    }
+
+    async fn find_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrder>, DbError> {
+        let orders_db = sqlx::query_as::<_, StandingOrderDb>("SELECT * FROM standing_order WHERE status = 'ACTIVE' AND next_execution_date <= ? ORDER BY next_execution_date, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(orders_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid) -> Result<Vec<StandingOrder>, DbError> {
+        let orders_db = sqlx::query_as::<_, StandingOrderDb>("SELECT * FROM standing_order WHERE status = 'ACTIVE' AND (source_account_id = ? OR target_account_id = ?) ORDER BY next_execution_date, id")
+            .bind(account_id.to_string())
+            .bind(account_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(orders_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_execution(&self, execution: StandingOrderExecution) -> Result<StandingOrderExecution, DbError> {
+        let db_model = StandingOrderExecutionDb::from(execution.clone());
+        sqlx::query(
+            "INSERT INTO standing_order_execution (id, order_id, execution_date, attempt, status, posting_id, message, executed_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(&db_model.id)
+            .bind(&db_model.order_id)
+            .bind(db_model.execution_date)
+            .bind(db_model.attempt)
+            .bind(&db_model.status)
+            .bind(&db_model.posting_id)
+            .bind(&db_model.message)
+            .bind(db_model.executed_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(execution)
+    }
+
+    async fn find_executions_by_order_id(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, DbError> {
+        let executions_db = sqlx::query_as::<_, StandingOrderExecutionDb>("SELECT * FROM standing_order_execution WHERE order_id = ? ORDER BY execution_date, attempt")
+            .bind(order_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(executions_db.into_iter().map(Into::into).collect())
+    }
+}
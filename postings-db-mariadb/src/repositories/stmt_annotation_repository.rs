@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::stmt_annotation_repository::StmtAnnotationRepository;
+use postings_db::models::stmt_annotation::StmtAnnotation;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::stmt_annotation::StmtAnnotationDb;
+
+pub struct MariaDbStmtAnnotationRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStmtAnnotationRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtAnnotationRepository for MariaDbStmtAnnotationRepository {
+    async fn save(&self, annotation: StmtAnnotation) -> Result<StmtAnnotation, DbError> {
+        let db_model = StmtAnnotationDb::from(annotation.clone());
+        sqlx::query("INSERT INTO stmt_annotation (id, stmt_id, author, text, created) VALUES (?, ?, ?, ?, ?)")
+            .bind(&db_model.id)
+            .bind(&db_model.stmt_id)
+            .bind(&db_model.author)
+            .bind(&db_model.text)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(annotation)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, DbError> {
+        let annotations = sqlx::query_as::<_, StmtAnnotationDb>("SELECT * FROM stmt_annotation WHERE stmt_id = ? ORDER BY created, id")
+            .bind(stmt_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(annotations.into_iter().map(Into::into).collect())
+    }
+}
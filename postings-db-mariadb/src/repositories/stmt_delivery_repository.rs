@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::stmt_delivery_repository::StmtDeliveryRepository;
+use postings_db::models::stmt_delivery::StmtDelivery;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::stmt_delivery::StmtDeliveryDb;
+
+pub struct MariaDbStmtDeliveryRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStmtDeliveryRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtDeliveryRepository for MariaDbStmtDeliveryRepository {
+    async fn save(&self, delivery: StmtDelivery) -> Result<StmtDelivery, DbError> {
+        let db_model = StmtDeliveryDb::from(delivery.clone());
+        sqlx::query(
+            "INSERT INTO stmt_delivery (id, stmt_id, format, checksum, signature, key_id, created)
+             VALUES (?, ?, ?, ?, ?, ?, ?)")
+            .bind(&db_model.id)
+            .bind(&db_model.stmt_id)
+            .bind(&db_model.format)
+            .bind(&db_model.checksum)
+            .bind(&db_model.signature)
+            .bind(&db_model.key_id)
+            .bind(db_model.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(delivery)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, DbError> {
+        let deliveries_db = sqlx::query_as::<_, StmtDeliveryDb>("SELECT * FROM stmt_delivery WHERE stmt_id = ? ORDER BY created")
+            .bind(stmt_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(deliveries_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_stmt_id_and_checksum(&self, stmt_id: Uuid, checksum: &[u8]) -> Result<Option<StmtDelivery>, DbError> {
+        let delivery_db = sqlx::query_as::<_, StmtDeliveryDb>("SELECT * FROM stmt_delivery WHERE stmt_id = ? AND checksum = ? ORDER BY created DESC LIMIT 1")
+            .bind(stmt_id.to_string())
+            .bind(checksum)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(delivery_db.map(Into::into))
+    }
+}
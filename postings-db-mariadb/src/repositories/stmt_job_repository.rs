@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::stmt_job_repository::StmtJobRepository;
+use postings_db::models::stmt_job::{StmtJob, StmtJobStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::stmt_job::{status_to_db, StmtJobDb};
+
+pub struct MariaDbStmtJobRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStmtJobRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtJobRepository for MariaDbStmtJobRepository {
+    async fn save(&self, job: StmtJob) -> Result<StmtJob, DbError> {
+        let db_model = StmtJobDb::from(job.clone());
+        sqlx::query(
+            "INSERT INTO stmt_job (id, account_id, ref_time, status, stmt_id, error_message, created, completed_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                stmt_id = VALUES(stmt_id),
+                error_message = VALUES(error_message),
+                completed_time = VALUES(completed_time)")
+            .bind(&db_model.id)
+            .bind(&db_model.account_id)
+            .bind(db_model.ref_time)
+            .bind(&db_model.status)
+            .bind(&db_model.stmt_id)
+            .bind(&db_model.error_message)
+            .bind(db_model.created)
+            .bind(db_model.completed_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StmtJob>, DbError> {
+        let job_db = sqlx::query_as::<_, StmtJobDb>("SELECT * FROM stmt_job WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(job_db.map(Into::into))
+    }
+
+    async fn find_by_status(&self, status: StmtJobStatus, limit: i64) -> Result<Vec<StmtJob>, DbError> {
+        let jobs_db = sqlx::query_as::<_, StmtJobDb>("SELECT * FROM stmt_job WHERE status = ? ORDER BY created LIMIT ?")
+            .bind(status_to_db(&status))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(jobs_db.into_iter().map(Into::into).collect())
+    }
+}
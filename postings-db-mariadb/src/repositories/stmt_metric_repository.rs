@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::stmt_metric_repository::StmtMetricRepository;
+use postings_db::models::stmt_metric::{AccountStmtStats, StmtMetric};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::stmt_metric::AccountStmtStatsDb;
+
+pub struct MariaDbStmtMetricRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStmtMetricRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_account_stats(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order_by: &str, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        let stats = sqlx::query_as::<_, AccountStmtStatsDb>(&format!(
+            "SELECT account_id, COUNT(*) AS generations,
+                    CAST(SUM(duration_ms) AS SIGNED) AS total_duration_ms, MAX(duration_ms) AS max_duration_ms, MAX(line_count) AS max_lines
+             FROM stmt_metric
+             WHERE ledger_id = ? AND recorded >= ? AND recorded < ?
+             GROUP BY account_id
+             ORDER BY {order_by} DESC, account_id LIMIT ?"))
+            .bind(ledger_id.to_string())
+            .bind(from)
+            .bind(to)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(stats.into_iter().map(Into::into).collect())
+    }
+}
+
+#[async_trait]
+impl StmtMetricRepository for MariaDbStmtMetricRepository {
+    async fn save(&self, metric: StmtMetric) -> Result<StmtMetric, DbError> {
+        sqlx::query("INSERT INTO stmt_metric (id, ledger_id, account_id, recorded, duration_ms, line_count) VALUES (?, ?, ?, ?, ?, ?)")
+            .bind(metric.id.to_string())
+            .bind(metric.ledger_id.to_string())
+            .bind(metric.account_id.to_string())
+            .bind(metric.recorded)
+            .bind(metric.duration_ms)
+            .bind(metric.line_count)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(metric)
+    }
+
+    async fn find_slowest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        self.find_account_stats(ledger_id, from, to, "max_duration_ms", limit).await
+    }
+
+    async fn find_hottest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        self.find_account_stats(ledger_id, from, to, "max_lines", limit).await
+    }
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::stmt_repair_repository::StmtRepairRepository;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::stmt_repair::StmtRepair;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::stmt_repair::StmtRepairDb;
+
+pub struct MariaDbStmtRepairRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbStmtRepairRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtRepairRepository for MariaDbStmtRepairRepository {
+    async fn save(&self, repair: StmtRepair, stmt: &AccountStmt) -> Result<StmtRepair, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query(
+            "UPDATE account_stmt SET
+                opening_debit = ?,
+                opening_credit = ?,
+                total_debit = ?,
+                total_credit = ?,
+                line_count = ?,
+                currency_totals = ?
+             WHERE id = ?")
+            .bind(&stmt.opening_debit)
+            .bind(&stmt.opening_credit)
+            .bind(&stmt.total_debit)
+            .bind(&stmt.total_credit)
+            .bind(stmt.line_count)
+            .bind(&stmt.currency_totals)
+            .bind(stmt.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        sqlx::query(
+            "INSERT INTO stmt_repair (id, stmt_id, account_id, pst_time, recorded, expected, principal, reason, repaired_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(repair.id.to_string())
+            .bind(repair.stmt_id.to_string())
+            .bind(repair.account_id.to_string())
+            .bind(repair.pst_time)
+            .bind(&repair.recorded)
+            .bind(&repair.expected)
+            .bind(&repair.principal)
+            .bind(&repair.reason)
+            .bind(repair.repaired_time)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(repair)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<StmtRepair>, DbError> {
+        let repairs = sqlx::query_as::<_, StmtRepairDb>("SELECT * FROM stmt_repair WHERE account_id = ? ORDER BY repaired_time DESC")
+            .bind(account_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(repairs.into_iter().map(Into::into).collect())
+    }
+}
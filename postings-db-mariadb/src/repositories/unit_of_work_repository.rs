@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use sqlx::{Connection, MySqlConnection, MySqlPool};
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::{Committed, RolledBack, UnitOfWork, Write};
+use postings_db::DbError;
+use crate::repositories::account_stmt_repository::insert_account_stmt;
+use crate::repositories::earmark_repository::upsert_earmark;
+use crate::repositories::ledger_stmt_repository::upsert_ledger_stmt;
+use crate::repositories::outbox_repository::insert_outbox_entry;
+use crate::repositories::posting_line_repository::insert_posting_line;
+use crate::repositories::posting_repository::{discard_posting, insert_posting};
+
+pub struct MariaDbUnitOfWorkRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbUnitOfWorkRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for MariaDbUnitOfWorkRepository {
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        let mut committed = Committed::default();
+        apply_each(&mut tx, work, &mut committed).await?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(committed)
+    }
+}
+
+/// Applies the writes on the connection. Savepoints run in a nested transaction, which sqlx
+/// maps to `SAVEPOINT`, and are rolled back on their own when one of their writes fails.
+fn apply_each<'a>(conn: &'a mut MySqlConnection, work: UnitOfWork, committed: &'a mut Committed) -> BoxFuture<'a, Result<(), DbError>> {
+    Box::pin(async move {
+        for write in work.into_writes() {
+            match write {
+                Write::Posting(posting) => insert_posting(&mut *conn, &posting).await?,
+                Write::DiscardPosting { id, discarding_id } => discard_posting(&mut *conn, id, discarding_id).await?,
+                Write::PostingLine(line) => {
+                    insert_posting_line(&mut *conn, &line).await?;
+                }
+                Write::AccountStmt(stmt) => {
+                    insert_account_stmt(&mut *conn, stmt).await?;
+                }
+                Write::LedgerStmt(stmt) => {
+                    upsert_ledger_stmt(&mut *conn, stmt).await?;
+                }
+                Write::OutboxEntry(entry) => insert_outbox_entry(&mut *conn, &entry).await?,
+                Write::Earmark(earmark) => {
+                    upsert_earmark(&mut *conn, earmark).await?;
+                }
+                Write::Savepoint(savepoint, nested) => {
+                    let mut sp = conn.begin().await.map_err(DbError::from)?;
+                    let mut nested_committed = Committed::default();
+                    match apply_each(&mut sp, nested, &mut nested_committed).await {
+                        Ok(()) => {
+                            sp.commit().await.map_err(DbError::from)?;
+                            committed.rolled_back.append(&mut nested_committed.rolled_back);
+                        }
+                        Err(error) => {
+                            sp.rollback().await.map_err(DbError::from)?;
+                            committed.rolled_back.push(RolledBack { savepoint, error });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,31 @@
+-- =============================================================================
+-- SETTLEMENT BATCHES
+-- =============================================================================
+
+CREATE TYPE batch_status AS ENUM ('OPEN', 'CLOSED', 'SETTLED', 'REJECTED');
+
+CREATE TABLE settlement_batch (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    batch_ref BYTEA NOT NULL,          -- 34-byte hash
+    control_amount NUMERIC(19, 2) NOT NULL,
+    control_count INT,
+    status batch_status NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    closed_time TIMESTAMPTZ
+);
+
+CREATE TABLE settlement_batch_entry (
+    batch_id UUID NOT NULL REFERENCES settlement_batch(id),
+    posting_id UUID NOT NULL REFERENCES posting(id),
+    amount NUMERIC(19, 2) NOT NULL,
+    assigned_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (batch_id, posting_id),
+    UNIQUE(posting_id)                 -- A posting belongs to at most one batch
+);
+
+CREATE INDEX idx_settlement_batch_ledger_id ON settlement_batch(ledger_id);
+CREATE INDEX idx_settlement_batch_batch_ref ON settlement_batch(batch_ref);
+
+COMMENT ON TABLE settlement_batch IS 'Settlement batches - postings settled together, validated against a control amount';
+COMMENT ON COLUMN settlement_batch_entry.amount IS 'Debit sum of the posting lines at assignment time';
@@ -0,0 +1,30 @@
+-- =============================================================================
+-- END-OF-DAY PROCESSING
+-- =============================================================================
+
+CREATE TYPE eod_status AS ENUM ('RUNNING', 'COMPLETED', 'FAILED');
+
+CREATE TABLE eod_run (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    business_date DATE NOT NULL,
+    status eod_status NOT NULL,
+    started TIMESTAMPTZ NOT NULL,
+    finished TIMESTAMPTZ,
+    UNIQUE(ledger_id, business_date)
+);
+
+-- One row per executed step; completed rows act as checkpoints for resumed runs
+CREATE TABLE eod_step_result (
+    run_id UUID NOT NULL REFERENCES eod_run(id),
+    step_seq INT NOT NULL,
+    step_name VARCHAR(255) NOT NULL,
+    status eod_status NOT NULL,
+    message VARCHAR(2048),
+    started TIMESTAMPTZ NOT NULL,
+    finished TIMESTAMPTZ,
+    PRIMARY KEY (run_id, step_seq)
+);
+
+COMMENT ON TABLE eod_run IS 'End-of-day runs per ledger and business date';
+COMMENT ON TABLE eod_step_result IS 'Per-step results and checkpoints of end-of-day runs';
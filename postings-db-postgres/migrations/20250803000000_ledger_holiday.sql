@@ -0,0 +1,12 @@
+-- =============================================================================
+-- LEDGER HOLIDAY CALENDAR
+-- =============================================================================
+
+CREATE TABLE ledger_holiday (
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    holiday_date DATE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    PRIMARY KEY (ledger_id, holiday_date)
+);
+
+COMMENT ON TABLE ledger_holiday IS 'Non-business days per ledger; weekends are implicit';
@@ -0,0 +1,13 @@
+-- =============================================================================
+-- ACCOUNT LIMITS
+-- =============================================================================
+
+CREATE TABLE account_limit (
+    account_id UUID PRIMARY KEY REFERENCES ledger_account(id),
+    max_debit_balance NUMERIC(19, 2),
+    overdraft_limit NUMERIC(19, 2),
+    daily_turnover_limit NUMERIC(19, 2),
+    updated TIMESTAMPTZ NOT NULL
+);
+
+COMMENT ON TABLE account_limit IS 'Per-account limits checked when validating new postings';
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- EARMARKS (BLOCKED AMOUNTS)
+-- =============================================================================
+
+CREATE TYPE earmark_status AS ENUM ('ACTIVE', 'RELEASED', 'CONSUMED', 'EXPIRED');
+
+CREATE TABLE earmark (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    amount NUMERIC(19, 2) NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    expiry TIMESTAMPTZ,
+    status earmark_status NOT NULL,
+    closed_time TIMESTAMPTZ
+);
+
+CREATE INDEX idx_earmark_account_status ON earmark(account_id, status);
+
+COMMENT ON TABLE earmark IS 'Amounts blocked on accounts, reducing the available balance without posting';
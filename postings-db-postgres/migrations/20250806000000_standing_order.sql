@@ -0,0 +1,43 @@
+-- =============================================================================
+-- STANDING ORDERS
+-- =============================================================================
+
+CREATE TYPE frequency AS ENUM ('DAILY', 'WEEKLY', 'MONTHLY', 'QUARTERLY', 'YEARLY');
+CREATE TYPE standing_order_status AS ENUM ('ACTIVE', 'COMPLETED', 'CANCELLED');
+CREATE TYPE execution_status AS ENUM ('EXECUTED', 'FAILED', 'SKIPPED');
+
+CREATE TABLE standing_order (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    source_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    target_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    amount NUMERIC(19, 2) NOT NULL,
+    opr_type BYTEA NOT NULL,           -- 34-byte hash
+    frequency frequency NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    max_executions INT,
+    max_retries INT NOT NULL,
+    next_execution_date DATE NOT NULL,
+    occurrence INT NOT NULL,
+    execution_count INT NOT NULL,
+    status standing_order_status NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE standing_order_execution (
+    id UUID PRIMARY KEY,
+    order_id UUID NOT NULL REFERENCES standing_order(id),
+    execution_date DATE NOT NULL,
+    attempt INT NOT NULL,
+    status execution_status NOT NULL,
+    posting_id UUID REFERENCES posting(id),
+    message VARCHAR(2048),
+    executed_time TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_standing_order_due ON standing_order(status, next_execution_date);
+CREATE INDEX idx_standing_order_execution_order ON standing_order_execution(order_id);
+
+COMMENT ON TABLE standing_order IS 'Recurring fixed amount transfers executed by the scheduler';
+COMMENT ON TABLE standing_order_execution IS 'Execution attempts of standing order occurrences';
@@ -0,0 +1,29 @@
+-- =============================================================================
+-- FEE SCHEDULES
+-- =============================================================================
+
+CREATE TYPE fee_type AS ENUM ('FLAT', 'PERCENTAGE', 'TIERED');
+
+CREATE TABLE fee_schedule (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    opr_type BYTEA NOT NULL,           -- 34-byte hash
+    fee_type fee_type NOT NULL,
+    flat_amount NUMERIC(19, 2),
+    rate NUMERIC(19, 8),
+    min_fee NUMERIC(19, 2),
+    max_fee NUMERIC(19, 2),
+    fee_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE(ledger_id, opr_type)
+);
+
+CREATE TABLE fee_tier (
+    schedule_id UUID NOT NULL REFERENCES fee_schedule(id),
+    from_amount NUMERIC(19, 2) NOT NULL,
+    flat_amount NUMERIC(19, 2) NOT NULL,
+    rate NUMERIC(19, 8) NOT NULL,
+    PRIMARY KEY (schedule_id, from_amount)
+);
+
+COMMENT ON TABLE fee_schedule IS 'Fees generated automatically per operation type';
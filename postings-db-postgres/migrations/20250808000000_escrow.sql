@@ -0,0 +1,27 @@
+-- =============================================================================
+-- ESCROW (TWO-PHASE POSTINGS)
+-- =============================================================================
+
+CREATE TYPE escrow_status AS ENUM ('PENDING', 'RELEASED', 'RETURNED');
+CREATE TYPE escrow_timeout_action AS ENUM ('RELEASE', 'RETURN');
+
+CREATE TABLE escrow (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    source_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    escrow_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    target_account_id UUID NOT NULL REFERENCES ledger_account(id),
+    amount NUMERIC(19, 2) NOT NULL,
+    opr_type BYTEA NOT NULL,           -- 34-byte hash
+    status escrow_status NOT NULL,
+    timeout TIMESTAMPTZ NOT NULL,
+    timeout_action escrow_timeout_action NOT NULL,
+    initiation_posting_id UUID REFERENCES posting(id),
+    resolution_posting_id UUID REFERENCES posting(id),
+    created TIMESTAMPTZ NOT NULL,
+    resolved_time TIMESTAMPTZ
+);
+
+CREATE INDEX idx_escrow_pending_timeout ON escrow(status, timeout);
+
+COMMENT ON TABLE escrow IS 'Two-phase transfers parked on an escrow account until confirmed, cancelled or timed out';
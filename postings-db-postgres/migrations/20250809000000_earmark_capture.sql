@@ -0,0 +1,5 @@
+-- =============================================================================
+-- EARMARK PARTIAL CAPTURE
+-- =============================================================================
+
+ALTER TABLE earmark ADD COLUMN captured_amount NUMERIC(19, 2) NOT NULL DEFAULT 0;
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- IMPORT QUARANTINE
+-- =============================================================================
+
+CREATE TYPE quarantine_status AS ENUM ('PENDING', 'RESUBMITTED', 'DISCARDED');
+
+CREATE TABLE quarantined_entry (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    import_type VARCHAR(64) NOT NULL,
+    line_number BIGINT NOT NULL,
+    payload TEXT NOT NULL,
+    reason VARCHAR(1024) NOT NULL,
+    status quarantine_status NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    resolved_time TIMESTAMPTZ
+);
+
+CREATE INDEX idx_quarantined_entry_ledger_status ON quarantined_entry(ledger_id, status);
+
+COMMENT ON TABLE quarantined_entry IS 'Import records rejected by lenient imports, awaiting correction and resubmission';
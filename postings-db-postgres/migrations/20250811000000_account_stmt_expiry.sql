@@ -0,0 +1,9 @@
+-- =============================================================================
+-- SIMULATED STATEMENT EXPIRY
+-- =============================================================================
+
+ALTER TABLE account_stmt ADD COLUMN expiry TIMESTAMPTZ;
+
+CREATE INDEX idx_account_stmt_status_expiry ON account_stmt(stmt_status, expiry);
+
+COMMENT ON COLUMN account_stmt.expiry IS 'Expiry of persisted simulated statements; NULL for closed statements';
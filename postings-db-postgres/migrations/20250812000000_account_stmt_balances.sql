@@ -0,0 +1,10 @@
+-- =============================================================================
+-- STATEMENT OPENING BALANCE, LINE COUNT AND CLOSING BALANCE
+-- =============================================================================
+
+ALTER TABLE account_stmt ADD COLUMN opening_debit NUMERIC(19, 2) NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN opening_credit NUMERIC(19, 2) NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN line_count BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE account_stmt ADD COLUMN closing_balance NUMERIC(19, 2) GENERATED ALWAYS AS (total_debit - total_credit) STORED;
+
+COMMENT ON COLUMN account_stmt.closing_balance IS 'Debit-oriented closing balance (total_debit - total_credit)';
@@ -0,0 +1,18 @@
+-- =============================================================================
+-- LEDGER STATEMENTS
+-- =============================================================================
+
+CREATE TABLE ledger_stmt (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    total_debit NUMERIC(19, 2) NOT NULL,
+    total_credit NUMERIC(19, 2) NOT NULL,
+    posting_id UUID REFERENCES posting(id),
+    pst_time TIMESTAMPTZ NOT NULL,
+    stmt_status stmt_status NOT NULL,
+    stmt_seq_nbr INT NOT NULL
+);
+
+CREATE INDEX idx_ledger_stmt_ledger_pst_time ON ledger_stmt(ledger_id, pst_time);
+
+COMMENT ON TABLE ledger_stmt IS 'Ledger-level statements sharing the financial statement core with account statements';
@@ -0,0 +1,13 @@
+-- =============================================================================
+-- EXTERNALLY STORED OPERATION DETAILS
+-- =============================================================================
+
+CREATE TABLE external_content (
+    content_hash BYTEA PRIMARY KEY,    -- 34-byte hash
+    object_key VARCHAR(1024) NOT NULL,
+    size BIGINT NOT NULL,
+    media_type VARCHAR(255) NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+COMMENT ON TABLE external_content IS 'References to operation details kept in object storage, keyed by the hash carried in posting.opr_details';
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- ASYNCHRONOUS STATEMENT JOBS
+-- =============================================================================
+
+CREATE TYPE stmt_job_status AS ENUM ('PENDING', 'RUNNING', 'COMPLETED', 'FAILED');
+
+CREATE TABLE stmt_job (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    ref_time TIMESTAMPTZ NOT NULL,
+    status stmt_job_status NOT NULL,
+    stmt_id UUID,
+    error_message VARCHAR(1024),
+    created TIMESTAMPTZ NOT NULL,
+    completed_time TIMESTAMPTZ
+);
+
+CREATE INDEX idx_stmt_job_status_created ON stmt_job(status, created);
+
+COMMENT ON TABLE stmt_job IS 'Queued statement generations, polled by clients through their job id';
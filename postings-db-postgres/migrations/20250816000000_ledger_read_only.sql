@@ -0,0 +1,7 @@
+-- =============================================================================
+-- LEDGER READ-ONLY MODE
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN read_only BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN ledger.read_only IS 'Maintenance switch rejecting all mutating operations on the ledger';
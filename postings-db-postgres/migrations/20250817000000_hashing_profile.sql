@@ -0,0 +1,14 @@
+-- =============================================================================
+-- HASHING PROFILES
+-- =============================================================================
+
+CREATE TABLE hashing_profile (
+    ledger_id UUID PRIMARY KEY REFERENCES ledger(id),
+    excluded_fields VARCHAR(255) NOT NULL,
+    updated TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE posting ADD COLUMN hash_excluded_fields VARCHAR(255);
+
+COMMENT ON TABLE hashing_profile IS 'Posting fields left out of the posting hash per ledger, so they can be redacted later';
+COMMENT ON COLUMN posting.hash_excluded_fields IS 'Fields excluded from the hash when the posting was recorded';
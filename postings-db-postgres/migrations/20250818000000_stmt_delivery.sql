@@ -0,0 +1,19 @@
+-- =============================================================================
+-- STATEMENT DELIVERY MANIFESTS
+-- =============================================================================
+
+CREATE TYPE stmt_document_format AS ENUM ('CSV', 'JSON', 'PDF');
+
+CREATE TABLE stmt_delivery (
+    id UUID PRIMARY KEY,
+    stmt_id UUID NOT NULL REFERENCES account_stmt(id),
+    format stmt_document_format NOT NULL,
+    checksum BYTEA NOT NULL, -- 34-byte hash
+    signature BYTEA,
+    key_id VARCHAR(255),
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_stmt_delivery_stmt_checksum ON stmt_delivery(stmt_id, checksum);
+
+COMMENT ON TABLE stmt_delivery IS 'Checksums and detached signatures of statement documents rendered for delivery';
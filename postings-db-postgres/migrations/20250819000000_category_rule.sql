@@ -0,0 +1,21 @@
+-- =============================================================================
+-- POSTING LINE CATEGORIZATION RULES
+-- =============================================================================
+
+CREATE TABLE category_rule (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    category VARCHAR(255) NOT NULL,
+    priority INTEGER NOT NULL,
+    account_id UUID REFERENCES ledger_account(id),
+    details BYTEA, -- 34-byte hash
+    src_account BYTEA, -- 34-byte hash
+    opr_src BYTEA, -- 34-byte hash
+    min_amount NUMERIC(19, 2),
+    max_amount NUMERIC(19, 2),
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_category_rule_ledger_priority ON category_rule(ledger_id, priority);
+
+COMMENT ON TABLE category_rule IS 'Rules labelling posting lines with a category when they are read; first match by priority wins';
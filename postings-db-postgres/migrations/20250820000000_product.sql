@@ -0,0 +1,32 @@
+-- =============================================================================
+-- ACCOUNT PRODUCTS
+-- =============================================================================
+
+CREATE TYPE day_count_convention AS ENUM ('ACT360', 'ACT365FIXED', 'ACTACTISDA', 'THIRTY360');
+
+CREATE TABLE product (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    name VARCHAR(255) NOT NULL,
+    interest_rate NUMERIC(19, 8),
+    day_count_convention day_count_convention,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE account_product (
+    account_id UUID PRIMARY KEY REFERENCES ledger_account(id),
+    product_id UUID NOT NULL REFERENCES product(id),
+    linked TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_product_ledger_id ON product(ledger_id);
+CREATE INDEX idx_account_product_product_id ON account_product(product_id);
+
+-- Fee schedules of a product override the ledger-wide schedule of the same operation type
+ALTER TABLE fee_schedule ADD COLUMN product_id UUID REFERENCES product(id);
+ALTER TABLE fee_schedule DROP CONSTRAINT fee_schedule_ledger_id_opr_type_key;
+CREATE UNIQUE INDEX idx_fee_schedule_ledger_default ON fee_schedule(ledger_id, opr_type) WHERE product_id IS NULL;
+CREATE UNIQUE INDEX idx_fee_schedule_product ON fee_schedule(product_id, opr_type) WHERE product_id IS NOT NULL;
+
+COMMENT ON TABLE product IS 'Account products carrying interest terms and product-specific fee schedules';
+COMMENT ON TABLE account_product IS 'Product each ledger account is linked to';
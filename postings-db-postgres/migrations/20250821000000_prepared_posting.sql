@@ -0,0 +1,22 @@
+-- =============================================================================
+-- TWO-PHASE POSTINGS
+-- =============================================================================
+
+CREATE TYPE prepared_posting_status AS ENUM ('PREPARED', 'COMMITTED', 'ABORTED');
+
+CREATE TABLE prepared_posting (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    opr_id BYTEA NOT NULL,             -- 34-byte hash
+    payload TEXT NOT NULL,
+    status prepared_posting_status NOT NULL,
+    coordinator_ref VARCHAR(255) NOT NULL,
+    expires TIMESTAMPTZ NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    resolved_time TIMESTAMPTZ,
+    posting_id UUID
+);
+
+CREATE INDEX idx_prepared_posting_status_expires ON prepared_posting(status, expires);
+
+COMMENT ON TABLE prepared_posting IS 'Postings validated in the first phase of externally coordinated transactions';
@@ -0,0 +1,60 @@
+-- =============================================================================
+-- CHANGE DATA CAPTURE EVENTS
+-- =============================================================================
+
+CREATE TYPE ledger_event_operation AS ENUM ('INSERT', 'UPDATE', 'DELETE');
+
+-- Append-only; written by triggers in the transaction of the mutation itself
+CREATE TABLE ledger_event (
+    seq BIGSERIAL PRIMARY KEY,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255) NOT NULL,
+    operation ledger_event_operation NOT NULL,
+    payload TEXT NOT NULL,             -- JSON of the row, the deleted row for DELETE
+    created TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp()
+);
+
+CREATE INDEX idx_ledger_event_entity ON ledger_event(entity_type, entity_id);
+
+CREATE FUNCTION record_ledger_event() RETURNS TRIGGER AS $$
+DECLARE
+    entity JSONB;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        entity := to_jsonb(OLD);
+    ELSE
+        entity := to_jsonb(NEW);
+    END IF;
+    INSERT INTO ledger_event (entity_type, entity_id, operation, payload)
+    VALUES (TG_TABLE_NAME, entity->>'id', TG_OP::ledger_event_operation, entity::text);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION reject_ledger_event_change() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'ledger_event is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_ledger_event_append_only BEFORE UPDATE OR DELETE ON ledger_event
+    FOR EACH ROW EXECUTE FUNCTION reject_ledger_event_change();
+
+CREATE TRIGGER trg_chart_of_account_cdc AFTER INSERT OR UPDATE OR DELETE ON chart_of_account
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_ledger_cdc AFTER INSERT OR UPDATE OR DELETE ON ledger
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_ledger_account_cdc AFTER INSERT OR UPDATE OR DELETE ON ledger_account
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_named_cdc AFTER INSERT OR UPDATE OR DELETE ON named
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_posting_cdc AFTER INSERT OR UPDATE OR DELETE ON posting
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_posting_line_cdc AFTER INSERT OR UPDATE OR DELETE ON posting_line
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_account_stmt_cdc AFTER INSERT OR UPDATE OR DELETE ON account_stmt
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+CREATE TRIGGER trg_posting_trace_cdc AFTER INSERT OR UPDATE OR DELETE ON posting_trace
+    FOR EACH ROW EXECUTE FUNCTION record_ledger_event();
+
+COMMENT ON TABLE ledger_event IS 'Ordered change events of the core ledger tables for CDC consumers and the outbox dispatcher';
@@ -0,0 +1,26 @@
+-- =============================================================================
+-- CHANGE EVENT COMPACTION
+-- =============================================================================
+
+-- Positions acknowledged by downstream consumers; events below all of them are dispatched
+CREATE TABLE ledger_event_consumer (
+    name VARCHAR(255) PRIMARY KEY,
+    last_seq BIGINT NOT NULL,
+    updated TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE ledger_event_archive (
+    seq BIGINT PRIMARY KEY,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255) NOT NULL,
+    operation ledger_event_operation NOT NULL,
+    payload TEXT NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+-- Dispatched events may now be moved to the archive; rows stay immutable
+DROP TRIGGER trg_ledger_event_append_only ON ledger_event;
+CREATE TRIGGER trg_ledger_event_append_only BEFORE UPDATE ON ledger_event
+    FOR EACH ROW EXECUTE FUNCTION reject_ledger_event_change();
+
+COMMENT ON TABLE ledger_event_archive IS 'Dispatched change events moved out of ledger_event after their retention period';
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- API KEYS
+-- =============================================================================
+
+CREATE TABLE api_key (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    tenant_id UUID NOT NULL,
+    ledger_id UUID REFERENCES ledger(id),
+    roles VARCHAR(255) NOT NULL,
+    key_prefix VARCHAR(16) NOT NULL UNIQUE,
+    key_hash BYTEA NOT NULL,           -- 34-byte hash
+    created TIMESTAMPTZ NOT NULL,
+    expires TIMESTAMPTZ,
+    revoked_time TIMESTAMPTZ
+);
+
+CREATE INDEX idx_api_key_tenant_id ON api_key(tenant_id);
+
+COMMENT ON TABLE api_key IS 'Hashed credentials of service accounts, scoped to a tenant or one of its ledgers';
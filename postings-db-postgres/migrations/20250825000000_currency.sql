@@ -0,0 +1,16 @@
+-- =============================================================================
+-- MULTI-CURRENCY ACCOUNTS AND POSTING LINES
+-- =============================================================================
+
+-- NULL on accounts holding several currencies and on ledgers without currencies
+ALTER TABLE ledger_account ADD COLUMN currency CHAR(3);
+
+ALTER TABLE posting_line ADD COLUMN currency CHAR(3);
+ALTER TABLE posting_line ADD COLUMN fx_base_currency CHAR(3);
+ALTER TABLE posting_line ADD COLUMN fx_rate NUMERIC(19, 10);
+
+ALTER TABLE posting_line ADD CONSTRAINT chk_posting_line_fx
+    CHECK ((fx_base_currency IS NULL) = (fx_rate IS NULL));
+
+-- JSON array of {currency, total_debit, total_credit}
+ALTER TABLE account_stmt ADD COLUMN currency_totals TEXT;
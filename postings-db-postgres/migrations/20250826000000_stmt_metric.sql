@@ -0,0 +1,16 @@
+-- =============================================================================
+-- STATEMENT GENERATION METRICS
+-- =============================================================================
+
+CREATE TABLE stmt_metric (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    recorded TIMESTAMPTZ NOT NULL,
+    duration_ms BIGINT NOT NULL,
+    line_count BIGINT NOT NULL
+);
+
+CREATE INDEX idx_stmt_metric_ledger_recorded ON stmt_metric(ledger_id, recorded);
+
+COMMENT ON TABLE stmt_metric IS 'Duration and lines read per statement generation, for slow and hot account diagnostics';
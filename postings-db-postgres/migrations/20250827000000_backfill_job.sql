@@ -0,0 +1,24 @@
+-- =============================================================================
+-- BACKFILL JOBS
+-- =============================================================================
+
+CREATE TYPE backfill_status AS ENUM ('RUNNING', 'COMPLETED', 'FAILED');
+
+CREATE TABLE backfill_job (
+    id UUID PRIMARY KEY,
+    task_name VARCHAR(255) NOT NULL,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    status backfill_status NOT NULL,
+    batch_size INTEGER NOT NULL,
+    checkpoint VARCHAR(255),
+    processed BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    error_message VARCHAR(1024),
+    started TIMESTAMPTZ NOT NULL,
+    updated TIMESTAMPTZ NOT NULL,
+    finished TIMESTAMPTZ
+);
+
+CREATE INDEX idx_backfill_job_task_ledger ON backfill_job(task_name, ledger_id, status);
+
+COMMENT ON TABLE backfill_job IS 'Batched population of derived data for historical records, resumed from the saved checkpoint';
@@ -0,0 +1,19 @@
+-- =============================================================================
+-- STATEMENT REPAIRS
+-- =============================================================================
+
+CREATE TABLE stmt_repair (
+    id UUID PRIMARY KEY,
+    stmt_id UUID NOT NULL REFERENCES account_stmt(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    pst_time TIMESTAMPTZ NOT NULL,
+    recorded TEXT NOT NULL,
+    expected TEXT NOT NULL,
+    principal VARCHAR(255) NOT NULL,
+    reason VARCHAR(1024) NOT NULL,
+    repaired_time TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_stmt_repair_account_repaired ON stmt_repair(account_id, repaired_time);
+
+COMMENT ON TABLE stmt_repair IS 'Audit log of closed statements whose figures were recomputed from their posting lines';
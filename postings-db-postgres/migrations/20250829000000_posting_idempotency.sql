@@ -0,0 +1,9 @@
+-- =============================================================================
+-- POSTING IDEMPOTENCY
+-- =============================================================================
+
+-- UNIQUE(opr_id, discarding_id) does not apply while discarding_id is NULL, so an operation
+-- could be recorded twice. Only one current version of an operation is allowed.
+CREATE UNIQUE INDEX uq_posting_current_opr_id ON posting(opr_id) WHERE discarding_id IS NULL;
+
+COMMENT ON INDEX uq_posting_current_opr_id IS 'The operation id is the idempotency key of a posting: at most one current version per operation';
@@ -0,0 +1,12 @@
+-- =============================================================================
+-- REVERSAL POLICIES
+-- =============================================================================
+
+CREATE TABLE reversal_policy (
+    ledger_id UUID PRIMARY KEY REFERENCES ledger(id),
+    teller_window_days INTEGER NOT NULL,
+    supervisor_window_days INTEGER,
+    updated TIMESTAMPTZ NOT NULL
+);
+
+COMMENT ON TABLE reversal_policy IS 'Days after the posting date during which tellers may storno and supervisors may still correct postings per ledger';
@@ -0,0 +1,39 @@
+-- =============================================================================
+-- SCHEDULED REPORTS
+-- =============================================================================
+
+CREATE TYPE report_type AS ENUM ('TRIAL_BALANCE', 'DAILY_JOURNAL');
+CREATE TYPE report_format AS ENUM ('JSON', 'CSV', 'PDF');
+CREATE TYPE report_frequency AS ENUM ('DAILY', 'MONTH_END');
+CREATE TYPE report_schedule_status AS ENUM ('ACTIVE', 'CANCELLED');
+
+CREATE TABLE report_schedule (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    report_type report_type NOT NULL,
+    format report_format NOT NULL,
+    frequency report_frequency NOT NULL,
+    next_ref_time TIMESTAMPTZ NOT NULL,
+    status report_schedule_status NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE report_artifact (
+    id UUID PRIMARY KEY,
+    schedule_id UUID REFERENCES report_schedule(id),
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    report_type report_type NOT NULL,
+    format report_format NOT NULL,
+    ref_time TIMESTAMPTZ NOT NULL,
+    version INT NOT NULL,
+    object_key VARCHAR(512) NOT NULL,
+    checksum BYTEA NOT NULL,           -- 34-byte multihash of the content
+    size BIGINT NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE (ledger_id, report_type, ref_time, format, version)
+);
+
+CREATE INDEX idx_report_schedule_due ON report_schedule(status, next_ref_time);
+
+COMMENT ON TABLE report_schedule IS 'Reports generated by the scheduler at the end of each period';
+COMMENT ON TABLE report_artifact IS 'Versions of generated reports, content kept in the object store';
@@ -0,0 +1,20 @@
+-- =============================================================================
+-- ACCOUNT GROUPS
+-- =============================================================================
+
+CREATE TABLE account_group (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    name VARCHAR(255) NOT NULL,
+    category account_category,         -- accounts of this category belong to the group
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE (ledger_id, name)
+);
+
+CREATE TABLE account_group_member (
+    group_id UUID NOT NULL REFERENCES account_group(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    PRIMARY KEY (group_id, account_id)
+);
+
+COMMENT ON TABLE account_group IS 'Named sets of accounts reported on together, by category and explicit members';
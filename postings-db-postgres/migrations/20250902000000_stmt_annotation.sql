@@ -0,0 +1,17 @@
+-- =============================================================================
+-- STATEMENT ANNOTATIONS
+-- =============================================================================
+
+-- No foreign key on stmt_id: notes on simulated statements stay on record after
+-- the statements themselves are purged.
+CREATE TABLE stmt_annotation (
+    id UUID PRIMARY KEY,
+    stmt_id UUID NOT NULL,
+    author VARCHAR(255) NOT NULL,
+    text TEXT NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_stmt_annotation_stmt ON stmt_annotation(stmt_id, created);
+
+COMMENT ON TABLE stmt_annotation IS 'Append-only reviewer notes on account statements';
@@ -0,0 +1,7 @@
+-- =============================================================================
+-- MEMO LEDGERS
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN memo BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN ledger.memo IS 'Memo ledger whose postings are not required to balance';
@@ -0,0 +1,7 @@
+-- =============================================================================
+-- CLOSING SUMMARIES
+-- =============================================================================
+
+ALTER TABLE ledger ADD COLUMN closing_summary BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN ledger.closing_summary IS 'Closing postings record the hash of the closed statement totals as opr_details';
@@ -0,0 +1,18 @@
+-- =============================================================================
+-- IMPORT CHECKPOINTS
+-- =============================================================================
+
+CREATE TABLE import_checkpoint (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    import_type VARCHAR(64) NOT NULL,
+    source_hash BYTEA NOT NULL,
+    next_line BIGINT NOT NULL,
+    loaded_count BIGINT NOT NULL,
+    quarantined_count BIGINT NOT NULL,
+    completed BOOLEAN NOT NULL,
+    updated TIMESTAMPTZ NOT NULL,
+    UNIQUE (ledger_id, import_type, source_hash)
+);
+
+COMMENT ON TABLE import_checkpoint IS 'Progress of file imports, for resuming an interrupted import after its last completed line';
@@ -0,0 +1,17 @@
+-- =============================================================================
+-- OUTBOX
+-- =============================================================================
+
+CREATE TABLE outbox (
+    id UUID PRIMARY KEY,
+    event_type VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    delivered TIMESTAMPTZ
+);
+
+CREATE INDEX idx_outbox_undelivered ON outbox(created, id) WHERE delivered IS NULL;
+
+COMMENT ON TABLE outbox IS 'Domain events written in the transaction of their change, delivered afterwards by the outbox relay';
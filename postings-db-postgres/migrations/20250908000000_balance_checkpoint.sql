@@ -0,0 +1,18 @@
+-- =============================================================================
+-- BALANCE CHECKPOINTS
+-- =============================================================================
+
+CREATE TABLE balance_checkpoint (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    as_of TIMESTAMPTZ NOT NULL,
+    base_stmt_id UUID REFERENCES account_stmt(id),
+    total_debit NUMERIC(19, 2) NOT NULL,
+    total_credit NUMERIC(19, 2) NOT NULL,
+    line_count BIGINT NOT NULL,
+    currency_totals TEXT,
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE (account_id, as_of)
+);
+
+COMMENT ON TABLE balance_checkpoint IS 'Statement totals of accounts at a posting time, so statement generation only reads the lines posted after it';
@@ -0,0 +1,23 @@
+-- =============================================================================
+-- EXTERNAL REFERENCES
+-- =============================================================================
+
+CREATE TYPE external_ref_entity AS ENUM ('POSTING', 'LEDGER_ACCOUNT');
+
+CREATE TABLE external_ref (
+    id UUID PRIMARY KEY,
+    entity_type external_ref_entity NOT NULL,
+    entity_id UUID NOT NULL,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    ref_system VARCHAR(64) NOT NULL,
+    reference VARCHAR(255) NOT NULL,
+    unique_scope VARCHAR(36),
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE (entity_type, entity_id, ref_system),
+    -- NULL scopes never conflict, so shared references are not constrained
+    UNIQUE (entity_type, ref_system, reference, unique_scope)
+);
+
+CREATE INDEX idx_external_ref_lookup ON external_ref(ledger_id, entity_type, ref_system, reference);
+
+COMMENT ON TABLE external_ref IS 'Identifiers of postings and accounts in other systems, for cross-system correlation';
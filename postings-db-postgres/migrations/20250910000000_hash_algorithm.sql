@@ -0,0 +1,10 @@
+-- =============================================================================
+-- HASH ALGORITHM
+-- =============================================================================
+
+-- Postings recorded before this migration were hashed with SHA-256.
+ALTER TABLE posting ADD COLUMN hash_algorithm VARCHAR(16) NOT NULL DEFAULT 'SHA2_256';
+ALTER TABLE hashing_profile ADD COLUMN hash_algorithm VARCHAR(16) NOT NULL DEFAULT 'SHA2_256';
+
+COMMENT ON COLUMN posting.hash_algorithm IS 'Hash function the posting hash was computed with';
+COMMENT ON COLUMN hashing_profile.hash_algorithm IS 'Hash function new postings of the ledger are hashed with';
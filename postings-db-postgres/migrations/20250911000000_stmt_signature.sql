@@ -0,0 +1,13 @@
+-- =============================================================================
+-- STATEMENT SIGNATURES
+-- =============================================================================
+
+-- Set when a statement is closed with a signer configured
+ALTER TABLE account_stmt ADD COLUMN signature BYTEA;
+ALTER TABLE account_stmt ADD COLUMN signing_key_id VARCHAR(255);
+
+ALTER TABLE account_stmt ADD CONSTRAINT chk_account_stmt_signature
+    CHECK ((signature IS NULL) = (signing_key_id IS NULL));
+
+COMMENT ON COLUMN account_stmt.signature IS 'Ed25519 signature over the canonical form of the closed statement';
+COMMENT ON COLUMN account_stmt.signing_key_id IS 'Key that produced the signature';
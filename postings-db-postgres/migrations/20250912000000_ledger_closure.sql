@@ -0,0 +1,19 @@
+-- =============================================================================
+-- LEDGER CLOSURES
+-- =============================================================================
+
+CREATE TABLE ledger_closure (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    account_id UUID REFERENCES ledger_account(id),
+    cut_off TIMESTAMPTZ NOT NULL,
+    allow_adjustments BOOLEAN NOT NULL DEFAULT FALSE,
+    closed_by VARCHAR(255) NOT NULL,
+    reason TEXT NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_ledger_closure_ledger ON ledger_closure(ledger_id, cut_off);
+
+COMMENT ON TABLE ledger_closure IS 'Locks of ledgers or accounts against postings before a cut-off posting time';
+COMMENT ON COLUMN ledger_closure.account_id IS 'Locked account, the whole ledger when NULL';
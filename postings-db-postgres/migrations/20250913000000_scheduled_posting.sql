@@ -0,0 +1,26 @@
+-- =============================================================================
+-- SCHEDULED POSTINGS
+-- =============================================================================
+
+CREATE TYPE scheduled_posting_status AS ENUM ('ACTIVE', 'COMPLETED', 'CANCELLED');
+
+CREATE TABLE scheduled_posting (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    opr_type BYTEA NOT NULL,           -- 34-byte hash
+    pst_type posting_type NOT NULL,
+    lines TEXT NOT NULL,               -- JSON of the line templates
+    frequency frequency,
+    start_time TIMESTAMPTZ NOT NULL,
+    end_time TIMESTAMPTZ,
+    max_occurrences INT,
+    next_pst_time TIMESTAMPTZ NOT NULL,
+    occurrence INT NOT NULL,
+    status scheduled_posting_status NOT NULL,
+    created TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_scheduled_posting_due ON scheduled_posting(status, next_pst_time);
+
+COMMENT ON TABLE scheduled_posting IS 'Future-dated and recurring posting templates booked by the scheduler, e.g. accruals and deferrals';
+COMMENT ON COLUMN scheduled_posting.frequency IS 'Recurrence, a single posting at start_time when NULL';
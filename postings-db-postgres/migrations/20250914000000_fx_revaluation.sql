@@ -0,0 +1,24 @@
+-- =============================================================================
+-- FX REVALUATIONS
+-- =============================================================================
+
+CREATE TABLE fx_revaluation (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    currency CHAR(3) NOT NULL,
+    valuation_time TIMESTAMPTZ NOT NULL,
+    foreign_balance NUMERIC(19, 2) NOT NULL,
+    rate NUMERIC(19, 10) NOT NULL,
+    carrying_value NUMERIC(19, 2) NOT NULL,
+    revalued_value NUMERIC(19, 2) NOT NULL,
+    posting_id UUID REFERENCES posting(id),
+    created TIMESTAMPTZ NOT NULL,
+    UNIQUE (account_id, valuation_time)
+);
+
+CREATE INDEX idx_fx_revaluation_ledger ON fx_revaluation(ledger_id, valuation_time);
+
+COMMENT ON TABLE fx_revaluation IS 'Revaluations of foreign currency accounts into the functional currency at closing rates';
+COMMENT ON COLUMN fx_revaluation.carrying_value IS 'Functional currency value before the revaluation, as debit balance';
+COMMENT ON COLUMN fx_revaluation.posting_id IS 'Posting booking the gain or loss, NULL when there was none';
@@ -0,0 +1,22 @@
+-- =============================================================================
+-- LEDGER ACCOUNT NAMES
+-- =============================================================================
+
+CREATE TABLE ledger_account_name (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    number VARCHAR(64),
+    name VARCHAR(255) NOT NULL,
+    valid_from TIMESTAMPTZ NOT NULL,
+    valid_to TIMESTAMPTZ,
+    created TIMESTAMPTZ NOT NULL,
+    CHECK (valid_to IS NULL OR valid_to > valid_from)
+);
+
+CREATE INDEX idx_ledger_account_name_name ON ledger_account_name(ledger_id, name);
+CREATE INDEX idx_ledger_account_name_number ON ledger_account_name(ledger_id, number);
+CREATE INDEX idx_ledger_account_name_account ON ledger_account_name(account_id);
+
+COMMENT ON TABLE ledger_account_name IS 'Human readable names and numbers of ledger accounts with their validity periods';
+COMMENT ON COLUMN ledger_account_name.valid_to IS 'Exclusive end of the validity, NULL while the name is in use';
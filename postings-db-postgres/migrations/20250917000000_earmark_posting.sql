@@ -0,0 +1,5 @@
+-- =============================================================================
+-- EARMARK CAPTURE POSTING
+-- =============================================================================
+
+ALTER TABLE earmark ADD COLUMN posting_id UUID REFERENCES posting(id);
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::account_group_repository::AccountGroupRepository;
+use postings_db::models::account_group::{AccountGroup, AccountGroupMember};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresAccountGroupRepository {
+    pool: PgPool,
+}
+
+impl PostgresAccountGroupRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountGroupRepository for PostgresAccountGroupRepository {
+    async fn save(&self, group: AccountGroup) -> Result<AccountGroup, DbError> {
+        sqlx::query_as("INSERT INTO account_group (id, ledger_id, name, category, created) VALUES ($1, $2, $3, $4, $5) RETURNING *")
+            .bind(group.id)
+            .bind(group.ledger_id)
+            .bind(group.name)
+            .bind(group.category)
+            .bind(group.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::on_insert)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountGroup>, DbError> {
+        sqlx::query_as("SELECT * FROM account_group WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_name(&self, ledger_id: Uuid, name: &str) -> Result<Option<AccountGroup>, DbError> {
+        sqlx::query_as("SELECT * FROM account_group WHERE ledger_id = $1 AND name = $2")
+            .bind(ledger_id)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<AccountGroup>, DbError> {
+        sqlx::query_as("SELECT * FROM account_group WHERE ledger_id = $1 ORDER BY name")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn add_member(&self, member: AccountGroupMember) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO account_group_member (group_id, account_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+            .bind(member.group_id)
+            .bind(member.account_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<bool, DbError> {
+        let result = sqlx::query("DELETE FROM account_group_member WHERE group_id = $1 AND account_id = $2")
+            .bind(group_id)
+            .bind(account_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_member_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>, DbError> {
+        sqlx::query_scalar("SELECT account_id FROM account_group_member WHERE group_id = $1 ORDER BY account_id")
+            .bind(group_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::account_limit_repository::AccountLimitRepository;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresAccountLimitRepository {
+    pool: PgPool,
+}
+
+impl PostgresAccountLimitRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountLimitRepository for PostgresAccountLimitRepository {
+    async fn save(&self, limit: AccountLimit) -> Result<AccountLimit, DbError> {
+        sqlx::query_as(
+            "INSERT INTO account_limit (account_id, max_debit_balance, overdraft_limit, daily_turnover_limit, updated) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (account_id) DO UPDATE SET \
+                max_debit_balance = EXCLUDED.max_debit_balance, \
+                overdraft_limit = EXCLUDED.overdraft_limit, \
+                daily_turnover_limit = EXCLUDED.daily_turnover_limit, \
+                updated = EXCLUDED.updated \
+             RETURNING *"
+        )
+            .bind(limit.account_id)
+            .bind(limit.max_debit_balance)
+            .bind(limit.overdraft_limit)
+            .bind(limit.daily_turnover_limit)
+            .bind(limit.updated)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        sqlx::query_as("SELECT * FROM account_limit WHERE account_id = $1")
+            .bind(account_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
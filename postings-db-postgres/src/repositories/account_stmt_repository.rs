@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::models::account_stmt::AccountStmt;
 use postings_db::models::stmt_status::StmtStatus;
 use postings_db::DbError;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
@@ -39,37 +40,84 @@ impl AccountStmtRepository for PostgresAccountStmtRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM account_stmt WHERE account_id = $1 AND stmt_status = 'CLOSED' AND pst_time < $2 ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1")
+            .bind(account_id)
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            "DELETE FROM account_stmt s \
+             USING ledger_account a \
+             WHERE s.account_id = a.id AND a.ledger_id = $1 \
+               AND s.stmt_status = 'SIMULATED' AND s.expiry <= $2"
+        )
+            .bind(ledger_id)
+            .bind(as_of)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM account_stmt WHERE account_id = $1 AND stmt_status = 'CLOSED' AND pst_time >= $2 AND pst_time <= $3 ORDER BY pst_time, stmt_seq_nbr")
+            .bind(account_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
         sqlx::query_as(
-            "INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
-             ON CONFLICT (id) DO UPDATE SET \
-                account_id = EXCLUDED.account_id, \
-                youngest_pst_id = EXCLUDED.youngest_pst_id, \
-                total_debit = EXCLUDED.total_debit, \
-                total_credit = EXCLUDED.total_credit, \
-                posting_id = EXCLUDED.posting_id, \
-                pst_time = EXCLUDED.pst_time, \
-                stmt_status = EXCLUDED.stmt_status, \
-                latest_pst_id = EXCLUDED.latest_pst_id, \
-                stmt_seq_nbr = EXCLUDED.stmt_seq_nbr \
-             RETURNING *"
+            "SELECT s.* FROM account_stmt s \
+             JOIN ledger_account a ON s.account_id = a.id \
+             WHERE a.ledger_id = $1 AND s.stmt_status = 'CLOSED' AND ($2::uuid IS NULL OR s.id > $2) \
+             ORDER BY s.id LIMIT $3"
+        )
+            .bind(ledger_id)
+            .bind(after)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM account_stmt s \
+             JOIN ledger_account a ON s.account_id = a.id \
+             WHERE a.ledger_id = $1 AND s.stmt_status = 'CLOSED'"
         )
-            .bind(stmt.id)
-            .bind(stmt.account_id)
-            .bind(stmt.youngest_pst_id)
-            .bind(stmt.total_debit)
-            .bind(stmt.total_credit)
-            .bind(stmt.posting_id)
-            .bind(stmt.pst_time)
-            .bind(stmt.stmt_status)
-            .bind(stmt.latest_pst_id)
-            .bind(stmt.stmt_seq_nbr)
+            .bind(ledger_id)
             .fetch_one(&self.pool)
             .await
             .map_err(DbError::from)
     }
 
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        sqlx::query("UPDATE account_stmt SET opening_debit = $2, opening_credit = $3, line_count = $4 WHERE id = $1")
+            .bind(id)
+            .bind(opening_debit)
+            .bind(opening_credit)
+            .bind(line_count)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        upsert_account_stmt(&self.pool, stmt).await
+
+    }
+
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
         sqlx::query_as("SELECT * FROM account_stmt WHERE id = $1")
             .bind(id)
@@ -78,3 +126,49 @@ impl AccountStmtRepository for PostgresAccountStmtRepository {
             .map_err(DbError::from)
     }
 }
+
+/// Inserts the statement or replaces the statement stored under its id.
+pub(crate) async fn upsert_account_stmt<'e, E: PgExecutor<'e>>(executor: E, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+    sqlx::query_as(
+        "INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr, expiry, opening_debit, opening_credit, line_count, currency_totals, signature, signing_key_id) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17) \
+         ON CONFLICT (id) DO UPDATE SET \
+            account_id = EXCLUDED.account_id, \
+            youngest_pst_id = EXCLUDED.youngest_pst_id, \
+            total_debit = EXCLUDED.total_debit, \
+            total_credit = EXCLUDED.total_credit, \
+            posting_id = EXCLUDED.posting_id, \
+            pst_time = EXCLUDED.pst_time, \
+            stmt_status = EXCLUDED.stmt_status, \
+            latest_pst_id = EXCLUDED.latest_pst_id, \
+            stmt_seq_nbr = EXCLUDED.stmt_seq_nbr, \
+            expiry = EXCLUDED.expiry, \
+            opening_debit = EXCLUDED.opening_debit, \
+            opening_credit = EXCLUDED.opening_credit, \
+            line_count = EXCLUDED.line_count, \
+            currency_totals = EXCLUDED.currency_totals, \
+            signature = EXCLUDED.signature, \
+            signing_key_id = EXCLUDED.signing_key_id \
+         RETURNING *"
+    )
+        .bind(stmt.id)
+        .bind(stmt.account_id)
+        .bind(stmt.youngest_pst_id)
+        .bind(stmt.total_debit)
+        .bind(stmt.total_credit)
+        .bind(stmt.posting_id)
+        .bind(stmt.pst_time)
+        .bind(stmt.stmt_status)
+        .bind(stmt.latest_pst_id)
+        .bind(stmt.stmt_seq_nbr)
+        .bind(stmt.expiry)
+        .bind(stmt.opening_debit)
+        .bind(stmt.opening_credit)
+        .bind(stmt.line_count)
+        .bind(stmt.currency_totals)
+        .bind(stmt.signature)
+        .bind(stmt.signing_key_id)
+        .fetch_one(executor)
+        .await
+        .map_err(DbError::from)
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::api_key_repository::ApiKeyRepository;
+use postings_db::models::api_key::ApiKey;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn save(&self, key: ApiKey) -> Result<ApiKey, DbError> {
+        sqlx::query_as(
+            "INSERT INTO api_key (id, name, tenant_id, ledger_id, roles, key_prefix, key_hash, created, expires, revoked_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+             ON CONFLICT (id) DO UPDATE SET \
+                roles = EXCLUDED.roles, \
+                expires = EXCLUDED.expires, \
+                revoked_time = EXCLUDED.revoked_time \
+             RETURNING *"
+        )
+            .bind(key.id)
+            .bind(key.name)
+            .bind(key.tenant_id)
+            .bind(key.ledger_id)
+            .bind(key.roles)
+            .bind(key.key_prefix)
+            .bind(key.key_hash)
+            .bind(key.created)
+            .bind(key.expires)
+            .bind(key.revoked_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, DbError> {
+        sqlx::query_as("SELECT * FROM api_key WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_key_prefix(&self, key_prefix: &str) -> Result<Option<ApiKey>, DbError> {
+        sqlx::query_as("SELECT * FROM api_key WHERE key_prefix = $1")
+            .bind(key_prefix)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_tenant_id(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, DbError> {
+        sqlx::query_as("SELECT * FROM api_key WHERE tenant_id = $1 ORDER BY created")
+            .bind(tenant_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::backfill_job_repository::BackfillJobRepository;
+use postings_db::models::backfill_job::BackfillJob;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresBackfillJobRepository {
+    pool: PgPool,
+}
+
+impl PostgresBackfillJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BackfillJobRepository for PostgresBackfillJobRepository {
+    async fn save(&self, job: BackfillJob) -> Result<BackfillJob, DbError> {
+        sqlx::query_as(
+            "INSERT INTO backfill_job (id, task_name, ledger_id, status, batch_size, checkpoint, processed, total, error_message, started, updated, finished) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) \
+             ON CONFLICT (id) DO UPDATE SET \
+                status = EXCLUDED.status, \
+                checkpoint = EXCLUDED.checkpoint, \
+                processed = EXCLUDED.processed, \
+                error_message = EXCLUDED.error_message, \
+                updated = EXCLUDED.updated, \
+                finished = EXCLUDED.finished \
+             RETURNING *"
+        )
+            .bind(job.id)
+            .bind(job.task_name)
+            .bind(job.ledger_id)
+            .bind(job.status)
+            .bind(job.batch_size)
+            .bind(job.checkpoint)
+            .bind(job.processed)
+            .bind(job.total)
+            .bind(job.error_message)
+            .bind(job.started)
+            .bind(job.updated)
+            .bind(job.finished)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        sqlx::query_as("SELECT * FROM backfill_job WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_unfinished_by_task_and_ledger(&self, task_name: &str, ledger_id: Uuid) -> Result<Option<BackfillJob>, DbError> {
+        sqlx::query_as("SELECT * FROM backfill_job WHERE task_name = $1 AND ledger_id = $2 AND status <> 'COMPLETED' ORDER BY started DESC LIMIT 1")
+            .bind(task_name)
+            .bind(ledger_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresBalanceCheckpointRepository {
+    pool: PgPool,
+}
+
+impl PostgresBalanceCheckpointRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BalanceCheckpointRepository for PostgresBalanceCheckpointRepository {
+    async fn save(&self, checkpoint: &BalanceCheckpoint) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO balance_checkpoint (id, account_id, as_of, base_stmt_id, total_debit, total_credit, line_count, currency_totals, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+             ON CONFLICT (account_id, as_of) DO UPDATE SET \
+                id = EXCLUDED.id, \
+                base_stmt_id = EXCLUDED.base_stmt_id, \
+                total_debit = EXCLUDED.total_debit, \
+                total_credit = EXCLUDED.total_credit, \
+                line_count = EXCLUDED.line_count, \
+                currency_totals = EXCLUDED.currency_totals, \
+                created = EXCLUDED.created"
+        )
+            .bind(checkpoint.id)
+            .bind(checkpoint.account_id)
+            .bind(checkpoint.as_of)
+            .bind(checkpoint.base_stmt_id)
+            .bind(&checkpoint.total_debit)
+            .bind(&checkpoint.total_credit)
+            .bind(checkpoint.line_count)
+            .bind(&checkpoint.currency_totals)
+            .bind(checkpoint.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_last_by_account_and_as_of_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<BalanceCheckpoint>, DbError> {
+        sqlx::query_as("SELECT * FROM balance_checkpoint WHERE account_id = $1 AND as_of <= $2 ORDER BY as_of DESC LIMIT 1")
+            .bind(account_id)
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_by_account_and_as_of_greater_than_equal(&self, account_id: Uuid, from: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("DELETE FROM balance_checkpoint WHERE account_id = $1 AND as_of >= $2")
+            .bind(account_id)
+            .bind(from)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+}
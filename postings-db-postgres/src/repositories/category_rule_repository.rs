@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::models::category_rule::CategoryRule;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresCategoryRuleRepository {
+    pool: PgPool,
+}
+
+impl PostgresCategoryRuleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRuleRepository for PostgresCategoryRuleRepository {
+    async fn save(&self, rule: CategoryRule) -> Result<CategoryRule, DbError> {
+        sqlx::query_as(
+            "INSERT INTO category_rule (id, ledger_id, category, priority, account_id, details, src_account, opr_src, min_amount, max_amount, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+             ON CONFLICT (id) DO UPDATE SET \
+                category = EXCLUDED.category, \
+                priority = EXCLUDED.priority, \
+                account_id = EXCLUDED.account_id, \
+                details = EXCLUDED.details, \
+                src_account = EXCLUDED.src_account, \
+                opr_src = EXCLUDED.opr_src, \
+                min_amount = EXCLUDED.min_amount, \
+                max_amount = EXCLUDED.max_amount \
+             RETURNING *"
+        )
+            .bind(rule.id)
+            .bind(rule.ledger_id)
+            .bind(rule.category)
+            .bind(rule.priority)
+            .bind(rule.account_id)
+            .bind(rule.details)
+            .bind(rule.src_account)
+            .bind(rule.opr_src)
+            .bind(rule.min_amount)
+            .bind(rule.max_amount)
+            .bind(rule.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<CategoryRule>, DbError> {
+        sqlx::query_as("SELECT * FROM category_rule WHERE ledger_id = $1 ORDER BY priority, created")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query("DELETE FROM category_rule WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+}
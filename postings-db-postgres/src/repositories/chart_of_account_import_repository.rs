@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::chart_of_account_import_repository::ChartOfAccountImportRepository;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::DbError;
+
+pub struct PostgresChartOfAccountImportRepository {
+    pool: PgPool,
+}
+
+impl PostgresChartOfAccountImportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountImportRepository for PostgresChartOfAccountImportRepository {
+    async fn save_all(&self, coa: &ChartOfAccount, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query("INSERT INTO chart_of_account (id) VALUES ($1)")
+            .bind(coa.id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("INSERT INTO ledger (id, coa_id) VALUES ($1, $2)")
+            .bind(ledger.id)
+            .bind(ledger.coa_id)
+            .execute(&mut *tx)
+            .await?;
+        for account in accounts {
+            sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category, currency) VALUES ($1, $2, $3, $4, $5, $6, $7)")
+                .bind(account.id)
+                .bind(account.ledger_id)
+                .bind(account.parent_id)
+                .bind(account.coa_id)
+                .bind(&account.balance_side)
+                .bind(&account.category)
+                .bind(&account.currency)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for n in named {
+            sqlx::query("INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type) \
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)")
+                .bind(n.id)
+                .bind(n.container)
+                .bind(n.context)
+                .bind(&n.name)
+                .bind(&n.language)
+                .bind(n.created)
+                .bind(n.user_details)
+                .bind(&n.short_desc)
+                .bind(&n.long_desc)
+                .bind(&n.container_type)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(())
+    }
+}
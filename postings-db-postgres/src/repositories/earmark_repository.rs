@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use postings_db::repositories::earmark_repository::EarmarkRepository;
+use postings_db::models::earmark::{Earmark, EarmarkStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresEarmarkRepository {
+    pool: PgPool,
+}
+
+impl PostgresEarmarkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EarmarkRepository for PostgresEarmarkRepository {
+    async fn save(&self, earmark: Earmark) -> Result<Earmark, DbError> {
+        upsert_earmark(&self.pool, earmark).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Earmark>, DbError> {
+        sqlx::query_as("SELECT * FROM earmark WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_id_and_status(&self, account_id: Uuid, status: EarmarkStatus) -> Result<Vec<Earmark>, DbError> {
+        sqlx::query_as("SELECT * FROM earmark WHERE account_id = $1 AND status = $2 ORDER BY created")
+            .bind(account_id)
+            .bind(status)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_active_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, DbError> {
+        sqlx::query_as("SELECT * FROM earmark WHERE status = 'ACTIVE' AND expiry <= $1 ORDER BY expiry")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
+
+pub(crate) async fn upsert_earmark<'e, E: PgExecutor<'e>>(executor: E, earmark: Earmark) -> Result<Earmark, DbError> {
+    sqlx::query_as(
+        "INSERT INTO earmark (id, account_id, amount, captured_amount, reason, created, expiry, status, closed_time, posting_id) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+         ON CONFLICT (id) DO UPDATE SET \
+            amount = EXCLUDED.amount, \
+            captured_amount = EXCLUDED.captured_amount, \
+            expiry = EXCLUDED.expiry, \
+            status = EXCLUDED.status, \
+            closed_time = EXCLUDED.closed_time, \
+            posting_id = EXCLUDED.posting_id \
+         RETURNING *"
+    )
+        .bind(earmark.id)
+        .bind(earmark.account_id)
+        .bind(earmark.amount)
+        .bind(earmark.captured_amount)
+        .bind(earmark.reason)
+        .bind(earmark.created)
+        .bind(earmark.expiry)
+        .bind(earmark.status)
+        .bind(earmark.closed_time)
+        .bind(earmark.posting_id)
+        .fetch_one(executor)
+        .await
+        .map_err(DbError::from)
+}
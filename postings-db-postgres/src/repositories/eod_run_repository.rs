@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use postings_db::repositories::eod_run_repository::EodRunRepository;
+use postings_db::models::eod_run::{EodRun, EodStepResult};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresEodRunRepository {
+    pool: PgPool,
+}
+
+impl PostgresEodRunRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EodRunRepository for PostgresEodRunRepository {
+    async fn save_run(&self, run: EodRun) -> Result<EodRun, DbError> {
+        sqlx::query_as(
+            "INSERT INTO eod_run (id, ledger_id, business_date, status, started, finished) \
+             VALUES ($1, $2, $3, $4, $5, $6) \
+             ON CONFLICT (id) DO UPDATE SET \
+                status = EXCLUDED.status, \
+                finished = EXCLUDED.finished \
+             RETURNING *"
+        )
+            .bind(run.id)
+            .bind(run.ledger_id)
+            .bind(run.business_date)
+            .bind(run.status)
+            .bind(run.started)
+            .bind(run.finished)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_run_by_ledger_and_business_date(&self, ledger_id: Uuid, business_date: NaiveDate) -> Result<Option<EodRun>, DbError> {
+        sqlx::query_as("SELECT * FROM eod_run WHERE ledger_id = $1 AND business_date = $2")
+            .bind(ledger_id)
+            .bind(business_date)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_step_result(&self, result: EodStepResult) -> Result<EodStepResult, DbError> {
+        sqlx::query_as(
+            "INSERT INTO eod_step_result (run_id, step_seq, step_name, status, message, started, finished) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7) \
+             ON CONFLICT (run_id, step_seq) DO UPDATE SET \
+                step_name = EXCLUDED.step_name, \
+                status = EXCLUDED.status, \
+                message = EXCLUDED.message, \
+                started = EXCLUDED.started, \
+                finished = EXCLUDED.finished \
+             RETURNING *"
+        )
+            .bind(result.run_id)
+            .bind(result.step_seq)
+            .bind(result.step_name)
+            .bind(result.status)
+            .bind(result.message)
+            .bind(result.started)
+            .bind(result.finished)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_step_results_by_run_id(&self, run_id: Uuid) -> Result<Vec<EodStepResult>, DbError> {
+        sqlx::query_as("SELECT * FROM eod_step_result WHERE run_id = $1 ORDER BY step_seq")
+            .bind(run_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
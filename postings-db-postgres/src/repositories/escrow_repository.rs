@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::escrow_repository::EscrowRepository;
+use postings_db::models::escrow::{Escrow, EscrowStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresEscrowRepository {
+    pool: PgPool,
+}
+
+impl PostgresEscrowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EscrowRepository for PostgresEscrowRepository {
+    async fn save(&self, escrow: Escrow) -> Result<Escrow, DbError> {
+        sqlx::query_as(
+            "INSERT INTO escrow (id, ledger_id, source_account_id, escrow_account_id, target_account_id, amount, opr_type, \
+                status, timeout, timeout_action, initiation_posting_id, resolution_posting_id, created, resolved_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) \
+             ON CONFLICT (id) DO UPDATE SET \
+                status = EXCLUDED.status, \
+                initiation_posting_id = EXCLUDED.initiation_posting_id, \
+                resolution_posting_id = EXCLUDED.resolution_posting_id, \
+                resolved_time = EXCLUDED.resolved_time \
+             RETURNING *"
+        )
+            .bind(escrow.id)
+            .bind(escrow.ledger_id)
+            .bind(escrow.source_account_id)
+            .bind(escrow.escrow_account_id)
+            .bind(escrow.target_account_id)
+            .bind(escrow.amount)
+            .bind(escrow.opr_type)
+            .bind(escrow.status)
+            .bind(escrow.timeout)
+            .bind(escrow.timeout_action)
+            .bind(escrow.initiation_posting_id)
+            .bind(escrow.resolution_posting_id)
+            .bind(escrow.created)
+            .bind(escrow.resolved_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Escrow>, DbError> {
+        sqlx::query_as("SELECT * FROM escrow WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn resolve(&self, id: Uuid, status: EscrowStatus, resolved_time: DateTime<Utc>) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            "UPDATE escrow SET status = $2, resolved_time = $3 \
+             WHERE id = $1 AND status = 'PENDING'"
+        )
+            .bind(id)
+            .bind(status)
+            .bind(resolved_time)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_pending_timed_out(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, DbError> {
+        sqlx::query_as("SELECT * FROM escrow WHERE status = 'PENDING' AND timeout <= $1 ORDER BY timeout")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::external_content_repository::ExternalContentRepository;
+use postings_db::models::external_content::ExternalContent;
+use postings_db::DbError;
+
+pub struct PostgresExternalContentRepository {
+    pool: PgPool,
+}
+
+impl PostgresExternalContentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExternalContentRepository for PostgresExternalContentRepository {
+    async fn save(&self, content: ExternalContent) -> Result<ExternalContent, DbError> {
+        sqlx::query(
+            "INSERT INTO external_content (content_hash, object_key, size, media_type, created) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (content_hash) DO NOTHING"
+        )
+            .bind(content.content_hash)
+            .bind(&content.object_key)
+            .bind(content.size)
+            .bind(&content.media_type)
+            .bind(content.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        self.find_by_content_hash(&content.content_hash)
+            .await?
+            .ok_or(DbError::NotFound)
+    }
+
+    async fn find_by_content_hash(&self, content_hash: &[u8]) -> Result<Option<ExternalContent>, DbError> {
+        sqlx::query_as("SELECT * FROM external_content WHERE content_hash = $1")
+            .bind(content_hash)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
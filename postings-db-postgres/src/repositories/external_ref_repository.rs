@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::models::external_ref::{ExternalRef, ExternalRefEntity};
+use postings_db::repositories::external_ref_repository::ExternalRefRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresExternalRefRepository {
+    pool: PgPool,
+}
+
+impl PostgresExternalRefRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExternalRefRepository for PostgresExternalRefRepository {
+    async fn save(&self, external_ref: &ExternalRef) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO external_ref (id, entity_type, entity_id, ledger_id, ref_system, reference, unique_scope, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        )
+            .bind(external_ref.id)
+            .bind(external_ref.entity_type)
+            .bind(external_ref.entity_id)
+            .bind(external_ref.ledger_id)
+            .bind(&external_ref.ref_system)
+            .bind(&external_ref.reference)
+            .bind(&external_ref.unique_scope)
+            .bind(external_ref.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_entity(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, DbError> {
+        sqlx::query_as("SELECT * FROM external_ref WHERE entity_type = $1 AND entity_id = $2 ORDER BY ref_system")
+            .bind(entity_type)
+            .bind(entity_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_and_reference(&self, ledger_id: Uuid, entity_type: ExternalRefEntity, ref_system: &str, reference: &str) -> Result<Vec<ExternalRef>, DbError> {
+        sqlx::query_as("SELECT * FROM external_ref WHERE ledger_id = $1 AND entity_type = $2 AND ref_system = $3 AND reference = $4 ORDER BY created, id")
+            .bind(ledger_id)
+            .bind(entity_type)
+            .bind(ref_system)
+            .bind(reference)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use postings_db::models::fee_schedule::{FeeSchedule, FeeTier};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresFeeScheduleRepository {
+    pool: PgPool,
+}
+
+impl PostgresFeeScheduleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeeScheduleRepository for PostgresFeeScheduleRepository {
+    async fn save(&self, schedule: FeeSchedule) -> Result<FeeSchedule, DbError> {
+        sqlx::query_as(
+            "INSERT INTO fee_schedule (id, ledger_id, opr_type, fee_type, flat_amount, rate, min_fee, max_fee, fee_account_id, product_id, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+             ON CONFLICT (id) DO UPDATE SET \
+                fee_type = EXCLUDED.fee_type, \
+                flat_amount = EXCLUDED.flat_amount, \
+                rate = EXCLUDED.rate, \
+                min_fee = EXCLUDED.min_fee, \
+                max_fee = EXCLUDED.max_fee, \
+                fee_account_id = EXCLUDED.fee_account_id \
+             RETURNING *"
+        )
+            .bind(schedule.id)
+            .bind(schedule.ledger_id)
+            .bind(schedule.opr_type)
+            .bind(schedule.fee_type)
+            .bind(schedule.flat_amount)
+            .bind(schedule.rate)
+            .bind(schedule.min_fee)
+            .bind(schedule.max_fee)
+            .bind(schedule.fee_account_id)
+            .bind(schedule.product_id)
+            .bind(schedule.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_opr_type(&self, ledger_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM fee_schedule WHERE ledger_id = $1 AND opr_type = $2 AND product_id IS NULL")
+            .bind(ledger_id)
+            .bind(opr_type)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_product_id_and_opr_type(&self, product_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM fee_schedule WHERE product_id = $1 AND opr_type = $2")
+            .bind(product_id)
+            .bind(opr_type)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_tiers(&self, schedule_id: Uuid, tiers: Vec<FeeTier>) -> Result<Vec<FeeTier>, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query("DELETE FROM fee_tier WHERE schedule_id = $1")
+            .bind(schedule_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        for tier in tiers.iter() {
+            sqlx::query("INSERT INTO fee_tier (schedule_id, from_amount, flat_amount, rate) VALUES ($1, $2, $3, $4)")
+                .bind(schedule_id)
+                .bind(&tier.from_amount)
+                .bind(&tier.flat_amount)
+                .bind(&tier.rate)
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::from)?;
+        }
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(tiers)
+    }
+
+    async fn find_tiers_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<FeeTier>, DbError> {
+        sqlx::query_as("SELECT * FROM fee_tier WHERE schedule_id = $1 ORDER BY from_amount")
+            .bind(schedule_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::models::fx_revaluation::FxRevaluation;
+use postings_db::repositories::fx_revaluation_repository::FxRevaluationRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresFxRevaluationRepository {
+    pool: PgPool,
+}
+
+impl PostgresFxRevaluationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FxRevaluationRepository for PostgresFxRevaluationRepository {
+    async fn save(&self, revaluation: &FxRevaluation) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO fx_revaluation (id, ledger_id, account_id, currency, valuation_time, foreign_balance, rate, carrying_value, revalued_value, posting_id, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+        )
+            .bind(revaluation.id)
+            .bind(revaluation.ledger_id)
+            .bind(revaluation.account_id)
+            .bind(&revaluation.currency)
+            .bind(revaluation.valuation_time)
+            .bind(&revaluation.foreign_balance)
+            .bind(&revaluation.rate)
+            .bind(&revaluation.carrying_value)
+            .bind(&revaluation.revalued_value)
+            .bind(revaluation.posting_id)
+            .bind(revaluation.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<FxRevaluation>, DbError> {
+        sqlx::query_as("SELECT * FROM fx_revaluation WHERE account_id = $1 ORDER BY valuation_time DESC")
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
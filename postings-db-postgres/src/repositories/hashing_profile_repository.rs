@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use postings_db::models::hashing_profile::HashingProfile;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresHashingProfileRepository {
+    pool: PgPool,
+}
+
+impl PostgresHashingProfileRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HashingProfileRepository for PostgresHashingProfileRepository {
+    async fn save(&self, profile: HashingProfile) -> Result<HashingProfile, DbError> {
+        sqlx::query_as(
+            "INSERT INTO hashing_profile (ledger_id, excluded_fields, hash_algorithm, updated) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (ledger_id) DO UPDATE SET \
+                excluded_fields = EXCLUDED.excluded_fields, \
+                hash_algorithm = EXCLUDED.hash_algorithm, \
+                updated = EXCLUDED.updated \
+             RETURNING *"
+        )
+            .bind(profile.ledger_id)
+            .bind(profile.excluded_fields)
+            .bind(profile.hash_algorithm)
+            .bind(profile.updated)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<HashingProfile>, DbError> {
+        sqlx::query_as("SELECT * FROM hashing_profile WHERE ledger_id = $1")
+            .bind(ledger_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
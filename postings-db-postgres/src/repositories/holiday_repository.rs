@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db::models::holiday::Holiday;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresHolidayRepository {
+    pool: PgPool,
+}
+
+impl PostgresHolidayRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HolidayRepository for PostgresHolidayRepository {
+    async fn save(&self, holiday: Holiday) -> Result<Holiday, DbError> {
+        sqlx::query_as(
+            "INSERT INTO ledger_holiday (ledger_id, holiday_date, name) VALUES ($1, $2, $3) \
+             ON CONFLICT (ledger_id, holiday_date) DO UPDATE SET name = EXCLUDED.name \
+             RETURNING *"
+        )
+            .bind(holiday.ledger_id)
+            .bind(holiday.holiday_date)
+            .bind(holiday.name)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete(&self, ledger_id: Uuid, holiday_date: NaiveDate) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM ledger_holiday WHERE ledger_id = $1 AND holiday_date = $2")
+            .bind(ledger_id)
+            .bind(holiday_date)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Holiday>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_holiday WHERE ledger_id = $1 ORDER BY holiday_date")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_date_between(&self, ledger_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_holiday WHERE ledger_id = $1 AND holiday_date BETWEEN $2 AND $3 ORDER BY holiday_date")
+            .bind(ledger_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
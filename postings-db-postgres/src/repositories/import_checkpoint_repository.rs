@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::repositories::import_checkpoint_repository::ImportCheckpointRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresImportCheckpointRepository {
+    pool: PgPool,
+}
+
+impl PostgresImportCheckpointRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImportCheckpointRepository for PostgresImportCheckpointRepository {
+    async fn save(&self, checkpoint: &ImportCheckpoint) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO import_checkpoint (id, ledger_id, import_type, source_hash, next_line, loaded_count, quarantined_count, completed, updated) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+             ON CONFLICT (id) DO UPDATE SET \
+                next_line = EXCLUDED.next_line, \
+                loaded_count = EXCLUDED.loaded_count, \
+                quarantined_count = EXCLUDED.quarantined_count, \
+                completed = EXCLUDED.completed, \
+                updated = EXCLUDED.updated"
+        )
+            .bind(checkpoint.id)
+            .bind(checkpoint.ledger_id)
+            .bind(&checkpoint.import_type)
+            .bind(checkpoint.source_hash)
+            .bind(checkpoint.next_line)
+            .bind(checkpoint.loaded_count)
+            .bind(checkpoint.quarantined_count)
+            .bind(checkpoint.completed)
+            .bind(checkpoint.updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_source(&self, ledger_id: Uuid, import_type: &str, source_hash: &[u8; 34]) -> Result<Option<ImportCheckpoint>, DbError> {
+        sqlx::query_as("SELECT * FROM import_checkpoint WHERE ledger_id = $1 AND import_type = $2 AND source_hash = $3")
+            .bind(ledger_id)
+            .bind(import_type)
+            .bind(source_hash.as_slice())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::models::ledger_account_name::LedgerAccountName;
+use postings_db::repositories::ledger_account_name_repository::LedgerAccountNameRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresLedgerAccountNameRepository {
+    pool: PgPool,
+}
+
+impl PostgresLedgerAccountNameRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountNameRepository for PostgresLedgerAccountNameRepository {
+    async fn save(&self, name: &LedgerAccountName) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_account_name (id, ledger_id, account_id, number, name, valid_from, valid_to, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (id) DO UPDATE SET valid_to = EXCLUDED.valid_to"
+        )
+            .bind(name.id)
+            .bind(name.ledger_id)
+            .bind(name.account_id)
+            .bind(&name.number)
+            .bind(&name.name)
+            .bind(name.valid_from)
+            .bind(name.valid_to)
+            .bind(name.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccountName>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account_name WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_and_value(&self, ledger_id: Uuid, value: &str) -> Result<Vec<LedgerAccountName>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account_name WHERE ledger_id = $1 AND (name = $2 OR number = $2)")
+            .bind(ledger_id)
+            .bind(value)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<LedgerAccountName>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account_name WHERE account_id = $1 ORDER BY valid_from DESC")
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
 use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::page::{Page, PageRequest};
 use postings_db::DbError;
 
 pub struct PostgresLedgerAccountRepository {
@@ -27,15 +28,50 @@ impl LedgerAccountRepository for PostgresLedgerAccountRepository {
     }
 
     async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
+        sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category, currency) VALUES ($1, $2, $3, $4, $5, $6, $7)")
             .bind(ledger_account.id)
             .bind(ledger_account.ledger_id)
             .bind(ledger_account.parent_id)
             .bind(ledger_account.coa_id)
             .bind(&ledger_account.balance_side)
             .bind(&ledger_account.category)
+            .bind(&ledger_account.currency)
             .execute(&self.pool)
             .await?;
         Ok(())
     }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = $1 ORDER BY id")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ledger_account WHERE ledger_id = $1")
+            .bind(ledger_id)
+            .fetch_one(&self.pool)
+            .await?;
+        let content = sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = $1 ORDER BY id LIMIT $2 OFFSET $3")
+            .bind(ledger_id)
+            .bind(page.limit())
+            .bind(page.offset())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(Page::new(content, page, total as u64))
+    }
+
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("WITH RECURSIVE subtree AS (\
+                SELECT * FROM ledger_account WHERE parent_id = $1 \
+                UNION ALL \
+                SELECT a.* FROM ledger_account a JOIN subtree s ON a.parent_id = s.id\
+            ) SELECT * FROM subtree ORDER BY id")
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
 }
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::models::ledger_closure::LedgerClosure;
+use postings_db::repositories::ledger_closure_repository::LedgerClosureRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresLedgerClosureRepository {
+    pool: PgPool,
+}
+
+impl PostgresLedgerClosureRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerClosureRepository for PostgresLedgerClosureRepository {
+    async fn save(&self, closure: &LedgerClosure) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_closure (id, ledger_id, account_id, cut_off, allow_adjustments, closed_by, reason, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        )
+            .bind(closure.id)
+            .bind(closure.ledger_id)
+            .bind(closure.account_id)
+            .bind(closure.cut_off)
+            .bind(closure.allow_adjustments)
+            .bind(&closure.closed_by)
+            .bind(&closure.reason)
+            .bind(closure.created)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::on_insert)?;
+        Ok(())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerClosure>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_closure WHERE ledger_id = $1 ORDER BY cut_off DESC, created DESC")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
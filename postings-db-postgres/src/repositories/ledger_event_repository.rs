@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use postings_db::models::ledger_event::LedgerEvent;
+use postings_db::DbError;
+
+pub struct PostgresLedgerEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresLedgerEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerEventRepository for PostgresLedgerEventRepository {
+    async fn find_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_event WHERE seq > $1 ORDER BY seq LIMIT $2")
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_max_seq(&self) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT MAX(seq) FROM ledger_event")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_archived_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_event_archive WHERE seq > $1 ORDER BY seq LIMIT $2")
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn archive_up_to(&self, up_to_seq: i64, created_before: DateTime<Utc>) -> Result<u64, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query(
+            "INSERT INTO ledger_event_archive (seq, entity_type, entity_id, operation, payload, created) \
+             SELECT seq, entity_type, entity_id, operation, payload, created FROM ledger_event \
+             WHERE seq <= $1 AND created < $2 \
+             ON CONFLICT (seq) DO NOTHING"
+        )
+            .bind(up_to_seq)
+            .bind(created_before)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        let result = sqlx::query("DELETE FROM ledger_event WHERE seq <= $1 AND created < $2")
+            .bind(up_to_seq)
+            .bind(created_before)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn save_consumer_position(&self, consumer: &str, last_seq: i64, updated: DateTime<Utc>) -> Result<(), DbError> {
+        sqlx::query(
+            "INSERT INTO ledger_event_consumer (name, last_seq, updated) VALUES ($1, $2, $3) \
+             ON CONFLICT (name) DO UPDATE SET \
+                last_seq = GREATEST(ledger_event_consumer.last_seq, EXCLUDED.last_seq), \
+                updated = EXCLUDED.updated"
+        )
+            .bind(consumer)
+            .bind(last_seq)
+            .bind(updated)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn find_consumer_seq(&self, consumer: &str) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT last_seq FROM ledger_event_consumer WHERE name = $1")
+            .bind(consumer)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_min_consumer_seq(&self) -> Result<Option<i64>, DbError> {
+        sqlx::query_scalar("SELECT MIN(last_seq) FROM ledger_event_consumer")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
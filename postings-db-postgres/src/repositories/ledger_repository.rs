@@ -34,4 +34,40 @@ impl LedgerRepository for PostgresLedgerRepository {
             .await?;
         Ok(())
     }
+
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET read_only = $1 WHERE id = $2")
+            .bind(read_only)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET memo = $1 WHERE id = $2")
+            .bind(memo)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE ledger SET closing_summary = $1 WHERE id = $2")
+            .bind(closing_summary)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
 }
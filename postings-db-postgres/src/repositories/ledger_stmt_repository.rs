@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use postings_db::repositories::ledger_stmt_repository::LedgerStmtRepository;
+use postings_db::models::ledger_stmt::LedgerStmt;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresLedgerStmtRepository {
+    pool: PgPool,
+}
+
+impl PostgresLedgerStmtRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerStmtRepository for PostgresLedgerStmtRepository {
+    async fn find_last_closed_by_ledger_and_pst_time_less_than(&self, ledger_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<LedgerStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_stmt WHERE ledger_id = $1 AND stmt_status = 'CLOSED' AND pst_time < $2 ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1")
+            .bind(ledger_id)
+            .bind(ref_time)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save(&self, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+        upsert_ledger_stmt(&self.pool, stmt).await
+
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerStmt>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_stmt WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
+
+/// Inserts the statement or replaces the statement stored under its id.
+pub(crate) async fn upsert_ledger_stmt<'e, E: PgExecutor<'e>>(executor: E, stmt: LedgerStmt) -> Result<LedgerStmt, DbError> {
+    sqlx::query_as(
+        "INSERT INTO ledger_stmt (id, ledger_id, total_debit, total_credit, posting_id, pst_time, stmt_status, stmt_seq_nbr) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+         ON CONFLICT (id) DO UPDATE SET \
+            total_debit = EXCLUDED.total_debit, \
+            total_credit = EXCLUDED.total_credit, \
+            posting_id = EXCLUDED.posting_id, \
+            pst_time = EXCLUDED.pst_time, \
+            stmt_status = EXCLUDED.stmt_status, \
+            stmt_seq_nbr = EXCLUDED.stmt_seq_nbr \
+         RETURNING *"
+    )
+        .bind(stmt.id)
+        .bind(stmt.ledger_id)
+        .bind(stmt.total_debit)
+        .bind(stmt.total_credit)
+        .bind(stmt.posting_id)
+        .bind(stmt.pst_time)
+        .bind(stmt.stmt_status)
+        .bind(stmt.stmt_seq_nbr)
+        .fetch_one(executor)
+        .await
+        .map_err(DbError::from)
+}
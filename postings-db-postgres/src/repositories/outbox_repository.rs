@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use postings_db::models::outbox_entry::OutboxEntry;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn save(&self, entry: &OutboxEntry) -> Result<(), DbError> {
+        insert_outbox_entry(&self.pool, entry).await
+    }
+
+    async fn find_undelivered(&self, limit: i64) -> Result<Vec<OutboxEntry>, DbError> {
+        sqlx::query_as("SELECT * FROM outbox WHERE delivered IS NULL ORDER BY created, id LIMIT $1")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn mark_delivered(&self, id: Uuid, delivered: DateTime<Utc>) -> Result<(), DbError> {
+        sqlx::query("UPDATE outbox SET delivered = $2 WHERE id = $1")
+            .bind(id)
+            .bind(delivered)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn record_failure(&self, id: Uuid, error: &str) -> Result<(), DbError> {
+        sqlx::query("UPDATE outbox SET attempts = attempts + 1, last_error = $2 WHERE id = $1")
+            .bind(id)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(())
+    }
+
+    async fn delete_delivered_before(&self, before: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("DELETE FROM outbox WHERE delivered < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
+}
+
+pub(crate) async fn insert_outbox_entry<'e, E: PgExecutor<'e>>(executor: E, entry: &OutboxEntry) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO outbox (id, event_type, payload, created, attempts, last_error, delivered) VALUES ($1, $2, $3, $4, $5, $6, $7)")
+        .bind(entry.id)
+        .bind(&entry.event_type)
+        .bind(&entry.payload)
+        .bind(entry.created)
+        .bind(entry.attempts)
+        .bind(&entry.last_error)
+        .bind(entry.delivered)
+        .execute(executor)
+        .await
+        .map_err(DbError::on_insert)?;
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::page::{Page, PageRequest};
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, StreamExt};
 use uuid::Uuid;
 
 pub struct PostgresPostingLineRepository {
@@ -19,26 +22,7 @@ impl PostgresPostingLineRepository {
 #[async_trait]
 impl PostingLineRepository for PostgresPostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
-        sqlx::query_as("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16) RETURNING *")
-            .bind(posting_line.id)
-            .bind(posting_line.account_id)
-            .bind(posting_line.debit_amount)
-            .bind(posting_line.credit_amount)
-            .bind(posting_line.details)
-            .bind(posting_line.src_account)
-            .bind(posting_line.base_line)
-            .bind(posting_line.sub_opr_src_id)
-            .bind(posting_line.record_time)
-            .bind(posting_line.opr_id)
-            .bind(posting_line.opr_src)
-            .bind(posting_line.pst_time)
-            .bind(posting_line.pst_type)
-            .bind(posting_line.pst_status)
-            .bind(posting_line.hash)
-            .bind(posting_line.discarded_time)
-            .fetch_one(&self.pool)
-            .await
-            .map_err(DbError::from)
+        insert_posting_line(&self.pool, &posting_line).await
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
@@ -50,7 +34,7 @@ impl PostingLineRepository for PostgresPostingLineRepository {
     }
 
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
-        sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL ORDER BY pst_time DESC")
+        sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL ORDER BY pst_time DESC, record_time DESC, id DESC")
             .bind(account_id)
             .bind(from)
             .bind(to)
@@ -59,6 +43,24 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .map_err(DbError::from)
     }
 
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL")
+            .bind(account_id)
+            .bind(from)
+            .bind(to)
+            .fetch_one(&self.pool)
+            .await?;
+        let content = sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL ORDER BY pst_time DESC, record_time DESC, id DESC LIMIT $4 OFFSET $5")
+            .bind(account_id)
+            .bind(from)
+            .bind(to)
+            .bind(page.limit())
+            .bind(page.offset())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(Page::new(content, page, total as u64))
+    }
+
     async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
         sqlx::query_as("SELECT * FROM posting_line WHERE id = $1 AND account_id = $2")
             .bind(id)
@@ -77,6 +79,14 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .map_err(DbError::from)
     }
 
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_line WHERE opr_id = $1 ORDER BY record_time, id")
+            .bind(opr_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
     async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
         sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time <= $2 AND discarded_time IS NULL ORDER BY record_time DESC")
             .bind(account_id)
@@ -85,4 +95,101 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .await
             .map_err(DbError::from)
     }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time <= $2 AND record_time <= $3 ORDER BY record_time DESC")
+            .bind(account_id)
+            .bind(ref_time)
+            .bind(known_at)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        sqlx::query_as(
+            "SELECT COUNT(*) AS line_count, MIN(pst_time) AS first_pst_time, MAX(pst_time) AS last_pst_time, \
+                (SELECT opr_id FROM posting_line WHERE account_id = $1 AND discarded_time IS NULL \
+                 ORDER BY record_time DESC, id DESC LIMIT 1) AS last_opr_id \
+             FROM posting_line WHERE account_id = $1 AND discarded_time IS NULL"
+        )
+            .bind(account_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        sqlx::query_as(
+            "SELECT account_id, SUM(debit_amount) AS total_debit, SUM(credit_amount) AS total_credit \
+             FROM posting_line WHERE account_id = ANY($1) AND pst_time <= $2 AND discarded_time IS NULL \
+             GROUP BY account_id"
+        )
+            .bind(account_ids)
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let sql = match order {
+            LineOrder::PostingTime => "SELECT * FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL \
+                 ORDER BY pst_time, record_time, id",
+            LineOrder::ValueTime => "SELECT l.* FROM posting_line l \
+                 LEFT JOIN posting p ON p.opr_id = l.opr_id AND p.discarding_id IS NULL \
+                 WHERE l.account_id = $1 AND l.pst_time > $2 AND l.pst_time <= $3 AND l.discarded_time IS NULL \
+                 ORDER BY COALESCE(p.val_time, l.pst_time), l.pst_time, l.record_time, l.id",
+        };
+        sqlx::query_as::<_, PostingLine>(sql)
+            .bind(account_id)
+            .bind(from)
+            .bind(to)
+            .fetch(&self.pool)
+            .map(|line| line.map_err(DbError::from))
+            .boxed()
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let sql = match order {
+            LineOrder::PostingTime => "SELECT * FROM posting_line WHERE account_id = $1 AND pst_time <= $2 AND discarded_time IS NULL \
+                 ORDER BY pst_time, record_time, id",
+            LineOrder::ValueTime => "SELECT l.* FROM posting_line l \
+                 LEFT JOIN posting p ON p.opr_id = l.opr_id AND p.discarding_id IS NULL \
+                 WHERE l.account_id = $1 AND l.pst_time <= $2 AND l.discarded_time IS NULL \
+                 ORDER BY COALESCE(p.val_time, l.pst_time), l.pst_time, l.record_time, l.id",
+        };
+        sqlx::query_as::<_, PostingLine>(sql)
+            .bind(account_id)
+            .bind(ref_time)
+            .fetch(&self.pool)
+            .map(|line| line.map_err(DbError::from))
+            .boxed()
+    }
+}
+
+pub(crate) async fn insert_posting_line<'e, E: PgExecutor<'e>>(executor: E, posting_line: &PostingLine) -> Result<PostingLine, DbError> {
+    sqlx::query_as("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, currency, fx_base_currency, fx_rate) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19) RETURNING *")
+        .bind(posting_line.id)
+        .bind(posting_line.account_id)
+        .bind(&posting_line.debit_amount)
+        .bind(&posting_line.credit_amount)
+        .bind(posting_line.details)
+        .bind(posting_line.src_account)
+        .bind(posting_line.base_line)
+        .bind(posting_line.sub_opr_src_id)
+        .bind(posting_line.record_time)
+        .bind(posting_line.opr_id)
+        .bind(posting_line.opr_src)
+        .bind(posting_line.pst_time)
+        .bind(&posting_line.pst_type)
+        .bind(&posting_line.pst_status)
+        .bind(posting_line.hash)
+        .bind(posting_line.discarded_time)
+        .bind(&posting_line.currency)
+        .bind(&posting_line.fx_base_currency)
+        .bind(&posting_line.fx_rate)
+        .fetch_one(executor)
+        .await
+        .map_err(DbError::from)
 }
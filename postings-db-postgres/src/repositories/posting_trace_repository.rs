@@ -1,7 +1,8 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{FromRow, PgPool, Row};
 use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
 use postings_db::models::posting_trace::PostingTrace;
+use postings_db::upsert::Upserted;
 use postings_db::DbError;
 use uuid::Uuid;
 
@@ -17,8 +18,18 @@ impl PostgresPostingTraceRepository {
 
 #[async_trait]
 impl PostingTraceRepository for PostgresPostingTraceRepository {
-    async fn save(&self, trace: PostingTrace) -> Result<PostingTrace, DbError> {
-        sqlx::query_as("INSERT INTO posting_trace (id, tgt_pst_id, src_pst_time, src_pst_id, src_opr_id, account_id, debit_amount, credit_amount, src_pst_hash) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *")
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError> {
+        // xmax is only set on the row version written by the update branch
+        let row = sqlx::query(
+            "INSERT INTO posting_trace (id, tgt_pst_id, src_pst_time, src_pst_id, src_opr_id, account_id, debit_amount, credit_amount, src_pst_hash) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+             ON CONFLICT (tgt_pst_id, src_pst_id) DO UPDATE SET \
+                src_pst_time = EXCLUDED.src_pst_time, \
+                debit_amount = EXCLUDED.debit_amount, \
+                credit_amount = EXCLUDED.credit_amount, \
+                src_pst_hash = EXCLUDED.src_pst_hash \
+             RETURNING *, (xmax = 0) AS inserted"
+        )
             .bind(trace.id)
             .bind(trace.tgt_pst_id)
             .bind(trace.src_pst_time)
@@ -30,7 +41,9 @@ impl PostingTraceRepository for PostgresPostingTraceRepository {
             .bind(trace.src_pst_hash)
             .fetch_one(&self.pool)
             .await
-            .map_err(DbError::from)
+            .map_err(DbError::from)?;
+        let stored = PostingTrace::from_row(&row)?;
+        Ok(if row.try_get("inserted")? { Upserted::Inserted(stored) } else { Upserted::Existing(stored) })
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
@@ -40,4 +53,18 @@ impl PostingTraceRepository for PostgresPostingTraceRepository {
             .await
             .map_err(DbError::from)
     }
+
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            "DELETE FROM posting_trace t \
+             USING ledger_account a \
+             WHERE t.account_id = a.id AND a.ledger_id = $1 \
+               AND NOT EXISTS (SELECT 1 FROM account_stmt s WHERE s.id = t.tgt_pst_id)"
+        )
+            .bind(ledger_id)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected())
+    }
 }
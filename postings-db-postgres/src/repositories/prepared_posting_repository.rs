@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::prepared_posting_repository::PreparedPostingRepository;
+use postings_db::models::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresPreparedPostingRepository {
+    pool: PgPool,
+}
+
+impl PostgresPreparedPostingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PreparedPostingRepository for PostgresPreparedPostingRepository {
+    async fn save(&self, prepared: PreparedPosting) -> Result<PreparedPosting, DbError> {
+        sqlx::query_as(
+            "INSERT INTO prepared_posting (id, ledger_id, opr_id, payload, status, coordinator_ref, expires, created, resolved_time, posting_id) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+             ON CONFLICT (id) DO UPDATE SET \
+                status = EXCLUDED.status, \
+                resolved_time = EXCLUDED.resolved_time, \
+                posting_id = EXCLUDED.posting_id \
+             RETURNING *"
+        )
+            .bind(prepared.id)
+            .bind(prepared.ledger_id)
+            .bind(prepared.opr_id)
+            .bind(prepared.payload)
+            .bind(prepared.status)
+            .bind(prepared.coordinator_ref)
+            .bind(prepared.expires)
+            .bind(prepared.created)
+            .bind(prepared.resolved_time)
+            .bind(prepared.posting_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PreparedPosting>, DbError> {
+        sqlx::query_as("SELECT * FROM prepared_posting WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn resolve(&self, id: Uuid, status: PreparedPostingStatus, resolved_time: DateTime<Utc>, posting_id: Option<Uuid>) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            "UPDATE prepared_posting SET status = $2, resolved_time = $3, posting_id = $4 \
+             WHERE id = $1 AND status = 'PREPARED'"
+        )
+            .bind(id)
+            .bind(status)
+            .bind(resolved_time)
+            .bind(posting_id)
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<Vec<PreparedPosting>, DbError> {
+        sqlx::query_as(
+            "SELECT * FROM prepared_posting WHERE status = 'PREPARED' AND expires <= $1 ORDER BY expires LIMIT $2"
+        )
+            .bind(as_of)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::product_repository::ProductRepository;
+use postings_db::models::product::{AccountProduct, Product};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresProductRepository {
+    pool: PgPool,
+}
+
+impl PostgresProductRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProductRepository for PostgresProductRepository {
+    async fn save(&self, product: Product) -> Result<Product, DbError> {
+        sqlx::query_as(
+            "INSERT INTO product (id, ledger_id, name, interest_rate, day_count_convention, created) \
+             VALUES ($1, $2, $3, $4, $5, $6) \
+             ON CONFLICT (id) DO UPDATE SET \
+                name = EXCLUDED.name, \
+                interest_rate = EXCLUDED.interest_rate, \
+                day_count_convention = EXCLUDED.day_count_convention \
+             RETURNING *"
+        )
+            .bind(product.id)
+            .bind(product.ledger_id)
+            .bind(product.name)
+            .bind(product.interest_rate)
+            .bind(product.day_count_convention)
+            .bind(product.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, DbError> {
+        sqlx::query_as("SELECT * FROM product WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_account_product(&self, link: AccountProduct) -> Result<AccountProduct, DbError> {
+        sqlx::query_as(
+            "INSERT INTO account_product (account_id, product_id, linked) VALUES ($1, $2, $3) \
+             ON CONFLICT (account_id) DO UPDATE SET \
+                product_id = EXCLUDED.product_id, \
+                linked = EXCLUDED.linked \
+             RETURNING *"
+        )
+            .bind(link.account_id)
+            .bind(link.product_id)
+            .bind(link.linked)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<Product>, DbError> {
+        sqlx::query_as(
+            "SELECT p.* FROM product p \
+             JOIN account_product ap ON ap.product_id = p.id \
+             WHERE ap.account_id = $1"
+        )
+            .bind(account_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
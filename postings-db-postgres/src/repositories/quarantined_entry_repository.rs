@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::quarantined_entry_repository::QuarantinedEntryRepository;
+use postings_db::models::quarantined_entry::{QuarantineStatus, QuarantinedEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresQuarantinedEntryRepository {
+    pool: PgPool,
+}
+
+impl PostgresQuarantinedEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuarantinedEntryRepository for PostgresQuarantinedEntryRepository {
+    async fn save(&self, entry: QuarantinedEntry) -> Result<QuarantinedEntry, DbError> {
+        sqlx::query_as(
+            "INSERT INTO quarantined_entry (id, ledger_id, import_type, line_number, payload, reason, status, created, resolved_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+             ON CONFLICT (id) DO UPDATE SET \
+                payload = EXCLUDED.payload, \
+                reason = EXCLUDED.reason, \
+                status = EXCLUDED.status, \
+                resolved_time = EXCLUDED.resolved_time \
+             RETURNING *"
+        )
+            .bind(entry.id)
+            .bind(entry.ledger_id)
+            .bind(entry.import_type)
+            .bind(entry.line_number)
+            .bind(entry.payload)
+            .bind(entry.reason)
+            .bind(entry.status)
+            .bind(entry.created)
+            .bind(entry.resolved_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuarantinedEntry>, DbError> {
+        sqlx::query_as("SELECT * FROM quarantined_entry WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_status(&self, ledger_id: Uuid, status: QuarantineStatus) -> Result<Vec<QuarantinedEntry>, DbError> {
+        sqlx::query_as("SELECT * FROM quarantined_entry WHERE ledger_id = $1 AND status = $2 ORDER BY created, line_number")
+            .bind(ledger_id)
+            .bind(status)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::read_snapshot::AccountSnapshot;
+use postings_db::repositories::read_snapshot_repository::ReadSnapshotRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresReadSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PostgresReadSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReadSnapshotRepository for PostgresReadSnapshotRepository {
+    async fn read_account(&self, account_id: Uuid, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountSnapshot, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        // Must precede the first query: the snapshot is taken by it
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        let last_closed_stmt: Option<AccountStmt> = sqlx::query_as(
+            "SELECT * FROM account_stmt WHERE account_id = $1 AND stmt_status = 'CLOSED' AND pst_time < $2 ORDER BY pst_time DESC, stmt_seq_nbr DESC LIMIT 1"
+        )
+            .bind(account_id)
+            .bind(ref_time)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        // Without closed statement, the balance needs all lines
+        let from = last_closed_stmt.as_ref().map(|stmt| stmt.pst_time.min(activity_from));
+        let lines = sqlx::query_as(
+            "SELECT * FROM posting_line WHERE account_id = $1 AND ($2::timestamptz IS NULL OR pst_time > $2) AND pst_time <= $3 AND discarded_time IS NULL \
+             ORDER BY pst_time DESC, record_time DESC, id DESC"
+        )
+            .bind(account_id)
+            .bind(from)
+            .bind(ref_time)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(AccountSnapshot { last_closed_stmt, lines })
+    }
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::report_schedule_repository::ReportScheduleRepository;
+use postings_db::models::report_schedule::{ReportArtifact, ReportSchedule, ReportType};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresReportScheduleRepository {
+    pool: PgPool,
+}
+
+impl PostgresReportScheduleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReportScheduleRepository for PostgresReportScheduleRepository {
+    async fn save(&self, schedule: ReportSchedule) -> Result<ReportSchedule, DbError> {
+        sqlx::query_as(
+            "INSERT INTO report_schedule (id, ledger_id, report_type, format, frequency, next_ref_time, status, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (id) DO UPDATE SET \
+                next_ref_time = EXCLUDED.next_ref_time, \
+                status = EXCLUDED.status \
+             RETURNING *"
+        )
+            .bind(schedule.id)
+            .bind(schedule.ledger_id)
+            .bind(schedule.report_type)
+            .bind(schedule.format)
+            .bind(schedule.frequency)
+            .bind(schedule.next_ref_time)
+            .bind(schedule.status)
+            .bind(schedule.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReportSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM report_schedule WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM report_schedule WHERE status = 'ACTIVE' AND next_ref_time <= $1 ORDER BY next_ref_time, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_artifact(&self, artifact: ReportArtifact) -> Result<ReportArtifact, DbError> {
+        sqlx::query_as(
+            "INSERT INTO report_artifact (id, schedule_id, ledger_id, report_type, format, ref_time, version, object_key, checksum, size, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *"
+        )
+            .bind(artifact.id)
+            .bind(artifact.schedule_id)
+            .bind(artifact.ledger_id)
+            .bind(artifact.report_type)
+            .bind(artifact.format)
+            .bind(artifact.ref_time)
+            .bind(artifact.version)
+            .bind(artifact.object_key)
+            .bind(artifact.checksum)
+            .bind(artifact.size)
+            .bind(artifact.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::on_insert)
+    }
+
+    async fn find_artifact_by_id(&self, id: Uuid) -> Result<Option<ReportArtifact>, DbError> {
+        sqlx::query_as("SELECT * FROM report_artifact WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, DbError> {
+        sqlx::query_as("SELECT * FROM report_artifact WHERE ledger_id = $1 AND report_type = $2 AND ref_time = $3 ORDER BY format, version")
+            .bind(ledger_id)
+            .bind(report_type)
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
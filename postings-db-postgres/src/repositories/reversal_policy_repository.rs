@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::reversal_policy_repository::ReversalPolicyRepository;
+use postings_db::models::reversal_policy::ReversalPolicy;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresReversalPolicyRepository {
+    pool: PgPool,
+}
+
+impl PostgresReversalPolicyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReversalPolicyRepository for PostgresReversalPolicyRepository {
+    async fn save(&self, policy: ReversalPolicy) -> Result<ReversalPolicy, DbError> {
+        sqlx::query_as(
+            "INSERT INTO reversal_policy (ledger_id, teller_window_days, supervisor_window_days, updated) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (ledger_id) DO UPDATE SET \
+                teller_window_days = EXCLUDED.teller_window_days, \
+                supervisor_window_days = EXCLUDED.supervisor_window_days, \
+                updated = EXCLUDED.updated \
+             RETURNING *"
+        )
+            .bind(policy.ledger_id)
+            .bind(policy.teller_window_days)
+            .bind(policy.supervisor_window_days)
+            .bind(policy.updated)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ReversalPolicy>, DbError> {
+        sqlx::query_as("SELECT * FROM reversal_policy WHERE ledger_id = $1")
+            .bind(ledger_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
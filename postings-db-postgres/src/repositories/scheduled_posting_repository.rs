@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::scheduled_posting_repository::ScheduledPostingRepository;
+use postings_db::models::scheduled_posting::ScheduledPosting;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresScheduledPostingRepository {
+    pool: PgPool,
+}
+
+impl PostgresScheduledPostingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduledPostingRepository for PostgresScheduledPostingRepository {
+    async fn save(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, DbError> {
+        sqlx::query_as(
+            "INSERT INTO scheduled_posting (id, ledger_id, opr_type, pst_type, lines, frequency, start_time, end_time, \
+                max_occurrences, next_pst_time, occurrence, status, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+             ON CONFLICT (id) DO UPDATE SET \
+                next_pst_time = EXCLUDED.next_pst_time, \
+                occurrence = EXCLUDED.occurrence, \
+                status = EXCLUDED.status \
+             RETURNING *"
+        )
+            .bind(schedule.id)
+            .bind(schedule.ledger_id)
+            .bind(schedule.opr_type)
+            .bind(schedule.pst_type)
+            .bind(schedule.lines)
+            .bind(schedule.frequency)
+            .bind(schedule.start_time)
+            .bind(schedule.end_time)
+            .bind(schedule.max_occurrences)
+            .bind(schedule.next_pst_time)
+            .bind(schedule.occurrence)
+            .bind(schedule.status)
+            .bind(schedule.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPosting>, DbError> {
+        sqlx::query_as("SELECT * FROM scheduled_posting WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ScheduledPosting>, DbError> {
+        sqlx::query_as("SELECT * FROM scheduled_posting WHERE status = 'ACTIVE' AND next_pst_time <= $1 ORDER BY next_pst_time, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
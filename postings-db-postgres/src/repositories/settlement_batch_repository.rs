@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::settlement_batch_repository::SettlementBatchRepository;
+use postings_db::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresSettlementBatchRepository {
+    pool: PgPool,
+}
+
+impl PostgresSettlementBatchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SettlementBatchRepository for PostgresSettlementBatchRepository {
+    async fn save(&self, batch: SettlementBatch) -> Result<SettlementBatch, DbError> {
+        sqlx::query_as(
+            "INSERT INTO settlement_batch (id, ledger_id, batch_ref, control_amount, control_count, status, created, closed_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (id) DO UPDATE SET \
+                control_amount = EXCLUDED.control_amount, \
+                control_count = EXCLUDED.control_count, \
+                status = EXCLUDED.status, \
+                closed_time = EXCLUDED.closed_time \
+             RETURNING *"
+        )
+            .bind(batch.id)
+            .bind(batch.ledger_id)
+            .bind(batch.batch_ref)
+            .bind(batch.control_amount)
+            .bind(batch.control_count)
+            .bind(batch.status)
+            .bind(batch.created)
+            .bind(batch.closed_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<SettlementBatch>, DbError> {
+        sqlx::query_as("SELECT * FROM settlement_batch WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_entry(&self, entry: SettlementBatchEntry) -> Result<SettlementBatchEntry, DbError> {
+        sqlx::query_as("INSERT INTO settlement_batch_entry (batch_id, posting_id, amount, assigned_time) VALUES ($1, $2, $3, $4) RETURNING *")
+            .bind(entry.batch_id)
+            .bind(entry.posting_id)
+            .bind(entry.amount)
+            .bind(entry.assigned_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::on_insert)
+    }
+
+    async fn find_entries_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<SettlementBatchEntry>, DbError> {
+        sqlx::query_as("SELECT * FROM settlement_batch_entry WHERE batch_id = $1 ORDER BY assigned_time")
+            .bind(batch_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_entry_by_posting_id(&self, posting_id: Uuid) -> Result<Option<SettlementBatchEntry>, DbError> {
+        sqlx::query_as("SELECT * FROM settlement_batch_entry WHERE posting_id = $1")
+            .bind(posting_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
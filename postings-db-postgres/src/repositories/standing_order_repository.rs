@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use postings_db::repositories::standing_order_repository::StandingOrderRepository;
+use postings_db::models::standing_order::{StandingOrder, StandingOrderExecution};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStandingOrderRepository {
+    pool: PgPool,
+}
+
+impl PostgresStandingOrderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StandingOrderRepository for PostgresStandingOrderRepository {
+    async fn save(&self, order: StandingOrder) -> Result<StandingOrder, DbError> {
+        sqlx::query_as(
+            "INSERT INTO standing_order (id, ledger_id, source_account_id, target_account_id, amount, opr_type, frequency, \
+                start_date, end_date, max_executions, max_retries, next_execution_date, occurrence, execution_count, status, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16) \
+             ON CONFLICT (id) DO UPDATE SET \
+                end_date = EXCLUDED.end_date, \
+                max_executions = EXCLUDED.max_executions, \
+                next_execution_date = EXCLUDED.next_execution_date, \
+                occurrence = EXCLUDED.occurrence, \
+                execution_count = EXCLUDED.execution_count, \
+                status = EXCLUDED.status \
+             RETURNING *"
+        )
+            .bind(order.id)
+            .bind(order.ledger_id)
+            .bind(order.source_account_id)
+            .bind(order.target_account_id)
+            .bind(order.amount)
+            .bind(order.opr_type)
+            .bind(order.frequency)
+            .bind(order.start_date)
+            .bind(order.end_date)
+            .bind(order.max_executions)
+            .bind(order.max_retries)
+            .bind(order.next_execution_date)
+            .bind(order.occurrence)
+            .bind(order.execution_count)
+            .bind(order.status)
+            .bind(order.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StandingOrder>, DbError> {
+        sqlx::query_as("SELECT * FROM standing_order WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrder>, DbError> {
+        sqlx::query_as("SELECT * FROM standing_order WHERE status = 'ACTIVE' AND next_execution_date <= $1 ORDER BY next_execution_date, id")
+            .bind(as_of)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid) -> Result<Vec<StandingOrder>, DbError> {
+        sqlx::query_as("SELECT * FROM standing_order WHERE status = 'ACTIVE' AND (source_account_id = $1 OR target_account_id = $1) ORDER BY next_execution_date, id")
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_execution(&self, execution: StandingOrderExecution) -> Result<StandingOrderExecution, DbError> {
+        sqlx::query_as(
+            "INSERT INTO standing_order_execution (id, order_id, execution_date, attempt, status, posting_id, message, executed_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *"
+        )
+            .bind(execution.id)
+            .bind(execution.order_id)
+            .bind(execution.execution_date)
+            .bind(execution.attempt)
+            .bind(execution.status)
+            .bind(execution.posting_id)
+            .bind(execution.message)
+            .bind(execution.executed_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_executions_by_order_id(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, DbError> {
+        sqlx::query_as("SELECT * FROM standing_order_execution WHERE order_id = $1 ORDER BY execution_date, attempt")
+            .bind(order_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
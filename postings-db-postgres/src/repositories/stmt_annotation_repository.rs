@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::stmt_annotation_repository::StmtAnnotationRepository;
+use postings_db::models::stmt_annotation::StmtAnnotation;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStmtAnnotationRepository {
+    pool: PgPool,
+}
+
+impl PostgresStmtAnnotationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtAnnotationRepository for PostgresStmtAnnotationRepository {
+    async fn save(&self, annotation: StmtAnnotation) -> Result<StmtAnnotation, DbError> {
+        sqlx::query_as("INSERT INTO stmt_annotation (id, stmt_id, author, text, created) VALUES ($1, $2, $3, $4, $5) RETURNING *")
+            .bind(annotation.id)
+            .bind(annotation.stmt_id)
+            .bind(annotation.author)
+            .bind(annotation.text)
+            .bind(annotation.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::on_insert)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_annotation WHERE stmt_id = $1 ORDER BY created, id")
+            .bind(stmt_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
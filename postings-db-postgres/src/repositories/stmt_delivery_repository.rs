@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::stmt_delivery_repository::StmtDeliveryRepository;
+use postings_db::models::stmt_delivery::StmtDelivery;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStmtDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresStmtDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtDeliveryRepository for PostgresStmtDeliveryRepository {
+    async fn save(&self, delivery: StmtDelivery) -> Result<StmtDelivery, DbError> {
+        sqlx::query_as(
+            "INSERT INTO stmt_delivery (id, stmt_id, format, checksum, signature, key_id, created) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7) \
+             RETURNING *"
+        )
+            .bind(delivery.id)
+            .bind(delivery.stmt_id)
+            .bind(delivery.format)
+            .bind(delivery.checksum)
+            .bind(delivery.signature)
+            .bind(delivery.key_id)
+            .bind(delivery.created)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_delivery WHERE stmt_id = $1 ORDER BY created")
+            .bind(stmt_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_stmt_id_and_checksum(&self, stmt_id: Uuid, checksum: &[u8]) -> Result<Option<StmtDelivery>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_delivery WHERE stmt_id = $1 AND checksum = $2 ORDER BY created DESC LIMIT 1")
+            .bind(stmt_id)
+            .bind(checksum)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::stmt_job_repository::StmtJobRepository;
+use postings_db::models::stmt_job::{StmtJob, StmtJobStatus};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStmtJobRepository {
+    pool: PgPool,
+}
+
+impl PostgresStmtJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtJobRepository for PostgresStmtJobRepository {
+    async fn save(&self, job: StmtJob) -> Result<StmtJob, DbError> {
+        sqlx::query_as(
+            "INSERT INTO stmt_job (id, account_id, ref_time, status, stmt_id, error_message, created, completed_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (id) DO UPDATE SET \
+                status = EXCLUDED.status, \
+                stmt_id = EXCLUDED.stmt_id, \
+                error_message = EXCLUDED.error_message, \
+                completed_time = EXCLUDED.completed_time \
+             RETURNING *"
+        )
+            .bind(job.id)
+            .bind(job.account_id)
+            .bind(job.ref_time)
+            .bind(job.status)
+            .bind(job.stmt_id)
+            .bind(job.error_message)
+            .bind(job.created)
+            .bind(job.completed_time)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StmtJob>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_job WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_status(&self, status: StmtJobStatus, limit: i64) -> Result<Vec<StmtJob>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_job WHERE status = $1 ORDER BY created LIMIT $2")
+            .bind(status)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::stmt_metric_repository::StmtMetricRepository;
+use postings_db::models::stmt_metric::{AccountStmtStats, StmtMetric};
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStmtMetricRepository {
+    pool: PgPool,
+}
+
+impl PostgresStmtMetricRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const ACCOUNT_STATS_SELECT: &str = "SELECT account_id, COUNT(*) AS generations, \
+        SUM(duration_ms)::BIGINT AS total_duration_ms, MAX(duration_ms) AS max_duration_ms, MAX(line_count) AS max_lines \
+     FROM stmt_metric \
+     WHERE ledger_id = $1 AND recorded >= $2 AND recorded < $3 \
+     GROUP BY account_id";
+
+#[async_trait]
+impl StmtMetricRepository for PostgresStmtMetricRepository {
+    async fn save(&self, metric: StmtMetric) -> Result<StmtMetric, DbError> {
+        sqlx::query_as(
+            "INSERT INTO stmt_metric (id, ledger_id, account_id, recorded, duration_ms, line_count) \
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING *"
+        )
+            .bind(metric.id)
+            .bind(metric.ledger_id)
+            .bind(metric.account_id)
+            .bind(metric.recorded)
+            .bind(metric.duration_ms)
+            .bind(metric.line_count)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_slowest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        sqlx::query_as(&format!("{ACCOUNT_STATS_SELECT} ORDER BY max_duration_ms DESC, account_id LIMIT $4"))
+            .bind(ledger_id)
+            .bind(from)
+            .bind(to)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_hottest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError> {
+        sqlx::query_as(&format!("{ACCOUNT_STATS_SELECT} ORDER BY max_lines DESC, account_id LIMIT $4"))
+            .bind(ledger_id)
+            .bind(from)
+            .bind(to)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
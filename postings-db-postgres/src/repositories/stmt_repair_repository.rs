@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::stmt_repair_repository::StmtRepairRepository;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::stmt_repair::StmtRepair;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresStmtRepairRepository {
+    pool: PgPool,
+}
+
+impl PostgresStmtRepairRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StmtRepairRepository for PostgresStmtRepairRepository {
+    async fn save(&self, repair: StmtRepair, stmt: &AccountStmt) -> Result<StmtRepair, DbError> {
+        let mut tx = self.pool.begin().await.map_err(DbError::from)?;
+        sqlx::query(
+            "UPDATE account_stmt SET \
+                opening_debit = $2, \
+                opening_credit = $3, \
+                total_debit = $4, \
+                total_credit = $5, \
+                line_count = $6, \
+                currency_totals = $7 \
+             WHERE id = $1"
+        )
+            .bind(stmt.id)
+            .bind(&stmt.opening_debit)
+            .bind(&stmt.opening_credit)
+            .bind(&stmt.total_debit)
+            .bind(&stmt.total_credit)
+            .bind(stmt.line_count)
+            .bind(&stmt.currency_totals)
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        let repair = sqlx::query_as(
+            "INSERT INTO stmt_repair (id, stmt_id, account_id, pst_time, recorded, expected, principal, reason, repaired_time) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+             RETURNING *"
+        )
+            .bind(repair.id)
+            .bind(repair.stmt_id)
+            .bind(repair.account_id)
+            .bind(repair.pst_time)
+            .bind(repair.recorded)
+            .bind(repair.expected)
+            .bind(repair.principal)
+            .bind(repair.reason)
+            .bind(repair.repaired_time)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(DbError::from)?;
+        tx.commit().await.map_err(DbError::from)?;
+        Ok(repair)
+    }
+
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<StmtRepair>, DbError> {
+        sqlx::query_as("SELECT * FROM stmt_repair WHERE account_id = $1 ORDER BY repaired_time DESC")
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
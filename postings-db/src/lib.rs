@@ -1,5 +1,10 @@
 pub mod repositories;
 pub mod models;
+pub mod page;
+pub mod read_snapshot;
+pub mod sharding;
+pub mod unit_of_work;
+pub mod upsert;
 
 #[derive(thiserror::Error, Debug)]
 pub enum DbError {
@@ -9,6 +14,25 @@ pub enum DbError {
     Query,
     #[error("Not found")]
     NotFound,
+    #[error("Unique constraint violated")]
+    UniqueViolation,
+    /// Written through a scoped repository to a ledger outside its scope.
+    #[error("Outside of the repository scope")]
+    OutOfScope,
+    /// Unit of work that cannot be committed in a single shard.
+    #[error("Writes span several shards")]
+    CrossShard,
     #[error(transparent)]
     Sqlx(#[from] sqlx::Error),
 }
+
+impl DbError {
+    /// Same as the `From` conversion, but reports unique constraint violations as
+    /// [`DbError::UniqueViolation`] so callers can tell a conflicting insert from a failure.
+    pub fn on_insert(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => DbError::UniqueViolation,
+            _ => DbError::from(e),
+        }
+    }
+}
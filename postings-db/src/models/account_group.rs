@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::account_category::AccountCategory;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountGroup {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub category: Option<AccountCategory>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountGroupMember {
+    pub group_id: Uuid,
+    pub account_id: Uuid,
+}
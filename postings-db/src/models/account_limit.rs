@@ -0,0 +1,13 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLimit {
+    pub account_id: Uuid,
+    pub max_debit_balance: Option<BigDecimal>,
+    pub overdraft_limit: Option<BigDecimal>,
+    pub daily_turnover_limit: Option<BigDecimal>,
+    pub updated: DateTime<Utc>,
+}
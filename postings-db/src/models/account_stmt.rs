@@ -15,4 +15,19 @@ pub struct AccountStmt {
     pub stmt_status: StmtStatus,
     pub latest_pst_id: Option<Uuid>,
     pub stmt_seq_nbr: i32,
+    /// Set on persisted simulated statements; they are purged once expired. Closed statements never expire.
+    pub expiry: Option<chrono::DateTime<chrono::Utc>>,
+    /// Totals carried over from the previous closed statement.
+    pub opening_debit: BigDecimal,
+    pub opening_credit: BigDecimal,
+    /// Number of posting lines added since the previous closed statement.
+    pub line_count: i64,
+    /// `total_debit - total_credit`, computed by the database. Ignored on save.
+    pub closing_balance: BigDecimal,
+    /// Totals per line currency as a JSON array of `{currency, total_debit, total_credit}`.
+    pub currency_totals: Option<String>,
+    /// Ed25519 signature over the canonical form of the closed statement, with the id of the
+    /// signing key. Set on close when a signer is configured.
+    pub signature: Option<Vec<u8>>,
+    pub signing_key_id: Option<String>,
 }
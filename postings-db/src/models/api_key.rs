@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub name: String,
+    pub tenant_id: Uuid,
+    pub ledger_id: Option<Uuid>,
+    /// Comma separated role names.
+    pub roles: String,
+    pub key_prefix: String,
+    /// Hash of the secret. It is a 32-byte hash.
+    pub key_hash: [u8; 34],
+    pub created: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+    pub revoked_time: Option<DateTime<Utc>>,
+}
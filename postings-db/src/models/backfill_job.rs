@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct BackfillJob {
+    pub id: Uuid,
+    pub task_name: String,
+    pub ledger_id: Uuid,
+    pub status: BackfillStatus,
+    pub batch_size: i32,
+    pub checkpoint: Option<String>,
+    pub processed: i64,
+    pub total: i64,
+    pub error_message: Option<String>,
+    pub started: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "backfill_status", rename_all = "UPPERCASE")]
+pub enum BackfillStatus {
+    Running,
+    Completed,
+    Failed,
+}
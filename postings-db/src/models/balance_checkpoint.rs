@@ -0,0 +1,23 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Progress of an account's statement at posting time `as_of`, so statement generation only
+/// reads the lines posted after it.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct BalanceCheckpoint {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub as_of: DateTime<Utc>,
+    /// Last closed statement before `as_of` the totals continue from; the checkpoint is stale
+    /// once another statement is closed in between.
+    pub base_stmt_id: Option<Uuid>,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    /// Lines applied since the base statement.
+    pub line_count: i64,
+    /// JSON of the per-currency totals, like the statement's.
+    pub currency_totals: Option<String>,
+    pub created: DateTime<Utc>,
+}
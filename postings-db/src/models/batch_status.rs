@@ -0,0 +1,10 @@
+use sqlx::Type;
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "batch_status", rename_all = "UPPERCASE")]
+pub enum BatchStatus {
+    Open,
+    Closed,
+    Settled,
+    Rejected,
+}
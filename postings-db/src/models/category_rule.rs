@@ -0,0 +1,19 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct CategoryRule {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub category: String,
+    pub priority: i32,
+    pub account_id: Option<Uuid>,
+    pub details: Option<[u8; 34]>,
+    pub src_account: Option<[u8; 34]>,
+    pub opr_src: Option<[u8; 34]>,
+    pub min_amount: Option<BigDecimal>,
+    pub max_amount: Option<BigDecimal>,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,27 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct Earmark {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub amount: BigDecimal,
+    pub captured_amount: BigDecimal,
+    pub reason: String,
+    pub created: DateTime<Utc>,
+    pub expiry: Option<DateTime<Utc>>,
+    pub status: EarmarkStatus,
+    pub closed_time: Option<DateTime<Utc>>,
+    pub posting_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "earmark_status", rename_all = "UPPERCASE")]
+pub enum EarmarkStatus {
+    Active,
+    Released,
+    Consumed,
+    Expired,
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EodRun {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub business_date: NaiveDate,
+    pub status: EodStatus,
+    pub started: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct EodStepResult {
+    pub run_id: Uuid,
+    pub step_seq: i32,
+    pub step_name: String,
+    pub status: EodStatus,
+    pub message: Option<String>,
+    pub started: DateTime<Utc>,
+    pub finished: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "eod_status", rename_all = "UPPERCASE")]
+pub enum EodStatus {
+    Running,
+    Completed,
+    Failed,
+}
@@ -0,0 +1,37 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct Escrow {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub source_account_id: Uuid,
+    pub escrow_account_id: Uuid,
+    pub target_account_id: Uuid,
+    pub amount: BigDecimal,
+    pub opr_type: [u8; 34],
+    pub status: EscrowStatus,
+    pub timeout: DateTime<Utc>,
+    pub timeout_action: EscrowTimeoutAction,
+    pub initiation_posting_id: Option<Uuid>,
+    pub resolution_posting_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "escrow_status", rename_all = "UPPERCASE")]
+pub enum EscrowStatus {
+    Pending,
+    Released,
+    Returned,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "escrow_timeout_action", rename_all = "UPPERCASE")]
+pub enum EscrowTimeoutAction {
+    Release,
+    Return,
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ExternalContent {
+    pub content_hash: [u8; 34],
+    pub object_key: String,
+    pub size: i64,
+    pub media_type: String,
+    pub created: DateTime<Utc>,
+}
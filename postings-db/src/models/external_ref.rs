@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ExternalRef {
+    pub id: Uuid,
+    pub entity_type: ExternalRefEntity,
+    pub entity_id: Uuid,
+    pub ledger_id: Uuid,
+    pub ref_system: String,
+    pub reference: String,
+    /// Scope the reference is unique in: the ledger id, `*` for all ledgers, or `None` for
+    /// references several entities may share.
+    pub unique_scope: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq, Eq)]
+#[sqlx(type_name = "external_ref_entity", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExternalRefEntity {
+    Posting,
+    LedgerAccount,
+}
@@ -0,0 +1,35 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FeeSchedule {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub opr_type: [u8; 34],
+    pub fee_type: FeeType,
+    pub flat_amount: Option<BigDecimal>,
+    pub rate: Option<BigDecimal>,
+    pub min_fee: Option<BigDecimal>,
+    pub max_fee: Option<BigDecimal>,
+    pub fee_account_id: Uuid,
+    pub product_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FeeTier {
+    pub schedule_id: Uuid,
+    pub from_amount: BigDecimal,
+    pub flat_amount: BigDecimal,
+    pub rate: BigDecimal,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "fee_type", rename_all = "UPPERCASE")]
+pub enum FeeType {
+    Flat,
+    Percentage,
+    Tiered,
+}
@@ -0,0 +1,19 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct FxRevaluation {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub account_id: Uuid,
+    pub currency: String,
+    pub valuation_time: DateTime<Utc>,
+    pub foreign_balance: BigDecimal,
+    pub rate: BigDecimal,
+    pub carrying_value: BigDecimal,
+    pub revalued_value: BigDecimal,
+    pub posting_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct HashingProfile {
+    pub ledger_id: Uuid,
+    /// Comma separated names of the excluded fields.
+    pub excluded_fields: String,
+    /// Name of the hash function new postings are hashed with.
+    pub hash_algorithm: String,
+    pub updated: DateTime<Utc>,
+}
@@ -0,0 +1,10 @@
+use chrono::NaiveDate;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct Holiday {
+    pub ledger_id: Uuid,
+    pub holiday_date: NaiveDate,
+    pub name: String,
+}
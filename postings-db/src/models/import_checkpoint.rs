@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Progress of an import of one source file, so an interrupted import resumes where it stopped.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ImportCheckpoint {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    /// Kind of import, e.g. `OPENING_BALANCE`.
+    pub import_type: String,
+    /// Hash of the source and of the import parameters; a changed file starts a new import.
+    pub source_hash: [u8; 34],
+    /// 1-based line the import continues with; earlier lines are done.
+    pub next_line: i64,
+    pub loaded_count: i64,
+    pub quarantined_count: i64,
+    pub completed: bool,
+    pub updated: DateTime<Utc>,
+}
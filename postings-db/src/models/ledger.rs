@@ -5,4 +5,10 @@ use sqlx::FromRow;
 pub struct Ledger {
     pub id: Uuid,
     pub coa_id: Uuid,
+    /// Rejects every mutating operation on the ledger while set.
+    pub read_only: bool,
+    /// Memo ledger: its postings are not required to balance.
+    pub memo: bool,
+    /// Closing postings carry the hash of the closed totals as their `opr_details`.
+    pub closing_summary: bool,
 }
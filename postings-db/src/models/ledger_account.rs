@@ -11,4 +11,5 @@ pub struct LedgerAccount {
     pub coa_id: Uuid,
     pub balance_side: BalanceSide,
     pub category: AccountCategory,
+    pub currency: Option<String>,
 }
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerAccountName {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub account_id: Uuid,
+    pub number: Option<String>,
+    pub name: String,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerClosure {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    /// Locked account, the whole ledger when unset.
+    pub account_id: Option<Uuid>,
+    pub cut_off: DateTime<Utc>,
+    pub allow_adjustments: bool,
+    pub closed_by: String,
+    pub reason: String,
+    pub created: DateTime<Utc>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerEvent {
+    pub seq: i64,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub operation: LedgerEventOperation,
+    pub payload: String,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "ledger_event_operation", rename_all = "UPPERCASE")]
+pub enum LedgerEventOperation {
+    Insert,
+    Update,
+    Delete,
+}
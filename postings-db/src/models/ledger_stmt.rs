@@ -0,0 +1,17 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::stmt_status::StmtStatus;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct LedgerStmt {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+    pub posting_id: Option<Uuid>,
+    pub pst_time: DateTime<Utc>,
+    pub stmt_status: StmtStatus,
+    pub stmt_seq_nbr: i32,
+}
@@ -0,0 +1,11 @@
+/// Order in which the lines of an account are read for statements.
+/// Both orders are total, so lines with equal times are always returned the same way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineOrder {
+    /// Ascending `pst_time`, then `record_time`, then line id.
+    #[default]
+    PostingTime,
+    /// Ascending value time of the line's current posting, falling back to `pst_time` for
+    /// postings without one, then as [`LineOrder::PostingTime`].
+    ValueTime,
+}
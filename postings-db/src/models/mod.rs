@@ -1,13 +1,50 @@
 pub mod account_category;
+pub mod account_group;
+pub mod account_limit;
 pub mod account_stmt;
+pub mod api_key;
+pub mod backfill_job;
+pub mod balance_checkpoint;
 pub mod balance_side;
+pub mod batch_status;
+pub mod category_rule;
 pub mod chart_of_account;
+pub mod earmark;
+pub mod eod_run;
+pub mod escrow;
+pub mod external_content;
+pub mod external_ref;
+pub mod fee_schedule;
+pub mod fx_revaluation;
+pub mod hashing_profile;
+pub mod holiday;
+pub mod import_checkpoint;
 pub mod ledger;
 pub mod ledger_account;
+pub mod ledger_account_name;
+pub mod ledger_closure;
+pub mod ledger_event;
+pub mod ledger_stmt;
+pub mod line_order;
 pub mod named;
+pub mod outbox_entry;
 pub mod posting;
+pub mod posting_filter;
 pub mod posting_line;
 pub mod posting_status;
 pub mod posting_trace;
 pub mod posting_type;
+pub mod prepared_posting;
+pub mod product;
+pub mod quarantined_entry;
+pub mod report_schedule;
+pub mod reversal_policy;
+pub mod scheduled_posting;
+pub mod settlement_batch;
+pub mod standing_order;
+pub mod stmt_annotation;
+pub mod stmt_delivery;
+pub mod stmt_job;
+pub mod stmt_metric;
+pub mod stmt_repair;
 pub mod stmt_status;
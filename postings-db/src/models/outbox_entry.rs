@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Event written in the transaction of the change it describes, delivered afterwards by a relay.
+/// Delivery is at least once: an entry delivered just before a crash is delivered again.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct OutboxEntry {
+    pub id: Uuid,
+    /// Kind of event, for consumers that route before decoding the payload.
+    pub event_type: String,
+    /// The event as JSON.
+    pub payload: String,
+    pub created: DateTime<Utc>,
+    /// Failed delivery attempts so far.
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub delivered: Option<DateTime<Utc>>,
+}
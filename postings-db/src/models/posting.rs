@@ -32,4 +32,10 @@ pub struct Posting {
     pub antecedent_hash: Option<[u8; 34]>,
     /// Multihash of the posting.
     pub hash: Option<[u8; 34]>,
+    /// Comma separated fields excluded from the hash by the ledger's hashing profile.
+    pub hash_excluded_fields: Option<String>,
+    /// Version of the hashing rules `hash` was computed with.
+    pub hash_version: i16,
+    /// Name of the hash function `hash` was computed with.
+    pub hash_algorithm: String,
 }
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::models::posting_status::PostingStatus;
+
+/// Criteria of [`crate::repositories::posting_repository::PostingRepository::find_by_filter_paged`].
+/// Unset criteria match every posting, set ones must all match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PostingFilter {
+    /// Any of these ledgers; empty for every ledger.
+    pub ledger_ids: Vec<Uuid>,
+    /// Postings with a line on the account.
+    pub account_id: Option<Uuid>,
+    pub opr_id: Option<[u8; 34]>,
+    pub opr_type: Option<[u8; 34]>,
+    pub pst_status: Option<PostingStatus>,
+    /// Inclusive lower bound of the posting time.
+    pub pst_time_from: Option<DateTime<Utc>>,
+    /// Inclusive upper bound of the posting time.
+    pub pst_time_to: Option<DateTime<Utc>>,
+    pub order: PostingOrder,
+}
+
+/// Order of filtered postings. Ties are broken by posting id, so pages never overlap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PostingOrder {
+    #[default]
+    RecordTimeAsc,
+    RecordTimeDesc,
+    PstTimeAsc,
+    PstTimeDesc,
+}
+
+impl PostingOrder {
+    /// `ORDER BY` clause of the order, on the columns of the `posting` table.
+    pub fn order_by(&self) -> &'static str {
+        match self {
+            PostingOrder::RecordTimeAsc => "record_time, id",
+            PostingOrder::RecordTimeDesc => "record_time DESC, id DESC",
+            PostingOrder::PstTimeAsc => "pst_time, id",
+            PostingOrder::PstTimeDesc => "pst_time DESC, id DESC",
+        }
+    }
+}
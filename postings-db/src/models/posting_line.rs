@@ -22,6 +22,9 @@ pub struct PostingLine {
     pub pst_status: PostingStatus,
     pub hash: Option<[u8; 34]>,
     pub discarded_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub currency: Option<String>,
+    pub fx_base_currency: Option<String>,
+    pub fx_rate: Option<BigDecimal>,
 }
 
 impl Default for PostingLine {
@@ -43,6 +46,27 @@ impl Default for PostingLine {
             pst_status: Default::default(),
             hash: None,
             discarded_time: None,
+            currency: None,
+            fx_base_currency: None,
+            fx_rate: None,
         }
     }
 }
+
+/// Aggregates over the current lines of an account.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLineStats {
+    pub line_count: i64,
+    pub first_pst_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_pst_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Operation of the line recorded last.
+    pub last_opr_id: Option<[u8; 34]>,
+}
+
+/// Totals of one account's current lines.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLineTotals {
+    pub account_id: Uuid,
+    pub total_debit: BigDecimal,
+    pub total_credit: BigDecimal,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PreparedPosting {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    /// Operation ID of the prepared posting. It is a 32-byte hash.
+    pub opr_id: [u8; 34],
+    /// JSON of the validated posting.
+    pub payload: String,
+    pub status: PreparedPostingStatus,
+    pub coordinator_ref: String,
+    pub expires: DateTime<Utc>,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+    pub posting_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "prepared_posting_status", rename_all = "UPPERCASE")]
+pub enum PreparedPostingStatus {
+    Prepared,
+    Committed,
+    Aborted,
+}
@@ -0,0 +1,30 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct Product {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub interest_rate: Option<BigDecimal>,
+    pub day_count_convention: Option<DayCountConvention>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountProduct {
+    pub account_id: Uuid,
+    pub product_id: Uuid,
+    pub linked: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "day_count_convention", rename_all = "UPPERCASE")]
+pub enum DayCountConvention {
+    Act360,
+    Act365Fixed,
+    ActActIsda,
+    Thirty360,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct QuarantinedEntry {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub import_type: String,
+    pub line_number: i64,
+    pub payload: String,
+    pub reason: String,
+    pub status: QuarantineStatus,
+    pub created: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "quarantine_status", rename_all = "UPPERCASE")]
+pub enum QuarantineStatus {
+    Pending,
+    Resubmitted,
+    Discarded,
+}
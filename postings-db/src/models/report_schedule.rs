@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReportSchedule {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub frequency: ReportFrequency,
+    pub next_ref_time: DateTime<Utc>,
+    pub status: ReportScheduleStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReportArtifact {
+    pub id: Uuid,
+    pub schedule_id: Option<Uuid>,
+    pub ledger_id: Uuid,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub ref_time: DateTime<Utc>,
+    pub version: i32,
+    pub object_key: String,
+    pub checksum: [u8; 34],
+    pub size: i64,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "report_type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportType {
+    TrialBalance,
+    DailyJournal,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "report_format", rename_all = "UPPERCASE")]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    Pdf,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "report_frequency", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportFrequency {
+    Daily,
+    MonthEnd,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "report_schedule_status", rename_all = "UPPERCASE")]
+pub enum ReportScheduleStatus {
+    Active,
+    Cancelled,
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ReversalPolicy {
+    pub ledger_id: Uuid,
+    pub teller_window_days: i32,
+    pub supervisor_window_days: Option<i32>,
+    pub updated: DateTime<Utc>,
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+use crate::models::posting_type::PostingType;
+use crate::models::standing_order::Frequency;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduledPosting {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub opr_type: [u8; 34],
+    pub pst_type: PostingType,
+    /// JSON of the line templates.
+    pub lines: String,
+    pub frequency: Option<Frequency>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub max_occurrences: Option<i32>,
+    pub next_pst_time: DateTime<Utc>,
+    pub occurrence: i32,
+    pub status: ScheduledPostingStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "scheduled_posting_status", rename_all = "UPPERCASE")]
+pub enum ScheduledPostingStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
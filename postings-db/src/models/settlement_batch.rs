@@ -0,0 +1,27 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::batch_status::BatchStatus;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SettlementBatch {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    /// External batch reference. It is a 32-byte hash.
+    pub batch_ref: [u8; 34],
+    pub control_amount: BigDecimal,
+    pub control_count: Option<i32>,
+    pub status: BatchStatus,
+    pub created: DateTime<Utc>,
+    pub closed_time: Option<DateTime<Utc>>,
+}
+
+/// Assignment of a posting to a batch. The amount is the debit sum of the posting lines at assignment time.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SettlementBatchEntry {
+    pub batch_id: Uuid,
+    pub posting_id: Uuid,
+    pub amount: BigDecimal,
+    pub assigned_time: DateTime<Utc>,
+}
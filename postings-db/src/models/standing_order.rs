@@ -0,0 +1,62 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StandingOrder {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub source_account_id: Uuid,
+    pub target_account_id: Uuid,
+    pub amount: BigDecimal,
+    pub opr_type: [u8; 34],
+    pub frequency: Frequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub max_executions: Option<i32>,
+    pub max_retries: i32,
+    pub next_execution_date: NaiveDate,
+    pub occurrence: i32,
+    pub execution_count: i32,
+    pub status: StandingOrderStatus,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StandingOrderExecution {
+    pub id: Uuid,
+    pub order_id: Uuid,
+    pub execution_date: NaiveDate,
+    pub attempt: i32,
+    pub status: ExecutionStatus,
+    pub posting_id: Option<Uuid>,
+    pub message: Option<String>,
+    pub executed_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "frequency", rename_all = "UPPERCASE")]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "standing_order_status", rename_all = "UPPERCASE")]
+pub enum StandingOrderStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "execution_status", rename_all = "UPPERCASE")]
+pub enum ExecutionStatus {
+    Executed,
+    Failed,
+    Skipped,
+}
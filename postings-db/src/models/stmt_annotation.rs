@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Reviewer note on an account statement. Kept apart from the statement so annotating never
+/// touches the statement figures, and never updated once written.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtAnnotation {
+    pub id: Uuid,
+    pub stmt_id: Uuid,
+    pub author: String,
+    pub text: String,
+    pub created: DateTime<Utc>,
+}
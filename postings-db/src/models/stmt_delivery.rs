@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtDelivery {
+    pub id: Uuid,
+    pub stmt_id: Uuid,
+    pub format: StmtDocumentFormat,
+    pub checksum: [u8; 34],
+    pub signature: Option<Vec<u8>>,
+    pub key_id: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "stmt_document_format", rename_all = "UPPERCASE")]
+pub enum StmtDocumentFormat {
+    Csv,
+    Json,
+    Pdf,
+}
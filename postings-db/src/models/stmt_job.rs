@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtJob {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub ref_time: DateTime<Utc>,
+    pub status: StmtJobStatus,
+    pub stmt_id: Option<Uuid>,
+    pub error_message: Option<String>,
+    pub created: DateTime<Utc>,
+    pub completed_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "stmt_job_status", rename_all = "UPPERCASE")]
+pub enum StmtJobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Duration and size of one statement generation.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtMetric {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub account_id: Uuid,
+    pub recorded: DateTime<Utc>,
+    pub duration_ms: i64,
+    pub line_count: i64,
+}
+
+/// Metrics of one account aggregated over a window.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountStmtStats {
+    pub account_id: Uuid,
+    pub generations: i64,
+    pub total_duration_ms: i64,
+    pub max_duration_ms: i64,
+    pub max_lines: i64,
+}
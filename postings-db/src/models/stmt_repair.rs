@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct StmtRepair {
+    pub id: Uuid,
+    pub stmt_id: Uuid,
+    pub account_id: Uuid,
+    pub pst_time: DateTime<Utc>,
+    /// Figures stored before the repair, as JSON.
+    pub recorded: String,
+    /// Recomputed figures written by the repair, as JSON.
+    pub expected: String,
+    pub principal: String,
+    pub reason: String,
+    pub repaired_time: DateTime<Utc>,
+}
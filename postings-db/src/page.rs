@@ -0,0 +1,62 @@
+/// Zero-based page of a query result, `size` rows per page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub page: u32,
+    pub size: u32,
+}
+
+impl PageRequest {
+    pub fn new(page: u32, size: u32) -> Self {
+        Self { page, size }
+    }
+
+    /// Rows to skip, as bound to `OFFSET`.
+    pub fn offset(&self) -> i64 {
+        self.page as i64 * self.size as i64
+    }
+
+    /// Rows to return, as bound to `LIMIT`.
+    pub fn limit(&self) -> i64 {
+        self.size as i64
+    }
+}
+
+/// One page of rows together with the number of rows matching the query overall.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub content: Vec<T>,
+    pub request: PageRequest,
+    pub total_elements: u64,
+}
+
+impl<T> Page<T> {
+    pub fn new(content: Vec<T>, request: PageRequest, total_elements: u64) -> Self {
+        Self { content, request, total_elements }
+    }
+
+    /// Builds the page by slicing an already complete, ordered result.
+    pub fn from_all(all: Vec<T>, request: PageRequest) -> Self {
+        let total_elements = all.len() as u64;
+        let content = all
+            .into_iter()
+            .skip(request.offset() as usize)
+            .take(request.limit() as usize)
+            .collect();
+        Self { content, request, total_elements }
+    }
+
+    pub fn total_pages(&self) -> u64 {
+        if self.request.size == 0 {
+            return 0;
+        }
+        self.total_elements.div_ceil(self.request.size as u64)
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Page<U> {
+        Page {
+            content: self.content.into_iter().map(f).collect(),
+            request: self.request,
+            total_elements: self.total_elements,
+        }
+    }
+}
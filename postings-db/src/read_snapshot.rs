@@ -0,0 +1,14 @@
+use crate::models::account_stmt::AccountStmt;
+use crate::models::posting_line::PostingLine;
+
+/// Rows of one account read from a single database snapshot by
+/// [`crate::repositories::read_snapshot_repository::ReadSnapshotRepository::read_account`], so
+/// statement, lines and the totals derived from them agree with each other.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountSnapshot {
+    /// Last statement closed before the reference time.
+    pub last_closed_stmt: Option<AccountStmt>,
+    /// Non discarded lines posted up to the reference time after the earlier of the last closed
+    /// statement and the start of the activity, all of them without closed statement. Latest first.
+    pub lines: Vec<PostingLine>,
+}
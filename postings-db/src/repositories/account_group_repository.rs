@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use crate::models::account_group::{AccountGroup, AccountGroupMember};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountGroupRepository {
+    /// Fails with `UniqueViolation` if the ledger already has a group of that name.
+    async fn save(&self, group: AccountGroup) -> Result<AccountGroup, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountGroup>, DbError>;
+    async fn find_by_ledger_id_and_name(&self, ledger_id: Uuid, name: &str) -> Result<Option<AccountGroup>, DbError>;
+    /// Ordered by name.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<AccountGroup>, DbError>;
+    /// Adding an account already in the group leaves it unchanged.
+    async fn add_member(&self, member: AccountGroupMember) -> Result<(), DbError>;
+    /// Returns whether the account was a member.
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<bool, DbError>;
+    /// Ordered by account id.
+    async fn find_member_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>, DbError>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::models::account_limit::AccountLimit;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountLimitRepository {
+    async fn save(&self, limit: AccountLimit) -> Result<AccountLimit, DbError>;
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError>;
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use crate::models::account_stmt::AccountStmt;
 use crate::models::stmt_status::StmtStatus;
 use crate::DbError;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
@@ -9,6 +10,18 @@ use uuid::Uuid;
 pub trait AccountStmtRepository {
     async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError>;
     async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError>;
+    /// Latest closed statement before `ref_time`. Simulated statements are never returned.
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError>;
+    /// Deletes simulated statements of the ledger's accounts whose expiry is on or before `as_of`.
+    /// Returns the number of deleted statements.
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError>;
+    /// Closed statements of the account posted in `from..=to`, ordered by posting time.
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError>;
+    /// Closed statements of the ledger's accounts with an id greater than `after`, ordered by id.
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError>;
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError>;
+    /// Overwrites the carried-over totals and line count of an existing statement, leaving its other columns untouched.
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError>;
     async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError>;
 }
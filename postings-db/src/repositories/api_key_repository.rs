@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::models::api_key::ApiKey;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ApiKeyRepository {
+    async fn save(&self, key: ApiKey) -> Result<ApiKey, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, DbError>;
+    async fn find_by_key_prefix(&self, key_prefix: &str) -> Result<Option<ApiKey>, DbError>;
+    async fn find_by_tenant_id(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, DbError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::models::backfill_job::BackfillJob;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait BackfillJobRepository {
+    async fn save(&self, job: BackfillJob) -> Result<BackfillJob, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BackfillJob>, DbError>;
+    /// Most recently started job of the task on the ledger that has not completed.
+    async fn find_unfinished_by_task_and_ledger(&self, task_name: &str, ledger_id: Uuid) -> Result<Option<BackfillJob>, DbError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::models::balance_checkpoint::BalanceCheckpoint;
+use crate::DbError;
+
+#[async_trait]
+pub trait BalanceCheckpointRepository {
+    /// Inserts the checkpoint, replacing a stored one of the same account and `as_of`.
+    async fn save(&self, checkpoint: &BalanceCheckpoint) -> Result<(), DbError>;
+    /// Checkpoint of the account with the latest `as_of` not after `ref_time`.
+    async fn find_last_by_account_and_as_of_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<BalanceCheckpoint>, DbError>;
+    /// Drops the checkpoints of the account from `from` on, e.g. before booking lines back-dated
+    /// into them. Returns the number of deleted checkpoints.
+    async fn delete_by_account_and_as_of_greater_than_equal(&self, account_id: Uuid, from: DateTime<Utc>) -> Result<u64, DbError>;
+}
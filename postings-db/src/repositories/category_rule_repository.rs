@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::models::category_rule::CategoryRule;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CategoryRuleRepository {
+    async fn save(&self, rule: CategoryRule) -> Result<CategoryRule, DbError>;
+    /// Rules of the ledger ordered by ascending priority.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<CategoryRule>, DbError>;
+    /// Fails with `DbError::NotFound` if no rule has the id.
+    async fn delete_by_id(&self, id: Uuid) -> Result<(), DbError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::models::chart_of_account::ChartOfAccount;
+use crate::models::ledger::Ledger;
+use crate::models::ledger_account::LedgerAccount;
+use crate::models::named::Named;
+use crate::DbError;
+
+#[async_trait]
+pub trait ChartOfAccountImportRepository {
+    /// Writes the chart, its ledger, the accounts and their names in a single transaction.
+    /// Accounts must be ordered so that every parent precedes its children.
+    async fn save_all(&self, coa: &ChartOfAccount, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named]) -> Result<(), DbError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::earmark::{Earmark, EarmarkStatus};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EarmarkRepository {
+    async fn save(&self, earmark: Earmark) -> Result<Earmark, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Earmark>, DbError>;
+    async fn find_by_account_id_and_status(&self, account_id: Uuid, status: EarmarkStatus) -> Result<Vec<Earmark>, DbError>;
+    /// Active earmarks whose expiry is on or before `as_of`.
+    async fn find_active_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, DbError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::models::eod_run::{EodRun, EodStepResult};
+use crate::DbError;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EodRunRepository {
+    async fn save_run(&self, run: EodRun) -> Result<EodRun, DbError>;
+    async fn find_run_by_ledger_and_business_date(&self, ledger_id: Uuid, business_date: NaiveDate) -> Result<Option<EodRun>, DbError>;
+    async fn save_step_result(&self, result: EodStepResult) -> Result<EodStepResult, DbError>;
+    async fn find_step_results_by_run_id(&self, run_id: Uuid) -> Result<Vec<EodStepResult>, DbError>;
+}
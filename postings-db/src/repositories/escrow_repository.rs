@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::escrow::{Escrow, EscrowStatus};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EscrowRepository {
+    async fn save(&self, escrow: Escrow) -> Result<Escrow, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Escrow>, DbError>;
+    /// Moves an escrow still pending to `status`. Returns false when another caller resolved it first.
+    async fn resolve(&self, id: Uuid, status: EscrowStatus, resolved_time: DateTime<Utc>) -> Result<bool, DbError>;
+    /// Pending escrows whose timeout is on or before `as_of`.
+    async fn find_pending_timed_out(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, DbError>;
+}
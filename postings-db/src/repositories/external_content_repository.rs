@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::models::external_content::ExternalContent;
+use crate::DbError;
+
+#[async_trait]
+pub trait ExternalContentRepository {
+    /// Records the reference; an existing reference for the same hash is kept unchanged.
+    async fn save(&self, content: ExternalContent) -> Result<ExternalContent, DbError>;
+    async fn find_by_content_hash(&self, content_hash: &[u8]) -> Result<Option<ExternalContent>, DbError>;
+}
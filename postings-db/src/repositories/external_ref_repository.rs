@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::models::external_ref::{ExternalRef, ExternalRefEntity};
+use crate::DbError;
+
+#[async_trait]
+pub trait ExternalRefRepository {
+    /// Fails with [`DbError::UniqueViolation`] when the entity already carries a reference of the
+    /// system, or the reference is taken within its unique scope.
+    async fn save(&self, external_ref: &ExternalRef) -> Result<(), DbError>;
+    async fn find_by_entity(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, DbError>;
+    async fn find_by_ledger_and_reference(&self, ledger_id: Uuid, entity_type: ExternalRefEntity, ref_system: &str, reference: &str) -> Result<Vec<ExternalRef>, DbError>;
+}
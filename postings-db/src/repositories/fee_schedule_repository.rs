@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::models::fee_schedule::{FeeSchedule, FeeTier};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait FeeScheduleRepository {
+    async fn save(&self, schedule: FeeSchedule) -> Result<FeeSchedule, DbError>;
+    /// Ledger-wide default schedule of the operation type, ignoring product schedules.
+    async fn find_by_ledger_id_and_opr_type(&self, ledger_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError>;
+    async fn find_by_product_id_and_opr_type(&self, product_id: Uuid, opr_type: &[u8]) -> Result<Option<FeeSchedule>, DbError>;
+    /// Replaces all tiers of a schedule.
+    async fn save_tiers(&self, schedule_id: Uuid, tiers: Vec<FeeTier>) -> Result<Vec<FeeTier>, DbError>;
+    async fn find_tiers_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<FeeTier>, DbError>;
+}
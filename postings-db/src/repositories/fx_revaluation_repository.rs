@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::models::fx_revaluation::FxRevaluation;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait FxRevaluationRepository {
+    /// Fails with `UniqueViolation` when the account is already revalued at the valuation time.
+    async fn save(&self, revaluation: &FxRevaluation) -> Result<(), DbError>;
+    /// Revaluations of the account, latest valuation time first.
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<FxRevaluation>, DbError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::models::hashing_profile::HashingProfile;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait HashingProfileRepository {
+    /// Creates or replaces the profile of the ledger.
+    async fn save(&self, profile: HashingProfile) -> Result<HashingProfile, DbError>;
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<HashingProfile>, DbError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::models::holiday::Holiday;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait HolidayRepository {
+    async fn save(&self, holiday: Holiday) -> Result<Holiday, DbError>;
+    async fn delete(&self, ledger_id: Uuid, holiday_date: NaiveDate) -> Result<(), DbError>;
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Holiday>, DbError>;
+    async fn find_by_ledger_id_and_date_between(&self, ledger_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, DbError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::models::import_checkpoint::ImportCheckpoint;
+use crate::DbError;
+
+#[async_trait]
+pub trait ImportCheckpointRepository {
+    /// Inserts the checkpoint or updates the progress of the stored one with the same id.
+    async fn save(&self, checkpoint: &ImportCheckpoint) -> Result<(), DbError>;
+    async fn find_by_source(&self, ledger_id: Uuid, import_type: &str, source_hash: &[u8; 34]) -> Result<Option<ImportCheckpoint>, DbError>;
+}
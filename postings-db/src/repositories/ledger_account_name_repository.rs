@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::models::ledger_account_name::LedgerAccountName;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait LedgerAccountNameRepository {
+    /// Inserts the name, or updates the end of validity of a stored one.
+    async fn save(&self, name: &LedgerAccountName) -> Result<(), DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccountName>, DbError>;
+    /// Names of the ledger with `value` as name or number, of any validity.
+    async fn find_by_ledger_and_value(&self, ledger_id: Uuid, value: &str) -> Result<Vec<LedgerAccountName>, DbError>;
+    /// Names of the account, latest validity first.
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<LedgerAccountName>, DbError>;
+}
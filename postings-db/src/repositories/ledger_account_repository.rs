@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::models::ledger_account::LedgerAccount;
+use crate::page::{Page, PageRequest};
 use crate::DbError;
 use uuid::Uuid;
 
@@ -7,4 +8,10 @@ use uuid::Uuid;
 pub trait LedgerAccountRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError>;
     async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError>;
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError>;
+    /// One page of [`Self::find_by_ledger_id`], in the same order.
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError>;
+    /// Children, grandchildren and further descendants of the account, without the account
+    /// itself, ordered by id.
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError>;
 }
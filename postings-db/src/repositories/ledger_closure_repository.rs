@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::models::ledger_closure::LedgerClosure;
+use crate::DbError;
+
+#[async_trait]
+pub trait LedgerClosureRepository {
+    async fn save(&self, closure: &LedgerClosure) -> Result<(), DbError>;
+    /// Closures of the ledger and its accounts, latest cut-off first.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerClosure>, DbError>;
+}
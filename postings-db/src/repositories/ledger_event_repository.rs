@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::ledger_event::LedgerEvent;
+use crate::DbError;
+
+/// Events are written by database triggers only; the repository reads them, tracks consumer
+/// positions and moves dispatched events to the archive.
+#[async_trait]
+pub trait LedgerEventRepository {
+    async fn find_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError>;
+    async fn find_max_seq(&self) -> Result<Option<i64>, DbError>;
+    async fn find_archived_after_seq(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, DbError>;
+    /// Copies events up to `up_to_seq` created before `created_before` to the archive, then removes
+    /// them from the event table. Returns the number of removed events.
+    async fn archive_up_to(&self, up_to_seq: i64, created_before: DateTime<Utc>) -> Result<u64, DbError>;
+    /// Records the position of a consumer. Positions never move backwards.
+    async fn save_consumer_position(&self, consumer: &str, last_seq: i64, updated: DateTime<Utc>) -> Result<(), DbError>;
+    /// Position of the consumer, `None` when it never acknowledged an event.
+    async fn find_consumer_seq(&self, consumer: &str) -> Result<Option<i64>, DbError>;
+    /// Lowest position over all consumers, `None` when no consumer is registered.
+    async fn find_min_consumer_seq(&self) -> Result<Option<i64>, DbError>;
+}
@@ -7,4 +7,7 @@ use uuid::Uuid;
 pub trait LedgerRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError>;
     async fn save(&self, ledger: &Ledger) -> Result<(), DbError>;
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError>;
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError>;
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError>;
 }
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::ledger_stmt::LedgerStmt;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait LedgerStmtRepository {
+    /// Latest closed statement of the ledger before `ref_time`.
+    async fn find_last_closed_by_ledger_and_pst_time_less_than(&self, ledger_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<LedgerStmt>, DbError>;
+    async fn save(&self, stmt: LedgerStmt) -> Result<LedgerStmt, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerStmt>, DbError>;
+}
@@ -6,3 +6,40 @@ pub mod posting_repository;
 pub mod account_stmt_repository;
 pub mod posting_line_repository;
 pub mod posting_trace_repository;
+pub mod settlement_batch_repository;
+pub mod eod_run_repository;
+pub mod holiday_repository;
+pub mod account_limit_repository;
+pub mod earmark_repository;
+pub mod standing_order_repository;
+pub mod fee_schedule_repository;
+pub mod escrow_repository;
+pub mod quarantined_entry_repository;
+pub mod ledger_stmt_repository;
+pub mod external_content_repository;
+pub mod stmt_job_repository;
+pub mod hashing_profile_repository;
+pub mod stmt_delivery_repository;
+pub mod category_rule_repository;
+pub mod product_repository;
+pub mod prepared_posting_repository;
+pub mod ledger_event_repository;
+pub mod api_key_repository;
+pub mod stmt_metric_repository;
+pub mod backfill_job_repository;
+pub mod stmt_repair_repository;
+pub mod reversal_policy_repository;
+pub mod report_schedule_repository;
+pub mod account_group_repository;
+pub mod chart_of_account_import_repository;
+pub mod stmt_annotation_repository;
+pub mod unit_of_work_repository;
+pub mod import_checkpoint_repository;
+pub mod outbox_repository;
+pub mod balance_checkpoint_repository;
+pub mod external_ref_repository;
+pub mod ledger_closure_repository;
+pub mod scheduled_posting_repository;
+pub mod read_snapshot_repository;
+pub mod fx_revaluation_repository;
+pub mod ledger_account_name_repository;
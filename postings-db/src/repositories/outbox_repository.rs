@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::models::outbox_entry::OutboxEntry;
+use crate::DbError;
+
+/// Entries are normally written through a unit of work, together with the change they describe.
+#[async_trait]
+pub trait OutboxRepository {
+    async fn save(&self, entry: &OutboxEntry) -> Result<(), DbError>;
+    /// Undelivered entries, oldest first.
+    async fn find_undelivered(&self, limit: i64) -> Result<Vec<OutboxEntry>, DbError>;
+    async fn mark_delivered(&self, id: Uuid, delivered: DateTime<Utc>) -> Result<(), DbError>;
+    /// Counts a failed delivery attempt and keeps its error.
+    async fn record_failure(&self, id: Uuid, error: &str) -> Result<(), DbError>;
+    /// Removes entries delivered before `before`. Returns the number of removed entries.
+    async fn delete_delivered_before(&self, before: DateTime<Utc>) -> Result<u64, DbError>;
+}
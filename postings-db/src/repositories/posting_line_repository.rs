@@ -1,15 +1,37 @@
 use async_trait::async_trait;
-use crate::models::posting_line::PostingLine;
+use crate::models::line_order::LineOrder;
+use crate::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use crate::page::{Page, PageRequest};
 use crate::DbError;
 use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use uuid::Uuid;
 
 #[async_trait]
 pub trait PostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError>;
+    /// Latest first: descending `pst_time`, then `record_time`, then line id.
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// One page of [`Self::find_by_account_and_pst_time_between`], in the same order.
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError>;
     async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError>;
     async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// All lines of an operation, including discarded versions, ordered by record time.
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError>;
     async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// Lines posted up to `ref_time` and recorded up to `known_at`, discarded ones included, latest
+    /// record time first. Used to read the account as it was known at `known_at`.
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// Count and posting time range of the account's current lines, with the operation of the
+    /// line recorded last.
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError>;
+    /// Totals of the current lines up to `ref_time` of each of the accounts, in one grouped query.
+    /// Accounts without such lines are left out.
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError>;
+    /// Same lines as [`Self::find_by_account_and_pst_time_between`], fetched lazily in `order`.
+    /// The stream holds a pool connection until it is dropped.
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>>;
+    /// Same lines as [`Self::find_by_account_and_pst_time_less_than_equal`], fetched lazily in `order`.
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>>;
 }
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
 use crate::models::posting::Posting;
+use crate::models::posting_filter::PostingFilter;
+use crate::models::posting_line::PostingLine;
+use crate::page::{Page, PageRequest};
 use crate::DbError;
 use uuid::Uuid;
 
@@ -9,5 +12,17 @@ pub trait PostingRepository {
     async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError>;
     async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError>;
     async fn save(&self, posting: &Posting) -> Result<(), DbError>;
+    /// Inserts the postings and their lines in a single transaction, in the given order.
+    /// Nothing is written if any insert fails.
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError>;
+    /// Inserts the reversal and its lines and marks the reversed posting as discarded by it, in a
+    /// single transaction. Returns false, writing nothing, if the reversed posting is already discarded.
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError>;
+    /// All postings of a ledger, including discarded ones, ordered by record time.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError>;
+    /// One page of [`Self::find_by_ledger_id`], in the same order.
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError>;
+    /// One page of the postings matching the filter, discarded ones included, in the filter's order.
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError>;
 }
@@ -1,10 +1,16 @@
 use async_trait::async_trait;
 use crate::models::posting_trace::PostingTrace;
+use crate::upsert::Upserted;
 use crate::DbError;
 use uuid::Uuid;
 
 #[async_trait]
 pub trait PostingTraceRepository {
-    async fn save(&self, trace: PostingTrace) -> Result<PostingTrace, DbError>;
+    /// Inserts the trace, or refreshes the amounts, time and hash of the existing trace for the same
+    /// (tgt_pst_id, src_pst_id). The stored trace is returned, so its id may differ from the given one.
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError>;
+    /// Deletes traces of the ledger's accounts whose target statement was never persisted.
+    /// Returns the number of deleted traces.
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError>;
 }
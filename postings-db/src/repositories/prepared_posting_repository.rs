@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PreparedPostingRepository {
+    async fn save(&self, prepared: PreparedPosting) -> Result<PreparedPosting, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PreparedPosting>, DbError>;
+    /// Moves a posting still in the prepared state to `status`. Returns false when another caller resolved it first.
+    async fn resolve(&self, id: Uuid, status: PreparedPostingStatus, resolved_time: DateTime<Utc>, posting_id: Option<Uuid>) -> Result<bool, DbError>;
+    /// Prepared postings expired on or before `as_of`, oldest expiry first.
+    async fn find_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<Vec<PreparedPosting>, DbError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::models::product::{AccountProduct, Product};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ProductRepository {
+    async fn save(&self, product: Product) -> Result<Product, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, DbError>;
+    /// Creates or replaces the product link of the account.
+    async fn save_account_product(&self, link: AccountProduct) -> Result<AccountProduct, DbError>;
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Option<Product>, DbError>;
+}
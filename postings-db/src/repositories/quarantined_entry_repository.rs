@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::models::quarantined_entry::{QuarantineStatus, QuarantinedEntry};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait QuarantinedEntryRepository {
+    async fn save(&self, entry: QuarantinedEntry) -> Result<QuarantinedEntry, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuarantinedEntry>, DbError>;
+    async fn find_by_ledger_id_and_status(&self, ledger_id: Uuid, status: QuarantineStatus) -> Result<Vec<QuarantinedEntry>, DbError>;
+}
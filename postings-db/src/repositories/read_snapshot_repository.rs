@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::read_snapshot::AccountSnapshot;
+use crate::DbError;
+
+#[async_trait]
+pub trait ReadSnapshotRepository {
+    /// Reads the account's last statement closed before `ref_time` and its lines posted up to
+    /// `ref_time` after the earlier of that statement and `activity_from`, all within one
+    /// repeatable-read, read-only transaction. Postings committed meanwhile are seen by none of
+    /// the reads or by all of them.
+    async fn read_account(&self, account_id: Uuid, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountSnapshot, DbError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::report_schedule::{ReportArtifact, ReportSchedule, ReportType};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ReportScheduleRepository {
+    async fn save(&self, schedule: ReportSchedule) -> Result<ReportSchedule, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReportSchedule>, DbError>;
+    /// Active schedules whose next reference time is on or before `as_of`, most overdue first.
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportSchedule>, DbError>;
+    /// Fails with `UniqueViolation` if the version of the report is already stored.
+    async fn save_artifact(&self, artifact: ReportArtifact) -> Result<ReportArtifact, DbError>;
+    async fn find_artifact_by_id(&self, id: Uuid) -> Result<Option<ReportArtifact>, DbError>;
+    /// All formats and versions of the report, ordered by format and version.
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, DbError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::models::reversal_policy::ReversalPolicy;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ReversalPolicyRepository {
+    /// Creates or replaces the policy of the ledger.
+    async fn save(&self, policy: ReversalPolicy) -> Result<ReversalPolicy, DbError>;
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ReversalPolicy>, DbError>;
+}
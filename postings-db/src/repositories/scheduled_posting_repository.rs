@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::scheduled_posting::ScheduledPosting;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScheduledPostingRepository {
+    async fn save(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPosting>, DbError>;
+    /// Active schedules whose next posting time is on or before `as_of`.
+    async fn find_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ScheduledPosting>, DbError>;
+}
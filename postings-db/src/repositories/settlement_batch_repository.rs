@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::models::settlement_batch::{SettlementBatch, SettlementBatchEntry};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SettlementBatchRepository {
+    async fn save(&self, batch: SettlementBatch) -> Result<SettlementBatch, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<SettlementBatch>, DbError>;
+    async fn save_entry(&self, entry: SettlementBatchEntry) -> Result<SettlementBatchEntry, DbError>;
+    async fn find_entries_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<SettlementBatchEntry>, DbError>;
+    async fn find_entry_by_posting_id(&self, posting_id: Uuid) -> Result<Option<SettlementBatchEntry>, DbError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::models::standing_order::{StandingOrder, StandingOrderExecution};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StandingOrderRepository {
+    async fn save(&self, order: StandingOrder) -> Result<StandingOrder, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StandingOrder>, DbError>;
+    /// Active orders whose next execution date is on or before `as_of`.
+    async fn find_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrder>, DbError>;
+    /// Active orders with the account as source or target.
+    async fn find_active_by_account_id(&self, account_id: Uuid) -> Result<Vec<StandingOrder>, DbError>;
+    async fn save_execution(&self, execution: StandingOrderExecution) -> Result<StandingOrderExecution, DbError>;
+    async fn find_executions_by_order_id(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, DbError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::models::stmt_annotation::StmtAnnotation;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtAnnotationRepository {
+    async fn save(&self, annotation: StmtAnnotation) -> Result<StmtAnnotation, DbError>;
+    /// Ordered by creation time.
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, DbError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::models::stmt_delivery::StmtDelivery;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtDeliveryRepository {
+    async fn save(&self, delivery: StmtDelivery) -> Result<StmtDelivery, DbError>;
+    async fn find_by_stmt_id(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, DbError>;
+    async fn find_by_stmt_id_and_checksum(&self, stmt_id: Uuid, checksum: &[u8]) -> Result<Option<StmtDelivery>, DbError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::models::stmt_job::{StmtJob, StmtJobStatus};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtJobRepository {
+    async fn save(&self, job: StmtJob) -> Result<StmtJob, DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StmtJob>, DbError>;
+    /// Oldest jobs with the given status first.
+    async fn find_by_status(&self, status: StmtJobStatus, limit: i64) -> Result<Vec<StmtJob>, DbError>;
+}
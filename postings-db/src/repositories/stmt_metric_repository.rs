@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::stmt_metric::{AccountStmtStats, StmtMetric};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtMetricRepository {
+    async fn save(&self, metric: StmtMetric) -> Result<StmtMetric, DbError>;
+    /// Per-account aggregates of metrics recorded in `[from, to)`, by descending maximum duration.
+    async fn find_slowest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError>;
+    /// Per-account aggregates of metrics recorded in `[from, to)`, by descending maximum line count.
+    async fn find_hottest_accounts(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, limit: i64) -> Result<Vec<AccountStmtStats>, DbError>;
+}
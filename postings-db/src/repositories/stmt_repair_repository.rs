@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::models::account_stmt::AccountStmt;
+use crate::models::stmt_repair::StmtRepair;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StmtRepairRepository {
+    /// Writes the recomputed totals, openings, line count and currency totals of `stmt` and records
+    /// the repair, in a single transaction.
+    async fn save(&self, repair: StmtRepair, stmt: &AccountStmt) -> Result<StmtRepair, DbError>;
+    /// Repairs of the account, most recent first.
+    async fn find_by_account_id(&self, account_id: Uuid) -> Result<Vec<StmtRepair>, DbError>;
+}
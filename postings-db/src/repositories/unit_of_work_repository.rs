@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::unit_of_work::{Committed, UnitOfWork};
+use crate::DbError;
+
+#[async_trait]
+pub trait UnitOfWorkRepository {
+    /// Applies the writes of the unit in order within a single transaction. Nothing is written
+    /// if any of them fails, except within savepoints: a failed savepoint is rolled back on its
+    /// own and reported in the result.
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError>;
+}
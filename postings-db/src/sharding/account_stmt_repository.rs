@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use crate::models::account_stmt::AccountStmt;
+use crate::models::stmt_status::StmtStatus;
+use crate::repositories::account_stmt_repository::AccountStmtRepository;
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::sharding::{AccountLedgers, Shards};
+use crate::DbError;
+use uuid::Uuid;
+
+/// Statements are routed by the ledger of their account, looked up in `accounts`.
+pub struct ShardedAccountStmtRepository {
+    shards: Shards<dyn AccountStmtRepository + Send + Sync>,
+    accounts: AccountLedgers,
+}
+
+impl ShardedAccountStmtRepository {
+    pub fn new(shards: Shards<dyn AccountStmtRepository + Send + Sync>, accounts: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { shards, accounts: AccountLedgers::new(accounts) }
+    }
+
+    async fn for_account(&self, account_id: Uuid) -> Result<Option<&Arc<dyn AccountStmtRepository + Send + Sync>>, DbError> {
+        match self.accounts.ledger_of(account_id).await? {
+            Some(ledger_id) => self.shards.for_ledger(ledger_id).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountStmtRepository for ShardedAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_first_by_account_and_status_and_pst_time_less_than_ordered(account_id, status, ref_time).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, status, ref_time).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_last_closed_by_account_and_pst_time_less_than(account_id, ref_time).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        self.shards.for_ledger(ledger_id)?.delete_expired_simulated_by_ledger_id(ledger_id, as_of).await
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_closed_by_account_and_pst_time_between(account_id, from, to).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_closed_by_ledger_id_after(ledger_id, after, limit).await
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        self.shards.for_ledger(ledger_id)?.count_closed_by_ledger_id(ledger_id).await
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        for repo in self.shards.all() {
+            if repo.find_by_id(id).await?.is_some() {
+                return repo.update_opening_totals(id, opening_debit, opening_credit, line_count).await;
+            }
+        }
+        Err(DbError::NotFound)
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        match self.for_account(stmt.account_id).await? {
+            Some(repo) => repo.save(stmt).await,
+            None => Err(DbError::NotFound),
+        }
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(stmt) = repo.find_by_id(id).await? {
+                return Ok(Some(stmt));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use crate::models::ledger_account::LedgerAccount;
+use crate::page::{Page, PageRequest};
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::sharding::Shards;
+use crate::DbError;
+use uuid::Uuid;
+
+/// Accounts live in the shard of their ledger; lookups by account id ask every shard.
+pub struct ShardedLedgerAccountRepository {
+    shards: Shards<dyn LedgerAccountRepository + Send + Sync>,
+}
+
+impl ShardedLedgerAccountRepository {
+    pub fn new(shards: Shards<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { shards }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for ShardedLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(account) = repo.find_by_id(id).await? {
+                return Ok(Some(account));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
+        self.shards.for_ledger(ledger_account.ledger_id)?.save(ledger_account).await
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_by_ledger_id_paged(ledger_id, page).await
+    }
+
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        // Descendants live in the ledger of the account
+        match self.find_by_id(id).await? {
+            Some(account) => self.shards.for_ledger(account.ledger_id)?.find_descendants(id).await,
+            None => Ok(vec![]),
+        }
+    }
+}
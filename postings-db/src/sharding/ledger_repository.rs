@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use crate::models::ledger::Ledger;
+use crate::repositories::ledger_repository::LedgerRepository;
+use crate::sharding::Shards;
+use crate::DbError;
+use uuid::Uuid;
+
+pub struct ShardedLedgerRepository {
+    shards: Shards<dyn LedgerRepository + Send + Sync>,
+}
+
+impl ShardedLedgerRepository {
+    pub fn new(shards: Shards<dyn LedgerRepository + Send + Sync>) -> Self {
+        Self { shards }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for ShardedLedgerRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
+        self.shards.for_ledger(id)?.find_by_id(id).await
+    }
+
+    async fn save(&self, ledger: &Ledger) -> Result<(), DbError> {
+        self.shards.for_ledger(ledger.id)?.save(ledger).await
+    }
+
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError> {
+        self.shards.for_ledger(id)?.set_read_only(id, read_only).await
+    }
+
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError> {
+        self.shards.for_ledger(id)?.set_memo(id, memo).await
+    }
+
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError> {
+        self.shards.for_ledger(id)?.set_closing_summary(id, closing_summary).await
+    }
+}
@@ -0,0 +1,103 @@
+pub mod account_stmt_repository;
+pub mod ledger_account_repository;
+pub mod ledger_repository;
+pub mod posting_line_repository;
+pub mod posting_repository;
+pub mod posting_trace_repository;
+pub mod unit_of_work_repository;
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::DbError;
+use uuid::Uuid;
+
+/// Name of a physical database holding a subset of the ledgers.
+pub type ShardId = String;
+
+/// Assignment of ledgers to shards. Ledgers without an assignment live in the default shard, so an
+/// installation outgrowing one database keeps its existing ledgers there.
+///
+/// The sharded repositories of this module route every call to the shard of the ledger it concerns.
+/// Calls by the id of a row that does not carry its ledger ask all shards. Rows of one ledger are
+/// always in one shard, so batches and units of work within a ledger stay atomic. Chart of accounts
+/// and named repositories hold reference data and are not sharded.
+#[derive(Debug)]
+pub struct RoutingTable {
+    default_shard: ShardId,
+    ledgers: RwLock<HashMap<Uuid, ShardId>>,
+}
+
+impl RoutingTable {
+    pub fn new(default_shard: impl Into<ShardId>) -> Self {
+        Self { default_shard: default_shard.into(), ledgers: RwLock::new(HashMap::new()) }
+    }
+
+    pub fn with_ledger(self, ledger_id: Uuid, shard: impl Into<ShardId>) -> Self {
+        self.assign(ledger_id, shard);
+        self
+    }
+
+    /// Places a ledger, typically a new one before it is saved. Moving an existing ledger's rows to
+    /// the new shard is up to the operator.
+    pub fn assign(&self, ledger_id: Uuid, shard: impl Into<ShardId>) {
+        self.ledgers.write().unwrap().insert(ledger_id, shard.into());
+    }
+
+    pub fn shard_of(&self, ledger_id: Uuid) -> ShardId {
+        self.ledgers.read().unwrap().get(&ledger_id).cloned().unwrap_or_else(|| self.default_shard.clone())
+    }
+}
+
+/// One repository per shard, routed by a shared routing table.
+pub struct Shards<R: ?Sized> {
+    table: Arc<RoutingTable>,
+    repos: Vec<(ShardId, Arc<R>)>,
+}
+
+impl<R: ?Sized> Shards<R> {
+    pub fn new(table: Arc<RoutingTable>) -> Self {
+        Self { table, repos: Vec::new() }
+    }
+
+    pub fn with_shard(mut self, shard: impl Into<ShardId>, repo: Arc<R>) -> Self {
+        self.repos.push((shard.into(), repo));
+        self
+    }
+
+    /// Repository of the ledger's shard. A ledger routed to an unconfigured shard cannot be reached
+    /// and fails with [`DbError::Connection`].
+    pub(crate) fn for_ledger(&self, ledger_id: Uuid) -> Result<&Arc<R>, DbError> {
+        let shard = self.table.shard_of(ledger_id);
+        self.repos.iter().find(|(id, _)| *id == shard).map(|(_, repo)| repo).ok_or(DbError::Connection)
+    }
+
+    pub(crate) fn all(&self) -> impl Iterator<Item = &Arc<R>> {
+        self.repos.iter().map(|(_, repo)| repo)
+    }
+}
+
+/// Ledgers of accounts, for the repositories of rows that only carry their account. An account
+/// never changes its ledger, so found ledgers are cached.
+pub(crate) struct AccountLedgers {
+    accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    cache: RwLock<HashMap<Uuid, Uuid>>,
+}
+
+impl AccountLedgers {
+    pub(crate) fn new(accounts: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { accounts, cache: RwLock::new(HashMap::new()) }
+    }
+
+    pub(crate) async fn ledger_of(&self, account_id: Uuid) -> Result<Option<Uuid>, DbError> {
+        let cached = self.cache.read().unwrap().get(&account_id).copied();
+        if cached.is_some() {
+            return Ok(cached);
+        }
+        let Some(account) = self.accounts.find_by_id(account_id).await? else {
+            return Ok(None);
+        };
+        self.cache.write().unwrap().insert(account_id, account.ledger_id);
+        Ok(Some(account.ledger_id))
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use crate::models::line_order::LineOrder;
+use crate::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use crate::page::{Page, PageRequest};
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::repositories::posting_line_repository::PostingLineRepository;
+use crate::sharding::{AccountLedgers, ShardId, Shards};
+use crate::DbError;
+use uuid::Uuid;
+
+/// Lines do not carry their ledger, so each line is routed by the ledger of its account, looked up
+/// in `accounts`. Lines of unknown accounts read as missing and cannot be saved.
+pub struct ShardedPostingLineRepository {
+    shards: Shards<dyn PostingLineRepository + Send + Sync>,
+    accounts: AccountLedgers,
+}
+
+impl ShardedPostingLineRepository {
+    pub fn new(shards: Shards<dyn PostingLineRepository + Send + Sync>, accounts: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { shards, accounts: AccountLedgers::new(accounts) }
+    }
+
+    async fn for_account(&self, account_id: Uuid) -> Result<Option<&Arc<dyn PostingLineRepository + Send + Sync>>, DbError> {
+        match self.accounts.ledger_of(account_id).await? {
+            Some(ledger_id) => self.shards.for_ledger(ledger_id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Streams the lines of the account's shard, nothing for an unknown account.
+    fn route_stream<'a, F>(&'a self, account_id: Uuid, lines: F) -> BoxStream<'a, Result<PostingLine, DbError>>
+    where
+        F: FnOnce(&'a Arc<dyn PostingLineRepository + Send + Sync>) -> BoxStream<'a, Result<PostingLine, DbError>> + Send + 'a,
+    {
+        let mut lines = Some(lines);
+        stream::once(self.for_account(account_id))
+            .flat_map(move |repo| match (repo, lines.take()) {
+                (Ok(Some(repo)), Some(lines)) => lines(repo),
+                (Err(e), _) => stream::once(async { Err(e) }).boxed(),
+                _ => stream::empty().boxed(),
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl PostingLineRepository for ShardedPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        match self.for_account(posting_line.account_id).await? {
+            Some(repo) => repo.save(posting_line).await,
+            None => Err(DbError::NotFound),
+        }
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(line) = repo.find_by_id(id).await? {
+                return Ok(Some(line));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_by_account_and_pst_time_between(account_id, from, to).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_by_account_and_pst_time_between_paged(account_id, from, to, page).await,
+            None => Ok(Page::new(vec![], page, 0)),
+        }
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_by_id_and_account_id(id, account_id).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = Vec::new();
+        for repo in self.shards.all() {
+            lines.extend(repo.find_by_base_line_and_pst_time_less_than_equal(base_line, ref_time).await?);
+        }
+        Ok(lines)
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = Vec::new();
+        for repo in self.shards.all() {
+            lines.extend(repo.find_by_opr_id(opr_id).await?);
+        }
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_by_account_and_pst_time_less_than_equal(account_id, ref_time).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account_id, ref_time, known_at).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        match self.for_account(account_id).await? {
+            Some(repo) => repo.find_stats_by_account_id(account_id).await,
+            None => Ok(AccountLineStats { line_count: 0, first_pst_time: None, last_pst_time: None, last_opr_id: None }),
+        }
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        let mut by_shard: BTreeMap<ShardId, (Uuid, Vec<Uuid>)> = BTreeMap::new();
+        for account_id in account_ids {
+            if let Some(ledger_id) = self.accounts.ledger_of(*account_id).await? {
+                by_shard.entry(self.shards.table.shard_of(ledger_id)).or_insert_with(|| (ledger_id, Vec::new())).1.push(*account_id);
+            }
+        }
+        let mut totals = Vec::new();
+        for (ledger_id, ids) in by_shard.into_values() {
+            totals.extend(self.shards.for_ledger(ledger_id)?.sum_by_account_ids_and_pst_time_less_than_equal(&ids, ref_time).await?);
+        }
+        Ok(totals)
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.route_stream(account_id, move |repo| repo.stream_by_account_and_pst_time_between(account_id, from, to, order))
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.route_stream(account_id, move |repo| repo.stream_by_account_and_pst_time_less_than_equal(account_id, ref_time, order))
+    }
+}
@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use crate::models::posting::Posting;
+use crate::models::posting_filter::{PostingFilter, PostingOrder};
+use crate::models::posting_line::PostingLine;
+use crate::page::{Page, PageRequest};
+use crate::repositories::posting_repository::PostingRepository;
+use crate::sharding::{ShardId, Shards};
+use crate::DbError;
+use uuid::Uuid;
+
+pub struct ShardedPostingRepository {
+    shards: Shards<dyn PostingRepository + Send + Sync>,
+}
+
+impl ShardedPostingRepository {
+    pub fn new(shards: Shards<dyn PostingRepository + Send + Sync>) -> Self {
+        Self { shards }
+    }
+
+    fn compare(order: PostingOrder, a: &Posting, b: &Posting) -> Ordering {
+        match order {
+            PostingOrder::RecordTimeAsc => (a.record_time, a.id).cmp(&(b.record_time, b.id)),
+            PostingOrder::RecordTimeDesc => (b.record_time, b.id).cmp(&(a.record_time, a.id)),
+            PostingOrder::PstTimeAsc => (a.pst_time, a.id).cmp(&(b.pst_time, b.id)),
+            PostingOrder::PstTimeDesc => (b.pst_time, b.id).cmp(&(a.pst_time, a.id)),
+        }
+    }
+}
+
+#[async_trait]
+impl PostingRepository for ShardedPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(posting) = repo.find_by_opr_id_and_discarding_id_is_null(opr_id).await? {
+                return Ok(Some(posting));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        let mut postings = Vec::new();
+        for repo in self.shards.all() {
+            postings.extend(repo.find_by_opr_id(opr_id).await?);
+        }
+        Ok(postings)
+    }
+
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_first_by_ledger_order_by_record_time_desc(ledger_id).await
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        self.shards.for_ledger(posting.ledger_id)?.save(posting).await
+    }
+
+    /// Atomic per shard only: a batch spanning shards is saved shard by shard.
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        let mut by_shard: BTreeMap<ShardId, Vec<(Posting, Vec<PostingLine>)>> = BTreeMap::new();
+        for entry in postings {
+            by_shard.entry(self.shards.table.shard_of(entry.0.ledger_id)).or_default().push(entry.clone());
+        }
+        for batch in by_shard.into_values() {
+            self.shards.for_ledger(batch[0].0.ledger_id)?.save_batch(&batch).await?;
+        }
+        Ok(())
+    }
+
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        // A reversal is recorded in the ledger of the reversed posting
+        self.shards.for_ledger(reversal.ledger_id)?.save_reversal(reversal, lines, reversed_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(posting) = repo.find_by_id(id).await? {
+                return Ok(Some(posting));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        self.shards.for_ledger(ledger_id)?.find_by_ledger_id_paged(ledger_id, page).await
+    }
+
+    /// Filters on ledgers of one shard run there. Otherwise each shard returns its rows up to the end
+    /// of the page, which are merged in the filter's order.
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        if let Some((first, rest)) = filter.ledger_ids.split_first() {
+            let shard = self.shards.table.shard_of(*first);
+            if rest.iter().all(|id| self.shards.table.shard_of(*id) == shard) {
+                return self.shards.for_ledger(*first)?.find_by_filter_paged(filter, page).await;
+            }
+        }
+        let head = PageRequest::new(0, (page.offset() + page.limit()) as u32);
+        let mut postings = Vec::new();
+        let mut total_elements = 0;
+        for repo in self.shards.all() {
+            let shard_page = repo.find_by_filter_paged(filter, head).await?;
+            total_elements += shard_page.total_elements;
+            postings.extend(shard_page.content);
+        }
+        postings.sort_by(|a, b| Self::compare(filter.order, a, b));
+        let content = postings.into_iter().skip(page.offset() as usize).take(page.limit() as usize).collect();
+        Ok(Page::new(content, page, total_elements))
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::models::posting_trace::PostingTrace;
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::repositories::posting_trace_repository::PostingTraceRepository;
+use crate::sharding::{AccountLedgers, Shards};
+use crate::upsert::Upserted;
+use crate::DbError;
+use uuid::Uuid;
+
+/// Traces are routed by the ledger of their account, looked up in `accounts`.
+pub struct ShardedPostingTraceRepository {
+    shards: Shards<dyn PostingTraceRepository + Send + Sync>,
+    accounts: AccountLedgers,
+}
+
+impl ShardedPostingTraceRepository {
+    pub fn new(shards: Shards<dyn PostingTraceRepository + Send + Sync>, accounts: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { shards, accounts: AccountLedgers::new(accounts) }
+    }
+}
+
+#[async_trait]
+impl PostingTraceRepository for ShardedPostingTraceRepository {
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError> {
+        let ledger_id = self.accounts.ledger_of(trace.account_id).await?.ok_or(DbError::NotFound)?;
+        self.shards.for_ledger(ledger_id)?.upsert(trace).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
+        for repo in self.shards.all() {
+            if let Some(trace) = repo.find_by_id(id).await? {
+                return Ok(Some(trace));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError> {
+        self.shards.for_ledger(ledger_id)?.delete_orphaned_by_ledger_id(ledger_id).await
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::repositories::ledger_account_repository::LedgerAccountRepository;
+use crate::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use crate::sharding::{AccountLedgers, Shards};
+use crate::unit_of_work::{Committed, UnitOfWork, Write};
+use crate::DbError;
+use uuid::Uuid;
+
+/// Commits each unit in the shard of the ledger it writes to, so it stays a single transaction.
+/// Postings and ledger statements name their ledger, lines, account statements and earmarks are
+/// routed by the ledger of their account, looked up in `accounts`. Discards and outbox entries
+/// follow the other writes of the unit.
+///
+/// A unit writing to ledgers of different shards, or to no ledger that can be told, fails with
+/// [`DbError::CrossShard`] before anything is written.
+pub struct ShardedUnitOfWorkRepository {
+    shards: Shards<dyn UnitOfWorkRepository + Send + Sync>,
+    accounts: AccountLedgers,
+}
+
+impl ShardedUnitOfWorkRepository {
+    pub fn new(shards: Shards<dyn UnitOfWorkRepository + Send + Sync>, accounts: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { shards, accounts: AccountLedgers::new(accounts) }
+    }
+
+    /// Ledgers and accounts the writes name, savepoints included.
+    fn collect(writes: &[Write], ledgers: &mut Vec<Uuid>, accounts: &mut Vec<Uuid>) {
+        for write in writes {
+            match write {
+                Write::Posting(posting) => ledgers.push(posting.ledger_id),
+                Write::LedgerStmt(stmt) => ledgers.push(stmt.ledger_id),
+                Write::PostingLine(line) => accounts.push(line.account_id),
+                Write::AccountStmt(stmt) => accounts.push(stmt.account_id),
+                Write::Earmark(earmark) => accounts.push(earmark.account_id),
+                Write::DiscardPosting { .. } | Write::OutboxEntry(_) => {}
+                Write::Savepoint(_, nested) => Self::collect(nested.writes(), ledgers, accounts),
+            }
+        }
+    }
+
+    /// Ledger whose shard holds every write of the unit.
+    async fn ledger_of(&self, work: &UnitOfWork) -> Result<Uuid, DbError> {
+        let mut ledgers = Vec::new();
+        let mut accounts = Vec::new();
+        Self::collect(work.writes(), &mut ledgers, &mut accounts);
+        accounts.sort();
+        accounts.dedup();
+        for account_id in accounts {
+            ledgers.push(self.accounts.ledger_of(account_id).await?.ok_or(DbError::NotFound)?);
+        }
+        let Some(first) = ledgers.first().copied() else {
+            return Err(DbError::CrossShard);
+        };
+        let shard = self.shards.table.shard_of(first);
+        if ledgers.iter().any(|ledger_id| self.shards.table.shard_of(*ledger_id) != shard) {
+            return Err(DbError::CrossShard);
+        }
+        Ok(first)
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for ShardedUnitOfWorkRepository {
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        let ledger_id = self.ledger_of(&work).await?;
+        self.shards.for_ledger(ledger_id)?.commit(work).await
+    }
+}
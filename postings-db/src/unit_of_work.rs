@@ -0,0 +1,117 @@
+use crate::models::account_stmt::AccountStmt;
+use crate::models::earmark::Earmark;
+use crate::models::ledger_stmt::LedgerStmt;
+use crate::models::outbox_entry::OutboxEntry;
+use crate::models::posting::Posting;
+use crate::models::posting_line::PostingLine;
+use crate::DbError;
+use uuid::Uuid;
+
+/// One write of a [`UnitOfWork`], with the semantics of the matching repository `save`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Write {
+    Posting(Posting),
+    /// Marks the posting `id` as discarded by `discarding_id`. Fails with [`DbError::NotFound`]
+    /// when there is no such posting or it is already discarded.
+    DiscardPosting { id: Uuid, discarding_id: Uuid },
+    PostingLine(PostingLine),
+    AccountStmt(AccountStmt),
+    LedgerStmt(LedgerStmt),
+    OutboxEntry(OutboxEntry),
+    Earmark(Earmark),
+    /// Nested unit applied behind a savepoint: if one of its writes fails, only the nested
+    /// unit is rolled back and the enclosing one carries on.
+    Savepoint(String, UnitOfWork),
+}
+
+/// Writes spanning several repositories, committed together by
+/// [`crate::repositories::unit_of_work_repository::UnitOfWorkRepository::commit`]: either all of
+/// them are stored or none is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnitOfWork {
+    writes: Vec<Write>,
+}
+
+impl UnitOfWork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn save_posting(&mut self, posting: Posting) -> &mut Self {
+        self.writes.push(Write::Posting(posting));
+        self
+    }
+
+    pub fn discard_posting(&mut self, id: Uuid, discarding_id: Uuid) -> &mut Self {
+        self.writes.push(Write::DiscardPosting { id, discarding_id });
+        self
+    }
+
+    pub fn save_posting_line(&mut self, posting_line: PostingLine) -> &mut Self {
+        self.writes.push(Write::PostingLine(posting_line));
+        self
+    }
+
+    pub fn save_account_stmt(&mut self, stmt: AccountStmt) -> &mut Self {
+        self.writes.push(Write::AccountStmt(stmt));
+        self
+    }
+
+    pub fn save_ledger_stmt(&mut self, stmt: LedgerStmt) -> &mut Self {
+        self.writes.push(Write::LedgerStmt(stmt));
+        self
+    }
+
+    /// Records an event in the outbox, so it is stored exactly when the other writes are.
+    pub fn save_outbox_entry(&mut self, entry: OutboxEntry) -> &mut Self {
+        self.writes.push(Write::OutboxEntry(entry));
+        self
+    }
+
+    pub fn save_earmark(&mut self, earmark: Earmark) -> &mut Self {
+        self.writes.push(Write::Earmark(earmark));
+        self
+    }
+
+    /// Adds `nested` as a unit of its own, named `name` in [`Committed::rolled_back`]. Its writes
+    /// are applied at this position, but a failure among them only discards them.
+    pub fn savepoint(&mut self, name: impl Into<String>, nested: UnitOfWork) -> &mut Self {
+        self.writes.push(Write::Savepoint(name.into(), nested));
+        self
+    }
+
+    /// Adds the writes of `other` after the ones already added.
+    pub fn append(&mut self, other: UnitOfWork) -> &mut Self {
+        self.writes.extend(other.writes);
+        self
+    }
+
+    /// Writes in the order they were added, which is the order they are applied in.
+    pub fn writes(&self) -> &[Write] {
+        &self.writes
+    }
+
+    pub fn into_writes(self) -> Vec<Write> {
+        self.writes
+    }
+}
+
+/// Outcome of a committed [`UnitOfWork`].
+#[derive(Debug, Default)]
+pub struct Committed {
+    /// Savepoints whose writes were discarded, in the order they were applied. Savepoints
+    /// nested in a discarded one are not listed.
+    pub rolled_back: Vec<RolledBack>,
+}
+
+#[derive(Debug)]
+pub struct RolledBack {
+    pub savepoint: String,
+    pub error: DbError,
+}
+
+impl Committed {
+    pub fn is_complete(&self) -> bool {
+        self.rolled_back.is_empty()
+    }
+}
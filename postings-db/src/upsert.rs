@@ -0,0 +1,25 @@
+/// Outcome of an idempotent write: the stored row, and whether this write created it or the row
+/// was there already, e.g. from an earlier attempt of a retried operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Upserted<T> {
+    Inserted(T),
+    Existing(T),
+}
+
+impl<T> Upserted<T> {
+    pub fn is_inserted(&self) -> bool {
+        matches!(self, Upserted::Inserted(_))
+    }
+
+    pub fn get(&self) -> &T {
+        match self {
+            Upserted::Inserted(row) | Upserted::Existing(row) => row,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        match self {
+            Upserted::Inserted(row) | Upserted::Existing(row) => row,
+        }
+    }
+}
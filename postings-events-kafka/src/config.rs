@@ -0,0 +1,38 @@
+use std::time::Duration;
+use rdkafka::config::ClientConfig;
+use rdkafka::error::KafkaError;
+use rdkafka::producer::FutureProducer;
+
+#[derive(Debug, Clone)]
+pub struct KafkaConfig {
+    /// Comma separated `host:port` list of the bootstrap brokers.
+    pub brokers: String,
+    pub topic: String,
+    /// Stable id of the relay instance. A restarted relay with the same id fences off its
+    /// predecessor, so a zombie instance cannot commit a transaction any more.
+    pub transactional_id: String,
+    /// Bound of the transaction calls and of the delivery of each event.
+    pub timeout: Duration,
+}
+
+impl KafkaConfig {
+    pub fn new(brokers: impl Into<String>, topic: impl Into<String>, transactional_id: impl Into<String>) -> Self {
+        Self {
+            brokers: brokers.into(),
+            topic: topic.into(),
+            transactional_id: transactional_id.into(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Idempotent, transactional producer; transactions are initialized by [`crate::sink::KafkaEventSink::new`].
+    pub fn producer(&self) -> Result<FutureProducer, KafkaError> {
+        ClientConfig::new()
+            .set("bootstrap.servers", &self.brokers)
+            .set("transactional.id", &self.transactional_id)
+            .set("enable.idempotence", "true")
+            .set("acks", "all")
+            .set("message.timeout.ms", self.timeout.as_millis().to_string())
+            .create()
+    }
+}
@@ -0,0 +1,9 @@
+//! Delivers the change events of the `ledger_event` table to Kafka.
+//!
+//! The table is the outbox: database triggers write the events in the transaction of the change
+//! itself. [`relay::KafkaRelay`] tails it and publishes every batch in one Kafka transaction
+//! through [`sink::KafkaEventSink`], then acknowledges the batch as a consumer of the table.
+
+pub mod config;
+pub mod relay;
+pub mod sink;
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use log::{error, info};
+use postings_api::service::ledger_event_service::{LedgerEventService, LedgerEventSink};
+use postings_api::ServiceError;
+use crate::sink::KafkaEventSink;
+
+pub const DEFAULT_BATCH_SIZE: i64 = 500;
+
+/// Moves the events of the `ledger_event` table to Kafka, registered as consumer `consumer` of the
+/// table so compaction keeps the events it has not relayed yet.
+///
+/// A batch is acknowledged after its Kafka transaction committed. A crash in between publishes the
+/// batch again on restart; consumers drop such events by the sequence number in
+/// [`crate::sink::SEQ_HEADER`], which makes the delivery effectively exactly once.
+pub struct KafkaRelay {
+    events: Arc<dyn LedgerEventService + Send + Sync>,
+    sink: KafkaEventSink,
+    consumer: String,
+    batch_size: i64,
+    settle_delay: chrono::Duration,
+}
+
+impl KafkaRelay {
+    pub fn new(events: Arc<dyn LedgerEventService + Send + Sync>, sink: KafkaEventSink, consumer: impl Into<String>) -> Self {
+        Self {
+            events,
+            sink,
+            consumer: consumer.into(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            settle_delay: chrono::Duration::seconds(5),
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Age an event must reach before it is relayed. Sequence numbers are taken before commit, so
+    /// a slower transaction can still add a lower number than the latest one; waiting keeps the
+    /// relay from acknowledging past such an event.
+    pub fn with_settle_delay(mut self, settle_delay: chrono::Duration) -> Self {
+        self.settle_delay = settle_delay;
+        self
+    }
+
+    /// Relays one batch of settled events. Returns the number of relayed events.
+    pub async fn relay_once(&self) -> Result<u64, ServiceError> {
+        let position = self.events.consumer_position(&self.consumer).await?.unwrap_or(0);
+        let settled_before = Utc::now() - self.settle_delay;
+        let batch: Vec<_> = self.events
+            .read_events(position, self.batch_size)
+            .await?
+            .into_iter()
+            .take_while(|e| e.created < settled_before)
+            .collect();
+        let Some(last) = batch.last() else {
+            return Ok(0);
+        };
+        self.sink.publish(&batch).await?;
+        self.events.acknowledge(&self.consumer, last.seq).await?;
+        Ok(batch.len() as u64)
+    }
+
+    /// Relays batches until the table is drained, then polls it every `interval`. Failed batches
+    /// are retried with the next poll.
+    pub async fn run(&self, interval: Duration) {
+        info!("Relaying ledger events to Kafka as consumer {}", self.consumer);
+        loop {
+            match self.relay_once().await {
+                Ok(0) => tokio::time::sleep(interval).await,
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Relaying ledger events failed: {e:?}");
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        }
+    }
+}
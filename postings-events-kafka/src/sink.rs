@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use log::error;
+use postings_api::domain::ledger_event::LedgerEvent;
+use postings_api::service::ledger_event_service::LedgerEventSink;
+use postings_api::ServiceError;
+use rdkafka::error::{KafkaError, RDKafkaErrorCode};
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use crate::config::KafkaConfig;
+
+/// Header holding the sequence number of the event, for consumers to drop redelivered events.
+pub const SEQ_HEADER: &str = "ledger-event-seq";
+
+/// Publishes events as JSON to the configured topic, keyed by entity id so the events of one row
+/// stay ordered within a partition. Each call to `publish` is one Kafka transaction: consumers
+/// reading committed messages see the whole batch or nothing of it.
+pub struct KafkaEventSink {
+    producer: FutureProducer,
+    config: KafkaConfig,
+}
+
+impl KafkaEventSink {
+    pub async fn new(config: KafkaConfig) -> Result<Self, KafkaError> {
+        let producer = config.producer()?;
+        let init = producer.clone();
+        let timeout = config.timeout;
+        blocking(move || init.init_transactions(timeout)).await?;
+        Ok(Self { producer, config })
+    }
+
+    async fn send_all(&self, events: &[LedgerEvent]) -> Result<(), KafkaError> {
+        let mut deliveries = Vec::with_capacity(events.len());
+        for event in events {
+            let payload = serde_json::to_vec(event).map_err(|_| KafkaError::MessageProduction(RDKafkaErrorCode::InvalidMessage))?;
+            let seq = event.seq.to_string();
+            let record = FutureRecord::to(&self.config.topic)
+                .key(&event.entity_id)
+                .payload(&payload)
+                .headers(OwnedHeaders::new().insert(Header { key: SEQ_HEADER, value: Some(&seq) }));
+            deliveries.push(self.producer.send(record, self.config.timeout));
+        }
+        for delivery in deliveries {
+            delivery.await.map_err(|(e, _)| e)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LedgerEventSink for KafkaEventSink {
+    async fn publish(&self, events: &[LedgerEvent]) -> Result<(), ServiceError> {
+        self.producer.begin_transaction().map_err(publication_error)?;
+        let sent = self.send_all(events).await;
+        let producer = self.producer.clone();
+        let timeout = self.config.timeout;
+        match sent {
+            Ok(()) => blocking(move || producer.commit_transaction(timeout)).await.map_err(publication_error),
+            Err(e) => {
+                if let Err(abort) = blocking(move || producer.abort_transaction(timeout)).await {
+                    error!("Aborting the Kafka transaction failed: {abort:?}");
+                }
+                Err(publication_error(e))
+            }
+        }
+    }
+}
+
+/// Runs a blocking producer call, such as a transaction commit, off the async runtime.
+async fn blocking<F>(call: F) -> Result<(), KafkaError>
+where
+    F: FnOnce() -> Result<(), KafkaError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(call)
+        .await
+        .unwrap_or(Err(KafkaError::Canceled))
+}
+
+fn publication_error(e: KafkaError) -> ServiceError {
+    error!("Publishing ledger events to Kafka failed: {e:?}");
+    ServiceError::EventPublication
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::currency::{CurrencyTotal, FxDetails};
+use postings_api::domain::hash_record::HashRecord;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::stmt_status::StmtStatus;
+use prost_types::Timestamp;
+use tonic::Status;
+use uuid::Uuid;
+use crate::proto;
+
+pub fn to_timestamp(time: DateTime<Utc>) -> Timestamp {
+    Timestamp { seconds: time.timestamp(), nanos: time.timestamp_subsec_nanos() as i32 }
+}
+
+pub fn from_timestamp(timestamp: Option<Timestamp>, field: &str) -> Result<DateTime<Utc>, Status> {
+    timestamp
+        .and_then(|t| DateTime::from_timestamp(t.seconds, t.nanos.try_into().ok()?))
+        .ok_or_else(|| Status::invalid_argument(format!("{field} is missing or out of range")))
+}
+
+fn from_optional_timestamp(timestamp: Option<Timestamp>, field: &str) -> Result<Option<DateTime<Utc>>, Status> {
+    match timestamp {
+        Some(t) => from_timestamp(Some(t), field).map(Some),
+        None => Ok(None),
+    }
+}
+
+pub fn parse_uuid(value: &str, field: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(value).map_err(|_| Status::invalid_argument(format!("{field} is not a UUID")))
+}
+
+/// Ids the ledger assigns may be left empty on requests.
+fn parse_assigned_uuid(value: &str, field: &str) -> Result<Uuid, Status> {
+    if value.is_empty() {
+        Ok(Uuid::nil())
+    } else {
+        parse_uuid(value, field)
+    }
+}
+
+fn parse_optional_uuid(value: Option<String>, field: &str) -> Result<Option<Uuid>, Status> {
+    value.map(|v| parse_uuid(&v, field)).transpose()
+}
+
+fn parse_decimal(value: &str, field: &str) -> Result<BigDecimal, Status> {
+    BigDecimal::from_str(value).map_err(|_| Status::invalid_argument(format!("{field} is not a decimal")))
+}
+
+pub fn parse_hash(value: Vec<u8>, field: &str) -> Result<[u8; 34], Status> {
+    value.try_into().map_err(|_| Status::invalid_argument(format!("{field} must be 34 bytes")))
+}
+
+fn parse_optional_hash(value: Option<Vec<u8>>, field: &str) -> Result<Option<[u8; 34]>, Status> {
+    value.map(|v| parse_hash(v, field)).transpose()
+}
+
+impl From<PostingType> for proto::PostingType {
+    fn from(pst_type: PostingType) -> Self {
+        match pst_type {
+            PostingType::BusiTx => Self::BusiTx,
+            PostingType::AdjTx => Self::AdjTx,
+            PostingType::BalStmt => Self::BalStmt,
+            PostingType::PnLStmt => Self::PnLStmt,
+            PostingType::BsStmt => Self::BsStmt,
+            PostingType::LdgClsng => Self::LdgClsng,
+            PostingType::Unknown => Self::Unknown,
+        }
+    }
+}
+
+fn posting_type_from_proto(value: i32) -> Result<PostingType, Status> {
+    match proto::PostingType::try_from(value) {
+        Ok(proto::PostingType::BusiTx) => Ok(PostingType::BusiTx),
+        Ok(proto::PostingType::AdjTx) => Ok(PostingType::AdjTx),
+        Ok(proto::PostingType::BalStmt) => Ok(PostingType::BalStmt),
+        Ok(proto::PostingType::PnLStmt) => Ok(PostingType::PnLStmt),
+        Ok(proto::PostingType::BsStmt) => Ok(PostingType::BsStmt),
+        Ok(proto::PostingType::LdgClsng) => Ok(PostingType::LdgClsng),
+        Ok(proto::PostingType::Unknown) => Ok(PostingType::Unknown),
+        Ok(proto::PostingType::Unspecified) | Err(_) => Err(Status::invalid_argument("pst_type is missing")),
+    }
+}
+
+impl From<PostingStatus> for proto::PostingStatus {
+    fn from(pst_status: PostingStatus) -> Self {
+        match pst_status {
+            PostingStatus::Deferred => Self::Deferred,
+            PostingStatus::Posted => Self::Posted,
+            PostingStatus::Proposed => Self::Proposed,
+            PostingStatus::Simulated => Self::Simulated,
+            PostingStatus::Tax => Self::Tax,
+            PostingStatus::Unposted => Self::Unposted,
+            PostingStatus::Cancelled => Self::Cancelled,
+            PostingStatus::Other => Self::Other,
+        }
+    }
+}
+
+fn posting_status_from_proto(value: i32) -> Result<PostingStatus, Status> {
+    match proto::PostingStatus::try_from(value) {
+        Ok(proto::PostingStatus::Deferred) => Ok(PostingStatus::Deferred),
+        Ok(proto::PostingStatus::Posted) => Ok(PostingStatus::Posted),
+        Ok(proto::PostingStatus::Proposed) => Ok(PostingStatus::Proposed),
+        Ok(proto::PostingStatus::Simulated) => Ok(PostingStatus::Simulated),
+        Ok(proto::PostingStatus::Tax) => Ok(PostingStatus::Tax),
+        Ok(proto::PostingStatus::Unposted) => Ok(PostingStatus::Unposted),
+        Ok(proto::PostingStatus::Cancelled) => Ok(PostingStatus::Cancelled),
+        Ok(proto::PostingStatus::Other) => Ok(PostingStatus::Other),
+        Ok(proto::PostingStatus::Unspecified) | Err(_) => Err(Status::invalid_argument("pst_status is missing")),
+    }
+}
+
+impl From<BalanceSide> for proto::BalanceSide {
+    fn from(balance_side: BalanceSide) -> Self {
+        match balance_side {
+            BalanceSide::Dr => Self::Dr,
+            BalanceSide::Cr => Self::Cr,
+            BalanceSide::DrCr => Self::DrCr,
+        }
+    }
+}
+
+impl From<AccountCategory> for proto::AccountCategory {
+    fn from(category: AccountCategory) -> Self {
+        match category {
+            AccountCategory::RE => Self::Re,
+            AccountCategory::EX => Self::Ex,
+            AccountCategory::AS => Self::As,
+            AccountCategory::LI => Self::Li,
+            AccountCategory::EQ => Self::Eq,
+            AccountCategory::NOOP => Self::Noop,
+            AccountCategory::NORE => Self::Nore,
+            AccountCategory::NOEX => Self::Noex,
+        }
+    }
+}
+
+impl From<StmtStatus> for proto::StmtStatus {
+    fn from(stmt_status: StmtStatus) -> Self {
+        match stmt_status {
+            StmtStatus::SIMULATED => Self::Simulated,
+            StmtStatus::CLOSED => Self::Closed,
+        }
+    }
+}
+
+impl From<LedgerAccount> for proto::LedgerAccount {
+    fn from(account: LedgerAccount) -> Self {
+        Self {
+            id: account.id.to_string(),
+            ledger_id: account.ledger.id.to_string(),
+            coa_id: account.coa.id.to_string(),
+            parent_id: account.parent.map(|p| p.id.to_string()),
+            balance_side: proto::BalanceSide::from(account.balance_side).into(),
+            category: proto::AccountCategory::from(account.category).into(),
+            currency: account.currency,
+        }
+    }
+}
+
+impl From<PostingLine> for proto::PostingLine {
+    fn from(line: PostingLine) -> Self {
+        Self {
+            id: line.id.to_string(),
+            account_id: line.account.id.to_string(),
+            debit_amount: line.debit_amount.to_string(),
+            credit_amount: line.credit_amount.to_string(),
+            details: line.details.map(Vec::from),
+            src_account: line.src_account.map(Vec::from),
+            base_line: line.base_line.map(|id| id.to_string()),
+            sub_opr_src_id: line.sub_opr_src_id.map(Vec::from),
+            record_time: Some(to_timestamp(line.record_time)),
+            opr_id: line.opr_id.to_vec(),
+            opr_src: line.opr_src.map(Vec::from),
+            pst_time: Some(to_timestamp(line.pst_time)),
+            pst_type: proto::PostingType::from(line.pst_type).into(),
+            pst_status: proto::PostingStatus::from(line.pst_status).into(),
+            hash: line.hash.map(Vec::from),
+            additional_information: line.additional_information,
+            discarded_time: line.discarded_time.map(to_timestamp),
+            currency: line.currency,
+            fx: line.fx.map(|fx| proto::FxDetails { base_currency: fx.base_currency, rate: fx.rate.to_string() }),
+            category: line.category,
+        }
+    }
+}
+
+impl From<Posting> for proto::Posting {
+    fn from(posting: Posting) -> Self {
+        Self {
+            id: posting.id.to_string(),
+            record_user: posting.record_user.to_vec(),
+            record_time: Some(to_timestamp(posting.record_time)),
+            opr_id: posting.opr_id.to_vec(),
+            opr_time: Some(to_timestamp(posting.opr_time)),
+            opr_type: posting.opr_type.to_vec(),
+            opr_details: posting.opr_details.map(Vec::from),
+            opr_src: posting.opr_src.map(Vec::from),
+            pst_time: Some(to_timestamp(posting.pst_time)),
+            pst_type: proto::PostingType::from(posting.pst_type).into(),
+            pst_status: proto::PostingStatus::from(posting.pst_status).into(),
+            ledger_id: posting.ledger.id.to_string(),
+            val_time: posting.val_time.map(to_timestamp),
+            lines: posting.lines.into_iter().map(Into::into).collect(),
+            discarded_id: posting.discarded_id.map(|id| id.to_string()),
+            discarded_time: posting.discarded_time.map(to_timestamp),
+            discarding_id: posting.discarding_id.map(|id| id.to_string()),
+            antecedent_id: posting.hash_record.antecedent_id.map(|id| id.to_string()),
+            antecedent_hash: posting.hash_record.antecedent_hash.map(Vec::from),
+            hash: posting.hash_record.hash.map(Vec::from),
+        }
+    }
+}
+
+impl From<AccountStmt> for proto::AccountStmt {
+    fn from(stmt: AccountStmt) -> Self {
+        Self {
+            id: stmt.financial_stmt.id.to_string(),
+            account_id: stmt.account.id.to_string(),
+            posting_id: stmt.financial_stmt.posting.map(|p| p.id.to_string()),
+            pst_time: Some(to_timestamp(stmt.financial_stmt.pst_time)),
+            stmt_status: proto::StmtStatus::from(stmt.financial_stmt.stmt_status).into(),
+            stmt_seq_nbr: stmt.financial_stmt.stmt_seq_nbr,
+            total_debit: stmt.total_debit.to_string(),
+            total_credit: stmt.total_credit.to_string(),
+            opening_debit: stmt.opening_debit.to_string(),
+            opening_credit: stmt.opening_credit.to_string(),
+            line_count: stmt.line_count,
+            currency_totals: stmt.currency_totals.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<CurrencyTotal> for proto::CurrencyTotal {
+    fn from(total: CurrencyTotal) -> Self {
+        Self { currency: total.currency, total_debit: total.total_debit.to_string(), total_credit: total.total_credit.to_string() }
+    }
+}
+
+/// Line of a posting request, its account resolved by the caller.
+pub fn line_from_proto(line: proto::PostingLine, account: LedgerAccount) -> Result<PostingLine, Status> {
+    Ok(PostingLine {
+        id: parse_assigned_uuid(&line.id, "line id")?,
+        account,
+        debit_amount: parse_decimal(&line.debit_amount, "debit_amount")?,
+        credit_amount: parse_decimal(&line.credit_amount, "credit_amount")?,
+        details: parse_optional_hash(line.details, "details")?,
+        src_account: parse_optional_hash(line.src_account, "src_account")?,
+        base_line: parse_optional_uuid(line.base_line, "base_line")?,
+        sub_opr_src_id: parse_optional_hash(line.sub_opr_src_id, "sub_opr_src_id")?,
+        record_time: from_optional_timestamp(line.record_time, "record_time")?.unwrap_or_else(Utc::now),
+        opr_id: if line.opr_id.is_empty() { [0; 34] } else { parse_hash(line.opr_id, "line opr_id")? },
+        opr_src: parse_optional_hash(line.opr_src, "line opr_src")?,
+        pst_time: from_optional_timestamp(line.pst_time, "line pst_time")?.unwrap_or_else(Utc::now),
+        pst_type: posting_type_from_proto(line.pst_type).unwrap_or(PostingType::BusiTx),
+        pst_status: posting_status_from_proto(line.pst_status).unwrap_or(PostingStatus::Posted),
+        hash: None,
+        additional_information: line.additional_information,
+        discarded_time: None,
+        currency: line.currency,
+        fx: line.fx
+            .map(|fx| Ok::<_, Status>(FxDetails { rate: parse_decimal(&fx.rate, "fx rate")?, base_currency: fx.base_currency }))
+            .transpose()?,
+        category: None,
+    })
+}
+
+/// Posting request, its ledger and line accounts resolved by the caller. Accounts are looked
+/// up in `accounts` by the `account_id` of each line.
+pub fn posting_from_proto(posting: proto::Posting, ledger: Ledger, accounts: &HashMap<Uuid, LedgerAccount>) -> Result<Posting, Status> {
+    let lines = posting.lines
+        .into_iter()
+        .map(|line| {
+            let account_id = parse_uuid(&line.account_id, "account_id")?;
+            let account = accounts
+                .get(&account_id)
+                .cloned()
+                .ok_or_else(|| Status::not_found(format!("Ledger account {account_id} not found")))?;
+            line_from_proto(line, account)
+        })
+        .collect::<Result<Vec<_>, Status>>()?;
+    Ok(Posting {
+        id: parse_assigned_uuid(&posting.id, "id")?,
+        record_user: parse_hash(posting.record_user, "record_user")?,
+        record_time: from_optional_timestamp(posting.record_time, "record_time")?.unwrap_or_else(Utc::now),
+        opr_id: parse_hash(posting.opr_id, "opr_id")?,
+        opr_time: from_timestamp(posting.opr_time, "opr_time")?,
+        opr_type: parse_hash(posting.opr_type, "opr_type")?,
+        opr_details: parse_optional_hash(posting.opr_details, "opr_details")?,
+        opr_src: parse_optional_hash(posting.opr_src, "opr_src")?,
+        pst_time: from_timestamp(posting.pst_time, "pst_time")?,
+        pst_type: posting_type_from_proto(posting.pst_type)?,
+        pst_status: posting_status_from_proto(posting.pst_status)?,
+        ledger,
+        val_time: from_optional_timestamp(posting.val_time, "val_time")?,
+        lines,
+        discarded_id: parse_optional_uuid(posting.discarded_id, "discarded_id")?,
+        discarded_time: None,
+        discarding_id: None,
+        hash_record: HashRecord::default(),
+    })
+}
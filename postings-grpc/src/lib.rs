@@ -0,0 +1,19 @@
+//! gRPC services over the service traits, defined in `proto/postings.proto`. Register them
+//! with a tonic server of the host application:
+//!
+//! ```ignore
+//! Server::builder()
+//!     .add_service(PostingServiceServer::new(GrpcPostingService::new(posting_service, ledger_service.clone())))
+//!     .add_service(LedgerAccountServiceServer::new(GrpcLedgerAccountService::new(ledger_service.clone())))
+//!     .add_service(AccountStmtServiceServer::new(GrpcAccountStmtService::new(stmt_service, ledger_service)))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+pub mod convert;
+pub mod server;
+pub mod status;
+
+pub mod proto {
+    tonic::include_proto!("postings.v1");
+}
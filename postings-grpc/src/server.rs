@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::posting_service::PostingService;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::convert::{from_timestamp, parse_hash, parse_uuid, posting_from_proto};
+use crate::proto;
+use crate::status::to_status;
+
+async fn load_ledger_account(ledger_service: &(dyn LedgerService + Send + Sync), account_id: Uuid) -> Result<LedgerAccount, Status> {
+    ledger_service
+        .find_ledger_account_by_id(account_id)
+        .await
+        .map_err(to_status)?
+        .ok_or_else(|| Status::not_found("Ledger account not found"))
+}
+
+/// Records postings. Ledgers and line accounts are referenced by id and resolved through the
+/// ledger service.
+pub struct GrpcPostingService {
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+    ledger_service: Arc<dyn LedgerService + Send + Sync>,
+}
+
+impl GrpcPostingService {
+    pub fn new(posting_service: Arc<dyn PostingService + Send + Sync>, ledger_service: Arc<dyn LedgerService + Send + Sync>) -> Self {
+        Self { posting_service, ledger_service }
+    }
+}
+
+#[tonic::async_trait]
+impl proto::posting_service_server::PostingService for GrpcPostingService {
+    async fn new_posting(&self, request: Request<proto::NewPostingRequest>) -> Result<Response<proto::Posting>, Status> {
+        let posting = request
+            .into_inner()
+            .posting
+            .ok_or_else(|| Status::invalid_argument("posting is missing"))?;
+        let ledger_id = parse_uuid(&posting.ledger_id, "ledger_id")?;
+        let ledger = self.ledger_service
+            .find_ledger_by_id(ledger_id)
+            .await
+            .map_err(to_status)?
+            .ok_or_else(|| Status::not_found("Ledger not found"))?;
+        let mut accounts = HashMap::new();
+        for line in &posting.lines {
+            let account_id = parse_uuid(&line.account_id, "account_id")?;
+            if !accounts.contains_key(&account_id) {
+                accounts.insert(account_id, load_ledger_account(self.ledger_service.as_ref(), account_id).await?);
+            }
+        }
+        let posting = posting_from_proto(posting, ledger, &accounts)?;
+        let recorded = self.posting_service.new_posting(posting).await.map_err(to_status)?;
+        Ok(Response::new(recorded.into()))
+    }
+
+    async fn find_postings_by_operation(&self, request: Request<proto::FindPostingsByOperationRequest>) -> Result<Response<proto::FindPostingsResponse>, Status> {
+        let opr_id = parse_hash(request.into_inner().opr_id, "opr_id")?;
+        let postings = self.posting_service
+            .find_postings_by_operation_id(&opr_id)
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(proto::FindPostingsResponse { postings: postings.into_iter().map(Into::into).collect() }))
+    }
+}
+
+pub struct GrpcLedgerAccountService {
+    ledger_service: Arc<dyn LedgerService + Send + Sync>,
+}
+
+impl GrpcLedgerAccountService {
+    pub fn new(ledger_service: Arc<dyn LedgerService + Send + Sync>) -> Self {
+        Self { ledger_service }
+    }
+}
+
+#[tonic::async_trait]
+impl proto::ledger_account_service_server::LedgerAccountService for GrpcLedgerAccountService {
+    async fn find_ledger_account(&self, request: Request<proto::FindLedgerAccountRequest>) -> Result<Response<proto::LedgerAccount>, Status> {
+        let account_id = parse_uuid(&request.into_inner().id, "id")?;
+        let account = load_ledger_account(self.ledger_service.as_ref(), account_id).await?;
+        Ok(Response::new(account.into()))
+    }
+}
+
+pub struct GrpcAccountStmtService {
+    stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+    ledger_service: Arc<dyn LedgerService + Send + Sync>,
+}
+
+impl GrpcAccountStmtService {
+    pub fn new(stmt_service: Arc<dyn AccountStmtService + Send + Sync>, ledger_service: Arc<dyn LedgerService + Send + Sync>) -> Self {
+        Self { stmt_service, ledger_service }
+    }
+}
+
+#[tonic::async_trait]
+impl proto::account_stmt_service_server::AccountStmtService for GrpcAccountStmtService {
+    async fn read_stmt(&self, request: Request<proto::StmtRequest>) -> Result<Response<proto::AccountStmt>, Status> {
+        let request = request.into_inner();
+        let account = load_ledger_account(self.ledger_service.as_ref(), parse_uuid(&request.account_id, "account_id")?).await?;
+        let ref_time = from_timestamp(request.ref_time, "ref_time")?;
+        let stmt = self.stmt_service.read_stmt(account, ref_time).await.map_err(to_status)?;
+        Ok(Response::new(stmt.into()))
+    }
+
+    async fn create_stmt(&self, request: Request<proto::StmtRequest>) -> Result<Response<proto::AccountStmt>, Status> {
+        let request = request.into_inner();
+        let account = load_ledger_account(self.ledger_service.as_ref(), parse_uuid(&request.account_id, "account_id")?).await?;
+        let ref_time = from_timestamp(request.ref_time, "ref_time")?;
+        let stmt = self.stmt_service.create_stmt(account, ref_time).await.map_err(to_status)?;
+        Ok(Response::new(stmt.into()))
+    }
+}
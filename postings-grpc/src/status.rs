@@ -0,0 +1,40 @@
+use postings_api::ServiceError;
+use tonic::{Code, Status};
+
+/// Code of a service error. Matched without a wildcard, so a new variant has to be given a
+/// code here.
+pub fn code_of(error: &ServiceError) -> Code {
+    use ServiceError::*;
+    match error {
+        Db | ObjectStore | ExportFailed | EventPublication | UnknownHashAlgorithm { .. } => Code::Internal,
+        NotEnoughInfo | ChartOfAccountMismatch | DoubledEntryViolation { .. } | BaselineTime | PostingTimeMissing
+        | NoCategory | InvalidImportRecord { .. } | CurrencyMismatch => Code::InvalidArgument,
+        ChartOfAccountNotFound | LedgerAccountNotFound | LedgerNotFound | PostingNotFound
+        | PostingLineNotFound | StatementNotFound | StatementNotSigned | BatchNotFound | EarmarkNotFound
+        | StandingOrderNotFound | ScheduledPostingNotFound | EscrowNotFound | QuarantinedEntryNotFound
+        | ExternalContentNotFound | ProductNotFound | CategoryRuleNotFound
+        | PreparedPostingNotFound | ApiKeyNotFound | BackfillTaskNotFound
+        | BackfillJobNotFound | ReportScheduleNotFound | ReportArtifactNotFound
+        | AccountGroupNotFound | AccountNameNotFound => Code::NotFound,
+        AccountGroupNameTaken | DuplicateOperation { .. } | ExternalRefTaken | AccountNameTaken => Code::AlreadyExists,
+        StatementAlreadyClosed | StatementNotClosed | BatchStatusInvalid | PostingAlreadyBatched | ReversalNotBatchable
+        | StatementPeriodOverlap | PeriodClosed | RevaluationSuperseded | EarmarkNotActive | StandingOrderNotActive | ScheduledPostingNotActive | EscrowAlreadyResolved | QuarantinedEntryResolved
+        | ReadOnly | PreparedPostingResolved | PreparedPostingExpired | PostingAlreadyDiscarded
+        | BatchControlMismatch | EarmarkAmountExceeded | InsufficientAvailableBalance
+        | ExternalContentHashMismatch | ReportArtifactHashMismatch | EventLogSegmentHashMismatch | ReversalWindowExpired
+        | LimitExceeded { .. } | FxRateNotFound { .. } => Code::FailedPrecondition,
+        ApiKeyInvalid => Code::Unauthenticated,
+        Forbidden => Code::PermissionDenied,
+        QuotaExceeded { .. } => Code::ResourceExhausted,
+        StmtJobsDisabled | StmtAnnotationsDisabled | SignerNotConfigured | TwoPhaseDisabled
+        | ReportRendererNotConfigured => Code::Unimplemented,
+    }
+}
+
+pub fn to_status(error: ServiceError) -> Status {
+    let code = code_of(&error);
+    if code == Code::Internal {
+        log::error!("Call failed: {error:?}");
+    }
+    Status::new(code, error.to_string())
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use chrono::Utc;
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_grpc::convert::to_timestamp;
+use postings_grpc::proto;
+use postings_grpc::proto::account_stmt_service_server::AccountStmtService as _;
+use postings_grpc::proto::ledger_account_service_server::LedgerAccountService as _;
+use postings_grpc::proto::posting_service_server::PostingService as _;
+use postings_grpc::server::{GrpcAccountStmtService, GrpcLedgerAccountService, GrpcPostingService};
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+use postings_logic::services::ledger_service::LedgerServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use tonic::{Code, Request};
+use uuid::Uuid;
+
+fn create_shared() -> SharedService {
+    let store = Arc::new(InMemoryStore::new());
+    SharedService::new(
+        Arc::new(InMemoryChartOfAccountRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerAccountRepository::new(store.clone())),
+        Arc::new(InMemoryNamedRepository::new(store.clone())),
+        Arc::new(InMemoryPostingRepository::new(store.clone())),
+        Arc::new(InMemoryAccountStmtRepository::new(store.clone())),
+        Arc::new(InMemoryPostingLineRepository::new(store.clone())),
+        Arc::new(InMemoryPostingTraceRepository::new(store)),
+    )
+}
+
+fn create_ledger_service(shared: &SharedService) -> Arc<LedgerServiceImpl> {
+    Arc::new(LedgerServiceImpl::new(shared.clone(), ChartOfAccountServiceImpl::new(shared.clone())))
+}
+
+async fn setup_account(shared: &SharedService, ledger_service: &LedgerServiceImpl) -> LedgerAccount {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    ChartOfAccountServiceImpl::new(shared.clone()).new_chart_of_account(coa.clone(), vec![]).await.unwrap();
+    let (ledger, _) = ledger_service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await.unwrap();
+    let account = LedgerAccount {
+        id: Uuid::new_v4(),
+        ledger,
+        parent: None,
+        coa,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: Some("EUR".to_string()),
+    };
+    ledger_service.new_ledger_account(account, vec![]).await.unwrap().0
+}
+
+#[tokio::test]
+async fn test_ledger_account_is_returned_by_id() {
+    let shared = create_shared();
+    let ledger_service = create_ledger_service(&shared);
+    let account = setup_account(&shared, &ledger_service).await;
+    let service = GrpcLedgerAccountService::new(ledger_service);
+
+    let found = service
+        .find_ledger_account(Request::new(proto::FindLedgerAccountRequest { id: account.id.to_string() }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(found.ledger_id, account.ledger.id.to_string());
+    assert_eq!(found.category(), proto::AccountCategory::As);
+    assert_eq!(found.currency.as_deref(), Some("EUR"));
+
+    let missing = service.find_ledger_account(Request::new(proto::FindLedgerAccountRequest { id: Uuid::new_v4().to_string() })).await;
+    assert_eq!(missing.unwrap_err().code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_malformed_requests_are_invalid_arguments() {
+    let shared = create_shared();
+    let ledger_service = create_ledger_service(&shared);
+    let postings = GrpcPostingService::new(Arc::new(PostingServiceImpl::new(shared.clone())), ledger_service.clone());
+    let stmts = GrpcAccountStmtService::new(Arc::new(AccountStmtServiceImpl::new(shared.clone())), ledger_service);
+
+    let status = postings.new_posting(Request::new(proto::NewPostingRequest { posting: None })).await.unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    let status = postings.find_postings_by_operation(Request::new(proto::FindPostingsByOperationRequest { opr_id: vec![1, 2] })).await.unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    let request = proto::StmtRequest { account_id: "not a uuid".to_string(), ref_time: Some(to_timestamp(Utc::now())) };
+    assert_eq!(stmts.read_stmt(Request::new(request)).await.unwrap_err().code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_stmt_of_empty_account_has_zero_totals() {
+    let shared = create_shared();
+    let ledger_service = create_ledger_service(&shared);
+    let account = setup_account(&shared, &ledger_service).await;
+    let stmts = GrpcAccountStmtService::new(Arc::new(AccountStmtServiceImpl::new(shared.clone())), ledger_service);
+
+    let request = proto::StmtRequest { account_id: account.id.to_string(), ref_time: Some(to_timestamp(Utc::now())) };
+    let stmt = stmts.read_stmt(Request::new(request)).await.unwrap().into_inner();
+    assert_eq!(stmt.account_id, account.id.to_string());
+    assert_eq!(stmt.stmt_status(), proto::StmtStatus::Simulated);
+    assert_eq!(stmt.line_count, 0);
+}
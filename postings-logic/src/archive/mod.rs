@@ -0,0 +1 @@
+pub mod posting_line_repository;
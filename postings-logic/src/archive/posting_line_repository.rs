@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use futures::future;
+use futures::stream::{self, BoxStream, StreamExt};
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use uuid::Uuid;
+
+/// Reads posting lines from the hot store and, for periods reaching before `archived_before`,
+/// also from the archive store. Results of both tiers are merged and deduplicated by line id,
+/// so lines that are being moved to the archive are never lost or returned twice.
+/// New lines are always written to the hot store.
+pub struct TieredPostingLineRepository {
+    hot: Arc<dyn PostingLineRepository + Send + Sync>,
+    archive: Arc<dyn PostingLineRepository + Send + Sync>,
+    archived_before: DateTime<Utc>,
+}
+
+impl TieredPostingLineRepository {
+    pub fn new(
+        hot: Arc<dyn PostingLineRepository + Send + Sync>,
+        archive: Arc<dyn PostingLineRepository + Send + Sync>,
+        archived_before: DateTime<Utc>,
+    ) -> Self {
+        Self { hot, archive, archived_before }
+    }
+
+    fn reaches_archive(&self, from: Option<DateTime<Utc>>) -> bool {
+        from.is_none_or(|from| from < self.archived_before)
+    }
+
+    fn merge(hot: Vec<PostingLine>, archived: Vec<PostingLine>) -> Vec<PostingLine> {
+        let mut seen = HashSet::with_capacity(hot.len() + archived.len());
+        hot.into_iter().chain(archived).filter(|l| seen.insert(l.id)).collect()
+    }
+
+    /// Merges two streams read in `order`, dropping archived lines already read from the hot store.
+    /// Only the ids of the lines read so far are kept in memory.
+    ///
+    /// In [`LineOrder::PostingTime`] the tiers are interleaved so the result keeps the order. The
+    /// value time is not part of the line, so in [`LineOrder::ValueTime`] archived lines, which
+    /// are the older ones, are returned before hot lines and each tier keeps its own order.
+    fn merge_streams<'a>(
+        hot: BoxStream<'a, Result<PostingLine, DbError>>,
+        archived: BoxStream<'a, Result<PostingLine, DbError>>,
+        order: LineOrder,
+    ) -> BoxStream<'a, Result<PostingLine, DbError>> {
+        let mut seen = HashSet::new();
+        let merged = match order {
+            LineOrder::PostingTime => Self::interleave(hot, archived),
+            LineOrder::ValueTime => archived.chain(hot).boxed(),
+        };
+        merged
+            .filter(move |line| future::ready(match line {
+                Ok(l) => seen.insert(l.id),
+                Err(_) => true,
+            }))
+            .boxed()
+    }
+
+    /// Sorted merge of two streams in [`LineOrder::PostingTime`]. Errors are passed on first.
+    fn interleave<'a>(
+        hot: BoxStream<'a, Result<PostingLine, DbError>>,
+        archived: BoxStream<'a, Result<PostingLine, DbError>>,
+    ) -> BoxStream<'a, Result<PostingLine, DbError>> {
+        stream::unfold((hot.peekable(), archived.peekable()), |(mut hot, mut archived)| async move {
+            let take_hot = match (Pin::new(&mut hot).peek().await, Pin::new(&mut archived).peek().await) {
+                (None, None) => return None,
+                (Some(_), None) | (Some(Err(_)), _) => true,
+                (None, Some(_)) | (_, Some(Err(_))) => false,
+                (Some(Ok(h)), Some(Ok(a))) => posting_time_key(h) <= posting_time_key(a),
+            };
+            let next = if take_hot { hot.next().await } else { archived.next().await };
+            next.map(|line| (line, (hot, archived)))
+        })
+        .boxed()
+    }
+}
+
+fn posting_time_key(line: &PostingLine) -> (DateTime<Utc>, DateTime<Utc>, Uuid) {
+    (line.pst_time, line.record_time, line.id)
+}
+
+#[async_trait]
+impl PostingLineRepository for TieredPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        self.hot.save(posting_line).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        match self.hot.find_by_id(id).await? {
+            Some(line) => Ok(Some(line)),
+            None => self.archive.find_by_id(id).await,
+        }
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let hot = self.hot.find_by_account_and_pst_time_between(account_id, from, to).await?;
+        if !self.reaches_archive(Some(from)) {
+            return Ok(hot);
+        }
+        let archived = self.archive.find_by_account_and_pst_time_between(account_id, from, to).await?;
+        let mut lines = Self::merge(hot, archived);
+        lines.sort_by(|a, b| posting_time_key(b).cmp(&posting_time_key(a)));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        if !self.reaches_archive(Some(from)) {
+            return self.hot.find_by_account_and_pst_time_between_paged(account_id, from, to, page).await;
+        }
+        // Offsets cannot be split across tiers, the merged period is paged in memory
+        let mut lines = self.find_by_account_and_pst_time_between(account_id, from, to).await?;
+        Ok(Page::from_all(lines, page))
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        match self.hot.find_by_id_and_account_id(id, account_id).await? {
+            Some(line) => Ok(Some(line)),
+            None => self.archive.find_by_id_and_account_id(id, account_id).await,
+        }
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let hot = self.hot.find_by_base_line_and_pst_time_less_than_equal(base_line, ref_time).await?;
+        let archived = self.archive.find_by_base_line_and_pst_time_less_than_equal(base_line, ref_time).await?;
+        let mut lines = Self::merge(hot, archived);
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let hot = self.hot.find_by_opr_id(opr_id).await?;
+        let archived = self.archive.find_by_opr_id(opr_id).await?;
+        let mut lines = Self::merge(hot, archived);
+        lines.sort_by(|a, b| a.record_time.cmp(&b.record_time).then(a.id.cmp(&b.id)));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let hot = self.hot.find_by_account_and_pst_time_less_than_equal(account_id, ref_time).await?;
+        let archived = self.archive.find_by_account_and_pst_time_less_than_equal(account_id, ref_time).await?;
+        let mut lines = Self::merge(hot, archived);
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let hot = self.hot.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account_id, ref_time, known_at).await?;
+        let archived = self.archive.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account_id, ref_time, known_at).await?;
+        let mut lines = Self::merge(hot, archived);
+        lines.sort_by(|a, b| b.record_time.cmp(&a.record_time));
+        Ok(lines)
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        let hot = self.hot.find_stats_by_account_id(account_id).await?;
+        let archived = self.archive.find_stats_by_account_id(account_id).await?;
+        // Archived lines were recorded before the hot ones
+        Ok(AccountLineStats {
+            line_count: hot.line_count + archived.line_count,
+            first_pst_time: hot.first_pst_time.into_iter().chain(archived.first_pst_time).min(),
+            last_pst_time: hot.last_pst_time.into_iter().chain(archived.last_pst_time).max(),
+            last_opr_id: hot.last_opr_id.or(archived.last_opr_id),
+        })
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        // Adding up the totals of both tiers would count lines being moved twice, the lines of
+        // each account are merged instead
+        let mut totals = Vec::with_capacity(account_ids.len());
+        for &account_id in account_ids {
+            let lines = self.find_by_account_and_pst_time_less_than_equal(account_id, ref_time).await?;
+            if lines.is_empty() {
+                continue;
+            }
+            let (total_debit, total_credit) = lines
+                .iter()
+                .fold((BigDecimal::from(0), BigDecimal::from(0)), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+            totals.push(AccountLineTotals { account_id, total_debit, total_credit });
+        }
+        Ok(totals)
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let hot = self.hot.stream_by_account_and_pst_time_between(account_id, from, to, order);
+        if !self.reaches_archive(Some(from)) {
+            return hot;
+        }
+        Self::merge_streams(hot, self.archive.stream_by_account_and_pst_time_between(account_id, from, to, order), order)
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        let hot = self.hot.stream_by_account_and_pst_time_less_than_equal(account_id, ref_time, order);
+        Self::merge_streams(hot, self.archive.stream_by_account_and_pst_time_less_than_equal(account_id, ref_time, order), order)
+    }
+}
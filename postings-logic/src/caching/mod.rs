@@ -1 +1,2 @@
 pub mod chart_of_account_repository;
+pub mod report_cache;
@@ -0,0 +1,115 @@
+use std::future::Future;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_event::LedgerEvent;
+use postings_api::domain::trial_balance::{TrialBalance, TrialBalanceDrillDown};
+use postings_api::service::ledger_event_service::LedgerEventSink;
+use postings_api::service::trial_balance_service::TrialBalanceService;
+use postings_api::ServiceError;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Identifies a computed report.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReportKey {
+    pub report_type: &'static str,
+    pub ledger_id: Uuid,
+    /// Start of the period, `None` for reports reaching back to the first posting.
+    pub from: Option<DateTime<Utc>>,
+    pub to: DateTime<Utc>,
+    /// Further parameters of the report in a canonical text form, empty if there are none.
+    pub params: String,
+}
+
+/// Columns of a `posting` change event the cache needs.
+#[derive(Deserialize)]
+struct PostingChange {
+    ledger_id: Uuid,
+    pst_time: DateTime<Utc>,
+}
+
+/// Computed reports, dropped when a posting of their ledger at or before the end of their period
+/// is recorded or changed. Feed it the change events through [`LedgerEventSink::publish`], e.g.
+/// from the poller of [`postings_api::service::ledger_event_service::LedgerEventService`].
+///
+/// Reports of closed periods stay cached until a back-dated posting reaches into them. A report
+/// computed while an invalidating posting commits may be cached before its event arrives, so the
+/// cache is meant for periods no longer posted to as a matter of course.
+pub struct ReportCache<T> {
+    cache: Cache<ReportKey, T>,
+}
+
+impl<T: Clone + Send + Sync + 'static> ReportCache<T> {
+    pub fn new(max_capacity: u64) -> Self {
+        Self {
+            cache: Cache::builder()
+                .max_capacity(max_capacity)
+                .support_invalidation_closures()
+                .build(),
+        }
+    }
+
+    /// The cached report, or the one computed by `compute`, which is then cached. Errors are not cached.
+    pub async fn get_or_compute(&self, key: ReportKey, compute: impl Future<Output = Result<T, ServiceError>>) -> Result<T, ServiceError> {
+        if let Some(report) = self.cache.get(&key).await {
+            return Ok(report);
+        }
+        let report = compute.await?;
+        self.cache.insert(key, report.clone()).await;
+        Ok(report)
+    }
+
+    /// Drops the reports of the ledger a posting at `pst_time` changes; all of its reports when
+    /// the time is not known.
+    pub fn invalidate(&self, ledger_id: Uuid, pst_time: Option<DateTime<Utc>>) {
+        let result = self.cache.invalidate_entries_if(move |key, _| {
+            key.ledger_id == ledger_id && pst_time.map_or(true, |pst_time| pst_time <= key.to)
+        });
+        // Only fails when closures are not supported, which the builder enables
+        if let Err(e) = result {
+            log::error!("Report cache invalidation failed: {e:?}");
+            self.cache.invalidate_all();
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> LedgerEventSink for ReportCache<T> {
+    async fn publish(&self, events: &[LedgerEvent]) -> Result<(), ServiceError> {
+        for event in events.iter().filter(|e| e.entity_type == "posting") {
+            match serde_json::from_str::<PostingChange>(&event.payload) {
+                Ok(change) => self.invalidate(change.ledger_id, Some(change.pst_time)),
+                // A payload the cache cannot read must not leave stale reports behind
+                Err(_) => self.cache.invalidate_all(),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serves trial balances from a [`ReportCache`]. Drill-downs are paged views and always read through.
+pub struct CachingTrialBalanceService {
+    inner: Arc<dyn TrialBalanceService + Send + Sync>,
+    cache: Arc<ReportCache<TrialBalance>>,
+}
+
+impl CachingTrialBalanceService {
+    pub fn new(inner: Arc<dyn TrialBalanceService + Send + Sync>, cache: Arc<ReportCache<TrialBalance>>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl TrialBalanceService for CachingTrialBalanceService {
+    async fn trial_balance(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<TrialBalance, ServiceError> {
+        let key = ReportKey { report_type: "trial_balance", ledger_id: ledger.id, from: None, to: ref_time, params: String::new() };
+        self.cache.get_or_compute(key, self.inner.trial_balance(ledger, ref_time)).await
+    }
+
+    async fn drill_down(&self, trial_balance: &TrialBalance, account_id: Uuid, from: DateTime<Utc>, page: usize, size: usize) -> Result<TrialBalanceDrillDown, ServiceError> {
+        self.inner.drill_down(trial_balance, account_id, from, page, size).await
+    }
+}
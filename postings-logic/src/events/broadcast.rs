@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_api::service::event_publisher::EventPublisher;
+use postings_api::ServiceError;
+use tokio::sync::broadcast;
+
+/// In-process publisher handing every event to all current subscribers. A subscriber lagging more
+/// than `capacity` events behind misses the oldest ones, see [`broadcast::Receiver::recv`].
+pub struct BroadcastEventPublisher {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl BroadcastEventPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Receives the events published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for BroadcastEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ServiceError> {
+        // Sending only fails without subscribers, when nobody is interested in the event
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod broadcast;
+pub mod outbox_relay;
+pub mod event_log_backup;
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use log::{error, info, warn};
+use postings_api::service::event_publisher::EventPublisher;
+use postings_api::ServiceError;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use crate::mappers::outbox_entry::OutboxEntryMapper;
+
+pub const DEFAULT_BATCH_SIZE: i64 = 100;
+
+/// Delivers the events of the outbox to a publisher, oldest first, and marks them delivered.
+///
+/// Delivery is at least once: an event published right before a crash is published again on
+/// restart. A failed event stops its batch, so later events never overtake it; it is retried with
+/// the next batch, its attempts and last error kept on the entry.
+pub struct OutboxRelay {
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    publisher: Arc<dyn EventPublisher + Send + Sync>,
+    batch_size: i64,
+}
+
+impl OutboxRelay {
+    pub fn new(outbox_repo: Arc<dyn OutboxRepository + Send + Sync>, publisher: Arc<dyn EventPublisher + Send + Sync>) -> Self {
+        Self { outbox_repo, publisher, batch_size: DEFAULT_BATCH_SIZE }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Delivers one batch of events. Returns the number of delivered events.
+    pub async fn relay_once(&self) -> Result<u64, ServiceError> {
+        let entries = self.outbox_repo
+            .find_undelivered(self.batch_size)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut delivered = 0;
+        for entry in entries {
+            let failure = match OutboxEntryMapper::to_event(&entry) {
+                Ok(event) => self.publisher.publish(&event).await.err().map(|e| e.to_string()),
+                Err(e) => Some(format!("Undecodable payload: {e}")),
+            };
+            if let Some(failure) = failure {
+                warn!("Delivering outbox entry {} failed: {failure}", entry.id);
+                self.outbox_repo
+                    .record_failure(entry.id, &failure)
+                    .await
+                    .map_err(|_| ServiceError::Db)?;
+                return Err(ServiceError::EventPublication);
+            }
+            self.outbox_repo
+                .mark_delivered(entry.id, Utc::now())
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            delivered += 1;
+        }
+        Ok(delivered)
+    }
+
+    /// Delivers batches until the outbox is drained, then polls it every `interval`.
+    pub async fn run(&self, interval: Duration) {
+        info!("Relaying outbox events");
+        loop {
+            match self.relay_once().await {
+                Ok(0) => tokio::time::sleep(interval).await,
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Relaying outbox events failed: {e:?}");
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use bigdecimal::BigDecimal;
+use futures::future;
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::stream::{BoxStream, StreamExt};
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::stmt_status::StmtStatus;
+use postings_api::ServiceError;
+use postings_db::models::line_order::LineOrder;
+use serde::Serialize;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::services::shared_service::SharedService;
+
+/// One exported line with the account balance after it, on the account's balance side.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportRow {
+    #[serde(flatten)]
+    pub line: PostingLine,
+    pub running_balance: BigDecimal,
+}
+
+/// Layout of an export file. Implement it for formats beyond the built-in ones.
+pub trait ExportFormat: Send + Sync {
+    /// Written before the first record.
+    fn header(&self) -> Option<String> {
+        None
+    }
+
+    /// One record, terminated by a newline.
+    fn record(&self, row: &ExportRow) -> Result<String, ServiceError>;
+}
+
+/// Comma separated values with a header row, quoted where needed.
+pub struct CsvFormat;
+
+impl CsvFormat {
+    pub(crate) fn field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl ExportFormat for CsvFormat {
+    fn header(&self) -> Option<String> {
+        Some("line_id,pst_time,record_time,opr_id,debit_amount,credit_amount,currency,additional_information,running_balance\n".to_string())
+    }
+
+    fn record(&self, row: &ExportRow) -> Result<String, ServiceError> {
+        let line = &row.line;
+        let opr_id: String = line.opr_id.iter().map(|b| format!("{b:02x}")).collect();
+        let fields = [
+            line.id.to_string(),
+            line.pst_time.to_rfc3339(),
+            line.record_time.to_rfc3339(),
+            opr_id,
+            line.debit_amount.to_string(),
+            line.credit_amount.to_string(),
+            line.currency.clone().unwrap_or_default(),
+            line.additional_information.clone().unwrap_or_default(),
+            row.running_balance.to_string(),
+        ];
+        let mut record = fields.iter().map(|f| Self::field(f)).collect::<Vec<_>>().join(",");
+        record.push('\n');
+        Ok(record)
+    }
+}
+
+/// One JSON object per line, the posting line as serialized by the API plus `running_balance`.
+pub struct JsonLinesFormat;
+
+impl ExportFormat for JsonLinesFormat {
+    fn record(&self, row: &ExportRow) -> Result<String, ServiceError> {
+        let mut record = serde_json::to_string(row).map_err(|_| ServiceError::ExportFailed)?;
+        record.push('\n');
+        Ok(record)
+    }
+}
+
+/// Writes the lines of a closed statement as a flat file, for reconciliation. Lines are streamed
+/// from the repository as they are written, so large statements are never held in memory.
+pub struct AccountStmtExporter {
+    shared: SharedService,
+    line_order: LineOrder,
+}
+
+impl AccountStmtExporter {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared, line_order: LineOrder::default() }
+    }
+
+    /// Order of the exported lines; use the order the statements of the deployment are built in.
+    pub fn with_line_order(mut self, line_order: LineOrder) -> Self {
+        self.line_order = line_order;
+        self
+    }
+
+    /// Lines added by `stmt` since the previous closed statement, each with the running balance
+    /// starting from the statement's opening balance, streamed in the configured order.
+    pub async fn rows<'a>(&'a self, stmt: &'a AccountStmt) -> Result<BoxStream<'a, Result<ExportRow, ServiceError>>, ServiceError> {
+        if stmt.financial_stmt.stmt_status != StmtStatus::CLOSED {
+            return Err(ServiceError::StatementNotClosed);
+        }
+        let account = &stmt.account;
+        let previous = self.shared.stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(account.id, stmt.financial_stmt.pst_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let lines = match previous {
+            Some(previous) => self.shared.line_repo
+                .stream_by_account_and_pst_time_between(account.id, previous.pst_time, stmt.financial_stmt.pst_time, self.line_order),
+            None => self.shared.line_repo
+                .stream_by_account_and_pst_time_less_than_equal(account.id, stmt.financial_stmt.pst_time, self.line_order),
+        };
+
+        let opening_balance = side_balance(&account.balance_side, &stmt.opening_debit, &stmt.opening_credit);
+        Ok(lines
+            .scan(opening_balance, move |running_balance, line| {
+                let row = line.map_err(|_| ServiceError::Db).map(|line| {
+                    let line = PostingLineMapper::to_bo(line, account.clone());
+                    *running_balance += side_balance(&account.balance_side, &line.debit_amount, &line.credit_amount);
+                    ExportRow { line, running_balance: running_balance.clone() }
+                });
+                future::ready(Some(row))
+            })
+            .boxed())
+    }
+
+    /// Writes the lines added by `stmt` since the previous closed statement, starting from the
+    /// statement's opening balance. Returns the number of lines written.
+    pub async fn export<W: AsyncWrite + Unpin + Send>(&self, stmt: &AccountStmt, format: &dyn ExportFormat, writer: &mut W) -> Result<u64, ServiceError> {
+        let mut rows = self.rows(stmt).await?;
+        if let Some(header) = format.header() {
+            writer.write_all(header.as_bytes()).await.map_err(|_| ServiceError::ExportFailed)?;
+        }
+        let mut written = 0;
+        while let Some(row) = rows.next().await {
+            let record = format.record(&row?)?;
+            writer.write_all(record.as_bytes()).await.map_err(|_| ServiceError::ExportFailed)?;
+            written += 1;
+        }
+        writer.flush().await.map_err(|_| ServiceError::ExportFailed)?;
+        Ok(written)
+    }
+}
+
+pub(crate) fn side_balance(balance_side: &BalanceSide, debit: &BigDecimal, credit: &BigDecimal) -> BigDecimal {
+    match balance_side {
+        BalanceSide::Cr => credit - debit,
+        _ => debit - credit,
+    }
+}
@@ -0,0 +1,177 @@
+use bigdecimal::BigDecimal;
+use futures::StreamExt;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::ServiceError;
+use postings_db::models::line_order::LineOrder;
+use printpdf::{BuiltinFont, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference};
+use crate::export::account_stmt_exporter::{side_balance, AccountStmtExporter, ExportRow};
+use crate::services::shared_service::SharedService;
+
+const PAGE_WIDTH: Mm = Mm(210.0);
+const PAGE_HEIGHT: Mm = Mm(297.0);
+const MARGIN: f32 = 15.0;
+const LINE_HEIGHT: f32 = 5.0;
+/// Left edges of the date, description, debit, credit and balance columns.
+const COLUMNS: [f32; 5] = [MARGIN, 40.0, 115.0, 140.0, 165.0];
+const DESCRIPTION_CHARS: usize = 40;
+
+/// Renders a closed account statement as an A4 PDF for customers: the account details, every
+/// line added since the previous closed statement with its running balance, and the totals.
+pub struct AccountStmtPdfRenderer {
+    exporter: AccountStmtExporter,
+    title: String,
+}
+
+impl AccountStmtPdfRenderer {
+    pub fn new(shared: SharedService) -> Self {
+        Self { exporter: AccountStmtExporter::new(shared), title: "Account statement".to_string() }
+    }
+
+    /// Order of the listed lines; use the order the statements of the deployment are built in.
+    pub fn with_line_order(mut self, line_order: LineOrder) -> Self {
+        self.exporter = self.exporter.with_line_order(line_order);
+        self
+    }
+
+    /// Heading of the first page and title of the document, "Account statement" by default.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub async fn render(&self, stmt: &AccountStmt) -> Result<Vec<u8>, ServiceError> {
+        let mut rows = self.exporter.rows(stmt).await?;
+        let mut page = PageWriter::new(&self.title)?;
+        page.write_header(&self.title, stmt);
+        page.write_table_heading();
+        while let Some(row) = rows.next().await {
+            page.write_row(&row?);
+        }
+        page.write_totals(stmt);
+        page.doc.save_to_bytes().map_err(|_| ServiceError::ExportFailed)
+    }
+}
+
+/// Writes text top down, starting a new page when the current one is full.
+struct PageWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    font: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32,
+}
+
+impl PageWriter {
+    fn new(title: &str) -> Result<Self, ServiceError> {
+        let (doc, page, layer) = PdfDocument::new(title, PAGE_WIDTH, PAGE_HEIGHT, "Statement");
+        let font = doc.add_builtin_font(BuiltinFont::Helvetica).map_err(|_| ServiceError::ExportFailed)?;
+        let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold).map_err(|_| ServiceError::ExportFailed)?;
+        let layer = doc.get_page(page).get_layer(layer);
+        Ok(Self { doc, layer, font, bold, y: PAGE_HEIGHT.0 - MARGIN })
+    }
+
+    fn text(&mut self, text: &str, size: f32, x: f32, bold: bool) {
+        let font = if bold { &self.bold } else { &self.font };
+        self.layer.use_text(text, size, Mm(x), Mm(self.y), font);
+    }
+
+    fn next_line(&mut self) {
+        self.y -= LINE_HEIGHT;
+    }
+
+    /// Moves to a new page, repeating the table heading, when fewer than `lines` lines fit.
+    fn reserve(&mut self, lines: usize, table: bool) {
+        if self.y - LINE_HEIGHT * lines as f32 >= MARGIN {
+            return;
+        }
+        let (page, layer) = self.doc.add_page(PAGE_WIDTH, PAGE_HEIGHT, "Statement");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT.0 - MARGIN;
+        if table {
+            self.write_table_heading();
+        }
+    }
+
+    fn write_header(&mut self, title: &str, stmt: &AccountStmt) {
+        let account = &stmt.account;
+        self.text(title, 16.0, MARGIN, true);
+        self.y -= 2.0 * LINE_HEIGHT;
+        let mut details = vec![
+            format!("Account: {}", account.id),
+            format!("Ledger: {}", account.ledger.id),
+            format!("Category: {:?}, balance side: {:?}", account.category, account.balance_side),
+        ];
+        if let Some(currency) = &account.currency {
+            details.push(format!("Currency: {currency}"));
+        }
+        details.push(format!("Statement: {} (no. {})", stmt.financial_stmt.id, stmt.financial_stmt.stmt_seq_nbr));
+        details.push(format!("Closed as of: {}", stmt.financial_stmt.pst_time.format("%Y-%m-%d %H:%M UTC")));
+        for detail in details {
+            self.text(&detail, 10.0, MARGIN, false);
+            self.next_line();
+        }
+        let opening = side_balance(&account.balance_side, &stmt.opening_debit, &stmt.opening_credit);
+        self.text(&format!("Opening balance: {opening}"), 10.0, MARGIN, true);
+        self.y -= 2.0 * LINE_HEIGHT;
+    }
+
+    fn write_table_heading(&mut self) {
+        for (heading, x) in ["Date", "Description", "Debit", "Credit", "Balance"].into_iter().zip(COLUMNS) {
+            self.text(heading, 9.0, x, true);
+        }
+        self.next_line();
+    }
+
+    fn write_row(&mut self, row: &ExportRow) {
+        self.reserve(1, true);
+        let line = &row.line;
+        let description = line.additional_information.as_deref().unwrap_or_default();
+        let description: String = if description.chars().count() > DESCRIPTION_CHARS {
+            description.chars().take(DESCRIPTION_CHARS - 3).chain("...".chars()).collect()
+        } else {
+            description.to_string()
+        };
+        let cells = [
+            line.pst_time.format("%Y-%m-%d").to_string(),
+            description,
+            amount(&line.debit_amount),
+            amount(&line.credit_amount),
+            row.running_balance.to_string(),
+        ];
+        for (cell, x) in cells.iter().zip(COLUMNS) {
+            self.text(cell, 9.0, x, false);
+        }
+        self.next_line();
+    }
+
+    fn write_totals(&mut self, stmt: &AccountStmt) {
+        self.reserve(5 + stmt.currency_totals.len(), false);
+        self.next_line();
+        let closing = side_balance(&stmt.account.balance_side, &stmt.total_debit, &stmt.total_credit);
+        let totals = [
+            ("Total debit", stmt.total_debit.to_string()),
+            ("Total credit", stmt.total_credit.to_string()),
+            ("Closing balance", closing.to_string()),
+            ("Lines", stmt.line_count.to_string()),
+        ];
+        for (label, value) in totals {
+            self.text(label, 10.0, MARGIN, true);
+            self.text(&value, 10.0, COLUMNS[2], false);
+            self.next_line();
+        }
+        for total in stmt.currency_totals.iter() {
+            self.text(&format!("{} debit / credit", total.currency), 10.0, MARGIN, false);
+            self.text(&format!("{} / {}", total.total_debit, total.total_credit), 10.0, COLUMNS[2], false);
+            self.next_line();
+        }
+    }
+}
+
+/// Blank for zero, so each line shows its side only.
+fn amount(value: &BigDecimal) -> String {
+    if *value == BigDecimal::from(0) {
+        String::new()
+    } else {
+        value.to_string()
+    }
+}
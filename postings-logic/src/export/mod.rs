@@ -0,0 +1,3 @@
+pub mod account_stmt_exporter;
+#[cfg(feature = "pdf")]
+pub mod account_stmt_pdf;
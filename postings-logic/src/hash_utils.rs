@@ -1,11 +1,66 @@
 use multihash_codetable::{Code, MultihashDigest};
+use postings_api::domain::hash_record::{HashAlgorithm, HashVersion};
+use postings_api::domain::hashing_profile::{hashed_view, stored_view};
+use postings_api::domain::posting::Posting;
 use serde::Serialize;
 
 pub fn hash_serialize<T: Serialize>(item: &T) -> Result<[u8; 34], serde_json::Error> {
+    hash_serialize_with(strategy(HashAlgorithm::Sha256), item)
+}
+
+/// Hashes the serialized form of `item` with `strategy`, e.g. the posting hash with the
+/// function of the ledger's hashing profile.
+pub fn hash_serialize_with<T: Serialize>(strategy: &dyn HashStrategy, item: &T) -> Result<[u8; 34], serde_json::Error> {
     let json = serde_json::to_string(item)?;
-    let hash = Code::Sha2_256.digest(json.as_bytes());
-    let bytes = hash.to_bytes();
-    let mut result = [0u8; 34];
-    result.copy_from_slice(&bytes[..34]);
-    Ok(result)
+    Ok(strategy.digest(json.as_bytes()))
 }
+
+/// Posting hash by the version and function of the posting's hash record.
+pub fn hash_posting(posting: &Posting) -> Result<[u8; 34], serde_json::Error> {
+    let strategy = strategy(posting.hash_record.algorithm);
+    match posting.hash_record.version {
+        HashVersion::Stored => hash_serialize_with(strategy, &stored_view(posting)),
+        HashVersion::Legacy | HashVersion::Canonical => hash_serialize_with(strategy, &hashed_view(posting)),
+    }
+}
+
+/// Hashes raw content (not its serialized form), e.g. externally stored documents.
+pub fn hash_bytes(content: &[u8]) -> [u8; 34] {
+    strategy(HashAlgorithm::Sha256).digest(content)
+}
+
+/// Hash function producing 34-byte multihashes.
+pub trait HashStrategy: Send + Sync {
+    fn algorithm(&self) -> HashAlgorithm;
+    fn digest(&self, content: &[u8]) -> [u8; 34];
+}
+
+/// Multihash of `code`, a function with a 256 bit digest.
+struct MultihashStrategy {
+    algorithm: HashAlgorithm,
+    code: Code,
+}
+
+impl HashStrategy for MultihashStrategy {
+    fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    fn digest(&self, content: &[u8]) -> [u8; 34] {
+        let bytes = self.code.digest(content).to_bytes();
+        let mut result = [0u8; 34];
+        result.copy_from_slice(&bytes[..34]);
+        result
+    }
+}
+
+static SHA2_256: MultihashStrategy = MultihashStrategy { algorithm: HashAlgorithm::Sha256, code: Code::Sha2_256 };
+static BLAKE3: MultihashStrategy = MultihashStrategy { algorithm: HashAlgorithm::Blake3, code: Code::Blake3_256 };
+
+pub fn strategy(algorithm: HashAlgorithm) -> &'static dyn HashStrategy {
+    match algorithm {
+        HashAlgorithm::Sha256 => &SHA2_256,
+        HashAlgorithm::Blake3 => &BLAKE3,
+    }
+}
+
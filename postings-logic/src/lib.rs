@@ -1,4 +1,12 @@
+pub mod archive;
 pub mod caching;
+pub mod events;
+pub mod export;
 pub mod hash_utils;
 pub mod mappers;
+pub mod posting_builder;
+pub mod scoping;
 pub mod services;
+pub mod signing;
+#[cfg(feature = "otel")]
+pub mod telemetry;
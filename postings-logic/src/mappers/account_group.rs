@@ -0,0 +1,47 @@
+use postings_api::domain::account_group::AccountGroup as AccountGroupBO;
+use postings_db::models::account_group::AccountGroup as AccountGroupModel;
+use uuid::Uuid;
+
+pub struct AccountGroupMapper;
+
+impl AccountGroupMapper {
+    pub fn to_bo(model: AccountGroupModel, ledger_bo: postings_api::domain::ledger::Ledger, member_ids: Vec<Uuid>) -> AccountGroupBO {
+        AccountGroupBO {
+            id: model.id,
+            ledger: ledger_bo,
+            name: model.name,
+            category: model.category.map(|category| match category {
+                postings_db::models::account_category::AccountCategory::RE => postings_api::domain::account_category::AccountCategory::RE,
+                postings_db::models::account_category::AccountCategory::EX => postings_api::domain::account_category::AccountCategory::EX,
+                postings_db::models::account_category::AccountCategory::AS => postings_api::domain::account_category::AccountCategory::AS,
+                postings_db::models::account_category::AccountCategory::LI => postings_api::domain::account_category::AccountCategory::LI,
+                postings_db::models::account_category::AccountCategory::EQ => postings_api::domain::account_category::AccountCategory::EQ,
+                postings_db::models::account_category::AccountCategory::NOOP => postings_api::domain::account_category::AccountCategory::NOOP,
+                postings_db::models::account_category::AccountCategory::NORE => postings_api::domain::account_category::AccountCategory::NORE,
+                postings_db::models::account_category::AccountCategory::NOEX => postings_api::domain::account_category::AccountCategory::NOEX,
+            }),
+            member_ids,
+            created: model.created,
+        }
+    }
+
+    /// The group row; members are stored separately.
+    pub fn to_model(bo: AccountGroupBO) -> AccountGroupModel {
+        AccountGroupModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            name: bo.name,
+            category: bo.category.map(|category| match category {
+                postings_api::domain::account_category::AccountCategory::RE => postings_db::models::account_category::AccountCategory::RE,
+                postings_api::domain::account_category::AccountCategory::EX => postings_db::models::account_category::AccountCategory::EX,
+                postings_api::domain::account_category::AccountCategory::AS => postings_db::models::account_category::AccountCategory::AS,
+                postings_api::domain::account_category::AccountCategory::LI => postings_db::models::account_category::AccountCategory::LI,
+                postings_api::domain::account_category::AccountCategory::EQ => postings_db::models::account_category::AccountCategory::EQ,
+                postings_api::domain::account_category::AccountCategory::NOOP => postings_db::models::account_category::AccountCategory::NOOP,
+                postings_api::domain::account_category::AccountCategory::NORE => postings_db::models::account_category::AccountCategory::NORE,
+                postings_api::domain::account_category::AccountCategory::NOEX => postings_db::models::account_category::AccountCategory::NOEX,
+            }),
+            created: bo.created,
+        }
+    }
+}
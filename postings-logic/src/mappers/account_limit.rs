@@ -0,0 +1,26 @@
+use postings_api::domain::account_limit::AccountLimit as AccountLimitBO;
+use postings_db::models::account_limit::AccountLimit as AccountLimitModel;
+
+pub struct AccountLimitMapper;
+
+impl AccountLimitMapper {
+    pub fn to_bo(model: AccountLimitModel, account_bo: postings_api::domain::ledger_account::LedgerAccount) -> AccountLimitBO {
+        AccountLimitBO {
+            account: account_bo,
+            max_debit_balance: model.max_debit_balance,
+            overdraft_limit: model.overdraft_limit,
+            daily_turnover_limit: model.daily_turnover_limit,
+            updated: model.updated,
+        }
+    }
+
+    pub fn to_model(bo: AccountLimitBO) -> AccountLimitModel {
+        AccountLimitModel {
+            account_id: bo.account.id,
+            max_debit_balance: bo.max_debit_balance,
+            overdraft_limit: bo.overdraft_limit,
+            daily_turnover_limit: bo.daily_turnover_limit,
+            updated: bo.updated,
+        }
+    }
+}
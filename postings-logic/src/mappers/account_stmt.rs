@@ -1,6 +1,7 @@
 use postings_api::domain::account_stmt::AccountStmt as AccountStmtBO;
 use postings_db::models::account_stmt::AccountStmt as AccountStmtModel;
 use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::stmt_signature::StmtSignature;
 
 pub struct AccountStmtMapper;
 
@@ -22,10 +23,32 @@ impl AccountStmtMapper {
             youngest_pst: youngest_pst_bo,
             total_debit: model.total_debit,
             total_credit: model.total_credit,
+            opening_debit: model.opening_debit,
+            opening_credit: model.opening_credit,
+            line_count: model.line_count,
+            currency_totals: model
+                .currency_totals
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default(),
+            annotations: Vec::new(),
+            signature: Self::signature_of(&model),
+        }
+    }
+
+    pub fn signature_of(model: &AccountStmtModel) -> Option<StmtSignature> {
+        match (&model.signature, &model.signing_key_id) {
+            (Some(signature), Some(key_id)) => Some(StmtSignature { key_id: key_id.clone(), signature: signature.clone() }),
+            _ => None,
         }
     }
 
     pub fn from_bo(bo: AccountStmtBO) -> AccountStmtModel {
+        let closing_balance = bo.debit_balance();
+        let currency_totals = if bo.currency_totals.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&bo.currency_totals).ok()
+        };
         AccountStmtModel {
             id: bo.financial_stmt.id,
             account_id: bo.account.id,
@@ -40,6 +63,14 @@ impl AccountStmtMapper {
             },
             latest_pst_id: bo.financial_stmt.latest_pst.map(|p| p.id),
             stmt_seq_nbr: bo.financial_stmt.stmt_seq_nbr,
+            expiry: None,
+            closing_balance,
+            opening_debit: bo.opening_debit,
+            opening_credit: bo.opening_credit,
+            line_count: bo.line_count,
+            currency_totals,
+            signing_key_id: bo.signature.as_ref().map(|s| s.key_id.clone()),
+            signature: bo.signature.map(|s| s.signature),
         }
     }
 }
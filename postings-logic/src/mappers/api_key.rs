@@ -0,0 +1,36 @@
+use postings_api::domain::api_key::{ApiKey as ApiKeyBO, ApiRole};
+use postings_db::models::api_key::ApiKey as ApiKeyModel;
+
+pub struct ApiKeyMapper;
+
+impl ApiKeyMapper {
+    pub fn to_bo(model: ApiKeyModel) -> ApiKeyBO {
+        ApiKeyBO {
+            id: model.id,
+            name: model.name,
+            tenant_id: model.tenant_id,
+            ledger_id: model.ledger_id,
+            roles: ApiRole::split(&model.roles),
+            key_prefix: model.key_prefix,
+            key_hash: model.key_hash,
+            created: model.created,
+            expires: model.expires,
+            revoked_time: model.revoked_time,
+        }
+    }
+
+    pub fn to_model(bo: ApiKeyBO) -> ApiKeyModel {
+        ApiKeyModel {
+            id: bo.id,
+            name: bo.name,
+            tenant_id: bo.tenant_id,
+            ledger_id: bo.ledger_id,
+            roles: ApiRole::join(&bo.roles),
+            key_prefix: bo.key_prefix,
+            key_hash: bo.key_hash,
+            created: bo.created,
+            expires: bo.expires,
+            revoked_time: bo.revoked_time,
+        }
+    }
+}
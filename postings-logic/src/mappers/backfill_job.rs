@@ -0,0 +1,49 @@
+use postings_api::domain::backfill::BackfillJob as BackfillJobBO;
+use postings_api::domain::ledger::Ledger;
+use postings_db::models::backfill_job::BackfillJob as BackfillJobModel;
+
+pub struct BackfillJobMapper;
+
+impl BackfillJobMapper {
+    pub fn to_bo(model: BackfillJobModel, ledger: Ledger) -> BackfillJobBO {
+        BackfillJobBO {
+            id: model.id,
+            task_name: model.task_name,
+            ledger,
+            status: match model.status {
+                postings_db::models::backfill_job::BackfillStatus::Running => postings_api::domain::backfill::BackfillStatus::Running,
+                postings_db::models::backfill_job::BackfillStatus::Completed => postings_api::domain::backfill::BackfillStatus::Completed,
+                postings_db::models::backfill_job::BackfillStatus::Failed => postings_api::domain::backfill::BackfillStatus::Failed,
+            },
+            batch_size: model.batch_size as u32,
+            cursor: model.checkpoint,
+            processed: model.processed as u64,
+            total: model.total as u64,
+            error_message: model.error_message,
+            started: model.started,
+            updated: model.updated,
+            finished: model.finished,
+        }
+    }
+
+    pub fn to_model(bo: BackfillJobBO) -> BackfillJobModel {
+        BackfillJobModel {
+            id: bo.id,
+            task_name: bo.task_name,
+            ledger_id: bo.ledger.id,
+            status: match bo.status {
+                postings_api::domain::backfill::BackfillStatus::Running => postings_db::models::backfill_job::BackfillStatus::Running,
+                postings_api::domain::backfill::BackfillStatus::Completed => postings_db::models::backfill_job::BackfillStatus::Completed,
+                postings_api::domain::backfill::BackfillStatus::Failed => postings_db::models::backfill_job::BackfillStatus::Failed,
+            },
+            batch_size: bo.batch_size as i32,
+            checkpoint: bo.cursor,
+            processed: bo.processed as i64,
+            total: bo.total as i64,
+            error_message: bo.error_message,
+            started: bo.started,
+            updated: bo.updated,
+            finished: bo.finished,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use postings_api::domain::category_rule::CategoryRule as CategoryRuleBO;
+use postings_db::models::category_rule::CategoryRule as CategoryRuleModel;
+
+pub struct CategoryRuleMapper;
+
+impl CategoryRuleMapper {
+    pub fn to_bo(model: CategoryRuleModel, ledger_bo: postings_api::domain::ledger::Ledger) -> CategoryRuleBO {
+        CategoryRuleBO {
+            id: model.id,
+            ledger: ledger_bo,
+            category: model.category,
+            priority: model.priority,
+            account_id: model.account_id,
+            details: model.details,
+            src_account: model.src_account,
+            opr_src: model.opr_src,
+            min_amount: model.min_amount,
+            max_amount: model.max_amount,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: CategoryRuleBO) -> CategoryRuleModel {
+        CategoryRuleModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            category: bo.category,
+            priority: bo.priority,
+            account_id: bo.account_id,
+            details: bo.details,
+            src_account: bo.src_account,
+            opr_src: bo.opr_src,
+            min_amount: bo.min_amount,
+            max_amount: bo.max_amount,
+            created: bo.created,
+        }
+    }
+}
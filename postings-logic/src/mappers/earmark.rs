@@ -0,0 +1,46 @@
+use postings_api::domain::earmark::Earmark as EarmarkBO;
+use postings_db::models::earmark::Earmark as EarmarkModel;
+
+pub struct EarmarkMapper;
+
+impl EarmarkMapper {
+    pub fn to_bo(model: EarmarkModel, account_bo: postings_api::domain::ledger_account::LedgerAccount) -> EarmarkBO {
+        EarmarkBO {
+            id: model.id,
+            account: account_bo,
+            amount: model.amount,
+            captured_amount: model.captured_amount,
+            reason: model.reason,
+            created: model.created,
+            expiry: model.expiry,
+            status: match model.status {
+                postings_db::models::earmark::EarmarkStatus::Active => postings_api::domain::earmark::EarmarkStatus::Active,
+                postings_db::models::earmark::EarmarkStatus::Released => postings_api::domain::earmark::EarmarkStatus::Released,
+                postings_db::models::earmark::EarmarkStatus::Consumed => postings_api::domain::earmark::EarmarkStatus::Consumed,
+                postings_db::models::earmark::EarmarkStatus::Expired => postings_api::domain::earmark::EarmarkStatus::Expired,
+            },
+            closed_time: model.closed_time,
+            posting_id: model.posting_id,
+        }
+    }
+
+    pub fn to_model(bo: EarmarkBO) -> EarmarkModel {
+        EarmarkModel {
+            id: bo.id,
+            account_id: bo.account.id,
+            amount: bo.amount,
+            captured_amount: bo.captured_amount,
+            reason: bo.reason,
+            created: bo.created,
+            expiry: bo.expiry,
+            status: match bo.status {
+                postings_api::domain::earmark::EarmarkStatus::Active => postings_db::models::earmark::EarmarkStatus::Active,
+                postings_api::domain::earmark::EarmarkStatus::Released => postings_db::models::earmark::EarmarkStatus::Released,
+                postings_api::domain::earmark::EarmarkStatus::Consumed => postings_db::models::earmark::EarmarkStatus::Consumed,
+                postings_api::domain::earmark::EarmarkStatus::Expired => postings_db::models::earmark::EarmarkStatus::Expired,
+            },
+            closed_time: bo.closed_time,
+            posting_id: bo.posting_id,
+        }
+    }
+}
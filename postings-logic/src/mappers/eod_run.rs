@@ -0,0 +1,69 @@
+use postings_api::domain::eod_run::{EodRun as EodRunBO, EodStepResult as EodStepResultBO};
+use postings_db::models::eod_run::{EodRun as EodRunModel, EodStepResult as EodStepResultModel};
+use uuid::Uuid;
+
+pub struct EodRunMapper;
+
+impl EodRunMapper {
+    pub fn to_bo(model: EodRunModel, ledger_bo: postings_api::domain::ledger::Ledger, steps: Vec<EodStepResultModel>) -> EodRunBO {
+        EodRunBO {
+            id: model.id,
+            ledger: ledger_bo,
+            business_date: model.business_date,
+            status: Self::status_to_bo(model.status),
+            started: model.started,
+            finished: model.finished,
+            steps: steps.into_iter().map(Self::step_to_bo).collect(),
+        }
+    }
+
+    pub fn to_model(bo: EodRunBO) -> EodRunModel {
+        EodRunModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            business_date: bo.business_date,
+            status: Self::status_to_model(bo.status),
+            started: bo.started,
+            finished: bo.finished,
+        }
+    }
+
+    pub fn step_to_bo(model: EodStepResultModel) -> EodStepResultBO {
+        EodStepResultBO {
+            step_seq: model.step_seq,
+            step_name: model.step_name,
+            status: Self::status_to_bo(model.status),
+            message: model.message,
+            started: model.started,
+            finished: model.finished,
+        }
+    }
+
+    pub fn step_to_model(run_id: Uuid, bo: EodStepResultBO) -> EodStepResultModel {
+        EodStepResultModel {
+            run_id,
+            step_seq: bo.step_seq,
+            step_name: bo.step_name,
+            status: Self::status_to_model(bo.status),
+            message: bo.message,
+            started: bo.started,
+            finished: bo.finished,
+        }
+    }
+
+    fn status_to_bo(status: postings_db::models::eod_run::EodStatus) -> postings_api::domain::eod_run::EodStatus {
+        match status {
+            postings_db::models::eod_run::EodStatus::Running => postings_api::domain::eod_run::EodStatus::Running,
+            postings_db::models::eod_run::EodStatus::Completed => postings_api::domain::eod_run::EodStatus::Completed,
+            postings_db::models::eod_run::EodStatus::Failed => postings_api::domain::eod_run::EodStatus::Failed,
+        }
+    }
+
+    fn status_to_model(status: postings_api::domain::eod_run::EodStatus) -> postings_db::models::eod_run::EodStatus {
+        match status {
+            postings_api::domain::eod_run::EodStatus::Running => postings_db::models::eod_run::EodStatus::Running,
+            postings_api::domain::eod_run::EodStatus::Completed => postings_db::models::eod_run::EodStatus::Completed,
+            postings_api::domain::eod_run::EodStatus::Failed => postings_db::models::eod_run::EodStatus::Failed,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use postings_api::domain::escrow::Escrow as EscrowBO;
+use postings_db::models::escrow::Escrow as EscrowModel;
+
+pub struct EscrowMapper;
+
+impl EscrowMapper {
+    pub fn to_bo(
+        model: EscrowModel,
+        ledger_bo: postings_api::domain::ledger::Ledger,
+        source_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+        escrow_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+        target_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+    ) -> EscrowBO {
+        EscrowBO {
+            id: model.id,
+            ledger: ledger_bo,
+            source_account: source_account_bo,
+            escrow_account: escrow_account_bo,
+            target_account: target_account_bo,
+            amount: model.amount,
+            opr_type: model.opr_type,
+            status: match model.status {
+                postings_db::models::escrow::EscrowStatus::Pending => postings_api::domain::escrow::EscrowStatus::Pending,
+                postings_db::models::escrow::EscrowStatus::Released => postings_api::domain::escrow::EscrowStatus::Released,
+                postings_db::models::escrow::EscrowStatus::Returned => postings_api::domain::escrow::EscrowStatus::Returned,
+            },
+            timeout: model.timeout,
+            timeout_action: match model.timeout_action {
+                postings_db::models::escrow::EscrowTimeoutAction::Release => postings_api::domain::escrow::EscrowTimeoutAction::Release,
+                postings_db::models::escrow::EscrowTimeoutAction::Return => postings_api::domain::escrow::EscrowTimeoutAction::Return,
+            },
+            initiation_posting_id: model.initiation_posting_id,
+            resolution_posting_id: model.resolution_posting_id,
+            created: model.created,
+            resolved_time: model.resolved_time,
+        }
+    }
+
+    pub fn to_model(bo: EscrowBO) -> EscrowModel {
+        EscrowModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            source_account_id: bo.source_account.id,
+            escrow_account_id: bo.escrow_account.id,
+            target_account_id: bo.target_account.id,
+            amount: bo.amount,
+            opr_type: bo.opr_type,
+            status: Self::status_to_model(bo.status),
+            timeout: bo.timeout,
+            timeout_action: match bo.timeout_action {
+                postings_api::domain::escrow::EscrowTimeoutAction::Release => postings_db::models::escrow::EscrowTimeoutAction::Release,
+                postings_api::domain::escrow::EscrowTimeoutAction::Return => postings_db::models::escrow::EscrowTimeoutAction::Return,
+            },
+            initiation_posting_id: bo.initiation_posting_id,
+            resolution_posting_id: bo.resolution_posting_id,
+            created: bo.created,
+            resolved_time: bo.resolved_time,
+        }
+    }
+
+    pub fn status_to_model(status: postings_api::domain::escrow::EscrowStatus) -> postings_db::models::escrow::EscrowStatus {
+        match status {
+            postings_api::domain::escrow::EscrowStatus::Pending => postings_db::models::escrow::EscrowStatus::Pending,
+            postings_api::domain::escrow::EscrowStatus::Released => postings_db::models::escrow::EscrowStatus::Released,
+            postings_api::domain::escrow::EscrowStatus::Returned => postings_db::models::escrow::EscrowStatus::Returned,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use postings_api::domain::external_content::ExternalContent as ExternalContentBO;
+use postings_db::models::external_content::ExternalContent as ExternalContentModel;
+
+pub struct ExternalContentMapper;
+
+impl ExternalContentMapper {
+    pub fn to_bo(model: ExternalContentModel) -> ExternalContentBO {
+        ExternalContentBO {
+            content_hash: model.content_hash,
+            object_key: model.object_key,
+            size: model.size,
+            media_type: model.media_type,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: ExternalContentBO) -> ExternalContentModel {
+        ExternalContentModel {
+            content_hash: bo.content_hash,
+            object_key: bo.object_key,
+            size: bo.size,
+            media_type: bo.media_type,
+            created: bo.created,
+        }
+    }
+}
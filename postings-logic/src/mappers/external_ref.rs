@@ -0,0 +1,27 @@
+use postings_api::domain::external_ref::{ExternalRef as ExternalRefBO, ExternalRefEntity as ExternalRefEntityBO};
+use postings_db::models::external_ref::{ExternalRef as ExternalRefModel, ExternalRefEntity as ExternalRefEntityModel};
+
+pub struct ExternalRefMapper;
+
+impl ExternalRefMapper {
+    pub fn to_bo(model: ExternalRefModel) -> ExternalRefBO {
+        ExternalRefBO {
+            entity_type: match model.entity_type {
+                ExternalRefEntityModel::Posting => ExternalRefEntityBO::Posting,
+                ExternalRefEntityModel::LedgerAccount => ExternalRefEntityBO::LedgerAccount,
+            },
+            entity_id: model.entity_id,
+            ledger_id: model.ledger_id,
+            system: model.ref_system,
+            reference: model.reference,
+            created: model.created,
+        }
+    }
+
+    pub fn entity_to_model(entity_type: ExternalRefEntityBO) -> ExternalRefEntityModel {
+        match entity_type {
+            ExternalRefEntityBO::Posting => ExternalRefEntityModel::Posting,
+            ExternalRefEntityBO::LedgerAccount => ExternalRefEntityModel::LedgerAccount,
+        }
+    }
+}
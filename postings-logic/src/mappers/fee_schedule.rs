@@ -0,0 +1,70 @@
+use postings_api::domain::fee_schedule::{FeeSchedule as FeeScheduleBO, FeeTier as FeeTierBO};
+use postings_db::models::fee_schedule::{FeeSchedule as FeeScheduleModel, FeeTier as FeeTierModel};
+use uuid::Uuid;
+
+pub struct FeeScheduleMapper;
+
+impl FeeScheduleMapper {
+    pub fn to_bo(
+        model: FeeScheduleModel,
+        ledger_bo: postings_api::domain::ledger::Ledger,
+        fee_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+        tiers: Vec<FeeTierModel>,
+    ) -> FeeScheduleBO {
+        FeeScheduleBO {
+            id: model.id,
+            ledger: ledger_bo,
+            opr_type: model.opr_type,
+            fee_type: match model.fee_type {
+                postings_db::models::fee_schedule::FeeType::Flat => postings_api::domain::fee_schedule::FeeType::Flat,
+                postings_db::models::fee_schedule::FeeType::Percentage => postings_api::domain::fee_schedule::FeeType::Percentage,
+                postings_db::models::fee_schedule::FeeType::Tiered => postings_api::domain::fee_schedule::FeeType::Tiered,
+            },
+            flat_amount: model.flat_amount,
+            rate: model.rate,
+            tiers: tiers.into_iter().map(Self::tier_to_bo).collect(),
+            min_fee: model.min_fee,
+            max_fee: model.max_fee,
+            fee_account: fee_account_bo,
+            product_id: model.product_id,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: FeeScheduleBO) -> FeeScheduleModel {
+        FeeScheduleModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            opr_type: bo.opr_type,
+            fee_type: match bo.fee_type {
+                postings_api::domain::fee_schedule::FeeType::Flat => postings_db::models::fee_schedule::FeeType::Flat,
+                postings_api::domain::fee_schedule::FeeType::Percentage => postings_db::models::fee_schedule::FeeType::Percentage,
+                postings_api::domain::fee_schedule::FeeType::Tiered => postings_db::models::fee_schedule::FeeType::Tiered,
+            },
+            flat_amount: bo.flat_amount,
+            rate: bo.rate,
+            min_fee: bo.min_fee,
+            max_fee: bo.max_fee,
+            fee_account_id: bo.fee_account.id,
+            product_id: bo.product_id,
+            created: bo.created,
+        }
+    }
+
+    pub fn tier_to_bo(model: FeeTierModel) -> FeeTierBO {
+        FeeTierBO {
+            from_amount: model.from_amount,
+            flat_amount: model.flat_amount,
+            rate: model.rate,
+        }
+    }
+
+    pub fn tier_to_model(schedule_id: Uuid, bo: FeeTierBO) -> FeeTierModel {
+        FeeTierModel {
+            schedule_id,
+            from_amount: bo.from_amount,
+            flat_amount: bo.flat_amount,
+            rate: bo.rate,
+        }
+    }
+}
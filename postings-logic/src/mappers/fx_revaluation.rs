@@ -0,0 +1,38 @@
+use postings_api::domain::fx_revaluation::AccountRevaluation;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_db::models::fx_revaluation::FxRevaluation as FxRevaluationModel;
+
+pub struct FxRevaluationMapper;
+
+impl FxRevaluationMapper {
+    pub fn to_bo(model: FxRevaluationModel, account: LedgerAccountBO) -> AccountRevaluation {
+        AccountRevaluation {
+            id: model.id,
+            account,
+            currency: model.currency,
+            valuation_time: model.valuation_time,
+            foreign_balance: model.foreign_balance,
+            rate: model.rate,
+            carrying_value: model.carrying_value,
+            revalued_value: model.revalued_value,
+            posting_id: model.posting_id,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: AccountRevaluation) -> FxRevaluationModel {
+        FxRevaluationModel {
+            id: bo.id,
+            ledger_id: bo.account.ledger.id,
+            account_id: bo.account.id,
+            currency: bo.currency,
+            valuation_time: bo.valuation_time,
+            foreign_balance: bo.foreign_balance,
+            rate: bo.rate,
+            carrying_value: bo.carrying_value,
+            revalued_value: bo.revalued_value,
+            posting_id: bo.posting_id,
+            created: bo.created,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use postings_api::domain::hash_record::HashAlgorithm;
+use postings_api::domain::hashing_profile::{HashedField, HashingProfile as HashingProfileBO};
+use postings_api::ServiceError;
+use postings_db::models::hashing_profile::HashingProfile as HashingProfileModel;
+
+pub struct HashingProfileMapper;
+
+impl HashingProfileMapper {
+    pub fn to_bo(model: HashingProfileModel, ledger_bo: postings_api::domain::ledger::Ledger) -> Result<HashingProfileBO, ServiceError> {
+        Ok(HashingProfileBO {
+            ledger: ledger_bo,
+            excluded_fields: HashedField::split(Some(&model.excluded_fields)),
+            algorithm: HashAlgorithm::parse(&model.hash_algorithm)?,
+            updated: model.updated,
+        })
+    }
+
+    pub fn to_model(bo: HashingProfileBO) -> HashingProfileModel {
+        HashingProfileModel {
+            ledger_id: bo.ledger.id,
+            excluded_fields: HashedField::join(&bo.excluded_fields).unwrap_or_default(),
+            hash_algorithm: bo.algorithm.as_str().to_string(),
+            updated: bo.updated,
+        }
+    }
+}
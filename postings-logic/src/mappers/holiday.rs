@@ -0,0 +1,22 @@
+use postings_api::domain::holiday::Holiday as HolidayBO;
+use postings_db::models::holiday::Holiday as HolidayModel;
+
+pub struct HolidayMapper;
+
+impl HolidayMapper {
+    pub fn to_bo(model: HolidayModel, ledger_bo: postings_api::domain::ledger::Ledger) -> HolidayBO {
+        HolidayBO {
+            ledger: ledger_bo,
+            date: model.holiday_date,
+            name: model.name,
+        }
+    }
+
+    pub fn to_model(bo: HolidayBO) -> HolidayModel {
+        HolidayModel {
+            ledger_id: bo.ledger.id,
+            holiday_date: bo.date,
+            name: bo.name,
+        }
+    }
+}
@@ -15,6 +15,9 @@ impl LedgerMapper {
         LedgerModel {
             id: bo.id,
             coa_id: bo.coa.id,
+            read_only: false,
+            memo: false,
+            closing_summary: false,
         }
     }
 }
@@ -25,6 +25,7 @@ impl LedgerAccountMapper {
                 postings_db::models::account_category::AccountCategory::NORE => postings_api::domain::account_category::AccountCategory::NORE,
                 postings_db::models::account_category::AccountCategory::NOEX => postings_api::domain::account_category::AccountCategory::NOEX,
             },
+            currency: model.currency,
         }
     }
 
@@ -49,6 +50,7 @@ impl LedgerAccountMapper {
                 postings_api::domain::account_category::AccountCategory::NORE => postings_db::models::account_category::AccountCategory::NORE,
                 postings_api::domain::account_category::AccountCategory::NOEX => postings_db::models::account_category::AccountCategory::NOEX,
             },
+            currency: bo.currency,
         }
     }
 }
@@ -0,0 +1,32 @@
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::ledger_account_name::LedgerAccountName as LedgerAccountNameBO;
+use postings_db::models::ledger_account_name::LedgerAccountName as LedgerAccountNameModel;
+
+pub struct LedgerAccountNameMapper;
+
+impl LedgerAccountNameMapper {
+    pub fn to_bo(model: LedgerAccountNameModel, account: LedgerAccountBO) -> LedgerAccountNameBO {
+        LedgerAccountNameBO {
+            id: model.id,
+            account,
+            number: model.number,
+            name: model.name,
+            valid_from: model.valid_from,
+            valid_to: model.valid_to,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: LedgerAccountNameBO) -> LedgerAccountNameModel {
+        LedgerAccountNameModel {
+            id: bo.id,
+            ledger_id: bo.account.ledger.id,
+            account_id: bo.account.id,
+            number: bo.number,
+            name: bo.name,
+            valid_from: bo.valid_from,
+            valid_to: bo.valid_to,
+            created: bo.created,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use postings_api::domain::ledger::Ledger as LedgerBO;
+use postings_api::domain::ledger_closure::LedgerClosure as LedgerClosureBO;
+use postings_db::models::ledger_closure::LedgerClosure as LedgerClosureModel;
+
+pub struct LedgerClosureMapper;
+
+impl LedgerClosureMapper {
+    pub fn to_bo(model: LedgerClosureModel, ledger: LedgerBO) -> LedgerClosureBO {
+        LedgerClosureBO {
+            id: model.id,
+            ledger,
+            account_id: model.account_id,
+            cut_off: model.cut_off,
+            allow_adjustments: model.allow_adjustments,
+            closed_by: model.closed_by,
+            reason: model.reason,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: LedgerClosureBO) -> LedgerClosureModel {
+        LedgerClosureModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            account_id: bo.account_id,
+            cut_off: bo.cut_off,
+            allow_adjustments: bo.allow_adjustments,
+            closed_by: bo.closed_by,
+            reason: bo.reason,
+            created: bo.created,
+        }
+    }
+}
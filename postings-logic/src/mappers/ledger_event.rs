@@ -0,0 +1,21 @@
+use postings_api::domain::ledger_event::LedgerEvent as LedgerEventBO;
+use postings_db::models::ledger_event::LedgerEvent as LedgerEventModel;
+
+pub struct LedgerEventMapper;
+
+impl LedgerEventMapper {
+    pub fn to_bo(model: LedgerEventModel) -> LedgerEventBO {
+        LedgerEventBO {
+            seq: model.seq,
+            entity_type: model.entity_type,
+            entity_id: model.entity_id,
+            operation: match model.operation {
+                postings_db::models::ledger_event::LedgerEventOperation::Insert => postings_api::domain::ledger_event::LedgerEventOperation::Insert,
+                postings_db::models::ledger_event::LedgerEventOperation::Update => postings_api::domain::ledger_event::LedgerEventOperation::Update,
+                postings_db::models::ledger_event::LedgerEventOperation::Delete => postings_api::domain::ledger_event::LedgerEventOperation::Delete,
+            },
+            payload: model.payload,
+            created: model.created,
+        }
+    }
+}
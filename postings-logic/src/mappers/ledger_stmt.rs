@@ -0,0 +1,42 @@
+use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::ledger_stmt::LedgerStmt as LedgerStmtBO;
+use postings_db::models::ledger_stmt::LedgerStmt as LedgerStmtModel;
+
+pub struct LedgerStmtMapper;
+
+impl LedgerStmtMapper {
+    pub fn to_bo(model: LedgerStmtModel, ledger_bo: postings_api::domain::ledger::Ledger, posting_bo: Option<postings_api::domain::posting::Posting>) -> LedgerStmtBO {
+        LedgerStmtBO {
+            financial_stmt: FinancialStmt {
+                id: model.id,
+                posting: posting_bo,
+                pst_time: model.pst_time,
+                stmt_status: match model.stmt_status {
+                    postings_db::models::stmt_status::StmtStatus::Simulated => postings_api::domain::stmt_status::StmtStatus::SIMULATED,
+                    postings_db::models::stmt_status::StmtStatus::Closed => postings_api::domain::stmt_status::StmtStatus::CLOSED,
+                },
+                latest_pst: None,
+                stmt_seq_nbr: model.stmt_seq_nbr,
+            },
+            ledger: ledger_bo,
+            total_debit: model.total_debit,
+            total_credit: model.total_credit,
+        }
+    }
+
+    pub fn to_model(bo: LedgerStmtBO) -> LedgerStmtModel {
+        LedgerStmtModel {
+            id: bo.financial_stmt.id,
+            ledger_id: bo.ledger.id,
+            total_debit: bo.total_debit,
+            total_credit: bo.total_credit,
+            posting_id: bo.financial_stmt.posting.map(|p| p.id),
+            pst_time: bo.financial_stmt.pst_time,
+            stmt_status: match bo.financial_stmt.stmt_status {
+                postings_api::domain::stmt_status::StmtStatus::SIMULATED => postings_db::models::stmt_status::StmtStatus::Simulated,
+                postings_api::domain::stmt_status::StmtStatus::CLOSED => postings_db::models::stmt_status::StmtStatus::Closed,
+            },
+            stmt_seq_nbr: bo.financial_stmt.stmt_seq_nbr,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::Utc;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_db::models::outbox_entry::OutboxEntry;
+use uuid::Uuid;
+
+pub struct OutboxEntryMapper;
+
+impl OutboxEntryMapper {
+    /// New undelivered entry of the event.
+    pub fn from_event(event: &DomainEvent) -> Result<OutboxEntry, serde_json::Error> {
+        let event_type = match event {
+            DomainEvent::PostingCreated(_) => "PostingCreated",
+            DomainEvent::PostingReversed { .. } => "PostingReversed",
+            DomainEvent::StatementClosed { .. } => "StatementClosed",
+        };
+        Ok(OutboxEntry {
+            id: Uuid::new_v4(),
+            event_type: event_type.to_string(),
+            payload: serde_json::to_string(event)?,
+            created: Utc::now(),
+            attempts: 0,
+            last_error: None,
+            delivered: None,
+        })
+    }
+
+    pub fn to_event(entry: &OutboxEntry) -> Result<DomainEvent, serde_json::Error> {
+        serde_json::from_str(&entry.payload)
+    }
+}
@@ -0,0 +1,39 @@
+use postings_api::service::posting_service::Page as PageBO;
+use postings_api::ServiceError;
+use postings_db::page::{Page as PageModel, PageRequest};
+
+pub struct PageMapper;
+
+impl PageMapper {
+    /// Rejects empty pages and page numbers or sizes the repositories cannot address.
+    pub fn to_request(page: usize, size: usize) -> Result<PageRequest, ServiceError> {
+        let page = u32::try_from(page).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let size = u32::try_from(size).map_err(|_| ServiceError::NotEnoughInfo)?;
+        if size == 0 {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        Ok(PageRequest::new(page, size))
+    }
+
+    pub fn to_bo<T, U>(model: PageModel<T>, f: impl FnMut(T) -> U) -> PageBO<U> {
+        let request = model.request;
+        let model = model.map(f);
+        PageBO {
+            content: model.content,
+            page: request.page as usize,
+            size: request.size as usize,
+            total_elements: model.total_elements,
+        }
+    }
+
+    /// Like `to_bo`, failing with the first element that cannot be mapped.
+    pub fn try_to_bo<T, U>(model: PageModel<T>, f: impl FnMut(T) -> Result<U, ServiceError>) -> Result<PageBO<U>, ServiceError> {
+        let page = Self::to_bo(model, f);
+        Ok(PageBO {
+            content: page.content.into_iter().collect::<Result<_, _>>()?,
+            page: page.page,
+            size: page.size,
+            total_elements: page.total_elements,
+        })
+    }
+}
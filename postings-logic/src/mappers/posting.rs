@@ -1,12 +1,13 @@
 use postings_api::domain::posting::Posting as PostingBO;
 use postings_db::models::posting::Posting as PostingModel;
-use postings_api::domain::hash_record::HashRecord;
+use postings_api::domain::hash_record::{HashAlgorithm, HashRecord, HashVersion};
+use postings_api::ServiceError;
 
 pub struct PostingMapper;
 
 impl PostingMapper {
-    pub fn to_bo(model: PostingModel, ledger_bo: postings_api::domain::ledger::Ledger, lines_bo: Vec<postings_api::domain::posting_line::PostingLine>) -> PostingBO {
-        PostingBO {
+    pub fn to_bo(model: PostingModel, ledger_bo: postings_api::domain::ledger::Ledger, lines_bo: Vec<postings_api::domain::posting_line::PostingLine>) -> Result<PostingBO, ServiceError> {
+        Ok(PostingBO {
             id: model.id,
             record_user: model.record_user,
             record_time: model.record_time,
@@ -45,8 +46,11 @@ impl PostingMapper {
                 antecedent_id: model.antecedent_id,
                 antecedent_hash: model.antecedent_hash,
                 hash: model.hash,
+                excluded_fields: postings_api::domain::hashing_profile::HashedField::split(model.hash_excluded_fields.as_deref()),
+                version: HashVersion::from_number(model.hash_version),
+                algorithm: HashAlgorithm::parse(&model.hash_algorithm)?,
             },
-        }
+        })
     }
 
     pub fn to_model(bo: PostingBO) -> PostingModel {
@@ -69,16 +73,7 @@ impl PostingMapper {
                 postings_api::domain::posting_type::PostingType::LdgClsng => postings_db::models::posting_type::PostingType::LdgClsng,
                 postings_api::domain::posting_type::PostingType::Unknown => postings_db::models::posting_type::PostingType::Unknown,
             },
-            pst_status: match bo.pst_status {
-                postings_api::domain::posting_status::PostingStatus::Deferred => postings_db::models::posting_status::PostingStatus::Deferred,
-                postings_api::domain::posting_status::PostingStatus::Posted => postings_db::models::posting_status::PostingStatus::Posted,
-                postings_api::domain::posting_status::PostingStatus::Proposed => postings_db::models::posting_status::PostingStatus::Proposed,
-                postings_api::domain::posting_status::PostingStatus::Simulated => postings_db::models::posting_status::PostingStatus::Simulated,
-                postings_api::domain::posting_status::PostingStatus::Tax => postings_db::models::posting_status::PostingStatus::Tax,
-                postings_api::domain::posting_status::PostingStatus::Unposted => postings_db::models::posting_status::PostingStatus::Unposted,
-                postings_api::domain::posting_status::PostingStatus::Cancelled => postings_db::models::posting_status::PostingStatus::Cancelled,
-                postings_api::domain::posting_status::PostingStatus::Other => postings_db::models::posting_status::PostingStatus::Other,
-            },
+            pst_status: Self::status_to_model(bo.pst_status),
             ledger_id: bo.ledger.id,
             val_time: bo.val_time,
             discarded_id: bo.discarded_id,
@@ -87,6 +82,22 @@ impl PostingMapper {
             antecedent_id: bo.hash_record.antecedent_id,
             antecedent_hash: bo.hash_record.antecedent_hash,
             hash: bo.hash_record.hash,
+            hash_excluded_fields: postings_api::domain::hashing_profile::HashedField::join(&bo.hash_record.excluded_fields),
+            hash_version: bo.hash_record.version.number(),
+            hash_algorithm: bo.hash_record.algorithm.as_str().to_string(),
+        }
+    }
+
+    pub fn status_to_model(status: postings_api::domain::posting_status::PostingStatus) -> postings_db::models::posting_status::PostingStatus {
+        match status {
+            postings_api::domain::posting_status::PostingStatus::Deferred => postings_db::models::posting_status::PostingStatus::Deferred,
+            postings_api::domain::posting_status::PostingStatus::Posted => postings_db::models::posting_status::PostingStatus::Posted,
+            postings_api::domain::posting_status::PostingStatus::Proposed => postings_db::models::posting_status::PostingStatus::Proposed,
+            postings_api::domain::posting_status::PostingStatus::Simulated => postings_db::models::posting_status::PostingStatus::Simulated,
+            postings_api::domain::posting_status::PostingStatus::Tax => postings_db::models::posting_status::PostingStatus::Tax,
+            postings_api::domain::posting_status::PostingStatus::Unposted => postings_db::models::posting_status::PostingStatus::Unposted,
+            postings_api::domain::posting_status::PostingStatus::Cancelled => postings_db::models::posting_status::PostingStatus::Cancelled,
+            postings_api::domain::posting_status::PostingStatus::Other => postings_db::models::posting_status::PostingStatus::Other,
         }
     }
 }
@@ -1,3 +1,4 @@
+use postings_api::domain::currency::FxDetails;
 use postings_api::domain::posting_line::PostingLine as PostingLineBO;
 use postings_db::models::posting_line::PostingLine as PostingLineModel;
 
@@ -41,6 +42,12 @@ impl PostingLineMapper {
             hash: model.hash,
             additional_information: None, // Not in DB model
             discarded_time: model.discarded_time,
+            category: None,
+            currency: model.currency,
+            fx: match (model.fx_base_currency, model.fx_rate) {
+                (Some(base_currency), Some(rate)) => Some(FxDetails { base_currency, rate }),
+                _ => None,
+            },
         }
     }
 
@@ -79,6 +86,9 @@ impl PostingLineMapper {
             },
             hash: bo.hash,
             discarded_time: bo.discarded_time,
+            fx_base_currency: bo.fx.as_ref().map(|fx| fx.base_currency.clone()),
+            fx_rate: bo.fx.map(|fx| fx.rate),
+            currency: bo.currency,
         }
     }
 }
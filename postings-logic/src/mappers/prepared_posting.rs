@@ -0,0 +1,51 @@
+use postings_api::domain::posting::Posting;
+use postings_api::domain::prepared_posting::PreparedPosting as PreparedPostingBO;
+use postings_db::models::prepared_posting::PreparedPosting as PreparedPostingModel;
+
+pub struct PreparedPostingMapper;
+
+impl PreparedPostingMapper {
+    pub fn to_bo(model: PreparedPostingModel, posting: Posting) -> PreparedPostingBO {
+        PreparedPostingBO {
+            id: model.id,
+            posting,
+            status: Self::status_to_bo(model.status),
+            coordinator_ref: model.coordinator_ref,
+            expires: model.expires,
+            created: model.created,
+            resolved_time: model.resolved_time,
+            posting_id: model.posting_id,
+        }
+    }
+
+    pub fn to_model(bo: PreparedPostingBO, payload: String) -> PreparedPostingModel {
+        PreparedPostingModel {
+            id: bo.id,
+            ledger_id: bo.posting.ledger.id,
+            opr_id: bo.posting.opr_id,
+            payload,
+            status: Self::status_to_model(bo.status),
+            coordinator_ref: bo.coordinator_ref,
+            expires: bo.expires,
+            created: bo.created,
+            resolved_time: bo.resolved_time,
+            posting_id: bo.posting_id,
+        }
+    }
+
+    pub fn status_to_bo(status: postings_db::models::prepared_posting::PreparedPostingStatus) -> postings_api::domain::prepared_posting::PreparedPostingStatus {
+        match status {
+            postings_db::models::prepared_posting::PreparedPostingStatus::Prepared => postings_api::domain::prepared_posting::PreparedPostingStatus::Prepared,
+            postings_db::models::prepared_posting::PreparedPostingStatus::Committed => postings_api::domain::prepared_posting::PreparedPostingStatus::Committed,
+            postings_db::models::prepared_posting::PreparedPostingStatus::Aborted => postings_api::domain::prepared_posting::PreparedPostingStatus::Aborted,
+        }
+    }
+
+    pub fn status_to_model(status: postings_api::domain::prepared_posting::PreparedPostingStatus) -> postings_db::models::prepared_posting::PreparedPostingStatus {
+        match status {
+            postings_api::domain::prepared_posting::PreparedPostingStatus::Prepared => postings_db::models::prepared_posting::PreparedPostingStatus::Prepared,
+            postings_api::domain::prepared_posting::PreparedPostingStatus::Committed => postings_db::models::prepared_posting::PreparedPostingStatus::Committed,
+            postings_api::domain::prepared_posting::PreparedPostingStatus::Aborted => postings_db::models::prepared_posting::PreparedPostingStatus::Aborted,
+        }
+    }
+}
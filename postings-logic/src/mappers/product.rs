@@ -0,0 +1,39 @@
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::product::Product as ProductBO;
+use postings_db::models::product::Product as ProductModel;
+
+pub struct ProductMapper;
+
+impl ProductMapper {
+    pub fn to_bo(model: ProductModel, ledger: Ledger) -> ProductBO {
+        ProductBO {
+            id: model.id,
+            ledger,
+            name: model.name,
+            interest_rate: model.interest_rate,
+            day_count_convention: model.day_count_convention.map(|c| match c {
+                postings_db::models::product::DayCountConvention::Act360 => postings_api::domain::day_count_convention::DayCountConvention::Act360,
+                postings_db::models::product::DayCountConvention::Act365Fixed => postings_api::domain::day_count_convention::DayCountConvention::Act365Fixed,
+                postings_db::models::product::DayCountConvention::ActActIsda => postings_api::domain::day_count_convention::DayCountConvention::ActActIsda,
+                postings_db::models::product::DayCountConvention::Thirty360 => postings_api::domain::day_count_convention::DayCountConvention::Thirty360,
+            }),
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: ProductBO) -> ProductModel {
+        ProductModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            name: bo.name,
+            interest_rate: bo.interest_rate,
+            day_count_convention: bo.day_count_convention.map(|c| match c {
+                postings_api::domain::day_count_convention::DayCountConvention::Act360 => postings_db::models::product::DayCountConvention::Act360,
+                postings_api::domain::day_count_convention::DayCountConvention::Act365Fixed => postings_db::models::product::DayCountConvention::Act365Fixed,
+                postings_api::domain::day_count_convention::DayCountConvention::ActActIsda => postings_db::models::product::DayCountConvention::ActActIsda,
+                postings_api::domain::day_count_convention::DayCountConvention::Thirty360 => postings_db::models::product::DayCountConvention::Thirty360,
+            }),
+            created: bo.created,
+        }
+    }
+}
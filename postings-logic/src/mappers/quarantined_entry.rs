@@ -0,0 +1,42 @@
+use postings_api::domain::quarantined_entry::QuarantinedEntry as QuarantinedEntryBO;
+use postings_db::models::quarantined_entry::QuarantinedEntry as QuarantinedEntryModel;
+
+pub struct QuarantinedEntryMapper;
+
+impl QuarantinedEntryMapper {
+    pub fn to_bo(model: QuarantinedEntryModel, ledger_bo: postings_api::domain::ledger::Ledger) -> QuarantinedEntryBO {
+        QuarantinedEntryBO {
+            id: model.id,
+            ledger: ledger_bo,
+            import_type: model.import_type,
+            line_number: model.line_number,
+            payload: model.payload,
+            reason: model.reason,
+            status: match model.status {
+                postings_db::models::quarantined_entry::QuarantineStatus::Pending => postings_api::domain::quarantined_entry::QuarantineStatus::Pending,
+                postings_db::models::quarantined_entry::QuarantineStatus::Resubmitted => postings_api::domain::quarantined_entry::QuarantineStatus::Resubmitted,
+                postings_db::models::quarantined_entry::QuarantineStatus::Discarded => postings_api::domain::quarantined_entry::QuarantineStatus::Discarded,
+            },
+            created: model.created,
+            resolved_time: model.resolved_time,
+        }
+    }
+
+    pub fn to_model(bo: QuarantinedEntryBO) -> QuarantinedEntryModel {
+        QuarantinedEntryModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            import_type: bo.import_type,
+            line_number: bo.line_number,
+            payload: bo.payload,
+            reason: bo.reason,
+            status: match bo.status {
+                postings_api::domain::quarantined_entry::QuarantineStatus::Pending => postings_db::models::quarantined_entry::QuarantineStatus::Pending,
+                postings_api::domain::quarantined_entry::QuarantineStatus::Resubmitted => postings_db::models::quarantined_entry::QuarantineStatus::Resubmitted,
+                postings_api::domain::quarantined_entry::QuarantineStatus::Discarded => postings_db::models::quarantined_entry::QuarantineStatus::Discarded,
+            },
+            created: bo.created,
+            resolved_time: bo.resolved_time,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use postings_api::domain::report_schedule::{
+    ReportArtifact as ReportArtifactBO, ReportFormat as ReportFormatBO, ReportSchedule as ReportScheduleBO,
+    ReportType as ReportTypeBO,
+};
+use postings_db::models::report_schedule::{
+    ReportArtifact as ReportArtifactModel, ReportFormat as ReportFormatModel, ReportSchedule as ReportScheduleModel,
+    ReportType as ReportTypeModel,
+};
+
+pub struct ReportScheduleMapper;
+
+impl ReportScheduleMapper {
+    pub fn to_bo(model: ReportScheduleModel, ledger_bo: postings_api::domain::ledger::Ledger) -> ReportScheduleBO {
+        ReportScheduleBO {
+            id: model.id,
+            ledger: ledger_bo,
+            report_type: Self::type_to_bo(model.report_type),
+            format: Self::format_to_bo(model.format),
+            frequency: match model.frequency {
+                postings_db::models::report_schedule::ReportFrequency::Daily => postings_api::domain::report_schedule::ReportFrequency::Daily,
+                postings_db::models::report_schedule::ReportFrequency::MonthEnd => postings_api::domain::report_schedule::ReportFrequency::MonthEnd,
+            },
+            next_ref_time: model.next_ref_time,
+            status: match model.status {
+                postings_db::models::report_schedule::ReportScheduleStatus::Active => postings_api::domain::report_schedule::ReportScheduleStatus::Active,
+                postings_db::models::report_schedule::ReportScheduleStatus::Cancelled => postings_api::domain::report_schedule::ReportScheduleStatus::Cancelled,
+            },
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: ReportScheduleBO) -> ReportScheduleModel {
+        ReportScheduleModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            report_type: Self::type_to_model(bo.report_type),
+            format: Self::format_to_model(bo.format),
+            frequency: match bo.frequency {
+                postings_api::domain::report_schedule::ReportFrequency::Daily => postings_db::models::report_schedule::ReportFrequency::Daily,
+                postings_api::domain::report_schedule::ReportFrequency::MonthEnd => postings_db::models::report_schedule::ReportFrequency::MonthEnd,
+            },
+            next_ref_time: bo.next_ref_time,
+            status: match bo.status {
+                postings_api::domain::report_schedule::ReportScheduleStatus::Active => postings_db::models::report_schedule::ReportScheduleStatus::Active,
+                postings_api::domain::report_schedule::ReportScheduleStatus::Cancelled => postings_db::models::report_schedule::ReportScheduleStatus::Cancelled,
+            },
+            created: bo.created,
+        }
+    }
+
+    pub fn artifact_to_bo(model: ReportArtifactModel) -> ReportArtifactBO {
+        ReportArtifactBO {
+            id: model.id,
+            schedule_id: model.schedule_id,
+            ledger_id: model.ledger_id,
+            report_type: Self::type_to_bo(model.report_type),
+            format: Self::format_to_bo(model.format),
+            ref_time: model.ref_time,
+            version: model.version,
+            object_key: model.object_key,
+            checksum: model.checksum,
+            size: model.size,
+            created: model.created,
+        }
+    }
+
+    pub fn artifact_to_model(bo: ReportArtifactBO) -> ReportArtifactModel {
+        ReportArtifactModel {
+            id: bo.id,
+            schedule_id: bo.schedule_id,
+            ledger_id: bo.ledger_id,
+            report_type: Self::type_to_model(bo.report_type),
+            format: Self::format_to_model(bo.format),
+            ref_time: bo.ref_time,
+            version: bo.version,
+            object_key: bo.object_key,
+            checksum: bo.checksum,
+            size: bo.size,
+            created: bo.created,
+        }
+    }
+
+    pub fn type_to_bo(model: ReportTypeModel) -> ReportTypeBO {
+        match model {
+            ReportTypeModel::TrialBalance => ReportTypeBO::TrialBalance,
+            ReportTypeModel::DailyJournal => ReportTypeBO::DailyJournal,
+        }
+    }
+
+    pub fn type_to_model(bo: ReportTypeBO) -> ReportTypeModel {
+        match bo {
+            ReportTypeBO::TrialBalance => ReportTypeModel::TrialBalance,
+            ReportTypeBO::DailyJournal => ReportTypeModel::DailyJournal,
+        }
+    }
+
+    fn format_to_bo(model: ReportFormatModel) -> ReportFormatBO {
+        match model {
+            ReportFormatModel::Json => ReportFormatBO::Json,
+            ReportFormatModel::Csv => ReportFormatBO::Csv,
+            ReportFormatModel::Pdf => ReportFormatBO::Pdf,
+        }
+    }
+
+    fn format_to_model(bo: ReportFormatBO) -> ReportFormatModel {
+        match bo {
+            ReportFormatBO::Json => ReportFormatModel::Json,
+            ReportFormatBO::Csv => ReportFormatModel::Csv,
+            ReportFormatBO::Pdf => ReportFormatModel::Pdf,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use postings_api::domain::reversal_policy::ReversalPolicy as ReversalPolicyBO;
+use postings_db::models::reversal_policy::ReversalPolicy as ReversalPolicyModel;
+
+pub struct ReversalPolicyMapper;
+
+impl ReversalPolicyMapper {
+    pub fn to_bo(model: ReversalPolicyModel, ledger_bo: postings_api::domain::ledger::Ledger) -> ReversalPolicyBO {
+        ReversalPolicyBO {
+            ledger: ledger_bo,
+            teller_window_days: model.teller_window_days.max(0) as u32,
+            supervisor_window_days: model.supervisor_window_days.map(|d| d.max(0) as u32),
+            updated: model.updated,
+        }
+    }
+
+    pub fn to_model(bo: ReversalPolicyBO) -> ReversalPolicyModel {
+        ReversalPolicyModel {
+            ledger_id: bo.ledger.id,
+            teller_window_days: bo.teller_window_days.min(i32::MAX as u32) as i32,
+            supervisor_window_days: bo.supervisor_window_days.map(|d| d.min(i32::MAX as u32) as i32),
+            updated: bo.updated,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use postings_api::domain::ledger::Ledger as LedgerBO;
+use postings_api::domain::posting_type::PostingType as PostingTypeBO;
+use postings_api::domain::scheduled_posting::{
+    ScheduledLine as ScheduledLineBO, ScheduledPosting as ScheduledPostingBO, ScheduledPostingStatus as ScheduledPostingStatusBO,
+};
+use postings_api::domain::standing_order::Frequency as FrequencyBO;
+use postings_db::models::posting_type::PostingType as PostingTypeModel;
+use postings_db::models::scheduled_posting::{ScheduledPosting as ScheduledPostingModel, ScheduledPostingStatus as ScheduledPostingStatusModel};
+use postings_db::models::standing_order::Frequency as FrequencyModel;
+
+pub struct ScheduledPostingMapper;
+
+impl ScheduledPostingMapper {
+    pub fn to_bo(model: ScheduledPostingModel, ledger: LedgerBO, lines: Vec<ScheduledLineBO>) -> ScheduledPostingBO {
+        ScheduledPostingBO {
+            id: model.id,
+            ledger,
+            opr_type: model.opr_type,
+            pst_type: match model.pst_type {
+                PostingTypeModel::BusiTx => PostingTypeBO::BusiTx,
+                PostingTypeModel::AdjTx => PostingTypeBO::AdjTx,
+                PostingTypeModel::BalStmt => PostingTypeBO::BalStmt,
+                PostingTypeModel::PnlStmt => PostingTypeBO::PnLStmt,
+                PostingTypeModel::BsStmt => PostingTypeBO::BsStmt,
+                PostingTypeModel::LdgClsng => PostingTypeBO::LdgClsng,
+                PostingTypeModel::Unknown => PostingTypeBO::Unknown,
+            },
+            lines,
+            frequency: model.frequency.map(|frequency| match frequency {
+                FrequencyModel::Daily => FrequencyBO::Daily,
+                FrequencyModel::Weekly => FrequencyBO::Weekly,
+                FrequencyModel::Monthly => FrequencyBO::Monthly,
+                FrequencyModel::Quarterly => FrequencyBO::Quarterly,
+                FrequencyModel::Yearly => FrequencyBO::Yearly,
+            }),
+            start_time: model.start_time,
+            end_time: model.end_time,
+            max_occurrences: model.max_occurrences,
+            next_pst_time: model.next_pst_time,
+            occurrence: model.occurrence,
+            status: match model.status {
+                ScheduledPostingStatusModel::Active => ScheduledPostingStatusBO::Active,
+                ScheduledPostingStatusModel::Completed => ScheduledPostingStatusBO::Completed,
+                ScheduledPostingStatusModel::Cancelled => ScheduledPostingStatusBO::Cancelled,
+            },
+            created: model.created,
+        }
+    }
+
+    /// `lines` is the JSON of the schedule's line templates.
+    pub fn to_model(bo: ScheduledPostingBO, lines: String) -> ScheduledPostingModel {
+        ScheduledPostingModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            opr_type: bo.opr_type,
+            pst_type: match bo.pst_type {
+                PostingTypeBO::BusiTx => PostingTypeModel::BusiTx,
+                PostingTypeBO::AdjTx => PostingTypeModel::AdjTx,
+                PostingTypeBO::BalStmt => PostingTypeModel::BalStmt,
+                PostingTypeBO::PnLStmt => PostingTypeModel::PnlStmt,
+                PostingTypeBO::BsStmt => PostingTypeModel::BsStmt,
+                PostingTypeBO::LdgClsng => PostingTypeModel::LdgClsng,
+                PostingTypeBO::Unknown => PostingTypeModel::Unknown,
+            },
+            lines,
+            frequency: bo.frequency.map(|frequency| match frequency {
+                FrequencyBO::Daily => FrequencyModel::Daily,
+                FrequencyBO::Weekly => FrequencyModel::Weekly,
+                FrequencyBO::Monthly => FrequencyModel::Monthly,
+                FrequencyBO::Quarterly => FrequencyModel::Quarterly,
+                FrequencyBO::Yearly => FrequencyModel::Yearly,
+            }),
+            start_time: bo.start_time,
+            end_time: bo.end_time,
+            max_occurrences: bo.max_occurrences,
+            next_pst_time: bo.next_pst_time,
+            occurrence: bo.occurrence,
+            status: match bo.status {
+                ScheduledPostingStatusBO::Active => ScheduledPostingStatusModel::Active,
+                ScheduledPostingStatusBO::Completed => ScheduledPostingStatusModel::Completed,
+                ScheduledPostingStatusBO::Cancelled => ScheduledPostingStatusModel::Cancelled,
+            },
+            created: bo.created,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use postings_api::domain::settlement_batch::SettlementBatch as SettlementBatchBO;
+use postings_db::models::settlement_batch::SettlementBatch as SettlementBatchModel;
+
+pub struct SettlementBatchMapper;
+
+impl SettlementBatchMapper {
+    pub fn to_bo(model: SettlementBatchModel, ledger_bo: postings_api::domain::ledger::Ledger) -> SettlementBatchBO {
+        SettlementBatchBO {
+            id: model.id,
+            ledger: ledger_bo,
+            batch_ref: model.batch_ref,
+            control_amount: model.control_amount,
+            control_count: model.control_count,
+            status: match model.status {
+                postings_db::models::batch_status::BatchStatus::Open => postings_api::domain::batch_status::BatchStatus::Open,
+                postings_db::models::batch_status::BatchStatus::Closed => postings_api::domain::batch_status::BatchStatus::Closed,
+                postings_db::models::batch_status::BatchStatus::Settled => postings_api::domain::batch_status::BatchStatus::Settled,
+                postings_db::models::batch_status::BatchStatus::Rejected => postings_api::domain::batch_status::BatchStatus::Rejected,
+            },
+            created: model.created,
+            closed_time: model.closed_time,
+        }
+    }
+
+    pub fn to_model(bo: SettlementBatchBO) -> SettlementBatchModel {
+        SettlementBatchModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            batch_ref: bo.batch_ref,
+            control_amount: bo.control_amount,
+            control_count: bo.control_count,
+            status: match bo.status {
+                postings_api::domain::batch_status::BatchStatus::Open => postings_db::models::batch_status::BatchStatus::Open,
+                postings_api::domain::batch_status::BatchStatus::Closed => postings_db::models::batch_status::BatchStatus::Closed,
+                postings_api::domain::batch_status::BatchStatus::Settled => postings_db::models::batch_status::BatchStatus::Settled,
+                postings_api::domain::batch_status::BatchStatus::Rejected => postings_db::models::batch_status::BatchStatus::Rejected,
+            },
+            created: bo.created,
+            closed_time: bo.closed_time,
+        }
+    }
+}
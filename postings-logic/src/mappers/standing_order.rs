@@ -0,0 +1,107 @@
+use postings_api::domain::standing_order::{StandingOrder as StandingOrderBO, StandingOrderExecution as StandingOrderExecutionBO};
+use postings_db::models::standing_order::{StandingOrder as StandingOrderModel, StandingOrderExecution as StandingOrderExecutionModel};
+
+pub struct StandingOrderMapper;
+
+impl StandingOrderMapper {
+    pub fn to_bo(
+        model: StandingOrderModel,
+        ledger_bo: postings_api::domain::ledger::Ledger,
+        source_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+        target_account_bo: postings_api::domain::ledger_account::LedgerAccount,
+    ) -> StandingOrderBO {
+        StandingOrderBO {
+            id: model.id,
+            ledger: ledger_bo,
+            source_account: source_account_bo,
+            target_account: target_account_bo,
+            amount: model.amount,
+            opr_type: model.opr_type,
+            frequency: match model.frequency {
+                postings_db::models::standing_order::Frequency::Daily => postings_api::domain::standing_order::Frequency::Daily,
+                postings_db::models::standing_order::Frequency::Weekly => postings_api::domain::standing_order::Frequency::Weekly,
+                postings_db::models::standing_order::Frequency::Monthly => postings_api::domain::standing_order::Frequency::Monthly,
+                postings_db::models::standing_order::Frequency::Quarterly => postings_api::domain::standing_order::Frequency::Quarterly,
+                postings_db::models::standing_order::Frequency::Yearly => postings_api::domain::standing_order::Frequency::Yearly,
+            },
+            start_date: model.start_date,
+            end_date: model.end_date,
+            max_executions: model.max_executions,
+            max_retries: model.max_retries,
+            next_execution_date: model.next_execution_date,
+            occurrence: model.occurrence,
+            execution_count: model.execution_count,
+            status: match model.status {
+                postings_db::models::standing_order::StandingOrderStatus::Active => postings_api::domain::standing_order::StandingOrderStatus::Active,
+                postings_db::models::standing_order::StandingOrderStatus::Completed => postings_api::domain::standing_order::StandingOrderStatus::Completed,
+                postings_db::models::standing_order::StandingOrderStatus::Cancelled => postings_api::domain::standing_order::StandingOrderStatus::Cancelled,
+            },
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: StandingOrderBO) -> StandingOrderModel {
+        StandingOrderModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            source_account_id: bo.source_account.id,
+            target_account_id: bo.target_account.id,
+            amount: bo.amount,
+            opr_type: bo.opr_type,
+            frequency: match bo.frequency {
+                postings_api::domain::standing_order::Frequency::Daily => postings_db::models::standing_order::Frequency::Daily,
+                postings_api::domain::standing_order::Frequency::Weekly => postings_db::models::standing_order::Frequency::Weekly,
+                postings_api::domain::standing_order::Frequency::Monthly => postings_db::models::standing_order::Frequency::Monthly,
+                postings_api::domain::standing_order::Frequency::Quarterly => postings_db::models::standing_order::Frequency::Quarterly,
+                postings_api::domain::standing_order::Frequency::Yearly => postings_db::models::standing_order::Frequency::Yearly,
+            },
+            start_date: bo.start_date,
+            end_date: bo.end_date,
+            max_executions: bo.max_executions,
+            max_retries: bo.max_retries,
+            next_execution_date: bo.next_execution_date,
+            occurrence: bo.occurrence,
+            execution_count: bo.execution_count,
+            status: match bo.status {
+                postings_api::domain::standing_order::StandingOrderStatus::Active => postings_db::models::standing_order::StandingOrderStatus::Active,
+                postings_api::domain::standing_order::StandingOrderStatus::Completed => postings_db::models::standing_order::StandingOrderStatus::Completed,
+                postings_api::domain::standing_order::StandingOrderStatus::Cancelled => postings_db::models::standing_order::StandingOrderStatus::Cancelled,
+            },
+            created: bo.created,
+        }
+    }
+
+    pub fn execution_to_bo(model: StandingOrderExecutionModel) -> StandingOrderExecutionBO {
+        StandingOrderExecutionBO {
+            id: model.id,
+            order_id: model.order_id,
+            execution_date: model.execution_date,
+            attempt: model.attempt,
+            status: match model.status {
+                postings_db::models::standing_order::ExecutionStatus::Executed => postings_api::domain::standing_order::ExecutionStatus::Executed,
+                postings_db::models::standing_order::ExecutionStatus::Failed => postings_api::domain::standing_order::ExecutionStatus::Failed,
+                postings_db::models::standing_order::ExecutionStatus::Skipped => postings_api::domain::standing_order::ExecutionStatus::Skipped,
+            },
+            posting_id: model.posting_id,
+            message: model.message,
+            executed_time: model.executed_time,
+        }
+    }
+
+    pub fn execution_to_model(bo: StandingOrderExecutionBO) -> StandingOrderExecutionModel {
+        StandingOrderExecutionModel {
+            id: bo.id,
+            order_id: bo.order_id,
+            execution_date: bo.execution_date,
+            attempt: bo.attempt,
+            status: match bo.status {
+                postings_api::domain::standing_order::ExecutionStatus::Executed => postings_db::models::standing_order::ExecutionStatus::Executed,
+                postings_api::domain::standing_order::ExecutionStatus::Failed => postings_db::models::standing_order::ExecutionStatus::Failed,
+                postings_api::domain::standing_order::ExecutionStatus::Skipped => postings_db::models::standing_order::ExecutionStatus::Skipped,
+            },
+            posting_id: bo.posting_id,
+            message: bo.message,
+            executed_time: bo.executed_time,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use postings_api::domain::stmt_annotation::StmtAnnotation as StmtAnnotationBO;
+use postings_db::models::stmt_annotation::StmtAnnotation as StmtAnnotationModel;
+
+pub struct StmtAnnotationMapper;
+
+impl StmtAnnotationMapper {
+    pub fn to_bo(model: StmtAnnotationModel) -> StmtAnnotationBO {
+        StmtAnnotationBO {
+            id: model.id,
+            stmt_id: model.stmt_id,
+            author: model.author,
+            text: model.text,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: StmtAnnotationBO) -> StmtAnnotationModel {
+        StmtAnnotationModel {
+            id: bo.id,
+            stmt_id: bo.stmt_id,
+            author: bo.author,
+            text: bo.text,
+            created: bo.created,
+        }
+    }
+}
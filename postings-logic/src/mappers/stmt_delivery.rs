@@ -0,0 +1,38 @@
+use postings_api::domain::stmt_delivery::StmtDelivery as StmtDeliveryBO;
+use postings_db::models::stmt_delivery::StmtDelivery as StmtDeliveryModel;
+
+pub struct StmtDeliveryMapper;
+
+impl StmtDeliveryMapper {
+    pub fn to_bo(model: StmtDeliveryModel) -> StmtDeliveryBO {
+        StmtDeliveryBO {
+            id: model.id,
+            stmt_id: model.stmt_id,
+            format: match model.format {
+                postings_db::models::stmt_delivery::StmtDocumentFormat::Csv => postings_api::domain::stmt_delivery::StmtDocumentFormat::Csv,
+                postings_db::models::stmt_delivery::StmtDocumentFormat::Json => postings_api::domain::stmt_delivery::StmtDocumentFormat::Json,
+                postings_db::models::stmt_delivery::StmtDocumentFormat::Pdf => postings_api::domain::stmt_delivery::StmtDocumentFormat::Pdf,
+            },
+            checksum: model.checksum,
+            signature: model.signature,
+            key_id: model.key_id,
+            created: model.created,
+        }
+    }
+
+    pub fn to_model(bo: StmtDeliveryBO) -> StmtDeliveryModel {
+        StmtDeliveryModel {
+            id: bo.id,
+            stmt_id: bo.stmt_id,
+            format: match bo.format {
+                postings_api::domain::stmt_delivery::StmtDocumentFormat::Csv => postings_db::models::stmt_delivery::StmtDocumentFormat::Csv,
+                postings_api::domain::stmt_delivery::StmtDocumentFormat::Json => postings_db::models::stmt_delivery::StmtDocumentFormat::Json,
+                postings_api::domain::stmt_delivery::StmtDocumentFormat::Pdf => postings_db::models::stmt_delivery::StmtDocumentFormat::Pdf,
+            },
+            checksum: bo.checksum,
+            signature: bo.signature,
+            key_id: bo.key_id,
+            created: bo.created,
+        }
+    }
+}
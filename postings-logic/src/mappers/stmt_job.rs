@@ -0,0 +1,42 @@
+use postings_api::domain::stmt_job::StmtJob as StmtJobBO;
+use postings_db::models::stmt_job::StmtJob as StmtJobModel;
+
+pub struct StmtJobMapper;
+
+impl StmtJobMapper {
+    pub fn to_bo(model: StmtJobModel, account_bo: postings_api::domain::ledger_account::LedgerAccount) -> StmtJobBO {
+        StmtJobBO {
+            id: model.id,
+            account: account_bo,
+            ref_time: model.ref_time,
+            status: match model.status {
+                postings_db::models::stmt_job::StmtJobStatus::Pending => postings_api::domain::stmt_job::StmtJobStatus::Pending,
+                postings_db::models::stmt_job::StmtJobStatus::Running => postings_api::domain::stmt_job::StmtJobStatus::Running,
+                postings_db::models::stmt_job::StmtJobStatus::Completed => postings_api::domain::stmt_job::StmtJobStatus::Completed,
+                postings_db::models::stmt_job::StmtJobStatus::Failed => postings_api::domain::stmt_job::StmtJobStatus::Failed,
+            },
+            stmt_id: model.stmt_id,
+            error_message: model.error_message,
+            created: model.created,
+            completed_time: model.completed_time,
+        }
+    }
+
+    pub fn to_model(bo: StmtJobBO) -> StmtJobModel {
+        StmtJobModel {
+            id: bo.id,
+            account_id: bo.account.id,
+            ref_time: bo.ref_time,
+            status: match bo.status {
+                postings_api::domain::stmt_job::StmtJobStatus::Pending => postings_db::models::stmt_job::StmtJobStatus::Pending,
+                postings_api::domain::stmt_job::StmtJobStatus::Running => postings_db::models::stmt_job::StmtJobStatus::Running,
+                postings_api::domain::stmt_job::StmtJobStatus::Completed => postings_db::models::stmt_job::StmtJobStatus::Completed,
+                postings_api::domain::stmt_job::StmtJobStatus::Failed => postings_db::models::stmt_job::StmtJobStatus::Failed,
+            },
+            stmt_id: bo.stmt_id,
+            error_message: bo.error_message,
+            created: bo.created,
+            completed_time: bo.completed_time,
+        }
+    }
+}
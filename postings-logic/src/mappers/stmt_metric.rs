@@ -0,0 +1,16 @@
+use postings_api::domain::stmt_diagnostics::AccountStmtStats as AccountStmtStatsBO;
+use postings_db::models::stmt_metric::AccountStmtStats as AccountStmtStatsModel;
+
+pub struct StmtMetricMapper;
+
+impl StmtMetricMapper {
+    pub fn stats_to_bo(model: AccountStmtStatsModel) -> AccountStmtStatsBO {
+        AccountStmtStatsBO {
+            account_id: model.account_id,
+            generations: model.generations.max(0) as u64,
+            total_duration_ms: model.total_duration_ms.max(0) as u64,
+            max_duration_ms: model.max_duration_ms.max(0) as u64,
+            max_lines: model.max_lines.max(0) as u64,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use postings_api::domain::stmt_repair::{StmtDrift, StmtFigures, StmtRepair as StmtRepairBO};
+use postings_db::models::account_stmt::AccountStmt as AccountStmtModel;
+use postings_db::models::stmt_repair::StmtRepair as StmtRepairModel;
+
+pub struct StmtRepairMapper;
+
+impl StmtRepairMapper {
+    pub fn figures(model: &AccountStmtModel) -> StmtFigures {
+        StmtFigures {
+            opening_debit: model.opening_debit.clone(),
+            opening_credit: model.opening_credit.clone(),
+            total_debit: model.total_debit.clone(),
+            total_credit: model.total_credit.clone(),
+            line_count: model.line_count,
+            currency_totals: model
+                .currency_totals
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Copy of the statement carrying the given figures.
+    pub fn with_figures(model: &AccountStmtModel, figures: &StmtFigures) -> AccountStmtModel {
+        AccountStmtModel {
+            opening_debit: figures.opening_debit.clone(),
+            opening_credit: figures.opening_credit.clone(),
+            total_debit: figures.total_debit.clone(),
+            total_credit: figures.total_credit.clone(),
+            line_count: figures.line_count,
+            closing_balance: figures.total_debit.clone() - figures.total_credit.clone(),
+            currency_totals: if figures.currency_totals.is_empty() {
+                None
+            } else {
+                serde_json::to_string(&figures.currency_totals).ok()
+            },
+            ..model.clone()
+        }
+    }
+
+    pub fn to_bo(model: StmtRepairModel) -> Result<StmtRepairBO, serde_json::Error> {
+        Ok(StmtRepairBO {
+            id: model.id,
+            drift: StmtDrift {
+                stmt_id: model.stmt_id,
+                account_id: model.account_id,
+                pst_time: model.pst_time,
+                recorded: serde_json::from_str(&model.recorded)?,
+                expected: serde_json::from_str(&model.expected)?,
+            },
+            principal: model.principal,
+            reason: model.reason,
+            repaired_time: model.repaired_time,
+        })
+    }
+
+    pub fn to_model(bo: StmtRepairBO) -> Result<StmtRepairModel, serde_json::Error> {
+        Ok(StmtRepairModel {
+            id: bo.id,
+            stmt_id: bo.drift.stmt_id,
+            account_id: bo.drift.account_id,
+            pst_time: bo.drift.pst_time,
+            recorded: serde_json::to_string(&bo.drift.recorded)?,
+            expected: serde_json::to_string(&bo.drift.expected)?,
+            principal: bo.principal,
+            reason: bo.reason,
+            repaired_time: bo.repaired_time,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::hash_record::HashRecord;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use uuid::Uuid;
+
+/// Assembles system generated postings (standing orders, fees, transfers...) line by line.
+/// Ids, record time and hashes are assigned by the posting service when the posting is recorded.
+pub struct PostingBuilder {
+    posting: Posting,
+}
+
+impl PostingBuilder {
+    pub fn new(ledger: Ledger, opr_id: [u8; 34], opr_type: [u8; 34], pst_time: DateTime<Utc>) -> Self {
+        Self {
+            posting: Posting {
+                id: Uuid::nil(),
+                record_user: [0; 34],
+                record_time: pst_time,
+                opr_id,
+                opr_time: pst_time,
+                opr_type,
+                opr_details: None,
+                opr_src: None,
+                pst_time,
+                pst_type: PostingType::BusiTx,
+                pst_status: PostingStatus::Posted,
+                ledger,
+                val_time: None,
+                lines: Vec::new(),
+                discarded_id: None,
+                discarded_time: None,
+                discarding_id: None,
+                hash_record: HashRecord::default(),
+            },
+        }
+    }
+
+    pub fn record_user(mut self, record_user: [u8; 34]) -> Self {
+        self.posting.record_user = record_user;
+        self
+    }
+
+    pub fn opr_details(mut self, opr_details: [u8; 34]) -> Self {
+        self.posting.opr_details = Some(opr_details);
+        self
+    }
+
+    pub fn opr_src(mut self, opr_src: [u8; 34]) -> Self {
+        self.posting.opr_src = Some(opr_src);
+        self
+    }
+
+    pub fn val_time(mut self, val_time: DateTime<Utc>) -> Self {
+        self.posting.val_time = Some(val_time);
+        self
+    }
+
+    pub fn pst_type(mut self, pst_type: PostingType) -> Self {
+        self.posting.pst_type = pst_type;
+        self
+    }
+
+    pub fn debit(self, account: LedgerAccount, amount: BigDecimal) -> Self {
+        self.line(account, amount, BigDecimal::from(0))
+    }
+
+    pub fn credit(self, account: LedgerAccount, amount: BigDecimal) -> Self {
+        self.line(account, BigDecimal::from(0), amount)
+    }
+
+    pub fn line(mut self, account: LedgerAccount, debit_amount: BigDecimal, credit_amount: BigDecimal) -> Self {
+        self.posting.lines.push(PostingLine {
+            id: Uuid::new_v4(),
+            account,
+            debit_amount,
+            credit_amount,
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: self.posting.record_time,
+            opr_id: self.posting.opr_id,
+            opr_src: self.posting.opr_src,
+            pst_time: self.posting.pst_time,
+            pst_type: self.posting.pst_type.clone(),
+            pst_status: self.posting.pst_status.clone(),
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+            category: None,
+            currency: None,
+            fx: None,
+        });
+        self
+    }
+
+    pub fn build(self) -> Posting {
+        self.posting
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Statements are scoped by the ledger of their account, looked up in `accounts`. Statements of
+/// accounts outside the scope read as missing and cannot be saved.
+pub struct ScopedAccountStmtRepository {
+    inner: Arc<dyn AccountStmtRepository + Send + Sync>,
+    accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedAccountStmtRepository {
+    pub fn new(
+        inner: Arc<dyn AccountStmtRepository + Send + Sync>,
+        accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+        scope: LedgerScope,
+    ) -> Self {
+        Self { inner, accounts, scope }
+    }
+
+    async fn in_scope(&self, account_id: Uuid) -> Result<bool, DbError> {
+        self.scope.contains_account(self.accounts.as_ref(), account_id).await
+    }
+
+    async fn retain_in_scope(&self, stmt: Option<AccountStmt>) -> Result<Option<AccountStmt>, DbError> {
+        match stmt {
+            Some(stmt) if self.in_scope(stmt.account_id).await? => Ok(Some(stmt)),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountStmtRepository for ScopedAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(None);
+        }
+        self.inner.find_first_by_account_and_status_and_pst_time_less_than_ordered(account_id, status, ref_time).await
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(None);
+        }
+        self.inner.find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, status, ref_time).await
+    }
+
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(None);
+        }
+        self.inner.find_last_closed_by_account_and_pst_time_less_than(account_id, ref_time).await
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(0);
+        }
+        self.inner.delete_expired_simulated_by_ledger_id(ledger_id, as_of).await
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(vec![]);
+        }
+        self.inner.find_closed_by_account_and_pst_time_between(account_id, from, to).await
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(vec![]);
+        }
+        self.inner.find_closed_by_ledger_id_after(ledger_id, after, limit).await
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(0);
+        }
+        self.inner.count_closed_by_ledger_id(ledger_id).await
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        if self.find_by_id(id).await?.is_none() {
+            return Err(DbError::NotFound);
+        }
+        self.inner.update_opening_totals(id, opening_debit, opening_credit, line_count).await
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        if !self.in_scope(stmt.account_id).await? {
+            return Err(DbError::OutOfScope);
+        }
+        self.inner.save(stmt).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        let stmt = self.inner.find_by_id(id).await?;
+        self.retain_in_scope(stmt).await
+    }
+}
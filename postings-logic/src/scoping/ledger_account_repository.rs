@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::page::{Page, PageRequest};
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Accounts of ledgers outside the scope read as missing and cannot be saved.
+pub struct ScopedLedgerAccountRepository {
+    inner: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedLedgerAccountRepository {
+    pub fn new(inner: Arc<dyn LedgerAccountRepository + Send + Sync>, scope: LedgerScope) -> Self {
+        Self { inner, scope }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for ScopedLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        Ok(self.inner.find_by_id(id).await?.filter(|a| self.scope.contains(a.ledger_id)))
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
+        self.scope.check(ledger_account.ledger_id)?;
+        // An existing account must not be moved in from another ledger either
+        if let Some(existing) = self.inner.find_by_id(ledger_account.id).await? {
+            self.scope.check(existing.ledger_id)?;
+        }
+        self.inner.save(ledger_account).await
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(vec![]);
+        }
+        self.inner.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(Page::new(vec![], page, 0));
+        }
+        self.inner.find_by_ledger_id_paged(ledger_id, page).await
+    }
+
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        // Descendants live in the ledger of the account
+        if self.find_by_id(id).await?.is_none() {
+            return Ok(vec![]);
+        }
+        self.inner.find_descendants(id).await
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::ledger::Ledger;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Ledgers outside the scope read as missing and cannot be created or changed.
+pub struct ScopedLedgerRepository {
+    inner: Arc<dyn LedgerRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedLedgerRepository {
+    pub fn new(inner: Arc<dyn LedgerRepository + Send + Sync>, scope: LedgerScope) -> Self {
+        Self { inner, scope }
+    }
+
+    /// Out-of-scope ledgers are reported as not found, as by the reads.
+    fn check_known(&self, id: Uuid) -> Result<(), DbError> {
+        self.scope.check(id).map_err(|_| DbError::NotFound)
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for ScopedLedgerRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
+        if !self.scope.contains(id) {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+
+    async fn save(&self, ledger: &Ledger) -> Result<(), DbError> {
+        self.scope.check(ledger.id)?;
+        self.inner.save(ledger).await
+    }
+
+    async fn set_read_only(&self, id: Uuid, read_only: bool) -> Result<(), DbError> {
+        self.check_known(id)?;
+        self.inner.set_read_only(id, read_only).await
+    }
+
+    async fn set_memo(&self, id: Uuid, memo: bool) -> Result<(), DbError> {
+        self.check_known(id)?;
+        self.inner.set_memo(id, memo).await
+    }
+
+    async fn set_closing_summary(&self, id: Uuid, closing_summary: bool) -> Result<(), DbError> {
+        self.check_known(id)?;
+        self.inner.set_closing_summary(id, closing_summary).await
+    }
+}
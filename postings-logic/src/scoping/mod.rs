@@ -0,0 +1,54 @@
+pub mod account_stmt_repository;
+pub mod ledger_account_repository;
+pub mod ledger_repository;
+pub mod posting_line_repository;
+pub mod posting_repository;
+pub mod posting_trace_repository;
+pub mod unit_of_work_repository;
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+/// Ledgers a caller may reach, e.g. the single ledger of a request or the ledgers of a tenant.
+///
+/// The scoped repositories of this module can only be built from a scope and apply it to every
+/// query: rows of other ledgers read as missing and writes to them fail with [`DbError::OutOfScope`],
+/// whatever ids a service passes in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerScope {
+    ledger_ids: Arc<BTreeSet<Uuid>>,
+}
+
+impl LedgerScope {
+    pub fn ledger(ledger_id: Uuid) -> Self {
+        Self::ledgers([ledger_id])
+    }
+
+    pub fn ledgers(ledger_ids: impl IntoIterator<Item = Uuid>) -> Self {
+        Self { ledger_ids: Arc::new(ledger_ids.into_iter().collect()) }
+    }
+
+    pub fn contains(&self, ledger_id: Uuid) -> bool {
+        self.ledger_ids.contains(&ledger_id)
+    }
+
+    pub(crate) fn ledger_ids(&self) -> impl Iterator<Item = Uuid> + '_ {
+        self.ledger_ids.iter().copied()
+    }
+
+    /// Whether the account is found in `accounts` and belongs to a ledger of the scope.
+    pub(crate) async fn contains_account(&self, accounts: &(dyn LedgerAccountRepository + Send + Sync), account_id: Uuid) -> Result<bool, DbError> {
+        Ok(accounts.find_by_id(account_id).await?.is_some_and(|a| self.contains(a.ledger_id)))
+    }
+
+    pub(crate) fn check(&self, ledger_id: Uuid) -> Result<(), DbError> {
+        if self.contains(ledger_id) {
+            Ok(())
+        } else {
+            Err(DbError::OutOfScope)
+        }
+    }
+}
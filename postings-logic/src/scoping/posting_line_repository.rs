@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::page::{Page, PageRequest};
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Lines do not carry their ledger, so each line is scoped by the ledger of its account, looked up
+/// in `accounts`. Lines of accounts outside the scope read as missing and cannot be saved.
+pub struct ScopedPostingLineRepository {
+    inner: Arc<dyn PostingLineRepository + Send + Sync>,
+    accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedPostingLineRepository {
+    pub fn new(
+        inner: Arc<dyn PostingLineRepository + Send + Sync>,
+        accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+        scope: LedgerScope,
+    ) -> Self {
+        Self { inner, accounts, scope }
+    }
+
+    /// Unknown accounts are out of scope.
+    async fn in_scope(&self, account_id: Uuid) -> Result<bool, DbError> {
+        self.scope.contains_account(self.accounts.as_ref(), account_id).await
+    }
+
+    /// Keeps the lines of in-scope accounts, looking up each account once.
+    async fn retain_in_scope(&self, lines: Vec<PostingLine>) -> Result<Vec<PostingLine>, DbError> {
+        let mut accounts: HashMap<Uuid, bool> = HashMap::new();
+        let mut kept = Vec::with_capacity(lines.len());
+        for line in lines {
+            let in_scope = match accounts.get(&line.account_id) {
+                Some(in_scope) => *in_scope,
+                None => {
+                    let in_scope = self.in_scope(line.account_id).await?;
+                    accounts.insert(line.account_id, in_scope);
+                    in_scope
+                }
+            };
+            if in_scope {
+                kept.push(line);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Hands out `lines` once the account is found in scope, nothing otherwise.
+    fn guard_stream<'a>(&'a self, account_id: Uuid, lines: BoxStream<'a, Result<PostingLine, DbError>>) -> BoxStream<'a, Result<PostingLine, DbError>> {
+        let mut lines = Some(lines);
+        stream::once(self.in_scope(account_id))
+            .flat_map(move |in_scope| match (in_scope, lines.take()) {
+                (Ok(true), Some(lines)) => lines,
+                (Err(e), _) => stream::once(async { Err(e) }).boxed(),
+                _ => stream::empty().boxed(),
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl PostingLineRepository for ScopedPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        if !self.in_scope(posting_line.account_id).await? {
+            return Err(DbError::OutOfScope);
+        }
+        self.inner.save(posting_line).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        match self.inner.find_by_id(id).await? {
+            Some(line) if self.in_scope(line.account_id).await? => Ok(Some(line)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(vec![]);
+        }
+        self.inner.find_by_account_and_pst_time_between(account_id, from, to).await
+    }
+
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(Page::new(vec![], page, 0));
+        }
+        self.inner.find_by_account_and_pst_time_between_paged(account_id, from, to, page).await
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(None);
+        }
+        self.inner.find_by_id_and_account_id(id, account_id).await
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let lines = self.inner.find_by_base_line_and_pst_time_less_than_equal(base_line, ref_time).await?;
+        self.retain_in_scope(lines).await
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let lines = self.inner.find_by_opr_id(opr_id).await?;
+        self.retain_in_scope(lines).await
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(vec![]);
+        }
+        self.inner.find_by_account_and_pst_time_less_than_equal(account_id, ref_time).await
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(vec![]);
+        }
+        self.inner.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account_id, ref_time, known_at).await
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        if !self.in_scope(account_id).await? {
+            return Ok(AccountLineStats { line_count: 0, first_pst_time: None, last_pst_time: None, last_opr_id: None });
+        }
+        self.inner.find_stats_by_account_id(account_id).await
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        let mut scoped = Vec::with_capacity(account_ids.len());
+        for account_id in account_ids {
+            if self.in_scope(*account_id).await? {
+                scoped.push(*account_id);
+            }
+        }
+        self.inner.sum_by_account_ids_and_pst_time_less_than_equal(&scoped, ref_time).await
+    }
+
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.guard_stream(account_id, self.inner.stream_by_account_and_pst_time_between(account_id, from, to, order))
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.guard_stream(account_id, self.inner.stream_by_account_and_pst_time_less_than_equal(account_id, ref_time, order))
+    }
+}
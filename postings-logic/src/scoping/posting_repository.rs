@@ -0,0 +1,92 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_filter::PostingFilter;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::page::{Page, PageRequest};
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Postings of ledgers outside the scope read as missing and cannot be saved or reversed.
+pub struct ScopedPostingRepository {
+    inner: Arc<dyn PostingRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedPostingRepository {
+    pub fn new(inner: Arc<dyn PostingRepository + Send + Sync>, scope: LedgerScope) -> Self {
+        Self { inner, scope }
+    }
+}
+
+#[async_trait]
+impl PostingRepository for ScopedPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        Ok(self.inner.find_by_opr_id_and_discarding_id_is_null(opr_id).await?.filter(|p| self.scope.contains(p.ledger_id)))
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        let mut postings = self.inner.find_by_opr_id(opr_id).await?;
+        postings.retain(|p| self.scope.contains(p.ledger_id));
+        Ok(postings)
+    }
+
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(None);
+        }
+        self.inner.find_first_by_ledger_order_by_record_time_desc(ledger_id).await
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        self.scope.check(posting.ledger_id)?;
+        self.inner.save(posting).await
+    }
+
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        for (posting, _) in postings {
+            self.scope.check(posting.ledger_id)?;
+        }
+        self.inner.save_batch(postings).await
+    }
+
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        self.scope.check(reversal.ledger_id)?;
+        if let Some(reversed) = self.inner.find_by_id(reversed_id).await? {
+            self.scope.check(reversed.ledger_id)?;
+        }
+        self.inner.save_reversal(reversal, lines, reversed_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.inner.find_by_id(id).await?.filter(|p| self.scope.contains(p.ledger_id)))
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(vec![]);
+        }
+        self.inner.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(Page::new(vec![], page, 0));
+        }
+        self.inner.find_by_ledger_id_paged(ledger_id, page).await
+    }
+
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        let ledger_ids: Vec<Uuid> = if filter.ledger_ids.is_empty() {
+            self.scope.ledger_ids().collect()
+        } else {
+            filter.ledger_ids.iter().copied().filter(|id| self.scope.contains(*id)).collect()
+        };
+        if ledger_ids.is_empty() {
+            return Ok(Page::new(vec![], page, 0));
+        }
+        self.inner.find_by_filter_paged(&PostingFilter { ledger_ids, ..filter.clone() }, page).await
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::upsert::Upserted;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Traces are scoped by the ledger of their account, looked up in `accounts`. Traces of accounts
+/// outside the scope read as missing and cannot be written.
+pub struct ScopedPostingTraceRepository {
+    inner: Arc<dyn PostingTraceRepository + Send + Sync>,
+    accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedPostingTraceRepository {
+    pub fn new(
+        inner: Arc<dyn PostingTraceRepository + Send + Sync>,
+        accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+        scope: LedgerScope,
+    ) -> Self {
+        Self { inner, accounts, scope }
+    }
+}
+
+#[async_trait]
+impl PostingTraceRepository for ScopedPostingTraceRepository {
+    async fn upsert(&self, trace: PostingTrace) -> Result<Upserted<PostingTrace>, DbError> {
+        if !self.scope.contains_account(self.accounts.as_ref(), trace.account_id).await? {
+            return Err(DbError::OutOfScope);
+        }
+        self.inner.upsert(trace).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
+        match self.inner.find_by_id(id).await? {
+            Some(trace) if self.scope.contains_account(self.accounts.as_ref(), trace.account_id).await? => Ok(Some(trace)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn delete_orphaned_by_ledger_id(&self, ledger_id: Uuid) -> Result<u64, DbError> {
+        if !self.scope.contains(ledger_id) {
+            return Ok(0);
+        }
+        self.inner.delete_orphaned_by_ledger_id(ledger_id).await
+    }
+}
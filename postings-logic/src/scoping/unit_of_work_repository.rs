@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::{Committed, UnitOfWork, Write};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::scoping::LedgerScope;
+
+/// Units of work writing outside the scope fail with [`DbError::OutOfScope`] before anything is
+/// written. Postings and ledger statements are checked by their ledger, lines, account statements
+/// and earmarks by the ledger of their account and discards by the ledger of the discarded
+/// posting. Outbox entries carry the events of the other writes and are not checked.
+pub struct ScopedUnitOfWorkRepository {
+    inner: Arc<dyn UnitOfWorkRepository + Send + Sync>,
+    accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    postings: Arc<dyn PostingRepository + Send + Sync>,
+    scope: LedgerScope,
+}
+
+impl ScopedUnitOfWorkRepository {
+    pub fn new(
+        inner: Arc<dyn UnitOfWorkRepository + Send + Sync>,
+        accounts: Arc<dyn LedgerAccountRepository + Send + Sync>,
+        postings: Arc<dyn PostingRepository + Send + Sync>,
+        scope: LedgerScope,
+    ) -> Self {
+        Self { inner, accounts, postings, scope }
+    }
+
+    /// Ledgers, accounts and discarded postings the writes name, savepoints included.
+    fn collect(writes: &[Write], ledgers: &mut Vec<Uuid>, accounts: &mut Vec<Uuid>, discarded: &mut Vec<Uuid>) {
+        for write in writes {
+            match write {
+                Write::Posting(posting) => ledgers.push(posting.ledger_id),
+                Write::LedgerStmt(stmt) => ledgers.push(stmt.ledger_id),
+                Write::PostingLine(line) => accounts.push(line.account_id),
+                Write::AccountStmt(stmt) => accounts.push(stmt.account_id),
+                Write::Earmark(earmark) => accounts.push(earmark.account_id),
+                Write::DiscardPosting { id, .. } => discarded.push(*id),
+                Write::OutboxEntry(_) => {}
+                Write::Savepoint(_, nested) => Self::collect(nested.writes(), ledgers, accounts, discarded),
+            }
+        }
+    }
+
+    async fn check(&self, work: &UnitOfWork) -> Result<(), DbError> {
+        let mut ledgers = Vec::new();
+        let mut accounts = Vec::new();
+        let mut discarded = Vec::new();
+        Self::collect(work.writes(), &mut ledgers, &mut accounts, &mut discarded);
+        for ledger_id in ledgers {
+            self.scope.check(ledger_id)?;
+        }
+        accounts.sort();
+        accounts.dedup();
+        for account_id in accounts {
+            if !self.scope.contains_account(self.accounts.as_ref(), account_id).await? {
+                return Err(DbError::OutOfScope);
+            }
+        }
+        for posting_id in discarded {
+            // A missing posting fails the discard itself
+            if let Some(posting) = self.postings.find_by_id(posting_id).await? {
+                self.scope.check(posting.ledger_id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for ScopedUnitOfWorkRepository {
+    async fn commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        self.check(&work).await?;
+        self.inner.commit(work).await
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_group::{AccountGroup, AccountGroupBalance};
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::trial_balance::TrialBalance;
+use postings_api::service::account_group_service::AccountGroupService;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::balance_service::BalanceService;
+use postings_api::ServiceError;
+use postings_db::models::account_group::AccountGroupMember;
+use postings_db::repositories::account_group_repository::AccountGroupRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::mappers::account_group::AccountGroupMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct AccountGroupServiceImpl {
+    shared: SharedService,
+    group_repo: Arc<dyn AccountGroupRepository + Send + Sync>,
+    balance_service: Arc<dyn BalanceService + Send + Sync>,
+    stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+}
+
+impl AccountGroupServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        group_repo: Arc<dyn AccountGroupRepository + Send + Sync>,
+        balance_service: Arc<dyn BalanceService + Send + Sync>,
+        stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+    ) -> Self {
+        Self { shared, group_repo, balance_service, stmt_service }
+    }
+
+    async fn to_bo(&self, model: postings_db::models::account_group::AccountGroup) -> Result<AccountGroup, ServiceError> {
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let member_ids = self.group_repo
+            .find_member_ids(model.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(AccountGroupMapper::to_bo(model, ledger_bo, member_ids))
+    }
+
+    async fn load_group(&self, group_id: Uuid) -> Result<AccountGroup, ServiceError> {
+        let model = self.group_repo
+            .find_by_id(group_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::AccountGroupNotFound)?;
+        self.to_bo(model).await
+    }
+
+    async fn accounts_of(&self, group: &AccountGroup) -> Result<Vec<LedgerAccount>, ServiceError> {
+        let mut accounts: Vec<LedgerAccount> = self.shared
+            .load_ledger_accounts_bo(&group.ledger)
+            .await?
+            .into_iter()
+            .filter(|account| group.contains(account))
+            .collect();
+        accounts.sort_by_key(|a| a.id);
+        Ok(accounts)
+    }
+
+    /// Only accounts of the group's own ledger can be listed as members.
+    async fn check_member(&self, group: &AccountGroup, account_id: Uuid) -> Result<(), ServiceError> {
+        match self.shared.load_ledger_account(account_id).await? {
+            Some(account) if account.ledger_id == group.ledger.id => Ok(()),
+            _ => Err(ServiceError::LedgerAccountNotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountGroupService for AccountGroupServiceImpl {
+    async fn create_group(&self, mut group: AccountGroup) -> Result<AccountGroup, ServiceError> {
+        group.ledger = self.shared.load_ledger_bo(group.ledger.id).await?;
+        group.member_ids.sort();
+        group.member_ids.dedup();
+        for &account_id in &group.member_ids {
+            self.check_member(&group, account_id).await?;
+        }
+        self.group_repo
+            .save(AccountGroupMapper::to_model(group.clone()))
+            .await
+            .map_err(|e| match e {
+                DbError::UniqueViolation => ServiceError::AccountGroupNameTaken,
+                _ => ServiceError::Db,
+            })?;
+        for &account_id in &group.member_ids {
+            self.group_repo
+                .add_member(AccountGroupMember { group_id: group.id, account_id })
+                .await
+                .map_err(|_| ServiceError::Db)?;
+        }
+        Ok(group)
+    }
+
+    async fn find_group_by_id(&self, group_id: Uuid) -> Result<Option<AccountGroup>, ServiceError> {
+        match self.load_group(group_id).await {
+            Ok(group) => Ok(Some(group)),
+            Err(ServiceError::AccountGroupNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_group_by_name(&self, ledger: &Ledger, name: &str) -> Result<Option<AccountGroup>, ServiceError> {
+        match self.group_repo
+            .find_by_ledger_id_and_name(ledger.id, name)
+            .await
+            .map_err(|_| ServiceError::Db)?
+        {
+            Some(model) => Ok(Some(self.to_bo(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_groups(&self, ledger: &Ledger) -> Result<Vec<AccountGroup>, ServiceError> {
+        let models = self.group_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut groups = Vec::with_capacity(models.len());
+        for model in models {
+            groups.push(self.to_bo(model).await?);
+        }
+        Ok(groups)
+    }
+
+    async fn add_member(&self, group_id: Uuid, account_id: Uuid) -> Result<AccountGroup, ServiceError> {
+        let group = self.load_group(group_id).await?;
+        self.check_member(&group, account_id).await?;
+        self.group_repo
+            .add_member(AccountGroupMember { group_id, account_id })
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        self.load_group(group_id).await
+    }
+
+    async fn remove_member(&self, group_id: Uuid, account_id: Uuid) -> Result<AccountGroup, ServiceError> {
+        self.load_group(group_id).await?;
+        self.group_repo
+            .remove_member(group_id, account_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        self.load_group(group_id).await
+    }
+
+    async fn accounts(&self, group_id: Uuid) -> Result<Vec<LedgerAccount>, ServiceError> {
+        let group = self.load_group(group_id).await?;
+        self.accounts_of(&group).await
+    }
+
+    async fn balance(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<AccountGroupBalance, ServiceError> {
+        let group = self.load_group(group_id).await?;
+        let account_ids = self.accounts_of(&group).await?.iter().map(|a| a.id).collect();
+        let balances = self.balance_service.balances(account_ids, ref_time).await?;
+        Ok(AccountGroupBalance::new(group, ref_time, balances))
+    }
+
+    async fn read_stmts(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<AccountStmt>, ServiceError> {
+        let accounts = self.accounts(group_id).await?;
+        let mut stmts = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            stmts.push(self.stmt_service.read_stmt(account, ref_time).await?);
+        }
+        Ok(stmts)
+    }
+
+    async fn trial_balance(&self, group_id: Uuid, ref_time: DateTime<Utc>) -> Result<TrialBalance, ServiceError> {
+        let group_balance = self.balance(group_id, ref_time).await?;
+        Ok(TrialBalance::from_balances(group_balance.group.ledger, ref_time, group_balance.balances))
+    }
+}
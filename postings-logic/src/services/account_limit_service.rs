@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::account_limit::AccountLimit;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::account_limit_service::AccountLimitService;
+use postings_api::ServiceError;
+use postings_db::repositories::account_limit_repository::AccountLimitRepository;
+use crate::mappers::account_limit::AccountLimitMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct AccountLimitServiceImpl {
+    shared: SharedService,
+    limit_repo: Arc<dyn AccountLimitRepository + Send + Sync>,
+}
+
+impl AccountLimitServiceImpl {
+    pub fn new(shared: SharedService, limit_repo: Arc<dyn AccountLimitRepository + Send + Sync>) -> Self {
+        Self { shared, limit_repo }
+    }
+}
+
+#[async_trait]
+impl AccountLimitService for AccountLimitServiceImpl {
+    async fn set_limit(&self, mut limit: AccountLimit) -> Result<AccountLimit, ServiceError> {
+        let account = self.shared
+            .load_ledger_account(limit.account.id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        self.shared.ensure_writable(account.ledger_id).await?;
+        limit.updated = Utc::now();
+        let account_bo = limit.account.clone();
+        let saved = self
+            .limit_repo
+            .save(AccountLimitMapper::to_model(limit))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(AccountLimitMapper::to_bo(saved, account_bo))
+    }
+
+    async fn find_limit(&self, ledger_account: LedgerAccount) -> Result<Option<AccountLimit>, ServiceError> {
+        let model = self
+            .limit_repo
+            .find_by_account_id(ledger_account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(model.map(|m| AccountLimitMapper::to_bo(m, ledger_account)))
+    }
+}
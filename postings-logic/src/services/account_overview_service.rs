@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::domain::account_overview::AccountOverview;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::account_overview_service::AccountOverviewService;
+use postings_api::ServiceError;
+use postings_db::read_snapshot::AccountSnapshot;
+use postings_db::repositories::read_snapshot_repository::ReadSnapshotRepository;
+use uuid::Uuid;
+use crate::mappers::account_stmt::AccountStmtMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct AccountOverviewServiceImpl {
+    shared: SharedService,
+    snapshot_repo: Option<Arc<dyn ReadSnapshotRepository + Send + Sync>>,
+}
+
+impl AccountOverviewServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared, snapshot_repo: None }
+    }
+
+    /// Reads each overview from one repeatable-read snapshot. Without it, the statement and the
+    /// lines are read one after the other and may disagree about postings recorded in between.
+    pub fn with_read_snapshot(mut self, snapshot_repo: Arc<dyn ReadSnapshotRepository + Send + Sync>) -> Self {
+        self.snapshot_repo = Some(snapshot_repo);
+        self
+    }
+
+    /// The rows of the overview, and whether they were read from one snapshot.
+    async fn read(&self, account_id: Uuid, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<(AccountSnapshot, bool), ServiceError> {
+        if let Some(snapshot_repo) = &self.snapshot_repo {
+            let snapshot = snapshot_repo
+                .read_account(account_id, activity_from, ref_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            return Ok((snapshot, true));
+        }
+        let last_closed_stmt = self.shared.stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(account_id, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let lines = match &last_closed_stmt {
+            Some(stmt) => self.shared.line_repo
+                .find_by_account_and_pst_time_between(account_id, stmt.pst_time.min(activity_from), ref_time)
+                .await,
+            None => self.shared.line_repo
+                .find_by_account_and_pst_time_less_than_equal(account_id, ref_time)
+                .await,
+        }
+        .map_err(|_| ServiceError::Db)?;
+        Ok((AccountSnapshot { last_closed_stmt, lines }, false))
+    }
+}
+
+#[async_trait]
+impl AccountOverviewService for AccountOverviewServiceImpl {
+    async fn account_overview(&self, ledger_account: LedgerAccount, activity_from: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<AccountOverview, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(ledger_account.id).await?;
+        let (snapshot, consistent) = self.read(account.id, activity_from, ref_time).await?;
+
+        // The balance continues from the statement, the activity is cut at its own start
+        let (opening, since) = match &snapshot.last_closed_stmt {
+            Some(stmt) => ((stmt.total_debit.clone(), stmt.total_credit.clone()), Some(stmt.pst_time)),
+            None => ((BigDecimal::from(0), BigDecimal::from(0)), None),
+        };
+        let (total_debit, total_credit) = snapshot.lines
+            .iter()
+            .filter(|l| since.is_none_or(|since| l.pst_time > since))
+            .fold(opening, |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        let mut activity: Vec<_> = snapshot.lines.into_iter().filter(|l| l.pst_time > activity_from).collect();
+        activity.sort_by(|a, b| (b.pst_time, b.record_time, b.id).cmp(&(a.pst_time, a.record_time, a.id)));
+
+        Ok(AccountOverview {
+            last_closed_stmt: snapshot.last_closed_stmt.map(|stmt| AccountStmtMapper::to_bo(stmt, account.clone(), None, None, None)),
+            activity_from,
+            activity: self.shared.lines_to_bo(activity).await?,
+            balance: AccountBalance { account: account.clone(), ref_time, total_debit, total_credit, known_at: None },
+            consistent,
+            account,
+        })
+    }
+}
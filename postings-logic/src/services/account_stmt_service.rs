@@ -1,40 +1,285 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
-use log::{error, info};
+use chrono::{DateTime, Duration, Utc};
+use futures::{stream, StreamExt, TryStreamExt};
+use log::{error, info, warn};
+use ed25519_dalek::VerifyingKey;
 use uuid::Uuid;
 
 use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::account_stmt_delta::AccountStmtDelta;
+use postings_api::domain::closing_summary::ClosingSummary;
+use postings_api::domain::currency::CurrencyTotal;
+use postings_api::domain::domain_event::DomainEvent;
 use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::ledger::Ledger;
 use postings_api::domain::ledger_account::LedgerAccount;
-use postings_api::domain::posting_status::PostingStatus;
-use postings_api::domain::posting_type::PostingType;
-use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::domain::stmt_annotation::StmtAnnotation;
+use postings_api::domain::stmt_job::{StmtJob, StmtJobStatus};
+use postings_api::domain::stmt_period::StatementPeriod;
+use postings_api::domain::stmt_template::{RenderedStmt, StmtTemplateRegistry};
+use postings_api::domain::youngest_pst::YoungestPstStrategy;
+use postings_api::service::account_stmt_service::{AccountStmtService, StmtJobListener};
+use postings_api::service::document_signer::DocumentSigner;
 use postings_api::ServiceError;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::models::line_order::LineOrder;
 use postings_db::models::posting_line::PostingLine;
 use postings_db::models::posting_trace::PostingTrace;
 use postings_db::models::stmt_status::StmtStatus;
+use postings_db::models::stmt_metric::StmtMetric;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::repositories::stmt_annotation_repository::StmtAnnotationRepository;
+use postings_db::repositories::stmt_job_repository::StmtJobRepository;
+use postings_db::repositories::stmt_metric_repository::StmtMetricRepository;
+use postings_db::unit_of_work::UnitOfWork;
 
-use crate::hash_utils::hash_serialize;
 use crate::mappers::account_stmt::AccountStmtMapper;
-use crate::mappers::ledger::LedgerMapper;
 use crate::mappers::posting::PostingMapper;
+use crate::mappers::posting_line::PostingLineMapper;
 use crate::mappers::posting_trace::PostingTraceMapper;
-use crate::services::shared_service::SharedService;
+use crate::mappers::stmt_annotation::StmtAnnotationMapper;
+use crate::mappers::stmt_job::StmtJobMapper;
+use crate::services::category_rule_service::categorize_lines;
+use crate::services::shared_service::{ScopedSharedService, SharedService};
+use crate::signing::{canonical_stmt, verify_ed25519};
+
+/// Lifetime of a persisted simulated statement unless configured otherwise.
+const DEFAULT_SIMULATED_STMT_TTL_HOURS: i64 = 24;
 
 pub struct AccountStmtServiceImpl {
     shared: SharedService,
+    simulated_ttl: Duration,
+    job_repo: Option<Arc<dyn StmtJobRepository + Send + Sync>>,
+    job_listeners: Vec<Arc<dyn StmtJobListener + Send + Sync>>,
+    category_rule_repo: Option<Arc<dyn CategoryRuleRepository + Send + Sync>>,
+    templates: Arc<StmtTemplateRegistry>,
+    metric_repo: Option<Arc<dyn StmtMetricRepository + Send + Sync>>,
+    line_order: LineOrder,
+    annotation_repo: Option<Arc<dyn StmtAnnotationRepository + Send + Sync>>,
+    stmt_period: Option<StatementPeriod>,
+    youngest_pst_strategy: YoungestPstStrategy,
+    checkpoint_repo: Option<Arc<dyn BalanceCheckpointRepository + Send + Sync>>,
+    stmt_signer: Option<Arc<dyn DocumentSigner + Send + Sync>>,
+    verifying_keys: HashMap<String, VerifyingKey>,
+}
+
+/// Sort key of a line as built by [`YoungestPstStrategy::key`].
+type LineKey = (DateTime<Utc>, DateTime<Utc>, Uuid);
+
+/// Keys and traces of the youngest and latest lines applied to a statement so far.
+#[derive(Default)]
+struct TrackedPsts {
+    youngest: Option<LineKey>,
+    latest: Option<LineKey>,
+    youngest_trace: Option<PostingTrace>,
+    latest_trace: Option<PostingTrace>,
 }
 
 impl AccountStmtServiceImpl {
     pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+        Self {
+            shared,
+            simulated_ttl: Duration::hours(DEFAULT_SIMULATED_STMT_TTL_HOURS),
+            job_repo: None,
+            job_listeners: Vec::new(),
+            category_rule_repo: None,
+            templates: Arc::new(StmtTemplateRegistry::default()),
+            metric_repo: None,
+            line_order: LineOrder::default(),
+            annotation_repo: None,
+            stmt_period: None,
+            youngest_pst_strategy: YoungestPstStrategy::default(),
+            checkpoint_repo: None,
+            stmt_signer: None,
+            verifying_keys: HashMap::new(),
+        }
+    }
+
+    /// Service for one tenant or ledger, reading and writing only the statements of `shared`'s scope.
+    pub fn scoped(shared: ScopedSharedService) -> Self {
+        Self::new(shared.into_shared())
+    }
+
+    /// Sets how long simulated statements persisted by `create_stmt` are kept.
+    pub fn with_simulated_ttl(mut self, simulated_ttl: Duration) -> Self {
+        self.simulated_ttl = simulated_ttl;
+        self
+    }
+
+    /// Enables `create_stmt_async`, persisting queued jobs in `job_repo`.
+    pub fn with_job_repo(mut self, job_repo: Arc<dyn StmtJobRepository + Send + Sync>) -> Self {
+        self.job_repo = Some(job_repo);
+        self
+    }
+
+    /// Registers a listener notified whenever a statement job finishes.
+    pub fn with_job_listener(mut self, listener: Arc<dyn StmtJobListener + Send + Sync>) -> Self {
+        self.job_listeners.push(listener);
+        self
+    }
+
+    /// Sets the statement templates used by `render_delta`.
+    pub fn with_templates(mut self, templates: Arc<StmtTemplateRegistry>) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// Labels the lines of statement deltas using the ledger's categorization rules.
+    pub fn with_category_rule_repo(mut self, category_rule_repo: Arc<dyn CategoryRuleRepository + Send + Sync>) -> Self {
+        self.category_rule_repo = Some(category_rule_repo);
+        self
+    }
+
+    /// Records the duration and line count of every statement generation in `metric_repo`.
+    pub fn with_metric_repo(mut self, metric_repo: Arc<dyn StmtMetricRepository + Send + Sync>) -> Self {
+        self.metric_repo = Some(metric_repo);
+        self
+    }
+
+    /// Sets the order in which lines are applied to statements, posting time by default.
+    pub fn with_line_order(mut self, line_order: LineOrder) -> Self {
+        self.line_order = line_order;
+        self
+    }
+
+    /// Enables reviewer notes; statements are read with their notes attached.
+    pub fn with_annotation_repo(mut self, annotation_repo: Arc<dyn StmtAnnotationRepository + Send + Sync>) -> Self {
+        self.annotation_repo = Some(annotation_repo);
+        self
+    }
+
+    /// Allows at most one closed statement per account and period. Without a period, a statement
+    /// can still not be closed at or before the posting time of an already closed one.
+    pub fn with_stmt_period(mut self, stmt_period: StatementPeriod) -> Self {
+        self.stmt_period = Some(stmt_period);
+        self
+    }
+
+    /// Sets the time by which the youngest posting of a statement is picked, posting time by
+    /// default. The latest posting is always the most recently recorded line.
+    pub fn with_youngest_pst_strategy(mut self, youngest_pst_strategy: YoungestPstStrategy) -> Self {
+        self.youngest_pst_strategy = youngest_pst_strategy;
+        self
+    }
+
+    /// Generates statements from the latest balance checkpoint instead of the last closed
+    /// statement. Lines before the checkpoint are not read, so they get no posting trace and
+    /// cannot become the youngest or latest posting of the statement.
+    pub fn with_checkpoint_repo(mut self, checkpoint_repo: Arc<dyn BalanceCheckpointRepository + Send + Sync>) -> Self {
+        self.checkpoint_repo = Some(checkpoint_repo);
+        self
+    }
+
+    /// Signs statements on close with `signer`, an ed25519 key, for non-repudiation of issued
+    /// statements. Statements closed before stay unsigned.
+    pub fn with_stmt_signer(mut self, signer: Arc<dyn DocumentSigner + Send + Sync>) -> Self {
+        self.stmt_signer = Some(signer);
+        self
+    }
+
+    /// Registers the public key `verify_stmt_signature` checks signatures made with `key_id` against.
+    /// Keys of rotated signers stay registered as long as their statements are verified.
+    pub fn with_verifying_key(mut self, key_id: impl Into<String>, key: VerifyingKey) -> Self {
+        self.verifying_keys.insert(key_id.into(), key);
+        self
+    }
+
+    async fn ensure_period_open(&self, account_id: Uuid, pst_time: DateTime<Utc>) -> Result<(), ServiceError> {
+        let from = match self.stmt_period {
+            Some(period) => period.start_of(pst_time),
+            None => pst_time,
+        };
+        let overlapping = self
+            .shared
+            .stmt_repo
+            .find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, StmtStatus::Closed, from)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        match overlapping {
+            Some(closed) => {
+                warn!("Statement period of account {account_id} at {pst_time} overlaps closed statement {}", closed.id);
+                Err(ServiceError::StatementPeriodOverlap)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn job_repo(&self) -> Result<&Arc<dyn StmtJobRepository + Send + Sync>, ServiceError> {
+        self.job_repo.as_ref().ok_or(ServiceError::StmtJobsDisabled)
     }
 
+    async fn run_stmt_job(&self, mut job: StmtJob) -> Result<StmtJob, ServiceError> {
+        let job_repo = self.job_repo()?;
+        job.status = StmtJobStatus::Running;
+        job_repo
+            .save(StmtJobMapper::to_model(job.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        match self.create_stmt(job.account.clone(), job.ref_time).await {
+            Ok(stmt) => {
+                job.status = StmtJobStatus::Completed;
+                job.stmt_id = Some(stmt.financial_stmt.id);
+            }
+            Err(e) => {
+                error!("Statement job {} failed: {e:?}", job.id);
+                job.status = StmtJobStatus::Failed;
+                job.error_message = Some(e.to_string());
+            }
+        }
+        job.completed_time = Some(Utc::now());
+        job_repo
+            .save(StmtJobMapper::to_model(job.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        for listener in &self.job_listeners {
+            listener.on_job_finished(&job).await;
+        }
+        Ok(job)
+    }
+
+    /// Statement at `ref_time`. Posting traces of the applied lines are stored only with
+    /// `persist_traces`; informational reads leave no rows behind.
     async fn stmt(
         &self,
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
+        persist_traces: bool,
+    ) -> Result<AccountStmt, ServiceError> {
+        let started = Instant::now();
+        let mut stmt = self.generate_stmt(ledger_account, ref_time, persist_traces).await?;
+        if self.annotation_repo.is_some() {
+            stmt.annotations = self.find_stmt_annotations(stmt.financial_stmt.id).await?;
+        }
+        if let Some(metric_repo) = &self.metric_repo {
+            let metric = StmtMetric {
+                id: Uuid::new_v4(),
+                ledger_id: stmt.account.ledger.id,
+                account_id: stmt.account.id,
+                recorded: Utc::now(),
+                duration_ms: started.elapsed().as_millis() as i64,
+                line_count: stmt.line_count,
+            };
+            // Diagnostics must not fail statement generation
+            if let Err(e) = metric_repo.save(metric).await {
+                warn!("Failed to record statement metric of account {}: {e:?}", stmt.account.id);
+            }
+        }
+        Ok(stmt)
+    }
+
+    async fn generate_stmt(
+        &self,
+        ledger_account: LedgerAccount,
+        ref_time: DateTime<Utc>,
+        persist_traces: bool,
     ) -> Result<AccountStmt, ServiceError> {
         info!(
             "Generating statement for account: {} at time: {}",
@@ -57,36 +302,56 @@ impl AccountStmtServiceImpl {
         let last_closed_stmt = self
             .shared
             .stmt_repo
-            .find_first_by_account_and_status_and_pst_time_less_than_ordered(
-                account_model.id,
-                StmtStatus::Closed,
-                ref_time,
-            )
+            .find_last_closed_by_account_and_pst_time_less_than(account_model.id, ref_time)
             .await
             .map_err(|e| {
                 info!("Error finding last closed statement: {e:?}");
                 ServiceError::Db
             })?;
 
-        let (mut stmt, posting_lines) = if let Some(last_stmt) = last_closed_stmt {
+        // A statement closed exactly at ref_time is returned as closed rather than regenerated
+        let closed_at_ref_time = self
+            .shared
+            .stmt_repo
+            .find_first_by_account_and_status_and_pst_time_greater_than_equal(account_model.id, StmtStatus::Closed, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .filter(|closed| closed.pst_time == ref_time);
+
+        let (mut stmt, mut posting_lines) = if let Some(closed) = closed_at_ref_time {
+            info!("Found statement {} closed at reference time", closed.id);
+            (closed, stream::empty().boxed())
+        } else if let Some(last_stmt) = last_closed_stmt {
             info!("Found last closed statement: {}", last_stmt.id);
+            let mut since = last_stmt.pst_time;
+            let checkpoint = self.find_checkpoint(account_model.id, ref_time, Some(last_stmt.id)).await?;
+            let mut next_stmt = postings_db::models::account_stmt::AccountStmt {
+                id: Uuid::new_v4(),
+                posting_id: None,
+                pst_time: ref_time,
+                stmt_status: StmtStatus::Simulated,
+                stmt_seq_nbr: last_stmt.stmt_seq_nbr + 1,
+                expiry: None,
+                opening_debit: last_stmt.total_debit.clone(),
+                opening_credit: last_stmt.total_credit.clone(),
+                line_count: 0,
+                signature: None,
+                signing_key_id: None,
+                ..last_stmt
+            };
+            if let Some(checkpoint) = checkpoint {
+                since = checkpoint.as_of;
+                Self::continue_from(&mut next_stmt, checkpoint);
+            }
             let lines = self
                 .shared
                 .line_repo
-                .find_by_account_and_pst_time_between(
-                    account_model.id,
-                    last_stmt.pst_time,
-                    ref_time,
-                )
-                .await
-                .map_err(|e| {
-                    info!("Error finding posting lines for existing statement: {e:?}");
-                    ServiceError::Db
-                })?;
-            (last_stmt, lines)
+                .stream_by_account_and_pst_time_between(account_model.id, since, ref_time, self.line_order);
+            (next_stmt, lines)
         } else {
             info!("No closed statement found, creating new simulated statement");
-            let new_stmt = postings_db::models::account_stmt::AccountStmt {
+            let checkpoint = self.find_checkpoint(account_model.id, ref_time, None).await?;
+            let mut new_stmt = postings_db::models::account_stmt::AccountStmt {
                 id: Uuid::new_v4(),
                 account_id: account_model.id,
                 youngest_pst_id: None,
@@ -97,30 +362,59 @@ impl AccountStmtServiceImpl {
                 stmt_status: StmtStatus::Simulated,
                 latest_pst_id: None,
                 stmt_seq_nbr: 0,
+                expiry: None,
+                opening_debit: BigDecimal::from(0),
+                opening_credit: BigDecimal::from(0),
+                line_count: 0,
+                closing_balance: BigDecimal::from(0),
+                currency_totals: None,
+                signature: None,
+                signing_key_id: None,
+            };
+            let lines = match checkpoint {
+                Some(checkpoint) => {
+                    let since = checkpoint.as_of;
+                    Self::continue_from(&mut new_stmt, checkpoint);
+                    self.shared
+                        .line_repo
+                        .stream_by_account_and_pst_time_between(account_model.id, since, ref_time, self.line_order)
+                }
+                None => self
+                    .shared
+                    .line_repo
+                    .stream_by_account_and_pst_time_less_than_equal(account_model.id, ref_time, self.line_order),
             };
-            let lines = self
-                .shared
-                .line_repo
-                .find_by_account_and_pst_time_less_than_equal(account_model.id, ref_time)
-                .await
-                .map_err(|e| {
-                    info!("Error finding posting lines for new statement: {e:?}");
-                    ServiceError::Db
-                })?;
             (new_stmt, lines)
         };
 
-        info!("Found {} posting lines", posting_lines.len());
-        for line in posting_lines {
-            self.refresh_statement(&mut stmt, &line)
+        let mut currency_totals: Vec<CurrencyTotal> = stmt
+            .currency_totals
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        // Lines are applied as they are fetched so large accounts are never held in memory at once
+        let mut line_total = 0;
+        // References inherited from the previous closed statement give way to any line of this one
+        let mut tracked = TrackedPsts::default();
+        while let Some(line) = posting_lines.next().await {
+            let line = line.map_err(|e| {
+                info!("Error reading posting lines: {e:?}");
+                ServiceError::Db
+            })?;
+            self.refresh_statement(&mut stmt, &mut currency_totals, &mut tracked, &line, persist_traces)
                 .await
                 .map_err(|e| {
                     info!("Error refreshing statement with line {}: {e:?}", line.id);
                     e
                 })?;
+            line_total += 1;
         }
+        info!("Applied {line_total} posting lines");
 
-        let youngest_pst_bo = if let Some(id) = stmt.youngest_pst_id {
+        // Traces of this statement's lines are at hand, only inherited ones are loaded
+        let youngest_pst_bo = if let Some(trace) = tracked.youngest_trace {
+            Some(PostingTraceMapper::to_bo(trace, ledger_account.clone()))
+        } else if let Some(id) = stmt.youngest_pst_id {
             self.shared
                 .trace_repo
                 .find_by_id(id)
@@ -130,7 +424,9 @@ impl AccountStmtServiceImpl {
         } else {
             None
         };
-        let latest_pst_bo = if let Some(id) = stmt.latest_pst_id {
+        let latest_pst_bo = if let Some(trace) = tracked.latest_trace {
+            Some(PostingTraceMapper::to_bo(trace, ledger_account.clone()))
+        } else if let Some(id) = stmt.latest_pst_id {
             self.shared
                 .trace_repo
                 .find_by_id(id)
@@ -152,10 +448,12 @@ impl AccountStmtServiceImpl {
                     let ledger_bo = ledger_account.ledger.clone();
                     PostingMapper::to_bo(pm, ledger_bo, vec![])
                 })
+                .transpose()?
         } else {
             None
         };
 
+        let signature = AccountStmtMapper::signature_of(&stmt);
         Ok(AccountStmt {
             financial_stmt: FinancialStmt {
                 id: stmt.id,
@@ -172,29 +470,81 @@ impl AccountStmtServiceImpl {
             youngest_pst: youngest_pst_bo,
             total_debit: stmt.total_debit,
             total_credit: stmt.total_credit,
+            opening_debit: stmt.opening_debit,
+            opening_credit: stmt.opening_credit,
+            line_count: stmt.line_count,
+            currency_totals,
+            annotations: Vec::new(),
+            signature,
         })
     }
 
+    /// Latest checkpoint of the account at or before `ref_time` continuing from `base_stmt_id`,
+    /// the last statement closed before `ref_time`.
+    async fn find_checkpoint(
+        &self,
+        account_id: Uuid,
+        ref_time: DateTime<Utc>,
+        base_stmt_id: Option<Uuid>,
+    ) -> Result<Option<BalanceCheckpoint>, ServiceError> {
+        let Some(checkpoint_repo) = &self.checkpoint_repo else {
+            return Ok(None);
+        };
+        let checkpoint = checkpoint_repo
+            .find_last_by_account_and_as_of_less_than_equal(account_id, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .filter(|c| c.base_stmt_id == base_stmt_id);
+        if let Some(checkpoint) = &checkpoint {
+            info!("Continuing from checkpoint of account {account_id} at {}", checkpoint.as_of);
+        }
+        Ok(checkpoint)
+    }
+
+    fn continue_from(stmt: &mut postings_db::models::account_stmt::AccountStmt, checkpoint: BalanceCheckpoint) {
+        stmt.total_debit = checkpoint.total_debit;
+        stmt.total_credit = checkpoint.total_credit;
+        stmt.line_count = checkpoint.line_count;
+        stmt.currency_totals = checkpoint.currency_totals;
+    }
+
     async fn refresh_statement(
         &self,
         stmt: &mut postings_db::models::account_stmt::AccountStmt,
+        currency_totals: &mut Vec<CurrencyTotal>,
+        tracked: &mut TrackedPsts,
         line: &PostingLine,
+        persist_traces: bool,
     ) -> Result<(), ServiceError> {
-        let trace = self.create_posting_trace(stmt, line);
-        info!("Created posting trace: {}", trace.id);
+        let mut trace = self.create_posting_trace(stmt, line);
+        if persist_traces {
+            // Regenerating a statement reuses the trace already stored for the same line
+            let upserted = self.shared.trace_repo.upsert(trace).await.map_err(|e| {
+                info!("Error saving posting trace: {e:?}");
+                ServiceError::Db
+            })?;
+            info!("{} posting trace: {}", if upserted.is_inserted() { "Saved" } else { "Refreshed" }, upserted.get().id);
+            trace = upserted.into_inner();
+        }
 
-        if stmt.youngest_pst_id.is_none() {
-            // Simplified logic
+        let youngest_key = self.youngest_pst_strategy.key(line.pst_time, line.record_time, line.id);
+        if tracked.youngest.is_none_or(|key| youngest_key > key) {
+            tracked.youngest = Some(youngest_key);
             stmt.youngest_pst_id = Some(trace.id);
+            tracked.youngest_trace = Some(trace.clone());
+        }
+        let latest_key = YoungestPstStrategy::RecordTime.key(line.pst_time, line.record_time, line.id);
+        if tracked.latest.is_none_or(|key| latest_key > key) {
+            tracked.latest = Some(latest_key);
+            stmt.latest_pst_id = Some(trace.id);
+            tracked.latest_trace = Some(trace);
         }
-        stmt.latest_pst_id = Some(trace.id);
         stmt.total_debit += line.debit_amount.clone();
         stmt.total_credit += line.credit_amount.clone();
-
-        self.shared.trace_repo.save(trace).await.map_err(|e| {
-            info!("Error saving posting trace: {e:?}");
-            ServiceError::Db
-        })?;
+        if let Some(currency) = &line.currency {
+            CurrencyTotal::accumulate(currency_totals, currency, &line.debit_amount, &line.credit_amount);
+        }
+        stmt.line_count += 1;
         Ok(())
     }
 
@@ -224,7 +574,7 @@ impl AccountStmtService for AccountStmtServiceImpl {
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
     ) -> Result<AccountStmt, ServiceError> {
-        self.stmt(ledger_account, ref_time).await
+        self.stmt(ledger_account, ref_time, false).await
     }
 
     async fn create_stmt(
@@ -232,8 +582,12 @@ impl AccountStmtService for AccountStmtServiceImpl {
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
     ) -> Result<AccountStmt, ServiceError> {
-        let stmt_bo = self.stmt(ledger_account, ref_time).await?;
-        let stmt_model = AccountStmtMapper::from_bo(stmt_bo.clone());
+        self.shared.ensure_writable(ledger_account.ledger.id).await?;
+        let stmt_bo = self.stmt(ledger_account, ref_time, true).await?;
+        let mut stmt_model = AccountStmtMapper::from_bo(stmt_bo.clone());
+        if stmt_model.stmt_status == StmtStatus::Simulated {
+            stmt_model.expiry = Some(Utc::now() + self.simulated_ttl);
+        }
         self.shared.stmt_repo.save(stmt_model).await.map_err(|e| {
             error!("Failed to save statement: {e:?}");
             ServiceError::Db
@@ -253,80 +607,210 @@ impl AccountStmtService for AccountStmtServiceImpl {
         if stmt_model.stmt_status == StmtStatus::Closed {
             return Err(ServiceError::StatementAlreadyClosed);
         }
+        self.ensure_period_open(stmt_model.account_id, stmt_model.pst_time).await?;
+        let last_closed = self
+            .shared
+            .stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(stmt_model.account_id, stmt_model.pst_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
 
-        let ledger_model = self
+        let closing_posting = self
             .shared
-            .ledger_repo
-            .find_by_id(stmt.account.ledger.id)
+            .closing_posting(stmt.account.ledger.id, ClosingSummary::new(
+                stmt.financial_stmt.id,
+                Some(stmt.account.id),
+                stmt.financial_stmt.pst_time,
+                &stmt.total_debit,
+                &stmt.total_credit,
+            ))
+            .await?;
+
+        stmt_model.stmt_status = StmtStatus::Closed;
+        stmt_model.stmt_seq_nbr = last_closed.map_or(0, |last| last.stmt_seq_nbr + 1);
+        stmt_model.posting_id = Some(closing_posting.id);
+        stmt_model.expiry = None;
+        if let Some(signer) = &self.stmt_signer {
+            let content = canonical_stmt(&stmt_model).map_err(|_| ServiceError::NotEnoughInfo)?;
+            stmt_model.signature = Some(signer.sign(&content).await?);
+            stmt_model.signing_key_id = Some(signer.key_id());
+        }
+        // Neither the posting nor the closed statement is stored without the other
+        let event = DomainEvent::StatementClosed {
+            stmt_id: stmt_model.id,
+            ledger_id: stmt.account.ledger.id,
+            account_id: Some(stmt_model.account_id),
+            pst_time: stmt_model.pst_time,
+            posting_id: closing_posting.id,
+        };
+        let mut work = UnitOfWork::new();
+        work.save_posting(PostingMapper::to_model(closing_posting.clone()))
+            .save_account_stmt(stmt_model.clone());
+        if let Some(entry) = self.shared.outbox_entry(&event)? {
+            work.save_outbox_entry(entry);
+        }
+        self.shared.commit(work).await?;
+        self.shared.publish(event).await;
+
+        let mut closed_stmt_bo = stmt;
+        closed_stmt_bo.financial_stmt.stmt_status =
+            postings_api::domain::stmt_status::StmtStatus::CLOSED;
+        closed_stmt_bo.financial_stmt.posting = Some(closing_posting);
+        closed_stmt_bo.financial_stmt.stmt_seq_nbr = stmt_model.stmt_seq_nbr;
+        closed_stmt_bo.signature = AccountStmtMapper::signature_of(&stmt_model);
+
+        Ok(closed_stmt_bo)
+    }
+
+    async fn purge_expired_simulated_stmts(&self, ledger: Ledger, as_of: DateTime<Utc>) -> Result<u64, ServiceError> {
+        let deleted = self.shared
+            .stmt_repo
+            .delete_expired_simulated_by_ledger_id(ledger.id, as_of)
             .await
-            .map_err(|_| ServiceError::Db)?
-            .unwrap();
-        let coa_bo = self
+            .map_err(|_| ServiceError::Db)?;
+        let traces = self.shared
+            .trace_repo
+            .delete_orphaned_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        info!("Purged {deleted} expired simulated statements and {traces} posting traces of ledger {}", ledger.id);
+        Ok(deleted)
+    }
+
+    async fn read_delta(&self, ledger_account: LedgerAccount, since_stmt_id: Uuid) -> Result<AccountStmtDelta, ServiceError> {
+        let since_stmt = self
             .shared
-            .coa_repo
-            .find_by_id(ledger_model.coa_id)
+            .stmt_repo
+            .find_by_id(since_stmt_id)
             .await
             .map_err(|_| ServiceError::Db)?
-            .map(crate::mappers::chart_of_account::ChartOfAccountMapper::to_bo)
-            .unwrap();
-        let ledger_bo = LedgerMapper::to_bo(ledger_model, coa_bo);
+            .filter(|s| s.account_id == ledger_account.id)
+            .ok_or(ServiceError::StatementNotFound)?;
+        let to_time = Utc::now();
+        // Same order as the lines are applied to statements
+        let lines: Vec<PostingLine> = self
+            .shared
+            .line_repo
+            .stream_by_account_and_pst_time_between(ledger_account.id, since_stmt.pst_time, to_time, self.line_order)
+            .try_collect()
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut lines_bo: Vec<_> = lines
+            .into_iter()
+            .map(|l| PostingLineMapper::to_bo(l, ledger_account.clone()))
+            .collect();
+        if let Some(rule_repo) = &self.category_rule_repo {
+            categorize_lines(rule_repo.as_ref(), &mut lines_bo).await?;
+        }
+        Ok(AccountStmtDelta::new(ledger_account, since_stmt_id, since_stmt.pst_time, to_time, lines_bo))
+    }
 
-        let mut closing_posting = postings_api::domain::posting::Posting {
+    async fn render_delta(&self, ledger_account: LedgerAccount, since_stmt_id: Uuid) -> Result<RenderedStmt, ServiceError> {
+        let delta = self.read_delta(ledger_account, since_stmt_id).await?;
+        Ok(self.templates.template_for(&delta.account.category).render(&delta))
+    }
+
+    async fn create_stmt_async(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<Uuid, ServiceError> {
+        self.shared.ensure_writable(ledger_account.ledger.id).await?;
+        let job = StmtJob {
             id: Uuid::new_v4(),
-            record_user: [0; 34],
-            record_time: Utc::now(),
-            opr_id: [0; 34],
-            opr_time: Utc::now(),
-            opr_type: [0; 34],
-            opr_details: None,
-            opr_src: None,
-            pst_time: stmt.financial_stmt.pst_time,
-            pst_type: PostingType::BalStmt,
-            pst_status: PostingStatus::Posted,
-            ledger: ledger_bo,
-            val_time: Some(Utc::now()),
-            lines: vec![],
-            discarded_id: None,
-            discarded_time: None,
-            discarding_id: None,
-            hash_record: Default::default(),
+            account: ledger_account,
+            ref_time,
+            status: StmtJobStatus::Pending,
+            stmt_id: None,
+            error_message: None,
+            created: Utc::now(),
+            completed_time: None,
         };
+        let saved = self
+            .job_repo()?
+            .save(StmtJobMapper::to_model(job))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        info!("Queued statement job {} for account {}", saved.id, saved.account_id);
+        Ok(saved.id)
+    }
 
-        let antecedent = self
-            .shared
-            .posting_repo
-            .find_first_by_ledger_order_by_record_time_desc(
-                closing_posting.ledger.id,
-            )
+    async fn find_stmt_job(&self, job_id: Uuid) -> Result<Option<StmtJob>, ServiceError> {
+        let job_model = self
+            .job_repo()?
+            .find_by_id(job_id)
             .await
             .map_err(|_| ServiceError::Db)?;
-        if let Some(ant) = antecedent {
-            closing_posting.hash_record.antecedent_id = Some(ant.id);
-            closing_posting.hash_record.antecedent_hash = ant.hash;
+        match job_model {
+            Some(model) => {
+                let account_bo = self.shared.load_ledger_account_bo(model.account_id).await?;
+                Ok(Some(StmtJobMapper::to_bo(model, account_bo)))
+            }
+            None => Ok(None),
         }
-        let hash = hash_serialize(&closing_posting).map_err(|_| ServiceError::NotEnoughInfo)?;
-        closing_posting.hash_record.hash = Some(hash);
+    }
 
-        let posting_model = PostingMapper::to_model(closing_posting.clone());
-        self.shared
-            .posting_repo
-            .save(&posting_model)
+    async fn run_stmt_jobs(&self, limit: i64) -> Result<usize, ServiceError> {
+        let pending = self
+            .job_repo()?
+            .find_by_status(postings_db::models::stmt_job::StmtJobStatus::Pending, limit)
             .await
             .map_err(|_| ServiceError::Db)?;
+        let mut processed = 0;
+        for model in pending {
+            let account_bo = self.shared.load_ledger_account_bo(model.account_id).await?;
+            self.run_stmt_job(StmtJobMapper::to_bo(model, account_bo)).await?;
+            processed += 1;
+        }
+        Ok(processed)
+    }
 
-        stmt_model.stmt_status = StmtStatus::Closed;
-        stmt_model.posting_id = Some(closing_posting.id);
+    async fn annotate_stmt(&self, stmt_id: Uuid, author: String, text: String) -> Result<StmtAnnotation, ServiceError> {
+        let annotation_repo = self.annotation_repo.as_ref().ok_or(ServiceError::StmtAnnotationsDisabled)?;
+        if author.trim().is_empty() || text.trim().is_empty() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
         self.shared
             .stmt_repo
-            .save(stmt_model.clone())
+            .find_by_id(stmt_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::StatementNotFound)?;
+        let annotation = StmtAnnotation {
+            id: Uuid::new_v4(),
+            stmt_id,
+            author,
+            text,
+            created: Utc::now(),
+        };
+        let saved = annotation_repo
+            .save(StmtAnnotationMapper::to_model(annotation))
             .await
             .map_err(|_| ServiceError::Db)?;
+        Ok(StmtAnnotationMapper::to_bo(saved))
+    }
 
-        let mut closed_stmt_bo = stmt;
-        closed_stmt_bo.financial_stmt.stmt_status =
-            postings_api::domain::stmt_status::StmtStatus::CLOSED;
-        closed_stmt_bo.financial_stmt.posting = Some(closing_posting);
+    async fn find_stmt_annotations(&self, stmt_id: Uuid) -> Result<Vec<StmtAnnotation>, ServiceError> {
+        let annotation_repo = self.annotation_repo.as_ref().ok_or(ServiceError::StmtAnnotationsDisabled)?;
+        let annotations = annotation_repo
+            .find_by_stmt_id(stmt_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(annotations.into_iter().map(StmtAnnotationMapper::to_bo).collect())
+    }
 
-        Ok(closed_stmt_bo)
+    async fn verify_stmt_signature(&self, stmt_id: Uuid) -> Result<bool, ServiceError> {
+        let stmt = self
+            .shared
+            .stmt_repo
+            .find_by_id(stmt_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::StatementNotFound)?;
+        let (Some(signature), Some(key_id)) = (&stmt.signature, &stmt.signing_key_id) else {
+            return Err(ServiceError::StatementNotSigned);
+        };
+        let key = self.verifying_keys.get(key_id).ok_or(ServiceError::SignerNotConfigured)?;
+        if stmt.stmt_status != StmtStatus::Closed {
+            return Ok(false);
+        }
+        let content = canonical_stmt(&stmt).map_err(|_| ServiceError::NotEnoughInfo)?;
+        Ok(verify_ed25519(key, &content, signature))
     }
 }
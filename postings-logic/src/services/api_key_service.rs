@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use postings_api::domain::api_key::{ApiKey, ApiRole, IssuedApiKey};
+use postings_api::service::api_key_service::ApiKeyService;
+use postings_api::ServiceError;
+use postings_db::repositories::api_key_repository::ApiKeyRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_bytes;
+use crate::mappers::api_key::ApiKeyMapper;
+
+/// Prefix marking secrets issued by this store.
+const SECRET_SCHEME: &str = "lpk";
+
+pub struct ApiKeyServiceImpl {
+    key_repo: Arc<dyn ApiKeyRepository + Send + Sync>,
+}
+
+impl ApiKeyServiceImpl {
+    pub fn new(key_repo: Arc<dyn ApiKeyRepository + Send + Sync>) -> Self {
+        Self { key_repo }
+    }
+
+    /// Secrets have the form `lpk_<prefix>_<random>`; the prefix locates the stored key.
+    fn key_prefix(secret: &str) -> Option<&str> {
+        let mut parts = secret.splitn(3, '_');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(SECRET_SCHEME), Some(prefix), Some(_)) => Some(prefix),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyService for ApiKeyServiceImpl {
+    async fn issue_key(&self, name: String, tenant_id: Uuid, ledger_id: Option<Uuid>, roles: Vec<ApiRole>, expires: Option<DateTime<Utc>>) -> Result<IssuedApiKey, ServiceError> {
+        if name.is_empty() || roles.is_empty() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let key_prefix = Uuid::new_v4().simple().to_string()[..8].to_string();
+        let secret = format!("{SECRET_SCHEME}_{key_prefix}_{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let key = ApiKey {
+            id: Uuid::new_v4(),
+            name,
+            tenant_id,
+            ledger_id,
+            roles,
+            key_prefix,
+            key_hash: hash_bytes(secret.as_bytes()),
+            created: Utc::now(),
+            expires,
+            revoked_time: None,
+        };
+        self.key_repo
+            .save(ApiKeyMapper::to_model(key.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        info!("Issued API key {} for service account {}", key.id, key.name);
+        Ok(IssuedApiKey { key, secret })
+    }
+
+    async fn authenticate(&self, secret: &str) -> Result<ApiKey, ServiceError> {
+        let key_prefix = Self::key_prefix(secret).ok_or(ServiceError::ApiKeyInvalid)?;
+        let key = self.key_repo
+            .find_by_key_prefix(key_prefix)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .map(ApiKeyMapper::to_bo)
+            .ok_or(ServiceError::ApiKeyInvalid)?;
+        if key.key_hash != hash_bytes(secret.as_bytes()) || !key.is_active(Utc::now()) {
+            warn!("Rejected API key {}", key.id);
+            return Err(ServiceError::ApiKeyInvalid);
+        }
+        Ok(key)
+    }
+
+    async fn authorize(&self, secret: &str, tenant_id: Uuid, ledger_id: Uuid, role: ApiRole) -> Result<ApiKey, ServiceError> {
+        let key = self.authenticate(secret).await?;
+        if !key.grants(tenant_id, ledger_id, role) {
+            warn!("API key {} denied {} on ledger {ledger_id}", key.id, role.as_str());
+            return Err(ServiceError::Forbidden);
+        }
+        Ok(key)
+    }
+
+    async fn revoke_key(&self, key_id: Uuid) -> Result<ApiKey, ServiceError> {
+        let mut key = self.key_repo
+            .find_by_id(key_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .map(ApiKeyMapper::to_bo)
+            .ok_or(ServiceError::ApiKeyNotFound)?;
+        if key.revoked_time.is_none() {
+            key.revoked_time = Some(Utc::now());
+            self.key_repo
+                .save(ApiKeyMapper::to_model(key.clone()))
+                .await
+                .map_err(|_| ServiceError::Db)?;
+        }
+        Ok(key)
+    }
+
+    async fn find_keys_by_tenant(&self, tenant_id: Uuid) -> Result<Vec<ApiKey>, ServiceError> {
+        let keys = self.key_repo
+            .find_by_tenant_id(tenant_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(keys.into_iter().map(ApiKeyMapper::to_bo).collect())
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use log::{error, info};
+use postings_api::domain::backfill::{BackfillJob, BackfillStatus};
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::backfill_service::{BackfillBatch, BackfillService, BackfillTask};
+use postings_api::ServiceError;
+use postings_db::repositories::backfill_job_repository::BackfillJobRepository;
+use uuid::Uuid;
+use crate::mappers::backfill_job::BackfillJobMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct BackfillServiceImpl {
+    shared: SharedService,
+    job_repo: Arc<dyn BackfillJobRepository + Send + Sync>,
+    tasks: Vec<Arc<dyn BackfillTask + Send + Sync>>,
+}
+
+impl BackfillServiceImpl {
+    pub fn new(shared: SharedService, job_repo: Arc<dyn BackfillJobRepository + Send + Sync>) -> Self {
+        Self { shared, job_repo, tasks: Vec::new() }
+    }
+
+    /// Registers a task that can be started by its name.
+    pub fn with_task(mut self, task: Arc<dyn BackfillTask + Send + Sync>) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    fn task(&self, task_name: &str) -> Result<&Arc<dyn BackfillTask + Send + Sync>, ServiceError> {
+        self.tasks
+            .iter()
+            .find(|t| t.name() == task_name)
+            .ok_or(ServiceError::BackfillTaskNotFound)
+    }
+
+    async fn save_job(&self, job: &BackfillJob) -> Result<(), ServiceError> {
+        self.job_repo
+            .save(BackfillJobMapper::to_model(job.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BackfillService for BackfillServiceImpl {
+    async fn start_backfill(&self, task_name: &str, ledger: Ledger, batch_size: u32) -> Result<BackfillJob, ServiceError> {
+        if batch_size == 0 {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let task = self.task(task_name)?;
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let existing = self.job_repo
+            .find_unfinished_by_task_and_ledger(task_name, ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        if let Some(model) = existing {
+            return Ok(BackfillJobMapper::to_bo(model, ledger));
+        }
+
+        let total = task.count(&ledger).await?;
+        let now = Utc::now();
+        let job = BackfillJob {
+            id: Uuid::new_v4(),
+            task_name: task_name.to_string(),
+            ledger,
+            status: BackfillStatus::Running,
+            batch_size,
+            cursor: None,
+            processed: 0,
+            total,
+            error_message: None,
+            started: now,
+            updated: now,
+            finished: None,
+        };
+        self.save_job(&job).await?;
+        info!("Started backfill {} '{}' on ledger {}: {} records", job.id, task_name, job.ledger.id, total);
+        Ok(job)
+    }
+
+    async fn run_backfill(&self, job_id: Uuid, max_batches: u32) -> Result<BackfillJob, ServiceError> {
+        let mut job = self.find_backfill(job_id).await?.ok_or(ServiceError::BackfillJobNotFound)?;
+        if job.status == BackfillStatus::Completed {
+            return Ok(job);
+        }
+        let task = self.task(&job.task_name)?;
+        job.status = BackfillStatus::Running;
+        job.error_message = None;
+
+        for _ in 0..max_batches {
+            match task.run_batch(&job.ledger, job.cursor.as_deref(), job.batch_size).await {
+                Ok(BackfillBatch { processed, cursor }) => {
+                    job.processed += processed;
+                    job.updated = Utc::now();
+                    // The cursor is only advanced once the batch is done, so an interrupted batch is run again
+                    if cursor.is_none() {
+                        job.status = BackfillStatus::Completed;
+                        job.finished = Some(job.updated);
+                    } else {
+                        job.cursor = cursor;
+                    }
+                    self.save_job(&job).await?;
+                    info!(
+                        "Backfill {} '{}' on ledger {}: {}/{} records ({:.1}%)",
+                        job.id, job.task_name, job.ledger.id, job.processed, job.total, job.progress() * 100.0
+                    );
+                    if job.status == BackfillStatus::Completed {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    error!("Backfill {} '{}' failed on ledger {} after {} records: {e:?}", job.id, job.task_name, job.ledger.id, job.processed);
+                    job.status = BackfillStatus::Failed;
+                    job.error_message = Some(format!("{e:?}"));
+                    job.updated = Utc::now();
+                    self.save_job(&job).await?;
+                    break;
+                }
+            }
+        }
+        Ok(job)
+    }
+
+    async fn find_backfill(&self, job_id: Uuid) -> Result<Option<BackfillJob>, ServiceError> {
+        let model = self.job_repo
+            .find_by_id(job_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        match model {
+            Some(model) => {
+                let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+                Ok(Some(BackfillJobMapper::to_bo(model, ledger)))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Recomputes the opening totals and line count of closed statements created before those columns existed.
+/// Openings are the totals of the previous closed statement of the account; the line count covers the lines
+/// posted after it, up to the statement's posting time.
+pub struct StmtOpeningBalancesBackfill {
+    shared: SharedService,
+}
+
+impl StmtOpeningBalancesBackfill {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl BackfillTask for StmtOpeningBalancesBackfill {
+    fn name(&self) -> &str {
+        "stmt_opening_balances"
+    }
+
+    async fn count(&self, ledger: &Ledger) -> Result<u64, ServiceError> {
+        let count = self.shared
+            .stmt_repo
+            .count_closed_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(count as u64)
+    }
+
+    async fn run_batch(&self, ledger: &Ledger, cursor: Option<&str>, batch_size: u32) -> Result<BackfillBatch, ServiceError> {
+        let after = cursor
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|_| ServiceError::NotEnoughInfo)?;
+        let stmts = self.shared
+            .stmt_repo
+            .find_closed_by_ledger_id_after(ledger.id, after, batch_size as i64)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        for stmt in &stmts {
+            let previous = self.shared
+                .stmt_repo
+                .find_last_closed_by_account_and_pst_time_less_than(stmt.account_id, stmt.pst_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let (opening_debit, opening_credit, lines) = match previous {
+                Some(previous) => {
+                    let lines = self.shared
+                        .line_repo
+                        .find_by_account_and_pst_time_between(stmt.account_id, previous.pst_time, stmt.pst_time)
+                        .await
+                        .map_err(|_| ServiceError::Db)?;
+                    (previous.total_debit, previous.total_credit, lines)
+                }
+                None => {
+                    let lines = self.shared
+                        .line_repo
+                        .find_by_account_and_pst_time_less_than_equal(stmt.account_id, stmt.pst_time)
+                        .await
+                        .map_err(|_| ServiceError::Db)?;
+                    (BigDecimal::from(0), BigDecimal::from(0), lines)
+                }
+            };
+            self.shared
+                .stmt_repo
+                .update_opening_totals(stmt.id, opening_debit, opening_credit, lines.len() as i64)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+        }
+
+        let cursor = if stmts.len() < batch_size as usize {
+            None
+        } else {
+            stmts.last().map(|s| s.id.to_string())
+        };
+        Ok(BackfillBatch { processed: stmts.len() as u64, cursor })
+    }
+}
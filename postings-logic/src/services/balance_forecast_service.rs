@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{Days, NaiveDate, TimeZone, Utc};
+use postings_api::domain::balance_forecast::{BalanceForecast, ForecastHold, ForecastItem, ForecastSource};
+use postings_api::domain::earmark::EarmarkStatus;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::balance_forecast_service::BalanceForecastService;
+use postings_api::ServiceError;
+use postings_db::models::earmark::EarmarkStatus as EarmarkStatusModel;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::repositories::earmark_repository::EarmarkRepository;
+use postings_db::repositories::standing_order_repository::StandingOrderRepository;
+use crate::mappers::earmark::EarmarkMapper;
+use crate::mappers::standing_order::StandingOrderMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct BalanceForecastServiceImpl {
+    shared: SharedService,
+    order_repo: Arc<dyn StandingOrderRepository + Send + Sync>,
+    earmark_repo: Arc<dyn EarmarkRepository + Send + Sync>,
+}
+
+impl BalanceForecastServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        order_repo: Arc<dyn StandingOrderRepository + Send + Sync>,
+        earmark_repo: Arc<dyn EarmarkRepository + Send + Sync>,
+    ) -> Self {
+        Self { shared, order_repo, earmark_repo }
+    }
+
+    async fn standing_order_items(&self, ledger_account: &LedgerAccount, from: NaiveDate, until: NaiveDate) -> Result<Vec<ForecastItem>, ServiceError> {
+        let models = self.order_repo
+            .find_active_by_account_id(ledger_account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut items = Vec::new();
+        for model in models {
+            let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+            let source_bo = self.shared.load_ledger_account_bo(model.source_account_id).await?;
+            let target_bo = self.shared.load_ledger_account_bo(model.target_account_id).await?;
+            let order = StandingOrderMapper::to_bo(model, ledger_bo, source_bo, target_bo);
+            let calendar = self.shared.business_calendar(order.ledger.id).await?;
+            // The source account is debited, the target account credited
+            let (debit_amount, credit_amount) = if order.source_account.id == ledger_account.id {
+                (order.amount.clone(), BigDecimal::from(0))
+            } else {
+                (BigDecimal::from(0), order.amount.clone())
+            };
+            items.extend(
+                order
+                    .upcoming_dates(until, calendar.as_ref())
+                    .into_iter()
+                    .filter(|date| *date >= from)
+                    .map(|date| ForecastItem {
+                        date,
+                        debit_amount: debit_amount.clone(),
+                        credit_amount: credit_amount.clone(),
+                        source: ForecastSource::StandingOrder(order.id),
+                    }),
+            );
+        }
+        Ok(items)
+    }
+}
+
+#[async_trait]
+impl BalanceForecastService for BalanceForecastServiceImpl {
+    async fn forecast(&self, ledger_account: LedgerAccount, from: NaiveDate, days: u32) -> Result<BalanceForecast, ServiceError> {
+        let until = from + Days::new(days.saturating_sub(1) as u64);
+        let start_time = Utc.from_utc_datetime(&from.and_hms_opt(0, 0, 0).unwrap());
+        let end_time = Utc.from_utc_datetime(&(until + Days::new(1)).and_hms_opt(0, 0, 0).unwrap());
+        let opening_balance = self.shared.booked_balance(&ledger_account, start_time).await?;
+
+        let mut items: Vec<ForecastItem> = self.shared
+            .line_repo
+            .find_by_account_and_pst_time_between(ledger_account.id, start_time, end_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .filter(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none())
+            .map(|l| ForecastItem {
+                date: l.pst_time.date_naive(),
+                debit_amount: l.debit_amount,
+                credit_amount: l.credit_amount,
+                source: ForecastSource::ScheduledLine(l.id),
+            })
+            .collect();
+        items.extend(self.standing_order_items(&ledger_account, from, until).await?);
+
+        let holds: Vec<ForecastHold> = self.earmark_repo
+            .find_by_account_id_and_status(ledger_account.id, EarmarkStatusModel::Active)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .map(|m| EarmarkMapper::to_bo(m, ledger_account.clone()))
+            .filter(|e| e.status == EarmarkStatus::Active)
+            .map(|e| ForecastHold {
+                amount: e.remaining_amount(),
+                release_date: e.expiry.map(|expiry| expiry.date_naive()),
+            })
+            .collect();
+
+        Ok(BalanceForecast::project(ledger_account, opening_balance, &holds, items, from, days))
+    }
+}
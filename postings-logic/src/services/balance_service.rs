@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::service::balance_service::BalanceService;
+use postings_api::ServiceError;
+use uuid::Uuid;
+use crate::services::shared_service::SharedService;
+
+pub struct BalanceServiceImpl {
+    shared: SharedService,
+}
+
+impl BalanceServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl BalanceService for BalanceServiceImpl {
+    async fn balances(&self, account_ids: Vec<Uuid>, ref_time: DateTime<Utc>) -> Result<Vec<AccountBalance>, ServiceError> {
+        let mut accounts = Vec::with_capacity(account_ids.len());
+        for &account_id in &account_ids {
+            accounts.push(self.shared.load_ledger_account_bo(account_id).await?);
+        }
+        let totals: HashMap<Uuid, (BigDecimal, BigDecimal)> = self.shared.line_repo
+            .sum_by_account_ids_and_pst_time_less_than_equal(&account_ids, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .map(|t| (t.account_id, (t.total_debit, t.total_credit)))
+            .collect();
+        Ok(accounts
+            .into_iter()
+            .map(|account| {
+                // A duplicated id gets the same totals each time
+                let (total_debit, total_credit) = totals
+                    .get(&account.id)
+                    .cloned()
+                    .unwrap_or_else(|| (BigDecimal::from(0), BigDecimal::from(0)));
+                AccountBalance { account, ref_time, total_debit, total_credit, known_at: None }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use postings_api::domain::business_calendar::BusinessCalendar;
+use postings_api::domain::holiday::Holiday;
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::calendar_service::CalendarService;
+use postings_api::ServiceError;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use crate::mappers::holiday::HolidayMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct CalendarServiceImpl {
+    shared: SharedService,
+    holiday_repo: Arc<dyn HolidayRepository + Send + Sync>,
+}
+
+impl CalendarServiceImpl {
+    pub fn new(shared: SharedService, holiday_repo: Arc<dyn HolidayRepository + Send + Sync>) -> Self {
+        Self { shared, holiday_repo }
+    }
+}
+
+#[async_trait]
+impl CalendarService for CalendarServiceImpl {
+    async fn add_holiday(&self, mut holiday: Holiday) -> Result<Holiday, ServiceError> {
+        self.shared.ensure_writable(holiday.ledger.id).await?;
+        holiday.ledger = self.shared.load_ledger_bo(holiday.ledger.id).await?;
+        let ledger_bo = holiday.ledger.clone();
+        let saved = self
+            .holiday_repo
+            .save(HolidayMapper::to_model(holiday))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(HolidayMapper::to_bo(saved, ledger_bo))
+    }
+
+    async fn remove_holiday(&self, ledger: Ledger, date: NaiveDate) -> Result<(), ServiceError> {
+        self.shared.ensure_writable(ledger.id).await?;
+        self.holiday_repo
+            .delete(ledger.id, date)
+            .await
+            .map_err(|_| ServiceError::Db)
+    }
+
+    async fn find_holidays(&self, ledger: Ledger, from: NaiveDate, to: NaiveDate) -> Result<Vec<Holiday>, ServiceError> {
+        let models = self
+            .holiday_repo
+            .find_by_ledger_id_and_date_between(ledger.id, from, to)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(models.into_iter().map(|m| HolidayMapper::to_bo(m, ledger.clone())).collect())
+    }
+
+    async fn load_calendar(&self, ledger: Ledger) -> Result<BusinessCalendar, ServiceError> {
+        let models = self
+            .holiday_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(BusinessCalendar::new(models.into_iter().map(|m| m.holiday_date)))
+    }
+}
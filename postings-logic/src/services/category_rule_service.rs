@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::category_rule::{categorize, CategoryRule};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::service::category_rule_service::CategoryRuleService;
+use postings_api::ServiceError;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::mappers::category_rule::CategoryRuleMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct CategoryRuleServiceImpl {
+    shared: SharedService,
+    rule_repo: Arc<dyn CategoryRuleRepository + Send + Sync>,
+}
+
+impl CategoryRuleServiceImpl {
+    pub fn new(shared: SharedService, rule_repo: Arc<dyn CategoryRuleRepository + Send + Sync>) -> Self {
+        Self { shared, rule_repo }
+    }
+}
+
+/// Sets the category of each line from the rules of its account's ledger.
+pub(crate) async fn categorize_lines(
+    rule_repo: &(dyn CategoryRuleRepository + Send + Sync),
+    lines: &mut [PostingLine],
+) -> Result<(), ServiceError> {
+    let mut rules_by_ledger: BTreeMap<Uuid, Vec<CategoryRule>> = BTreeMap::new();
+    for line in lines.iter_mut() {
+        let ledger = &line.account.ledger;
+        if !rules_by_ledger.contains_key(&ledger.id) {
+            let rules = rule_repo
+                .find_by_ledger_id(ledger.id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .map(|m| CategoryRuleMapper::to_bo(m, ledger.clone()))
+                .collect();
+            rules_by_ledger.insert(ledger.id, rules);
+        }
+        line.category = categorize(&rules_by_ledger[&ledger.id], line);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CategoryRuleService for CategoryRuleServiceImpl {
+    async fn save_category_rule(&self, mut rule: CategoryRule) -> Result<CategoryRule, ServiceError> {
+        self.shared.ensure_writable(rule.ledger.id).await?;
+        rule.created = Utc::now();
+        let ledger = rule.ledger.clone();
+        let saved = self.rule_repo
+            .save(CategoryRuleMapper::to_model(rule))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(CategoryRuleMapper::to_bo(saved, ledger))
+    }
+
+    async fn find_category_rules(&self, ledger: Ledger) -> Result<Vec<CategoryRule>, ServiceError> {
+        let models = self.rule_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(models.into_iter().map(|m| CategoryRuleMapper::to_bo(m, ledger.clone())).collect())
+    }
+
+    async fn delete_category_rule(&self, rule_id: Uuid) -> Result<(), ServiceError> {
+        self.rule_repo.delete_by_id(rule_id).await.map_err(|e| match e {
+            DbError::NotFound => ServiceError::CategoryRuleNotFound,
+            _ => ServiceError::Db,
+        })
+    }
+}
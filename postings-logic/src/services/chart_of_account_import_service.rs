@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::coa_import::{self, CoaImportError, CoaImportFormat, CoaImportReport, CoaImportRequest, CoaImportRow, ImportedAccount};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::service::chart_of_account_import_service::ChartOfAccountImportService;
+use postings_api::ServiceError;
+use postings_db::repositories::chart_of_account_import_repository::ChartOfAccountImportRepository;
+use uuid::Uuid;
+use crate::mappers::chart_of_account::ChartOfAccountMapper;
+use crate::mappers::ledger::LedgerMapper;
+use crate::mappers::ledger_account::LedgerAccountMapper;
+use crate::mappers::named::NamedMapper;
+
+pub struct ChartOfAccountImportServiceImpl {
+    import_repo: Arc<dyn ChartOfAccountImportRepository + Send + Sync>,
+}
+
+impl ChartOfAccountImportServiceImpl {
+    pub fn new(import_repo: Arc<dyn ChartOfAccountImportRepository + Send + Sync>) -> Self {
+        Self { import_repo }
+    }
+
+    /// Expects a YAML sequence of rows; entries are numbered from 1 in the order they appear.
+    fn parse_yaml(source: &str) -> (Vec<(usize, CoaImportRow)>, Vec<CoaImportError>) {
+        let values: Vec<serde_yaml::Value> = match serde_yaml::from_str(source) {
+            Ok(values) => values,
+            Err(e) => {
+                let record = e.location().map(|l| l.line()).unwrap_or(0);
+                return (Vec::new(), vec![CoaImportError { record, name: None, reason: e.to_string() }]);
+            }
+        };
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+        for (idx, value) in values.into_iter().enumerate() {
+            let name = value.get("name").and_then(|n| n.as_str()).map(str::to_string);
+            match serde_yaml::from_value::<CoaImportRow>(value) {
+                Ok(row) => rows.push((idx + 1, row)),
+                Err(e) => errors.push(CoaImportError { record: idx + 1, name, reason: e.to_string() }),
+            }
+        }
+        (rows, errors)
+    }
+
+    fn named(request: &CoaImportRequest, name: &str, container: Uuid, context: Uuid, container_type: ContainerType) -> Named {
+        Named {
+            id: Uuid::new_v4(),
+            container,
+            context,
+            name: name.to_string(),
+            language: request.language.clone(),
+            created: Utc::now(),
+            user_details: request.user_details,
+            short_desc: None,
+            long_desc: None,
+            container_type,
+        }
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountImportService for ChartOfAccountImportServiceImpl {
+    async fn import(&self, request: CoaImportRequest) -> Result<CoaImportReport, ServiceError> {
+        let (rows, mut errors) = match request.format {
+            CoaImportFormat::Csv => CoaImportRow::parse_csv(&request.source),
+            CoaImportFormat::Yaml => Self::parse_yaml(&request.source),
+        };
+        let entries = match coa_import::plan(rows) {
+            Ok(entries) if errors.is_empty() => entries,
+            Ok(_) => return Ok(CoaImportReport::rejected(errors)),
+            Err(plan_errors) => {
+                errors.extend(plan_errors);
+                errors.sort_by_key(|e| e.record);
+                return Ok(CoaImportReport::rejected(errors));
+            }
+        };
+
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+        let mut named = vec![
+            Self::named(&request, &request.name, coa.id, coa.id, ContainerType::ChartOfAccount),
+            Self::named(&request, &request.name, ledger.id, coa.id, ContainerType::Ledger),
+        ];
+        // Entries come parents first, so every parent is already in the map.
+        let mut by_path: HashMap<String, LedgerAccount> = HashMap::new();
+        let mut accounts = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let parent = entry.parent_path.as_ref()
+                .and_then(|p| by_path.get(p))
+                .map(|p| Box::new(p.clone()));
+            let account = LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                parent,
+                coa: coa.clone(),
+                balance_side: entry.row.effective_balance_side(),
+                category: entry.row.category.clone(),
+                currency: entry.row.currency.clone(),
+            };
+            named.push(Self::named(&request, entry.row.name.trim(), account.id, ledger.id, ContainerType::LedgerAccount));
+            by_path.insert(entry.path.clone(), account.clone());
+            accounts.push(ImportedAccount { path: entry.path, account });
+        }
+
+        let account_models: Vec<_> = accounts.iter().map(|a| LedgerAccountMapper::to_model(a.account.clone())).collect();
+        let named_models: Vec<_> = named.into_iter().map(NamedMapper::to_model).collect();
+        self.import_repo
+            .save_all(
+                &ChartOfAccountMapper::to_model(coa),
+                &LedgerMapper::to_model(ledger.clone()),
+                &account_models,
+                &named_models,
+            )
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        Ok(CoaImportReport { ledger: Some(ledger), accounts, errors: Vec::new() })
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use log::info;
+use postings_api::domain::currency::CurrencyTotal;
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::checkpoint_service::CheckpointService;
+use postings_api::ServiceError;
+use postings_db::models::balance_checkpoint::BalanceCheckpoint;
+use postings_db::models::line_order::LineOrder;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use uuid::Uuid;
+use crate::services::shared_service::SharedService;
+
+pub struct CheckpointServiceImpl {
+    shared: SharedService,
+    checkpoint_repo: Arc<dyn BalanceCheckpointRepository + Send + Sync>,
+}
+
+impl CheckpointServiceImpl {
+    pub fn new(shared: SharedService, checkpoint_repo: Arc<dyn BalanceCheckpointRepository + Send + Sync>) -> Self {
+        Self { shared, checkpoint_repo }
+    }
+
+    async fn create_checkpoint(&self, account_id: Uuid, as_of: DateTime<Utc>) -> Result<BalanceCheckpoint, ServiceError> {
+        let last_closed = self.shared.stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(account_id, as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let base_stmt_id = last_closed.as_ref().map(|stmt| stmt.id);
+        // A previous checkpoint only counts while no statement was closed after it
+        let previous = self.checkpoint_repo
+            .find_last_by_account_and_as_of_less_than_equal(account_id, as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .filter(|c| c.as_of < as_of && c.base_stmt_id == base_stmt_id);
+
+        let mut checkpoint = BalanceCheckpoint {
+            id: Uuid::new_v4(),
+            account_id,
+            as_of,
+            base_stmt_id,
+            total_debit: BigDecimal::from(0),
+            total_credit: BigDecimal::from(0),
+            line_count: 0,
+            currency_totals: None,
+            created: Utc::now(),
+        };
+        let since = match (previous, last_closed) {
+            (Some(previous), _) => {
+                checkpoint.total_debit = previous.total_debit;
+                checkpoint.total_credit = previous.total_credit;
+                checkpoint.line_count = previous.line_count;
+                checkpoint.currency_totals = previous.currency_totals;
+                Some(previous.as_of)
+            }
+            (None, Some(stmt)) => {
+                checkpoint.total_debit = stmt.total_debit;
+                checkpoint.total_credit = stmt.total_credit;
+                checkpoint.currency_totals = stmt.currency_totals;
+                Some(stmt.pst_time)
+            }
+            (None, None) => None,
+        };
+
+        let mut currency_totals: Vec<CurrencyTotal> = checkpoint
+            .currency_totals
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        let mut lines = match since {
+            Some(since) => self.shared.line_repo.stream_by_account_and_pst_time_between(account_id, since, as_of, LineOrder::default()),
+            None => self.shared.line_repo.stream_by_account_and_pst_time_less_than_equal(account_id, as_of, LineOrder::default()),
+        };
+        while let Some(line) = lines.next().await {
+            let line = line.map_err(|_| ServiceError::Db)?;
+            checkpoint.total_debit += line.debit_amount.clone();
+            checkpoint.total_credit += line.credit_amount.clone();
+            if let Some(currency) = &line.currency {
+                CurrencyTotal::accumulate(&mut currency_totals, currency, &line.debit_amount, &line.credit_amount);
+            }
+            checkpoint.line_count += 1;
+        }
+        checkpoint.currency_totals = if currency_totals.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&currency_totals).ok()
+        };
+
+        self.checkpoint_repo.save(&checkpoint).await.map_err(|_| ServiceError::Db)?;
+        Ok(checkpoint)
+    }
+}
+
+#[async_trait]
+impl CheckpointService for CheckpointServiceImpl {
+    async fn create_checkpoints(&self, ledger: Ledger, as_of: DateTime<Utc>) -> Result<u64, ServiceError> {
+        // Lines may still be booked at future posting times, which would pass the checkpoint
+        if as_of > Utc::now() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let mut created = 0;
+        for account in self.shared.load_ledger_accounts_bo(&ledger).await? {
+            let checkpoint = self.create_checkpoint(account.id, as_of).await?;
+            info!("Checkpoint of account {} at {as_of}: {} lines", account.id, checkpoint.line_count);
+            created += 1;
+        }
+        Ok(created)
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::stmt_diagnostics::AccountStmtStats;
+use postings_api::service::diagnostics_service::DiagnosticsService;
+use postings_api::ServiceError;
+use postings_db::repositories::stmt_metric_repository::StmtMetricRepository;
+use crate::mappers::stmt_metric::StmtMetricMapper;
+
+pub struct DiagnosticsServiceImpl {
+    metric_repo: Arc<dyn StmtMetricRepository + Send + Sync>,
+}
+
+impl DiagnosticsServiceImpl {
+    pub fn new(metric_repo: Arc<dyn StmtMetricRepository + Send + Sync>) -> Self {
+        Self { metric_repo }
+    }
+}
+
+#[async_trait]
+impl DiagnosticsService for DiagnosticsServiceImpl {
+    async fn slowest_accounts(&self, ledger: Ledger, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> Result<Vec<AccountStmtStats>, ServiceError> {
+        let stats = self.metric_repo
+            .find_slowest_accounts(ledger.id, from, to, limit as i64)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(stats.into_iter().map(StmtMetricMapper::stats_to_bo).collect())
+    }
+
+    async fn hottest_accounts(&self, ledger: Ledger, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> Result<Vec<AccountStmtStats>, ServiceError> {
+        let stats = self.metric_repo
+            .find_hottest_accounts(ledger.id, from, to, limit as i64)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(stats.into_iter().map(StmtMetricMapper::stats_to_bo).collect())
+    }
+}
@@ -0,0 +1,214 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::earmark::{Earmark, EarmarkStatus};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::earmark_service::EarmarkService;
+use postings_api::ServiceError;
+use postings_db::models::earmark::EarmarkStatus as EarmarkStatusModel;
+use postings_db::repositories::earmark_repository::EarmarkRepository;
+use postings_db::unit_of_work::UnitOfWork;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::earmark::EarmarkMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::posting_service::PostingServiceImpl;
+use crate::services::shared_service::SharedService;
+
+pub struct EarmarkServiceImpl {
+    shared: SharedService,
+    earmark_repo: Arc<dyn EarmarkRepository + Send + Sync>,
+    /// Books captures together with the earmark update, so it needs a unit-of-work repository.
+    posting_service: Arc<PostingServiceImpl>,
+}
+
+impl EarmarkServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        earmark_repo: Arc<dyn EarmarkRepository + Send + Sync>,
+        posting_service: Arc<PostingServiceImpl>,
+    ) -> Self {
+        Self { shared, earmark_repo, posting_service }
+    }
+
+    async fn load_earmark(&self, earmark_id: Uuid) -> Result<Earmark, ServiceError> {
+        let model = self
+            .earmark_repo
+            .find_by_id(earmark_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::EarmarkNotFound)?;
+        let account_bo = self.shared.load_ledger_account_bo(model.account_id).await?;
+        Ok(EarmarkMapper::to_bo(model, account_bo))
+    }
+
+    async fn save_earmark(&self, earmark: Earmark) -> Result<Earmark, ServiceError> {
+        let account_bo = earmark.account.clone();
+        let saved = self
+            .earmark_repo
+            .save(EarmarkMapper::to_model(earmark))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(EarmarkMapper::to_bo(saved, account_bo))
+    }
+
+    /// Earmark that can still be changed: blocking, on a ledger that is not read-only.
+    async fn load_blocking_earmark(&self, earmark_id: Uuid) -> Result<Earmark, ServiceError> {
+        let earmark = self.load_earmark(earmark_id).await?;
+        if !earmark.is_blocking(Utc::now()) {
+            return Err(ServiceError::EarmarkNotActive);
+        }
+        self.shared.ensure_writable(earmark.account.ledger.id).await?;
+        Ok(earmark)
+    }
+
+    async fn close_earmark(&self, earmark_id: Uuid, status: EarmarkStatus) -> Result<Earmark, ServiceError> {
+        let mut earmark = self.load_blocking_earmark(earmark_id).await?;
+        let now = Utc::now();
+        earmark.status = status;
+        earmark.closed_time = Some(now);
+        self.save_earmark(earmark).await
+    }
+}
+
+#[async_trait]
+impl EarmarkService for EarmarkServiceImpl {
+    async fn create_earmark(&self, mut earmark: Earmark) -> Result<Earmark, ServiceError> {
+        if earmark.amount <= BigDecimal::from(0) {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        earmark.account = self.shared.load_ledger_account_bo(earmark.account.id).await?;
+        self.shared.ensure_writable(earmark.account.ledger.id).await?;
+        earmark.id = Uuid::new_v4();
+        earmark.captured_amount = BigDecimal::from(0);
+        earmark.created = Utc::now();
+        earmark.status = EarmarkStatus::Active;
+        earmark.closed_time = None;
+        earmark.posting_id = None;
+        self.save_earmark(earmark).await
+    }
+
+    async fn find_earmark_by_id(&self, earmark_id: Uuid) -> Result<Option<Earmark>, ServiceError> {
+        match self.load_earmark(earmark_id).await {
+            Ok(earmark) => Ok(Some(earmark)),
+            Err(ServiceError::EarmarkNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_active_earmarks(&self, ledger_account: LedgerAccount) -> Result<Vec<Earmark>, ServiceError> {
+        let models = self
+            .earmark_repo
+            .find_by_account_id_and_status(ledger_account.id, EarmarkStatusModel::Active)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let now = Utc::now();
+        Ok(models
+            .into_iter()
+            .map(|m| EarmarkMapper::to_bo(m, ledger_account.clone()))
+            .filter(|e| e.is_blocking(now))
+            .collect())
+    }
+
+    async fn release_earmark(&self, earmark_id: Uuid) -> Result<Earmark, ServiceError> {
+        self.close_earmark(earmark_id, EarmarkStatus::Released).await
+    }
+
+    async fn consume_earmark(&self, earmark_id: Uuid, counter_account: LedgerAccount) -> Result<Earmark, ServiceError> {
+        let earmark = self.load_blocking_earmark(earmark_id).await?;
+        self.capture_earmark(earmark_id, earmark.remaining_amount(), counter_account).await
+    }
+
+    async fn capture_earmark(&self, earmark_id: Uuid, amount: BigDecimal, counter_account: LedgerAccount) -> Result<Earmark, ServiceError> {
+        let mut earmark = self.load_blocking_earmark(earmark_id).await?;
+        if amount <= BigDecimal::from(0) {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        if amount > earmark.remaining_amount() {
+            return Err(ServiceError::EarmarkAmountExceeded);
+        }
+        let counter_account = self.shared.load_ledger_account_bo(counter_account.id).await?;
+        if counter_account.ledger.id != earmark.account.ledger.id {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+
+        // One operation per captured state, so of two concurrent captures only one is booked
+        let opr_id = hash_serialize(&(earmark.id, earmark.captured_amount.to_string())).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let posting = PostingBuilder::new(
+            earmark.account.ledger.clone(),
+            opr_id,
+            hash_serialize(&"earmark-capture").map_err(|_| ServiceError::NotEnoughInfo)?,
+            Utc::now(),
+        )
+            .record_user(hash_serialize(&"earmark").map_err(|_| ServiceError::NotEnoughInfo)?)
+            .opr_src(hash_serialize(&earmark.id).map_err(|_| ServiceError::NotEnoughInfo)?)
+            .debit(earmark.account.clone(), amount.clone())
+            .credit(counter_account, amount.clone())
+            .build();
+
+        earmark.captured_amount += amount;
+        if earmark.remaining_amount() == BigDecimal::from(0) {
+            earmark.status = EarmarkStatus::Consumed;
+            earmark.closed_time = Some(Utc::now());
+        }
+        self.posting_service
+            .new_posting_with(posting, |posting| {
+                earmark.posting_id = Some(posting.id);
+                let mut work = UnitOfWork::new();
+                work.save_earmark(EarmarkMapper::to_model(earmark));
+                work
+            })
+            .await?;
+        self.load_earmark(earmark_id).await
+    }
+
+    async fn increase_earmark(&self, earmark_id: Uuid, amount: BigDecimal, expiry: Option<DateTime<Utc>>) -> Result<Earmark, ServiceError> {
+        let mut earmark = self.load_blocking_earmark(earmark_id).await?;
+        if amount <= BigDecimal::from(0) {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        earmark.amount += amount;
+        if expiry.is_some() {
+            earmark.expiry = expiry;
+        }
+        self.save_earmark(earmark).await
+    }
+
+    async fn expire_earmarks(&self, as_of: DateTime<Utc>) -> Result<Vec<Earmark>, ServiceError> {
+        let models = self
+            .earmark_repo
+            .find_active_expired(as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut expired = Vec::new();
+        for model in models {
+            let account_bo = self.shared.load_ledger_account_bo(model.account_id).await?;
+            // Read-only ledgers keep their earmarks until they are writable again
+            if self.shared.load_ledger(account_bo.ledger.id).await?.read_only {
+                continue;
+            }
+            let mut earmark = EarmarkMapper::to_bo(model, account_bo);
+            earmark.status = EarmarkStatus::Expired;
+            earmark.closed_time = Some(as_of);
+            expired.push(self.save_earmark(earmark).await?);
+        }
+        Ok(expired)
+    }
+
+    async fn available_balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<BigDecimal, ServiceError> {
+        let booked = self.shared.booked_balance(&ledger_account, ref_time).await?;
+
+        let blocked: BigDecimal = self
+            .earmark_repo
+            .find_by_account_id_and_status(ledger_account.id, EarmarkStatusModel::Active)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .map(|m| EarmarkMapper::to_bo(m, ledger_account.clone()))
+            .filter(|e| e.is_blocking(ref_time))
+            .map(|e| e.remaining_amount())
+            .sum();
+        Ok(booked - blocked)
+    }
+}
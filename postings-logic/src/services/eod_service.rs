@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+use log::{error, info};
+use postings_api::domain::eod_run::{EodRun, EodStatus, EodStepResult};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::stmt_delivery::StmtDocumentFormat;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::eod_service::{EodService, EodStep};
+use postings_api::service::ledger_closure_service::LedgerClosureService;
+use postings_api::service::stmt_delivery_service::StmtDeliveryService;
+use postings_api::ServiceError;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::eod_run_repository::EodRunRepository;
+use uuid::Uuid;
+use crate::export::account_stmt_exporter::{AccountStmtExporter, CsvFormat};
+use crate::mappers::account_stmt::AccountStmtMapper;
+use crate::mappers::eod_run::EodRunMapper;
+use crate::services::shared_service::SharedService;
+
+type Steps = Vec<Arc<dyn EodStep + Send + Sync>>;
+
+pub struct EodServiceImpl {
+    shared: SharedService,
+    run_repo: Arc<dyn EodRunRepository + Send + Sync>,
+    steps: Steps,
+    ledger_steps: HashMap<Uuid, Steps>,
+}
+
+impl EodServiceImpl {
+    pub fn new(shared: SharedService, run_repo: Arc<dyn EodRunRepository + Send + Sync>) -> Self {
+        Self { shared, run_repo, steps: Vec::new(), ledger_steps: HashMap::new() }
+    }
+
+    /// Appends a step to the default pipeline, run for ledgers without a pipeline of their own.
+    /// Steps are executed in the order they were added.
+    pub fn with_step(mut self, step: Arc<dyn EodStep + Send + Sync>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Appends a step to the pipeline of the ledger, which replaces the default pipeline for it.
+    pub fn with_ledger_step(mut self, ledger_id: Uuid, step: Arc<dyn EodStep + Send + Sync>) -> Self {
+        self.ledger_steps.entry(ledger_id).or_default().push(step);
+        self
+    }
+
+    fn steps_of(&self, ledger_id: Uuid) -> &Steps {
+        self.ledger_steps.get(&ledger_id).unwrap_or(&self.steps)
+    }
+
+    async fn load_run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<EodRun>, ServiceError> {
+        let model = self
+            .run_repo
+            .find_run_by_ledger_and_business_date(ledger.id, business_date)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        match model {
+            Some(model) => {
+                let steps = self
+                    .run_repo
+                    .find_step_results_by_run_id(model.id)
+                    .await
+                    .map_err(|_| ServiceError::Db)?;
+                Ok(Some(EodRunMapper::to_bo(model, ledger.clone(), steps)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn save_run(&self, run: &EodRun) -> Result<(), ServiceError> {
+        self.run_repo
+            .save_run(EodRunMapper::to_model(run.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(())
+    }
+
+    async fn save_step(&self, run_id: Uuid, step: &EodStepResult) -> Result<(), ServiceError> {
+        self.run_repo
+            .save_step_result(EodRunMapper::step_to_model(run_id, step.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EodService for EodServiceImpl {
+    async fn run_eod(&self, ledger: Ledger, business_date: NaiveDate) -> Result<EodRun, ServiceError> {
+        self.shared.ensure_writable(ledger.id).await?;
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let mut run = match self.load_run(&ledger, business_date).await? {
+            Some(run) if run.status == EodStatus::Completed => return Ok(run),
+            Some(mut run) => {
+                info!("Resuming end-of-day run {} for ledger {} on {}", run.id, ledger.id, business_date);
+                run.status = EodStatus::Running;
+                run.finished = None;
+                run
+            }
+            None => EodRun {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                business_date,
+                status: EodStatus::Running,
+                started: Utc::now(),
+                finished: None,
+                steps: Vec::new(),
+            },
+        };
+        self.save_run(&run).await?;
+
+        for (idx, step) in self.steps_of(ledger.id).iter().enumerate() {
+            let step_seq = idx as i32;
+            // A step completed by a previous attempt is a checkpoint and is not executed again
+            let checkpointed = run.steps.iter().any(|s| {
+                s.step_seq == step_seq && s.step_name == step.name() && s.status == EodStatus::Completed
+            });
+            if checkpointed {
+                continue;
+            }
+
+            let started = Utc::now();
+            let outcome = step.run(&ledger, business_date).await;
+            let result = EodStepResult {
+                step_seq,
+                step_name: step.name().to_string(),
+                status: if outcome.is_ok() { EodStatus::Completed } else { EodStatus::Failed },
+                message: match &outcome {
+                    Ok(message) => message.clone(),
+                    Err(e) => Some(format!("{e:?}")),
+                },
+                started,
+                finished: Some(Utc::now()),
+            };
+            self.save_step(run.id, &result).await?;
+            run.steps.retain(|s| s.step_seq != step_seq);
+            run.steps.push(result);
+
+            if let Err(e) = outcome {
+                error!("End-of-day step '{}' failed for ledger {} on {}: {e:?}", step.name(), ledger.id, business_date);
+                run.status = EodStatus::Failed;
+                run.finished = Some(Utc::now());
+                self.save_run(&run).await?;
+                return Ok(run);
+            }
+        }
+
+        run.status = EodStatus::Completed;
+        run.finished = Some(Utc::now());
+        self.save_run(&run).await?;
+        Ok(run)
+    }
+
+    async fn find_eod_run(&self, ledger: Ledger, business_date: NaiveDate) -> Result<Option<EodRun>, ServiceError> {
+        self.load_run(&ledger, business_date).await
+    }
+}
+
+/// Creates an account statement for every account of the ledger at the end of the business date.
+/// Accounts that already have a statement on the business date are skipped, so a step resumed
+/// after a failure does not create their statements twice.
+pub struct StatementGenerationStep {
+    shared: SharedService,
+    stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+}
+
+impl StatementGenerationStep {
+    pub fn new(shared: SharedService, stmt_service: Arc<dyn AccountStmtService + Send + Sync>) -> Self {
+        Self { shared, stmt_service }
+    }
+
+    async fn has_stmt(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<bool, ServiceError> {
+        for status in [StmtStatus::Simulated, StmtStatus::Closed] {
+            let stmt = self
+                .shared
+                .stmt_repo
+                .find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, status, from)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            if stmt.is_some_and(|s| s.pst_time <= to) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl EodStep for StatementGenerationStep {
+    fn name(&self) -> &str {
+        "statement_generation"
+    }
+
+    async fn run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<String>, ServiceError> {
+        let start = Utc.from_utc_datetime(&business_date.and_hms_opt(0, 0, 0).unwrap());
+        let ref_time = Utc.from_utc_datetime(&business_date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap());
+        let accounts = self.shared.load_ledger_accounts_bo(ledger).await?;
+        let mut created = 0;
+        let mut skipped = 0;
+        for account in accounts {
+            if self.has_stmt(account.id, start, ref_time).await? {
+                skipped += 1;
+                continue;
+            }
+            self.stmt_service.create_stmt(account, ref_time).await?;
+            created += 1;
+        }
+        Ok(Some(format!("{created} statements created, {skipped} already present")))
+    }
+}
+
+/// Removes posting traces left behind by simulated statements that were read but never persisted.
+pub struct TraceCompactionStep {
+    shared: SharedService,
+}
+
+impl TraceCompactionStep {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl EodStep for TraceCompactionStep {
+    fn name(&self) -> &str {
+        "trace_compaction"
+    }
+
+    async fn run(&self, ledger: &Ledger, _business_date: NaiveDate) -> Result<Option<String>, ServiceError> {
+        let deleted = self.shared
+            .trace_repo
+            .delete_orphaned_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(Some(format!("{deleted} orphaned traces deleted")))
+    }
+}
+
+/// Purges simulated statements that expired by the end of the business date.
+pub struct SimulatedStmtCleanupStep {
+    stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+}
+
+impl SimulatedStmtCleanupStep {
+    pub fn new(stmt_service: Arc<dyn AccountStmtService + Send + Sync>) -> Self {
+        Self { stmt_service }
+    }
+}
+
+#[async_trait]
+impl EodStep for SimulatedStmtCleanupStep {
+    fn name(&self) -> &str {
+        "simulated_stmt_cleanup"
+    }
+
+    async fn run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<String>, ServiceError> {
+        let as_of = Utc.from_utc_datetime(&business_date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap());
+        let deleted = self.stmt_service.purge_expired_simulated_stmts(ledger.clone(), as_of).await?;
+        Ok(Some(format!("{deleted} simulated statements purged")))
+    }
+}
+
+/// Locks the ledger against postings up to the end of the business date. A closure already
+/// covering the business date is kept, so a resumed step does not close the period twice.
+pub struct LedgerClosureStep {
+    closure_service: Arc<dyn LedgerClosureService + Send + Sync>,
+    allow_adjustments: bool,
+    context: PrivilegedContext,
+}
+
+impl LedgerClosureStep {
+    /// `context` is recorded as the closer of every period the step locks.
+    pub fn new(closure_service: Arc<dyn LedgerClosureService + Send + Sync>, allow_adjustments: bool, context: PrivilegedContext) -> Self {
+        Self { closure_service, allow_adjustments, context }
+    }
+}
+
+#[async_trait]
+impl EodStep for LedgerClosureStep {
+    fn name(&self) -> &str {
+        "ledger_closure"
+    }
+
+    async fn run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<String>, ServiceError> {
+        let cut_off = Utc.from_utc_datetime(&(business_date + Days::new(1)).and_hms_opt(0, 0, 0).unwrap());
+        let closures = self.closure_service.find_closures(ledger.clone()).await?;
+        if closures.iter().any(|c| c.account_id.is_none() && c.cut_off >= cut_off) {
+            return Ok(Some(format!("already closed up to {cut_off}")));
+        }
+        self.closure_service
+            .close_ledger(ledger.clone(), cut_off, self.allow_adjustments, self.context.clone())
+            .await?;
+        Ok(Some(format!("closed up to {cut_off}")))
+    }
+}
+
+/// Exports the statements closed on the business date as CSV and records their delivery.
+/// Statements already delivered as CSV are skipped, so a resumed step does not export them twice.
+pub struct StmtExportStep {
+    shared: SharedService,
+    exporter: AccountStmtExporter,
+    delivery_service: Arc<dyn StmtDeliveryService + Send + Sync>,
+}
+
+impl StmtExportStep {
+    pub fn new(shared: SharedService, delivery_service: Arc<dyn StmtDeliveryService + Send + Sync>) -> Self {
+        Self { exporter: AccountStmtExporter::new(shared.clone()), shared, delivery_service }
+    }
+}
+
+#[async_trait]
+impl EodStep for StmtExportStep {
+    fn name(&self) -> &str {
+        "stmt_export"
+    }
+
+    async fn run(&self, ledger: &Ledger, business_date: NaiveDate) -> Result<Option<String>, ServiceError> {
+        let start = Utc.from_utc_datetime(&business_date.and_hms_opt(0, 0, 0).unwrap());
+        let end = Utc.from_utc_datetime(&business_date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap());
+        let accounts = self.shared.load_ledger_accounts_bo(ledger).await?;
+        let mut exported = 0;
+        let mut skipped = 0;
+        for account in accounts {
+            let stmts = self
+                .shared
+                .stmt_repo
+                .find_closed_by_account_and_pst_time_between(account.id, start, end)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            for model in stmts {
+                let deliveries = self.delivery_service.find_deliveries(model.id).await?;
+                if deliveries.iter().any(|d| d.format == StmtDocumentFormat::Csv) {
+                    skipped += 1;
+                    continue;
+                }
+                let stmt = AccountStmtMapper::to_bo(model, account.clone(), None, None, None);
+                let mut document = Vec::new();
+                self.exporter.export(&stmt, &CsvFormat, &mut document).await?;
+                self.delivery_service.record_delivery(stmt, StmtDocumentFormat::Csv, &document, false).await?;
+                exported += 1;
+            }
+        }
+        Ok(Some(format!("{exported} statements exported, {skipped} already exported")))
+    }
+}
@@ -0,0 +1,180 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use postings_api::domain::escrow::{Escrow, EscrowStatus, EscrowTimeoutAction};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::service::escrow_service::EscrowService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::repositories::escrow_repository::EscrowRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::escrow::EscrowMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::shared_service::SharedService;
+
+pub struct EscrowServiceImpl {
+    shared: SharedService,
+    escrow_repo: Arc<dyn EscrowRepository + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+}
+
+impl EscrowServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        escrow_repo: Arc<dyn EscrowRepository + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+    ) -> Self {
+        Self { shared, escrow_repo, posting_service }
+    }
+
+    async fn to_bo(&self, model: postings_db::models::escrow::Escrow) -> Result<Escrow, ServiceError> {
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let source_bo = self.shared.load_ledger_account_bo(model.source_account_id).await?;
+        let escrow_bo = self.shared.load_ledger_account_bo(model.escrow_account_id).await?;
+        let target_bo = self.shared.load_ledger_account_bo(model.target_account_id).await?;
+        Ok(EscrowMapper::to_bo(model, ledger_bo, source_bo, escrow_bo, target_bo))
+    }
+
+    async fn load_escrow(&self, escrow_id: Uuid) -> Result<Escrow, ServiceError> {
+        let model = self
+            .escrow_repo
+            .find_by_id(escrow_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::EscrowNotFound)?;
+        self.to_bo(model).await
+    }
+
+    async fn save_escrow(&self, escrow: Escrow) -> Result<Escrow, ServiceError> {
+        self.escrow_repo
+            .save(EscrowMapper::to_model(escrow.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(escrow)
+    }
+
+    /// Books a transfer belonging to the escrow. `phase` distinguishes the operations of one escrow.
+    /// A transfer an earlier attempt recorded is returned instead of being booked again.
+    async fn transfer(&self, escrow: &Escrow, phase: &str, from: LedgerAccount, to: LedgerAccount, pst_time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        let opr_id = hash_serialize(&(escrow.id, phase)).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let recorded = self.posting_service.find_postings_by_operation_id(&opr_id).await?;
+        if let Some(posting) = recorded.into_iter().find(|p| p.discarded_time.is_none()) {
+            return Ok(posting);
+        }
+        let opr_src = hash_serialize(&escrow.id).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let posting = PostingBuilder::new(escrow.ledger.clone(), opr_id, escrow.opr_type, pst_time)
+            .record_user(hash_serialize(&"escrow").map_err(|_| ServiceError::NotEnoughInfo)?)
+            .opr_src(opr_src)
+            .debit(from, escrow.amount.clone())
+            .credit(to, escrow.amount.clone())
+            .build();
+        self.posting_service.new_posting(posting).await
+    }
+
+    async fn resolve(&self, mut escrow: Escrow, action: EscrowTimeoutAction) -> Result<Escrow, ServiceError> {
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ServiceError::EscrowAlreadyResolved);
+        }
+        self.shared.ensure_writable(escrow.ledger.id).await?;
+        let pending = EscrowMapper::to_model(escrow.clone());
+        let (status, to) = match action {
+            EscrowTimeoutAction::Release => (EscrowStatus::Released, escrow.target_account.clone()),
+            EscrowTimeoutAction::Return => (EscrowStatus::Returned, escrow.source_account.clone()),
+        };
+
+        // Claim the escrow first so a concurrent release or return cannot pay it out a second time
+        let now = Utc::now();
+        let claimed = self
+            .escrow_repo
+            .resolve(escrow.id, EscrowMapper::status_to_model(status.clone()), now)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        if !claimed {
+            return Err(ServiceError::EscrowAlreadyResolved);
+        }
+        // Release and return share the operation, so at most one of them is ever booked
+        let posting = match self.transfer(&escrow, "resolve", escrow.escrow_account.clone(), to, now).await {
+            Ok(posting) => posting,
+            Err(e) => {
+                // Back to pending, leaving the escrow free to be resolved again
+                if self.escrow_repo.save(pending).await.is_err() {
+                    error!("Escrow {} is marked resolved but its transfer was not recorded", escrow.id);
+                }
+                return Err(e);
+            }
+        };
+        escrow.status = status;
+        escrow.resolution_posting_id = Some(posting.id);
+        escrow.resolved_time = Some(now);
+        self.save_escrow(escrow).await
+    }
+}
+
+#[async_trait]
+impl EscrowService for EscrowServiceImpl {
+    async fn initiate_escrow(&self, mut escrow: Escrow) -> Result<Escrow, ServiceError> {
+        if escrow.amount <= BigDecimal::from(0) {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(escrow.ledger.id).await?;
+        escrow.ledger = self.shared.load_ledger_bo(escrow.ledger.id).await?;
+        escrow.source_account = self.shared.load_ledger_account_bo(escrow.source_account.id).await?;
+        escrow.escrow_account = self.shared.load_ledger_account_bo(escrow.escrow_account.id).await?;
+        escrow.target_account = self.shared.load_ledger_account_bo(escrow.target_account.id).await?;
+        let ledger_id = escrow.ledger.id;
+        if [&escrow.source_account, &escrow.escrow_account, &escrow.target_account].iter().any(|a| a.ledger.id != ledger_id) {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+        escrow.id = Uuid::new_v4();
+        escrow.status = EscrowStatus::Pending;
+        escrow.created = Utc::now();
+        escrow.resolution_posting_id = None;
+        escrow.resolved_time = None;
+
+        let posting = self.transfer(&escrow, "initiate", escrow.source_account.clone(), escrow.escrow_account.clone(), escrow.created).await?;
+        escrow.initiation_posting_id = Some(posting.id);
+        self.save_escrow(escrow).await
+    }
+
+    async fn find_escrow_by_id(&self, escrow_id: Uuid) -> Result<Option<Escrow>, ServiceError> {
+        match self.load_escrow(escrow_id).await {
+            Ok(escrow) => Ok(Some(escrow)),
+            Err(ServiceError::EscrowNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn confirm_escrow(&self, escrow_id: Uuid) -> Result<Escrow, ServiceError> {
+        let escrow = self.load_escrow(escrow_id).await?;
+        self.resolve(escrow, EscrowTimeoutAction::Release).await
+    }
+
+    async fn cancel_escrow(&self, escrow_id: Uuid) -> Result<Escrow, ServiceError> {
+        let escrow = self.load_escrow(escrow_id).await?;
+        self.resolve(escrow, EscrowTimeoutAction::Return).await
+    }
+
+    async fn resolve_expired(&self, as_of: DateTime<Utc>) -> Result<Vec<Escrow>, ServiceError> {
+        let expired = self
+            .escrow_repo
+            .find_pending_timed_out(as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut resolved = Vec::new();
+        for model in expired {
+            let escrow = self.to_bo(model).await?;
+            info!("Escrow {} timed out, applying {:?}", escrow.id, escrow.timeout_action);
+            let action = escrow.timeout_action.clone();
+            match self.resolve(escrow, action).await {
+                // Resolved once the ledger is writable again
+                Err(ServiceError::ReadOnly) => continue,
+                result => resolved.push(result?),
+            }
+        }
+        Ok(resolved)
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::external_ref::{ExternalRef, ExternalRefEntity, ExternalRefUniqueness};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::service::external_ref_service::ExternalRefService;
+use postings_api::ServiceError;
+use postings_db::models::external_ref::ExternalRef as ExternalRefModel;
+use postings_db::repositories::external_ref_repository::ExternalRefRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::mappers::external_ref::ExternalRefMapper;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+/// Scope value of references unique over all ledgers.
+const GLOBAL_SCOPE: &str = "*";
+
+pub struct ExternalRefServiceImpl {
+    shared: SharedService,
+    ref_repo: Arc<dyn ExternalRefRepository + Send + Sync>,
+    uniqueness: HashMap<String, ExternalRefUniqueness>,
+}
+
+impl ExternalRefServiceImpl {
+    pub fn new(shared: SharedService, ref_repo: Arc<dyn ExternalRefRepository + Send + Sync>) -> Self {
+        Self { shared, ref_repo, uniqueness: HashMap::new() }
+    }
+
+    /// Sets how many entities may carry the same reference of `system`, one per ledger by default.
+    /// Applies to references attached from now on.
+    pub fn with_uniqueness(mut self, system: impl Into<String>, uniqueness: ExternalRefUniqueness) -> Self {
+        self.uniqueness.insert(system.into(), uniqueness);
+        self
+    }
+
+    async fn attach(&self, entity_type: ExternalRefEntity, entity_id: Uuid, ledger_id: Uuid, system: &str, reference: &str) -> Result<ExternalRef, ServiceError> {
+        if system.is_empty() || reference.is_empty() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let entity_model = ExternalRefMapper::entity_to_model(entity_type);
+        let attached = self.ref_repo
+            .find_by_entity(entity_model, entity_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .find(|r| r.ref_system == system);
+        if let Some(attached) = attached {
+            return if attached.reference == reference {
+                Ok(ExternalRefMapper::to_bo(attached))
+            } else {
+                Err(ServiceError::ExternalRefTaken)
+            };
+        }
+
+        let unique_scope = match self.uniqueness.get(system).copied().unwrap_or_default() {
+            ExternalRefUniqueness::Shared => None,
+            ExternalRefUniqueness::PerLedger => Some(ledger_id.to_string()),
+            ExternalRefUniqueness::Global => Some(GLOBAL_SCOPE.to_string()),
+        };
+        let model = ExternalRefModel {
+            id: Uuid::new_v4(),
+            entity_type: entity_model,
+            entity_id,
+            ledger_id,
+            ref_system: system.to_string(),
+            reference: reference.to_string(),
+            unique_scope,
+            created: Utc::now(),
+        };
+        // The unique constraints decide concurrent attachments
+        match self.ref_repo.save(&model).await {
+            Ok(()) => Ok(ExternalRefMapper::to_bo(model)),
+            Err(DbError::UniqueViolation) => Err(ServiceError::ExternalRefTaken),
+            Err(_) => Err(ServiceError::Db),
+        }
+    }
+
+    async fn find_entity_ids(&self, ledger_id: Uuid, entity_type: ExternalRefEntity, system: &str, reference: &str) -> Result<Vec<Uuid>, ServiceError> {
+        let refs = self.ref_repo
+            .find_by_ledger_and_reference(ledger_id, ExternalRefMapper::entity_to_model(entity_type), system, reference)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(refs.into_iter().map(|r| r.entity_id).collect())
+    }
+}
+
+#[async_trait]
+impl ExternalRefService for ExternalRefServiceImpl {
+    async fn attach_posting_ref(&self, posting_id: Uuid, system: &str, reference: &str) -> Result<ExternalRef, ServiceError> {
+        let posting = self.shared.posting_repo
+            .find_by_id(posting_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingNotFound)?;
+        self.shared.ensure_writable(posting.ledger_id).await?;
+        self.attach(ExternalRefEntity::Posting, posting.id, posting.ledger_id, system, reference).await
+    }
+
+    async fn attach_account_ref(&self, account_id: Uuid, system: &str, reference: &str) -> Result<ExternalRef, ServiceError> {
+        let account = self.shared
+            .load_ledger_account(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        self.shared.ensure_writable(account.ledger_id).await?;
+        self.attach(ExternalRefEntity::LedgerAccount, account.id, account.ledger_id, system, reference).await
+    }
+
+    async fn find_refs(&self, entity_type: ExternalRefEntity, entity_id: Uuid) -> Result<Vec<ExternalRef>, ServiceError> {
+        let refs = self.ref_repo
+            .find_by_entity(ExternalRefMapper::entity_to_model(entity_type), entity_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(refs.into_iter().map(ExternalRefMapper::to_bo).collect())
+    }
+
+    async fn find_postings_by_ref(&self, ledger: Ledger, system: &str, reference: &str) -> Result<Vec<Posting>, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let mut postings = Vec::new();
+        for posting_id in self.find_entity_ids(ledger.id, ExternalRefEntity::Posting, system, reference).await? {
+            let Some(model) = self.shared.posting_repo.find_by_id(posting_id).await.map_err(|_| ServiceError::Db)? else {
+                continue;
+            };
+            // Lines of a version were discarded together with it
+            let lines = self.shared.line_repo
+                .find_by_opr_id(&model.opr_id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .filter(|l| l.discarded_time == model.discarded_time)
+                .collect();
+            let lines = self.shared.lines_to_bo(lines).await?;
+            postings.push(PostingMapper::to_bo(model, ledger.clone(), lines)?);
+        }
+        postings.sort_by_key(|p| p.record_time);
+        Ok(postings)
+    }
+
+    async fn find_accounts_by_ref(&self, ledger: Ledger, system: &str, reference: &str) -> Result<Vec<LedgerAccount>, ServiceError> {
+        let mut accounts = Vec::new();
+        for account_id in self.find_entity_ids(ledger.id, ExternalRefEntity::LedgerAccount, system, reference).await? {
+            accounts.push(self.shared.load_ledger_account_bo(account_id).await?);
+        }
+        Ok(accounts)
+    }
+}
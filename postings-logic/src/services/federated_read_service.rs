@@ -0,0 +1,88 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_activity::{merge_lines, AccountActivity};
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::service::federated_read_service::{FederatedReadService, ReadBackend};
+use postings_api::ServiceError;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::services::shared_service::SharedService;
+
+/// Source name of the lines read from the ledger database.
+const DATABASE_SOURCE: &str = "database";
+
+pub struct FederatedReadServiceImpl {
+    shared: SharedService,
+    backends: Vec<Arc<dyn ReadBackend + Send + Sync>>,
+}
+
+impl FederatedReadServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared, backends: Vec::new() }
+    }
+
+    /// Adds a backend queried for periods starting before its coverage ends. When a line is held by
+    /// several stores, the database wins, then backends in the order they were added.
+    pub fn with_backend(mut self, backend: Arc<dyn ReadBackend + Send + Sync>) -> Self {
+        self.backends.push(backend);
+        self
+    }
+
+    /// Lines of the account posted after `from` and up to `to` in all stores, with the names of the stores that returned some.
+    async fn read_lines(&self, ledger_account: &LedgerAccount, from: Option<DateTime<Utc>>, to: DateTime<Utc>) -> Result<(Vec<PostingLine>, Vec<String>), ServiceError> {
+        let database_lines = match from {
+            Some(from) => self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, from, to).await,
+            None => self.shared.line_repo.find_by_account_and_pst_time_less_than_equal(ledger_account.id, to).await,
+        }
+        .map_err(|_| ServiceError::Db)?;
+
+        let mut sources = Vec::new();
+        if !database_lines.is_empty() {
+            sources.push(DATABASE_SOURCE.to_string());
+        }
+        let mut parts = vec![database_lines
+            .into_iter()
+            .map(|l| PostingLineMapper::to_bo(l, ledger_account.clone()))
+            .collect::<Vec<_>>()];
+        for backend in self.backends.iter() {
+            if from.is_some_and(|from| from >= backend.covered_until()) {
+                continue;
+            }
+            let lines = backend.find_lines(ledger_account, from, to).await?;
+            if !lines.is_empty() {
+                sources.push(backend.name().to_string());
+            }
+            parts.push(lines);
+        }
+        Ok((merge_lines(parts), sources))
+    }
+}
+
+#[async_trait]
+impl FederatedReadService for FederatedReadServiceImpl {
+    async fn balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        let (lines, _) = self.read_lines(&ledger_account, None, ref_time).await?;
+        let (total_debit, total_credit) = lines
+            .iter()
+            .fold((BigDecimal::from(0), BigDecimal::from(0)), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        Ok(AccountBalance { account: ledger_account, ref_time, total_debit, total_credit, known_at: None })
+    }
+
+    async fn activity(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<AccountActivity, ServiceError> {
+        if from > to {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let opening = self.balance(ledger_account.clone(), from).await?;
+        let (lines, sources) = self.read_lines(&ledger_account, Some(from), to).await?;
+        Ok(AccountActivity::new(
+            ledger_account,
+            (from, to),
+            (opening.total_debit, opening.total_credit),
+            lines,
+            sources,
+        ))
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::fee_schedule::{FeeSchedule, FeeType};
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::fee_schedule_service::FeeScheduleService;
+use postings_api::ServiceError;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::fee_schedule::FeeScheduleMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct FeeScheduleServiceImpl {
+    shared: SharedService,
+    fee_repo: Arc<dyn FeeScheduleRepository + Send + Sync>,
+}
+
+impl FeeScheduleServiceImpl {
+    pub fn new(shared: SharedService, fee_repo: Arc<dyn FeeScheduleRepository + Send + Sync>) -> Self {
+        Self { shared, fee_repo }
+    }
+}
+
+/// Loads the schedule applying to an operation type of the ledger, with its tiers and fee account.
+/// A schedule of `product_id` takes precedence over the ledger default.
+pub(crate) async fn load_fee_schedule(
+    shared: &SharedService,
+    fee_repo: &(dyn FeeScheduleRepository + Send + Sync),
+    ledger: &Ledger,
+    opr_type: &[u8; 34],
+    product_id: Option<Uuid>,
+) -> Result<Option<FeeSchedule>, ServiceError> {
+    let product_model = match product_id {
+        Some(product_id) => fee_repo
+            .find_by_product_id_and_opr_type(product_id, opr_type)
+            .await
+            .map_err(|_| ServiceError::Db)?,
+        None => None,
+    };
+    let model = match product_model {
+        Some(model) => model,
+        None => match fee_repo
+            .find_by_ledger_id_and_opr_type(ledger.id, opr_type)
+            .await
+            .map_err(|_| ServiceError::Db)?
+        {
+            Some(model) => model,
+            None => return Ok(None),
+        },
+    };
+    let tiers = fee_repo
+        .find_tiers_by_schedule_id(model.id)
+        .await
+        .map_err(|_| ServiceError::Db)?;
+    let fee_account_bo = shared.load_ledger_account_bo(model.fee_account_id).await?;
+    Ok(Some(FeeScheduleMapper::to_bo(model, ledger.clone(), fee_account_bo, tiers)))
+}
+
+/// `sub_opr_src_id` of the fee lines booked by the schedule, linking them to it.
+pub fn fee_line_ref(schedule_id: Uuid) -> Result<[u8; 34], ServiceError> {
+    hash_serialize(&schedule_id).map_err(|_| ServiceError::NotEnoughInfo)
+}
+
+#[async_trait]
+impl FeeScheduleService for FeeScheduleServiceImpl {
+    async fn save_fee_schedule(&self, mut schedule: FeeSchedule) -> Result<FeeSchedule, ServiceError> {
+        let complete = match schedule.fee_type {
+            FeeType::Flat => schedule.flat_amount.is_some(),
+            FeeType::Percentage => schedule.rate.is_some(),
+            FeeType::Tiered => !schedule.tiers.is_empty(),
+        };
+        if !complete {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(schedule.ledger.id).await?;
+        schedule.ledger = self.shared.load_ledger_bo(schedule.ledger.id).await?;
+        schedule.fee_account = self.shared.load_ledger_account_bo(schedule.fee_account.id).await?;
+        if schedule.fee_account.ledger.id != schedule.ledger.id {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+
+        // Saving a schedule for an operation type that already has one replaces it
+        let existing = match schedule.product_id {
+            Some(product_id) => self.fee_repo.find_by_product_id_and_opr_type(product_id, &schedule.opr_type).await,
+            None => self.fee_repo.find_by_ledger_id_and_opr_type(schedule.ledger.id, &schedule.opr_type).await,
+        }
+        .map_err(|_| ServiceError::Db)?;
+        if let Some(existing) = existing {
+            schedule.id = existing.id;
+            schedule.created = existing.created;
+        } else {
+            schedule.id = Uuid::new_v4();
+            schedule.created = Utc::now();
+        }
+
+        self.fee_repo
+            .save(FeeScheduleMapper::to_model(schedule.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let tiers = schedule
+            .tiers
+            .iter()
+            .cloned()
+            .map(|t| FeeScheduleMapper::tier_to_model(schedule.id, t))
+            .collect();
+        self.fee_repo
+            .save_tiers(schedule.id, tiers)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(schedule)
+    }
+
+    async fn find_fee_schedule(&self, ledger: Ledger, opr_type: &[u8; 34]) -> Result<Option<FeeSchedule>, ServiceError> {
+        load_fee_schedule(&self.shared, self.fee_repo.as_ref(), &ledger, opr_type, None).await
+    }
+
+    async fn calculate_fee(&self, ledger: Ledger, opr_type: &[u8; 34], amount: BigDecimal) -> Result<Option<BigDecimal>, ServiceError> {
+        let schedule = load_fee_schedule(&self.shared, self.fee_repo.as_ref(), &ledger, opr_type, None).await?;
+        Ok(schedule.map(|s| s.calculate(&amount)))
+    }
+}
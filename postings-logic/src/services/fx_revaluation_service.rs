@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use log::info;
+use postings_api::domain::fx_revaluation::{AccountRevaluation, FxRevaluation, RevaluationAccounts};
+use postings_api::domain::group_reporting::TranslationRate;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::service::fx_rate_provider::FxRateProvider;
+use postings_api::service::fx_revaluation_service::FxRevaluationService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::repositories::fx_revaluation_repository::FxRevaluationRepository;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::fx_revaluation::FxRevaluationMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::shared_service::SharedService;
+
+/// Revalues the accounts kept in a foreign currency into `functional_currency`, the currency
+/// the ledgers report in. Gains and losses are booked through the posting service, so they are
+/// chained and hashed like any other posting of their ledger.
+pub struct FxRevaluationServiceImpl {
+    shared: SharedService,
+    rate_provider: Arc<dyn FxRateProvider + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+    revaluation_repo: Arc<dyn FxRevaluationRepository + Send + Sync>,
+    functional_currency: String,
+}
+
+impl FxRevaluationServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        rate_provider: Arc<dyn FxRateProvider + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+        revaluation_repo: Arc<dyn FxRevaluationRepository + Send + Sync>,
+        functional_currency: impl Into<String>,
+    ) -> Self {
+        Self {
+            shared,
+            rate_provider,
+            posting_service,
+            revaluation_repo,
+            functional_currency: functional_currency.into(),
+        }
+    }
+
+    async fn closing_rate(&self, currency: &str, at: DateTime<Utc>, rates: &mut HashMap<(String, DateTime<Utc>), BigDecimal>) -> Result<BigDecimal, ServiceError> {
+        let key = (currency.to_string(), at);
+        if let Some(rate) = rates.get(&key) {
+            return Ok(rate.clone());
+        }
+        let rate = self.rate_provider
+            .closing_rate(currency, &self.functional_currency, at)
+            .await?
+            .ok_or(ServiceError::FxRateNotFound { currency: currency.to_string(), rate_type: TranslationRate::Closing })?;
+        rates.insert(key, rate.clone());
+        Ok(rate)
+    }
+
+    /// Revaluations of the ledger's foreign currency accounts at `valuation_time`, each with
+    /// whether it is booked already. Accounts without balance or carrying value are left out.
+    /// When `booking`, an account revalued at a later time fails the whole revaluation.
+    async fn revaluations(&self, ledger: &Ledger, valuation_time: DateTime<Utc>, booking: bool) -> Result<Vec<(AccountRevaluation, bool)>, ServiceError> {
+        let mut rates = HashMap::new();
+        let mut revaluations = Vec::new();
+        for account in self.shared.load_ledger_accounts_bo(ledger).await? {
+            let Some(currency) = account.currency.clone().filter(|c| *c != self.functional_currency) else {
+                continue;
+            };
+            let earlier = self.revaluation_repo
+                .find_by_account_id(account.id)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            if let Some(booked) = earlier.iter().find(|r| r.valuation_time == valuation_time) {
+                revaluations.push((FxRevaluationMapper::to_bo(booked.clone(), account), true));
+                continue;
+            }
+            if booking && earlier.first().is_some_and(|latest| latest.valuation_time > valuation_time) {
+                return Err(ServiceError::RevaluationSuperseded);
+            }
+
+            let lines = self.shared.line_repo
+                .find_by_account_and_pst_time_less_than_equal(account.id, valuation_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let zero = BigDecimal::from(0);
+            let (mut foreign_balance, mut carrying_value) = (zero.clone(), zero.clone());
+            for line in lines.iter().filter(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none()) {
+                let amount = line.debit_amount.clone() - line.credit_amount.clone();
+                // Lines count at the rate they were booked at, else at the closing rate of their posting time
+                let rate = match (&line.fx_base_currency, &line.fx_rate) {
+                    (Some(base), Some(rate)) if *base == self.functional_currency => rate.clone(),
+                    _ => self.closing_rate(&currency, line.pst_time, &mut rates).await?,
+                };
+                carrying_value += &amount * rate;
+                foreign_balance += amount;
+            }
+            for revaluation in earlier.iter().filter(|r| r.valuation_time < valuation_time) {
+                carrying_value += revaluation.revalued_value.clone() - revaluation.carrying_value.clone();
+            }
+            if foreign_balance == zero && carrying_value == zero {
+                continue;
+            }
+            let rate = self.closing_rate(&currency, valuation_time, &mut rates).await?;
+            revaluations.push((AccountRevaluation::new(account, currency, valuation_time, foreign_balance, rate, carrying_value), false));
+        }
+        Ok(revaluations)
+    }
+
+    /// Posting of the account's gain or loss. Its operation id is derived from the account and
+    /// the valuation time, so booking a revaluation again returns the posting recorded before.
+    fn revaluation_posting(revaluation: &AccountRevaluation, accounts: &RevaluationAccounts) -> Result<Posting, ServiceError> {
+        let opr_id = hash_serialize(&("fx-revaluation", revaluation.account.id, revaluation.valuation_time)).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let opr_type = hash_serialize(&"fx-revaluation").map_err(|_| ServiceError::NotEnoughInfo)?;
+        let gain_loss = revaluation.gain_loss();
+        let builder = PostingBuilder::new(revaluation.account.ledger.clone(), opr_id, opr_type, revaluation.valuation_time)
+            .record_user(opr_type)
+            .opr_src(hash_serialize(&revaluation.account.id).map_err(|_| ServiceError::NotEnoughInfo)?)
+            .val_time(revaluation.valuation_time);
+        let builder = if gain_loss > BigDecimal::from(0) {
+            builder
+                .debit(accounts.adjustment_account.clone(), gain_loss.clone())
+                .credit(accounts.gain_account.clone(), gain_loss)
+        } else {
+            builder
+                .debit(accounts.loss_account.clone(), -gain_loss.clone())
+                .credit(accounts.adjustment_account.clone(), -gain_loss)
+        };
+        Ok(builder.build())
+    }
+
+    /// The configured accounts as currently stored; they must belong to the ledger and be kept in
+    /// the functional currency.
+    async fn load_accounts(&self, ledger: &Ledger, accounts: RevaluationAccounts) -> Result<RevaluationAccounts, ServiceError> {
+        let mut loaded = Vec::with_capacity(3);
+        for account in [accounts.gain_account, accounts.loss_account, accounts.adjustment_account] {
+            let account = self.shared.load_ledger_account_bo(account.id).await?;
+            if account.ledger.id != ledger.id {
+                return Err(ServiceError::LedgerAccountNotFound);
+            }
+            if account.currency.as_ref().is_some_and(|c| *c != self.functional_currency) {
+                return Err(ServiceError::CurrencyMismatch);
+            }
+            loaded.push(account);
+        }
+        let adjustment_account = loaded.pop().unwrap();
+        let loss_account = loaded.pop().unwrap();
+        let gain_account = loaded.pop().unwrap();
+        Ok(RevaluationAccounts { gain_account, loss_account, adjustment_account })
+    }
+}
+
+#[async_trait]
+impl FxRevaluationService for FxRevaluationServiceImpl {
+    async fn compute_revaluation(&self, ledger: Ledger, valuation_time: DateTime<Utc>) -> Result<FxRevaluation, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let revaluations = self.revaluations(&ledger, valuation_time, false).await?;
+        Ok(FxRevaluation::new(ledger, self.functional_currency.clone(), valuation_time, revaluations.into_iter().map(|(r, _)| r).collect()))
+    }
+
+    async fn revalue(&self, ledger: Ledger, valuation_time: DateTime<Utc>, accounts: RevaluationAccounts) -> Result<FxRevaluation, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        self.shared.ensure_writable(ledger.id).await?;
+        let accounts = self.load_accounts(&ledger, accounts).await?;
+
+        let mut booked = Vec::new();
+        for (mut revaluation, stored) in self.revaluations(&ledger, valuation_time, true).await? {
+            if !stored {
+                if revaluation.gain_loss() != BigDecimal::from(0) {
+                    let posting = self.posting_service.new_posting(Self::revaluation_posting(&revaluation, &accounts)?).await?;
+                    revaluation.posting_id = Some(posting.id);
+                }
+                self.revaluation_repo
+                    .save(&FxRevaluationMapper::to_model(revaluation.clone()))
+                    .await
+                    .map_err(|_| ServiceError::Db)?;
+                info!("Revalued account {} at {}: {} {} at {}", revaluation.account.id, valuation_time, revaluation.foreign_balance, revaluation.currency, revaluation.rate);
+            }
+            booked.push(revaluation);
+        }
+        Ok(FxRevaluation::new(ledger, self.functional_currency.clone(), valuation_time, booked))
+    }
+
+    async fn find_revaluations(&self, ledger_account: LedgerAccount) -> Result<Vec<AccountRevaluation>, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(ledger_account.id).await?;
+        let revaluations = self.revaluation_repo
+            .find_by_account_id(account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(revaluations.into_iter().map(|r| FxRevaluationMapper::to_bo(r, account.clone())).collect())
+    }
+}
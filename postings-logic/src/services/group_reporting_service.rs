@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::currency::CurrencyTotal;
+use postings_api::domain::group_reporting::{TranslatedLine, TranslatedTrialBalance, TranslationRate};
+use postings_api::service::account_group_service::AccountGroupService;
+use postings_api::service::fx_rate_provider::FxRateProvider;
+use postings_api::service::group_reporting_service::GroupReportingService;
+use postings_api::ServiceError;
+use postings_db::models::posting_status::PostingStatus;
+use uuid::Uuid;
+use crate::services::shared_service::SharedService;
+
+/// Translates group reports into `reporting_currency`. Amounts count in the currency of their
+/// line, else of their account, else in `functional_currency`, the currency the ledger keeps
+/// untagged amounts in.
+pub struct GroupReportingServiceImpl {
+    shared: SharedService,
+    group_service: Arc<dyn AccountGroupService + Send + Sync>,
+    rate_provider: Arc<dyn FxRateProvider + Send + Sync>,
+    reporting_currency: String,
+    functional_currency: String,
+    rates: HashMap<AccountCategory, TranslationRate>,
+}
+
+impl GroupReportingServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        group_service: Arc<dyn AccountGroupService + Send + Sync>,
+        rate_provider: Arc<dyn FxRateProvider + Send + Sync>,
+        reporting_currency: impl Into<String>,
+        functional_currency: impl Into<String>,
+    ) -> Self {
+        Self {
+            shared,
+            group_service,
+            rate_provider,
+            reporting_currency: reporting_currency.into(),
+            functional_currency: functional_currency.into(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Rate the balances of `category` are translated at. By default income statement
+    /// categories use the period average rate and all others the closing rate.
+    pub fn with_rate(mut self, category: AccountCategory, rate: TranslationRate) -> Self {
+        self.rates.insert(category, rate);
+        self
+    }
+
+    fn rate_type(&self, category: &AccountCategory) -> TranslationRate {
+        match self.rates.get(category) {
+            Some(rate) => *rate,
+            None => match category {
+                AccountCategory::RE | AccountCategory::EX | AccountCategory::NORE | AccountCategory::NOEX => TranslationRate::PeriodAverage,
+                _ => TranslationRate::Closing,
+            },
+        }
+    }
+
+    async fn rate(&self, currency: &str, rate_type: TranslationRate, period_start: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<BigDecimal, ServiceError> {
+        if currency == self.reporting_currency {
+            return Ok(BigDecimal::from(1));
+        }
+        match rate_type {
+            TranslationRate::Closing => self.rate_provider.closing_rate(currency, &self.reporting_currency, ref_time).await?,
+            TranslationRate::PeriodAverage => self.rate_provider.average_rate(currency, &self.reporting_currency, period_start, ref_time).await?,
+        }
+        .ok_or(ServiceError::FxRateNotFound { currency: currency.to_string(), rate_type })
+    }
+}
+
+#[async_trait]
+impl GroupReportingService for GroupReportingServiceImpl {
+    async fn translated_trial_balance(&self, group_id: Uuid, period_start: DateTime<Utc>, ref_time: DateTime<Utc>) -> Result<TranslatedTrialBalance, ServiceError> {
+        let group = self.group_service
+            .find_group_by_id(group_id)
+            .await?
+            .ok_or(ServiceError::AccountGroupNotFound)?;
+        let mut rates: HashMap<(String, TranslationRate), BigDecimal> = HashMap::new();
+        let mut lines = Vec::new();
+        for account in self.group_service.accounts(group_id).await? {
+            let posted = self.shared.line_repo
+                .find_by_account_and_pst_time_less_than_equal(account.id, ref_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let mut totals = Vec::new();
+            for line in posted.iter().filter(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none()) {
+                let currency = line.currency.as_ref().or(account.currency.as_ref()).unwrap_or(&self.functional_currency);
+                CurrencyTotal::accumulate(&mut totals, currency, &line.debit_amount, &line.credit_amount);
+            }
+            let rate_type = self.rate_type(&account.category);
+            for total in totals {
+                let key = (total.currency.clone(), rate_type);
+                let rate = match rates.get(&key) {
+                    Some(rate) => rate.clone(),
+                    None => {
+                        let rate = self.rate(&total.currency, rate_type, period_start, ref_time).await?;
+                        rates.insert(key, rate.clone());
+                        rate
+                    }
+                };
+                let net_debit = total.debit_balance();
+                lines.push(TranslatedLine::new(account.clone(), total.currency, net_debit, rate_type, rate));
+            }
+        }
+        Ok(TranslatedTrialBalance::new(group, self.reporting_currency.clone(), period_start, ref_time, lines))
+    }
+}
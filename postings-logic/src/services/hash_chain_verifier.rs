@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use log::warn;
+use postings_api::domain::chain_verification::{BrokenLink, ChainBreak, ChainVerification};
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::hash_chain_verifier::HashChainVerifier;
+use postings_api::ServiceError;
+use postings_db::models::posting::Posting as PostingModel;
+use uuid::Uuid;
+use crate::hash_utils;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct HashChainVerifierImpl {
+    shared: SharedService,
+}
+
+impl HashChainVerifierImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+
+    /// Checks one link of the chain: the antecedent hash and the posting's own hash, recomputed
+    /// from the posting as it was recorded, before any later discard.
+    async fn check_link(&self, ledger: &Ledger, posting: &PostingModel, antecedent: Option<&PostingModel>) -> Result<Option<ChainBreak>, ServiceError> {
+        if posting.antecedent_hash != antecedent.and_then(|a| a.hash) {
+            return Ok(Some(ChainBreak::AntecedentHashMismatch));
+        }
+        let stored_hash = match posting.hash {
+            Some(hash) => hash,
+            None => return Ok(Some(ChainBreak::MissingHash)),
+        };
+        let lines = self.shared.line_repo
+            .find_by_opr_id(&posting.opr_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .filter(|l| l.discarded_time == posting.discarded_time)
+            .map(|l| postings_db::models::posting_line::PostingLine { discarded_time: None, ..l })
+            .collect();
+        let lines = self.shared.lines_to_bo(lines).await?;
+        let mut recorded = PostingMapper::to_bo(posting.clone(), ledger.clone(), lines)?;
+        recorded.discarded_id = None;
+        recorded.discarded_time = None;
+        recorded.discarding_id = None;
+        let hash = hash_utils::hash_posting(&recorded).map_err(|_| ServiceError::NotEnoughInfo)?;
+        Ok((hash != stored_hash).then_some(ChainBreak::HashMismatch))
+    }
+}
+
+#[async_trait]
+impl HashChainVerifier for HashChainVerifierImpl {
+    async fn verify_chain(&self, ledger: Ledger) -> Result<ChainVerification, ServiceError> {
+        let postings = self.shared.posting_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut successors: HashMap<Option<Uuid>, Vec<PostingModel>> = HashMap::new();
+        for posting in postings {
+            successors.entry(posting.antecedent_id).or_default().push(posting);
+        }
+
+        let mut report = ChainVerification { ledger_id: ledger.id, verified: 0, last_verified_id: None, broken_link: None };
+        let mut antecedent: Option<PostingModel> = None;
+        while let Some(mut next) = successors.remove(&antecedent.as_ref().map(|a| a.id)) {
+            let antecedent_id = antecedent.as_ref().map(|a| a.id);
+            if next.len() > 1 {
+                report.broken_link = Some(BrokenLink { posting_id: next[1].id, antecedent_id, reason: ChainBreak::Fork });
+                break;
+            }
+            let posting = next.remove(0);
+            if let Some(reason) = self.check_link(&ledger, &posting, antecedent.as_ref()).await? {
+                report.broken_link = Some(BrokenLink { posting_id: posting.id, antecedent_id, reason });
+                break;
+            }
+            report.verified += 1;
+            report.last_verified_id = Some(posting.id);
+            antecedent = Some(posting);
+        }
+
+        if report.broken_link.is_none() {
+            // Postings left over hang off a posting that is not part of the chain
+            if let Some(orphan) = successors.into_values().flatten().min_by_key(|p| p.record_time) {
+                report.broken_link = Some(BrokenLink { posting_id: orphan.id, antecedent_id: orphan.antecedent_id, reason: ChainBreak::Unlinked });
+            }
+        }
+        if let Some(link) = &report.broken_link {
+            warn!("Hash chain of ledger {} broken at posting {}: {:?}", ledger.id, link.posting_id, link.reason);
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::hashing_profile::HashingProfile;
+use postings_api::domain::ledger::Ledger;
+use postings_api::service::hashing_profile_service::HashingProfileService;
+use postings_api::ServiceError;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use crate::mappers::hashing_profile::HashingProfileMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct HashingProfileServiceImpl {
+    shared: SharedService,
+    profile_repo: Arc<dyn HashingProfileRepository + Send + Sync>,
+}
+
+impl HashingProfileServiceImpl {
+    pub fn new(shared: SharedService, profile_repo: Arc<dyn HashingProfileRepository + Send + Sync>) -> Self {
+        Self { shared, profile_repo }
+    }
+}
+
+#[async_trait]
+impl HashingProfileService for HashingProfileServiceImpl {
+    async fn save_hashing_profile(&self, mut profile: HashingProfile) -> Result<HashingProfile, ServiceError> {
+        self.shared.ensure_writable(profile.ledger.id).await?;
+        profile.updated = Utc::now();
+        let ledger = profile.ledger.clone();
+        let saved = self.profile_repo
+            .save(HashingProfileMapper::to_model(profile))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        HashingProfileMapper::to_bo(saved, ledger)
+    }
+
+    async fn find_hashing_profile(&self, ledger: Ledger) -> Result<Option<HashingProfile>, ServiceError> {
+        let model = self.profile_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        model.map(|m| HashingProfileMapper::to_bo(m, ledger)).transpose()
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::ledger_account_name::LedgerAccountName;
+use postings_api::service::ledger_account_name_service::LedgerAccountNameService;
+use postings_api::ServiceError;
+use postings_db::models::ledger_account_name::LedgerAccountName as LedgerAccountNameModel;
+use postings_db::repositories::ledger_account_name_repository::LedgerAccountNameRepository;
+use uuid::Uuid;
+use crate::mappers::ledger_account_name::LedgerAccountNameMapper;
+use crate::services::shared_service::SharedService;
+
+/// Resolves accounts by the names and numbers integrators know them by. Names are matched
+/// exactly, without normalizing case or whitespace inside them.
+pub struct LedgerAccountNameServiceImpl {
+    shared: SharedService,
+    name_repo: Arc<dyn LedgerAccountNameRepository + Send + Sync>,
+}
+
+impl LedgerAccountNameServiceImpl {
+    pub fn new(shared: SharedService, name_repo: Arc<dyn LedgerAccountNameRepository + Send + Sync>) -> Self {
+        Self { shared, name_repo }
+    }
+
+    fn valid_at(model: &LedgerAccountNameModel, at: DateTime<Utc>) -> bool {
+        model.valid_from <= at && model.valid_to.is_none_or(|to| at < to)
+    }
+
+    /// Fails with `AccountNameTaken` when the name or number of `name` identifies another account
+    /// of its ledger during its validity period.
+    async fn check_available(&self, name: &LedgerAccountName) -> Result<(), ServiceError> {
+        for value in std::iter::once(&name.name).chain(name.number.as_ref()) {
+            let taken = self.name_repo
+                .find_by_ledger_and_value(name.account.ledger.id, value)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .filter(|other| other.id != name.id && other.account_id != name.account.id)
+                .any(|other| name.overlaps(other.valid_from, other.valid_to));
+            if taken {
+                return Err(ServiceError::AccountNameTaken);
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_name(&self, name_id: Uuid) -> Result<LedgerAccountName, ServiceError> {
+        let model = self.name_repo
+            .find_by_id(name_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::AccountNameNotFound)?;
+        let account = self.shared.load_ledger_account_bo(model.account_id).await?;
+        Ok(LedgerAccountNameMapper::to_bo(model, account))
+    }
+}
+
+#[async_trait]
+impl LedgerAccountNameService for LedgerAccountNameServiceImpl {
+    async fn register_name(&self, mut name: LedgerAccountName) -> Result<LedgerAccountName, ServiceError> {
+        name.name = name.name.trim().to_string();
+        name.number = name.number.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        if name.name.is_empty() || name.valid_to.is_some_and(|to| to <= name.valid_from) {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        name.account = self.shared.load_ledger_account_bo(name.account.id).await?;
+        self.shared.ensure_writable(name.account.ledger.id).await?;
+        name.id = Uuid::new_v4();
+        name.created = Utc::now();
+        self.check_available(&name).await?;
+        self.name_repo
+            .save(&LedgerAccountNameMapper::to_model(name.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(name)
+    }
+
+    async fn end_name(&self, name_id: Uuid, valid_to: DateTime<Utc>) -> Result<LedgerAccountName, ServiceError> {
+        let mut name = self.load_name(name_id).await?;
+        if valid_to <= name.valid_from {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(name.account.ledger.id).await?;
+        name.valid_to = Some(valid_to);
+        // Moving the end to a later time can run into a name registered since
+        self.check_available(&name).await?;
+        self.name_repo
+            .save(&LedgerAccountNameMapper::to_model(name.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(name)
+    }
+
+    async fn resolve(&self, ledger: Ledger, label: &str, at: DateTime<Utc>) -> Result<Option<LedgerAccount>, ServiceError> {
+        let label = label.trim();
+        let mut found = self.name_repo
+            .find_by_ledger_and_value(ledger.id, label)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .find(|n| Self::valid_at(n, at));
+        // A label combining number and name, as in "1.1.2.3 Cash"
+        if let (None, Some((number, name))) = (&found, label.split_once(' ')) {
+            found = self.name_repo
+                .find_by_ledger_and_value(ledger.id, number)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .find(|n| n.number.as_deref() == Some(number) && n.name == name.trim() && Self::valid_at(n, at));
+        }
+        match found {
+            Some(name) => Ok(Some(self.shared.load_ledger_account_bo(name.account_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_names(&self, ledger_account: LedgerAccount) -> Result<Vec<LedgerAccountName>, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(ledger_account.id).await?;
+        let names = self.name_repo
+            .find_by_account_id(account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(names.into_iter().map(|n| LedgerAccountNameMapper::to_bo(n, account.clone())).collect())
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::ledger_account_stats::{ChainPosition, LedgerAccountStats, StmtSummary};
+use postings_api::domain::ledger_account_tree::LedgerAccountTree;
+use postings_api::service::ledger_account_service::LedgerAccountService;
+use postings_api::service::posting_service::Page;
+use postings_api::ServiceError;
+use uuid::Uuid;
+use crate::mappers::ledger_account::LedgerAccountMapper;
+use crate::mappers::page::PageMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct LedgerAccountServiceImpl {
+    shared: SharedService,
+}
+
+impl LedgerAccountServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+
+    /// Business objects of the descendants of `root`, grouped by parent id, each carrying its
+    /// chain of parents up to `root`.
+    async fn load_descendants_bo(&self, root: &LedgerAccount) -> Result<HashMap<Uuid, Vec<LedgerAccount>>, ServiceError> {
+        let models = self.shared.ledger_account_repo
+            .find_descendants(root.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut models_by_parent: HashMap<Uuid, Vec<_>> = HashMap::new();
+        for model in models {
+            if let Some(parent_id) = model.parent_id {
+                models_by_parent.entry(parent_id).or_default().push(model);
+            }
+        }
+        // Top down, so the parent of each account is mapped before the account itself
+        let mut children_by_parent = HashMap::new();
+        let mut parents = vec![root.clone()];
+        while let Some(parent) = parents.pop() {
+            let children: Vec<LedgerAccount> = models_by_parent
+                .remove(&parent.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|model| LedgerAccountMapper::to_bo(model, root.ledger.clone(), root.coa.clone(), Some(Box::new(parent.clone()))))
+                .collect();
+            parents.extend(children.iter().cloned());
+            children_by_parent.insert(parent.id, children);
+        }
+        Ok(children_by_parent)
+    }
+}
+
+fn build_tree(
+    account: LedgerAccount,
+    children_by_parent: &mut HashMap<Uuid, Vec<LedgerAccount>>,
+    totals: &HashMap<Uuid, (BigDecimal, BigDecimal)>,
+    ref_time: DateTime<Utc>,
+) -> LedgerAccountTree {
+    let children = children_by_parent
+        .remove(&account.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|child| build_tree(child, children_by_parent, totals, ref_time))
+        .collect();
+    let (total_debit, total_credit) = totals
+        .get(&account.id)
+        .cloned()
+        .unwrap_or_else(|| (BigDecimal::from(0), BigDecimal::from(0)));
+    let balance = AccountBalance { account, ref_time, total_debit, total_credit, known_at: None };
+    LedgerAccountTree::new(balance, children)
+}
+
+#[async_trait]
+impl LedgerAccountService for LedgerAccountServiceImpl {
+    async fn balance(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        self.shared.account_balance(ledger_account, ref_time).await
+    }
+
+    async fn balance_as_at(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        self.shared.account_balance_as_at(ledger_account, ref_time, known_at).await
+    }
+
+    async fn stats(&self, account_id: Uuid) -> Result<LedgerAccountStats, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(account_id).await?;
+        let now = Utc::now();
+        let line_stats = self.shared.line_repo
+            .find_stats_by_account_id(account_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let last_stmt = self.shared.stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(account_id, now)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .map(|stmt| StmtSummary {
+                id: stmt.id,
+                pst_time: stmt.pst_time,
+                stmt_seq_nbr: stmt.stmt_seq_nbr,
+                total_debit: stmt.total_debit,
+                total_credit: stmt.total_credit,
+                line_count: stmt.line_count,
+            });
+        let chain_position = match line_stats.last_opr_id {
+            Some(opr_id) => self.shared.posting_repo
+                .find_by_opr_id_and_discarding_id_is_null(&opr_id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .map(|posting| ChainPosition { posting_id: posting.id, record_time: posting.record_time, hash: posting.hash }),
+            None => None,
+        };
+        let balance = self.shared.account_balance(account.clone(), now).await?;
+        Ok(LedgerAccountStats {
+            account,
+            line_count: line_stats.line_count as u64,
+            first_pst_time: line_stats.first_pst_time,
+            last_pst_time: line_stats.last_pst_time,
+            balance,
+            last_stmt,
+            chain_position,
+        })
+    }
+
+    async fn find_ledger_accounts_paged(&self, ledger: Ledger, page: usize, size: usize) -> Result<Page<LedgerAccount>, ServiceError> {
+        let request = PageMapper::to_request(page, size)?;
+        let models = self.shared.ledger_account_repo
+            .find_by_ledger_id_paged(ledger.id, request)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        // Parents are not necessarily on the same page, they are loaded one by one
+        let mut accounts = Vec::with_capacity(models.content.len());
+        for model in models.content {
+            let parent = match model.parent_id {
+                Some(parent_id) => Some(Box::new(self.shared.load_ledger_account_bo(parent_id).await?)),
+                None => None,
+            };
+            accounts.push(LedgerAccountMapper::to_bo(model, ledger.clone(), ledger.coa.clone(), parent));
+        }
+        Ok(Page { content: accounts, page, size, total_elements: models.total_elements })
+    }
+
+    async fn find_descendants(&self, account_id: Uuid) -> Result<Vec<LedgerAccount>, ServiceError> {
+        let root = self.shared.load_ledger_account_bo(account_id).await?;
+        let mut descendants: Vec<LedgerAccount> = self.load_descendants_bo(&root).await?
+            .into_values()
+            .flatten()
+            .collect();
+        descendants.sort_by_key(|a| a.id);
+        Ok(descendants)
+    }
+
+    async fn tree(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<LedgerAccountTree, ServiceError> {
+        let root = self.shared.load_ledger_account_bo(account_id).await?;
+        let mut children_by_parent = self.load_descendants_bo(&root).await?;
+        for children in children_by_parent.values_mut() {
+            children.sort_by_key(|a| a.id);
+        }
+        let mut account_ids = vec![root.id];
+        account_ids.extend(children_by_parent.values().flatten().map(|a| a.id));
+        let totals: HashMap<Uuid, (BigDecimal, BigDecimal)> = self.shared.line_repo
+            .sum_by_account_ids_and_pst_time_less_than_equal(&account_ids, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .map(|t| (t.account_id, (t.total_debit, t.total_credit)))
+            .collect();
+        Ok(build_tree(root, &mut children_by_parent, &totals, ref_time))
+    }
+}
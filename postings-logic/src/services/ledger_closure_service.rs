@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::ledger_closure::LedgerClosure;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::service::ledger_closure_service::LedgerClosureService;
+use postings_api::ServiceError;
+use postings_db::repositories::ledger_closure_repository::LedgerClosureRepository;
+use uuid::Uuid;
+use crate::mappers::ledger_closure::LedgerClosureMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct LedgerClosureServiceImpl {
+    shared: SharedService,
+    closure_repo: Arc<dyn LedgerClosureRepository + Send + Sync>,
+}
+
+impl LedgerClosureServiceImpl {
+    pub fn new(shared: SharedService, closure_repo: Arc<dyn LedgerClosureRepository + Send + Sync>) -> Self {
+        Self { shared, closure_repo }
+    }
+
+    async fn close(&self, ledger_id: Uuid, account_id: Option<Uuid>, cut_off: DateTime<Utc>, allow_adjustments: bool, context: PrivilegedContext) -> Result<LedgerClosure, ServiceError> {
+        if context.principal().is_empty() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let closure = LedgerClosure {
+            id: Uuid::new_v4(),
+            ledger: self.shared.load_ledger_bo(ledger_id).await?,
+            account_id,
+            cut_off,
+            allow_adjustments,
+            closed_by: context.principal().to_string(),
+            reason: context.reason().to_string(),
+            created: Utc::now(),
+        };
+        self.closure_repo
+            .save(&LedgerClosureMapper::to_model(closure.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        warn!(
+            "Ledger {} closed before {} (account {:?}, adjustments allowed: {}) by {}: {}",
+            ledger_id, cut_off, account_id, allow_adjustments, closure.closed_by, closure.reason
+        );
+        Ok(closure)
+    }
+}
+
+#[async_trait]
+impl LedgerClosureService for LedgerClosureServiceImpl {
+    async fn close_ledger(&self, ledger: Ledger, cut_off: DateTime<Utc>, allow_adjustments: bool, context: PrivilegedContext) -> Result<LedgerClosure, ServiceError> {
+        self.close(ledger.id, None, cut_off, allow_adjustments, context).await
+    }
+
+    async fn close_account(&self, account: LedgerAccount, cut_off: DateTime<Utc>, allow_adjustments: bool, context: PrivilegedContext) -> Result<LedgerClosure, ServiceError> {
+        let account_model = self.shared
+            .load_ledger_account(account.id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        self.close(account_model.ledger_id, Some(account.id), cut_off, allow_adjustments, context).await
+    }
+
+    async fn find_closures(&self, ledger: Ledger) -> Result<Vec<LedgerClosure>, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let closures = self.closure_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(closures.into_iter().map(|c| LedgerClosureMapper::to_bo(c, ledger.clone())).collect())
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, TimeZone, Utc};
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_comparison::{AccountSnapshot, LedgerDiff, LedgerSnapshot};
+use postings_api::service::ledger_comparison_service::LedgerComparisonService;
+use postings_api::ServiceError;
+use postings_db::models::posting_status::PostingStatus;
+use crate::services::shared_service::SharedService;
+
+pub struct LedgerComparisonServiceImpl {
+    shared: SharedService,
+}
+
+impl LedgerComparisonServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl LedgerComparisonService for LedgerComparisonServiceImpl {
+    async fn snapshot(&self, ledger: Ledger, as_of: NaiveDate) -> Result<LedgerSnapshot, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let ref_time = Utc.from_utc_datetime(&as_of.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap());
+        let accounts = self.shared.load_ledger_accounts_bo(&ledger).await?;
+
+        let mut snapshots = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            let key = self
+                .shared
+                .named_repo
+                .find_by_container(account.id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .next()
+                .map(|n| n.name)
+                .unwrap_or_else(|| account.id.to_string());
+            let lines = self
+                .shared
+                .line_repo
+                .find_by_account_and_pst_time_less_than_equal(account.id, ref_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let booked: Vec<_> = lines
+                .iter()
+                .filter(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none())
+                .collect();
+            let (debit, credit) = booked
+                .iter()
+                .fold((BigDecimal::from(0), BigDecimal::from(0)), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+            snapshots.push(AccountSnapshot {
+                key,
+                balance: match account.balance_side {
+                    BalanceSide::Cr => credit - debit,
+                    _ => debit - credit,
+                },
+                line_count: booked.len() as i64,
+            });
+        }
+
+        Ok(LedgerSnapshot { label: ledger.id.to_string(), as_of, accounts: snapshots })
+    }
+
+    async fn compare_ledgers(&self, left: Ledger, right: Ledger, as_of: NaiveDate) -> Result<LedgerDiff, ServiceError> {
+        let left = self.snapshot(left, as_of).await?;
+        let right = self.snapshot(right, as_of).await?;
+        Ok(left.diff(&right))
+    }
+
+    async fn compare_with_snapshot(&self, ledger: Ledger, snapshot: LedgerSnapshot) -> Result<LedgerDiff, ServiceError> {
+        let left = self.snapshot(ledger, snapshot.as_of).await?;
+        Ok(left.diff(&snapshot))
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use log::info;
+use postings_api::domain::ledger_event::LedgerEvent;
+use postings_api::service::ledger_event_service::{LedgerEventService, LedgerEventSink};
+use postings_api::ServiceError;
+use postings_db::models::ledger_event::LedgerEvent as LedgerEventModel;
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use crate::mappers::ledger_event::LedgerEventMapper;
+
+pub const DEFAULT_EVENT_RETENTION_DAYS: i64 = 7;
+const REPLAY_BATCH_SIZE: i64 = 500;
+
+/// Up to `limit` events after `after_seq` from the event table and the archive, in sequence order.
+/// Compaction may move events between the tables while reading, so both are read from the cursor
+/// and merged by sequence number.
+pub(crate) async fn read_merged(
+    event_repo: &(dyn LedgerEventRepository + Send + Sync),
+    after_seq: i64,
+    limit: i64,
+) -> Result<Vec<LedgerEventModel>, ServiceError> {
+    let mut events = event_repo
+        .find_archived_after_seq(after_seq, limit)
+        .await
+        .map_err(|_| ServiceError::Db)?;
+    events.extend(
+        event_repo
+            .find_after_seq(after_seq, limit)
+            .await
+            .map_err(|_| ServiceError::Db)?,
+    );
+    events.sort_by_key(|e| e.seq);
+    events.dedup_by_key(|e| e.seq);
+    events.truncate(limit.max(0) as usize);
+    Ok(events)
+}
+
+pub struct LedgerEventServiceImpl {
+    event_repo: Arc<dyn LedgerEventRepository + Send + Sync>,
+    retention: Duration,
+}
+
+impl LedgerEventServiceImpl {
+    pub fn new(event_repo: Arc<dyn LedgerEventRepository + Send + Sync>) -> Self {
+        Self { event_repo, retention: Duration::days(DEFAULT_EVENT_RETENTION_DAYS) }
+    }
+
+    /// Sets how long dispatched events stay in the event table before `compact` archives them.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+}
+
+#[async_trait]
+impl LedgerEventService for LedgerEventServiceImpl {
+    async fn read_events(&self, after_seq: i64, limit: i64) -> Result<Vec<LedgerEvent>, ServiceError> {
+        let events = self.event_repo
+            .find_after_seq(after_seq, limit)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(events.into_iter().map(LedgerEventMapper::to_bo).collect())
+    }
+
+    async fn latest_seq(&self) -> Result<Option<i64>, ServiceError> {
+        self.event_repo.find_max_seq().await.map_err(|_| ServiceError::Db)
+    }
+
+    async fn acknowledge(&self, consumer: &str, seq: i64) -> Result<(), ServiceError> {
+        self.event_repo
+            .save_consumer_position(consumer, seq, Utc::now())
+            .await
+            .map_err(|_| ServiceError::Db)
+    }
+
+    async fn consumer_position(&self, consumer: &str) -> Result<Option<i64>, ServiceError> {
+        self.event_repo.find_consumer_seq(consumer).await.map_err(|_| ServiceError::Db)
+    }
+
+    async fn compact(&self) -> Result<u64, ServiceError> {
+        // Without any registered consumer nothing counts as dispatched
+        let dispatched_seq = match self.event_repo.find_min_consumer_seq().await.map_err(|_| ServiceError::Db)? {
+            Some(seq) => seq,
+            None => return Ok(0),
+        };
+        let archived = self.event_repo
+            .archive_up_to(dispatched_seq, Utc::now() - self.retention)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        info!("Archived {archived} ledger events up to sequence {dispatched_seq}");
+        Ok(archived)
+    }
+
+    async fn replay(&self, from_seq: i64, to_seq: i64, sink: &(dyn LedgerEventSink + Send + Sync)) -> Result<u64, ServiceError> {
+        let mut cursor = from_seq - 1;
+        let mut published = 0;
+        while cursor < to_seq {
+            let mut batch = read_merged(self.event_repo.as_ref(), cursor, REPLAY_BATCH_SIZE).await?;
+            batch.retain(|e| e.seq <= to_seq);
+            let Some(last) = batch.last() else {
+                break;
+            };
+            cursor = last.seq;
+            let events: Vec<LedgerEvent> = batch.into_iter().map(LedgerEventMapper::to_bo).collect();
+            sink.publish(&events).await?;
+            published += events.len() as u64;
+        }
+        Ok(published)
+    }
+}
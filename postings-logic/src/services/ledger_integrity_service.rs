@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_integrity::{HierarchyBreak, HierarchyIssue, LedgerIntegrityReport, UnbalancedPosting};
+use postings_api::service::hash_chain_verifier::HashChainVerifier;
+use postings_api::service::ledger_integrity_service::LedgerIntegrityService;
+use postings_api::service::stmt_repair_service::StmtRepairService;
+use postings_api::ServiceError;
+use postings_db::models::ledger_account::LedgerAccount as LedgerAccountModel;
+use uuid::Uuid;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct LedgerIntegrityServiceImpl {
+    shared: SharedService,
+    chain_verifier: Arc<dyn HashChainVerifier + Send + Sync>,
+    stmt_repair_service: Arc<dyn StmtRepairService + Send + Sync>,
+}
+
+impl LedgerIntegrityServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        chain_verifier: Arc<dyn HashChainVerifier + Send + Sync>,
+        stmt_repair_service: Arc<dyn StmtRepairService + Send + Sync>,
+    ) -> Self {
+        Self { shared, chain_verifier, stmt_repair_service }
+    }
+
+    /// Checks the parent of every account. Returns the issues and the accounts whose parent chain
+    /// runs into a cycle: those cannot be loaded and are left out of the other checks.
+    async fn check_hierarchy(&self, ledger: &Ledger, accounts: &[LedgerAccountModel]) -> Result<(Vec<HierarchyIssue>, HashSet<Uuid>), ServiceError> {
+        let by_id: HashMap<Uuid, &LedgerAccountModel> = accounts.iter().map(|a| (a.id, a)).collect();
+        let mut issues = Vec::new();
+        let mut tangled = HashSet::new();
+        for account in accounts {
+            let Some(parent_id) = account.parent_id else {
+                continue;
+            };
+            if !by_id.contains_key(&parent_id) {
+                let reason = match self.shared.load_ledger_account(parent_id).await? {
+                    Some(parent) if parent.ledger_id != ledger.id => HierarchyBreak::ParentOutsideLedger,
+                    Some(_) => continue,
+                    None => HierarchyBreak::MissingParent,
+                };
+                issues.push(HierarchyIssue { account_id: account.id, parent_id, reason });
+                continue;
+            }
+
+            let mut ancestors = HashSet::new();
+            let mut next = Some(parent_id);
+            while let Some(ancestor_id) = next {
+                if ancestor_id == account.id {
+                    issues.push(HierarchyIssue { account_id: account.id, parent_id, reason: HierarchyBreak::Cycle });
+                    tangled.insert(account.id);
+                    break;
+                }
+                if !ancestors.insert(ancestor_id) {
+                    // Runs into a cycle the account is not part of, reported with its members
+                    tangled.insert(account.id);
+                    break;
+                }
+                next = by_id.get(&ancestor_id).and_then(|a| a.parent_id);
+            }
+        }
+        Ok((issues, tangled))
+    }
+
+    /// Checks that the lines of every current posting balance, as recorded.
+    async fn check_double_entry(&self, ledger: &Ledger, tangled: &HashSet<Uuid>) -> Result<Vec<UnbalancedPosting>, ServiceError> {
+        let postings = self.shared.posting_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut unbalanced = Vec::new();
+        for posting in postings.into_iter().filter(|p| p.discarding_id.is_none()) {
+            let lines: Vec<_> = self.shared.line_repo
+                .find_by_opr_id(&posting.opr_id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .filter(|l| l.discarded_time == posting.discarded_time)
+                .collect();
+            if lines.iter().any(|l| tangled.contains(&l.account_id)) {
+                continue;
+            }
+            let lines = self.shared.lines_to_bo(lines).await?;
+            let posting_id = posting.id;
+            if let Err(e) = PostingMapper::to_bo(posting, ledger.clone(), lines)?.check_balanced() {
+                unbalanced.push(UnbalancedPosting { posting_id, message: e.to_string() });
+            }
+        }
+        Ok(unbalanced)
+    }
+}
+
+#[async_trait]
+impl LedgerIntegrityService for LedgerIntegrityServiceImpl {
+    async fn verify_ledger(&self, ledger_id: Uuid) -> Result<LedgerIntegrityReport, ServiceError> {
+        let checked_at = Utc::now();
+        let ledger = self.shared.load_ledger_bo(ledger_id).await?;
+        let chain = self.chain_verifier.verify_chain(ledger.clone()).await?;
+
+        let accounts = self.shared.ledger_account_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let (hierarchy_issues, tangled) = self.check_hierarchy(&ledger, &accounts).await?;
+
+        let mut stmt_drifts = Vec::new();
+        for account in accounts.iter().filter(|a| !tangled.contains(&a.id)) {
+            let account = self.shared.load_ledger_account_bo(account.id).await?;
+            stmt_drifts.extend(self.stmt_repair_service.detect_drift(account, DateTime::<Utc>::MIN_UTC, checked_at).await?);
+        }
+
+        let unbalanced_postings = self.check_double_entry(&ledger, &tangled).await?;
+        let report = LedgerIntegrityReport { ledger_id, checked_at, chain, stmt_drifts, hierarchy_issues, unbalanced_postings };
+        if !report.is_intact() {
+            warn!(
+                "Ledger {ledger_id} failed integrity checks: chain intact {}, {} drifted statements, {} hierarchy issues, {} unbalanced postings",
+                report.chain.is_intact(), report.stmt_drifts.len(), report.hierarchy_issues.len(), report.unbalanced_postings.len()
+            );
+        }
+        Ok(report)
+    }
+}
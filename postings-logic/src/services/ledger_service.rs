@@ -149,6 +149,9 @@ impl LedgerService for LedgerServiceImpl {
         if leddger.coa_id != ledger_account.coa.id {
             return Err(ServiceError::ChartOfAccountMismatch);
         }
+        if leddger.read_only {
+            return Err(ServiceError::ReadOnly);
+        }
 
         let model = LedgerAccountMapper::to_model(ledger_account);
         self.shared
@@ -229,6 +232,53 @@ impl LedgerService for LedgerServiceImpl {
         Ok(!result.is_empty())
     }
 
+    async fn set_read_only(&self, ledger_id: Uuid, read_only: bool) -> Result<(), ServiceError> {
+        self.shared
+            .ledger_repo
+            .set_read_only(ledger_id, read_only)
+            .await
+            .map_err(|e| match e {
+                postings_db::DbError::NotFound => ServiceError::LedgerNotFound,
+                _ => ServiceError::Db,
+            })?;
+        log::info!("Ledger {ledger_id} read-only mode set to {read_only}");
+        Ok(())
+    }
+
+    async fn is_read_only(&self, ledger_id: Uuid) -> Result<bool, ServiceError> {
+        Ok(self.shared.load_ledger(ledger_id).await?.read_only)
+    }
+
+    async fn set_memo(&self, ledger_id: Uuid, memo: bool) -> Result<(), ServiceError> {
+        self.shared
+            .ledger_repo
+            .set_memo(ledger_id, memo)
+            .await
+            .map_err(|e| match e {
+                postings_db::DbError::NotFound => ServiceError::LedgerNotFound,
+                _ => ServiceError::Db,
+            })?;
+        log::info!("Ledger {ledger_id} memo mode set to {memo}");
+        Ok(())
+    }
+
+    async fn is_memo(&self, ledger_id: Uuid) -> Result<bool, ServiceError> {
+        Ok(self.shared.load_ledger(ledger_id).await?.memo)
+    }
+
+    async fn set_closing_summary(&self, ledger_id: Uuid, closing_summary: bool) -> Result<(), ServiceError> {
+        self.shared
+            .ledger_repo
+            .set_closing_summary(ledger_id, closing_summary)
+            .await
+            .map_err(|e| match e {
+                postings_db::DbError::NotFound => ServiceError::LedgerNotFound,
+                _ => ServiceError::Db,
+            })?;
+        log::info!("Ledger {ledger_id} closing summaries set to {closing_summary}");
+        Ok(())
+    }
+
     async fn find_ledger_accounts_by_ibans(
         &self,
         ibans: Vec<String>,
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use log::info;
+use postings_api::domain::closing_summary::ClosingSummary;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_stmt::LedgerStmt;
+use postings_api::service::ledger_stmt_service::LedgerStmtService;
+use postings_api::ServiceError;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::ledger_stmt_repository::LedgerStmtRepository;
+use postings_db::unit_of_work::UnitOfWork;
+use uuid::Uuid;
+use crate::mappers::ledger_stmt::LedgerStmtMapper;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct LedgerStmtServiceImpl {
+    shared: SharedService,
+    ledger_stmt_repo: Arc<dyn LedgerStmtRepository + Send + Sync>,
+}
+
+impl LedgerStmtServiceImpl {
+    pub fn new(shared: SharedService, ledger_stmt_repo: Arc<dyn LedgerStmtRepository + Send + Sync>) -> Self {
+        Self { shared, ledger_stmt_repo }
+    }
+
+    /// Simulated statement at `ref_time`, continuing from the last closed statement of the ledger.
+    async fn stmt(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<LedgerStmt, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let last_closed = self.ledger_stmt_repo
+            .find_last_closed_by_ledger_and_pst_time_less_than(ledger.id, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        let mut stmt = postings_db::models::ledger_stmt::LedgerStmt {
+            id: Uuid::new_v4(),
+            ledger_id: ledger.id,
+            total_debit: BigDecimal::from(0),
+            total_credit: BigDecimal::from(0),
+            posting_id: None,
+            pst_time: ref_time,
+            stmt_status: StmtStatus::Simulated,
+            stmt_seq_nbr: 0,
+        };
+        let since = last_closed.as_ref().map(|last| last.pst_time);
+        if let Some(last) = last_closed {
+            info!("Continuing ledger statement {} of ledger {}", last.id, ledger.id);
+            stmt.total_debit = last.total_debit;
+            stmt.total_credit = last.total_credit;
+            stmt.stmt_seq_nbr = last.stmt_seq_nbr + 1;
+        }
+
+        for account in self.shared.load_ledger_accounts_bo(&ledger).await? {
+            let lines = match since {
+                Some(since) => self.shared.line_repo.find_by_account_and_pst_time_between(account.id, since, ref_time).await,
+                None => self.shared.line_repo.find_by_account_and_pst_time_less_than_equal(account.id, ref_time).await,
+            }
+            .map_err(|_| ServiceError::Db)?;
+            for line in lines.iter().filter(|l| since.is_none_or(|since| l.pst_time > since)) {
+                stmt.total_debit += line.debit_amount.clone();
+                stmt.total_credit += line.credit_amount.clone();
+            }
+        }
+
+        Ok(LedgerStmtMapper::to_bo(stmt, ledger, None))
+    }
+}
+
+#[async_trait]
+impl LedgerStmtService for LedgerStmtServiceImpl {
+    async fn read_stmt(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<LedgerStmt, ServiceError> {
+        self.stmt(ledger, ref_time).await
+    }
+
+    async fn create_stmt(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<LedgerStmt, ServiceError> {
+        self.shared.ensure_writable(ledger.id).await?;
+        let stmt = self.stmt(ledger, ref_time).await?;
+        self.ledger_stmt_repo
+            .save(LedgerStmtMapper::to_model(stmt.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(stmt)
+    }
+
+    async fn close_stmt(&self, stmt: LedgerStmt) -> Result<LedgerStmt, ServiceError> {
+        let mut stmt_model = self.ledger_stmt_repo
+            .find_by_id(stmt.financial_stmt.id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::StatementNotFound)?;
+        if stmt_model.stmt_status == StmtStatus::Closed {
+            return Err(ServiceError::StatementAlreadyClosed);
+        }
+
+        let closing_posting = self.shared
+            .closing_posting(stmt.ledger.id, ClosingSummary::new(
+                stmt.financial_stmt.id,
+                None,
+                stmt.financial_stmt.pst_time,
+                &stmt.total_debit,
+                &stmt.total_credit,
+            ))
+            .await?;
+        stmt_model.stmt_status = StmtStatus::Closed;
+        stmt_model.posting_id = Some(closing_posting.id);
+        let event = DomainEvent::StatementClosed {
+            stmt_id: stmt_model.id,
+            ledger_id: stmt_model.ledger_id,
+            account_id: None,
+            pst_time: stmt_model.pst_time,
+            posting_id: closing_posting.id,
+        };
+        let mut work = UnitOfWork::new();
+        work.save_posting(PostingMapper::to_model(closing_posting.clone()));
+        let saved = if self.shared.uow_repo.is_some() {
+            // Neither the posting nor the closed statement is stored without the other
+            work.save_ledger_stmt(stmt_model.clone());
+            if let Some(entry) = self.shared.outbox_entry(&event)? {
+                work.save_outbox_entry(entry);
+            }
+            self.shared.commit(work).await?;
+            stmt_model
+        } else {
+            self.shared.commit(work).await?;
+            self.ledger_stmt_repo
+                .save(stmt_model)
+                .await
+                .map_err(|_| ServiceError::Db)?
+        };
+        self.shared.publish(event).await;
+        Ok(LedgerStmtMapper::to_bo(saved, stmt.ledger, Some(closing_posting)))
+    }
+}
@@ -0,0 +1,316 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use uuid::Uuid;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::opening_balance::{OpeningBalanceCheck, OpeningBalanceReport, OpeningBalanceRow, OpeningBalanceStatus};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::quarantined_entry::{ImportMode, QuarantineStatus, QuarantinedEntry};
+use postings_api::service::opening_balance_service::OpeningBalanceService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::import_checkpoint::ImportCheckpoint;
+use postings_db::repositories::import_checkpoint_repository::ImportCheckpointRepository;
+use postings_db::repositories::quarantined_entry_repository::QuarantinedEntryRepository;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::quarantined_entry::QuarantinedEntryMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::shared_service::SharedService;
+
+pub struct OpeningBalanceServiceImpl {
+    shared: SharedService,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+    quarantine_repo: Arc<dyn QuarantinedEntryRepository + Send + Sync>,
+    checkpoint_repo: Option<Arc<dyn ImportCheckpointRepository + Send + Sync>>,
+    checkpoint_interval: i64,
+}
+
+const IMPORT_TYPE: &str = "OPENING_BALANCE";
+pub const DEFAULT_CHECKPOINT_INTERVAL: i64 = 100;
+
+/// Record of the import file by line: a parsed row, or the raw text of an unparsable one.
+enum ImportRecord {
+    Row(OpeningBalanceRow),
+    Rejected(String),
+}
+
+impl OpeningBalanceServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+        quarantine_repo: Arc<dyn QuarantinedEntryRepository + Send + Sync>,
+    ) -> Self {
+        Self { shared, posting_service, quarantine_repo, checkpoint_repo: None, checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL }
+    }
+
+    /// Records the progress of imports so an interrupted import of a file resumes where it stopped.
+    pub fn with_checkpoint_repo(mut self, checkpoint_repo: Arc<dyn ImportCheckpointRepository + Send + Sync>) -> Self {
+        self.checkpoint_repo = Some(checkpoint_repo);
+        self
+    }
+
+    /// Number of loaded rows between two checkpoints. Rows after the last checkpoint are loaded again
+    /// on resume, which books nothing twice since their operation ids are already recorded.
+    pub fn with_checkpoint_interval(mut self, checkpoint_interval: i64) -> Self {
+        self.checkpoint_interval = checkpoint_interval.max(1);
+        self
+    }
+
+    /// Checkpoint of an earlier import of the same file and parameters, or a new one.
+    async fn load_checkpoint(&self, ledger: &Ledger, csv: &str, pst_time: DateTime<Utc>, mode: &ImportMode) -> Result<Option<ImportCheckpoint>, ServiceError> {
+        let Some(checkpoint_repo) = &self.checkpoint_repo else {
+            return Ok(None);
+        };
+        let source_hash = hash_serialize(&(csv, pst_time, mode)).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let stored = checkpoint_repo
+            .find_by_source(ledger.id, IMPORT_TYPE, &source_hash)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(Some(match stored {
+            Some(checkpoint) if !checkpoint.completed => checkpoint,
+            // A completed import is loaded again from the start
+            stored => ImportCheckpoint {
+                id: stored.map_or_else(Uuid::new_v4, |c| c.id),
+                ledger_id: ledger.id,
+                import_type: IMPORT_TYPE.to_string(),
+                source_hash,
+                next_line: 1,
+                loaded_count: 0,
+                quarantined_count: 0,
+                completed: false,
+                updated: Utc::now(),
+            },
+        }))
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &mut ImportCheckpoint) -> Result<(), ServiceError> {
+        if let Some(checkpoint_repo) = &self.checkpoint_repo {
+            checkpoint.updated = Utc::now();
+            checkpoint_repo.save(checkpoint).await.map_err(|_| ServiceError::Db)?;
+        }
+        Ok(())
+    }
+
+    async fn load_ledgers(&self, ledger: &Ledger, migration_account: &LedgerAccount) -> Result<(Ledger, LedgerAccount), ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let migration_account = self.shared.load_ledger_account_bo(migration_account.id).await?;
+        if migration_account.ledger.id != ledger.id {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+        Ok((ledger, migration_account))
+    }
+
+    /// Books the row and compares the resulting balance with the source balance.
+    async fn check_row(&self, ledger: &Ledger, migration_account: &LedgerAccount, row: &OpeningBalanceRow, pst_time: DateTime<Utc>) -> Result<OpeningBalanceCheck, ServiceError> {
+        let mut check = OpeningBalanceCheck {
+            account_id: row.account_id,
+            source_balance: row.balance.clone(),
+            loaded_balance: None,
+            posting_id: None,
+            status: OpeningBalanceStatus::Failed,
+            message: None,
+        };
+        match self.load_row(ledger, migration_account, row, pst_time).await {
+            Ok(posting) => {
+                check.posting_id = posting.map(|p| p.id);
+                let account = self.shared.load_ledger_account_bo(row.account_id).await?;
+                let loaded = self.shared.booked_balance(&account, pst_time).await?;
+                check.status = if loaded == row.balance { OpeningBalanceStatus::Matched } else { OpeningBalanceStatus::Mismatch };
+                check.loaded_balance = Some(loaded);
+            }
+            Err(e) => {
+                warn!("Opening balance of account {} not loaded: {e:?}", row.account_id);
+                check.message = Some(e.to_string());
+            }
+        }
+        Ok(check)
+    }
+
+    async fn quarantine(&self, ledger: &Ledger, line_number: usize, payload: String, reason: String) -> Result<QuarantinedEntry, ServiceError> {
+        warn!("Quarantining opening balance record at line {line_number}: {reason}");
+        let entry = QuarantinedEntry {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            import_type: IMPORT_TYPE.to_string(),
+            line_number: line_number as i64,
+            payload,
+            reason,
+            status: QuarantineStatus::Pending,
+            created: Utc::now(),
+            resolved_time: None,
+        };
+        self.save_entry(entry).await
+    }
+
+    async fn save_entry(&self, entry: QuarantinedEntry) -> Result<QuarantinedEntry, ServiceError> {
+        let ledger = entry.ledger.clone();
+        let saved = self.quarantine_repo
+            .save(QuarantinedEntryMapper::to_model(entry))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(QuarantinedEntryMapper::to_bo(saved, ledger))
+    }
+
+    async fn load_pending_entry(&self, entry_id: Uuid) -> Result<QuarantinedEntry, ServiceError> {
+        let model = self.quarantine_repo
+            .find_by_id(entry_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::QuarantinedEntryNotFound)?;
+        let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let entry = QuarantinedEntryMapper::to_bo(model, ledger);
+        if entry.status != QuarantineStatus::Pending {
+            return Err(ServiceError::QuarantinedEntryResolved);
+        }
+        Ok(entry)
+    }
+
+    async fn load_row(&self, ledger: &Ledger, migration_account: &LedgerAccount, row: &OpeningBalanceRow, pst_time: DateTime<Utc>) -> Result<Option<Posting>, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(row.account_id).await?;
+        if account.ledger.id != ledger.id {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+        let zero = BigDecimal::from(0);
+        if row.balance == zero {
+            return Ok(None);
+        }
+
+        // A positive balance sits on the account's balance side; negative balances are booked the other way
+        let debit_account = match account.balance_side {
+            BalanceSide::Cr => row.balance < zero,
+            _ => row.balance > zero,
+        };
+        let amount = row.balance.abs();
+        let opr_id = hash_serialize(&(ledger.id, row.account_id, "opening-balance")).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let opr_type = hash_serialize(&"OPENING_BALANCE").map_err(|_| ServiceError::NotEnoughInfo)?;
+        let builder = PostingBuilder::new(ledger.clone(), opr_id, opr_type, pst_time)
+            .record_user(hash_serialize(&"opening-balance-loader").map_err(|_| ServiceError::NotEnoughInfo)?)
+            .pst_type(PostingType::AdjTx);
+        let posting = if debit_account {
+            builder.debit(account, amount.clone()).credit(migration_account.clone(), amount).build()
+        } else {
+            builder.debit(migration_account.clone(), amount.clone()).credit(account, amount).build()
+        };
+        self.posting_service.new_posting(posting).await.map(Some)
+    }
+}
+
+#[async_trait]
+impl OpeningBalanceService for OpeningBalanceServiceImpl {
+    async fn load_opening_balances(&self, ledger: Ledger, migration_account: LedgerAccount, csv: &str, pst_time: DateTime<Utc>) -> Result<OpeningBalanceReport, ServiceError> {
+        self.load_opening_balances_with_mode(ledger, migration_account, csv, pst_time, ImportMode::Strict).await
+    }
+
+    async fn load_opening_balances_with_mode(&self, ledger: Ledger, migration_account: LedgerAccount, csv: &str, pst_time: DateTime<Utc>, mode: ImportMode) -> Result<OpeningBalanceReport, ServiceError> {
+        let (rows, rejected) = OpeningBalanceRow::parse_csv_lenient(csv);
+        if let (ImportMode::Strict, Some((line, _))) = (&mode, rejected.first()) {
+            return Err(ServiceError::InvalidImportRecord { line: *line });
+        }
+        let (ledger, migration_account) = self.load_ledgers(&ledger, &migration_account).await?;
+        let mut checkpoint = self.load_checkpoint(&ledger, csv, pst_time, &mode).await?;
+        let next_line = checkpoint.as_ref().map_or(1, |c| c.next_line as usize);
+        if next_line > 1 {
+            info!("Resuming opening balance import on ledger {} at line {next_line}", ledger.id);
+        }
+
+        let mut records: Vec<(usize, ImportRecord)> = rows.into_iter().map(|(line, row)| (line, ImportRecord::Row(row)))
+            .chain(rejected.into_iter().map(|(line, raw)| (line, ImportRecord::Rejected(raw))))
+            .filter(|(line, _)| *line >= next_line)
+            .collect();
+        records.sort_by_key(|(line, _)| *line);
+
+        let mut quarantined = Vec::new();
+        let mut checks = Vec::with_capacity(records.len());
+        for (line_number, record) in records {
+            let set_aside = match record {
+                ImportRecord::Rejected(raw) => {
+                    let reason = ServiceError::InvalidImportRecord { line: line_number }.to_string();
+                    Some((raw, reason))
+                }
+                ImportRecord::Row(row) => {
+                    let check = self.check_row(&ledger, &migration_account, &row, pst_time).await?;
+                    if mode == ImportMode::Lenient && check.status == OpeningBalanceStatus::Failed {
+                        Some((format!("{},{}", row.account_id, row.balance), check.message.clone().unwrap_or_default()))
+                    } else {
+                        checks.push(check);
+                        None
+                    }
+                }
+            };
+            let Some(checkpoint) = checkpoint.as_mut() else {
+                if let Some((payload, reason)) = set_aside {
+                    quarantined.push(self.quarantine(&ledger, line_number, payload, reason).await?);
+                }
+                continue;
+            };
+            checkpoint.next_line = line_number as i64 + 1;
+            if let Some((payload, reason)) = set_aside {
+                quarantined.push(self.quarantine(&ledger, line_number, payload, reason).await?);
+                // Quarantined entries have no operation id to dedup on, so they are checkpointed at once
+                checkpoint.quarantined_count += 1;
+                self.save_checkpoint(checkpoint).await?;
+            } else {
+                checkpoint.loaded_count += 1;
+                if checkpoint.loaded_count % self.checkpoint_interval == 0 {
+                    self.save_checkpoint(checkpoint).await?;
+                }
+            }
+        }
+        if let Some(checkpoint) = checkpoint.as_mut() {
+            checkpoint.completed = true;
+            self.save_checkpoint(checkpoint).await?;
+        }
+
+        Ok(OpeningBalanceReport { ledger, migration_account, checks, quarantined, resumed_from_line: (next_line > 1).then_some(next_line) })
+    }
+
+    async fn find_quarantined(&self, ledger: Ledger) -> Result<Vec<QuarantinedEntry>, ServiceError> {
+        let ledger = self.shared.load_ledger_bo(ledger.id).await?;
+        let models = self.quarantine_repo
+            .find_by_ledger_id_and_status(ledger.id, postings_db::models::quarantined_entry::QuarantineStatus::Pending)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(models
+            .into_iter()
+            .filter(|m| m.import_type == IMPORT_TYPE)
+            .map(|m| QuarantinedEntryMapper::to_bo(m, ledger.clone()))
+            .collect())
+    }
+
+    async fn resubmit_quarantined(&self, entry_id: Uuid, corrected_payload: String, migration_account: LedgerAccount, pst_time: DateTime<Utc>) -> Result<OpeningBalanceCheck, ServiceError> {
+        let mut entry = self.load_pending_entry(entry_id).await?;
+        let (ledger, migration_account) = self.load_ledgers(&entry.ledger, &migration_account).await?;
+        entry.payload = corrected_payload.trim().to_string();
+
+        let row = match OpeningBalanceRow::parse_line(&entry.payload) {
+            Some(row) => row,
+            None => {
+                entry.reason = ServiceError::InvalidImportRecord { line: entry.line_number as usize }.to_string();
+                self.save_entry(entry.clone()).await?;
+                return Err(ServiceError::InvalidImportRecord { line: entry.line_number as usize });
+            }
+        };
+        let check = self.check_row(&ledger, &migration_account, &row, pst_time).await?;
+        if check.status == OpeningBalanceStatus::Failed {
+            entry.reason = check.message.clone().unwrap_or_default();
+        } else {
+            info!("Quarantined opening balance record {} resubmitted", entry.id);
+            entry.status = QuarantineStatus::Resubmitted;
+            entry.resolved_time = Some(Utc::now());
+        }
+        self.save_entry(entry).await?;
+        Ok(check)
+    }
+
+    async fn discard_quarantined(&self, entry_id: Uuid) -> Result<QuarantinedEntry, ServiceError> {
+        let mut entry = self.load_pending_entry(entry_id).await?;
+        entry.status = QuarantineStatus::Discarded;
+        entry.resolved_time = Some(Utc::now());
+        self.save_entry(entry).await
+    }
+}
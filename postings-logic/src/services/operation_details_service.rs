@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::external_content::ExternalContent;
+use postings_api::domain::posting::Posting;
+use postings_api::service::object_store::ObjectStore;
+use postings_api::service::operation_details_service::OperationDetailsService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::repositories::external_content_repository::ExternalContentRepository;
+use crate::hash_utils::hash_bytes;
+use crate::mappers::external_content::ExternalContentMapper;
+
+pub struct OperationDetailsServiceImpl {
+    content_repo: Arc<dyn ExternalContentRepository + Send + Sync>,
+    object_store: Arc<dyn ObjectStore + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+}
+
+impl OperationDetailsServiceImpl {
+    pub fn new(
+        content_repo: Arc<dyn ExternalContentRepository + Send + Sync>,
+        object_store: Arc<dyn ObjectStore + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+    ) -> Self {
+        Self { content_repo, object_store, posting_service }
+    }
+
+    /// Content addressed key, so identical payloads share one object.
+    fn object_key(content_hash: &[u8; 34]) -> String {
+        let hex: String = content_hash.iter().map(|b| format!("{b:02x}")).collect();
+        format!("opr-details/{hex}")
+    }
+}
+
+#[async_trait]
+impl OperationDetailsService for OperationDetailsServiceImpl {
+    async fn store_details(&self, content: Vec<u8>, media_type: &str) -> Result<ExternalContent, ServiceError> {
+        let content_hash = hash_bytes(&content);
+        if let Some(existing) = self.content_repo.find_by_content_hash(&content_hash).await.map_err(|_| ServiceError::Db)? {
+            return Ok(ExternalContentMapper::to_bo(existing));
+        }
+
+        let reference = ExternalContent {
+            content_hash,
+            object_key: Self::object_key(&content_hash),
+            size: content.len() as i64,
+            media_type: media_type.to_string(),
+            created: Utc::now(),
+        };
+        // Store the object first: a reference must never point to missing content
+        self.object_store.put(&reference.object_key, content).await?;
+        let saved = self.content_repo
+            .save(ExternalContentMapper::to_model(reference))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(ExternalContentMapper::to_bo(saved))
+    }
+
+    async fn new_posting_with_details(&self, mut posting: Posting, content: Vec<u8>, media_type: &str) -> Result<Posting, ServiceError> {
+        let reference = self.store_details(content, media_type).await?;
+        posting.opr_details = Some(reference.content_hash);
+        self.posting_service.new_posting(posting).await
+    }
+
+    async fn load_details(&self, content_hash: &[u8; 34]) -> Result<Vec<u8>, ServiceError> {
+        let reference = self.content_repo
+            .find_by_content_hash(content_hash)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::ExternalContentNotFound)?;
+        let content = self.object_store
+            .get(&reference.object_key)
+            .await?
+            .ok_or(ServiceError::ExternalContentNotFound)?;
+        if hash_bytes(&content) != *content_hash {
+            return Err(ServiceError::ExternalContentHashMismatch);
+        }
+        Ok(content)
+    }
+}
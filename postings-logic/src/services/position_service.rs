@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use postings_api::domain::account_position::{AccountPosition, PositionType};
+use postings_api::domain::business_calendar::BusinessCalendar;
+use postings_api::domain::currency::CurrencyTotal;
+use postings_api::domain::currency_position::CurrencyPositionReport;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::position_service::PositionService;
+use postings_api::ServiceError;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use crate::services::shared_service::SharedService;
+
+pub struct PositionServiceImpl {
+    shared: SharedService,
+}
+
+impl PositionServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+
+    fn end_of_day(date: NaiveDate) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap())
+    }
+
+    /// Lines with the date they take value on, earliest first: the value time of their posting,
+    /// or their posting time without one, as in
+    /// [`postings_db::models::line_order::LineOrder::ValueTime`]. The date is moved to the
+    /// following business day when the ledger has a calendar.
+    async fn value_dated(&self, lines: Vec<PostingLine>, calendar: Option<&BusinessCalendar>) -> Result<Vec<(NaiveDate, PostingLine)>, ServiceError> {
+        let mut val_times: HashMap<[u8; 34], Option<DateTime<Utc>>> = HashMap::new();
+        let mut dated = Vec::with_capacity(lines.len());
+        for line in lines {
+            let val_time = match val_times.get(&line.opr_id) {
+                Some(val_time) => *val_time,
+                None => {
+                    let val_time = self.shared
+                        .posting_repo
+                        .find_by_opr_id_and_discarding_id_is_null(&line.opr_id)
+                        .await
+                        .map_err(|_| ServiceError::Db)?
+                        .and_then(|p| p.val_time);
+                    val_times.insert(line.opr_id, val_time);
+                    val_time
+                }
+            };
+            let date = val_time.unwrap_or(line.pst_time).date_naive();
+            dated.push((calendar.map_or(date, |calendar| calendar.adjust_following(date)), line));
+        }
+        dated.sort_by_key(|(date, line)| (*date, line.pst_time));
+        Ok(dated)
+    }
+
+    fn empty_position(ledger_account: LedgerAccount, value_date: NaiveDate) -> AccountPosition {
+        AccountPosition {
+            position_type: PositionType::of(&ledger_account),
+            account: ledger_account,
+            value_date,
+            booked_debit: BigDecimal::from(0),
+            booked_credit: BigDecimal::from(0),
+            pending_debit: BigDecimal::from(0),
+            pending_credit: BigDecimal::from(0),
+        }
+    }
+
+    fn accumulate(position: &mut AccountPosition, line: &PostingLine) {
+        match line.pst_status {
+            PostingStatus::Posted => {
+                position.booked_debit += line.debit_amount.clone();
+                position.booked_credit += line.credit_amount.clone();
+            }
+            PostingStatus::Deferred | PostingStatus::Proposed => {
+                position.pending_debit += line.debit_amount.clone();
+                position.pending_credit += line.credit_amount.clone();
+            }
+            // Simulated, cancelled and other lines never move the position
+            _ => {}
+        }
+    }
+}
+
+#[async_trait]
+impl PositionService for PositionServiceImpl {
+    async fn position(&self, ledger_account: LedgerAccount, value_date: NaiveDate) -> Result<AccountPosition, ServiceError> {
+        self.position_ladder(ledger_account, value_date, value_date)
+            .await?
+            .pop()
+            .ok_or(ServiceError::NotEnoughInfo)
+    }
+
+    async fn position_ladder(&self, ledger_account: LedgerAccount, from: NaiveDate, to: NaiveDate) -> Result<Vec<AccountPosition>, ServiceError> {
+        if from > to {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let account = self.shared
+            .load_ledger_account(ledger_account.id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        let calendar = self.shared.business_calendar(account.ledger_id).await?;
+
+        let mut lines = self
+            .shared
+            .line_repo
+            .find_by_account_and_pst_time_less_than_equal(ledger_account.id, Self::end_of_day(to))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        // Lines of discarded posting versions never count, whatever their status
+        lines.retain(|l| l.discarded_time.is_none());
+        let lines = self.value_dated(lines, calendar.as_ref()).await?;
+
+        let mut current = Self::empty_position(ledger_account, from);
+        let mut pending_lines = lines.iter().peekable();
+        let mut ladder = Vec::new();
+        let mut value_date = from;
+        loop {
+            while let Some((_, line)) = pending_lines.next_if(|(date, _)| *date <= value_date) {
+                Self::accumulate(&mut current, line);
+            }
+            current.value_date = value_date;
+            ladder.push(current.clone());
+            if value_date >= to {
+                break;
+            }
+            value_date = value_date.succ_opt().ok_or(ServiceError::NotEnoughInfo)?;
+        }
+        Ok(ladder)
+    }
+    async fn currency_positions(&self, ledger: Ledger, value_date: NaiveDate) -> Result<CurrencyPositionReport, ServiceError> {
+        let accounts = self.shared.load_ledger_accounts_bo(&ledger).await?;
+        let calendar = self.shared.business_calendar(ledger.id).await?;
+        let mut totals = Vec::new();
+        for account in accounts {
+            let mut lines = self
+                .shared
+                .line_repo
+                .find_by_account_and_pst_time_less_than_equal(account.id, Self::end_of_day(value_date))
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            lines.retain(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none());
+            let lines = self.value_dated(lines, calendar.as_ref()).await?;
+            for (_, line) in lines.iter().filter(|(date, _)| *date <= value_date) {
+                if let Some(currency) = line.currency.as_ref().or(account.currency.as_ref()) {
+                    CurrencyTotal::accumulate(&mut totals, currency, &line.debit_amount, &line.credit_amount);
+                }
+            }
+        }
+        Ok(CurrencyPositionReport::from_totals(ledger, value_date, totals))
+    }
+}
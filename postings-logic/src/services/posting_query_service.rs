@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::operation_history::{OperationHistory, OperationVersion};
+use postings_api::service::posting_query_service::PostingQueryService;
+use postings_api::domain::posting::Posting;
+use postings_api::service::posting_service::{Page, PostingService};
+use postings_api::ServiceError;
+use uuid::Uuid;
+use crate::mappers::page::PageMapper;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct PostingQueryServiceImpl {
+    shared: SharedService,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+}
+
+impl PostingQueryServiceImpl {
+    pub fn new(shared: SharedService, posting_service: Arc<dyn PostingService + Send + Sync>) -> Self {
+        Self { shared, posting_service }
+    }
+}
+
+#[async_trait]
+impl PostingQueryService for PostingQueryServiceImpl {
+    async fn operation_history(&self, opr_id: &[u8; 34]) -> Result<OperationHistory, ServiceError> {
+        let mut postings = self.shared.posting_repo
+            .find_by_opr_id(opr_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        if postings.is_empty() {
+            return Err(ServiceError::PostingNotFound);
+        }
+        postings.sort_by_key(|p| p.record_time);
+
+        let mut ledgers: HashMap<Uuid, Ledger> = HashMap::new();
+        let mut versions = Vec::with_capacity(postings.len());
+        for posting in postings {
+            let ledger = match ledgers.get(&posting.ledger_id) {
+                Some(ledger) => ledger.clone(),
+                None => {
+                    let ledger = self.shared.load_ledger_bo(posting.ledger_id).await?;
+                    ledgers.insert(posting.ledger_id, ledger.clone());
+                    ledger
+                }
+            };
+            versions.push(OperationVersion::of(PostingMapper::to_bo(posting, ledger, vec![])?));
+        }
+        let lines = self.posting_service.find_posting_lines_by_operation_id(opr_id).await?;
+
+        Ok(OperationHistory { opr_id: *opr_id, versions, lines })
+    }
+
+    async fn find_postings_by_ledger_paged(&self, ledger: Ledger, page: usize, size: usize) -> Result<Page<Posting>, ServiceError> {
+        let request = PageMapper::to_request(page, size)?;
+        let postings = self.shared.posting_repo
+            .find_by_ledger_id_paged(ledger.id, request)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        PageMapper::try_to_bo(postings, |p| PostingMapper::to_bo(p, ledger.clone(), vec![]))
+    }
+}
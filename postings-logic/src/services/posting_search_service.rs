@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_search::{PostingSearchFilter, PostingSort};
+use postings_api::service::posting_search_service::PostingSearchService;
+use postings_api::service::posting_service::Page;
+use postings_api::ServiceError;
+use postings_db::models::posting_filter::{PostingFilter, PostingOrder};
+use uuid::Uuid;
+use crate::mappers::page::PageMapper;
+use crate::mappers::posting::PostingMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct PostingSearchServiceImpl {
+    shared: SharedService,
+}
+
+impl PostingSearchServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl PostingSearchService for PostingSearchServiceImpl {
+    async fn search(&self, filter: PostingSearchFilter) -> Result<Page<Posting>, ServiceError> {
+        let request = PageMapper::to_request(filter.page, filter.size)?;
+        let criteria = PostingFilter {
+            ledger_ids: filter.ledger_id.into_iter().collect(),
+            account_id: filter.account_id,
+            opr_id: filter.opr_id,
+            opr_type: filter.opr_type,
+            pst_status: filter.pst_status.map(PostingMapper::status_to_model),
+            pst_time_from: filter.pst_time_from,
+            pst_time_to: filter.pst_time_to,
+            order: match filter.sort {
+                PostingSort::RecordTimeAsc => PostingOrder::RecordTimeAsc,
+                PostingSort::RecordTimeDesc => PostingOrder::RecordTimeDesc,
+                PostingSort::PstTimeAsc => PostingOrder::PstTimeAsc,
+                PostingSort::PstTimeDesc => PostingOrder::PstTimeDesc,
+            },
+        };
+        let postings = self.shared.posting_repo
+            .find_by_filter_paged(&criteria, request)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+
+        let mut ledgers: HashMap<Uuid, Ledger> = HashMap::new();
+        for posting in postings.content.iter() {
+            if !ledgers.contains_key(&posting.ledger_id) {
+                let ledger = self.shared.load_ledger_bo(posting.ledger_id).await?;
+                ledgers.insert(posting.ledger_id, ledger);
+            }
+        }
+        PageMapper::try_to_bo(postings, |p| {
+            let ledger = ledgers[&p.ledger_id].clone();
+            PostingMapper::to_bo(p, ledger, vec![])
+        })
+    }
+}
@@ -1,88 +1,934 @@
 use async_trait::async_trait;
+use postings_api::domain::domain_event::DomainEvent;
 use postings_api::domain::posting::Posting;
 use postings_api::domain::posting_line::PostingLine;
 use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::privileged_context::PrivilegedContext;
 use postings_api::service::posting_service::{PostingService, Page};
 use postings_api::ServiceError;
-use crate::services::shared_service::SharedService;
-use chrono::{DateTime, Utc};
+use crate::services::shared_service::{seal_posting, ChainLink, HashingRules, ScopedSharedService, SharedService};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use uuid::Uuid;
 use bigdecimal::BigDecimal;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use log::{error, info, warn};
+use postings_db::DbError;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::unit_of_work::UnitOfWork;
+use postings_db::repositories::account_limit_repository::AccountLimitRepository;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use postings_db::repositories::category_rule_repository::CategoryRuleRepository;
+use postings_db::repositories::product_repository::ProductRepository;
+use postings_db::repositories::prepared_posting_repository::PreparedPostingRepository;
+use postings_db::repositories::ledger_closure_repository::LedgerClosureRepository;
+use postings_db::repositories::balance_checkpoint_repository::BalanceCheckpointRepository;
+use postings_db::models::prepared_posting::PreparedPosting as PreparedPostingModel;
+use postings_api::domain::prepared_posting::{PreparedPosting, PreparedPostingStatus};
+use postings_api::service::two_phase_posting_service::{TwoPhaseHook, TwoPhasePostingService};
+use crate::mappers::prepared_posting::PreparedPostingMapper;
 use crate::hash_utils::hash_serialize;
+use crate::mappers::account_limit::AccountLimitMapper;
 use crate::mappers::posting::PostingMapper;
 use crate::mappers::posting_line::PostingLineMapper;
+use crate::mappers::page::PageMapper;
+use crate::services::fee_schedule_service::{fee_line_ref, load_fee_schedule};
+use crate::services::category_rule_service::categorize_lines;
+use crate::mappers::category_rule::CategoryRuleMapper;
+use crate::mappers::ledger_closure::LedgerClosureMapper;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::category_rule::categorize;
+use futures::stream::{BoxStream, StreamExt};
 
 pub struct PostingServiceImpl {
     shared: SharedService,
     // posting_repo, stmt_repo, line_repo would be here
+    limit_repo: Option<Arc<dyn AccountLimitRepository + Send + Sync>>,
+    fee_repo: Option<Arc<dyn FeeScheduleRepository + Send + Sync>>,
+    category_rule_repo: Option<Arc<dyn CategoryRuleRepository + Send + Sync>>,
+    product_repo: Option<Arc<dyn ProductRepository + Send + Sync>>,
+    prepared_repo: Option<Arc<dyn PreparedPostingRepository + Send + Sync>>,
+    two_phase_hooks: Vec<Arc<dyn TwoPhaseHook + Send + Sync>>,
+    closure_repo: Option<Arc<dyn LedgerClosureRepository + Send + Sync>>,
+    checkpoint_repo: Option<Arc<dyn BalanceCheckpointRepository + Send + Sync>>,
+}
+
+/// Controls a new posting passes before it is recorded.
+#[derive(Clone, Copy)]
+struct Controls {
+    limits: bool,
+    /// Privileged adjustment, which passes the period closures allowing adjustments.
+    adjustment: bool,
+}
+
+impl Controls {
+    const REGULAR: Controls = Controls { limits: true, adjustment: false };
+    const LIMIT_OVERRIDE: Controls = Controls { limits: false, adjustment: false };
+    const ADJUSTMENT: Controls = Controls { limits: true, adjustment: true };
 }
 
 impl PostingServiceImpl {
     pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+        Self {
+            shared,
+            limit_repo: None,
+            fee_repo: None,
+            category_rule_repo: None,
+            product_repo: None,
+            prepared_repo: None,
+            two_phase_hooks: Vec::new(),
+            closure_repo: None,
+            checkpoint_repo: None,
+        }
+    }
+
+    /// Service for one tenant or ledger, reading and writing only the postings of `shared`'s scope.
+    pub fn scoped(shared: ScopedSharedService) -> Self {
+        Self::new(shared.into_shared())
+    }
+
+    /// Rejects postings before the cut-off of a closed period with `ServiceError::PeriodClosed`.
+    pub fn with_closure_repo(mut self, closure_repo: Arc<dyn LedgerClosureRepository + Send + Sync>) -> Self {
+        self.closure_repo = Some(closure_repo);
+        self
+    }
+
+    /// Drops the balance checkpoints a new posting is back-dated into, so statements generated
+    /// from checkpoints see its lines. Needed wherever checkpoints are created.
+    pub fn with_checkpoint_repo(mut self, checkpoint_repo: Arc<dyn BalanceCheckpointRepository + Send + Sync>) -> Self {
+        self.checkpoint_repo = Some(checkpoint_repo);
+        self
+    }
+
+    /// Enables account limit checks on new postings.
+    pub fn with_limit_repo(mut self, limit_repo: Arc<dyn AccountLimitRepository + Send + Sync>) -> Self {
+        self.limit_repo = Some(limit_repo);
+        self
+    }
+
+    /// Enables automatic fee lines for operation types that have a fee schedule.
+    pub fn with_fee_repo(mut self, fee_repo: Arc<dyn FeeScheduleRepository + Send + Sync>) -> Self {
+        self.fee_repo = Some(fee_repo);
+        self
+    }
+
+    /// Labels lines returned by queries using the ledgers' categorization rules.
+    pub fn with_category_rule_repo(mut self, category_rule_repo: Arc<dyn CategoryRuleRepository + Send + Sync>) -> Self {
+        self.category_rule_repo = Some(category_rule_repo);
+        self
+    }
+
+    /// Applies the fee schedules of the payer account's product in place of the ledger defaults.
+    pub fn with_product_repo(mut self, product_repo: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+        self.product_repo = Some(product_repo);
+        self
+    }
+
+    /// Enables two-phase posting, persisting prepared postings in `prepared_repo`.
+    pub fn with_prepared_posting_repo(mut self, prepared_repo: Arc<dyn PreparedPostingRepository + Send + Sync>) -> Self {
+        self.prepared_repo = Some(prepared_repo);
+        self
+    }
+
+    /// Registers a hook called around the phases of prepared postings.
+    pub fn with_two_phase_hook(mut self, hook: Arc<dyn TwoPhaseHook + Send + Sync>) -> Self {
+        self.two_phase_hooks.push(hook);
+        self
+    }
+
+    /// Records the posting like [`PostingService::new_posting`] and commits the writes `with`
+    /// returns for the sealed posting in the same unit of work, so they are stored exactly when the
+    /// posting is. Needs a unit-of-work repository on the shared service.
+    pub async fn new_posting_with<F>(&self, posting: Posting, with: F) -> Result<Posting, ServiceError>
+    where
+        F: FnOnce(&Posting) -> UnitOfWork + Send,
+    {
+        self.record_posting_with(posting, Controls::REGULAR, with).await
+    }
+
+    async fn categorize(&self, lines: &mut [PostingLine]) -> Result<(), ServiceError> {
+        match &self.category_rule_repo {
+            Some(rule_repo) => categorize_lines(rule_repo.as_ref(), lines).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Appends the fee of the posting's operation type as a debit of the first debited account and a
+    /// credit of the fee account. The schedule of the payer account's product wins over the ledger default.
+    /// Fee lines carry the operation id of the posting and reference the schedule.
+    async fn append_fee_lines(&self, posting: &mut Posting) -> Result<(), ServiceError> {
+        let fee_repo = match &self.fee_repo {
+            Some(repo) => repo,
+            None => return Ok(()),
+        };
+        let zero = BigDecimal::from(0);
+        let payer_line = match posting.lines.iter().find(|l| l.debit_amount > zero) {
+            Some(line) => line.clone(),
+            None => return Ok(()),
+        };
+        let product_id = match &self.product_repo {
+            Some(product_repo) => product_repo
+                .find_by_account_id(payer_line.account.id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .map(|p| p.id),
+            None => None,
+        };
+        let schedule = match load_fee_schedule(&self.shared, fee_repo.as_ref(), &posting.ledger, &posting.opr_type, product_id).await? {
+            Some(schedule) => schedule,
+            None => return Ok(()),
+        };
+        let amount: BigDecimal = posting.lines.iter().map(|l| l.debit_amount.clone()).sum();
+        let fee = schedule.calculate(&amount);
+        if fee <= zero {
+            return Ok(());
+        }
+
+        let fee_ref = fee_line_ref(schedule.id)?;
+        let fee_line = |account: LedgerAccount, debit_amount: BigDecimal, credit_amount: BigDecimal| PostingLine {
+            id: Uuid::new_v4(),
+            account,
+            debit_amount,
+            credit_amount,
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: Some(fee_ref),
+            additional_information: None,
+            hash: None,
+            discarded_time: None,
+            ..payer_line.clone()
+        };
+        posting.lines.push(fee_line(payer_line.account.clone(), fee.clone(), zero.clone()));
+        posting.lines.push(fee_line(schedule.fee_account.clone(), zero, fee));
+        Ok(())
+    }
+
+    /// Verifies that the account totals at the posting time, including the new lines, stay within the account limits.
+    async fn check_limits(&self, posting: &Posting) -> Result<(), ServiceError> {
+        let limit_repo = match &self.limit_repo {
+            Some(repo) => repo,
+            None => return Ok(()),
+        };
+
+        let mut accounts: BTreeMap<Uuid, (&LedgerAccount, BigDecimal, BigDecimal)> = BTreeMap::new();
+        for line in posting.lines.iter() {
+            let entry = accounts
+                .entry(line.account.id)
+                .or_insert((&line.account, BigDecimal::from(0), BigDecimal::from(0)));
+            entry.1 += line.debit_amount.clone();
+            entry.2 += line.credit_amount.clone();
+        }
+
+        // Simulated, cancelled and unposted lines never consume a limit
+        let counts = |status: &PostingStatus| !matches!(status, PostingStatus::Simulated | PostingStatus::Cancelled | PostingStatus::Unposted);
+        let day = posting.pst_time.date_naive();
+        let day_start = Utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0).unwrap());
+        let day_end = Utc.from_utc_datetime(&day.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap());
+        for (account_id, (account, new_debit, new_credit)) in accounts {
+            let limit = match limit_repo.find_by_account_id(account_id).await.map_err(|_| ServiceError::Db)? {
+                Some(limit) => AccountLimitMapper::to_bo(limit, account.clone()),
+                None => continue,
+            };
+
+            let lines = self.shared.line_repo
+                .find_by_account_and_pst_time_less_than_equal(account_id, posting.pst_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let (debit_total, credit_total) = lines
+                .iter()
+                .filter(|l| counts(&l.pst_status) && l.discarded_time.is_none())
+                .fold((new_debit.clone(), new_credit), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+
+            let day_lines = self.shared.line_repo
+                .find_by_account_and_pst_time_between(account_id, day_start, day_end)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            let daily_debit_turnover = day_lines
+                .iter()
+                .filter(|l| counts(&l.pst_status) && l.discarded_time.is_none())
+                .fold(new_debit, |d, l| d + l.debit_amount.clone());
+
+            limit
+                .check(&debit_total, &credit_total, &daily_debit_turnover)
+                .map_err(|limit_type| ServiceError::LimitExceeded { account_id, limit_type })?;
+        }
+        Ok(())
+    }
+
+    /// Fails with `ServiceError::PeriodClosed` when a closure of the ledger or of one of the
+    /// posting's accounts has a later cut-off than the posting time.
+    async fn check_period_open(&self, posting: &Posting, adjustment: bool) -> Result<(), ServiceError> {
+        let Some(closure_repo) = &self.closure_repo else {
+            return Ok(());
+        };
+        let closures = closure_repo
+            .find_by_ledger_id(posting.ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let closed = closures
+            .into_iter()
+            .map(|c| LedgerClosureMapper::to_bo(c, posting.ledger.clone()))
+            .find(|c| c.rejects(posting.pst_time, posting.lines.iter().map(|l| l.account.id), adjustment));
+        if let Some(closure) = closed {
+            info!("Posting at {} rejected by closure {} of ledger {}", posting.pst_time, closure.id, posting.ledger.id);
+            return Err(ServiceError::PeriodClosed);
+        }
+        Ok(())
+    }
+
+    /// Checks a new posting and completes it with its fee lines.
+    async fn validate_posting(&self, posting: &mut Posting, controls: Controls) -> Result<(), ServiceError> {
+        self.shared.ensure_writable(posting.ledger.id).await?;
+        self.check_period_open(posting, controls.adjustment).await?;
+
+        for line in posting.lines.iter_mut() {
+            match (&line.currency, &line.account.currency) {
+                (None, account_currency) => line.currency = account_currency.clone(),
+                (Some(currency), Some(account_currency)) if currency != account_currency => {
+                    return Err(ServiceError::CurrencyMismatch);
+                }
+                _ => {}
+            }
+        }
+        self.check_double_entry(posting).await?;
+
+        self.append_fee_lines(posting).await?;
+
+        if controls.limits {
+            self.check_limits(posting).await?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the posting balances, unless its ledger is a memo ledger.
+    async fn check_double_entry(&self, posting: &Posting) -> Result<(), ServiceError> {
+        if self.shared.load_ledger(posting.ledger.id).await?.memo {
+            return Ok(());
+        }
+        posting.check_balanced()
+    }
+
+    /// The operation id is the idempotency key of a posting. Returns the current version of the
+    /// operation if `posting` is a retry of it, and refuses a different posting under the same id.
+    async fn recorded_operation(&self, posting: &Posting) -> Result<Option<Posting>, ServiceError> {
+        match self.load_current_posting(&posting.opr_id).await? {
+            Some(recorded) if posting.is_retry_of(&recorded) => {
+                info!("Operation of posting {} is already recorded, returning it", recorded.id);
+                Ok(Some(recorded))
+            }
+            Some(recorded) => Err(ServiceError::DuplicateOperation { posting_id: recorded.id }),
+            None => Ok(None),
+        }
+    }
+
+    /// Error for an insert refused by the store because the operation already has a current version.
+    async fn duplicate_operation(&self, opr_id: &[u8; 34]) -> ServiceError {
+        match self.shared.posting_repo.find_by_opr_id_and_discarding_id_is_null(opr_id).await {
+            Ok(Some(recorded)) => ServiceError::DuplicateOperation { posting_id: recorded.id },
+            _ => ServiceError::Db,
+        }
+    }
+
+    /// Drops the checkpoints at or after the posting time of each account the postings book on.
+    /// Runs before the postings are saved, so a failure leaves no line behind a checkpoint.
+    async fn drop_checkpoints(&self, postings: &[Posting]) -> Result<(), ServiceError> {
+        let Some(checkpoint_repo) = &self.checkpoint_repo else {
+            return Ok(());
+        };
+        let mut earliest: HashMap<Uuid, DateTime<Utc>> = HashMap::new();
+        for posting in postings {
+            for line in posting.lines.iter() {
+                earliest
+                    .entry(line.account.id)
+                    .and_modify(|pst_time| *pst_time = (*pst_time).min(posting.pst_time))
+                    .or_insert(posting.pst_time);
+            }
+        }
+        for (account_id, pst_time) in earliest {
+            let dropped = checkpoint_repo
+                .delete_by_account_and_as_of_greater_than_equal(account_id, pst_time)
+                .await
+                .map_err(|_| ServiceError::Db)?;
+            if dropped > 0 {
+                info!("Dropped {dropped} checkpoints of account {account_id} from {pst_time}");
+            }
+        }
+        Ok(())
+    }
+
+    async fn record_posting(&self, posting: Posting, controls: Controls) -> Result<Posting, ServiceError> {
+        self.record_posting_with(posting, controls, |_| UnitOfWork::new()).await
+    }
+
+    /// Records the posting together with the writes `with` returns for it. An operation recorded
+    /// before is returned as it is, without calling `with`.
+    async fn record_posting_with<F>(&self, mut posting: Posting, controls: Controls, with: F) -> Result<Posting, ServiceError>
+    where
+        F: FnOnce(&Posting) -> UnitOfWork + Send,
+    {
+        if let Some(recorded) = self.recorded_operation(&posting).await? {
+            return Ok(recorded);
+        }
+        self.validate_posting(&mut posting, controls).await?;
+        posting.id = Uuid::new_v4();
+        match self.persist_posting_with(posting.clone(), with).await {
+            // Recorded concurrently since the check above
+            Err(ServiceError::DuplicateOperation { .. }) => self.recorded_operation(&posting).await?.ok_or(ServiceError::Db),
+            result => result,
+        }
+    }
+
+    async fn persist_posting(&self, posting: Posting) -> Result<Posting, ServiceError> {
+        self.persist_posting_with(posting, |_| UnitOfWork::new()).await
+    }
+
+    /// Chains the posting to the ledger's latest posting, hashes it and saves it with its lines
+    /// and the writes `with` returns for it.
+    async fn persist_posting_with<F>(&self, mut posting: Posting, with: F) -> Result<Posting, ServiceError>
+    where
+        F: FnOnce(&Posting) -> UnitOfWork + Send,
+    {
+        posting.record_time = Utc::now();
+
+        let antecedent = self.shared.posting_repo.find_first_by_ledger_order_by_record_time_desc(posting.ledger.id).await.map_err(|_| ServiceError::Db)?;
+        let rules = self.shared.hashing_rules(posting.ledger.id).await?;
+        seal_posting(&mut posting, antecedent.map(|ant| (ant.id, ant.hash)), rules)?;
+        self.drop_checkpoints(std::slice::from_ref(&posting)).await?;
+
+        let db_posting = PostingMapper::to_model(posting.clone());
+        let event = DomainEvent::PostingCreated(posting.clone());
+        let entry = self.shared.outbox_entry(&event)?;
+        let attached = with(&posting);
+        if entry.is_some() || !attached.writes().is_empty() {
+            // The posting, its lines, its event and the attached writes are stored together
+            let mut work = UnitOfWork::new();
+            work.save_posting(db_posting);
+            for line in posting.lines.iter() {
+                work.save_posting_line(PostingLineMapper::from_bo(line.clone()));
+            }
+            if let Some(entry) = entry {
+                work.save_outbox_entry(entry);
+            }
+            work.append(attached);
+            match self.shared.try_commit(work).await {
+                Ok(_) => {}
+                Err(DbError::UniqueViolation) => return Err(self.duplicate_operation(&posting.opr_id).await),
+                Err(_) => return Err(ServiceError::Db),
+            }
+        } else {
+            match self.shared.posting_repo.save(&db_posting).await {
+                Ok(()) => {}
+                Err(DbError::UniqueViolation) => return Err(self.duplicate_operation(&posting.opr_id).await),
+                Err(_) => return Err(ServiceError::Db),
+            }
+
+            for line in posting.lines.iter() {
+                let db_line = PostingLineMapper::from_bo(line.clone());
+                self.shared.line_repo.save(db_line).await.map_err(|_| ServiceError::Db)?;
+            }
+        }
+        self.shared.publish(event).await;
+
+        Ok(posting)
+    }
+
+    /// Chains and hashes the postings in order, then saves them all in one repository call.
+    /// Within a ledger, each posting is the antecedent of the next one. Each posting's hash covers
+    /// the hash of its antecedent, so only the chains of different ledgers are hashed in parallel.
+    async fn persist_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError> {
+        let batch_size = postings.len();
+        let mut chains: Vec<LedgerChain> = Vec::new();
+        let mut chain_of: HashMap<Uuid, usize> = HashMap::new();
+        for (position, mut posting) in postings.into_iter().enumerate() {
+            let ledger_id = posting.ledger.id;
+            let chain_idx = match chain_of.get(&ledger_id) {
+                Some(chain_idx) => *chain_idx,
+                None => {
+                    let antecedent = self.shared.posting_repo
+                        .find_first_by_ledger_order_by_record_time_desc(ledger_id)
+                        .await
+                        .map_err(|_| ServiceError::Db)?;
+                    chains.push(LedgerChain {
+                        last_record_time: antecedent.as_ref().map(|ant| ant.record_time).unwrap_or(DateTime::<Utc>::MIN_UTC),
+                        antecedent: antecedent.map(|ant| (ant.id, ant.hash)),
+                        rules: self.shared.hashing_rules(ledger_id).await?,
+                        postings: Vec::new(),
+                    });
+                    chain_of.insert(ledger_id, chains.len() - 1);
+                    chains.len() - 1
+                }
+            };
+            let chain = &mut chains[chain_idx];
+            // Strictly increasing record times keep the latest posting of the ledger unambiguous
+            posting.record_time = Utc::now().max(chain.last_record_time + Duration::microseconds(1));
+            chain.last_record_time = posting.record_time;
+            chain.postings.push((position, posting));
+        }
+
+        let chains = if batch_size >= BLOCKING_HASH_MIN_BATCH {
+            // Off the async workers, one task per ledger
+            let tasks = chains.into_iter().map(|mut chain| tokio::task::spawn_blocking(move || chain.seal().map(|_| chain)));
+            let mut sealed_chains = Vec::with_capacity(chain_of.len());
+            for task in futures::future::join_all(tasks).await {
+                sealed_chains.push(task.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))?);
+            }
+            sealed_chains
+        } else {
+            for chain in chains.iter_mut() {
+                chain.seal()?;
+            }
+            chains
+        };
+        let mut sealed: Vec<(usize, Posting)> = chains.into_iter().flat_map(|chain| chain.postings).collect();
+        sealed.sort_by_key(|(position, _)| *position);
+        let sealed: Vec<Posting> = sealed.into_iter().map(|(_, posting)| posting).collect();
+
+        let models: Vec<_> = sealed
+            .iter()
+            .map(|posting| {
+                let lines = posting.lines.iter().map(|line| PostingLineMapper::from_bo(line.clone())).collect();
+                (PostingMapper::to_model(posting.clone()), lines)
+            })
+            .collect();
+        self.drop_checkpoints(&sealed).await?;
+        let saved = if self.shared.outbox_repo.is_some() {
+            let mut work = UnitOfWork::new();
+            for (posting, (model, lines)) in sealed.iter().zip(models) {
+                work.save_posting(model);
+                for line in lines {
+                    work.save_posting_line(line);
+                }
+                if let Some(entry) = self.shared.outbox_entry(&DomainEvent::PostingCreated(posting.clone()))? {
+                    work.save_outbox_entry(entry);
+                }
+            }
+            self.shared.try_commit(work).await.map(|_| ())
+        } else {
+            self.shared.posting_repo.save_batch(&models).await
+        };
+        match saved {
+            Ok(()) => {
+                for posting in sealed.iter() {
+                    self.shared.publish(DomainEvent::PostingCreated(posting.clone())).await;
+                }
+                Ok(sealed)
+            }
+            Err(DbError::UniqueViolation) => {
+                // An operation of the batch was recorded concurrently
+                for posting in sealed.iter() {
+                    if let error @ ServiceError::DuplicateOperation { .. } = self.duplicate_operation(&posting.opr_id).await {
+                        return Err(error);
+                    }
+                }
+                Err(ServiceError::Db)
+            }
+            Err(_) => Err(ServiceError::Db),
+        }
+    }
+
+    /// Current, non discarded version of an operation with its lines.
+    async fn load_current_posting(&self, opr_id: &[u8; 34]) -> Result<Option<Posting>, ServiceError> {
+        let model = match self.shared.posting_repo.find_by_opr_id_and_discarding_id_is_null(opr_id).await.map_err(|_| ServiceError::Db)? {
+            Some(model) => model,
+            None => return Ok(None),
+        };
+        let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let lines = self.shared.line_repo
+            .find_by_opr_id(opr_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .filter(|l| l.discarded_time.is_none())
+            .collect();
+        let lines = self.shared.lines_to_bo(lines).await?;
+        Ok(Some(PostingMapper::to_bo(model, ledger, lines)?))
+    }
+
+    fn prepared_repo(&self) -> Result<&Arc<dyn PreparedPostingRepository + Send + Sync>, ServiceError> {
+        self.prepared_repo.as_ref().ok_or(ServiceError::TwoPhaseDisabled)
+    }
+
+    async fn load_prepared(&self, prepared_id: Uuid) -> Result<Option<(PreparedPostingModel, PreparedPosting)>, ServiceError> {
+        let model = match self.prepared_repo()?.find_by_id(prepared_id).await.map_err(|_| ServiceError::Db)? {
+            Some(model) => model,
+            None => return Ok(None),
+        };
+        let posting: Posting = serde_json::from_str(&model.payload).map_err(|_| ServiceError::Db)?;
+        Ok(Some((model.clone(), PreparedPostingMapper::to_bo(model, posting))))
+    }
+
+    /// Aborts the prepared posting unless it was resolved concurrently, and notifies the hooks.
+    async fn abort(&self, mut prepared: PreparedPosting) -> Result<Option<PreparedPosting>, ServiceError> {
+        let now = Utc::now();
+        let aborted = self.prepared_repo()?
+            .resolve(prepared.id, PreparedPostingMapper::status_to_model(PreparedPostingStatus::Aborted), now, None)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        if !aborted {
+            return Ok(None);
+        }
+        prepared.status = PreparedPostingStatus::Aborted;
+        prepared.resolved_time = Some(now);
+        info!("Aborted prepared posting {} of {}", prepared.id, prepared.coordinator_ref);
+        for hook in self.two_phase_hooks.iter() {
+            hook.on_abort(&prepared).await;
+        }
+        Ok(Some(prepared))
+    }
+}
+
+/// Batches from this size on are hashed on the blocking thread pool.
+const BLOCKING_HASH_MIN_BATCH: usize = 32;
+
+/// Postings of a batch in one ledger with their positions in the batch, in chain order.
+struct LedgerChain {
+    antecedent: Option<ChainLink>,
+    rules: HashingRules,
+    last_record_time: DateTime<Utc>,
+    postings: Vec<(usize, Posting)>,
+}
+
+impl LedgerChain {
+    /// Seals the postings in order, each chained to the one before.
+    fn seal(&mut self) -> Result<(), ServiceError> {
+        for (_, posting) in self.postings.iter_mut() {
+            seal_posting(posting, self.antecedent, self.rules.clone())?;
+            self.antecedent = Some((posting.id, posting.hash_record.hash));
+        }
+        Ok(())
     }
 }
 
 #[async_trait]
 impl PostingService for PostingServiceImpl {
-    async fn new_posting(&self, mut posting: Posting) -> Result<Posting, ServiceError> {
-        let debit_sum: BigDecimal = posting.lines.iter().map(|l| l.debit_amount.clone()).sum();
-        let credit_sum: BigDecimal = posting.lines.iter().map(|l| l.credit_amount.clone()).sum();
+    async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError> {
+        self.record_posting(posting, Controls::REGULAR).await
+    }
 
-        if debit_sum != credit_sum {
-            return Err(ServiceError::DoubleEntry);
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError> {
+        let mut opr_ids = HashSet::with_capacity(postings.len());
+        if !postings.iter().all(|p| opr_ids.insert(p.opr_id)) {
+            return Err(ServiceError::NotEnoughInfo);
         }
+        // Postings already recorded by an earlier attempt keep their place in the result
+        let mut results: Vec<Option<Posting>> = Vec::with_capacity(postings.len());
+        let mut validated = Vec::with_capacity(postings.len());
+        for mut posting in postings {
+            if let Some(recorded) = self.recorded_operation(&posting).await? {
+                results.push(Some(recorded));
+                continue;
+            }
+            self.validate_posting(&mut posting, Controls::REGULAR).await?;
+            posting.id = Uuid::new_v4();
+            validated.push(posting);
+            results.push(None);
+        }
+        info!("Recording a batch of {} postings", validated.len());
+        let mut recorded = self.persist_postings(validated).await?.into_iter();
+        Ok(results.into_iter().filter_map(|r| r.or_else(|| recorded.next())).collect())
+    }
 
-        posting.id = Uuid::new_v4();
-        posting.record_time = Utc::now();
+    async fn new_posting_with_limit_override(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        context.authorize()?;
+        warn!("Account limits overridden by {} on ledger {}: {}", context.principal(), posting.ledger.id, context.reason());
+        self.record_posting(posting, Controls::LIMIT_OVERRIDE).await
+    }
 
-        // Simplified predecessor logic
-        let opr_id_bytes = posting.opr_id;
-        if let Some(_predecessor) = self.shared.posting_repo.find_by_opr_id_and_discarding_id_is_null(&opr_id_bytes).await.map_err(|_| ServiceError::Db)? {
-            // Discard predecessor, not fully implemented here
+    async fn new_adjustment_posting(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        if posting.pst_type != PostingType::AdjTx {
+            return Err(ServiceError::NotEnoughInfo);
         }
+        context.authorize()?;
+        warn!("Adjustment at {} posted by {} on ledger {}: {}", posting.pst_time, context.principal(), posting.ledger.id, context.reason());
+        self.record_posting(posting, Controls::ADJUSTMENT).await
+    }
 
-        let antecedent = self.shared.posting_repo.find_first_by_ledger_order_by_record_time_desc(posting.ledger.id).await.map_err(|_| ServiceError::Db)?;
-        if let Some(ant) = antecedent {
-            posting.hash_record.antecedent_id = Some(ant.id);
-            posting.hash_record.antecedent_hash = ant.hash;
+    async fn compensate_posting(&self, opr_id: &[u8; 34]) -> Result<Posting, ServiceError> {
+        let compensation_opr_id = hash_serialize(&(opr_id.as_slice(), "compensation")).map_err(|_| ServiceError::NotEnoughInfo)?;
+        if let Some(existing) = self.load_current_posting(&compensation_opr_id).await? {
+            return Ok(existing);
         }
-        
-        let hash = hash_serialize(&posting).map_err(|_| ServiceError::NotEnoughInfo)?; // Simplified error
-        posting.hash_record.hash = Some(hash);
+        let original = self.load_current_posting(opr_id).await?.ok_or(ServiceError::PostingNotFound)?;
+        let opr_type = hash_serialize(&"COMPENSATION").map_err(|_| ServiceError::NotEnoughInfo)?;
+        let compensation = original.compensation(compensation_opr_id, opr_type, Utc::now());
+        // Compensation restores balances that existed before, so account limits are not enforced
+        info!("Compensating posting {} on ledger {}", original.id, original.ledger.id);
+        self.record_posting(compensation, Controls::LIMIT_OVERRIDE).await
+    }
 
-        let db_posting = PostingMapper::to_model(posting.clone());
-        self.shared.posting_repo.save(&db_posting).await.map_err(|_| ServiceError::Db)?;
+    async fn reverse_posting(&self, posting_id: Uuid, reversal_time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        let model = self.shared.posting_repo
+            .find_by_id(posting_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingNotFound)?;
+        if model.discarding_id.is_some() {
+            return Err(ServiceError::PostingAlreadyDiscarded);
+        }
+        self.shared.ensure_writable(model.ledger_id).await?;
+        let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let lines = self.shared.line_repo
+            .find_by_opr_id(&model.opr_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .filter(|l| l.discarded_time.is_none())
+            .collect();
+        let lines = self.shared.lines_to_bo(lines).await?;
+        let original = PostingMapper::to_bo(model, ledger, lines)?;
 
-        for line in posting.lines.iter() {
-            let db_line = PostingLineMapper::from_bo(line.clone());
-            self.shared.line_repo.save(db_line).await.map_err(|_| ServiceError::Db)?;
+        let opr_id = hash_serialize(&(original.opr_id.as_slice(), original.id, "reversal")).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let opr_type = hash_serialize(&"REVERSAL").map_err(|_| ServiceError::NotEnoughInfo)?;
+        let mut reversal = original.reversal(opr_id, opr_type, reversal_time);
+        reversal.id = Uuid::new_v4();
+        self.check_double_entry(&reversal).await?;
+        self.check_period_open(&reversal, false).await?;
+
+        let antecedent = self.shared.posting_repo
+            .find_first_by_ledger_order_by_record_time_desc(reversal.ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        reversal.record_time = Utc::now();
+        let rules = self.shared.hashing_rules(reversal.ledger.id).await?;
+        seal_posting(&mut reversal, antecedent.map(|ant| (ant.id, ant.hash)), rules)?;
+
+        let lines: Vec<_> = reversal.lines.iter().map(|line| PostingLineMapper::from_bo(line.clone())).collect();
+        // A reversal restores balances that existed before, so account limits are not enforced
+        let event = DomainEvent::PostingReversed { reversed_id: original.id, reversal: reversal.clone() };
+        let linked = match self.shared.outbox_entry(&event)? {
+            Some(entry) => {
+                let mut work = UnitOfWork::new();
+                work.discard_posting(original.id, reversal.id)
+                    .save_posting(PostingMapper::to_model(reversal.clone()));
+                for line in lines {
+                    work.save_posting_line(line);
+                }
+                work.save_outbox_entry(entry);
+                match self.shared.try_commit(work).await {
+                    Ok(_) => true,
+                    Err(DbError::NotFound) => false,
+                    Err(_) => return Err(ServiceError::Db),
+                }
+            }
+            None => self.shared.posting_repo
+                .save_reversal(&PostingMapper::to_model(reversal.clone()), &lines, original.id)
+                .await
+                .map_err(|_| ServiceError::Db)?,
+        };
+        if !linked {
+            return Err(ServiceError::PostingAlreadyDiscarded);
         }
-        
-        Ok(posting)
+        info!("Reversed posting {} on ledger {} by {}", original.id, original.ledger.id, reversal.id);
+        self.shared.publish(event).await;
+        Ok(reversal)
     }
 
     async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError> {
-        // Simplified, mapping needed
-        self.shared.posting_repo.find_by_opr_id(opr_id).await.map_err(|_| ServiceError::Db)?;
-        Ok(vec![])
+        let mut models = self.shared.posting_repo.find_by_opr_id(opr_id).await.map_err(|_| ServiceError::Db)?;
+        if models.is_empty() {
+            return Ok(vec![]);
+        }
+        models.sort_by_key(|p| p.record_time);
+        let lines = self.shared.line_repo.find_by_opr_id(opr_id).await.map_err(|_| ServiceError::Db)?;
+        let ledger = self.shared.load_ledger_bo(models[0].ledger_id).await?;
+        let mut postings = Vec::with_capacity(models.len());
+        for model in models {
+            // Lines of a version were discarded together with it
+            let version_lines = lines
+                .iter()
+                .filter(|l| l.discarded_time == model.discarded_time)
+                .cloned()
+                .collect();
+            let version_lines = self.shared.lines_to_bo(version_lines).await?;
+            postings.push(PostingMapper::to_bo(model, ledger.clone(), version_lines)?);
+        }
+        Ok(postings)
     }
 
     async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError> {
-        // Simplified, mapping needed
-        self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|_| ServiceError::Db)?;
-        Ok(vec![])
+        let lines = self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|_| ServiceError::Db)?;
+        let mut result: Vec<PostingLine> = lines
+            .into_iter()
+            .map(|l| PostingLineMapper::to_bo(l, ledger_account.clone()))
+            .collect();
+        self.categorize(&mut result).await?;
+        Ok(result)
     }
 
-    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, _page: usize, _size: usize) -> Result<Page<PostingLine>, ServiceError> {
-        // Simplified, proper pagination and mapping needed
-        let lines = self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|_| ServiceError::Db)?;
-        Ok(Page { content: vec![], total_elements: lines.len() as u64 })
+    async fn stream_postings_by_dates<'a>(&'a self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<BoxStream<'a, Result<PostingLine, ServiceError>>, ServiceError> {
+        // Rules are loaded once for the whole stream
+        let rules = match &self.category_rule_repo {
+            Some(rule_repo) => rule_repo
+                .find_by_ledger_id(ledger_account.ledger.id)
+                .await
+                .map_err(|_| ServiceError::Db)?
+                .into_iter()
+                .map(|m| CategoryRuleMapper::to_bo(m, ledger_account.ledger.clone()))
+                .collect(),
+            None => Vec::new(),
+        };
+        let lines = self.shared.line_repo
+            .stream_by_account_and_pst_time_between(ledger_account.id, date_from, date_to, LineOrder::PostingTime)
+            .map(move |line| {
+                let line = line.map_err(|_| ServiceError::Db)?;
+                let mut line = PostingLineMapper::to_bo(line, ledger_account.clone());
+                line.category = categorize(&rules, &line);
+                Ok(line)
+            });
+        Ok(lines.boxed())
+    }
+
+    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError> {
+        let request = PageMapper::to_request(page, size)?;
+        let lines = self.shared.line_repo
+            .find_by_account_and_pst_time_between_paged(ledger_account.id, date_from, date_to, request)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut result = PageMapper::to_bo(lines, |l| PostingLineMapper::to_bo(l, ledger_account.clone()));
+        self.categorize(&mut result.content).await?;
+        Ok(result)
     }
 
     async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError> {
-        // Simplified, mapping needed
-        self.shared.line_repo.find_by_id_and_account_id(transaction_id, ledger_account.id).await.map_err(|_| ServiceError::Db)?;
-        Err(ServiceError::PostingNotFound)
+        let line = self.shared.line_repo
+            .find_by_id_and_account_id(transaction_id, ledger_account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingLineNotFound)?;
+        let mut line = PostingLineMapper::to_bo(line, ledger_account);
+        self.categorize(std::slice::from_mut(&mut line)).await?;
+        Ok(line)
+    }
+
+    async fn find_posting_line(&self, line_id: Uuid) -> Result<PostingLine, ServiceError> {
+        let line = self.shared.line_repo
+            .find_by_id(line_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingLineNotFound)?;
+        let account = self.shared.load_ledger_account_bo(line.account_id).await?;
+        let mut line = PostingLineMapper::to_bo(line, account);
+        self.categorize(std::slice::from_mut(&mut line)).await?;
+        Ok(line)
+    }
+
+    async fn find_posting_lines_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<PostingLine>, ServiceError> {
+        let lines = self.shared.line_repo.find_by_opr_id(opr_id).await.map_err(|_| ServiceError::Db)?;
+        let mut result = self.shared.lines_to_bo(lines).await?;
+        self.categorize(&mut result).await?;
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl TwoPhasePostingService for PostingServiceImpl {
+    async fn prepare_posting(&self, mut posting: Posting, coordinator_ref: String, timeout: Duration) -> Result<PreparedPosting, ServiceError> {
+        let prepared_repo = self.prepared_repo()?;
+        self.validate_posting(&mut posting, Controls::REGULAR).await?;
+        let now = Utc::now();
+        let prepared = PreparedPosting {
+            id: Uuid::new_v4(),
+            posting,
+            status: PreparedPostingStatus::Prepared,
+            coordinator_ref,
+            expires: now + timeout,
+            created: now,
+            resolved_time: None,
+            posting_id: None,
+        };
+        for hook in self.two_phase_hooks.iter() {
+            hook.on_prepare(&prepared).await?;
+        }
+        let payload = serde_json::to_string(&prepared.posting).map_err(|_| ServiceError::NotEnoughInfo)?;
+        prepared_repo
+            .save(PreparedPostingMapper::to_model(prepared.clone(), payload))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(prepared)
+    }
+
+    async fn commit_prepared(&self, prepared_id: Uuid) -> Result<Posting, ServiceError> {
+        let (model, mut prepared) = self.load_prepared(prepared_id).await?.ok_or(ServiceError::PreparedPostingNotFound)?;
+        if prepared.status != PreparedPostingStatus::Prepared {
+            return Err(ServiceError::PreparedPostingResolved);
+        }
+        if prepared.is_expired(Utc::now()) {
+            self.abort(prepared).await?;
+            return Err(ServiceError::PreparedPostingExpired);
+        }
+
+        // The period may have been closed since the posting was prepared
+        let mut posting = prepared.posting.clone();
+        self.shared.ensure_writable(posting.ledger.id).await?;
+        self.check_period_open(&posting, Controls::REGULAR.adjustment).await?;
+        self.check_limits(&posting).await?;
+        posting.id = Uuid::new_v4();
+
+        // Claim the prepared posting first so a concurrent abort cannot follow a recorded commit
+        let now = Utc::now();
+        let prepared_repo = self.prepared_repo()?;
+        let claimed = prepared_repo
+            .resolve(prepared_id, PreparedPostingMapper::status_to_model(PreparedPostingStatus::Committed), now, Some(posting.id))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        if !claimed {
+            return Err(ServiceError::PreparedPostingResolved);
+        }
+        let posting = match self.persist_posting(posting).await {
+            Ok(posting) => posting,
+            Err(e) => {
+                // Back to the prepared state, leaving the coordinator free to retry or abort
+                if prepared_repo.save(model).await.is_err() {
+                    error!("Prepared posting {prepared_id} is marked committed but its posting was not recorded");
+                }
+                return Err(e);
+            }
+        };
+        prepared.status = PreparedPostingStatus::Committed;
+        prepared.resolved_time = Some(now);
+        prepared.posting_id = Some(posting.id);
+        for hook in self.two_phase_hooks.iter() {
+            hook.on_commit(&prepared, &posting).await;
+        }
+        Ok(posting)
+    }
+
+    async fn abort_prepared(&self, prepared_id: Uuid) -> Result<PreparedPosting, ServiceError> {
+        let (_, prepared) = self.load_prepared(prepared_id).await?.ok_or(ServiceError::PreparedPostingNotFound)?;
+        match prepared.status {
+            PreparedPostingStatus::Aborted => return Ok(prepared),
+            PreparedPostingStatus::Committed => return Err(ServiceError::PreparedPostingResolved),
+            PreparedPostingStatus::Prepared => {}
+        }
+        if let Some(aborted) = self.abort(prepared).await? {
+            return Ok(aborted);
+        }
+        // Resolved concurrently: only an abort keeps this call a no-op
+        let (_, prepared) = self.load_prepared(prepared_id).await?.ok_or(ServiceError::PreparedPostingNotFound)?;
+        match prepared.status {
+            PreparedPostingStatus::Aborted => Ok(prepared),
+            _ => Err(ServiceError::PreparedPostingResolved),
+        }
+    }
+
+    async fn find_prepared(&self, prepared_id: Uuid) -> Result<Option<PreparedPosting>, ServiceError> {
+        Ok(self.load_prepared(prepared_id).await?.map(|(_, prepared)| prepared))
+    }
+
+    async fn abort_expired(&self, as_of: DateTime<Utc>, limit: i64) -> Result<usize, ServiceError> {
+        let expired = self.prepared_repo()?
+            .find_expired(as_of, limit)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut aborted = 0;
+        for model in expired {
+            let posting: Posting = serde_json::from_str(&model.payload).map_err(|_| ServiceError::Db)?;
+            if self.abort(PreparedPostingMapper::to_bo(model, posting)).await?.is_some() {
+                aborted += 1;
+            }
+        }
+        Ok(aborted)
     }
 }
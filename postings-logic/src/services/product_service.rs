@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use postings_api::domain::fee_schedule::FeeSchedule;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::product::{InterestTerms, Product};
+use postings_api::service::product_service::ProductService;
+use postings_api::ServiceError;
+use postings_db::models::product::AccountProduct;
+use postings_db::repositories::fee_schedule_repository::FeeScheduleRepository;
+use postings_db::repositories::product_repository::ProductRepository;
+use uuid::Uuid;
+use crate::mappers::product::ProductMapper;
+use crate::services::fee_schedule_service::load_fee_schedule;
+use crate::services::shared_service::SharedService;
+
+pub struct ProductServiceImpl {
+    shared: SharedService,
+    product_repo: Arc<dyn ProductRepository + Send + Sync>,
+    fee_repo: Arc<dyn FeeScheduleRepository + Send + Sync>,
+}
+
+impl ProductServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        product_repo: Arc<dyn ProductRepository + Send + Sync>,
+        fee_repo: Arc<dyn FeeScheduleRepository + Send + Sync>,
+    ) -> Self {
+        Self { shared, product_repo, fee_repo }
+    }
+
+    async fn load_account_product(&self, account_id: Uuid) -> Result<Option<Product>, ServiceError> {
+        let model = self.product_repo
+            .find_by_account_id(account_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        match model {
+            Some(model) => {
+                let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+                Ok(Some(ProductMapper::to_bo(model, ledger)))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl ProductService for ProductServiceImpl {
+    async fn save_product(&self, mut product: Product) -> Result<Product, ServiceError> {
+        if product.name.is_empty() || product.interest_rate.is_some() != product.day_count_convention.is_some() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(product.ledger.id).await?;
+        product.ledger = self.shared.load_ledger_bo(product.ledger.id).await?;
+        match self.product_repo.find_by_id(product.id).await.map_err(|_| ServiceError::Db)? {
+            Some(existing) if existing.ledger_id != product.ledger.id => return Err(ServiceError::ProductNotFound),
+            Some(existing) => product.created = existing.created,
+            None => {
+                product.id = Uuid::new_v4();
+                product.created = Utc::now();
+            }
+        }
+        self.product_repo
+            .save(ProductMapper::to_model(product.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(product)
+    }
+
+    async fn find_product_by_id(&self, product_id: Uuid) -> Result<Option<Product>, ServiceError> {
+        let model = self.product_repo
+            .find_by_id(product_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        match model {
+            Some(model) => {
+                let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+                Ok(Some(ProductMapper::to_bo(model, ledger)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn link_account(&self, ledger_account: LedgerAccount, product_id: Uuid) -> Result<Product, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(ledger_account.id).await?;
+        self.shared.ensure_writable(account.ledger.id).await?;
+        let product = self.find_product_by_id(product_id).await?.ok_or(ServiceError::ProductNotFound)?;
+        if product.ledger.id != account.ledger.id {
+            return Err(ServiceError::ProductNotFound);
+        }
+        self.product_repo
+            .save_account_product(AccountProduct { account_id: account.id, product_id, linked: Utc::now() })
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(product)
+    }
+
+    async fn find_account_product(&self, ledger_account: LedgerAccount) -> Result<Option<Product>, ServiceError> {
+        self.load_account_product(ledger_account.id).await
+    }
+
+    async fn resolve_fee_schedule(&self, ledger_account: LedgerAccount, opr_type: &[u8; 34]) -> Result<Option<FeeSchedule>, ServiceError> {
+        let account = self.shared.load_ledger_account_bo(ledger_account.id).await?;
+        let product_id = self.load_account_product(account.id).await?.map(|p| p.id);
+        load_fee_schedule(&self.shared, self.fee_repo.as_ref(), &account.ledger, opr_type, product_id).await
+    }
+
+    async fn resolve_interest_terms(&self, ledger_account: LedgerAccount) -> Result<Option<InterestTerms>, ServiceError> {
+        Ok(self.load_account_product(ledger_account.id).await?.and_then(|p| p.interest_terms()))
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use log::warn;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::tenant_quota::{QuotaUsage, TenantQuota};
+use postings_api::service::posting_service::{Page, PostingService};
+use postings_api::ServiceError;
+use uuid::Uuid;
+
+/// Maps a submitted posting to the tenant it is accounted to.
+pub type TenantResolver = Arc<dyn Fn(&Posting) -> Uuid + Send + Sync>;
+
+/// Ingestion gateway enforcing per-tenant posting quotas before delegating to the wrapped service.
+/// Tenants are identified by the posting's ledger unless another resolver is configured.
+/// Usage counters are kept in memory per process and exposed through [`QuotaPostingServiceImpl::usage`].
+pub struct QuotaPostingServiceImpl {
+    inner: Arc<dyn PostingService + Send + Sync>,
+    default_quota: TenantQuota,
+    tenant_quotas: HashMap<Uuid, TenantQuota>,
+    resolver: TenantResolver,
+    usage: Mutex<HashMap<Uuid, QuotaUsage>>,
+}
+
+impl QuotaPostingServiceImpl {
+    pub fn new(inner: Arc<dyn PostingService + Send + Sync>, default_quota: TenantQuota) -> Self {
+        Self {
+            inner,
+            default_quota,
+            tenant_quotas: HashMap::new(),
+            resolver: Arc::new(|posting: &Posting| posting.ledger.id),
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Overrides the default quota for one tenant.
+    pub fn with_tenant_quota(mut self, tenant_id: Uuid, quota: TenantQuota) -> Self {
+        self.tenant_quotas.insert(tenant_id, quota);
+        self
+    }
+
+    pub fn with_tenant_resolver(mut self, resolver: TenantResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Current usage of a tenant, `None` if it has not submitted anything yet.
+    pub fn usage(&self, tenant_id: Uuid) -> Option<QuotaUsage> {
+        self.usage.lock().unwrap().get(&tenant_id).cloned()
+    }
+
+    /// Usage of all tenants seen so far.
+    pub fn usage_snapshot(&self) -> HashMap<Uuid, QuotaUsage> {
+        self.usage.lock().unwrap().clone()
+    }
+
+    /// Counts the posting against its tenant's quota, failing if a window is exhausted.
+    fn acquire(&self, posting: &Posting) -> Result<(), ServiceError> {
+        let tenant_id = (self.resolver)(posting);
+        let quota = self.tenant_quotas.get(&tenant_id).unwrap_or(&self.default_quota);
+        let now = Utc::now();
+        let mut usage = self.usage.lock().unwrap();
+        let tenant_usage = usage.entry(tenant_id).or_insert_with(|| QuotaUsage::new(now));
+        tenant_usage.roll(now);
+        if let Err((window, limit)) = quota.check(tenant_usage) {
+            tenant_usage.rejected += 1;
+            warn!("Posting quota of {limit} per {window:?} exceeded for tenant {tenant_id} ({} rejected so far)", tenant_usage.rejected);
+            return Err(ServiceError::QuotaExceeded { tenant_id, window, limit });
+        }
+        tenant_usage.minute_count += 1;
+        tenant_usage.day_count += 1;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PostingService for QuotaPostingServiceImpl {
+    async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError> {
+        self.acquire(&posting)?;
+        self.inner.new_posting(posting).await
+    }
+
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError> {
+        for posting in postings.iter() {
+            self.acquire(posting)?;
+        }
+        self.inner.new_postings(postings).await
+    }
+
+    async fn new_posting_with_limit_override(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        self.acquire(&posting)?;
+        self.inner.new_posting_with_limit_override(posting, context).await
+    }
+
+    async fn new_adjustment_posting(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        self.acquire(&posting)?;
+        self.inner.new_adjustment_posting(posting, context).await
+    }
+
+    /// Compensations roll back earlier postings and are not counted against the quota.
+    async fn compensate_posting(&self, opr_id: &[u8; 34]) -> Result<Posting, ServiceError> {
+        self.inner.compensate_posting(opr_id).await
+    }
+
+    async fn reverse_posting(&self, posting_id: Uuid, reversal_time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        self.inner.reverse_posting(posting_id, reversal_time).await
+    }
+
+    async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError> {
+        self.inner.find_postings_by_operation_id(opr_id).await
+    }
+
+    async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError> {
+        self.inner.find_postings_by_dates(ledger_account, date_from, date_to).await
+    }
+
+    async fn stream_postings_by_dates<'a>(&'a self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<BoxStream<'a, Result<PostingLine, ServiceError>>, ServiceError> {
+        self.inner.stream_postings_by_dates(ledger_account, date_from, date_to).await
+    }
+
+    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError> {
+        self.inner.find_postings_by_dates_paged(ledger_account, date_from, date_to, page, size).await
+    }
+
+    async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError> {
+        self.inner.find_posting_line_by_id(ledger_account, transaction_id).await
+    }
+
+    async fn find_posting_line(&self, line_id: Uuid) -> Result<PostingLine, ServiceError> {
+        self.inner.find_posting_line(line_id).await
+    }
+
+    async fn find_posting_lines_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<PostingLine>, ServiceError> {
+        self.inner.find_posting_lines_by_operation_id(opr_id).await
+    }
+}
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use log::{info, warn};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::report_schedule::{
+    DailyJournal, Report, ReportArtifact, ReportFormat, ReportSchedule, ReportScheduleStatus, ReportType,
+};
+use postings_api::domain::trial_balance::TrialBalance;
+use postings_api::service::object_store::ObjectStore;
+use postings_api::service::report_renderer::ReportRenderer;
+use postings_api::service::report_schedule_service::ReportScheduleService;
+use postings_api::service::trial_balance_service::TrialBalanceService;
+use postings_api::ServiceError;
+use postings_db::repositories::report_schedule_repository::ReportScheduleRepository;
+use uuid::Uuid;
+use crate::export::account_stmt_exporter::CsvFormat;
+use crate::hash_utils::hash_bytes;
+use crate::mappers::report_schedule::ReportScheduleMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct ReportScheduleServiceImpl {
+    shared: SharedService,
+    schedule_repo: Arc<dyn ReportScheduleRepository + Send + Sync>,
+    object_store: Arc<dyn ObjectStore + Send + Sync>,
+    trial_balance_service: Arc<dyn TrialBalanceService + Send + Sync>,
+    pdf_renderer: Option<Arc<dyn ReportRenderer + Send + Sync>>,
+}
+
+impl ReportScheduleServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        schedule_repo: Arc<dyn ReportScheduleRepository + Send + Sync>,
+        object_store: Arc<dyn ObjectStore + Send + Sync>,
+        trial_balance_service: Arc<dyn TrialBalanceService + Send + Sync>,
+    ) -> Self {
+        Self { shared, schedule_repo, object_store, trial_balance_service, pdf_renderer: None }
+    }
+
+    /// Enables reports in [`ReportFormat::Pdf`]. Without a renderer they fail with
+    /// `ReportRendererNotConfigured`.
+    pub fn with_pdf_renderer(mut self, renderer: Arc<dyn ReportRenderer + Send + Sync>) -> Self {
+        self.pdf_renderer = Some(renderer);
+        self
+    }
+
+    async fn load_schedule(&self, schedule_id: Uuid) -> Result<ReportSchedule, ServiceError> {
+        let model = self.schedule_repo
+            .find_by_id(schedule_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::ReportScheduleNotFound)?;
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        Ok(ReportScheduleMapper::to_bo(model, ledger_bo))
+    }
+
+    async fn save_schedule(&self, schedule: ReportSchedule) -> Result<ReportSchedule, ServiceError> {
+        self.schedule_repo
+            .save(ReportScheduleMapper::to_model(schedule.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(schedule)
+    }
+
+    async fn compute(&self, ledger: Ledger, report_type: &ReportType, ref_time: DateTime<Utc>) -> Result<Report, ServiceError> {
+        match report_type {
+            ReportType::TrialBalance => Ok(Report::TrialBalance(self.trial_balance_service.trial_balance(ledger, ref_time).await?)),
+            ReportType::DailyJournal => {
+                let from = ref_time - Duration::days(1);
+                let mut lines = Vec::new();
+                for account in self.shared.load_ledger_accounts_bo(&ledger).await? {
+                    lines.extend(self.shared.line_repo
+                        .find_by_account_and_pst_time_between(account.id, from, ref_time)
+                        .await
+                        .map_err(|_| ServiceError::Db)?);
+                }
+                lines.sort_by(|a, b| (a.pst_time, a.record_time, a.id).cmp(&(b.pst_time, b.record_time, b.id)));
+                let lines = self.shared.lines_to_bo(lines).await?;
+                Ok(Report::DailyJournal(DailyJournal { ledger, from, to: ref_time, lines }))
+            }
+        }
+    }
+
+    fn render(&self, report: &Report, format: &ReportFormat) -> Result<Vec<u8>, ServiceError> {
+        match format {
+            ReportFormat::Json => serde_json::to_vec(report).map_err(|_| ServiceError::ExportFailed),
+            ReportFormat::Csv => Ok(match report {
+                Report::TrialBalance(trial_balance) => trial_balance_csv(trial_balance),
+                Report::DailyJournal(journal) => journal_csv(journal),
+            }.into_bytes()),
+            ReportFormat::Pdf => self.pdf_renderer
+                .as_ref()
+                .ok_or(ServiceError::ReportRendererNotConfigured)?
+                .render(report),
+        }
+    }
+
+    async fn generate_artifact(&self, ledger: Ledger, report_type: ReportType, format: ReportFormat, ref_time: DateTime<Utc>, schedule_id: Option<Uuid>) -> Result<ReportArtifact, ServiceError> {
+        let ledger_id = ledger.id;
+        let report = self.compute(ledger, &report_type, ref_time).await?;
+        let content = self.render(&report, &format)?;
+        let version = self.find_artifacts(ledger_id, report_type.clone(), ref_time)
+            .await?
+            .iter()
+            .filter(|a| a.format == format)
+            .map(|a| a.version)
+            .max()
+            .unwrap_or(0) + 1;
+        let type_name = match report_type {
+            ReportType::TrialBalance => "trial-balance",
+            ReportType::DailyJournal => "daily-journal",
+        };
+        let object_key = format!(
+            "reports/{ledger_id}/{type_name}/{}/v{version}.{}",
+            ref_time.format("%Y%m%dT%H%M%S%.6fZ"),
+            format.extension(),
+        );
+        let artifact = ReportArtifact {
+            id: Uuid::new_v4(),
+            schedule_id,
+            ledger_id,
+            report_type,
+            format,
+            ref_time,
+            version,
+            object_key,
+            checksum: hash_bytes(&content),
+            size: content.len() as i64,
+            created: Utc::now(),
+        };
+        // Store the object first: an artifact must never point to missing content
+        self.object_store.put(&artifact.object_key, content).await?;
+        self.schedule_repo
+            .save_artifact(ReportScheduleMapper::artifact_to_model(artifact.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(artifact)
+    }
+
+    /// Generates the reports of all periods of the schedule ended by `as_of`, advancing the
+    /// schedule after each one so a failure resumes at the failed period on the next run.
+    async fn run_schedule(&self, mut schedule: ReportSchedule, as_of: DateTime<Utc>) -> Result<Vec<ReportArtifact>, ServiceError> {
+        let mut artifacts = Vec::new();
+        while schedule.next_ref_time <= as_of {
+            let artifact = self.generate_artifact(
+                schedule.ledger.clone(),
+                schedule.report_type.clone(),
+                schedule.format.clone(),
+                schedule.next_ref_time,
+                Some(schedule.id),
+            ).await?;
+            info!("Report schedule {} generated {}", schedule.id, artifact.object_key);
+            artifacts.push(artifact);
+            schedule.next_ref_time = schedule.frequency.next_ref_time(schedule.next_ref_time);
+            schedule = self.save_schedule(schedule).await?;
+        }
+        Ok(artifacts)
+    }
+}
+
+fn trial_balance_csv(trial_balance: &TrialBalance) -> String {
+    let mut csv = String::from("category,account_id,debit_balance,credit_balance\n");
+    for group in &trial_balance.groups {
+        for line in &group.lines {
+            csv.push_str(&format!("{},{},{},{}\n", group.category, line.account.id, line.debit_balance, line.credit_balance));
+        }
+    }
+    csv.push_str(&format!("TOTAL,,{},{}\n", trial_balance.total_debit, trial_balance.total_credit));
+    csv
+}
+
+fn journal_csv(journal: &DailyJournal) -> String {
+    let mut csv = String::from("line_id,pst_time,record_time,opr_id,account_id,debit_amount,credit_amount,currency,additional_information\n");
+    for line in &journal.lines {
+        let opr_id: String = line.opr_id.iter().map(|b| format!("{b:02x}")).collect();
+        let fields = [
+            line.id.to_string(),
+            line.pst_time.to_rfc3339(),
+            line.record_time.to_rfc3339(),
+            opr_id,
+            line.account.id.to_string(),
+            line.debit_amount.to_string(),
+            line.credit_amount.to_string(),
+            line.currency.clone().unwrap_or_default(),
+            line.additional_information.clone().unwrap_or_default(),
+        ];
+        csv.push_str(&fields.iter().map(|f| CsvFormat::field(f)).collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[async_trait]
+impl ReportScheduleService for ReportScheduleServiceImpl {
+    async fn create_schedule(&self, mut schedule: ReportSchedule) -> Result<ReportSchedule, ServiceError> {
+        self.shared.load_ledger_bo(schedule.ledger.id).await?;
+        schedule.next_ref_time = schedule.frequency.period_end(schedule.next_ref_time);
+        schedule.status = ReportScheduleStatus::Active;
+        self.save_schedule(schedule).await
+    }
+
+    async fn find_schedule_by_id(&self, schedule_id: Uuid) -> Result<Option<ReportSchedule>, ServiceError> {
+        match self.load_schedule(schedule_id).await {
+            Ok(schedule) => Ok(Some(schedule)),
+            Err(ServiceError::ReportScheduleNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn cancel_schedule(&self, schedule_id: Uuid) -> Result<ReportSchedule, ServiceError> {
+        let mut schedule = self.load_schedule(schedule_id).await?;
+        schedule.status = ReportScheduleStatus::Cancelled;
+        self.save_schedule(schedule).await
+    }
+
+    async fn run_due(&self, as_of: DateTime<Utc>) -> Result<Vec<ReportArtifact>, ServiceError> {
+        let due = self.schedule_repo
+            .find_due(as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut artifacts = Vec::new();
+        for model in due {
+            let schedule_id = model.id;
+            let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+            // One failing schedule must not hold back the others, it is retried on the next run
+            match self.run_schedule(ReportScheduleMapper::to_bo(model, ledger_bo), as_of).await {
+                Ok(generated) => artifacts.extend(generated),
+                Err(e) => warn!("Report schedule {schedule_id} failed: {e:?}"),
+            }
+        }
+        Ok(artifacts)
+    }
+
+    async fn generate(&self, ledger: Ledger, report_type: ReportType, format: ReportFormat, ref_time: DateTime<Utc>) -> Result<ReportArtifact, ServiceError> {
+        self.generate_artifact(ledger, report_type, format, ref_time, None).await
+    }
+
+    async fn find_artifacts(&self, ledger_id: Uuid, report_type: ReportType, ref_time: DateTime<Utc>) -> Result<Vec<ReportArtifact>, ServiceError> {
+        Ok(self.schedule_repo
+            .find_artifacts(ledger_id, ReportScheduleMapper::type_to_model(report_type), ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .map(ReportScheduleMapper::artifact_to_bo)
+            .collect())
+    }
+
+    async fn load_artifact(&self, artifact_id: Uuid) -> Result<(ReportArtifact, Vec<u8>), ServiceError> {
+        let artifact = self.schedule_repo
+            .find_artifact_by_id(artifact_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .map(ReportScheduleMapper::artifact_to_bo)
+            .ok_or(ServiceError::ReportArtifactNotFound)?;
+        let content = self.object_store
+            .get(&artifact.object_key)
+            .await?
+            .ok_or(ServiceError::ReportArtifactNotFound)?;
+        if hash_bytes(&content) != artifact.checksum {
+            return Err(ServiceError::ReportArtifactHashMismatch);
+        }
+        Ok((artifact, content))
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::info;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::reversal_policy::{Reversal, ReversalContext, ReversalMode, ReversalPolicy};
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::reversal_service::ReversalService;
+use postings_api::ServiceError;
+use postings_db::repositories::reversal_policy_repository::ReversalPolicyRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::reversal_policy::ReversalPolicyMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct ReversalServiceImpl {
+    shared: SharedService,
+    policy_repo: Arc<dyn ReversalPolicyRepository + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+}
+
+impl ReversalServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        policy_repo: Arc<dyn ReversalPolicyRepository + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+    ) -> Self {
+        Self { shared, policy_repo, posting_service }
+    }
+
+    /// Compensation of the posting at `reversal_time`, booked under an operation id derived from
+    /// the posting so a retried correction returns the recorded one.
+    async fn correct(&self, opr_id: &[u8; 34], posting_id: Uuid, reversal_time: DateTime<Utc>, context: &ReversalContext) -> Result<Posting, ServiceError> {
+        let original = self.posting_service
+            .find_postings_by_operation_id(opr_id)
+            .await?
+            .into_iter()
+            .find(|p| p.id == posting_id)
+            .ok_or(ServiceError::PostingNotFound)?;
+        let correction_opr_id = hash_serialize(&(opr_id.as_slice(), posting_id, "correction")).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let opr_type = hash_serialize(&"CORRECTION").map_err(|_| ServiceError::NotEnoughInfo)?;
+        let correction = original.compensation(correction_opr_id, opr_type, reversal_time);
+        // A correction restores balances that existed before, so account limits are not enforced.
+        // The override is authorized with the caller's own key.
+        let privileged = PrivilegedContext::new(context.key(), context.reason())?;
+        self.posting_service.new_posting_with_limit_override(correction, privileged).await
+    }
+}
+
+#[async_trait]
+impl ReversalService for ReversalServiceImpl {
+    async fn reverse(&self, posting_id: Uuid, reversal_time: DateTime<Utc>, context: ReversalContext) -> Result<Reversal, ServiceError> {
+        let model = self.shared.posting_repo
+            .find_by_id(posting_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingNotFound)?;
+        if model.discarding_id.is_some() {
+            return Err(ServiceError::PostingAlreadyDiscarded);
+        }
+        if context.key().ledger_id.is_some_and(|ledger_id| ledger_id != model.ledger_id) {
+            return Err(ServiceError::Forbidden);
+        }
+        let ledger = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let policy = self.find_reversal_policy(ledger).await?;
+        let mode = policy.authorize(context.role(), model.pst_time, reversal_time)?;
+        info!("{:?} of posting {} by {} ({:?}): {}", mode, posting_id, context.principal(), context.role(), context.reason());
+        let posting = match mode {
+            ReversalMode::Storno => self.posting_service.reverse_posting(posting_id, reversal_time).await?,
+            ReversalMode::Correction => self.correct(&model.opr_id, posting_id, reversal_time, &context).await?,
+        };
+        Ok(Reversal { mode, reversed_id: posting_id, posting })
+    }
+
+    async fn save_reversal_policy(&self, mut policy: ReversalPolicy) -> Result<ReversalPolicy, ServiceError> {
+        self.shared.ensure_writable(policy.ledger.id).await?;
+        policy.updated = Utc::now();
+        let ledger = policy.ledger.clone();
+        let saved = self.policy_repo
+            .save(ReversalPolicyMapper::to_model(policy))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(ReversalPolicyMapper::to_bo(saved, ledger))
+    }
+
+    async fn find_reversal_policy(&self, ledger: Ledger) -> Result<ReversalPolicy, ServiceError> {
+        let model = self.policy_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(match model {
+            Some(m) => ReversalPolicyMapper::to_bo(m, ledger),
+            None => ReversalPolicy::same_day(ledger),
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::scheduled_posting::{ScheduledLine, ScheduledPosting, ScheduledPostingStatus};
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::scheduled_posting_service::ScheduledPostingService;
+use postings_api::ServiceError;
+use postings_db::repositories::scheduled_posting_repository::ScheduledPostingRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::scheduled_posting::ScheduledPostingMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::shared_service::SharedService;
+
+/// Books the occurrences of scheduled postings through the posting service, so they are chained
+/// and hashed like any other posting of their ledger.
+pub struct ScheduledPostingServiceImpl {
+    shared: SharedService,
+    schedule_repo: Arc<dyn ScheduledPostingRepository + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+}
+
+impl ScheduledPostingServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        schedule_repo: Arc<dyn ScheduledPostingRepository + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+    ) -> Self {
+        Self { shared, schedule_repo, posting_service }
+    }
+
+    async fn to_bo(&self, model: postings_db::models::scheduled_posting::ScheduledPosting) -> Result<ScheduledPosting, ServiceError> {
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let templates: Vec<ScheduledLine> = serde_json::from_str(&model.lines).map_err(|_| ServiceError::Db)?;
+        let mut lines = Vec::with_capacity(templates.len());
+        for line in templates {
+            // Occurrences are booked on the current state of the accounts
+            let account = self.shared.load_ledger_account_bo(line.account.id).await?;
+            lines.push(ScheduledLine { account, ..line });
+        }
+        Ok(ScheduledPostingMapper::to_bo(model, ledger_bo, lines))
+    }
+
+    async fn load_schedule(&self, schedule_id: Uuid) -> Result<ScheduledPosting, ServiceError> {
+        let model = self.schedule_repo
+            .find_by_id(schedule_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::ScheduledPostingNotFound)?;
+        self.to_bo(model).await
+    }
+
+    async fn save_schedule(&self, schedule: ScheduledPosting) -> Result<ScheduledPosting, ServiceError> {
+        let lines = serde_json::to_string(&schedule.lines).map_err(|_| ServiceError::NotEnoughInfo)?;
+        self.schedule_repo
+            .save(ScheduledPostingMapper::to_model(schedule.clone(), lines))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(schedule)
+    }
+
+    /// Posting of the schedule's next occurrence. Its operation id is derived from the
+    /// occurrence, so booking an occurrence again returns the posting recorded before.
+    fn occurrence_posting(schedule: &ScheduledPosting) -> Result<Posting, ServiceError> {
+        let opr_id = hash_serialize(&(schedule.id, schedule.occurrence)).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let mut builder = PostingBuilder::new(schedule.ledger.clone(), opr_id, schedule.opr_type, schedule.next_pst_time)
+            .record_user(hash_serialize(&"scheduled-posting").map_err(|_| ServiceError::NotEnoughInfo)?)
+            .opr_src(hash_serialize(&schedule.id).map_err(|_| ServiceError::NotEnoughInfo)?)
+            .val_time(schedule.next_pst_time)
+            .pst_type(schedule.pst_type.clone());
+        for line in schedule.lines.iter() {
+            builder = builder.line(line.account.clone(), line.debit_amount.clone(), line.credit_amount.clone());
+        }
+        let mut posting = builder.build();
+        for (line, template) in posting.lines.iter_mut().zip(schedule.lines.iter()) {
+            line.details = template.details.clone();
+        }
+        Ok(posting)
+    }
+}
+
+#[async_trait]
+impl ScheduledPostingService for ScheduledPostingServiceImpl {
+    async fn create_schedule(&self, mut schedule: ScheduledPosting) -> Result<ScheduledPosting, ServiceError> {
+        let zero = BigDecimal::from(0);
+        if schedule.lines.is_empty()
+            || schedule.lines.iter().any(|l| l.debit_amount < zero || l.credit_amount < zero)
+            || schedule.max_occurrences.is_some_and(|max| max <= 0)
+        {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(schedule.ledger.id).await?;
+        schedule.ledger = self.shared.load_ledger_bo(schedule.ledger.id).await?;
+        for line in schedule.lines.iter_mut() {
+            line.account = self.shared.load_ledger_account_bo(line.account.id).await?;
+            if line.account.ledger.id != schedule.ledger.id {
+                return Err(ServiceError::LedgerAccountNotFound);
+            }
+        }
+        schedule.id = Uuid::new_v4();
+        schedule.created = Utc::now();
+        schedule.status = ScheduledPostingStatus::Active;
+        let calendar = self.shared.business_calendar(schedule.ledger.id).await?;
+        schedule.next_pst_time = schedule.nth_booking_time(0, calendar.as_ref());
+        schedule.occurrence = 0;
+        if !self.shared.load_ledger(schedule.ledger.id).await?.memo {
+            Self::occurrence_posting(&schedule)?.check_balanced()?;
+        }
+        if schedule.end_time.is_some_and(|end| end < schedule.start_time) {
+            schedule.status = ScheduledPostingStatus::Completed;
+        }
+        self.save_schedule(schedule).await
+    }
+
+    async fn find_schedule_by_id(&self, schedule_id: Uuid) -> Result<Option<ScheduledPosting>, ServiceError> {
+        match self.load_schedule(schedule_id).await {
+            Ok(schedule) => Ok(Some(schedule)),
+            Err(ServiceError::ScheduledPostingNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn cancel_schedule(&self, schedule_id: Uuid) -> Result<ScheduledPosting, ServiceError> {
+        let mut schedule = self.load_schedule(schedule_id).await?;
+        self.shared.ensure_writable(schedule.ledger.id).await?;
+        if schedule.status != ScheduledPostingStatus::Active {
+            return Err(ServiceError::ScheduledPostingNotActive);
+        }
+        schedule.status = ScheduledPostingStatus::Cancelled;
+        self.save_schedule(schedule).await
+    }
+
+    async fn run_due(&self, as_of: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError> {
+        let models = self.schedule_repo
+            .find_due(as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut due = Vec::with_capacity(models.len());
+        for model in models {
+            due.push(self.to_bo(model).await?);
+        }
+
+        let mut postings = Vec::new();
+        // Earliest occurrence first, so the ledgers' chains follow the posting times of the schedules
+        while let Some(index) = due
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.status == ScheduledPostingStatus::Active && s.next_pst_time <= as_of)
+            .min_by_key(|(_, s)| (s.next_pst_time, s.id))
+            .map(|(index, _)| index)
+        {
+            let schedule = &mut due[index];
+            let posting = match Self::occurrence_posting(schedule) {
+                Ok(posting) => self.posting_service.new_posting(posting).await,
+                Err(e) => Err(e),
+            };
+            match posting {
+                Ok(posting) => {
+                    info!("Scheduled posting {} booked occurrence {} as posting {}", schedule.id, schedule.occurrence, posting.id);
+                    postings.push(posting);
+                    let calendar = self.shared.business_calendar(schedule.ledger.id).await?;
+                    schedule.advance(calendar.as_ref());
+                    self.save_schedule(schedule.clone()).await?;
+                }
+                Err(e) => {
+                    // Retried on the next run; later occurrences of the schedule wait for it
+                    warn!("Scheduled posting {} failed on occurrence {} at {}: {e:?}", schedule.id, schedule.occurrence, schedule.next_pst_time);
+                    due.swap_remove(index);
+                }
+            }
+        }
+        Ok(postings)
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::batch_status::BatchStatus;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::settlement_batch::{SettlementBatch, SettlementBatchTotals};
+use postings_api::service::settlement_batch_service::SettlementBatchService;
+use postings_api::ServiceError;
+use postings_db::models::settlement_batch::SettlementBatchEntry;
+use postings_db::repositories::settlement_batch_repository::SettlementBatchRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::settlement_batch::SettlementBatchMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct SettlementBatchServiceImpl {
+    shared: SharedService,
+    batch_repo: Arc<dyn SettlementBatchRepository + Send + Sync>,
+}
+
+impl SettlementBatchServiceImpl {
+    pub fn new(shared: SharedService, batch_repo: Arc<dyn SettlementBatchRepository + Send + Sync>) -> Self {
+        Self { shared, batch_repo }
+    }
+
+    async fn load_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError> {
+        let model = self
+            .batch_repo
+            .find_by_id(batch_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::BatchNotFound)?;
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        Ok(SettlementBatchMapper::to_bo(model, ledger_bo))
+    }
+
+    async fn save_batch(&self, batch: SettlementBatch) -> Result<SettlementBatch, ServiceError> {
+        let ledger_bo = batch.ledger.clone();
+        let saved = self
+            .batch_repo
+            .save(SettlementBatchMapper::to_model(batch))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(SettlementBatchMapper::to_bo(saved, ledger_bo))
+    }
+}
+
+#[async_trait]
+impl SettlementBatchService for SettlementBatchServiceImpl {
+    async fn open_batch(&self, mut batch: SettlementBatch) -> Result<SettlementBatch, ServiceError> {
+        self.shared.ensure_writable(batch.ledger.id).await?;
+        batch.ledger = self.shared.load_ledger_bo(batch.ledger.id).await?;
+        batch.id = Uuid::new_v4();
+        batch.status = BatchStatus::Open;
+        batch.created = Utc::now();
+        batch.closed_time = None;
+        self.save_batch(batch).await
+    }
+
+    async fn find_batch_by_id(&self, batch_id: Uuid) -> Result<Option<SettlementBatch>, ServiceError> {
+        match self.load_batch(batch_id).await {
+            Ok(batch) => Ok(Some(batch)),
+            Err(ServiceError::BatchNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn assign_posting(&self, batch_id: Uuid, posting: &Posting) -> Result<SettlementBatchTotals, ServiceError> {
+        let batch = self.load_batch(batch_id).await?;
+        self.shared.ensure_writable(batch.ledger.id).await?;
+        if batch.status != BatchStatus::Open {
+            return Err(ServiceError::BatchStatusInvalid);
+        }
+
+        // The posting must be persisted, unchanged and belong to the batch's ledger
+        let stored = self
+            .shared
+            .posting_repo
+            .find_by_id(posting.id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::PostingNotFound)?;
+        if stored.ledger_id != batch.ledger.id || stored.hash != posting.hash_record.hash {
+            return Err(ServiceError::PostingNotFound);
+        }
+        // Discarded postings no longer count, and reversals only undo a posting
+        if stored.discarding_id.is_some() {
+            return Err(ServiceError::PostingAlreadyDiscarded);
+        }
+        let reversal_type = hash_serialize(&"REVERSAL").map_err(|_| ServiceError::NotEnoughInfo)?;
+        if stored.discarded_id.is_some() && stored.opr_type == reversal_type {
+            return Err(ServiceError::ReversalNotBatchable);
+        }
+
+        if self
+            .batch_repo
+            .find_entry_by_posting_id(posting.id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .is_some()
+        {
+            return Err(ServiceError::PostingAlreadyBatched);
+        }
+
+        // The amount comes from the stored lines of the posting, not from the lines passed in
+        let amount: BigDecimal = self
+            .shared
+            .line_repo
+            .find_by_opr_id(&stored.opr_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .into_iter()
+            .filter(|l| l.discarded_time == stored.discarded_time)
+            .map(|l| l.debit_amount)
+            .sum();
+        // The unique posting id of the entries decides concurrent assignments
+        match self
+            .batch_repo
+            .save_entry(SettlementBatchEntry {
+                batch_id,
+                posting_id: posting.id,
+                amount,
+                assigned_time: Utc::now(),
+            })
+            .await
+        {
+            Ok(_) => {}
+            Err(DbError::UniqueViolation) => return Err(ServiceError::PostingAlreadyBatched),
+            Err(_) => return Err(ServiceError::Db),
+        }
+
+        self.batch_totals(batch_id).await
+    }
+
+    async fn batch_totals(&self, batch_id: Uuid) -> Result<SettlementBatchTotals, ServiceError> {
+        let entries = self
+            .batch_repo
+            .find_entries_by_batch_id(batch_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(SettlementBatchTotals {
+            batch_id,
+            posting_count: entries.len() as i64,
+            total_amount: entries.into_iter().map(|e| e.amount).sum(),
+        })
+    }
+
+    async fn find_batch_postings(&self, batch_id: Uuid) -> Result<Vec<Uuid>, ServiceError> {
+        let entries = self
+            .batch_repo
+            .find_entries_by_batch_id(batch_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(entries.into_iter().map(|e| e.posting_id).collect())
+    }
+
+    async fn close_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError> {
+        let mut batch = self.load_batch(batch_id).await?;
+        self.shared.ensure_writable(batch.ledger.id).await?;
+        if batch.status != BatchStatus::Open {
+            return Err(ServiceError::BatchStatusInvalid);
+        }
+        let totals = self.batch_totals(batch_id).await?;
+        if !batch.matches_control(&totals) {
+            return Err(ServiceError::BatchControlMismatch);
+        }
+        batch.status = BatchStatus::Closed;
+        batch.closed_time = Some(Utc::now());
+        self.save_batch(batch).await
+    }
+
+    async fn settle_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError> {
+        let mut batch = self.load_batch(batch_id).await?;
+        self.shared.ensure_writable(batch.ledger.id).await?;
+        if batch.status != BatchStatus::Closed {
+            return Err(ServiceError::BatchStatusInvalid);
+        }
+        batch.status = BatchStatus::Settled;
+        self.save_batch(batch).await
+    }
+
+    async fn reject_batch(&self, batch_id: Uuid) -> Result<SettlementBatch, ServiceError> {
+        let mut batch = self.load_batch(batch_id).await?;
+        self.shared.ensure_writable(batch.ledger.id).await?;
+        if batch.status == BatchStatus::Settled || batch.status == BatchStatus::Rejected {
+            return Err(ServiceError::BatchStatusInvalid);
+        }
+        batch.status = BatchStatus::Rejected;
+        batch.closed_time.get_or_insert_with(Utc::now);
+        self.save_batch(batch).await
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use log::warn;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::shadow_posting::ShadowComparison;
+use postings_api::service::posting_service::{Page, PostingService};
+use postings_api::ServiceError;
+use uuid::Uuid;
+
+/// Posting pair awaiting comparison.
+struct ShadowRecord {
+    primary: Posting,
+    shadow: Result<Posting, String>,
+}
+
+/// Parallel-run wrapper: every posting accepted by the primary service is also written to the
+/// shadow service. Shadow failures never reach the caller; both results are queued and compared
+/// later through [`ShadowPostingServiceImpl::compare_pending`]. Reads are served by the primary only.
+pub struct ShadowPostingServiceImpl {
+    primary: Arc<dyn PostingService + Send + Sync>,
+    shadow: Arc<dyn PostingService + Send + Sync>,
+    pending: Mutex<Vec<ShadowRecord>>,
+}
+
+impl ShadowPostingServiceImpl {
+    pub fn new(primary: Arc<dyn PostingService + Send + Sync>, shadow: Arc<dyn PostingService + Send + Sync>) -> Self {
+        Self { primary, shadow, pending: Mutex::new(Vec::new()) }
+    }
+
+    /// Compares all queued posting pairs and returns the comparisons that found differences.
+    pub fn compare_pending(&self) -> Vec<ShadowComparison> {
+        let records: Vec<ShadowRecord> = std::mem::take(&mut *self.pending.lock().unwrap());
+        records
+            .iter()
+            .map(|r| ShadowComparison::compare(&r.primary, r.shadow.as_ref().map_err(|e| e.clone())))
+            .filter(|c| !c.is_match())
+            .inspect(|c| warn!("Shadow posting mismatch for primary posting {}: {:?}", c.primary_posting_id, c.differences))
+            .collect()
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+
+    /// Posting ids differ between the stores, so the shadow reverses its own current version of
+    /// the operation reversed by the primary.
+    async fn reverse_shadow(&self, reversal: &Posting, reversal_time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        let reversed_opr_id = reversal.opr_src.ok_or(ServiceError::PostingNotFound)?;
+        let reversed = self.shadow
+            .find_postings_by_operation_id(&reversed_opr_id)
+            .await?
+            .into_iter()
+            .find(|p| p.discarding_id.is_none())
+            .ok_or(ServiceError::PostingNotFound)?;
+        self.shadow.reverse_posting(reversed.id, reversal_time).await
+    }
+
+    fn enqueue(&self, primary: &Posting, shadow: Result<Posting, ServiceError>) {
+        let shadow = shadow.map_err(|e| format!("{e:?}"));
+        self.pending.lock().unwrap().push(ShadowRecord { primary: primary.clone(), shadow });
+    }
+}
+
+#[async_trait]
+impl PostingService for ShadowPostingServiceImpl {
+    async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError> {
+        let recorded = self.primary.new_posting(posting.clone()).await?;
+        let shadow = self.shadow.new_posting(posting).await;
+        self.enqueue(&recorded, shadow);
+        Ok(recorded)
+    }
+
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError> {
+        let recorded = self.primary.new_postings(postings.clone()).await?;
+        match self.shadow.new_postings(postings).await {
+            Ok(shadow) => {
+                for (primary, shadow) in recorded.iter().zip(shadow) {
+                    self.enqueue(primary, Ok(shadow));
+                }
+            }
+            Err(e) => {
+                let e = format!("{e:?}");
+                let mut pending = self.pending.lock().unwrap();
+                pending.extend(recorded.iter().map(|p| ShadowRecord { primary: p.clone(), shadow: Err(e.clone()) }));
+            }
+        }
+        Ok(recorded)
+    }
+
+    async fn new_posting_with_limit_override(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        let recorded = self.primary.new_posting_with_limit_override(posting.clone(), context.clone()).await?;
+        let shadow = self.shadow.new_posting_with_limit_override(posting, context).await;
+        self.enqueue(&recorded, shadow);
+        Ok(recorded)
+    }
+
+    async fn new_adjustment_posting(&self, posting: Posting, context: PrivilegedContext) -> Result<Posting, ServiceError> {
+        let recorded = self.primary.new_adjustment_posting(posting.clone(), context.clone()).await?;
+        let shadow = self.shadow.new_adjustment_posting(posting, context).await;
+        self.enqueue(&recorded, shadow);
+        Ok(recorded)
+    }
+
+    async fn compensate_posting(&self, opr_id: &[u8; 34]) -> Result<Posting, ServiceError> {
+        let recorded = self.primary.compensate_posting(opr_id).await?;
+        let shadow = self.shadow.compensate_posting(opr_id).await;
+        self.enqueue(&recorded, shadow);
+        Ok(recorded)
+    }
+
+    async fn reverse_posting(&self, posting_id: Uuid, reversal_time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        let recorded = self.primary.reverse_posting(posting_id, reversal_time).await?;
+        let shadow = self.reverse_shadow(&recorded, reversal_time).await;
+        self.enqueue(&recorded, shadow);
+        Ok(recorded)
+    }
+
+    async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError> {
+        self.primary.find_postings_by_operation_id(opr_id).await
+    }
+
+    async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError> {
+        self.primary.find_postings_by_dates(ledger_account, date_from, date_to).await
+    }
+
+    async fn stream_postings_by_dates<'a>(&'a self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<BoxStream<'a, Result<PostingLine, ServiceError>>, ServiceError> {
+        self.primary.stream_postings_by_dates(ledger_account, date_from, date_to).await
+    }
+
+    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError> {
+        self.primary.find_postings_by_dates_paged(ledger_account, date_from, date_to, page, size).await
+    }
+
+    async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError> {
+        self.primary.find_posting_line_by_id(ledger_account, transaction_id).await
+    }
+
+    async fn find_posting_line(&self, line_id: Uuid) -> Result<PostingLine, ServiceError> {
+        self.primary.find_posting_line(line_id).await
+    }
+
+    async fn find_posting_lines_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<PostingLine>, ServiceError> {
+        self.primary.find_posting_lines_by_operation_id(opr_id).await
+    }
+}
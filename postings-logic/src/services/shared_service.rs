@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
 use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
@@ -7,10 +8,47 @@ use postings_db::repositories::posting_repository::PostingRepository;
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
 use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db::models::earmark::Earmark as EarmarkModel;
+use postings_db::models::ledger_stmt::LedgerStmt as LedgerStmtModel;
+use postings_db::models::outbox_entry::OutboxEntry;
+use postings_db::unit_of_work::{Committed, UnitOfWork, Write};
 use postings_api::ServiceError;
 use postings_db::DbError;
 use uuid::Uuid;
+use crate::mappers::chart_of_account::ChartOfAccountMapper;
+use crate::mappers::ledger::LedgerMapper;
+use crate::mappers::ledger_account::LedgerAccountMapper;
+use crate::mappers::outbox_entry::OutboxEntryMapper;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::hash_utils::{self, hash_serialize};
+use crate::scoping::ledger_account_repository::ScopedLedgerAccountRepository;
+use crate::scoping::ledger_repository::ScopedLedgerRepository;
+use crate::scoping::posting_line_repository::ScopedPostingLineRepository;
+use crate::scoping::posting_repository::ScopedPostingRepository;
+use crate::scoping::account_stmt_repository::ScopedAccountStmtRepository;
+use crate::scoping::posting_trace_repository::ScopedPostingTraceRepository;
+use crate::scoping::unit_of_work_repository::ScopedUnitOfWorkRepository;
+use crate::scoping::LedgerScope;
+use postings_api::domain::business_calendar::BusinessCalendar;
+use postings_api::domain::hash_record::{HashAlgorithm, HashVersion};
+use postings_api::domain::hashing_profile::HashedField;
+use postings_api::domain::posting::Posting;
+use std::collections::HashMap;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_balance::{was_booked_at, AccountBalance};
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::closing_summary::ClosingSummary;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_api::service::event_publisher::EventPublisher;
+use postings_api::domain::posting_type::PostingType;
+use postings_db::models::posting_status::PostingStatus;
 
+#[derive(Clone)]
 pub struct SharedService {
     pub coa_repo: Arc<dyn ChartOfAccountRepository + Send + Sync>,
     pub ledger_repo: Arc<dyn LedgerRepository + Send + Sync>,
@@ -20,6 +58,11 @@ pub struct SharedService {
     pub stmt_repo: Arc<dyn AccountStmtRepository + Send + Sync>,
     pub line_repo: Arc<dyn PostingLineRepository + Send + Sync>,
     pub trace_repo: Arc<dyn PostingTraceRepository + Send + Sync>,
+    pub uow_repo: Option<Arc<dyn UnitOfWorkRepository + Send + Sync>>,
+    pub event_publishers: Vec<Arc<dyn EventPublisher + Send + Sync>>,
+    pub outbox_repo: Option<Arc<dyn OutboxRepository + Send + Sync>>,
+    pub hashing_profile_repo: Option<Arc<dyn HashingProfileRepository + Send + Sync>>,
+    pub holiday_repo: Option<Arc<dyn HolidayRepository + Send + Sync>>,
 }
 
 impl SharedService {
@@ -43,9 +86,158 @@ impl SharedService {
             stmt_repo,
             line_repo,
             trace_repo,
+            uow_repo: None,
+            event_publishers: Vec::new(),
+            outbox_repo: None,
+            hashing_profile_repo: None,
+            holiday_repo: None,
         }
     }
 
+    /// Commits multi-repository writes such as statement closings in a single transaction.
+    pub fn with_unit_of_work(mut self, uow_repo: Arc<dyn UnitOfWorkRepository + Send + Sync>) -> Self {
+        self.uow_repo = Some(uow_repo);
+        self
+    }
+
+    /// Registers a publisher notified of postings and statement closings once they are committed.
+    pub fn with_event_publisher(mut self, publisher: Arc<dyn EventPublisher + Send + Sync>) -> Self {
+        self.event_publishers.push(publisher);
+        self
+    }
+
+    /// Writes the events of postings, reversals and statement closings to the outbox, in the
+    /// transaction of the change, for [`crate::events::outbox_relay::OutboxRelay`] to deliver. Needs the
+    /// unit-of-work repository.
+    pub fn with_outbox(mut self, outbox_repo: Arc<dyn OutboxRepository + Send + Sync>) -> Self {
+        self.outbox_repo = Some(outbox_repo);
+        self
+    }
+
+    /// Applies the ledgers' hashing profiles when hashing new postings, closing postings included.
+    pub fn with_hashing_profile_repo(mut self, hashing_profile_repo: Arc<dyn HashingProfileRepository + Send + Sync>) -> Self {
+        self.hashing_profile_repo = Some(hashing_profile_repo);
+        self
+    }
+
+    /// Dates standing orders, scheduled postings and value dated positions on the ledgers' business
+    /// days. Without it they fall on calendar days.
+    pub fn with_holiday_repo(mut self, holiday_repo: Arc<dyn HolidayRepository + Send + Sync>) -> Self {
+        self.holiday_repo = Some(holiday_repo);
+        self
+    }
+
+    /// Business calendar of the ledger, or `None` without a holiday repository.
+    pub(crate) async fn business_calendar(&self, ledger_id: Uuid) -> Result<Option<BusinessCalendar>, ServiceError> {
+        let Some(holiday_repo) = &self.holiday_repo else {
+            return Ok(None);
+        };
+        let holidays = holiday_repo
+            .find_by_ledger_id(ledger_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(Some(BusinessCalendar::new(holidays.into_iter().map(|h| h.holiday_date))))
+    }
+
+    /// Excluded fields and hash function new postings of the ledger are sealed with.
+    pub(crate) async fn hashing_rules(&self, ledger_id: Uuid) -> Result<HashingRules, ServiceError> {
+        let Some(profile_repo) = &self.hashing_profile_repo else {
+            return Ok((vec![], HashAlgorithm::default()));
+        };
+        let profile = profile_repo
+            .find_by_ledger_id(ledger_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let algorithm = profile.as_ref().map(|p| HashAlgorithm::parse(&p.hash_algorithm)).transpose()?;
+        Ok((
+            HashedField::split(profile.as_ref().map(|p| p.excluded_fields.as_str())),
+            algorithm.unwrap_or_default(),
+        ))
+    }
+
+    /// Outbox entry of the event, to be committed with the change, or `None` without an outbox.
+    pub fn outbox_entry(&self, event: &DomainEvent) -> Result<Option<OutboxEntry>, ServiceError> {
+        if self.outbox_repo.is_none() {
+            return Ok(None);
+        }
+        if self.uow_repo.is_none() {
+            log::error!("The outbox needs a unit-of-work repository to be written with the change");
+            return Err(ServiceError::Db);
+        }
+        OutboxEntryMapper::from_event(event).map(Some).map_err(|_| ServiceError::NotEnoughInfo)
+    }
+
+    /// Hands the event of a committed change to every publisher. Failures are logged only, the
+    /// change itself stays committed.
+    pub async fn publish(&self, event: DomainEvent) {
+        for publisher in self.event_publishers.iter() {
+            if let Err(e) = publisher.publish(&event).await {
+                log::warn!("Failed to publish a committed change: {e:?}");
+            }
+        }
+    }
+
+    /// Stores all writes of `work` or none of them when a unit-of-work repository is configured.
+    /// Otherwise the writes are saved one by one, and a failure leaves the earlier ones stored.
+    /// Ledger statements, posting discards, outbox entries and earmarks have no repository here and savepoints
+    /// cannot be rolled back one by one, all of them need the unit-of-work repository.
+    pub async fn commit(&self, work: UnitOfWork) -> Result<Committed, ServiceError> {
+        self.try_commit(work).await.map_err(|e| {
+            log::error!("Database error committing unit of work: {e:?}");
+            ServiceError::Db
+        })
+    }
+
+    /// Same as [`Self::commit`], with the database error for callers that tell conflicts apart.
+    pub async fn try_commit(&self, work: UnitOfWork) -> Result<Committed, DbError> {
+        if let Some(uow_repo) = &self.uow_repo {
+            return uow_repo.commit(work).await;
+        }
+        for write in work.into_writes() {
+            match write {
+                Write::Posting(posting) => self.posting_repo.save(&posting).await?,
+                Write::PostingLine(line) => {
+                    self.line_repo.save(line).await?;
+                }
+                Write::AccountStmt(stmt) => {
+                    self.stmt_repo.save(stmt).await?;
+                }
+                Write::LedgerStmt(LedgerStmtModel { id, .. })
+                | Write::DiscardPosting { id, .. }
+                | Write::OutboxEntry(OutboxEntry { id, .. })
+                | Write::Earmark(EarmarkModel { id, .. }) => {
+                    log::error!("Write of {id} needs a unit-of-work repository to be committed");
+                    return Err(DbError::Query);
+                }
+                Write::Savepoint(savepoint, _) => {
+                    log::error!("Savepoint {savepoint} needs a unit-of-work repository to be committed");
+                    return Err(DbError::Query);
+                }
+            }
+        }
+        Ok(Committed::default())
+    }
+
+    /// Same service with its ledger, account, posting, line, statement, trace and unit-of-work
+    /// repositories confined to `scope`, for handing to the services of one tenant or ledger. Charts
+    /// of account and named entries are shared between ledgers.
+    pub fn scoped(&self, scope: &LedgerScope) -> ScopedSharedService {
+        let accounts = self.ledger_account_repo.clone();
+        let shared = Self {
+            ledger_repo: Arc::new(ScopedLedgerRepository::new(self.ledger_repo.clone(), scope.clone())),
+            ledger_account_repo: Arc::new(ScopedLedgerAccountRepository::new(accounts.clone(), scope.clone())),
+            posting_repo: Arc::new(ScopedPostingRepository::new(self.posting_repo.clone(), scope.clone())),
+            line_repo: Arc::new(ScopedPostingLineRepository::new(self.line_repo.clone(), accounts.clone(), scope.clone())),
+            stmt_repo: Arc::new(ScopedAccountStmtRepository::new(self.stmt_repo.clone(), accounts.clone(), scope.clone())),
+            trace_repo: Arc::new(ScopedPostingTraceRepository::new(self.trace_repo.clone(), accounts.clone(), scope.clone())),
+            uow_repo: self.uow_repo.clone().map(|uow_repo| -> Arc<dyn UnitOfWorkRepository + Send + Sync> {
+                Arc::new(ScopedUnitOfWorkRepository::new(uow_repo, accounts.clone(), self.posting_repo.clone(), scope.clone()))
+            }),
+            ..self.clone()
+        };
+        ScopedSharedService { shared, scope: scope.clone() }
+    }
+
     pub async fn load_coa(&self, coa_id: Uuid) -> Result<postings_db::models::chart_of_account::ChartOfAccount, ServiceError> {
         self.coa_repo
             .find_by_id(coa_id)
@@ -62,6 +254,20 @@ impl SharedService {
             .ok_or(ServiceError::LedgerNotFound)
     }
 
+    /// Fails with `ServiceError::ReadOnly` when the ledger is switched to read-only mode.
+    pub async fn ensure_writable(&self, ledger_id: Uuid) -> Result<(), ServiceError> {
+        if self.load_ledger(ledger_id).await?.read_only {
+            return Err(ServiceError::ReadOnly);
+        }
+        Ok(())
+    }
+
+    pub async fn load_ledger_bo(&self, ledger_id: Uuid) -> Result<postings_api::domain::ledger::Ledger, ServiceError> {
+        let ledger_model = self.load_ledger(ledger_id).await?;
+        let coa_model = self.load_coa(ledger_model.coa_id).await?;
+        Ok(LedgerMapper::to_bo(ledger_model, ChartOfAccountMapper::to_bo(coa_model)))
+    }
+
     pub async fn load_ledger_account(&self, ledger_account_id: Uuid) -> Result<Option<postings_db::models::ledger_account::LedgerAccount>, ServiceError> {
         match self.ledger_account_repo
             .find_by_id(ledger_account_id)
@@ -75,4 +281,219 @@ impl SharedService {
             }
         }
     }
+
+    /// Loads an account with its ledger and parent chain.
+    pub async fn load_ledger_account_bo(&self, ledger_account_id: Uuid) -> Result<postings_api::domain::ledger_account::LedgerAccount, ServiceError> {
+        let mut chain = vec![self
+            .load_ledger_account(ledger_account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?];
+        while let Some(parent_id) = chain.last().and_then(|m| m.parent_id) {
+            match self.load_ledger_account(parent_id).await? {
+                Some(parent) => chain.push(parent),
+                None => break,
+            }
+        }
+        let ledger_bo = self.load_ledger_bo(chain[0].ledger_id).await?;
+        let mut account_bo = None;
+        for model in chain.into_iter().rev() {
+            account_bo = Some(LedgerAccountMapper::to_bo(model, ledger_bo.clone(), ledger_bo.coa.clone(), account_bo.map(Box::new)));
+        }
+        account_bo.ok_or(ServiceError::LedgerAccountNotFound)
+    }
+
+    /// Balance of posted, non discarded lines up to `ref_time`, on the account's balance side.
+    pub async fn booked_balance(&self, ledger_account: &postings_api::domain::ledger_account::LedgerAccount, ref_time: DateTime<Utc>) -> Result<BigDecimal, ServiceError> {
+        let lines = self.line_repo
+            .find_by_account_and_pst_time_less_than_equal(ledger_account.id, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let (debit, credit) = lines
+            .iter()
+            .filter(|l| l.pst_status == PostingStatus::Posted && l.discarded_time.is_none())
+            .fold((BigDecimal::from(0), BigDecimal::from(0)), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        Ok(match ledger_account.balance_side {
+            BalanceSide::Cr => credit - debit,
+            _ => debit - credit,
+        })
+    }
+
+    /// Totals of an account at `ref_time`, equal to those of its statement at that time. Starts from the
+    /// last closed statement like statement generation, so only newer lines are read.
+    pub async fn account_balance(&self, ledger_account: postings_api::domain::ledger_account::LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        let last_closed_stmt = self.stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(ledger_account.id, ref_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let (opening_debit, opening_credit, lines) = match last_closed_stmt {
+            Some(stmt) => {
+                let lines = self.line_repo
+                    .find_by_account_and_pst_time_between(ledger_account.id, stmt.pst_time, ref_time)
+                    .await
+                    .map_err(|_| ServiceError::Db)?;
+                (stmt.total_debit, stmt.total_credit, lines)
+            }
+            None => {
+                let lines = self.line_repo
+                    .find_by_account_and_pst_time_less_than_equal(ledger_account.id, ref_time)
+                    .await
+                    .map_err(|_| ServiceError::Db)?;
+                (BigDecimal::from(0), BigDecimal::from(0), lines)
+            }
+        };
+        let (total_debit, total_credit) = lines
+            .iter()
+            .fold((opening_debit, opening_credit), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        Ok(AccountBalance { account: ledger_account, ref_time, total_debit, total_credit, known_at: None })
+    }
+
+    /// Totals of an account at posting time `ref_time` as they were known at record time `known_at`.
+    /// Closed statements may include lines recorded after `known_at`, so all lines are read.
+    pub async fn account_balance_as_at(&self, ledger_account: postings_api::domain::ledger_account::LedgerAccount, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        let lines = self.line_repo
+            .find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(ledger_account.id, ref_time, known_at)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let (total_debit, total_credit) = lines
+            .iter()
+            .filter(|l| was_booked_at(l.record_time, l.discarded_time, known_at))
+            .fold((BigDecimal::from(0), BigDecimal::from(0)), |(d, c), l| (d + l.debit_amount.clone(), c + l.credit_amount.clone()));
+        Ok(AccountBalance { account: ledger_account, ref_time, total_debit, total_credit, known_at: Some(known_at) })
+    }
+
+    /// Builds the empty balance-statement posting that closes a statement, chained to the ledger's latest posting
+    /// and sealed by the ledger's hashing rules like any other posting. Ledgers with closing summaries record the
+    /// hash of `summary` as the posting's `opr_details`. The posting is saved by the caller, together with the
+    /// closed statement.
+    pub async fn closing_posting(&self, ledger_id: Uuid, summary: ClosingSummary) -> Result<Posting, ServiceError> {
+        self.ensure_writable(ledger_id).await?;
+        let ledger_model = self.load_ledger(ledger_id).await?;
+        let ledger_bo = self.load_ledger_bo(ledger_id).await?;
+        // One closing operation per statement, the operation id being unique per ledger
+        let opr_id = hash_serialize(&(summary.stmt_id, "closing")).map_err(|_| ServiceError::NotEnoughInfo)?;
+        let opr_details = if ledger_model.closing_summary {
+            Some(hash_serialize(&summary).map_err(|_| ServiceError::NotEnoughInfo)?)
+        } else {
+            None
+        };
+        let mut closing_posting = Posting {
+            id: Uuid::new_v4(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details,
+            opr_src: None,
+            pst_time: summary.pst_time,
+            pst_type: PostingType::BalStmt,
+            pst_status: postings_api::domain::posting_status::PostingStatus::Posted,
+            ledger: ledger_bo,
+            val_time: Some(Utc::now()),
+            lines: vec![],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        };
+
+        let antecedent = self.posting_repo
+            .find_first_by_ledger_order_by_record_time_desc(ledger_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let rules = self.hashing_rules(ledger_id).await?;
+        seal_posting(&mut closing_posting, antecedent.map(|ant| (ant.id, ant.hash)), rules)?;
+        Ok(closing_posting)
+    }
+
+    /// Maps lines of any accounts, loading each account once.
+    pub async fn lines_to_bo(&self, lines: Vec<postings_db::models::posting_line::PostingLine>) -> Result<Vec<postings_api::domain::posting_line::PostingLine>, ServiceError> {
+        let mut accounts: HashMap<Uuid, postings_api::domain::ledger_account::LedgerAccount> = HashMap::new();
+        let mut result = Vec::with_capacity(lines.len());
+        for line in lines {
+            let account = match accounts.get(&line.account_id) {
+                Some(account) => account.clone(),
+                None => {
+                    let account = self.load_ledger_account_bo(line.account_id).await?;
+                    accounts.insert(line.account_id, account.clone());
+                    account
+                }
+            };
+            result.push(PostingLineMapper::to_bo(line, account));
+        }
+        Ok(result)
+    }
+
+    /// Loads all accounts of a ledger, resolving parents from the same ledger.
+    pub async fn load_ledger_accounts_bo(&self, ledger: &postings_api::domain::ledger::Ledger) -> Result<Vec<postings_api::domain::ledger_account::LedgerAccount>, ServiceError> {
+        let models = self.ledger_account_repo
+            .find_by_ledger_id(ledger.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let by_id: HashMap<Uuid, postings_db::models::ledger_account::LedgerAccount> =
+            models.iter().map(|m| (m.id, m.clone())).collect();
+        Ok(models
+            .into_iter()
+            .map(|m| Self::build_ledger_account_bo(m, &by_id, ledger))
+            .collect())
+    }
+
+    fn build_ledger_account_bo(
+        model: postings_db::models::ledger_account::LedgerAccount,
+        by_id: &HashMap<Uuid, postings_db::models::ledger_account::LedgerAccount>,
+        ledger: &postings_api::domain::ledger::Ledger,
+    ) -> postings_api::domain::ledger_account::LedgerAccount {
+        let parent_bo = model
+            .parent_id
+            .and_then(|parent_id| by_id.get(&parent_id))
+            .map(|parent| Box::new(Self::build_ledger_account_bo(parent.clone(), by_id, ledger)));
+        LedgerAccountMapper::to_bo(model, ledger.clone(), ledger.coa.clone(), parent_bo)
+    }
+}
+
+/// [`SharedService`] whose repositories are all confined to one [`LedgerScope`]. Only
+/// [`SharedService::scoped`] builds it, so a service constructor taking it cannot be handed the
+/// unscoped repositories by mistake.
+#[derive(Clone)]
+pub struct ScopedSharedService {
+    shared: SharedService,
+    scope: LedgerScope,
+}
+
+impl ScopedSharedService {
+    pub fn scope(&self) -> &LedgerScope {
+        &self.scope
+    }
+
+    pub(crate) fn into_shared(self) -> SharedService {
+        self.shared
+    }
+}
+
+impl Deref for ScopedSharedService {
+    type Target = SharedService;
+
+    fn deref(&self) -> &SharedService {
+        &self.shared
+    }
+}
+
+/// Id and hash of the posting a new posting is chained to.
+pub(crate) type ChainLink = (Uuid, Option<[u8; 34]>);
+
+/// Excluded fields and hash function of the ledger's hashing profile.
+pub(crate) type HashingRules = (Vec<HashedField>, HashAlgorithm);
+
+/// Links the posting to its antecedent and computes its hash by the current hashing rules.
+pub(crate) fn seal_posting(posting: &mut Posting, antecedent: Option<ChainLink>, (excluded_fields, algorithm): HashingRules) -> Result<(), ServiceError> {
+    if let Some((antecedent_id, antecedent_hash)) = antecedent {
+        posting.hash_record.antecedent_id = Some(antecedent_id);
+        posting.hash_record.antecedent_hash = antecedent_hash;
+    }
+    posting.hash_record.excluded_fields = excluded_fields;
+    posting.hash_record.version = HashVersion::CURRENT;
+    posting.hash_record.algorithm = algorithm;
+    let hash = hash_utils::hash_posting(posting).map_err(|_| ServiceError::NotEnoughInfo)?; // Simplified error
+    posting.hash_record.hash = Some(hash);
+    Ok(())
 }
@@ -0,0 +1,224 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, TimeZone, Utc};
+use log::{info, warn};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::standing_order::{ExecutionStatus, StandingOrder, StandingOrderExecution, StandingOrderStatus};
+use postings_api::service::earmark_service::EarmarkService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::standing_order_service::StandingOrderService;
+use postings_api::ServiceError;
+use postings_db::repositories::standing_order_repository::StandingOrderRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_serialize;
+use crate::mappers::standing_order::StandingOrderMapper;
+use crate::posting_builder::PostingBuilder;
+use crate::services::shared_service::SharedService;
+
+pub struct StandingOrderServiceImpl {
+    shared: SharedService,
+    order_repo: Arc<dyn StandingOrderRepository + Send + Sync>,
+    posting_service: Arc<dyn PostingService + Send + Sync>,
+    earmark_service: Arc<dyn EarmarkService + Send + Sync>,
+}
+
+impl StandingOrderServiceImpl {
+    pub fn new(
+        shared: SharedService,
+        order_repo: Arc<dyn StandingOrderRepository + Send + Sync>,
+        posting_service: Arc<dyn PostingService + Send + Sync>,
+        earmark_service: Arc<dyn EarmarkService + Send + Sync>,
+    ) -> Self {
+        Self { shared, order_repo, posting_service, earmark_service }
+    }
+
+    async fn to_bo(&self, model: postings_db::models::standing_order::StandingOrder) -> Result<StandingOrder, ServiceError> {
+        let ledger_bo = self.shared.load_ledger_bo(model.ledger_id).await?;
+        let source_bo = self.shared.load_ledger_account_bo(model.source_account_id).await?;
+        let target_bo = self.shared.load_ledger_account_bo(model.target_account_id).await?;
+        Ok(StandingOrderMapper::to_bo(model, ledger_bo, source_bo, target_bo))
+    }
+
+    async fn load_order(&self, order_id: Uuid) -> Result<StandingOrder, ServiceError> {
+        let model = self
+            .order_repo
+            .find_by_id(order_id)
+            .await
+            .map_err(|_| ServiceError::Db)?
+            .ok_or(ServiceError::StandingOrderNotFound)?;
+        self.to_bo(model).await
+    }
+
+    async fn save_order(&self, order: StandingOrder) -> Result<StandingOrder, ServiceError> {
+        self.order_repo
+            .save(StandingOrderMapper::to_model(order.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(order)
+    }
+
+    async fn save_execution(&self, execution: StandingOrderExecution) -> Result<StandingOrderExecution, ServiceError> {
+        self.order_repo
+            .save_execution(StandingOrderMapper::execution_to_model(execution.clone()))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(execution)
+    }
+
+    /// Books one occurrence at the start of its execution date, provided the source account has
+    /// enough available balance.
+    async fn execute(&self, order: &StandingOrder, execution_date: NaiveDate) -> Result<Posting, ServiceError> {
+        let available = self
+            .earmark_service
+            .available_balance(order.source_account.clone(), Utc::now())
+            .await?;
+        if available < order.amount {
+            return Err(ServiceError::InsufficientAvailableBalance);
+        }
+
+        // One operation per occurrence, so retries of the same occurrence share the operation id
+        let opr_id = hash_serialize(&(order.id, execution_date)).map_err(|_| ServiceError::NotEnoughInfo)?;
+        // Retries of an occurrence book the same posting, so they are recognized as such
+        let pst_time = Utc.from_utc_datetime(&execution_date.and_hms_opt(0, 0, 0).unwrap());
+        let posting = PostingBuilder::new(order.ledger.clone(), opr_id, order.opr_type, pst_time)
+            .record_user(hash_serialize(&"standing-order").map_err(|_| ServiceError::NotEnoughInfo)?)
+            .opr_src(hash_serialize(&order.id).map_err(|_| ServiceError::NotEnoughInfo)?)
+            .val_time(pst_time)
+            .debit(order.source_account.clone(), order.amount.clone())
+            .credit(order.target_account.clone(), order.amount.clone())
+            .build();
+        self.posting_service.new_posting(posting).await
+    }
+
+    async fn run_order(&self, mut order: StandingOrder, as_of: NaiveDate) -> Result<Vec<StandingOrderExecution>, ServiceError> {
+        let previous = self
+            .order_repo
+            .find_executions_by_order_id(order.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let calendar = self.shared.business_calendar(order.ledger.id).await?;
+        let mut executions = Vec::new();
+
+        while order.status == StandingOrderStatus::Active && order.next_execution_date <= as_of {
+            let execution_date = order.next_execution_date;
+            let attempt = previous.iter().filter(|e| e.execution_date == execution_date).count() as i32 + 1;
+            let mut execution = StandingOrderExecution {
+                id: Uuid::new_v4(),
+                order_id: order.id,
+                execution_date,
+                attempt,
+                status: ExecutionStatus::Executed,
+                posting_id: None,
+                message: None,
+                executed_time: Utc::now(),
+            };
+
+            match self.execute(&order, execution_date).await {
+                Ok(posting) => {
+                    execution.posting_id = Some(posting.id);
+                    order.execution_count += 1;
+                    order.advance(calendar.as_ref());
+                }
+                Err(e @ ServiceError::InsufficientAvailableBalance) if attempt > order.max_retries => {
+                    warn!("Standing order {} skipped occurrence {} after {} attempts: {e:?}", order.id, execution_date, attempt);
+                    execution.status = ExecutionStatus::Skipped;
+                    execution.message = Some(e.to_string());
+                    order.advance(calendar.as_ref());
+                }
+                Err(e @ ServiceError::InsufficientAvailableBalance) => {
+                    // Retried on the next run
+                    info!("Standing order {} failed on {} (attempt {}): {e:?}", order.id, execution_date, attempt);
+                    execution.status = ExecutionStatus::Failed;
+                    execution.message = Some(e.to_string());
+                    executions.push(self.save_execution(execution).await?);
+                    break;
+                }
+                Err(e) => {
+                    // Only missing funds are retried or skipped; the occurrence stays due
+                    warn!("Standing order {} failed on {} (attempt {}): {e:?}", order.id, execution_date, attempt);
+                    execution.status = ExecutionStatus::Failed;
+                    execution.message = Some(e.to_string());
+                    executions.push(self.save_execution(execution).await?);
+                    break;
+                }
+            }
+            executions.push(self.save_execution(execution).await?);
+        }
+
+        self.save_order(order).await?;
+        Ok(executions)
+    }
+}
+
+#[async_trait]
+impl StandingOrderService for StandingOrderServiceImpl {
+    async fn create_standing_order(&self, mut order: StandingOrder) -> Result<StandingOrder, ServiceError> {
+        if order.amount <= BigDecimal::from(0) || order.max_retries < 0 {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        self.shared.ensure_writable(order.ledger.id).await?;
+        order.ledger = self.shared.load_ledger_bo(order.ledger.id).await?;
+        order.source_account = self.shared.load_ledger_account_bo(order.source_account.id).await?;
+        order.target_account = self.shared.load_ledger_account_bo(order.target_account.id).await?;
+        if order.source_account.ledger.id != order.ledger.id || order.target_account.ledger.id != order.ledger.id {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+        order.id = Uuid::new_v4();
+        order.created = Utc::now();
+        order.status = StandingOrderStatus::Active;
+        let calendar = self.shared.business_calendar(order.ledger.id).await?;
+        order.next_execution_date = order.nth_execution_date(0, calendar.as_ref());
+        order.occurrence = 0;
+        order.execution_count = 0;
+        if order.end_date.is_some_and(|end| end < order.start_date) {
+            order.status = StandingOrderStatus::Completed;
+        }
+        self.save_order(order).await
+    }
+
+    async fn find_standing_order_by_id(&self, order_id: Uuid) -> Result<Option<StandingOrder>, ServiceError> {
+        match self.load_order(order_id).await {
+            Ok(order) => Ok(Some(order)),
+            Err(ServiceError::StandingOrderNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn cancel_standing_order(&self, order_id: Uuid) -> Result<StandingOrder, ServiceError> {
+        let mut order = self.load_order(order_id).await?;
+        self.shared.ensure_writable(order.ledger.id).await?;
+        if order.status != StandingOrderStatus::Active {
+            return Err(ServiceError::StandingOrderNotActive);
+        }
+        order.status = StandingOrderStatus::Cancelled;
+        self.save_order(order).await
+    }
+
+    async fn find_executions(&self, order_id: Uuid) -> Result<Vec<StandingOrderExecution>, ServiceError> {
+        let models = self
+            .order_repo
+            .find_executions_by_order_id(order_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(models.into_iter().map(StandingOrderMapper::execution_to_bo).collect())
+    }
+
+    async fn run_due(&self, as_of: NaiveDate) -> Result<Vec<StandingOrderExecution>, ServiceError> {
+        let due = self
+            .order_repo
+            .find_due(as_of)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut executions = Vec::new();
+        for model in due {
+            // An order that cannot be loaded does not hold up the others
+            let order_id = model.id;
+            match self.to_bo(model).await {
+                Ok(order) => executions.extend(self.run_order(order, as_of).await?),
+                Err(e) => warn!("Standing order {order_id} could not be loaded: {e:?}"),
+            }
+        }
+        Ok(executions)
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use log::info;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::stmt_delivery::{StmtDelivery, StmtDocumentFormat};
+use postings_api::service::document_signer::DocumentSigner;
+use postings_api::service::stmt_delivery_service::StmtDeliveryService;
+use postings_api::ServiceError;
+use postings_db::repositories::stmt_delivery_repository::StmtDeliveryRepository;
+use uuid::Uuid;
+use crate::hash_utils::hash_bytes;
+use crate::mappers::stmt_delivery::StmtDeliveryMapper;
+
+pub struct StmtDeliveryServiceImpl {
+    delivery_repo: Arc<dyn StmtDeliveryRepository + Send + Sync>,
+    signer: Option<Arc<dyn DocumentSigner + Send + Sync>>,
+}
+
+impl StmtDeliveryServiceImpl {
+    pub fn new(delivery_repo: Arc<dyn StmtDeliveryRepository + Send + Sync>) -> Self {
+        Self { delivery_repo, signer: None }
+    }
+
+    /// Enables detached signatures on recorded deliveries.
+    pub fn with_signer(mut self, signer: Arc<dyn DocumentSigner + Send + Sync>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+}
+
+#[async_trait]
+impl StmtDeliveryService for StmtDeliveryServiceImpl {
+    async fn record_delivery(&self, stmt: AccountStmt, format: StmtDocumentFormat, document: &[u8], sign: bool) -> Result<StmtDelivery, ServiceError> {
+        let (signature, key_id) = if sign {
+            let signer = self.signer.as_ref().ok_or(ServiceError::SignerNotConfigured)?;
+            (Some(signer.sign(document).await?), Some(signer.key_id()))
+        } else {
+            (None, None)
+        };
+        let delivery = StmtDelivery {
+            id: Uuid::new_v4(),
+            stmt_id: stmt.financial_stmt.id,
+            format,
+            checksum: hash_bytes(document),
+            signature,
+            key_id,
+            created: Utc::now(),
+        };
+        let saved = self.delivery_repo
+            .save(StmtDeliveryMapper::to_model(delivery))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        info!("Recorded delivery {} of statement {}", saved.id, saved.stmt_id);
+        Ok(StmtDeliveryMapper::to_bo(saved))
+    }
+
+    async fn find_deliveries(&self, stmt_id: Uuid) -> Result<Vec<StmtDelivery>, ServiceError> {
+        let models = self.delivery_repo
+            .find_by_stmt_id(stmt_id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(models.into_iter().map(StmtDeliveryMapper::to_bo).collect())
+    }
+
+    async fn verify_delivery(&self, stmt_id: Uuid, document: &[u8]) -> Result<Option<StmtDelivery>, ServiceError> {
+        let model = self.delivery_repo
+            .find_by_stmt_id_and_checksum(stmt_id, &hash_bytes(document))
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        Ok(model.map(StmtDeliveryMapper::to_bo))
+    }
+}
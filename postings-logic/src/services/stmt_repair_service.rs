@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{error, warn};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::stmt_repair::{StmtDrift, StmtFigures, StmtRepair};
+use postings_api::service::stmt_repair_service::StmtRepairService;
+use postings_api::ServiceError;
+use postings_db::models::account_stmt::AccountStmt as AccountStmtModel;
+use postings_db::repositories::stmt_repair_repository::StmtRepairRepository;
+use uuid::Uuid;
+use crate::mappers::stmt_repair::StmtRepairMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct StmtRepairServiceImpl {
+    shared: SharedService,
+    repair_repo: Arc<dyn StmtRepairRepository + Send + Sync>,
+}
+
+impl StmtRepairServiceImpl {
+    pub fn new(shared: SharedService, repair_repo: Arc<dyn StmtRepairRepository + Send + Sync>) -> Self {
+        Self { shared, repair_repo }
+    }
+
+    /// Recomputes the closed statements posted in `from..=to` in posting time order, each one
+    /// opening with the recomputed totals of the one before. Returns the drifted statements.
+    async fn find_drifts(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<(AccountStmtModel, StmtDrift)>, ServiceError> {
+        let stmts = self.shared
+            .stmt_repo
+            .find_closed_by_account_and_pst_time_between(account_id, from, to)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let Some(first) = stmts.first() else {
+            return Ok(vec![]);
+        };
+        let previous = self.shared
+            .stmt_repo
+            .find_last_closed_by_account_and_pst_time_less_than(account_id, first.pst_time)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let mut previous = previous.map(|p| (p.pst_time, StmtRepairMapper::figures(&p)));
+
+        let mut drifts = Vec::new();
+        for stmt in stmts {
+            let (mut expected, lines) = match &previous {
+                Some((pst_time, figures)) => {
+                    let lines = self.shared
+                        .line_repo
+                        .find_by_account_and_pst_time_between(account_id, *pst_time, stmt.pst_time)
+                        .await
+                        .map_err(|_| ServiceError::Db)?;
+                    (figures.carry_over(), lines)
+                }
+                None => {
+                    let lines = self.shared
+                        .line_repo
+                        .find_by_account_and_pst_time_less_than_equal(account_id, stmt.pst_time)
+                        .await
+                        .map_err(|_| ServiceError::Db)?;
+                    (StmtFigures::empty(), lines)
+                }
+            };
+            // Lines are returned newest first; currency totals do not depend on the order
+            for line in lines.iter() {
+                expected.add_line(&line.debit_amount, &line.credit_amount, line.currency.as_deref());
+            }
+
+            let recorded = StmtRepairMapper::figures(&stmt);
+            previous = Some((stmt.pst_time, expected.clone()));
+            if recorded != expected {
+                let drift = StmtDrift {
+                    stmt_id: stmt.id,
+                    account_id,
+                    pst_time: stmt.pst_time,
+                    recorded,
+                    expected,
+                };
+                drifts.push((stmt, drift));
+            }
+        }
+        Ok(drifts)
+    }
+}
+
+#[async_trait]
+impl StmtRepairService for StmtRepairServiceImpl {
+    async fn detect_drift(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<StmtDrift>, ServiceError> {
+        let drifts = self.find_drifts(ledger_account.id, from, to).await?;
+        for (_, drift) in drifts.iter() {
+            warn!("Statement {} of account {} drifted: {:?}", drift.stmt_id, drift.account_id, drift.drifted_fields());
+        }
+        Ok(drifts.into_iter().map(|(_, drift)| drift).collect())
+    }
+
+    async fn repair(&self, ledger_account: LedgerAccount, from: DateTime<Utc>, to: DateTime<Utc>, context: PrivilegedContext) -> Result<Vec<StmtRepair>, ServiceError> {
+        let drifts = self.find_drifts(ledger_account.id, from, to).await?;
+        let mut repairs = Vec::with_capacity(drifts.len());
+        for (stmt, drift) in drifts {
+            let repaired_stmt = StmtRepairMapper::with_figures(&stmt, &drift.expected);
+            let repair = StmtRepair {
+                id: Uuid::new_v4(),
+                drift,
+                principal: context.principal().to_string(),
+                reason: context.reason().to_string(),
+                repaired_time: Utc::now(),
+            };
+            let model = StmtRepairMapper::to_model(repair.clone()).map_err(|_| ServiceError::NotEnoughInfo)?;
+            self.repair_repo
+                .save(model, &repaired_stmt)
+                .await
+                .map_err(|e| {
+                    error!("Error repairing statement {}: {e:?}", stmt.id);
+                    ServiceError::Db
+                })?;
+            warn!(
+                "Statement {} of account {} repaired by {} ({}): {:?}",
+                stmt.id, ledger_account.id, context.principal(), context.reason(), repair.drift.drifted_fields()
+            );
+            repairs.push(repair);
+        }
+        Ok(repairs)
+    }
+
+    async fn find_repairs(&self, ledger_account: LedgerAccount) -> Result<Vec<StmtRepair>, ServiceError> {
+        let models = self.repair_repo
+            .find_by_account_id(ledger_account.id)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        models
+            .into_iter()
+            .map(|m| StmtRepairMapper::to_bo(m).map_err(|_| ServiceError::Db))
+            .collect()
+    }
+}
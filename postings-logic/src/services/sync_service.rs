@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use postings_api::domain::change_set::ChangeSet;
+use postings_api::service::sync_service::SyncService;
+use postings_api::ServiceError;
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use crate::mappers::ledger_event::LedgerEventMapper;
+use crate::services::ledger_event_service::read_merged;
+
+const READ_BATCH_SIZE: i64 = 500;
+
+pub struct SyncServiceImpl {
+    event_repo: Arc<dyn LedgerEventRepository + Send + Sync>,
+    settle_delay: Duration,
+}
+
+impl SyncServiceImpl {
+    pub fn new(event_repo: Arc<dyn LedgerEventRepository + Send + Sync>) -> Self {
+        Self { event_repo, settle_delay: Duration::seconds(5) }
+    }
+
+    /// Age an event must reach before it is handed to replicas. Sequence numbers are taken before
+    /// commit, so a slower transaction can still add a lower number than the latest one.
+    pub fn with_settle_delay(mut self, settle_delay: Duration) -> Self {
+        self.settle_delay = settle_delay;
+        self
+    }
+}
+
+#[async_trait]
+impl SyncService for SyncServiceImpl {
+    async fn changes_since(&self, after_seq: i64, limit: i64, entity_types: &[String]) -> Result<ChangeSet, ServiceError> {
+        let settled_before = Utc::now() - self.settle_delay;
+        let mut changes = Vec::new();
+        let mut cursor = after_seq;
+        let mut has_more = false;
+        'read: while limit > 0 {
+            let batch = read_merged(self.event_repo.as_ref(), cursor, READ_BATCH_SIZE).await?;
+            if batch.is_empty() {
+                break;
+            }
+            for event in batch {
+                // Stop at the first unsettled event: the cursor must not move past a gap
+                if event.created >= settled_before {
+                    break 'read;
+                }
+                if changes.len() as i64 == limit {
+                    has_more = true;
+                    break 'read;
+                }
+                cursor = event.seq;
+                if entity_types.is_empty() || entity_types.contains(&event.entity_type) {
+                    changes.push(LedgerEventMapper::to_bo(event));
+                }
+            }
+        }
+        Ok(ChangeSet { after_seq, next_seq: cursor, changes, has_more })
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::trial_balance::{TrialBalance, TrialBalanceDrillDown};
+use postings_api::service::trial_balance_service::TrialBalanceService;
+use postings_api::ServiceError;
+use uuid::Uuid;
+use crate::mappers::page::PageMapper;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::services::shared_service::SharedService;
+
+pub struct TrialBalanceServiceImpl {
+    shared: SharedService,
+}
+
+impl TrialBalanceServiceImpl {
+    pub fn new(shared: SharedService) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl TrialBalanceService for TrialBalanceServiceImpl {
+    async fn trial_balance(&self, ledger: Ledger, ref_time: DateTime<Utc>) -> Result<TrialBalance, ServiceError> {
+        let accounts = self.shared.load_ledger_accounts_bo(&ledger).await?;
+        let mut balances = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            balances.push(self.shared.account_balance(account, ref_time).await?);
+        }
+        let report = TrialBalance::from_balances(ledger, ref_time, balances);
+        if !report.balanced {
+            warn!("Trial balance of ledger {} at {ref_time} is off: debit {} credit {}", report.ledger.id, report.total_debit, report.total_credit);
+        }
+        Ok(report)
+    }
+    async fn drill_down(&self, trial_balance: &TrialBalance, account_id: Uuid, from: DateTime<Utc>, page: usize, size: usize) -> Result<TrialBalanceDrillDown, ServiceError> {
+        let line = trial_balance.line(account_id).ok_or(ServiceError::LedgerAccountNotFound)?.clone();
+        let to = trial_balance.ref_time;
+        if from > to {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        let request = PageMapper::to_request(page, size)?;
+        let opening = self.shared.account_balance(line.account.clone(), from).await?;
+        let lines = self.shared.line_repo
+            .find_by_account_and_pst_time_between_paged(account_id, from, to, request)
+            .await
+            .map_err(|_| ServiceError::Db)?;
+        let lines = PageMapper::to_bo(lines, |l| PostingLineMapper::to_bo(l, line.account.clone()));
+        Ok(TrialBalanceDrillDown {
+            line,
+            from_time: from,
+            to_time: to,
+            opening_debit: opening.total_debit,
+            opening_credit: opening.total_credit,
+            lines: lines.content,
+            page,
+            size,
+            total_elements: lines.total_elements,
+        })
+    }
+}
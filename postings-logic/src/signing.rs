@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use postings_api::service::document_signer::DocumentSigner;
+use postings_api::ServiceError;
+use postings_db::models::account_stmt::AccountStmt;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Signs with an ed25519 key held in process memory. Keys kept in an HSM or KMS are used through
+/// their own [`DocumentSigner`].
+pub struct Ed25519Signer {
+    key_id: String,
+    key: SigningKey,
+}
+
+impl Ed25519Signer {
+    pub fn new(key_id: impl Into<String>, key: SigningKey) -> Self {
+        Self { key_id: key_id.into(), key }
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.key.verifying_key()
+    }
+}
+
+#[async_trait]
+impl DocumentSigner for Ed25519Signer {
+    fn key_id(&self) -> String {
+        self.key_id.clone()
+    }
+
+    async fn sign(&self, content: &[u8]) -> Result<Vec<u8>, ServiceError> {
+        Ok(self.key.sign(content).to_bytes().to_vec())
+    }
+}
+
+/// Whether `signature` is an ed25519 signature of `content` by `key`.
+pub fn verify_ed25519(key: &VerifyingKey, content: &[u8], signature: &[u8]) -> bool {
+    Signature::from_slice(signature).is_ok_and(|signature| key.verify(content, &signature).is_ok())
+}
+
+/// The part of a closed statement its signature covers.
+#[derive(Serialize)]
+struct SignedStmt<'a> {
+    id: Uuid,
+    account_id: Uuid,
+    pst_time_micros: i64,
+    stmt_seq_nbr: i32,
+    posting_id: Option<Uuid>,
+    youngest_pst_id: Option<Uuid>,
+    latest_pst_id: Option<Uuid>,
+    opening_debit: String,
+    opening_credit: String,
+    total_debit: String,
+    total_credit: String,
+    line_count: i64,
+    currency_totals: Option<&'a str>,
+}
+
+/// Compact JSON of the statement's identity, closing posting and figures, the content a statement
+/// signature is made over. Amounts are normalized and the posting time is cut to microseconds, so
+/// the form does not change when the statement is read back from the database.
+pub fn canonical_stmt(stmt: &AccountStmt) -> Result<Vec<u8>, serde_json::Error> {
+    serde_json::to_vec(&SignedStmt {
+        id: stmt.id,
+        account_id: stmt.account_id,
+        pst_time_micros: stmt.pst_time.timestamp_micros(),
+        stmt_seq_nbr: stmt.stmt_seq_nbr,
+        posting_id: stmt.posting_id,
+        youngest_pst_id: stmt.youngest_pst_id,
+        latest_pst_id: stmt.latest_pst_id,
+        opening_debit: stmt.opening_debit.normalized().to_string(),
+        opening_credit: stmt.opening_credit.normalized().to_string(),
+        total_debit: stmt.total_debit.normalized().to_string(),
+        total_credit: stmt.total_credit.normalized().to_string(),
+        line_count: stmt.line_count,
+        currency_totals: stmt.currency_totals.as_deref(),
+    })
+}
@@ -0,0 +1,75 @@
+//! OpenTelemetry integration, enabled with the `otel` feature.
+//!
+//! Transport layers extract the remote trace context of a request with [`extract_context`] and run
+//! the ledger operation through [`in_operation_span`]. The context stays attached to the operation's
+//! future, so the traced repository decorators open their spans as children of the caller's trace.
+
+pub mod repositories;
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use opentelemetry::global;
+use opentelemetry::trace::{FutureExt, SpanKind, Status, TraceContextExt, TraceError, Tracer};
+use opentelemetry::{Context, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Config, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+
+/// Name of the tracer the ledger's spans are recorded with.
+pub const TRACER_NAME: &str = "ledger-posting";
+
+/// Installs a global tracer provider exporting spans over OTLP/gRPC to `endpoint`, and the W3C
+/// trace context propagator. Keep the returned provider and shut it down on exit to flush spans.
+pub fn init_otlp(endpoint: &str, service_name: &str) -> Result<TracerProvider, TraceError> {
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
+        .with_trace_config(
+            Config::default().with_resource(Resource::new(vec![KeyValue::new("service.name", service_name.to_string())])),
+        )
+        .install_batch(runtime::Tokio)?;
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    global::set_tracer_provider(provider.clone());
+    Ok(provider)
+}
+
+/// Remote trace context carried by request headers or gRPC metadata (`traceparent`, `tracestate`).
+/// Returns an empty context when the carrier holds none.
+pub fn extract_context(carrier: &HashMap<String, String>) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(carrier))
+}
+
+/// Headers propagating the current trace context to a downstream service.
+pub fn inject_context(cx: &Context) -> HashMap<String, String> {
+    let mut carrier = HashMap::new();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(cx, &mut carrier));
+    carrier
+}
+
+/// Runs a ledger operation in a server span that continues the trace of `parent`.
+pub async fn in_operation_span<T, E, F>(name: &'static str, parent: &Context, operation: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+    E: std::fmt::Debug,
+{
+    traced(name, SpanKind::Server, parent, operation).await
+}
+
+/// Runs `fut` in a child span of `parent`, marking the span as failed when `fut` returns an error.
+pub(crate) async fn traced<T, E, F>(name: &'static str, kind: SpanKind, parent: &Context, fut: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+    E: std::fmt::Debug,
+{
+    let tracer = global::tracer(TRACER_NAME);
+    let span = tracer.span_builder(name).with_kind(kind).start_with_context(&tracer, parent);
+    let cx = parent.with_span(span);
+    let result = fut.with_context(cx.clone()).await;
+    if let Err(e) = &result {
+        cx.span().set_status(Status::error(format!("{e:?}")));
+    }
+    cx.span().end();
+    result
+}
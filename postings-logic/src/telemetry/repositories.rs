@@ -0,0 +1,236 @@
+//! Repository decorators recording a client span per database call, as a child of the context
+//! attached to the calling operation.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use opentelemetry::trace::SpanKind;
+use opentelemetry::Context;
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_filter::PostingFilter;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use uuid::Uuid;
+
+use crate::telemetry::traced;
+
+async fn db_span<T>(name: &'static str, fut: impl std::future::Future<Output = Result<T, DbError>>) -> Result<T, DbError> {
+    traced(name, SpanKind::Client, &Context::current(), fut).await
+}
+
+pub struct TracedPostingRepository {
+    inner: Arc<dyn PostingRepository + Send + Sync>,
+}
+
+impl TracedPostingRepository {
+    pub fn new(inner: Arc<dyn PostingRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl PostingRepository for TracedPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        db_span("posting.find_by_opr_id_and_discarding_id_is_null", self.inner.find_by_opr_id_and_discarding_id_is_null(opr_id)).await
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        db_span("posting.find_by_opr_id", self.inner.find_by_opr_id(opr_id)).await
+    }
+
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        db_span("posting.find_first_by_ledger_order_by_record_time_desc", self.inner.find_first_by_ledger_order_by_record_time_desc(ledger_id)).await
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        db_span("posting.save", self.inner.save(posting)).await
+    }
+
+    async fn save_batch(&self, postings: &[(Posting, Vec<PostingLine>)]) -> Result<(), DbError> {
+        db_span("posting.save_batch", self.inner.save_batch(postings)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        db_span("posting.find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        db_span("posting.find_by_ledger_id", self.inner.find_by_ledger_id(ledger_id)).await
+    }
+
+    async fn save_reversal(&self, reversal: &Posting, lines: &[PostingLine], reversed_id: Uuid) -> Result<bool, DbError> {
+        db_span("posting.save_reversal", self.inner.save_reversal(reversal, lines, reversed_id)).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        db_span("posting.find_by_ledger_id_paged", self.inner.find_by_ledger_id_paged(ledger_id, page)).await
+    }
+
+    async fn find_by_filter_paged(&self, filter: &PostingFilter, page: PageRequest) -> Result<Page<Posting>, DbError> {
+        db_span("posting.find_by_filter_paged", self.inner.find_by_filter_paged(filter, page)).await
+    }
+}
+
+pub struct TracedPostingLineRepository {
+    inner: Arc<dyn PostingLineRepository + Send + Sync>,
+}
+
+impl TracedPostingLineRepository {
+    pub fn new(inner: Arc<dyn PostingLineRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl PostingLineRepository for TracedPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        db_span("posting_line.save", self.inner.save(posting_line)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        db_span("posting_line.find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        db_span("posting_line.find_by_account_and_pst_time_between", self.inner.find_by_account_and_pst_time_between(account_id, from, to)).await
+    }
+
+    async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError> {
+        db_span("posting_line.find_by_account_and_pst_time_between_paged", self.inner.find_by_account_and_pst_time_between_paged(account_id, from, to, page)).await
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        db_span("posting_line.find_by_id_and_account_id", self.inner.find_by_id_and_account_id(id, account_id)).await
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        db_span("posting_line.find_by_base_line_and_pst_time_less_than_equal", self.inner.find_by_base_line_and_pst_time_less_than_equal(base_line, ref_time)).await
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        db_span("posting_line.find_by_opr_id", self.inner.find_by_opr_id(opr_id)).await
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        db_span("posting_line.find_by_account_and_pst_time_less_than_equal", self.inner.find_by_account_and_pst_time_less_than_equal(account_id, ref_time)).await
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        db_span("posting_line.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal", self.inner.find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account_id, ref_time, known_at)).await
+    }
+
+    async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError> {
+        db_span("posting_line.find_stats_by_account_id", self.inner.find_stats_by_account_id(account_id)).await
+    }
+
+    async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError> {
+        db_span("posting_line.sum_by_account_ids_and_pst_time_less_than_equal", self.inner.sum_by_account_ids_and_pst_time_less_than_equal(account_ids, ref_time)).await
+    }
+
+    // Streams are consumed by the caller over time; they are not covered by a span
+    fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.inner.stream_by_account_and_pst_time_between(account_id, from, to, order)
+    }
+
+    fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'_, Result<PostingLine, DbError>> {
+        self.inner.stream_by_account_and_pst_time_less_than_equal(account_id, ref_time, order)
+    }
+}
+
+pub struct TracedLedgerAccountRepository {
+    inner: Arc<dyn LedgerAccountRepository + Send + Sync>,
+}
+
+impl TracedLedgerAccountRepository {
+    pub fn new(inner: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for TracedLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        db_span("ledger_account.find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
+        db_span("ledger_account.save", self.inner.save(ledger_account)).await
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        db_span("ledger_account.find_by_ledger_id", self.inner.find_by_ledger_id(ledger_id)).await
+    }
+
+    async fn find_by_ledger_id_paged(&self, ledger_id: Uuid, page: PageRequest) -> Result<Page<LedgerAccount>, DbError> {
+        db_span("ledger_account.find_by_ledger_id_paged", self.inner.find_by_ledger_id_paged(ledger_id, page)).await
+    }
+
+    async fn find_descendants(&self, id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        db_span("ledger_account.find_descendants", self.inner.find_descendants(id)).await
+    }
+}
+
+pub struct TracedAccountStmtRepository {
+    inner: Arc<dyn AccountStmtRepository + Send + Sync>,
+}
+
+impl TracedAccountStmtRepository {
+    pub fn new(inner: Arc<dyn AccountStmtRepository + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl AccountStmtRepository for TracedAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        db_span("account_stmt.find_first_by_account_and_status_and_pst_time_less_than_ordered", self.inner.find_first_by_account_and_status_and_pst_time_less_than_ordered(account_id, status, ref_time)).await
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        db_span("account_stmt.find_first_by_account_and_status_and_pst_time_greater_than_equal", self.inner.find_first_by_account_and_status_and_pst_time_greater_than_equal(account_id, status, ref_time)).await
+    }
+
+    async fn find_last_closed_by_account_and_pst_time_less_than(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        db_span("account_stmt.find_last_closed_by_account_and_pst_time_less_than", self.inner.find_last_closed_by_account_and_pst_time_less_than(account_id, ref_time)).await
+    }
+
+    async fn delete_expired_simulated_by_ledger_id(&self, ledger_id: Uuid, as_of: DateTime<Utc>) -> Result<u64, DbError> {
+        db_span("account_stmt.delete_expired_simulated_by_ledger_id", self.inner.delete_expired_simulated_by_ledger_id(ledger_id, as_of)).await
+    }
+
+    async fn find_closed_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountStmt>, DbError> {
+        db_span("account_stmt.find_closed_by_account_and_pst_time_between", self.inner.find_closed_by_account_and_pst_time_between(account_id, from, to)).await
+    }
+
+    async fn find_closed_by_ledger_id_after(&self, ledger_id: Uuid, after: Option<Uuid>, limit: i64) -> Result<Vec<AccountStmt>, DbError> {
+        db_span("account_stmt.find_closed_by_ledger_id_after", self.inner.find_closed_by_ledger_id_after(ledger_id, after, limit)).await
+    }
+
+    async fn count_closed_by_ledger_id(&self, ledger_id: Uuid) -> Result<i64, DbError> {
+        db_span("account_stmt.count_closed_by_ledger_id", self.inner.count_closed_by_ledger_id(ledger_id)).await
+    }
+
+    async fn update_opening_totals(&self, id: Uuid, opening_debit: BigDecimal, opening_credit: BigDecimal, line_count: i64) -> Result<(), DbError> {
+        db_span("account_stmt.update_opening_totals", self.inner.update_opening_totals(id, opening_debit, opening_credit, line_count)).await
+    }
+
+    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
+        db_span("account_stmt.save", self.inner.save(stmt)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        db_span("account_stmt.find_by_id", self.inner.find_by_id(id)).await
+    }
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::domain::account_category::AccountCategory as AccountCategoryBO;
+use postings_api::domain::account_group::AccountGroup;
+use postings_api::service::account_group_service::AccountGroupService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::account_group_repository::InMemoryAccountGroupRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_group_service::AccountGroupServiceImpl;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::balance_service::BalanceServiceImpl;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn save_ledger(store: &Arc<InMemoryStore>) -> Ledger {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    ledger
+}
+
+async fn save_account(store: &Arc<InMemoryStore>, ledger: &Ledger, category: AccountCategory, debit: i64, credit: i64) -> Uuid {
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: ledger.coa_id,
+        balance_side: BalanceSide::Dr,
+        category,
+        currency: None,
+    }).await.unwrap();
+    let pst_time = Utc::now() - Duration::days(1);
+    InMemoryPostingLineRepository::new(store.clone()).save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id: id,
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        pst_time,
+        record_time: pst_time,
+        ..Default::default()
+    }).await.unwrap();
+    id
+}
+
+#[tokio::test]
+async fn test_group_balance_covers_category_and_listed_accounts() {
+    let store = Arc::new(InMemoryStore::new());
+    let ledger = save_ledger(&store).await;
+    let cash = save_account(&store, &ledger, AccountCategory::AS, 100, 0).await;
+    let securities = save_account(&store, &ledger, AccountCategory::AS, 50, 0).await;
+    let loan = save_account(&store, &ledger, AccountCategory::LI, 0, 30).await;
+    save_account(&store, &ledger, AccountCategory::LI, 0, 120).await;
+    let other_ledger = save_ledger(&store).await;
+    let foreign = save_account(&store, &other_ledger, AccountCategory::AS, 1, 0).await;
+
+    let shared = create_shared(store.clone());
+    let service = AccountGroupServiceImpl::new(
+        shared.clone(),
+        Arc::new(InMemoryAccountGroupRepository::new(store.clone())),
+        Arc::new(BalanceServiceImpl::new(shared.clone())),
+        Arc::new(AccountStmtServiceImpl::new(shared.clone())),
+    );
+    let ledger_bo = shared.load_ledger_bo(ledger.id).await.unwrap();
+    let group = AccountGroup {
+        id: Uuid::new_v4(),
+        ledger: ledger_bo.clone(),
+        name: "portfolio".to_string(),
+        category: Some(AccountCategoryBO::AS),
+        member_ids: vec![loan],
+        created: Utc::now(),
+    };
+    let group = service.create_group(group.clone()).await.unwrap();
+
+    let mut expected = vec![cash, securities, loan];
+    expected.sort();
+    let accounts: Vec<Uuid> = service.accounts(group.id).await.unwrap().iter().map(|a| a.id).collect();
+    assert_eq!(accounts, expected);
+    let balance = service.balance(group.id, Utc::now()).await.unwrap();
+    assert_eq!(balance.debit_balance(), BigDecimal::from(120));
+    let trial_balance = service.trial_balance(group.id, Utc::now()).await.unwrap();
+    assert_eq!(trial_balance.groups.len(), 2);
+    assert_eq!(service.read_stmts(group.id, Utc::now()).await.unwrap().len(), 3);
+
+    let group = service.remove_member(group.id, loan).await.unwrap();
+    assert!(group.member_ids.is_empty());
+    assert!(matches!(service.add_member(group.id, foreign).await, Err(ServiceError::LedgerAccountNotFound)));
+    let duplicate = AccountGroup { id: Uuid::new_v4(), member_ids: vec![], ..group };
+    assert!(matches!(service.create_group(duplicate).await, Err(ServiceError::AccountGroupNameTaken)));
+    assert!(service.find_group_by_name(&ledger_bo, "portfolio").await.unwrap().is_some());
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::posting::Posting;
+use postings_api::service::account_overview_service::AccountOverviewService;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::posting_service::PostingService;
+use postings_db_inmemory::repositories::read_snapshot_repository::InMemoryReadSnapshotRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_overview_service::AccountOverviewServiceImpl;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+fn posting(debit: &LedgerAccountBO, credit: &LedgerAccountBO, opr: u8, pst_time: DateTime<Utc>, amount: i32) -> Posting {
+    PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], pst_time)
+        .debit(debit.clone(), BigDecimal::from(amount))
+        .credit(credit.clone(), BigDecimal::from(amount))
+        .build()
+}
+
+#[tokio::test]
+async fn test_overview_continues_from_last_closed_stmt() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let postings = PostingServiceImpl::new(shared.clone());
+    let stmts = AccountStmtServiceImpl::new(shared.clone());
+    let overviews = AccountOverviewServiceImpl::new(shared.clone())
+        .with_read_snapshot(Arc::new(InMemoryReadSnapshotRepository::new(store)));
+    let now = Utc::now();
+    let closed_at = now - Duration::days(10);
+    let activity_from = now - Duration::days(5);
+
+    postings.new_posting(posting(&debit, &credit, 1, now - Duration::days(20), 100)).await.unwrap();
+    let stmt = stmts.create_stmt(debit.clone(), closed_at).await.unwrap();
+    stmts.close_stmt(stmt).await.unwrap();
+    postings.new_posting(posting(&debit, &credit, 2, now - Duration::days(7), 20)).await.unwrap();
+    postings.new_posting(posting(&debit, &credit, 3, now - Duration::days(3), 3)).await.unwrap();
+    postings.new_posting(posting(&debit, &credit, 4, now - Duration::days(1), 1)).await.unwrap();
+
+    let overview = overviews.account_overview(debit.clone(), activity_from, now).await.unwrap();
+    assert!(overview.consistent);
+    assert_eq!(overview.last_closed_stmt.unwrap().financial_stmt.pst_time, closed_at);
+    assert_eq!(overview.balance.total_debit, BigDecimal::from(124));
+    assert_eq!(overview.balance.total_credit, BigDecimal::from(0));
+    // Activity is cut at its own start, latest first
+    let amounts: Vec<_> = overview.activity.iter().map(|l| l.debit_amount.clone()).collect();
+    assert_eq!(amounts, vec![BigDecimal::from(1), BigDecimal::from(3)]);
+
+    // An activity window reaching before the statement still reads its lines
+    let overview = overviews.account_overview(debit, now - Duration::days(30), now).await.unwrap();
+    assert_eq!(overview.activity.len(), 4);
+    assert_eq!(overview.balance.total_debit, BigDecimal::from(124));
+}
+
+#[tokio::test]
+async fn test_overview_without_snapshot_is_not_consistent() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let postings = PostingServiceImpl::new(shared.clone());
+    let now = Utc::now();
+    postings.new_posting(posting(&debit, &credit, 1, now - Duration::days(2), 50)).await.unwrap();
+    postings.new_posting(posting(&debit, &credit, 2, now + Duration::days(2), 7)).await.unwrap();
+
+    let overview = AccountOverviewServiceImpl::new(shared)
+        .account_overview(credit, now - Duration::days(1), now)
+        .await
+        .unwrap();
+    assert!(!overview.consistent);
+    assert!(overview.last_closed_stmt.is_none());
+    assert!(overview.activity.is_empty());
+    assert_eq!(overview.balance.total_credit, BigDecimal::from(50));
+}
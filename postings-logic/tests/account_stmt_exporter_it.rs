@@ -0,0 +1,107 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, TimeZone, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::stmt_status::StmtStatus;
+use postings_api::ServiceError;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::export::account_stmt_exporter::{AccountStmtExporter, CsvFormat, JsonLinesFormat};
+use uuid::Uuid;
+use common::create_shared;
+
+fn create_closed_stmt(balance_side: BalanceSide, stmt_status: StmtStatus) -> AccountStmt {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    let account = LedgerAccount {
+        id: Uuid::new_v4(),
+        ledger: Ledger { id: Uuid::new_v4(), coa: coa.clone() },
+        parent: None,
+        coa,
+        balance_side,
+        category: AccountCategory::LI,
+        currency: None,
+    };
+    AccountStmt {
+        financial_stmt: FinancialStmt {
+            id: Uuid::new_v4(),
+            posting: None,
+            pst_time: Utc.with_ymd_and_hms(2025, 8, 31, 23, 0, 0).unwrap(),
+            stmt_status,
+            latest_pst: None,
+            stmt_seq_nbr: 1,
+        },
+        account,
+        youngest_pst: None,
+        total_debit: BigDecimal::from(30),
+        total_credit: BigDecimal::from(150),
+        opening_debit: BigDecimal::from(0),
+        opening_credit: BigDecimal::from(100),
+        line_count: 2,
+        currency_totals: vec![],
+        annotations: vec![],
+        signature: None,
+    }
+}
+
+async fn save_line(line_repo: &InMemoryPostingLineRepository, stmt: &AccountStmt, days_before: i64, debit: i64, credit: i64, info: &str) {
+    let pst_time = stmt.financial_stmt.pst_time - Duration::days(days_before);
+    line_repo.save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id: stmt.account.id,
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        pst_time,
+        record_time: pst_time,
+        additional_information: Some(info.to_string()),
+        ..Default::default()
+    }).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_csv_carries_running_balance_on_the_balance_side() {
+    let store = Arc::new(InMemoryStore::new());
+    let line_repo = InMemoryPostingLineRepository::new(store.clone());
+    let stmt = create_closed_stmt(BalanceSide::Cr, StmtStatus::CLOSED);
+    save_line(&line_repo, &stmt, 2, 0, 50, "salary, august").await;
+    save_line(&line_repo, &stmt, 1, 30, 0, "rent").await;
+    save_line(&line_repo, &stmt, -1, 5, 0, "after the statement").await;
+
+    let mut out = Vec::new();
+    let written = AccountStmtExporter::new(create_shared(store)).export(&stmt, &CsvFormat, &mut out).await.unwrap();
+
+    assert_eq!(written, 2);
+    let csv = String::from_utf8(out).unwrap();
+    let rows: Vec<&str> = csv.lines().collect();
+    assert!(rows[0].starts_with("line_id,"));
+    assert!(rows[1].contains(",\"salary, august\",150"));
+    assert!(rows[2].ends_with(",rent,120"));
+}
+
+#[tokio::test]
+async fn test_json_lines_and_open_statements() {
+    let store = Arc::new(InMemoryStore::new());
+    let line_repo = InMemoryPostingLineRepository::new(store.clone());
+    let stmt = create_closed_stmt(BalanceSide::Dr, StmtStatus::CLOSED);
+    save_line(&line_repo, &stmt, 1, 30, 0, "rent").await;
+    let exporter = AccountStmtExporter::new(create_shared(store));
+
+    let mut out = Vec::new();
+    exporter.export(&stmt, &JsonLinesFormat, &mut out).await.unwrap();
+    let row: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(row["running_balance"], serde_json::json!("-70"));
+    assert_eq!(row["additional_information"], serde_json::json!("rent"));
+
+    let simulated = create_closed_stmt(BalanceSide::Dr, StmtStatus::SIMULATED);
+    let result = exporter.export(&simulated, &JsonLinesFormat, &mut Vec::new()).await;
+    assert!(matches!(result, Err(ServiceError::StatementNotClosed)));
+}
@@ -75,6 +75,7 @@ mod postgres_tests {
             coa,
             balance_side: BalanceSide::Dr,
             category: AccountCategory::AS,
+            currency: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5)")
             .bind(ledger_account.id)
@@ -127,6 +128,9 @@ mod postgres_tests {
             pst_status: postings_db::models::posting_status::PostingStatus::Posted,
             hash: Some([0; 34]),
             discarded_time: None,
+            currency: None,
+            fx_base_currency: None,
+            fx_rate: None,
         };
         let line2 = PostingLineModel {
             id: Uuid::new_v4(),
@@ -145,6 +149,9 @@ mod postgres_tests {
             pst_status: postings_db::models::posting_status::PostingStatus::Posted,
             hash: Some([0; 34]),
             discarded_time: None,
+            currency: None,
+            fx_base_currency: None,
+            fx_rate: None,
         };
         sqlx::query("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, record_time, opr_id, pst_time, pst_type, pst_status, hash) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)")
             .bind(line1.id)
@@ -0,0 +1,83 @@
+mod common;
+
+#![cfg(feature = "pdf")]
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, TimeZone, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::stmt_status::StmtStatus;
+use postings_api::ServiceError;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::export::account_stmt_pdf::AccountStmtPdfRenderer;
+use uuid::Uuid;
+use common::create_shared;
+
+fn create_stmt(stmt_status: StmtStatus) -> AccountStmt {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    let account = LedgerAccount {
+        id: Uuid::new_v4(),
+        ledger: Ledger { id: Uuid::new_v4(), coa: coa.clone() },
+        parent: None,
+        coa,
+        balance_side: BalanceSide::Cr,
+        category: AccountCategory::LI,
+        currency: Some("EUR".to_string()),
+    };
+    AccountStmt {
+        financial_stmt: FinancialStmt {
+            id: Uuid::new_v4(),
+            posting: None,
+            pst_time: Utc.with_ymd_and_hms(2025, 8, 31, 23, 0, 0).unwrap(),
+            stmt_status,
+            latest_pst: None,
+            stmt_seq_nbr: 1,
+        },
+        account,
+        youngest_pst: None,
+        total_debit: BigDecimal::from(300),
+        total_credit: BigDecimal::from(1500),
+        opening_debit: BigDecimal::from(0),
+        opening_credit: BigDecimal::from(100),
+        line_count: 120,
+        currency_totals: vec![],
+        annotations: vec![],
+        signature: None,
+    }
+}
+
+#[tokio::test]
+async fn test_closed_statements_render_as_pdf() {
+    let store = Arc::new(InMemoryStore::new());
+    let line_repo = InMemoryPostingLineRepository::new(store.clone());
+    let stmt = create_stmt(StmtStatus::CLOSED);
+    for i in 0..120 {
+        let pst_time = stmt.financial_stmt.pst_time - Duration::hours(i + 1);
+        line_repo.save(PostingLine {
+            id: Uuid::new_v4(),
+            account_id: stmt.account.id,
+            debit_amount: BigDecimal::from(if i % 2 == 0 { 5 } else { 0 }),
+            credit_amount: BigDecimal::from(if i % 2 == 0 { 0 } else { 25 }),
+            pst_time,
+            record_time: pst_time,
+            additional_information: Some(format!("line {i} with a description longer than the column holds")),
+            ..Default::default()
+        }).await.unwrap();
+    }
+    let renderer = AccountStmtPdfRenderer::new(create_shared(store));
+
+    let pdf = renderer.render(&stmt).await.unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+
+    let open = create_stmt(StmtStatus::SIMULATED);
+    assert!(matches!(renderer.render(&open).await, Err(ServiceError::StatementNotClosed)));
+}
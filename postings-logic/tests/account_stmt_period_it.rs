@@ -0,0 +1,121 @@
+mod common;
+
+use std::sync::Arc;
+use chrono::{Duration, TimeZone, Utc};
+use postings_api::domain::closing_summary::ClosingSummary;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::stmt_period::StatementPeriod;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::hash_utils::hash_serialize;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn load_account(store: &Arc<InMemoryStore>, shared: &SharedService) -> LedgerAccountBO {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    shared.load_ledger_account_bo(id).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_stmt_seq_nbr_increases_per_closed_stmt() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let service = AccountStmtServiceImpl::new(shared);
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+    let first = service.create_stmt(account.clone(), day).await.unwrap();
+    assert_eq!(first.financial_stmt.stmt_seq_nbr, 0);
+    let first = service.close_stmt(first).await.unwrap();
+    assert_eq!(first.financial_stmt.stmt_seq_nbr, 0);
+
+    let second = service.create_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    assert_ne!(second.financial_stmt.id, first.financial_stmt.id);
+    assert_eq!(second.financial_stmt.stmt_seq_nbr, 1);
+    let second = service.close_stmt(second).await.unwrap();
+    assert_eq!(second.financial_stmt.stmt_seq_nbr, 1);
+
+    // The first statement is still closed with its own sequence number
+    let reread = service.read_stmt(account.clone(), day).await.unwrap();
+    assert_eq!(reread.financial_stmt.id, first.financial_stmt.id);
+    assert_eq!(reread.financial_stmt.stmt_seq_nbr, 0);
+    assert_eq!(service.read_stmt(account, day + Duration::days(2)).await.unwrap().financial_stmt.stmt_seq_nbr, 2);
+}
+
+#[tokio::test]
+async fn test_close_rejects_overlapping_period() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let service = AccountStmtServiceImpl::new(shared).with_stmt_period(StatementPeriod::Daily);
+    let noon = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+    let stale = service.create_stmt(account.clone(), noon - Duration::hours(2)).await.unwrap();
+    let stmt = service.create_stmt(account.clone(), noon).await.unwrap();
+    service.close_stmt(stmt).await.unwrap();
+
+    // Same day, later
+    let evening = service.create_stmt(account.clone(), noon + Duration::hours(6)).await.unwrap();
+    assert!(matches!(service.close_stmt(evening).await, Err(ServiceError::StatementPeriodOverlap)));
+    // Earlier than the closed statement
+    assert!(matches!(service.close_stmt(stale).await, Err(ServiceError::StatementPeriodOverlap)));
+    // Next day is free
+    let next_day = service.create_stmt(account, noon + Duration::days(1)).await.unwrap();
+    assert_eq!(service.close_stmt(next_day).await.unwrap().financial_stmt.stmt_seq_nbr, 1);
+}
+
+#[tokio::test]
+async fn test_closing_posting_records_summary_hash() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let service = AccountStmtServiceImpl::new(shared);
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+    // Off by default
+    let first = service.create_stmt(account.clone(), day).await.unwrap();
+    let first = service.close_stmt(first).await.unwrap();
+    assert_eq!(first.financial_stmt.posting.unwrap().opr_details, None);
+
+    InMemoryLedgerRepository::new(store.clone()).set_closing_summary(account.ledger.id, true).await.unwrap();
+    let second = service.create_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    let second = service.close_stmt(second).await.unwrap();
+    let summary = ClosingSummary::new(
+        second.financial_stmt.id,
+        Some(account.id),
+        second.financial_stmt.pst_time,
+        &second.total_debit,
+        &second.total_credit,
+    );
+    let posting = second.financial_stmt.posting.unwrap();
+    assert_eq!(posting.opr_details, Some(hash_serialize(&summary).unwrap()));
+    // Each statement is closed by its own operation
+    assert_ne!(posting.opr_id, [0; 34]);
+}
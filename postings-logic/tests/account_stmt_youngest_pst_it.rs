@@ -0,0 +1,141 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::youngest_pst::YoungestPstStrategy;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::models::posting_type::PostingType;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn load_account(store: &Arc<InMemoryStore>, shared: &SharedService) -> LedgerAccountBO {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    shared.load_ledger_account_bo(id).await.unwrap()
+}
+
+async fn save_line(store: &Arc<InMemoryStore>, account_id: Uuid, pst_time: DateTime<Utc>, record_time: DateTime<Utc>) -> Uuid {
+    let line = PostingLine {
+        id: Uuid::new_v4(),
+        account_id,
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        details: None,
+        src_account: None,
+        base_line: None,
+        sub_opr_src_id: None,
+        record_time,
+        opr_id: [1; 34],
+        opr_src: None,
+        pst_time,
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        hash: None,
+        discarded_time: None,
+        currency: None,
+        fx_base_currency: None,
+        fx_rate: None,
+    };
+    InMemoryPostingLineRepository::new(store.clone()).save(line).await.unwrap().id
+}
+
+#[tokio::test]
+async fn test_youngest_and_latest_pst_by_time() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    let early = save_line(&store, account.id, day - Duration::hours(3), day - Duration::hours(3)).await;
+    // Backdated: booked last with the earliest posting time
+    let backdated = save_line(&store, account.id, day - Duration::days(2), day).await;
+    let late = save_line(&store, account.id, day - Duration::hours(1), day - Duration::hours(1)).await;
+
+    let stmt = AccountStmtServiceImpl::new(shared.clone()).read_stmt(account.clone(), day).await.unwrap();
+    assert_eq!(stmt.youngest_pst.unwrap().src_pst_id, late);
+    assert_eq!(stmt.financial_stmt.latest_pst.unwrap().src_pst_id, backdated);
+
+    let stmt = AccountStmtServiceImpl::new(shared)
+        .with_youngest_pst_strategy(YoungestPstStrategy::RecordTime)
+        .read_stmt(account, day)
+        .await
+        .unwrap();
+    assert_eq!(stmt.youngest_pst.unwrap().src_pst_id, backdated);
+    assert_ne!(stmt.financial_stmt.latest_pst.unwrap().src_pst_id, early);
+}
+
+#[tokio::test]
+async fn test_next_stmt_tracks_its_own_lines() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let service = AccountStmtServiceImpl::new(shared);
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    let first_line = save_line(&store, account.id, day - Duration::hours(1), day - Duration::hours(1)).await;
+    let first = service.create_stmt(account.clone(), day).await.unwrap();
+    service.close_stmt(first).await.unwrap();
+
+    let second_line = save_line(&store, account.id, day + Duration::hours(1), day + Duration::hours(1)).await;
+    let second = service.create_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    assert_eq!(second.youngest_pst.as_ref().unwrap().src_pst_id, second_line);
+    assert_eq!(second.financial_stmt.latest_pst.as_ref().unwrap().src_pst_id, second_line);
+    service.close_stmt(second).await.unwrap();
+
+    // Without lines of its own, a statement keeps the references of the previous one
+    let third = service.read_stmt(account, day + Duration::days(2)).await.unwrap();
+    assert_eq!(third.youngest_pst.unwrap().src_pst_id, second_line);
+    assert_ne!(third.financial_stmt.latest_pst.unwrap().src_pst_id, first_line);
+}
+
+#[tokio::test]
+async fn test_read_stmt_stores_no_traces() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let service = AccountStmtServiceImpl::new(shared);
+    let trace_repo = InMemoryPostingTraceRepository::new(store.clone());
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    let line = save_line(&store, account.id, day - Duration::hours(1), day - Duration::hours(1)).await;
+
+    let read = service.read_stmt(account.clone(), day).await.unwrap();
+    let youngest = read.youngest_pst.unwrap();
+    assert_eq!(youngest.src_pst_id, line);
+    assert!(trace_repo.find_by_id(youngest.id).await.unwrap().is_none());
+
+    let created = service.create_stmt(account, day).await.unwrap();
+    let youngest = created.youngest_pst.unwrap();
+    assert_eq!(trace_repo.find_by_id(youngest.id).await.unwrap().unwrap().src_pst_id, line);
+}
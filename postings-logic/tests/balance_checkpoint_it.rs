@@ -0,0 +1,170 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::checkpoint_service::CheckpointService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::models::posting_type::PostingType;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::balance_checkpoint_repository::InMemoryBalanceCheckpointRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::checkpoint_service::CheckpointServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+async fn load_account(store: &Arc<InMemoryStore>, shared: &SharedService) -> LedgerAccountBO {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    shared.load_ledger_account_bo(id).await.unwrap()
+}
+
+async fn save_line(store: &Arc<InMemoryStore>, account_id: Uuid, pst_time: DateTime<Utc>, record_time: DateTime<Utc>) -> Uuid {
+    let line = PostingLine {
+        id: Uuid::new_v4(),
+        account_id,
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        details: None,
+        src_account: None,
+        base_line: None,
+        sub_opr_src_id: None,
+        record_time,
+        opr_id: [1; 34],
+        opr_src: None,
+        pst_time,
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        hash: None,
+        discarded_time: None,
+        currency: None,
+        fx_base_currency: None,
+        fx_rate: None,
+    };
+    InMemoryPostingLineRepository::new(store.clone()).save(line).await.unwrap().id
+}
+
+#[tokio::test]
+async fn test_stmt_continues_from_checkpoint() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let checkpoint_repo = Arc::new(InMemoryBalanceCheckpointRepository::new(store.clone()));
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    for hours in 1..=3 {
+        save_line(&store, account.id, day - Duration::hours(hours), day - Duration::hours(hours)).await;
+    }
+    let created = CheckpointServiceImpl::new(shared.clone(), checkpoint_repo.clone())
+        .create_checkpoints(account.ledger.clone(), day)
+        .await
+        .unwrap();
+    assert_eq!(created, 1);
+    let later = save_line(&store, account.id, day + Duration::hours(1), day + Duration::hours(1)).await;
+
+    let service = AccountStmtServiceImpl::new(shared.clone()).with_checkpoint_repo(checkpoint_repo);
+    let stmt = service.read_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    let full = AccountStmtServiceImpl::new(shared).read_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    assert_eq!(stmt.total_debit, full.total_debit);
+    assert_eq!(stmt.total_debit, BigDecimal::from(40));
+    assert_eq!(stmt.line_count, 4);
+    assert_eq!(stmt.youngest_pst.unwrap().src_pst_id, later);
+
+    // Lines up to the checkpoint are not read again
+    save_line(&store, account.id, day - Duration::hours(4), day + Duration::hours(2)).await;
+    let stmt = service.read_stmt(account, day + Duration::days(1)).await.unwrap();
+    assert_eq!(stmt.total_debit, BigDecimal::from(40));
+}
+
+#[tokio::test]
+async fn test_checkpoint_before_closed_stmt_is_ignored() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let account = load_account(&store, &shared).await;
+    let checkpoint_repo = Arc::new(InMemoryBalanceCheckpointRepository::new(store.clone()));
+    let checkpoints = CheckpointServiceImpl::new(shared.clone(), checkpoint_repo.clone());
+    let service = AccountStmtServiceImpl::new(shared).with_checkpoint_repo(checkpoint_repo);
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    save_line(&store, account.id, day - Duration::hours(1), day - Duration::hours(1)).await;
+    checkpoints.create_checkpoints(account.ledger.clone(), day).await.unwrap();
+
+    save_line(&store, account.id, day + Duration::hours(1), day + Duration::hours(1)).await;
+    let closed = service.create_stmt(account.clone(), day + Duration::hours(2)).await.unwrap();
+    service.close_stmt(closed).await.unwrap();
+    save_line(&store, account.id, day + Duration::hours(3), day + Duration::hours(3)).await;
+
+    let stmt = service.read_stmt(account.clone(), day + Duration::days(1)).await.unwrap();
+    assert_eq!(stmt.opening_debit, BigDecimal::from(20));
+    assert_eq!(stmt.total_debit, BigDecimal::from(30));
+    assert_eq!(stmt.line_count, 1);
+
+    // A checkpoint after the closed statement continues from it
+    checkpoints.create_checkpoints(account.ledger.clone(), day + Duration::hours(4)).await.unwrap();
+    let stmt = service.read_stmt(account, day + Duration::days(1)).await.unwrap();
+    assert_eq!(stmt.opening_debit, BigDecimal::from(20));
+    assert_eq!(stmt.total_debit, BigDecimal::from(30));
+    assert_eq!(stmt.line_count, 1);
+}
+
+#[tokio::test]
+async fn test_back_dated_posting_drops_checkpoint() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let checkpoint_repo = Arc::new(InMemoryBalanceCheckpointRepository::new(store.clone()));
+    let checkpoints = CheckpointServiceImpl::new(shared.clone(), checkpoint_repo.clone());
+    let postings = PostingServiceImpl::new(shared.clone()).with_checkpoint_repo(checkpoint_repo.clone());
+    let service = AccountStmtServiceImpl::new(shared).with_checkpoint_repo(checkpoint_repo);
+    let day = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+    let post = |opr_id: u8, pst_time: DateTime<Utc>| {
+        PostingBuilder::new(debit.ledger.clone(), [opr_id; 34], [2; 34], pst_time)
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit.clone(), BigDecimal::from(10))
+            .build()
+    };
+    postings.new_posting(post(1, day - Duration::hours(2))).await.unwrap();
+    checkpoints.create_checkpoints(debit.ledger.clone(), day).await.unwrap();
+
+    // Booked behind the checkpoint, which is dropped so the statement reads the line
+    postings.new_posting(post(3, day - Duration::hours(1))).await.unwrap();
+    let stmt = service.read_stmt(debit.clone(), day + Duration::days(1)).await.unwrap();
+    assert_eq!(stmt.total_debit, BigDecimal::from(20));
+    assert_eq!(stmt.line_count, 2);
+
+    assert!(matches!(
+        checkpoints.create_checkpoints(debit.ledger.clone(), Utc::now() + Duration::days(1)).await,
+        Err(ServiceError::NotEnoughInfo)
+    ));
+}
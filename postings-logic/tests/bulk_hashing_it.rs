@@ -0,0 +1,73 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::service::hash_chain_verifier::HashChainVerifier;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_large_batch_keeps_chain_order_per_ledger() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let ledgers = [load_accounts(&store, &shared).await, load_accounts(&store, &shared).await];
+    let service = PostingServiceImpl::new(shared.clone());
+    let batch: Vec<_> = (0..40u8)
+        .map(|i| {
+            let (debit, credit) = &ledgers[usize::from(i % 2)];
+            PostingBuilder::new(debit.ledger.clone(), [i + 1; 34], [2; 34], Utc::now())
+                .debit(debit.clone(), BigDecimal::from(10))
+                .credit(credit.clone(), BigDecimal::from(10))
+                .build()
+        })
+        .collect();
+    let ids: Vec<_> = batch.iter().map(|posting| posting.id).collect();
+
+    let saved = service.new_postings(batch).await.unwrap();
+
+    assert_eq!(saved.iter().map(|posting| posting.id).collect::<Vec<_>>(), ids);
+    for (debit, _) in &ledgers {
+        let chain: Vec<_> = saved.iter().filter(|posting| posting.ledger.id == debit.ledger.id).collect();
+        assert_eq!(chain[0].hash_record.antecedent_id, None);
+        for pair in chain.windows(2) {
+            assert_eq!(pair[1].hash_record.antecedent_id, Some(pair[0].id));
+            assert_eq!(pair[1].hash_record.antecedent_hash, pair[0].hash_record.hash);
+        }
+        let verification = HashChainVerifierImpl::new(shared.clone()).verify_chain(debit.ledger.clone()).await.unwrap();
+        assert!(verification.is_intact());
+        assert_eq!(verification.verified, 20);
+    }
+}
+
+#[tokio::test]
+async fn test_failing_batch_records_nothing() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared.clone());
+    let post = |opr: u8, credit_amount: i64| PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(10))
+        .credit(credit.clone(), BigDecimal::from(credit_amount))
+        .build();
+    let recorded = service.new_posting(post(1, 10)).await.unwrap();
+
+    // Rejected by validation after the first posting of the batch passed it
+    assert!(service.new_postings(vec![post(2, 10), post(3, 5)]).await.is_err());
+    // Rejected on insert: the second posting reuses a line id of the recorded posting
+    let mut clashing = post(5, 10);
+    clashing.lines[0].id = recorded.lines[0].id;
+    assert!(matches!(service.new_postings(vec![post(4, 10), clashing]).await, Err(ServiceError::Db)));
+
+    for opr in 2..=5u8 {
+        assert!(service.find_postings_by_operation_id(&[opr; 34]).await.unwrap().is_empty());
+    }
+    let verification = HashChainVerifierImpl::new(shared).verify_chain(debit.ledger.clone()).await.unwrap();
+    assert!(verification.is_intact());
+    assert_eq!(verification.verified, 1);
+}
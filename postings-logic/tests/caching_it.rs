@@ -5,6 +5,10 @@ use postings_db::models::chart_of_account::ChartOfAccount;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
 use postings_db::DbError;
 use postings_logic::caching::chart_of_account_repository::CachingChartOfAccountRepository;
+use postings_logic::caching::report_cache::{ReportCache, ReportKey};
+use postings_api::domain::ledger_event::{LedgerEvent, LedgerEventOperation};
+use postings_api::service::ledger_event_service::LedgerEventSink;
+use chrono::{TimeZone, Utc};
 use uuid::Uuid;
 
 mock! {
@@ -87,3 +91,39 @@ async fn test_save_invalidates_caches() {
     // 4. Find again, should hit the mock repo again.
     let _ = caching_repo.find_by_id(coa_id).await.unwrap();
 }
+
+fn posting_event(ledger_id: Uuid, pst_time: &str) -> LedgerEvent {
+    LedgerEvent {
+        seq: 1,
+        entity_type: "posting".to_string(),
+        entity_id: Uuid::new_v4().to_string(),
+        operation: LedgerEventOperation::Insert,
+        payload: format!(r#"{{"ledger_id": "{ledger_id}", "pst_time": "{pst_time}"}}"#),
+        created: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_report_cache_invalidated_by_posting_in_period() {
+    let cache = ReportCache::<u32>::new(100);
+    let ledger_id = Uuid::new_v4();
+    let key = ReportKey {
+        report_type: "trial_balance",
+        ledger_id,
+        from: None,
+        to: Utc.with_ymd_and_hms(2025, 6, 30, 23, 59, 59).unwrap(),
+        params: String::new(),
+    };
+    assert_eq!(cache.get_or_compute(key.clone(), async { Ok(1) }).await.unwrap(), 1);
+
+    // Postings after the period or of other ledgers leave the report in place
+    cache.publish(&[
+        posting_event(ledger_id, "2025-07-01T08:00:00+00:00"),
+        posting_event(Uuid::new_v4(), "2025-01-01T08:00:00+00:00"),
+    ]).await.unwrap();
+    assert_eq!(cache.get_or_compute(key.clone(), async { Ok(2) }).await.unwrap(), 1);
+
+    // A back-dated posting reaching into the period drops it
+    cache.publish(&[posting_event(ledger_id, "2025-06-15T08:00:00+00:00")]).await.unwrap();
+    assert_eq!(cache.get_or_compute(key, async { Ok(3) }).await.unwrap(), 3);
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::coa_import::{CoaImportFormat, CoaImportRequest};
+use postings_api::service::chart_of_account_import_service::ChartOfAccountImportService;
+use postings_db::models::named::ContainerType;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db_inmemory::repositories::chart_of_account_import_repository::InMemoryChartOfAccountImportRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::chart_of_account_import_service::ChartOfAccountImportServiceImpl;
+
+fn request(format: CoaImportFormat, source: &str) -> CoaImportRequest {
+    CoaImportRequest {
+        name: "Bootstrap".to_string(),
+        language: "en".to_string(),
+        user_details: [0u8; 34],
+        format,
+        source: source.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_import_csv_creates_account_tree() {
+    let store = Arc::new(InMemoryStore::new());
+    let service = ChartOfAccountImportServiceImpl::new(Arc::new(InMemoryChartOfAccountImportRepository::new(store.clone())));
+    let csv = "name,category,balance_side,parent,currency\n\
+        Cash,AS,,Assets/Current,EUR\n\
+        Assets,AS\n\
+        Current,AS,,Assets\n\
+        Fees,RE,DrCr\n";
+
+    let report = service.import(request(CoaImportFormat::Csv, csv)).await.unwrap();
+
+    assert!(report.is_imported());
+    let ledger = report.ledger.unwrap();
+    assert!(InMemoryLedgerRepository::new(store.clone()).find_by_id(ledger.id).await.unwrap().is_some());
+    let paths: Vec<_> = report.accounts.iter().map(|a| a.path.as_str()).collect();
+    assert_eq!(paths, vec!["Assets", "Fees", "Assets/Current", "Assets/Current/Cash"]);
+    let fees = &report.accounts[1].account;
+    assert_eq!(fees.balance_side, BalanceSide::DrCr);
+
+    let account_repo = InMemoryLedgerAccountRepository::new(store.clone());
+    assert_eq!(account_repo.find_by_ledger_id(ledger.id).await.unwrap().len(), 4);
+    let descendants = account_repo.find_descendants(report.accounts[0].account.id).await.unwrap();
+    assert_eq!(descendants.len(), 2);
+    let cash = &report.accounts[3].account;
+    assert_eq!(cash.parent.as_ref().map(|p| p.id), Some(report.accounts[2].account.id));
+    assert_eq!(cash.currency.as_deref(), Some("EUR"));
+
+    let named_repo = InMemoryNamedRepository::new(store.clone());
+    let cash_names = named_repo.find_by_name_and_type_and_context("Cash", ContainerType::LedgerAccount, ledger.id).await.unwrap();
+    assert_eq!(cash_names.iter().map(|n| n.container).collect::<Vec<_>>(), vec![cash.id]);
+    assert_eq!(named_repo.find_by_name_and_type("Bootstrap", ContainerType::Ledger).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_import_yaml_with_invalid_rows_writes_nothing() {
+    let store = Arc::new(InMemoryStore::new());
+    let service = ChartOfAccountImportServiceImpl::new(Arc::new(InMemoryChartOfAccountImportRepository::new(store.clone())));
+    let yaml = "\
+- name: Assets
+  category: AS
+- name: Cash
+  category: AS
+  parent: Assets
+- name: Loans
+  category: XX
+- name: Bank
+  category: AS
+  parent: Assets/Missing
+";
+
+    let report = service.import(request(CoaImportFormat::Yaml, yaml)).await.unwrap();
+
+    assert!(!report.is_imported());
+    assert!(report.accounts.is_empty());
+    assert_eq!(report.errors.iter().map(|e| e.record).collect::<Vec<_>>(), vec![3, 4]);
+    assert_eq!(report.errors[0].name.as_deref(), Some("Loans"));
+    let named_repo = InMemoryNamedRepository::new(store);
+    assert!(named_repo.find_by_name_and_type("Bootstrap", ContainerType::Ledger).await.unwrap().is_empty());
+    assert!(named_repo.find_by_name_and_type("Assets", ContainerType::LedgerAccount).await.unwrap().is_empty());
+}
@@ -0,0 +1,79 @@
+//! Fixtures shared by the in-memory integration tests.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use chrono::Utc;
+use postings_api::domain::api_key::{ApiKey, ApiRole};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+
+pub fn create_shared(store: Arc<InMemoryStore>) -> SharedService {
+    SharedService::new(
+        Arc::new(InMemoryChartOfAccountRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerAccountRepository::new(store.clone())),
+        Arc::new(InMemoryNamedRepository::new(store.clone())),
+        Arc::new(InMemoryPostingRepository::new(store.clone())),
+        Arc::new(InMemoryAccountStmtRepository::new(store.clone())),
+        Arc::new(InMemoryPostingLineRepository::new(store.clone())),
+        Arc::new(InMemoryPostingTraceRepository::new(store)),
+    )
+}
+
+/// Two accounts of a new ledger.
+pub async fn load_accounts(store: &Arc<InMemoryStore>, shared: &SharedService) -> (LedgerAccountBO, LedgerAccountBO) {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let mut accounts = Vec::new();
+    for balance_side in [BalanceSide::Dr, BalanceSide::Cr] {
+        let id = Uuid::new_v4();
+        InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+            id,
+            ledger_id: ledger.id,
+            parent_id: None,
+            coa_id: coa.id,
+            balance_side,
+            category: AccountCategory::AS,
+            currency: None,
+        }).await.unwrap();
+        accounts.push(shared.load_ledger_account_bo(id).await.unwrap());
+    }
+    let credit = accounts.pop().unwrap();
+    (accounts.pop().unwrap(), credit)
+}
+
+/// Authenticated key of a service account with access to every ledger.
+pub fn api_key(name: &str, roles: Vec<ApiRole>) -> ApiKey {
+    ApiKey {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        tenant_id: Uuid::nil(),
+        ledger_id: None,
+        roles,
+        key_prefix: "abcd1234".to_string(),
+        key_hash: [0; 34],
+        created: Utc::now(),
+        expires: None,
+        revoked_time: None,
+    }
+}
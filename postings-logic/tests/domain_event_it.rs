@@ -0,0 +1,35 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::service::posting_service::PostingService;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::events::broadcast::BroadcastEventPublisher;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_recorded_and_reversed_postings_are_published() {
+    let store = Arc::new(InMemoryStore::new());
+    let publisher = Arc::new(BroadcastEventPublisher::new(16));
+    let mut events = publisher.subscribe();
+    let shared = create_shared(store.clone()).with_event_publisher(publisher);
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared);
+
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit, BigDecimal::from(100))
+        .credit(credit, BigDecimal::from(100))
+        .build();
+    let recorded = service.new_posting(posting.clone()).await.unwrap();
+    // Recording the operation again publishes nothing
+    service.new_posting(posting).await.unwrap();
+    let reversal = service.reverse_posting(recorded.id, Utc::now()).await.unwrap();
+
+    assert_eq!(events.recv().await.unwrap(), DomainEvent::PostingCreated(recorded.clone()));
+    assert_eq!(events.recv().await.unwrap(), DomainEvent::PostingReversed { reversed_id: recorded.id, reversal });
+    assert!(events.try_recv().is_err());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::earmark::{Earmark, EarmarkStatus};
+use postings_api::service::earmark_service::EarmarkService;
+use postings_api::service::posting_service::PostingService;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db_inmemory::repositories::earmark_repository::InMemoryEarmarkRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::earmark_service::EarmarkServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_partial_capture_books_the_captured_amount() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone()).with_unit_of_work(Arc::new(InMemoryUnitOfWorkRepository::new(store.clone())));
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()));
+    posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(100))
+            .credit(credit.clone(), BigDecimal::from(100))
+            .build())
+        .await
+        .unwrap();
+    let service = EarmarkServiceImpl::new(shared.clone(), Arc::new(InMemoryEarmarkRepository::new(store.clone())), posting_service);
+
+    // The credit account holds 100, of which 60 are blocked
+    let earmark = service
+        .create_earmark(Earmark {
+            id: Uuid::nil(),
+            account: credit.clone(),
+            amount: BigDecimal::from(60),
+            captured_amount: BigDecimal::from(0),
+            reason: "card authorization".to_string(),
+            created: Utc::now(),
+            expiry: None,
+            status: EarmarkStatus::Active,
+            closed_time: None,
+            posting_id: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(service.available_balance(credit.clone(), Utc::now()).await.unwrap(), BigDecimal::from(40));
+
+    let captured = service.capture_earmark(earmark.id, BigDecimal::from(25), debit.clone()).await.unwrap();
+    assert_eq!(captured.captured_amount, BigDecimal::from(25));
+    assert_eq!(captured.status, EarmarkStatus::Active);
+    let posting_id = captured.posting_id.expect("the capture is booked");
+    let posting = InMemoryPostingRepository::new(store.clone()).find_by_id(posting_id).await.unwrap().unwrap();
+    assert_eq!(posting.ledger_id, credit.ledger.id);
+
+    // 25 left the account and are no longer blocked, the 35 still blocked keep the available balance
+    let now = Utc::now();
+    assert_eq!(shared.booked_balance(&credit, now).await.unwrap(), BigDecimal::from(75));
+    assert_eq!(service.available_balance(credit.clone(), now).await.unwrap(), BigDecimal::from(40));
+
+    let consumed = service.consume_earmark(earmark.id, debit).await.unwrap();
+    assert_eq!(consumed.status, EarmarkStatus::Consumed);
+    assert_ne!(consumed.posting_id, Some(posting_id));
+    let now = Utc::now();
+    assert_eq!(shared.booked_balance(&credit, now).await.unwrap(), BigDecimal::from(40));
+    assert_eq!(service.available_balance(credit, now).await.unwrap(), BigDecimal::from(40));
+}
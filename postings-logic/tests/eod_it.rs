@@ -0,0 +1,109 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, TimeZone, Utc};
+use postings_api::domain::api_key::ApiRole;
+use postings_api::domain::eod_run::EodStatus;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::eod_service::{EodService, EodStep};
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::stmt_delivery_service::StmtDeliveryService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::eod_run_repository::InMemoryEodRunRepository;
+use postings_db_inmemory::repositories::ledger_closure_repository::InMemoryLedgerClosureRepository;
+use postings_db_inmemory::repositories::stmt_delivery_repository::InMemoryStmtDeliveryRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::eod_service::{EodServiceImpl, LedgerClosureStep, StatementGenerationStep, StmtExportStep, TraceCompactionStep};
+use postings_logic::services::ledger_closure_service::LedgerClosureServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::stmt_delivery_service::StmtDeliveryServiceImpl;
+use common::{api_key, create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_statement_generation_skips_accounts_with_statements() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let business_date = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    let step = StatementGenerationStep::new(shared.clone(), Arc::new(AccountStmtServiceImpl::new(shared)));
+
+    let first = step.run(&debit.ledger, business_date).await.unwrap();
+    // A resumed step creates no second statement for the business date
+    let second = step.run(&debit.ledger, business_date).await.unwrap();
+    let next_day = step.run(&debit.ledger, business_date.succ_opt().unwrap()).await.unwrap();
+
+    assert_eq!(first.as_deref(), Some("2 statements created, 0 already present"));
+    assert_eq!(second.as_deref(), Some("0 statements created, 2 already present"));
+    assert_eq!(next_day.as_deref(), Some("2 statements created, 0 already present"));
+}
+
+#[tokio::test]
+async fn test_ledgers_run_their_own_pipeline() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (locked, locked_credit) = load_accounts(&store, &shared).await;
+    let (other, _) = load_accounts(&store, &shared).await;
+    let business_date = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let closure_repo = Arc::new(InMemoryLedgerClosureRepository::new(store.clone()));
+    let controller = PrivilegedContext::new(&api_key("eod", vec![ApiRole::Admin]), "End of day").unwrap();
+    let closure_step = Arc::new(LedgerClosureStep::new(Arc::new(LedgerClosureServiceImpl::new(shared.clone(), closure_repo.clone())), false, controller));
+    let service = EodServiceImpl::new(shared.clone(), Arc::new(InMemoryEodRunRepository::new(store)))
+        .with_step(Arc::new(TraceCompactionStep::new(shared.clone())))
+        .with_ledger_step(locked.ledger.id, closure_step.clone());
+
+    let locked_run = service.run_eod(locked.ledger.clone(), business_date).await.unwrap();
+    let other_run = service.run_eod(other.ledger.clone(), business_date).await.unwrap();
+
+    assert_eq!(locked_run.status, EodStatus::Completed);
+    assert_eq!(locked_run.steps.iter().map(|s| s.step_name.as_str()).collect::<Vec<_>>(), vec!["ledger_closure"]);
+    assert_eq!(other_run.steps.iter().map(|s| s.step_name.as_str()).collect::<Vec<_>>(), vec!["trace_compaction"]);
+    let late = PostingServiceImpl::new(shared.clone())
+        .with_closure_repo(closure_repo)
+        .new_posting(PostingBuilder::new(locked.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 3, 18, 0, 0).unwrap())
+            .debit(locked.clone(), BigDecimal::from(10))
+            .credit(locked_credit, BigDecimal::from(10))
+            .build())
+        .await;
+    assert!(matches!(late, Err(ServiceError::PeriodClosed)));
+    // A resumed closure keeps the period closed once
+    let again = closure_step.run(&locked.ledger, business_date).await.unwrap();
+    assert!(again.unwrap().starts_with("already closed"));
+}
+
+#[tokio::test]
+async fn test_stmt_export_records_each_closed_statement_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let business_date = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    let stmt_service = AccountStmtServiceImpl::new(shared.clone());
+    let stmt = stmt_service.create_stmt(debit.clone(), Utc.with_ymd_and_hms(2025, 3, 3, 23, 0, 0).unwrap()).await.unwrap();
+    let stmt = stmt_service.close_stmt(stmt).await.unwrap();
+    let delivery_service = Arc::new(StmtDeliveryServiceImpl::new(Arc::new(InMemoryStmtDeliveryRepository::new(store))));
+    let step = StmtExportStep::new(shared, delivery_service.clone());
+
+    let first = step.run(&debit.ledger, business_date).await.unwrap();
+    let second = step.run(&debit.ledger, business_date).await.unwrap();
+
+    assert_eq!(first.as_deref(), Some("1 statements exported, 0 already exported"));
+    assert_eq!(second.as_deref(), Some("0 statements exported, 1 already exported"));
+    assert_eq!(delivery_service.find_deliveries(stmt.financial_stmt.id).await.unwrap().len(), 1);
+}
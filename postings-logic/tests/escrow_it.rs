@@ -0,0 +1,80 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::domain::escrow::{Escrow, EscrowStatus, EscrowTimeoutAction};
+use postings_api::service::escrow_service::EscrowService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db_inmemory::repositories::escrow_repository::InMemoryEscrowRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::hash_utils::hash_serialize;
+use postings_logic::services::escrow_service::EscrowServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_concurrent_release_and_return_pay_out_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (source, escrow_account) = load_accounts(&store, &shared).await;
+    let target_id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id: target_id,
+        ledger_id: source.ledger.id,
+        parent_id: None,
+        coa_id: source.coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    let target = shared.load_ledger_account_bo(target_id).await.unwrap();
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let service = EscrowServiceImpl::new(shared, Arc::new(InMemoryEscrowRepository::new(store)), posting_service.clone());
+
+    let escrow = service
+        .initiate_escrow(Escrow {
+            id: Uuid::nil(),
+            ledger: source.ledger.clone(),
+            source_account: source,
+            escrow_account,
+            target_account: target,
+            amount: BigDecimal::from(50),
+            opr_type: [2; 34],
+            status: EscrowStatus::Pending,
+            timeout: Utc::now() + Duration::days(1),
+            timeout_action: EscrowTimeoutAction::Return,
+            initiation_posting_id: None,
+            resolution_posting_id: None,
+            created: Utc::now(),
+            resolved_time: None,
+        })
+        .await
+        .unwrap();
+
+    let (released, returned) = tokio::join!(service.confirm_escrow(escrow.id), service.cancel_escrow(escrow.id));
+    let resolved = match (released, returned) {
+        (Ok(resolved), Err(ServiceError::EscrowAlreadyResolved)) => {
+            assert_eq!(resolved.status, EscrowStatus::Released);
+            resolved
+        }
+        (Err(ServiceError::EscrowAlreadyResolved), Ok(resolved)) => {
+            assert_eq!(resolved.status, EscrowStatus::Returned);
+            resolved
+        }
+        other => panic!("exactly one resolution must succeed, got {other:?}"),
+    };
+
+    let opr_id = hash_serialize(&(escrow.id, "resolve")).unwrap();
+    let postings = posting_service.find_postings_by_operation_id(&opr_id).await.unwrap();
+    assert_eq!(postings.len(), 1);
+    assert_eq!(resolved.resolution_posting_id, Some(postings[0].id));
+    assert!(matches!(service.confirm_escrow(escrow.id).await, Err(ServiceError::EscrowAlreadyResolved)));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::external_ref::{ExternalRefEntity, ExternalRefUniqueness};
+use postings_api::service::external_ref_service::ExternalRefService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::external_ref_repository::InMemoryExternalRefRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::external_ref_service::ExternalRefServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_posting_found_by_external_ref() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(100))
+        .credit(credit, BigDecimal::from(100))
+        .build();
+    let recorded = PostingServiceImpl::new(shared.clone()).new_posting(posting).await.unwrap();
+    let refs = ExternalRefServiceImpl::new(shared, Arc::new(InMemoryExternalRefRepository::new(store)));
+
+    refs.attach_posting_ref(recorded.id, "core-banking", "CB-1").await.unwrap();
+    // Attaching again is idempotent, another reference of the same system is refused
+    refs.attach_posting_ref(recorded.id, "core-banking", "CB-1").await.unwrap();
+    assert!(matches!(refs.attach_posting_ref(recorded.id, "core-banking", "CB-2").await, Err(ServiceError::ExternalRefTaken)));
+    refs.attach_posting_ref(recorded.id, "payment-engine", "PE-9").await.unwrap();
+
+    let found = refs.find_postings_by_ref(debit.ledger.clone(), "core-banking", "CB-1").await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, recorded.id);
+    assert_eq!(found[0].lines.len(), 2);
+    let attached = refs.find_refs(ExternalRefEntity::Posting, recorded.id).await.unwrap();
+    assert_eq!(attached.iter().map(|r| r.system.as_str()).collect::<Vec<_>>(), vec!["core-banking", "payment-engine"]);
+}
+
+#[tokio::test]
+async fn test_uniqueness_per_system() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (first, second) = load_accounts(&store, &shared).await;
+    let refs = ExternalRefServiceImpl::new(shared, Arc::new(InMemoryExternalRefRepository::new(store)))
+        .with_uniqueness("batch", ExternalRefUniqueness::Shared);
+
+    refs.attach_account_ref(first.id, "core-banking", "ACC-1").await.unwrap();
+    assert!(matches!(refs.attach_account_ref(second.id, "core-banking", "ACC-1").await, Err(ServiceError::ExternalRefTaken)));
+
+    refs.attach_account_ref(first.id, "batch", "B-7").await.unwrap();
+    refs.attach_account_ref(second.id, "batch", "B-7").await.unwrap();
+    let shared_ref = refs.find_accounts_by_ref(first.ledger.clone(), "batch", "B-7").await.unwrap();
+    assert_eq!(shared_ref.len(), 2);
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::fee_schedule::{FeeSchedule, FeeType};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::fee_schedule_service::FeeScheduleService;
+use postings_api::service::hash_chain_verifier::HashChainVerifier;
+use postings_api::service::posting_service::PostingService;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::fee_schedule_repository::InMemoryFeeScheduleRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::fee_schedule_service::{fee_line_ref, FeeScheduleServiceImpl};
+use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+/// Payer, payee and fee account of a new ledger.
+async fn load_accounts(store: &Arc<InMemoryStore>, shared: &SharedService) -> Vec<LedgerAccountBO> {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let mut accounts = Vec::new();
+    for (balance_side, category) in [(BalanceSide::Dr, AccountCategory::AS), (BalanceSide::Cr, AccountCategory::LI), (BalanceSide::Cr, AccountCategory::RE)] {
+        let id = Uuid::new_v4();
+        InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+            id,
+            ledger_id: ledger.id,
+            parent_id: None,
+            coa_id: coa.id,
+            balance_side,
+            category,
+            currency: None,
+        }).await.unwrap();
+        accounts.push(shared.load_ledger_account_bo(id).await.unwrap());
+    }
+    accounts
+}
+
+#[tokio::test]
+async fn test_fee_lines_link_to_their_schedule() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let accounts = load_accounts(&store, &shared).await;
+    let (payer, payee, fee_account) = (&accounts[0], &accounts[1], &accounts[2]);
+    let fee_repo = Arc::new(InMemoryFeeScheduleRepository::new(store));
+    let schedule = FeeScheduleServiceImpl::new(shared.clone(), fee_repo.clone())
+        .save_fee_schedule(FeeSchedule {
+            id: Uuid::nil(),
+            ledger: payer.ledger.clone(),
+            opr_type: [2; 34],
+            fee_type: FeeType::Flat,
+            flat_amount: Some(BigDecimal::from(3)),
+            rate: None,
+            tiers: vec![],
+            min_fee: None,
+            max_fee: None,
+            fee_account: fee_account.clone(),
+            product_id: None,
+            created: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let posting = PostingBuilder::new(payer.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(payer.clone(), BigDecimal::from(100))
+        .credit(payee.clone(), BigDecimal::from(100))
+        .build();
+
+    PostingServiceImpl::new(shared.clone()).with_fee_repo(fee_repo).new_posting(posting).await.unwrap();
+
+    // The link is read back from the stored lines
+    let lines = shared.line_repo.find_by_opr_id(&[1; 34]).await.unwrap();
+    let fee_ref = fee_line_ref(schedule.id).unwrap();
+    let fee_lines: Vec<_> = lines.iter().filter(|l| l.sub_opr_src_id == Some(fee_ref)).collect();
+    assert_eq!(fee_lines.len(), 2);
+    assert!(fee_lines.iter().any(|l| l.account_id == fee_account.id && l.credit_amount == BigDecimal::from(3)));
+    assert!(fee_lines.iter().any(|l| l.account_id == payer.id && l.debit_amount == BigDecimal::from(3)));
+    let verification = HashChainVerifierImpl::new(shared).verify_chain(payer.ledger.clone()).await.unwrap();
+    assert!(verification.is_intact(), "{verification:?}");
+}
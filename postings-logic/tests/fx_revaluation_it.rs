@@ -0,0 +1,163 @@
+mod common;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
+use postings_api::domain::currency::FxDetails;
+use postings_api::domain::fx_revaluation::RevaluationAccounts;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::fx_rate_provider::FxRateProvider;
+use postings_api::service::fx_revaluation_service::FxRevaluationService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::fx_revaluation_repository::InMemoryFxRevaluationRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::fx_revaluation_service::FxRevaluationServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+/// USD to EUR at 0.9 until `change`, at 0.95 from then on.
+struct ChangingRate {
+    change: DateTime<Utc>,
+}
+
+#[async_trait]
+impl FxRateProvider for ChangingRate {
+    async fn closing_rate(&self, from: &str, to: &str, at: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError> {
+        let rate = if at < self.change { "0.9" } else { "0.95" };
+        Ok((from == "USD" && to == "EUR").then(|| BigDecimal::from_str(rate).unwrap()))
+    }
+
+    async fn average_rate(&self, _from: &str, _to: &str, _period_start: DateTime<Utc>, _period_end: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError> {
+        Ok(None)
+    }
+}
+
+async fn save_account(store: &Arc<InMemoryStore>, shared: &SharedService, ledger: &Ledger, category: AccountCategory, currency: &str) -> LedgerAccountBO {
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: ledger.coa_id,
+        balance_side: BalanceSide::Dr,
+        category,
+        currency: Some(currency.to_string()),
+    }).await.unwrap();
+    shared.load_ledger_account_bo(id).await.unwrap()
+}
+
+struct Books {
+    usd: LedgerAccountBO,
+    eur: LedgerAccountBO,
+    accounts: RevaluationAccounts,
+}
+
+async fn create_books(store: &Arc<InMemoryStore>, shared: &SharedService) -> Books {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    Books {
+        usd: save_account(store, shared, &ledger, AccountCategory::AS, "USD").await,
+        eur: save_account(store, shared, &ledger, AccountCategory::AS, "EUR").await,
+        accounts: RevaluationAccounts {
+            gain_account: save_account(store, shared, &ledger, AccountCategory::RE, "EUR").await,
+            loss_account: save_account(store, shared, &ledger, AccountCategory::EX, "EUR").await,
+            adjustment_account: save_account(store, shared, &ledger, AccountCategory::AS, "EUR").await,
+        },
+    }
+}
+
+/// Buys 100 USD for 90 EUR.
+async fn buy_usd(postings: &PostingServiceImpl, books: &Books, pst_time: DateTime<Utc>) {
+    let mut posting = PostingBuilder::new(books.usd.ledger.clone(), [1; 34], [2; 34], pst_time)
+        .debit(books.usd.clone(), BigDecimal::from(100))
+        .credit(books.eur.clone(), BigDecimal::from(90))
+        .build();
+    posting.lines[0].fx = Some(FxDetails { base_currency: "EUR".to_string(), rate: BigDecimal::from_str("0.9").unwrap() });
+    postings.new_posting(posting).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_revaluation_books_unrealized_gain_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let books = create_books(&store, &shared).await;
+    let postings = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let change = Utc::now() - Duration::days(10);
+    buy_usd(&postings, &books, change - Duration::days(1)).await;
+    let service = FxRevaluationServiceImpl::new(
+        shared.clone(),
+        Arc::new(ChangingRate { change }),
+        postings.clone(),
+        Arc::new(InMemoryFxRevaluationRepository::new(store)),
+        "EUR",
+    );
+    let valuation_time = Utc::now() - Duration::days(5);
+
+    let preview = service.compute_revaluation(books.usd.ledger.clone(), valuation_time).await.unwrap();
+    assert_eq!(preview.revaluations.len(), 1);
+    assert_eq!(preview.revaluations[0].carrying_value, BigDecimal::from(90));
+    assert_eq!(preview.revaluations[0].revalued_value, BigDecimal::from(95));
+    assert_eq!(preview.total_gain, BigDecimal::from(5));
+    assert!(preview.revaluations[0].posting_id.is_none());
+
+    let booked = service.revalue(books.usd.ledger.clone(), valuation_time, books.accounts.clone()).await.unwrap();
+    assert!(booked.revaluations[0].posting_id.is_some());
+    let gain_lines = postings
+        .find_postings_by_dates(books.accounts.gain_account.clone(), valuation_time - Duration::days(1), valuation_time + Duration::days(1))
+        .await
+        .unwrap();
+    assert_eq!(gain_lines.len(), 1);
+    assert_eq!(gain_lines[0].credit_amount, BigDecimal::from(5));
+
+    // Repeating the valuation date returns the booked revaluation, later dates build on it
+    let repeated = service.revalue(books.usd.ledger.clone(), valuation_time, books.accounts.clone()).await.unwrap();
+    assert_eq!(repeated.revaluations, booked.revaluations);
+    let later = service.compute_revaluation(books.usd.ledger.clone(), Utc::now()).await.unwrap();
+    assert_eq!(later.revaluations[0].carrying_value, BigDecimal::from(95));
+    assert_eq!(later.net_gain_loss(), BigDecimal::from(0));
+    assert_eq!(service.find_revaluations(books.usd.clone()).await.unwrap(), booked.revaluations);
+}
+
+#[tokio::test]
+async fn test_revaluation_before_booked_one_is_superseded() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let books = create_books(&store, &shared).await;
+    let postings = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let change = Utc::now() - Duration::days(10);
+    buy_usd(&postings, &books, change - Duration::days(1)).await;
+    let service = FxRevaluationServiceImpl::new(
+        shared,
+        Arc::new(ChangingRate { change }),
+        postings,
+        Arc::new(InMemoryFxRevaluationRepository::new(store)),
+        "EUR",
+    );
+
+    service.revalue(books.usd.ledger.clone(), Utc::now() - Duration::days(1), books.accounts.clone()).await.unwrap();
+    let earlier = service.revalue(books.usd.ledger.clone(), Utc::now() - Duration::days(3), books.accounts.clone()).await;
+    assert!(matches!(earlier, Err(ServiceError::RevaluationSuperseded)));
+
+    // Accounts in another currency cannot take the revaluation
+    let wrong = RevaluationAccounts { gain_account: books.usd.clone(), ..books.accounts };
+    assert!(matches!(service.revalue(books.usd.ledger.clone(), Utc::now(), wrong).await, Err(ServiceError::CurrencyMismatch)));
+}
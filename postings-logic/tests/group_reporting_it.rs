@@ -0,0 +1,154 @@
+mod common;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
+use postings_api::domain::account_category::AccountCategory as AccountCategoryBO;
+use postings_api::domain::account_group::AccountGroup;
+use postings_api::domain::group_reporting::TranslationRate;
+use postings_api::service::account_group_service::AccountGroupService;
+use postings_api::service::fx_rate_provider::FxRateProvider;
+use postings_api::service::group_reporting_service::GroupReportingService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::account_group_repository::InMemoryAccountGroupRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_group_service::AccountGroupServiceImpl;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::balance_service::BalanceServiceImpl;
+use postings_logic::services::group_reporting_service::GroupReportingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+/// USD to EUR at 0.9 closing and 0.8 on average, no other rates.
+struct FixedRates;
+
+#[async_trait]
+impl FxRateProvider for FixedRates {
+    async fn closing_rate(&self, from: &str, to: &str, _at: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError> {
+        Ok((from == "USD" && to == "EUR").then(|| BigDecimal::from_str("0.9").unwrap()))
+    }
+
+    async fn average_rate(&self, from: &str, to: &str, _period_start: DateTime<Utc>, _period_end: DateTime<Utc>) -> Result<Option<BigDecimal>, ServiceError> {
+        Ok((from == "USD" && to == "EUR").then(|| BigDecimal::from_str("0.8").unwrap()))
+    }
+}
+
+async fn save_account(store: &Arc<InMemoryStore>, ledger: &Ledger, category: AccountCategory, currency: Option<&str>) -> Uuid {
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: ledger.coa_id,
+        balance_side: BalanceSide::Dr,
+        category,
+        currency: currency.map(str::to_string),
+    }).await.unwrap();
+    id
+}
+
+async fn save_line(store: &Arc<InMemoryStore>, account_id: Uuid, debit: i64, credit: i64, currency: Option<&str>) {
+    let pst_time = Utc::now() - Duration::days(1);
+    InMemoryPostingLineRepository::new(store.clone()).save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id,
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        pst_time,
+        record_time: pst_time,
+        pst_status: PostingStatus::Posted,
+        currency: currency.map(str::to_string),
+        ..Default::default()
+    }).await.unwrap();
+}
+
+async fn setup() -> (Arc<InMemoryStore>, Ledger, SharedService, Arc<AccountGroupServiceImpl>) {
+    let store = Arc::new(InMemoryStore::new());
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let shared = create_shared(store.clone());
+    let group_service = Arc::new(AccountGroupServiceImpl::new(
+        shared.clone(),
+        Arc::new(InMemoryAccountGroupRepository::new(store.clone())),
+        Arc::new(BalanceServiceImpl::new(shared.clone())),
+        Arc::new(AccountStmtServiceImpl::new(shared.clone())),
+    ));
+    (store, ledger, shared, group_service)
+}
+
+async fn create_group(shared: &SharedService, group_service: &AccountGroupServiceImpl, ledger: &Ledger, member_ids: Vec<Uuid>) -> AccountGroup {
+    group_service.create_group(AccountGroup {
+        id: Uuid::new_v4(),
+        ledger: shared.load_ledger_bo(ledger.id).await.unwrap(),
+        name: "subsidiary".to_string(),
+        category: None,
+        member_ids,
+        created: Utc::now(),
+    }).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_translates_by_category_and_books_translation_difference() {
+    let (store, ledger, shared, group_service) = setup().await;
+    // Revenue of 100 USD received in cash, and 50 EUR of untagged equity in cash
+    let cash = save_account(&store, &ledger, AccountCategory::AS, None).await;
+    let revenue = save_account(&store, &ledger, AccountCategory::RE, Some("USD")).await;
+    let equity = save_account(&store, &ledger, AccountCategory::EQ, None).await;
+    save_line(&store, cash, 100, 0, Some("USD")).await;
+    save_line(&store, revenue, 0, 100, None).await;
+    save_line(&store, cash, 50, 0, None).await;
+    save_line(&store, equity, 0, 50, None).await;
+    let group = create_group(&shared, &group_service, &ledger, vec![cash, revenue, equity]).await;
+
+    let service = GroupReportingServiceImpl::new(shared.clone(), group_service.clone(), Arc::new(FixedRates), "EUR", "EUR");
+    let report = service.translated_trial_balance(group.id, Utc::now() - Duration::days(30), Utc::now()).await.unwrap();
+
+    let usd_cash = report.lines.iter().find(|l| l.account.id == cash && l.currency == "USD").unwrap();
+    assert_eq!(usd_cash.rate_type, TranslationRate::Closing);
+    assert_eq!(usd_cash.translated_debit, BigDecimal::from(90));
+    let eur_cash = report.lines.iter().find(|l| l.account.id == cash && l.currency == "EUR").unwrap();
+    assert_eq!(eur_cash.translated_debit, BigDecimal::from(50));
+    let revenue_line = report.lines.iter().find(|l| l.account.id == revenue).unwrap();
+    assert_eq!(revenue_line.rate_type, TranslationRate::PeriodAverage);
+    assert_eq!(revenue_line.translated_credit, BigDecimal::from(80));
+    assert_eq!(report.translation_difference(), BigDecimal::from(10));
+    assert_eq!(report.total_debit, report.total_credit);
+
+    // Translating revenue at the closing rate as well leaves no difference
+    let service = GroupReportingServiceImpl::new(shared, group_service, Arc::new(FixedRates), "EUR", "EUR")
+        .with_rate(AccountCategoryBO::RE, TranslationRate::Closing);
+    let report = service.translated_trial_balance(group.id, Utc::now() - Duration::days(30), Utc::now()).await.unwrap();
+    assert_eq!(report.translation_difference(), BigDecimal::from(0));
+}
+
+#[tokio::test]
+async fn test_unknown_rate_fails() {
+    let (store, ledger, shared, group_service) = setup().await;
+    let cash = save_account(&store, &ledger, AccountCategory::AS, Some("CHF")).await;
+    save_line(&store, cash, 10, 0, None).await;
+    let group = create_group(&shared, &group_service, &ledger, vec![cash]).await;
+
+    let service = GroupReportingServiceImpl::new(shared, group_service, Arc::new(FixedRates), "EUR", "EUR");
+    let result = service.translated_trial_balance(group.id, Utc::now() - Duration::days(30), Utc::now()).await;
+    assert!(matches!(result, Err(ServiceError::FxRateNotFound { currency, rate_type: TranslationRate::Closing }) if currency == "CHF"));
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::hash_record::HashAlgorithm;
+use postings_api::domain::hashing_profile::{HashedField, HashingProfile};
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::hash_chain_verifier::HashChainVerifier;
+use postings_api::service::hashing_profile_service::HashingProfileService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::hashing_profile::HashingProfile as HashingProfileModel;
+use postings_db::repositories::hashing_profile_repository::HashingProfileRepository;
+use postings_db_inmemory::repositories::hashing_profile_repository::InMemoryHashingProfileRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
+use postings_logic::services::hashing_profile_service::HashingProfileServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_chain_switching_to_blake3_verifies() {
+    let store = Arc::new(InMemoryStore::new());
+    let profile_repo = Arc::new(InMemoryHashingProfileRepository::new(store.clone()));
+    let shared = create_shared(store.clone()).with_hashing_profile_repo(profile_repo.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared.clone());
+    let post = |opr: u8| PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(10))
+        .credit(credit.clone(), BigDecimal::from(10))
+        .build();
+
+    let before = service.new_posting(post(1)).await.unwrap();
+    HashingProfileServiceImpl::new(shared.clone(), profile_repo)
+        .save_hashing_profile(HashingProfile {
+            ledger: debit.ledger.clone(),
+            excluded_fields: vec![],
+            algorithm: HashAlgorithm::Blake3,
+            updated: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let after = service.new_posting(post(3)).await.unwrap();
+
+    assert_eq!(before.hash_record.algorithm, HashAlgorithm::Sha256);
+    assert_eq!(after.hash_record.algorithm, HashAlgorithm::Blake3);
+    // Multihash code of blake3 with a 32 byte digest
+    assert_eq!(&after.hash_record.hash.unwrap()[..2], &[0x1e, 32]);
+    let verification = HashChainVerifierImpl::new(shared).verify_chain(debit.ledger.clone()).await.unwrap();
+    assert!(verification.is_intact());
+    assert_eq!(verification.verified, 2);
+}
+
+#[tokio::test]
+async fn test_unknown_hash_algorithm_is_rejected() {
+    let store = Arc::new(InMemoryStore::new());
+    let profile_repo = Arc::new(InMemoryHashingProfileRepository::new(store.clone()));
+    let shared = create_shared(store.clone()).with_hashing_profile_repo(profile_repo.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    profile_repo
+        .save(HashingProfileModel {
+            ledger_id: debit.ledger.id,
+            excluded_fields: String::new(),
+            hash_algorithm: "SHA2_512".to_string(),
+            updated: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let service = PostingServiceImpl::new(shared);
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit, BigDecimal::from(10))
+        .credit(credit, BigDecimal::from(10))
+        .build();
+
+    let result = service.new_posting(posting).await;
+
+    assert!(matches!(result, Err(ServiceError::UnknownHashAlgorithm { name }) if name == "SHA2_512"));
+}
+
+#[tokio::test]
+async fn test_closing_posting_follows_hashing_profile() {
+    let store = Arc::new(InMemoryStore::new());
+    let profile_repo = Arc::new(InMemoryHashingProfileRepository::new(store.clone()));
+    let shared = create_shared(store.clone()).with_hashing_profile_repo(profile_repo.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    HashingProfileServiceImpl::new(shared.clone(), profile_repo)
+        .save_hashing_profile(HashingProfile {
+            ledger: debit.ledger.clone(),
+            excluded_fields: vec![HashedField::OprDetails],
+            algorithm: HashAlgorithm::Blake3,
+            updated: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    // The chain still ends in a SHA-256 posting, the closing posting switches like any other
+    let stmts = AccountStmtServiceImpl::new(shared.clone());
+    let stmt = stmts.create_stmt(debit.clone(), Utc::now()).await.unwrap();
+    let closed = stmts.close_stmt(stmt).await.unwrap();
+    let closing = closed.financial_stmt.posting.unwrap();
+    assert_eq!(closing.hash_record.algorithm, HashAlgorithm::Blake3);
+    assert_eq!(closing.hash_record.excluded_fields, vec![HashedField::OprDetails]);
+    let verification = HashChainVerifierImpl::new(shared).verify_chain(debit.ledger.clone()).await.unwrap();
+    assert!(verification.is_intact());
+    assert_eq!(verification.verified, 2);
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use futures::FutureExt;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::posting_service::PostingService;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_type::PostingType;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db_inmemory::interleaving::{Explorer, InterleavedAccountStmtRepository, InterleavedPostingRepository, Scenario};
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+
+/// Services over the store whose posting and statement calls are scheduling points.
+fn create_shared(store: Arc<InMemoryStore>) -> SharedService {
+    SharedService::new(
+        Arc::new(InMemoryChartOfAccountRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerAccountRepository::new(store.clone())),
+        Arc::new(InMemoryNamedRepository::new(store.clone())),
+        Arc::new(InterleavedPostingRepository::new(Arc::new(InMemoryPostingRepository::new(store.clone())))),
+        Arc::new(InterleavedAccountStmtRepository::new(Arc::new(InMemoryAccountStmtRepository::new(store.clone())))),
+        Arc::new(InMemoryPostingLineRepository::new(store.clone())),
+        Arc::new(InMemoryPostingTraceRepository::new(store)),
+    )
+}
+
+/// Two accounts of a new ledger, the debit one with a line posted two days ago.
+async fn load_accounts(store: &Arc<InMemoryStore>, shared: &SharedService) -> (LedgerAccountBO, LedgerAccountBO) {
+    let (debit, credit) = common::load_accounts(store, shared).await;
+    let pst_time = Utc::now() - Duration::days(2);
+    InMemoryPostingLineRepository::new(store.clone()).save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id: debit.id,
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        pst_time,
+        record_time: pst_time,
+        ..Default::default()
+    }).await.unwrap();
+    (debit, credit)
+}
+
+/// Two postings recorded concurrently, to one ledger or to two. No two postings of a ledger
+/// may share an antecedent.
+async fn posting_scenario(same_ledger: bool) -> Scenario {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let first = load_accounts(&store, &shared).await;
+    let second = if same_ledger { first.clone() } else { load_accounts(&store, &shared).await };
+    let ledger_ids: HashSet<Uuid> = [first.0.ledger.id, second.0.ledger.id].into_iter().collect();
+    let service = Arc::new(PostingServiceImpl::new(shared));
+    let tasks = [(first, 1u8), (second, 3u8)]
+        .into_iter()
+        .map(|((debit, credit), opr)| {
+            let service = service.clone();
+            async move {
+                let posting = PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+                    .debit(debit, BigDecimal::from(10))
+                    .credit(credit, BigDecimal::from(10))
+                    .build();
+                service.new_posting(posting).await.unwrap();
+            }
+            .boxed_local()
+        })
+        .collect();
+    let check = async move {
+        let posting_repo = InMemoryPostingRepository::new(store);
+        for ledger_id in ledger_ids {
+            let mut antecedents = HashSet::new();
+            for posting in posting_repo.find_by_ledger_id(ledger_id).await.map_err(|e| e.to_string())? {
+                if !antecedents.insert(posting.antecedent_id) {
+                    return Err(format!("Chain of ledger {ledger_id} forks after {:?}", posting.antecedent_id));
+                }
+            }
+        }
+        Ok(())
+    }
+    .boxed_local();
+    Scenario { tasks, check }
+}
+
+/// The same statement closed twice concurrently. It must end up closed by exactly one closing posting.
+async fn close_scenario() -> Scenario {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (account, _) = load_accounts(&store, &shared).await;
+    let service = Arc::new(AccountStmtServiceImpl::new(shared));
+    let stmt = service.create_stmt(account.clone(), Utc::now() - Duration::days(1)).await.unwrap();
+    let stmt_id = stmt.financial_stmt.id;
+    let tasks = (0..2)
+        .map(|_| {
+            let (service, stmt) = (service.clone(), stmt.clone());
+            async move {
+                // One of the two is expected to fail
+                let _ = service.close_stmt(stmt).await;
+            }
+            .boxed_local()
+        })
+        .collect();
+    let check = async move {
+        let stored = InMemoryAccountStmtRepository::new(store.clone())
+            .find_by_id(stmt_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("Statement is gone")?;
+        let closing_ids: Vec<Uuid> = InMemoryPostingRepository::new(store)
+            .find_by_ledger_id(account.ledger.id)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|posting| posting.pst_type == PostingType::BalStmt)
+            .map(|posting| posting.id)
+            .collect();
+        if stored.stmt_status != StmtStatus::Closed || closing_ids.len() != 1 || stored.posting_id != closing_ids.first().copied() {
+            return Err(format!("Statement {:?} with closing postings {closing_ids:?}", stored.posting_id));
+        }
+        Ok(())
+    }
+    .boxed_local();
+    Scenario { tasks, check }
+}
+
+#[test]
+fn test_postings_of_different_ledgers_keep_their_chains() {
+    let runs = Explorer::new().explore(|| posting_scenario(false)).unwrap();
+    assert!(runs > 1);
+}
+
+// Known race: chain heads are read and extended in separate repository calls, so two postings of
+// one ledger can pick the same antecedent. Enable once the head is claimed atomically, e.g. by a
+// unique antecedent per ledger checked on insert.
+#[test]
+#[ignore = "postings of one ledger can still fork its chain, see the note above"]
+fn test_postings_of_one_ledger_keep_a_single_chain() {
+    let runs = Explorer::new().explore(|| posting_scenario(true)).unwrap();
+    assert!(runs > 1);
+}
+
+#[test]
+fn test_concurrent_closes_close_a_statement_once() {
+    let runs = Explorer::new().explore(close_scenario).unwrap();
+    assert!(runs > 1);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::ledger_account_name::LedgerAccountName;
+use postings_api::service::ledger_account_name_service::LedgerAccountNameService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::ledger_account_name_repository::InMemoryLedgerAccountNameRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::ledger_account_name_service::LedgerAccountNameServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+fn name(account: &LedgerAccountBO, number: Option<&str>, name: &str, valid_from: DateTime<Utc>) -> LedgerAccountName {
+    LedgerAccountName {
+        id: Uuid::nil(),
+        account: account.clone(),
+        number: number.map(str::to_string),
+        name: name.to_string(),
+        valid_from,
+        valid_to: None,
+        created: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_by_number_name_and_label() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (cash, other) = load_accounts(&store, &shared).await;
+    let names = LedgerAccountNameServiceImpl::new(shared, Arc::new(InMemoryLedgerAccountNameRepository::new(store)));
+    let start = Utc::now() - Duration::days(30);
+    let now = Utc::now();
+
+    let registered = names.register_name(name(&cash, Some(" 1.1.2.3 "), "Cash", start)).await.unwrap();
+    assert_eq!(registered.number.as_deref(), Some("1.1.2.3"));
+    for label in ["Cash", "1.1.2.3", "1.1.2.3 Cash"] {
+        assert_eq!(names.resolve(cash.ledger.clone(), label, now).await.unwrap().map(|a| a.id), Some(cash.id));
+    }
+    assert!(names.resolve(cash.ledger.clone(), "1.1.2.3 Bank", now).await.unwrap().is_none());
+    assert!(names.resolve(cash.ledger.clone(), "Cash", start - Duration::days(1)).await.unwrap().is_none());
+
+    // Numbers and names identify one account at a time
+    let taken = names.register_name(name(&other, Some("1.1.2.3"), "Bank", now)).await;
+    assert!(matches!(taken, Err(ServiceError::AccountNameTaken)));
+    let taken = names.register_name(name(&other, None, "Cash", now)).await;
+    assert!(matches!(taken, Err(ServiceError::AccountNameTaken)));
+}
+
+#[tokio::test]
+async fn test_name_moves_to_other_account_after_validity_ends() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (old_cash, new_cash) = load_accounts(&store, &shared).await;
+    let names = LedgerAccountNameServiceImpl::new(shared, Arc::new(InMemoryLedgerAccountNameRepository::new(store)));
+    let start = Utc::now() - Duration::days(30);
+    let moved = Utc::now() - Duration::days(10);
+
+    let old = names.register_name(name(&old_cash, None, "Cash", start)).await.unwrap();
+    names.end_name(old.id, moved).await.unwrap();
+    names.register_name(name(&new_cash, None, "Cash", moved)).await.unwrap();
+
+    let before = names.resolve(old_cash.ledger.clone(), "Cash", moved - Duration::seconds(1)).await.unwrap();
+    assert_eq!(before.map(|a| a.id), Some(old_cash.id));
+    let after = names.resolve(old_cash.ledger.clone(), "Cash", moved).await.unwrap();
+    assert_eq!(after.map(|a| a.id), Some(new_cash.id));
+    assert_eq!(names.find_names(old_cash.clone()).await.unwrap()[0].valid_to, Some(moved));
+
+    // The old name cannot be extended into the validity of the new one
+    assert!(matches!(names.end_name(old.id, moved + Duration::days(1)).await, Err(ServiceError::AccountNameTaken)));
+    assert!(matches!(names.end_name(Uuid::new_v4(), moved).await, Err(ServiceError::AccountNameNotFound)));
+}
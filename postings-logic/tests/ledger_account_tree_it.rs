@@ -0,0 +1,90 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::service::ledger_account_service::LedgerAccountService;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::ledger_account_service::LedgerAccountServiceImpl;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn save_account(repo: &InMemoryLedgerAccountRepository, ledger: &Ledger, parent_id: Option<Uuid>) -> Uuid {
+    let id = Uuid::new_v4();
+    repo.save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id,
+        coa_id: ledger.coa_id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    id
+}
+
+async fn save_line(repo: &InMemoryPostingLineRepository, account_id: Uuid, debit: i64, credit: i64, days_ago: i64) {
+    let pst_time = Utc::now() - Duration::days(days_ago);
+    repo.save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id,
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        pst_time,
+        record_time: pst_time,
+        ..Default::default()
+    }).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_tree_rolls_up_descendant_balances() {
+    let store = Arc::new(InMemoryStore::new());
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let account_repo = InMemoryLedgerAccountRepository::new(store.clone());
+    let line_repo = InMemoryPostingLineRepository::new(store.clone());
+
+    let root = save_account(&account_repo, &ledger, None).await;
+    let child = save_account(&account_repo, &ledger, Some(root)).await;
+    let grandchild = save_account(&account_repo, &ledger, Some(child)).await;
+    let sibling = save_account(&account_repo, &ledger, Some(root)).await;
+    let outside = save_account(&account_repo, &ledger, None).await;
+    save_line(&line_repo, root, 1, 0, 2).await;
+    save_line(&line_repo, grandchild, 100, 0, 2).await;
+    save_line(&line_repo, sibling, 0, 40, 2).await;
+    save_line(&line_repo, outside, 1000, 0, 2).await;
+    // After the reference time
+    save_line(&line_repo, grandchild, 7, 0, -1).await;
+
+    let service = LedgerAccountServiceImpl::new(create_shared(store));
+
+    let mut descendants: Vec<Uuid> = service.find_descendants(root).await.unwrap().iter().map(|a| a.id).collect();
+    descendants.sort();
+    let mut expected = vec![child, grandchild, sibling];
+    expected.sort();
+    assert_eq!(descendants, expected);
+
+    let tree = service.tree(root, Utc::now()).await.unwrap();
+    assert_eq!(tree.size(), 4);
+    assert_eq!(tree.balance.debit_balance(), BigDecimal::from(1));
+    assert_eq!(tree.debit_balance(), BigDecimal::from(61));
+    let child_node = tree.children.iter().find(|n| n.balance.account.id == child).unwrap();
+    assert_eq!(child_node.total_debit, BigDecimal::from(100));
+    assert_eq!(child_node.children[0].balance.account.parent.as_ref().unwrap().id, child);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
+use postings_api::domain::api_key::ApiRole;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::service::ledger_closure_service::LedgerClosureService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::ledger_closure_repository::InMemoryLedgerClosureRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::ledger_closure_service::LedgerClosureServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{api_key, create_shared, load_accounts};
+
+fn posting(debit: &LedgerAccountBO, credit: &LedgerAccountBO, opr: u8, pst_time: DateTime<Utc>, pst_type: PostingType) -> Posting {
+    PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], pst_time)
+        .pst_type(pst_type)
+        .debit(debit.clone(), BigDecimal::from(100))
+        .credit(credit.clone(), BigDecimal::from(100))
+        .build()
+}
+
+fn controller() -> PrivilegedContext {
+    PrivilegedContext::new(&api_key("controller", vec![ApiRole::Admin]), "Month-end close").unwrap()
+}
+
+#[tokio::test]
+async fn test_closed_ledger_rejects_back_dated_postings() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let closure_repo = Arc::new(InMemoryLedgerClosureRepository::new(store));
+    let closures = LedgerClosureServiceImpl::new(shared.clone(), closure_repo.clone());
+    let postings = PostingServiceImpl::new(shared).with_closure_repo(closure_repo);
+    let cut_off = Utc::now() - Duration::days(1);
+
+    let closure = closures.close_ledger(debit.ledger.clone(), cut_off, true, controller()).await.unwrap();
+    assert_eq!(closure.closed_by, "controller");
+    assert_eq!(closures.find_closures(debit.ledger.clone()).await.unwrap(), vec![closure]);
+
+    let back_dated = posting(&debit, &credit, 1, cut_off - Duration::hours(1), PostingType::BusiTx);
+    assert!(matches!(postings.new_posting(back_dated.clone()).await, Err(ServiceError::PeriodClosed)));
+    assert!(matches!(postings.new_postings(vec![back_dated]).await, Err(ServiceError::PeriodClosed)));
+    postings.new_posting(posting(&debit, &credit, 2, cut_off, PostingType::BusiTx)).await.unwrap();
+
+    // Privileged adjustments pass a closure that allows them, but only as adjustments
+    let adjustment = posting(&debit, &credit, 3, cut_off - Duration::hours(1), PostingType::AdjTx);
+    assert!(matches!(postings.new_posting(adjustment.clone()).await, Err(ServiceError::PeriodClosed)));
+    let wrong_type = posting(&debit, &credit, 4, cut_off - Duration::hours(1), PostingType::BusiTx);
+    assert!(matches!(postings.new_adjustment_posting(wrong_type, controller()).await, Err(ServiceError::NotEnoughInfo)));
+    let clerk = PrivilegedContext::new(&api_key("clerk", vec![ApiRole::Post]), "Month-end close").unwrap();
+    assert!(matches!(postings.new_adjustment_posting(adjustment.clone(), clerk).await, Err(ServiceError::Forbidden)));
+    let recorded = postings.new_adjustment_posting(adjustment, controller()).await.unwrap();
+    assert_eq!(recorded.pst_type, PostingType::AdjTx);
+}
+
+#[tokio::test]
+async fn test_closed_account_and_final_closure() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let (other_debit, other_credit) = load_accounts(&store, &shared).await;
+    let closure_repo = Arc::new(InMemoryLedgerClosureRepository::new(store));
+    let closures = LedgerClosureServiceImpl::new(shared.clone(), closure_repo.clone());
+    let postings = PostingServiceImpl::new(shared).with_closure_repo(closure_repo);
+    let cut_off = Utc::now() - Duration::days(1);
+    let before = cut_off - Duration::hours(1);
+
+    closures.close_account(debit.clone(), cut_off, false, controller()).await.unwrap();
+    // Closures of other ledgers and accounts do not apply
+    postings.new_posting(posting(&other_debit, &other_credit, 1, before, PostingType::BusiTx)).await.unwrap();
+    assert!(matches!(postings.new_posting(posting(&debit, &credit, 2, before, PostingType::BusiTx)).await, Err(ServiceError::PeriodClosed)));
+    assert!(matches!(postings.new_adjustment_posting(posting(&debit, &credit, 3, before, PostingType::AdjTx), controller()).await, Err(ServiceError::PeriodClosed)));
+
+    // Reversals booked into the closed period are rejected as well
+    let recorded = postings.new_posting(posting(&debit, &credit, 4, Utc::now(), PostingType::BusiTx)).await.unwrap();
+    assert!(matches!(postings.reverse_posting(recorded.id, before).await, Err(ServiceError::PeriodClosed)));
+    postings.reverse_posting(recorded.id, Utc::now()).await.unwrap();
+
+    let anonymous = PrivilegedContext::new(&api_key("", vec![ApiRole::Admin]), "Month-end close").unwrap();
+    assert!(matches!(closures.close_ledger(debit.ledger.clone(), cut_off, false, anonymous).await, Err(ServiceError::NotEnoughInfo)));
+}
@@ -0,0 +1,242 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::ledger_integrity::HierarchyBreak;
+use postings_api::service::ledger_integrity_service::LedgerIntegrityService;
+use postings_api::service::posting_service::PostingService;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::stmt_repair_repository::InMemoryStmtRepairRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
+use postings_logic::services::ledger_integrity_service::LedgerIntegrityServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use postings_logic::services::stmt_repair_service::StmtRepairServiceImpl;
+use uuid::Uuid;
+use common::create_shared;
+
+fn create_service(store: Arc<InMemoryStore>) -> LedgerIntegrityServiceImpl {
+    let shared = create_shared(store.clone());
+    LedgerIntegrityServiceImpl::new(
+        shared.clone(),
+        Arc::new(HashChainVerifierImpl::new(shared.clone())),
+        Arc::new(StmtRepairServiceImpl::new(shared, Arc::new(InMemoryStmtRepairRepository::new(store)))),
+    )
+}
+
+fn create_account(ledger: &Ledger, parent_id: Option<Uuid>, balance_side: BalanceSide) -> LedgerAccount {
+    LedgerAccount {
+        id: Uuid::new_v4(),
+        ledger_id: ledger.id,
+        parent_id,
+        coa_id: ledger.coa_id,
+        balance_side,
+        category: AccountCategory::AS,
+        currency: None,
+    }
+}
+
+/// A new ledger with a posting between two of its accounts.
+async fn load_ledger(store: &Arc<InMemoryStore>) -> (Ledger, LedgerAccountBO) {
+    let shared = create_shared(store.clone());
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let account_repo = InMemoryLedgerAccountRepository::new(store.clone());
+    let debit = create_account(&ledger, None, BalanceSide::Dr);
+    let credit = create_account(&ledger, Some(debit.id), BalanceSide::Cr);
+    account_repo.save(&debit).await.unwrap();
+    account_repo.save(&credit).await.unwrap();
+    let debit = shared.load_ledger_account_bo(debit.id).await.unwrap();
+    let credit = shared.load_ledger_account_bo(credit.id).await.unwrap();
+
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(100))
+        .credit(credit, BigDecimal::from(100))
+        .build();
+    PostingServiceImpl::new(shared).new_posting(posting).await.unwrap();
+    (ledger, debit)
+}
+
+#[tokio::test]
+async fn test_consistent_ledger_is_intact() {
+    let store = Arc::new(InMemoryStore::new());
+    let (ledger, _) = load_ledger(&store).await;
+
+    let report = create_service(store).verify_ledger(ledger.id).await.unwrap();
+
+    assert!(report.is_intact(), "{report:?}");
+    assert_eq!(report.chain.verified, 1);
+}
+
+#[tokio::test]
+async fn test_findings_of_all_checks_are_reported() {
+    let store = Arc::new(InMemoryStore::new());
+    let (ledger, debit) = load_ledger(&store).await;
+    let account_repo = InMemoryLedgerAccountRepository::new(store.clone());
+    let first = create_account(&ledger, None, BalanceSide::Dr);
+    let second = create_account(&ledger, Some(first.id), BalanceSide::Dr);
+    account_repo.save(&LedgerAccount { parent_id: Some(second.id), ..first.clone() }).await.unwrap();
+    account_repo.save(&second).await.unwrap();
+    let orphan = create_account(&ledger, Some(Uuid::new_v4()), BalanceSide::Dr);
+    account_repo.save(&orphan).await.unwrap();
+
+    // Written past the posting service: a single line, outside the hash chain
+    let now = Utc::now();
+    let unbalanced = Posting {
+        id: Uuid::new_v4(),
+        record_user: [0; 34],
+        record_time: now,
+        opr_id: [3; 34],
+        opr_time: now,
+        opr_type: [0; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time: now,
+        pst_type: Default::default(),
+        pst_status: Default::default(),
+        ledger_id: ledger.id,
+        val_time: None,
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        antecedent_id: None,
+        antecedent_hash: None,
+        hash: None,
+        hash_excluded_fields: None,
+        hash_version: 2,
+        hash_algorithm: "SHA2_256".to_string(),
+    };
+    let line = PostingLine {
+        id: Uuid::new_v4(),
+        opr_id: unbalanced.opr_id,
+        account_id: debit.id,
+        debit_amount: BigDecimal::from(5),
+        credit_amount: BigDecimal::from(0),
+        pst_time: now,
+        ..Default::default()
+    };
+    InMemoryPostingRepository::new(store.clone()).save_batch(&[(unbalanced.clone(), vec![line])]).await.unwrap();
+
+    let report = create_service(store).verify_ledger(ledger.id).await.unwrap();
+
+    assert!(!report.is_intact());
+    assert!(!report.chain.is_intact());
+    let mut cyclic: Vec<Uuid> = report.hierarchy_issues.iter()
+        .filter(|i| i.reason == HierarchyBreak::Cycle)
+        .map(|i| i.account_id)
+        .collect();
+    cyclic.sort();
+    let mut expected = vec![first.id, second.id];
+    expected.sort();
+    assert_eq!(cyclic, expected);
+    assert!(report.hierarchy_issues.iter().any(|i| i.account_id == orphan.id && i.reason == HierarchyBreak::MissingParent));
+    assert_eq!(report.unbalanced_postings.len(), 1);
+    assert_eq!(report.unbalanced_postings[0].posting_id, unbalanced.id);
+}
+
+#[tokio::test]
+async fn test_ledger_is_intact_with_fields_not_stored() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (ledger, debit) = load_ledger(&store).await;
+    let credit = create_account(&ledger, Some(debit.id), BalanceSide::Cr);
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&credit).await.unwrap();
+    // Submitted without its parent chain, read back with it
+    let credit = LedgerAccountBO { parent: None, ..shared.load_ledger_account_bo(credit.id).await.unwrap() };
+    let mut posting = PostingBuilder::new(debit.ledger.clone(), [4; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(30))
+        .credit(credit, BigDecimal::from(30))
+        .build();
+    // Not stored with the line
+    posting.lines[0].additional_information = Some("fee".to_string());
+    PostingServiceImpl::new(shared).new_posting(posting).await.unwrap();
+
+    let report = create_service(store).verify_ledger(ledger.id).await.unwrap();
+
+    assert!(report.is_intact(), "{report:?}");
+    assert_eq!(report.chain.verified, 2);
+}
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use super::*;
+    use sqlx::PgPool;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::stmt_repair_repository::PostgresStmtRepairRepository;
+
+    fn create_shared(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool)),
+        )
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_recorded_ledger_is_intact(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        let shared = create_shared(pool.clone());
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        PostgresChartOfAccountRepository::new(pool.clone()).save(&coa).await?;
+        let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+        PostgresLedgerRepository::new(pool.clone()).save(&ledger).await?;
+        let debit = create_account(&ledger, None, BalanceSide::Dr);
+        let credit = create_account(&ledger, Some(debit.id), BalanceSide::Cr);
+        PostgresLedgerAccountRepository::new(pool.clone()).save(&debit).await?;
+        PostgresLedgerAccountRepository::new(pool.clone()).save(&credit).await?;
+        let debit = shared.load_ledger_account_bo(debit.id).await?;
+        let credit = shared.load_ledger_account_bo(credit.id).await?;
+        let service = PostingServiceImpl::new(shared.clone());
+        for (opr_id, amount) in [([1; 34], 100), ([2; 34], 45)] {
+            let posting = PostingBuilder::new(debit.ledger.clone(), opr_id, [2; 34], Utc::now())
+                .debit(debit.clone(), BigDecimal::from(amount))
+                .credit(credit.clone(), BigDecimal::from(amount))
+                .build();
+            service.new_posting(posting).await?;
+        }
+
+        let report = LedgerIntegrityServiceImpl::new(
+            shared.clone(),
+            Arc::new(HashChainVerifierImpl::new(shared.clone())),
+            Arc::new(StmtRepairServiceImpl::new(shared, Arc::new(PostgresStmtRepairRepository::new(pool)))),
+        )
+        .verify_ledger(ledger.id)
+        .await?;
+
+        assert!(report.is_intact(), "{report:?}");
+        assert_eq!(report.chain.verified, 2);
+        Ok(())
+    }
+}
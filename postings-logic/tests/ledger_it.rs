@@ -79,6 +79,7 @@ mod postgres_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            currency: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
             .bind(ledger_account.id)
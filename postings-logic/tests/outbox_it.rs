@@ -0,0 +1,67 @@
+mod common;
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::domain_event::DomainEvent;
+use postings_api::service::event_publisher::EventPublisher;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db_inmemory::repositories::outbox_repository::InMemoryOutboxRepository;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::events::broadcast::BroadcastEventPublisher;
+use postings_logic::events::outbox_relay::OutboxRelay;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use common::load_accounts;
+
+fn create_shared(store: Arc<InMemoryStore>) -> SharedService {
+    common::create_shared(store.clone())
+        .with_unit_of_work(Arc::new(InMemoryUnitOfWorkRepository::new(store.clone())))
+        .with_outbox(Arc::new(InMemoryOutboxRepository::new(store)))
+}
+
+struct FailingPublisher;
+
+#[async_trait]
+impl EventPublisher for FailingPublisher {
+    async fn publish(&self, _event: &DomainEvent) -> Result<(), ServiceError> {
+        Err(ServiceError::EventPublication)
+    }
+}
+
+#[tokio::test]
+async fn test_events_are_relayed_from_the_outbox() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared);
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit, BigDecimal::from(100))
+        .credit(credit, BigDecimal::from(100))
+        .build();
+    let recorded = service.new_posting(posting).await.unwrap();
+    let reversal = service.reverse_posting(recorded.id, Utc::now()).await.unwrap();
+
+    let outbox_repo = Arc::new(InMemoryOutboxRepository::new(store));
+    let pending = outbox_repo.find_undelivered(10).await.unwrap();
+    assert_eq!(pending.iter().map(|e| e.event_type.as_str()).collect::<Vec<_>>(), vec!["PostingCreated", "PostingReversed"]);
+
+    // A failed delivery is recorded and stops the batch
+    let failing = OutboxRelay::new(outbox_repo.clone(), Arc::new(FailingPublisher));
+    assert!(matches!(failing.relay_once().await, Err(ServiceError::EventPublication)));
+    let pending = outbox_repo.find_undelivered(10).await.unwrap();
+    assert_eq!((pending[0].attempts, pending[1].attempts), (1, 0));
+
+    let publisher = Arc::new(BroadcastEventPublisher::new(16));
+    let mut events = publisher.subscribe();
+    let relay = OutboxRelay::new(outbox_repo.clone(), publisher);
+    assert_eq!(relay.relay_once().await.unwrap(), 2);
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+    assert_eq!(events.recv().await.unwrap(), DomainEvent::PostingCreated(recorded.clone()));
+    assert_eq!(events.recv().await.unwrap(), DomainEvent::PostingReversed { reversed_id: recorded.id, reversal });
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, TimeZone, Utc};
+use postings_api::service::position_service::PositionService;
+use postings_api::service::posting_service::PostingService;
+use postings_db::models::holiday::Holiday;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::holiday_repository::InMemoryHolidayRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::position_service::PositionServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_ladder_ignores_discarded_lines() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let pst_time = Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap();
+    PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], pst_time)
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    // Line of a discarded version of another posting
+    InMemoryPostingLineRepository::new(store.clone())
+        .save(PostingLine {
+            id: Uuid::new_v4(),
+            account_id: debit.id,
+            debit_amount: BigDecimal::from(100),
+            opr_id: [3; 34],
+            pst_time,
+            record_time: pst_time,
+            pst_status: PostingStatus::Posted,
+            discarded_time: Some(Utc::now()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let ladder = PositionServiceImpl::new(shared)
+        .position_ladder(debit, NaiveDate::from_ymd_opt(2025, 3, 2).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 4).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(ladder.len(), 3);
+    assert_eq!(ladder[0].booked_debit, BigDecimal::from(0));
+    for position in &ladder[1..] {
+        assert_eq!(position.booked_debit, BigDecimal::from(10));
+        assert_eq!(position.pending_debit, BigDecimal::from(0));
+    }
+}
+
+#[tokio::test]
+async fn test_lines_take_value_on_the_following_business_day() {
+    let store = Arc::new(InMemoryStore::new());
+    let holiday_repo = Arc::new(InMemoryHolidayRepository::new(store.clone()));
+    let shared = create_shared(store.clone()).with_holiday_repo(holiday_repo.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    // Posted on Saturday 2025-03-08, with Monday a holiday
+    holiday_repo
+        .save(Holiday { ledger_id: debit.ledger.id, holiday_date: NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), name: "Holiday".to_string() })
+        .await
+        .unwrap();
+    PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 8, 10, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+
+    let ladder = PositionServiceImpl::new(shared)
+        .position_ladder(debit, NaiveDate::from_ymd_opt(2025, 3, 8).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 11).unwrap())
+        .await
+        .unwrap();
+
+    let booked: Vec<BigDecimal> = ladder.into_iter().map(|p| p.booked_debit).collect();
+    assert_eq!(booked, vec![BigDecimal::from(0), BigDecimal::from(0), BigDecimal::from(0), BigDecimal::from(10)]);
+}
+
+#[tokio::test]
+async fn test_lines_are_bucketed_by_value_time() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting_service = PostingServiceImpl::new(shared.clone());
+    // Forward valued: posted on the 3rd, takes value on the 5th
+    posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap())
+            .val_time(Utc.with_ymd_and_hms(2025, 3, 5, 0, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit.clone(), BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    // Back valued: posted on the 4th, takes value on the 3rd
+    posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [3; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 4, 10, 0, 0).unwrap())
+            .val_time(Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(5))
+            .credit(credit, BigDecimal::from(5))
+            .build())
+        .await
+        .unwrap();
+
+    let ladder = PositionServiceImpl::new(shared)
+        .position_ladder(debit, NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 5).unwrap())
+        .await
+        .unwrap();
+
+    let booked: Vec<BigDecimal> = ladder.into_iter().map(|p| p.booked_debit).collect();
+    assert_eq!(booked, vec![BigDecimal::from(5), BigDecimal::from(5), BigDecimal::from(15)]);
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_unbalanced_posting_reports_imbalance() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared);
+
+    let posting = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit, BigDecimal::from(100))
+        .credit(credit, BigDecimal::from(90))
+        .build();
+    let result = service.new_posting(posting).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::DoubledEntryViolation { currency: None, imbalance }) if imbalance == BigDecimal::from(10)
+    ));
+}
+
+#[tokio::test]
+async fn test_memo_ledger_accepts_unbalanced_posting() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, _) = load_accounts(&store, &shared).await;
+    InMemoryLedgerRepository::new(store.clone()).set_memo(debit.ledger.id, true).await.unwrap();
+    let service = PostingServiceImpl::new(shared);
+
+    let memo = PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+        .debit(debit, BigDecimal::from(100))
+        .build();
+    let recorded = service.new_posting(memo).await.unwrap();
+    assert!(recorded.hash_record.hash.is_some());
+}
@@ -25,6 +25,8 @@ mod postgres_tests {
     use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
     use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
     use postings_db::repositories::posting_line_repository::PostingLineRepository;
+    use postings_api::service::hash_chain_verifier::HashChainVerifier;
+    use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
 
     #[derive(Type)]
     #[sqlx(type_name = "balance_side")]
@@ -55,6 +57,7 @@ mod postgres_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            currency: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
             .bind(ledger_account.id)
@@ -108,6 +111,10 @@ mod postgres_tests {
     }
 
     fn create_service(pool: PgPool) -> PostingServiceImpl {
+        PostingServiceImpl::new(create_shared_service(pool))
+    }
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
         let posting_repo = Arc::new(PostgresPostingRepository::new(pool.clone()));
         let ledger_repo = Arc::new(PostgresLedgerRepository::new(pool.clone()));
         let coa_repo = Arc::new(PostgresChartOfAccountRepository::new(pool.clone()));
@@ -117,7 +124,7 @@ mod postgres_tests {
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
 
-        let shared_service = SharedService::new(
+        SharedService::new(
             coa_repo,
             ledger_repo,
             ledger_account_repo,
@@ -126,8 +133,7 @@ mod postgres_tests {
             stmt_repo,
             line_repo,
             trace_repo,
-        );
-        PostingServiceImpl::new(shared_service)
+        )
     }
 
     struct TestContext {
@@ -202,6 +208,9 @@ mod postgres_tests {
                     hash: Some([0; 34]),
                     additional_information: None,
                     discarded_time: None,
+                    category: None,
+                    currency: None,
+                    fx: None,
                 },
                 PostingLine {
                     id: Uuid::new_v4(),
@@ -221,6 +230,9 @@ mod postgres_tests {
                     hash: Some([0; 34]),
                     additional_information: None,
                     discarded_time: None,
+                    category: None,
+                    currency: None,
+                    fx: None,
                 }
             ],
             discarded_id: None,
@@ -247,6 +259,31 @@ mod postgres_tests {
         
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_recorded_chain_verifies(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let mut first = create_test_posting(&pool, ledger.clone(), 100, 100).await?;
+        // Not stored, so left out of the hash
+        first.lines[0].additional_information = Some("fee".to_string());
+        let mut second = create_test_posting(&pool, ledger.clone(), 250, 250).await?;
+        second.opr_id = [9; 34];
+
+        // Act
+        service.new_posting(first).await?;
+        let second = service.new_posting(second).await?;
+        let verification = HashChainVerifierImpl::new(create_shared_service(pool.clone())).verify_chain(ledger).await?;
+
+        // Assert
+        assert_eq!(verification.verified, 2);
+        assert_eq!(verification.last_verified_id, Some(second.id));
+        assert!(verification.broken_link.is_none());
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "mariadb_tests")]
@@ -275,6 +312,9 @@ mod mariadb_tests {
     use postings_db_mariadb::repositories::posting_trace_repository::MariaDbPostingTraceRepository;
     use postings_db_mariadb::repositories::named_repository::MariaDbNamedRepository;
     use postings_db::repositories::posting_line_repository::PostingLineRepository;
+    use postings_api::domain::hash_record::HashVersion;
+    use postings_api::service::hash_chain_verifier::HashChainVerifier;
+    use postings_logic::services::hash_chain_verifier::HashChainVerifierImpl;
 
     async fn setup_ledger_account(pool: &MySqlPool, ledger: &Ledger, name: &str, category: AccountCategory, balance_side: BalanceSide, parent: Option<&LedgerAccount>) -> anyhow::Result<LedgerAccount> {
         let ledger_account_id = Uuid::new_v4();
@@ -285,6 +325,7 @@ mod mariadb_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            currency: None,
         };
         
         // Insert into simplified ledger_account table
@@ -391,6 +432,10 @@ mod mariadb_tests {
     }
 
     fn create_service(pool: MySqlPool) -> PostingServiceImpl {
+        PostingServiceImpl::new(create_shared_service(pool))
+    }
+
+    fn create_shared_service(pool: MySqlPool) -> SharedService {
         let posting_repo = Arc::new(MariaDbPostingRepository::new(pool.clone()));
         let ledger_repo = Arc::new(MariaDbLedgerRepository::new(pool.clone()));
         let coa_repo = Arc::new(MariaDbChartOfAccountRepository::new(pool.clone()));
@@ -400,7 +445,7 @@ mod mariadb_tests {
         let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
 
-        let shared_service = SharedService::new(
+        SharedService::new(
             coa_repo,
             ledger_repo,
             ledger_account_repo,
@@ -409,8 +454,7 @@ mod mariadb_tests {
             stmt_repo,
             line_repo,
             trace_repo,
-        );
-        PostingServiceImpl::new(shared_service)
+        )
     }
 
     struct TestContext {
@@ -485,6 +529,9 @@ mod mariadb_tests {
                     hash: Some([1; 34]),
                     additional_information: None,
                     discarded_time: None,
+                    category: None,
+                    currency: None,
+                    fx: None,
                 },
                 PostingLine {
                     id: Uuid::new_v4(),
@@ -504,6 +551,9 @@ mod mariadb_tests {
                     hash: Some([2; 34]),
                     additional_information: None,
                     discarded_time: None,
+                    category: None,
+                    currency: None,
+                    fx: None,
                 }
             ],
             discarded_id: None,
@@ -543,7 +593,7 @@ mod mariadb_tests {
         let result = service.new_posting(posting_bo).await;
 
         // Assert
-        assert!(matches!(result, Err(ServiceError::DoubleEntry)));
+        assert!(matches!(result, Err(ServiceError::DoubledEntryViolation { imbalance, .. }) if imbalance == BigDecimal::from(1)));
 
         Ok(())
     }
@@ -580,6 +630,33 @@ mod mariadb_tests {
         Ok(())
     }
 
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_new_posting_hash_verifies_with_lines_in_any_order(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let mut posting_bo = create_test_posting(&pool, ledger.clone(), 100, 100).await?;
+        // Submitted in descending id order, read back in whatever order the database returns
+        posting_bo.lines.sort_by_key(|l| std::cmp::Reverse(l.id));
+        let mut next_bo = create_test_posting(&pool, ledger.clone(), 250, 250).await?;
+        next_bo.opr_id = [9; 34];
+        next_bo.lines.sort_by_key(|l| std::cmp::Reverse(l.id));
+
+        // Act
+        service.new_posting(posting_bo).await?;
+        let result = service.new_posting(next_bo).await?;
+        let verification = HashChainVerifierImpl::new(create_shared_service(pool.clone())).verify_chain(ledger).await?;
+
+        // Assert
+        assert_eq!(result.hash_record.version, HashVersion::CURRENT);
+        assert_eq!(verification.verified, 2);
+        assert_eq!(verification.last_verified_id, Some(result.id));
+        assert!(verification.broken_link.is_none());
+
+        Ok(())
+    }
+
     #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
     async fn test_new_posting_stores_lines(pool: MySqlPool) -> anyhow::Result<()> {
         dotenvy::from_filename(".env.mariadb").ok();
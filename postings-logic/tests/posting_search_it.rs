@@ -0,0 +1,106 @@
+mod common;
+
+use std::sync::Arc;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use postings_api::domain::posting_search::{PostingSearchFilter, PostingSort};
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::service::posting_search_service::PostingSearchService;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus as PostingStatusModel;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::posting_search_service::PostingSearchServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn create_ledger(shared: &SharedService) -> Uuid {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    shared.coa_repo.save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    shared.ledger_repo.save(&ledger).await.unwrap();
+    ledger.id
+}
+
+/// Saves a posting of the operation with one line on the account.
+async fn create_posting(shared: &SharedService, ledger_id: Uuid, account_id: Uuid, opr: u8, pst_time: DateTime<Utc>, pst_status: PostingStatusModel) -> Posting {
+    let posting = Posting {
+        id: Uuid::new_v4(),
+        record_user: [0; 34],
+        record_time: Utc::now(),
+        opr_id: [opr; 34],
+        opr_time: pst_time,
+        opr_type: [opr % 2; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time,
+        pst_type: Default::default(),
+        pst_status,
+        ledger_id,
+        val_time: None,
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        antecedent_id: None,
+        antecedent_hash: None,
+        hash: None,
+        hash_excluded_fields: None,
+        hash_version: 2,
+        hash_algorithm: "SHA2_256".to_string(),
+    };
+    shared.posting_repo.save(&posting).await.unwrap();
+    shared.line_repo.save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id,
+        opr_id: posting.opr_id,
+        pst_time,
+        record_time: posting.record_time,
+        ..Default::default()
+    }).await.unwrap();
+    posting
+}
+
+#[tokio::test]
+async fn test_search_combines_filters() {
+    let shared = create_shared(Arc::new(InMemoryStore::new()));
+    let ledger_a = create_ledger(&shared).await;
+    let ledger_b = create_ledger(&shared).await;
+    let account = Uuid::new_v4();
+    let start = Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap();
+    let first = create_posting(&shared, ledger_a, account, 1, start, PostingStatusModel::Posted).await;
+    let second = create_posting(&shared, ledger_a, Uuid::new_v4(), 2, start + Duration::days(1), PostingStatusModel::Posted).await;
+    let third = create_posting(&shared, ledger_a, account, 3, start + Duration::days(2), PostingStatusModel::Proposed).await;
+    create_posting(&shared, ledger_b, account, 4, start, PostingStatusModel::Posted).await;
+    let service = PostingSearchServiceImpl::new(shared);
+
+    let all = service.search(PostingSearchFilter::new(0, 10)).await.unwrap();
+    assert_eq!(all.total_elements, 4);
+
+    let by_opr_id = service.search(PostingSearchFilter { opr_id: Some(second.opr_id), ..PostingSearchFilter::new(0, 10) }).await.unwrap();
+    assert_eq!(by_opr_id.content.iter().map(|p| p.id).collect::<Vec<_>>(), vec![second.id]);
+    assert!(by_opr_id.content[0].lines.is_empty());
+
+    let filter = PostingSearchFilter {
+        ledger_id: Some(ledger_a),
+        account_id: Some(account),
+        opr_type: Some([1; 34]),
+        pst_time_from: Some(start),
+        pst_time_to: Some(start + Duration::days(2)),
+        sort: PostingSort::PstTimeDesc,
+        ..PostingSearchFilter::new(0, 10)
+    };
+    let found = service.search(filter.clone()).await.unwrap();
+    assert_eq!(found.content.iter().map(|p| p.id).collect::<Vec<_>>(), vec![third.id, first.id]);
+    assert!(found.content.iter().all(|p| p.ledger.id == ledger_a));
+
+    let posted = service.search(PostingSearchFilter { pst_status: Some(PostingStatus::Posted), ..filter.clone() }).await.unwrap();
+    assert_eq!(posted.content.iter().map(|p| p.id).collect::<Vec<_>>(), vec![first.id]);
+
+    let second_page = service.search(PostingSearchFilter { page: 1, size: 1, ..filter }).await.unwrap();
+    assert_eq!(second_page.total_elements, 2);
+    assert_eq!(second_page.content.iter().map(|p| p.id).collect::<Vec<_>>(), vec![first.id]);
+}
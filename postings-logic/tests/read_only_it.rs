@@ -0,0 +1,269 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDate, Utc};
+use postings_api::domain::account_limit::AccountLimit;
+use postings_api::domain::batch_status::BatchStatus;
+use postings_api::domain::earmark::{Earmark, EarmarkStatus};
+use postings_api::domain::escrow::{Escrow, EscrowStatus, EscrowTimeoutAction};
+use postings_api::domain::fee_schedule::{FeeSchedule, FeeType};
+use postings_api::domain::holiday::Holiday;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::product::Product;
+use postings_api::domain::scheduled_posting::{ScheduledLine, ScheduledPosting, ScheduledPostingStatus};
+use postings_api::domain::settlement_batch::SettlementBatch;
+use postings_api::domain::standing_order::{Frequency, StandingOrder, StandingOrderStatus};
+use postings_api::service::account_limit_service::AccountLimitService;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::calendar_service::CalendarService;
+use postings_api::service::earmark_service::EarmarkService;
+use postings_api::service::eod_service::EodService;
+use postings_api::service::escrow_service::EscrowService;
+use postings_api::service::fee_schedule_service::FeeScheduleService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::product_service::ProductService;
+use postings_api::service::scheduled_posting_service::ScheduledPostingService;
+use postings_api::service::settlement_batch_service::SettlementBatchService;
+use postings_api::service::standing_order_service::StandingOrderService;
+use postings_api::ServiceError;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db_inmemory::repositories::account_limit_repository::InMemoryAccountLimitRepository;
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::earmark_repository::InMemoryEarmarkRepository;
+use postings_db_inmemory::repositories::eod_run_repository::InMemoryEodRunRepository;
+use postings_db_inmemory::repositories::escrow_repository::InMemoryEscrowRepository;
+use postings_db_inmemory::repositories::fee_schedule_repository::InMemoryFeeScheduleRepository;
+use postings_db_inmemory::repositories::holiday_repository::InMemoryHolidayRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::product_repository::InMemoryProductRepository;
+use postings_db_inmemory::repositories::scheduled_posting_repository::InMemoryScheduledPostingRepository;
+use postings_db_inmemory::repositories::settlement_batch_repository::InMemorySettlementBatchRepository;
+use postings_db_inmemory::repositories::standing_order_repository::InMemoryStandingOrderRepository;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_limit_service::AccountLimitServiceImpl;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::calendar_service::CalendarServiceImpl;
+use postings_logic::services::earmark_service::EarmarkServiceImpl;
+use postings_logic::services::eod_service::EodServiceImpl;
+use postings_logic::services::escrow_service::EscrowServiceImpl;
+use postings_logic::services::fee_schedule_service::FeeScheduleServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::product_service::ProductServiceImpl;
+use postings_logic::services::scheduled_posting_service::ScheduledPostingServiceImpl;
+use postings_logic::services::settlement_batch_service::SettlementBatchServiceImpl;
+use postings_logic::services::standing_order_service::StandingOrderServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_read_only_ledger_rejects_postings_and_statement_closing() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let postings = PostingServiceImpl::new(shared.clone());
+    let stmts = AccountStmtServiceImpl::new(shared.clone());
+    let post = |opr: u8| PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(10))
+        .credit(credit.clone(), BigDecimal::from(10))
+        .build();
+    postings.new_posting(post(1)).await.unwrap();
+    let stmt = stmts.create_stmt(debit.clone(), Utc::now()).await.unwrap();
+    let ledger_repo = InMemoryLedgerRepository::new(store.clone());
+
+    ledger_repo.set_read_only(debit.ledger.id, true).await.unwrap();
+    assert!(matches!(postings.new_posting(post(3)).await, Err(ServiceError::ReadOnly)));
+    assert!(matches!(postings.new_postings(vec![post(4)]).await, Err(ServiceError::ReadOnly)));
+    assert!(matches!(stmts.create_stmt(debit.clone(), Utc::now()).await, Err(ServiceError::ReadOnly)));
+    assert!(matches!(stmts.close_stmt(stmt.clone()).await, Err(ServiceError::ReadOnly)));
+    assert!(postings.find_postings_by_operation_id(&[3; 34]).await.unwrap().is_empty());
+    let unchanged = InMemoryAccountStmtRepository::new(store.clone()).find_by_id(stmt.financial_stmt.id).await.unwrap().unwrap();
+    assert_eq!(unchanged.stmt_status, StmtStatus::Simulated);
+
+    // Writable again, the statement closes
+    ledger_repo.set_read_only(debit.ledger.id, false).await.unwrap();
+    stmts.close_stmt(stmt).await.unwrap();
+    postings.new_posting(post(3)).await.unwrap();
+}
+
+fn is_read_only<T: std::fmt::Debug>(result: Result<T, ServiceError>) -> bool {
+    matches!(result, Err(ServiceError::ReadOnly))
+}
+
+#[tokio::test]
+async fn test_read_only_ledger_rejects_changes_through_every_service() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone()).with_unit_of_work(Arc::new(InMemoryUnitOfWorkRepository::new(store.clone())));
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let ledger = debit.ledger.clone();
+    let postings = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let posting = postings
+        .new_posting(PostingBuilder::new(ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(100))
+            .credit(credit.clone(), BigDecimal::from(100))
+            .build())
+        .await
+        .unwrap();
+    let earmarks = Arc::new(EarmarkServiceImpl::new(shared.clone(), Arc::new(InMemoryEarmarkRepository::new(store.clone())), postings.clone()));
+    let batches = SettlementBatchServiceImpl::new(shared.clone(), Arc::new(InMemorySettlementBatchRepository::new(store.clone())));
+    let limits = AccountLimitServiceImpl::new(shared.clone(), Arc::new(InMemoryAccountLimitRepository::new(store.clone())));
+    let calendar = CalendarServiceImpl::new(shared.clone(), Arc::new(InMemoryHolidayRepository::new(store.clone())));
+    let orders = StandingOrderServiceImpl::new(shared.clone(), Arc::new(InMemoryStandingOrderRepository::new(store.clone())), postings.clone(), earmarks.clone());
+    let escrows = EscrowServiceImpl::new(shared.clone(), Arc::new(InMemoryEscrowRepository::new(store.clone())), postings.clone());
+    let schedules = ScheduledPostingServiceImpl::new(shared.clone(), Arc::new(InMemoryScheduledPostingRepository::new(store.clone())), postings.clone());
+    let fees = FeeScheduleServiceImpl::new(shared.clone(), Arc::new(InMemoryFeeScheduleRepository::new(store.clone())));
+    let products = ProductServiceImpl::new(shared.clone(), Arc::new(InMemoryProductRepository::new(store.clone())), Arc::new(InMemoryFeeScheduleRepository::new(store.clone())));
+    let eod = EodServiceImpl::new(shared.clone(), Arc::new(InMemoryEodRunRepository::new(store.clone())));
+
+    let earmark = Earmark {
+        id: Uuid::nil(),
+        account: credit.clone(),
+        amount: BigDecimal::from(10),
+        captured_amount: BigDecimal::from(0),
+        reason: "hold".to_string(),
+        created: Utc::now(),
+        expiry: None,
+        status: EarmarkStatus::Active,
+        closed_time: None,
+        posting_id: None,
+    };
+    let batch = SettlementBatch {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        batch_ref: [5; 34],
+        control_amount: BigDecimal::from(100),
+        control_count: None,
+        status: BatchStatus::Open,
+        created: Utc::now(),
+        closed_time: None,
+    };
+    let order = StandingOrder {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        source_account: credit.clone(),
+        target_account: debit.clone(),
+        amount: BigDecimal::from(10),
+        opr_type: [2; 34],
+        frequency: Frequency::Daily,
+        start_date: Utc::now().date_naive(),
+        end_date: None,
+        max_executions: None,
+        max_retries: 0,
+        next_execution_date: Utc::now().date_naive(),
+        occurrence: 0,
+        execution_count: 0,
+        status: StandingOrderStatus::Active,
+        created: Utc::now(),
+    };
+    let escrow = Escrow {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        source_account: debit.clone(),
+        escrow_account: credit.clone(),
+        target_account: debit.clone(),
+        amount: BigDecimal::from(10),
+        opr_type: [2; 34],
+        status: EscrowStatus::Pending,
+        timeout: Utc::now() + Duration::days(1),
+        timeout_action: EscrowTimeoutAction::Return,
+        initiation_posting_id: None,
+        resolution_posting_id: None,
+        created: Utc::now(),
+        resolved_time: None,
+    };
+    let schedule = ScheduledPosting {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        opr_type: [2; 34],
+        pst_type: PostingType::BusiTx,
+        lines: vec![
+            ScheduledLine { account: debit.clone(), debit_amount: BigDecimal::from(10), credit_amount: BigDecimal::from(0), details: None },
+            ScheduledLine { account: credit.clone(), debit_amount: BigDecimal::from(0), credit_amount: BigDecimal::from(10), details: None },
+        ],
+        frequency: Some(Frequency::Daily),
+        start_time: Utc::now() + Duration::days(1),
+        end_time: None,
+        max_occurrences: None,
+        next_pst_time: Utc::now() + Duration::days(1),
+        occurrence: 0,
+        status: ScheduledPostingStatus::Active,
+        created: Utc::now(),
+    };
+    let product = Product {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        name: "Current account".to_string(),
+        interest_rate: None,
+        day_count_convention: None,
+        created: Utc::now(),
+    };
+    let holiday = Holiday { ledger: ledger.clone(), date: NaiveDate::from_ymd_opt(2025, 12, 25).unwrap(), name: "Christmas".to_string() };
+
+    // Created while the ledger is writable
+    let open_earmark = earmarks.create_earmark(earmark.clone()).await.unwrap();
+    let open_batch = batches.open_batch(batch.clone()).await.unwrap();
+    let closed_batch = batches.open_batch(SettlementBatch { batch_ref: [6; 34], ..batch.clone() }).await.unwrap();
+    batches.close_batch(closed_batch.id).await.unwrap();
+    let active_order = orders.create_standing_order(order.clone()).await.unwrap();
+    let pending_escrow = escrows.initiate_escrow(escrow.clone()).await.unwrap();
+    let active_schedule = schedules.create_schedule(schedule.clone()).await.unwrap();
+    let saved_product = products.save_product(product.clone()).await.unwrap();
+    calendar.add_holiday(holiday.clone()).await.unwrap();
+
+    InMemoryLedgerRepository::new(store.clone()).set_read_only(ledger.id, true).await.unwrap();
+
+    assert!(is_read_only(earmarks.create_earmark(earmark).await));
+    assert!(is_read_only(earmarks.increase_earmark(open_earmark.id, BigDecimal::from(5), None).await));
+    assert!(is_read_only(earmarks.capture_earmark(open_earmark.id, BigDecimal::from(5), debit.clone()).await));
+    assert!(is_read_only(earmarks.consume_earmark(open_earmark.id, debit.clone()).await));
+    assert!(is_read_only(earmarks.release_earmark(open_earmark.id).await));
+    assert!(is_read_only(batches.open_batch(SettlementBatch { batch_ref: [7; 34], ..batch }).await));
+    assert!(is_read_only(batches.assign_posting(open_batch.id, &posting).await));
+    assert!(is_read_only(batches.close_batch(open_batch.id).await));
+    assert!(is_read_only(batches.settle_batch(closed_batch.id).await));
+    assert!(is_read_only(batches.reject_batch(open_batch.id).await));
+    assert!(is_read_only(limits.set_limit(AccountLimit {
+        account: debit.clone(),
+        max_debit_balance: Some(BigDecimal::from(1000)),
+        overdraft_limit: None,
+        daily_turnover_limit: None,
+        updated: Utc::now(),
+    }).await));
+    assert!(is_read_only(calendar.add_holiday(Holiday { date: NaiveDate::from_ymd_opt(2025, 12, 26).unwrap(), ..holiday }).await));
+    assert!(is_read_only(calendar.remove_holiday(ledger.clone(), NaiveDate::from_ymd_opt(2025, 12, 25).unwrap()).await));
+    assert!(is_read_only(orders.create_standing_order(order).await));
+    assert!(is_read_only(orders.cancel_standing_order(active_order.id).await));
+    assert!(is_read_only(escrows.initiate_escrow(escrow).await));
+    assert!(is_read_only(escrows.confirm_escrow(pending_escrow.id).await));
+    assert!(is_read_only(escrows.cancel_escrow(pending_escrow.id).await));
+    assert!(is_read_only(schedules.create_schedule(schedule).await));
+    assert!(is_read_only(schedules.cancel_schedule(active_schedule.id).await));
+    assert!(is_read_only(fees.save_fee_schedule(FeeSchedule {
+        id: Uuid::nil(),
+        ledger: ledger.clone(),
+        opr_type: [2; 34],
+        fee_type: FeeType::Flat,
+        flat_amount: Some(BigDecimal::from(1)),
+        rate: None,
+        tiers: Vec::new(),
+        min_fee: None,
+        max_fee: None,
+        fee_account: credit.clone(),
+        product_id: None,
+        created: Utc::now(),
+    }).await));
+    assert!(is_read_only(products.save_product(product).await));
+    assert!(is_read_only(products.link_account(debit.clone(), saved_product.id).await));
+    assert!(is_read_only(eod.run_eod(ledger.clone(), Utc::now().date_naive()).await));
+
+    // Nothing changed
+    let unchanged = earmarks.find_earmark_by_id(open_earmark.id).await.unwrap().unwrap();
+    assert_eq!((unchanged.status, unchanged.amount), (EarmarkStatus::Active, BigDecimal::from(10)));
+    assert_eq!(batches.find_batch_by_id(open_batch.id).await.unwrap().unwrap().status, BatchStatus::Open);
+    assert_eq!(escrows.find_escrow_by_id(pending_escrow.id).await.unwrap().unwrap().status, EscrowStatus::Pending);
+    assert_eq!(calendar.find_holidays(ledger, NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()).await.unwrap().len(), 1);
+}
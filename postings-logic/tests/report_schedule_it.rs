@@ -0,0 +1,131 @@
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, TimeZone, Utc};
+use postings_api::domain::report_schedule::{ReportFormat, ReportFrequency, ReportSchedule, ReportScheduleStatus, ReportType};
+use postings_api::service::object_store::ObjectStore;
+use postings_api::service::report_schedule_service::ReportScheduleService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::report_schedule_repository::InMemoryReportScheduleRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::report_schedule_service::ReportScheduleServiceImpl;
+use postings_logic::services::trial_balance_service::TrialBalanceServiceImpl;
+use uuid::Uuid;
+use common::create_shared;
+
+#[derive(Default)]
+struct MapObjectStore {
+    objects: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+#[async_trait]
+impl ObjectStore for MapObjectStore {
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<(), ServiceError> {
+        self.objects.lock().unwrap().insert(key.to_string(), content);
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ServiceError> {
+        Ok(self.objects.lock().unwrap().get(key).cloned())
+    }
+}
+
+async fn save_account(store: &Arc<InMemoryStore>, ledger: &Ledger, category: AccountCategory) -> Uuid {
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: ledger.coa_id,
+        balance_side: BalanceSide::Dr,
+        category,
+        currency: None,
+    }).await.unwrap();
+    id
+}
+
+#[tokio::test]
+async fn test_month_end_trial_balances_are_stored_as_versions() {
+    let store = Arc::new(InMemoryStore::new());
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let cash = save_account(&store, &ledger, AccountCategory::AS).await;
+    let deposits = save_account(&store, &ledger, AccountCategory::LI).await;
+    let line_repo = InMemoryPostingLineRepository::new(store.clone());
+    let pst_time = Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap();
+    for (account_id, debit, credit) in [(cash, 100, 0), (deposits, 0, 100)] {
+        line_repo.save(PostingLine {
+            id: Uuid::new_v4(),
+            account_id,
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            pst_time,
+            record_time: pst_time,
+            ..Default::default()
+        }).await.unwrap();
+    }
+
+    let shared = create_shared(store.clone());
+    let service = ReportScheduleServiceImpl::new(
+        shared.clone(),
+        Arc::new(InMemoryReportScheduleRepository::new(store.clone())),
+        Arc::new(MapObjectStore::default()),
+        Arc::new(TrialBalanceServiceImpl::new(shared.clone())),
+    );
+    let ledger_bo = shared.load_ledger_bo(ledger.id).await.unwrap();
+    let schedule = service.create_schedule(ReportSchedule {
+        id: Uuid::new_v4(),
+        ledger: ledger_bo.clone(),
+        report_type: ReportType::TrialBalance,
+        format: ReportFormat::Csv,
+        frequency: ReportFrequency::MonthEnd,
+        next_ref_time: Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
+        status: ReportScheduleStatus::Active,
+        created: Utc::now(),
+    }).await.unwrap();
+    let end_of_january = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap() - Duration::microseconds(1);
+    assert_eq!(schedule.next_ref_time, end_of_january);
+
+    // January and February have ended
+    let artifacts = service.run_due(Utc.with_ymd_and_hms(2025, 3, 5, 0, 0, 0).unwrap()).await.unwrap();
+    assert_eq!(artifacts.len(), 2);
+    assert_eq!(artifacts[0].ref_time, end_of_january);
+    let stored = service.find_schedule_by_id(schedule.id).await.unwrap().unwrap();
+    assert_eq!(stored.next_ref_time, Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap() - Duration::microseconds(1));
+    assert!(service.run_due(Utc.with_ymd_and_hms(2025, 3, 6, 0, 0, 0).unwrap()).await.unwrap().is_empty());
+
+    let (artifact, content) = service.load_artifact(artifacts[0].id).await.unwrap();
+    assert_eq!(artifact.version, 1);
+    let csv = String::from_utf8(content).unwrap();
+    assert!(csv.contains(&format!("AS,{cash},100,0")));
+    assert!(csv.ends_with("TOTAL,,100,100\n"));
+
+    // Regenerated on request, e.g. after a back-dated posting
+    let regenerated = service.generate(ledger_bo.clone(), ReportType::TrialBalance, ReportFormat::Csv, end_of_january).await.unwrap();
+    assert_eq!(regenerated.version, 2);
+    assert_eq!(regenerated.schedule_id, None);
+    let versions = service.find_artifacts(ledger.id, ReportType::TrialBalance, end_of_january).await.unwrap();
+    assert_eq!(versions.iter().map(|a| a.version).collect::<Vec<_>>(), vec![1, 2]);
+
+    let pdf = service.generate(ledger_bo, ReportType::TrialBalance, ReportFormat::Pdf, end_of_january).await;
+    assert!(matches!(pdf, Err(ServiceError::ReportRendererNotConfigured)));
+}
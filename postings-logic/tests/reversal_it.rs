@@ -0,0 +1,48 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{TimeZone, Utc};
+use postings_api::domain::api_key::{ApiKey, ApiRole};
+use postings_api::domain::reversal_policy::{ReversalContext, ReversalMode};
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::reversal_service::ReversalService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::reversal_policy_repository::InMemoryReversalPolicyRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::reversal_service::ReversalServiceImpl;
+use uuid::Uuid;
+use common::{api_key, create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_reversal_role_follows_the_callers_key() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let posting = posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit, BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    let service = ReversalServiceImpl::new(shared, Arc::new(InMemoryReversalPolicyRepository::new(store)), posting_service);
+    // Past the same-day teller window
+    let reversal_time = Utc.with_ymd_and_hms(2025, 3, 5, 10, 0, 0).unwrap();
+
+    let teller = ReversalContext::new(&api_key("teller-desk", vec![ApiRole::Post]), "Duplicate").unwrap();
+    let denied = service.reverse(posting.id, reversal_time, teller).await;
+    assert!(matches!(denied, Err(ServiceError::Forbidden)));
+
+    let other_ledger = ReversalContext::new(&ApiKey { ledger_id: Some(Uuid::new_v4()), ..api_key("back-office", vec![ApiRole::Admin]) }, "Duplicate").unwrap();
+    let denied = service.reverse(posting.id, reversal_time, other_ledger).await;
+    assert!(matches!(denied, Err(ServiceError::Forbidden)));
+
+    let supervisor = ReversalContext::new(&ApiKey { ledger_id: Some(debit.ledger.id), ..api_key("back-office", vec![ApiRole::Admin]) }, "Duplicate").unwrap();
+    let reversal = service.reverse(posting.id, reversal_time, supervisor).await.unwrap();
+    assert_eq!(reversal.mode, ReversalMode::Correction);
+    assert_eq!(reversal.posting.pst_time, reversal_time);
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::scheduled_posting::{ScheduledLine, ScheduledPosting, ScheduledPostingStatus};
+use postings_api::domain::standing_order::Frequency;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::scheduled_posting_service::ScheduledPostingService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::scheduled_posting_repository::InMemoryScheduledPostingRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::scheduled_posting_service::ScheduledPostingServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+fn accrual(debit: &LedgerAccountBO, credit: &LedgerAccountBO, amount: i32) -> ScheduledPosting {
+    let start_time = Utc::now() - Duration::days(70);
+    ScheduledPosting {
+        id: Uuid::nil(),
+        ledger: debit.ledger.clone(),
+        opr_type: [7; 34],
+        pst_type: PostingType::BusiTx,
+        lines: vec![
+            ScheduledLine { account: debit.clone(), debit_amount: BigDecimal::from(amount), credit_amount: BigDecimal::from(0), details: Some("Interest accrual".to_string()) },
+            ScheduledLine { account: credit.clone(), debit_amount: BigDecimal::from(0), credit_amount: BigDecimal::from(amount), details: None },
+        ],
+        frequency: Some(Frequency::Monthly),
+        start_time,
+        end_time: None,
+        max_occurrences: None,
+        next_pst_time: start_time,
+        occurrence: 0,
+        status: ScheduledPostingStatus::Active,
+        created: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_due_occurrences_are_booked_in_posting_time_order() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let postings = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let schedules = ScheduledPostingServiceImpl::new(shared, Arc::new(InMemoryScheduledPostingRepository::new(store)), postings.clone());
+
+    let mut deferral = accrual(&debit, &credit, 30);
+    deferral.max_occurrences = Some(2);
+    let deferral = schedules.create_schedule(deferral).await.unwrap();
+    let mut future_dated = accrual(&debit, &credit, 50);
+    future_dated.frequency = None;
+    future_dated.start_time = Utc::now() + Duration::days(5);
+    let future_dated = schedules.create_schedule(future_dated).await.unwrap();
+    let monthly = schedules.create_schedule(accrual(&debit, &credit, 10)).await.unwrap();
+
+    let booked = schedules.run_due(Utc::now()).await.unwrap();
+    // Both occurrences of the deferral and three months of the accrual, not the future-dated posting
+    assert_eq!(booked.len(), 5);
+    assert!(booked.windows(2).all(|w| w[0].pst_time <= w[1].pst_time));
+    assert!(booked.windows(2).all(|w| w[1].hash_record.antecedent_id == Some(w[0].id)));
+    assert_eq!(booked[0].lines[0].details.as_deref(), Some("Interest accrual"));
+    assert_eq!(schedules.find_schedule_by_id(deferral.id).await.unwrap().unwrap().status, ScheduledPostingStatus::Completed);
+    assert_eq!(schedules.find_schedule_by_id(monthly.id).await.unwrap().unwrap().occurrence, 3);
+
+    // Nothing is booked twice
+    assert!(schedules.run_due(Utc::now()).await.unwrap().is_empty());
+    let booked = schedules.run_due(Utc::now() + Duration::days(6)).await.unwrap();
+    assert_eq!(booked.len(), 1);
+    assert_eq!(booked[0].pst_time, future_dated.start_time);
+    let future_dated = schedules.find_schedule_by_id(future_dated.id).await.unwrap().unwrap();
+    assert_eq!(future_dated.status, ScheduledPostingStatus::Completed);
+    let recorded = postings.find_postings_by_operation_id(&booked[0].opr_id).await.unwrap();
+    assert_eq!(recorded.len(), 1);
+}
+
+#[tokio::test]
+async fn test_unbalanced_and_cancelled_schedules() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let postings = Arc::new(PostingServiceImpl::new(shared.clone()));
+    let schedules = ScheduledPostingServiceImpl::new(shared, Arc::new(InMemoryScheduledPostingRepository::new(store)), postings);
+
+    let mut unbalanced = accrual(&debit, &credit, 10);
+    unbalanced.lines[1].credit_amount = BigDecimal::from(9);
+    assert!(matches!(schedules.create_schedule(unbalanced).await, Err(ServiceError::DoubledEntryViolation { .. })));
+
+    let schedule = schedules.create_schedule(accrual(&debit, &credit, 10)).await.unwrap();
+    schedules.cancel_schedule(schedule.id).await.unwrap();
+    assert!(matches!(schedules.cancel_schedule(schedule.id).await, Err(ServiceError::ScheduledPostingNotActive)));
+    assert!(schedules.run_due(Utc::now()).await.unwrap().is_empty());
+    assert!(schedules.find_schedule_by_id(Uuid::new_v4()).await.unwrap().is_none());
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use futures::StreamExt;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::unit_of_work::UnitOfWork;
+use postings_db::DbError;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::scoping::LedgerScope;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+/// A ledger with one account holding one line; returns the ledger and account ids.
+async fn create_ledger_with_line(shared: &SharedService) -> (Uuid, Uuid) {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    shared.coa_repo.save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    shared.ledger_repo.save(&ledger).await.unwrap();
+    let account_id = Uuid::new_v4();
+    shared.ledger_account_repo.save(&LedgerAccount {
+        id: account_id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    shared.line_repo.save(PostingLine { id: Uuid::new_v4(), account_id, pst_time: Utc::now(), ..Default::default() }).await.unwrap();
+    (ledger.id, account_id)
+}
+
+#[tokio::test]
+async fn test_scoped_repositories_hide_other_ledgers() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store);
+    let (ledger_a, account_a) = create_ledger_with_line(&shared).await;
+    let (ledger_b, account_b) = create_ledger_with_line(&shared).await;
+    let scoped = shared.scoped(&LedgerScope::ledger(ledger_a));
+    let now = Utc::now();
+
+    // In scope
+    assert!(scoped.load_ledger(ledger_a).await.is_ok());
+    assert!(scoped.ledger_account_repo.find_by_id(account_a).await.unwrap().is_some());
+    assert_eq!(scoped.line_repo.find_by_account_and_pst_time_less_than_equal(account_a, now).await.unwrap().len(), 1);
+    assert_eq!(scoped.line_repo.stream_by_account_and_pst_time_less_than_equal(account_a, now, LineOrder::PostingTime).count().await, 1);
+
+    // Out of scope reads as missing
+    assert!(matches!(scoped.load_ledger(ledger_b).await, Err(ServiceError::LedgerNotFound)));
+    assert!(scoped.ledger_account_repo.find_by_id(account_b).await.unwrap().is_none());
+    assert!(scoped.ledger_account_repo.find_by_ledger_id(ledger_b).await.unwrap().is_empty());
+    assert!(scoped.line_repo.find_by_account_and_pst_time_less_than_equal(account_b, now).await.unwrap().is_empty());
+    assert_eq!(scoped.line_repo.stream_by_account_and_pst_time_less_than_equal(account_b, now, LineOrder::PostingTime).count().await, 0);
+    assert!(matches!(scoped.ledger_repo.set_read_only(ledger_b, true).await, Err(DbError::NotFound)));
+
+    // Out of scope writes fail
+    let line = PostingLine { id: Uuid::new_v4(), account_id: account_b, pst_time: now, ..Default::default() };
+    assert!(matches!(scoped.line_repo.save(line).await, Err(DbError::OutOfScope)));
+    let mut moved = shared.ledger_account_repo.find_by_id(account_b).await.unwrap().unwrap();
+    moved.ledger_id = ledger_a;
+    assert!(matches!(scoped.ledger_account_repo.save(&moved).await, Err(DbError::OutOfScope)));
+
+    // The unscoped service still sees both ledgers
+    assert_eq!(shared.line_repo.find_by_account_and_pst_time_less_than_equal(account_b, now).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_tenant_scope_covers_its_ledgers() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store);
+    let (ledger_a, _) = create_ledger_with_line(&shared).await;
+    let (ledger_b, account_b) = create_ledger_with_line(&shared).await;
+    let (ledger_c, _) = create_ledger_with_line(&shared).await;
+    let scoped = shared.scoped(&LedgerScope::ledgers([ledger_a, ledger_b]));
+
+    assert!(scoped.load_ledger(ledger_b).await.is_ok());
+    assert!(scoped.ledger_account_repo.find_by_id(account_b).await.unwrap().is_some());
+    assert!(matches!(scoped.load_ledger(ledger_c).await, Err(ServiceError::LedgerNotFound)));
+}
+
+#[tokio::test]
+async fn test_scoped_services_stay_in_scope() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone()).with_unit_of_work(Arc::new(InMemoryUnitOfWorkRepository::new(store.clone())));
+    let (debit_a, credit_a) = load_accounts(&store, &shared).await;
+    let (debit_b, credit_b) = load_accounts(&store, &shared).await;
+    let scoped = shared.scoped(&LedgerScope::ledger(debit_a.ledger.id));
+    let postings = PostingServiceImpl::scoped(scoped.clone());
+    let stmts = AccountStmtServiceImpl::scoped(scoped.clone());
+    let post = |debit: &LedgerAccountBO, credit: &LedgerAccountBO, opr: u8| {
+        PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit.clone(), BigDecimal::from(10))
+            .build()
+    };
+
+    postings.new_posting(post(&debit_a, &credit_a, 1)).await.unwrap();
+    assert!(postings.new_posting(post(&debit_b, &credit_b, 3)).await.is_err());
+    let closed = stmts.close_stmt(stmts.create_stmt(debit_a.clone(), Utc::now()).await.unwrap()).await.unwrap();
+    assert!(scoped.stmt_repo.find_by_id(closed.financial_stmt.id).await.unwrap().is_some());
+    assert!(stmts.create_stmt(debit_b.clone(), Utc::now()).await.is_err());
+
+    // Statements of other ledgers read as missing and units of work cannot write them
+    PostingServiceImpl::new(shared.clone()).new_posting(post(&debit_b, &credit_b, 3)).await.unwrap();
+    let unscoped = AccountStmtServiceImpl::new(shared.clone());
+    let other = unscoped.close_stmt(unscoped.create_stmt(debit_b, Utc::now()).await.unwrap()).await.unwrap();
+    assert!(scoped.stmt_repo.find_by_id(other.financial_stmt.id).await.unwrap().is_none());
+    let stored = shared.stmt_repo.find_by_id(other.financial_stmt.id).await.unwrap().unwrap();
+    let mut work = UnitOfWork::new();
+    work.save_account_stmt(stored);
+    assert!(matches!(scoped.uow_repo.as_ref().unwrap().commit(work).await, Err(DbError::OutOfScope)));
+}
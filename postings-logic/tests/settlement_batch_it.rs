@@ -0,0 +1,91 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::batch_status::BatchStatus;
+use postings_api::domain::settlement_batch::SettlementBatch;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::settlement_batch_service::SettlementBatchService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::settlement_batch_repository::InMemorySettlementBatchRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::settlement_batch_service::SettlementBatchServiceImpl;
+use uuid::Uuid;
+use common::{create_shared, load_accounts};
+
+#[tokio::test]
+async fn test_assigned_amount_comes_from_stored_lines() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting = PostingServiceImpl::new(shared.clone())
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit.clone(), BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    let service = SettlementBatchServiceImpl::new(shared, Arc::new(InMemorySettlementBatchRepository::new(store)));
+    let batch = service
+        .open_batch(SettlementBatch {
+            id: Uuid::nil(),
+            ledger: debit.ledger.clone(),
+            batch_ref: [3; 34],
+            control_amount: BigDecimal::from(10),
+            control_count: Some(1),
+            status: BatchStatus::Open,
+            created: Utc::now(),
+            closed_time: None,
+        })
+        .await
+        .unwrap();
+
+    // Lines passed in by the caller do not change the assigned amount
+    let mut inflated = posting.clone();
+    inflated.lines[0].debit_amount = BigDecimal::from(1000);
+    let totals = service.assign_posting(batch.id, &inflated).await.unwrap();
+    assert_eq!(totals.total_amount, BigDecimal::from(10));
+
+    let again = service.assign_posting(batch.id, &posting).await;
+    assert!(matches!(again, Err(ServiceError::PostingAlreadyBatched)));
+    assert_eq!(service.close_batch(batch.id).await.unwrap().status, BatchStatus::Closed);
+}
+
+#[tokio::test]
+async fn test_discarded_postings_and_reversals_are_not_batched() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting_service = PostingServiceImpl::new(shared.clone());
+    let posting = posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(10))
+            .credit(credit.clone(), BigDecimal::from(10))
+            .build())
+        .await
+        .unwrap();
+    let reversal = posting_service.reverse_posting(posting.id, Utc::now()).await.unwrap();
+    let service = SettlementBatchServiceImpl::new(shared, Arc::new(InMemorySettlementBatchRepository::new(store)));
+    let batch = service
+        .open_batch(SettlementBatch {
+            id: Uuid::nil(),
+            ledger: debit.ledger.clone(),
+            batch_ref: [3; 34],
+            control_amount: BigDecimal::from(10),
+            control_count: Some(1),
+            status: BatchStatus::Open,
+            created: Utc::now(),
+            closed_time: None,
+        })
+        .await
+        .unwrap();
+
+    let discarded = service.assign_posting(batch.id, &posting).await;
+    assert!(matches!(discarded, Err(ServiceError::PostingAlreadyDiscarded)));
+    let reversed = service.assign_posting(batch.id, &reversal).await;
+    assert!(matches!(reversed, Err(ServiceError::ReversalNotBatchable)));
+    assert_eq!(service.find_batch_by_id(batch.id).await.unwrap().unwrap().status, BatchStatus::Open);
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::posting_service::PostingService;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_filter::PostingFilter;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::page::PageRequest;
+use postings_db::DbError;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::repositories::unit_of_work_repository::UnitOfWorkRepository;
+use postings_db::sharding::account_stmt_repository::ShardedAccountStmtRepository;
+use postings_db::sharding::ledger_account_repository::ShardedLedgerAccountRepository;
+use postings_db::sharding::ledger_repository::ShardedLedgerRepository;
+use postings_db::sharding::posting_line_repository::ShardedPostingLineRepository;
+use postings_db::sharding::posting_repository::ShardedPostingRepository;
+use postings_db::sharding::posting_trace_repository::ShardedPostingTraceRepository;
+use postings_db::sharding::unit_of_work_repository::ShardedUnitOfWorkRepository;
+use postings_db::sharding::{RoutingTable, Shards};
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::repositories::unit_of_work_repository::InMemoryUnitOfWorkRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_db::unit_of_work::UnitOfWork;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use uuid::Uuid;
+
+fn shards<R: ?Sized>(table: &Arc<RoutingTable>, first: Arc<R>, second: Arc<R>) -> Shards<R> {
+    Shards::new(table.clone()).with_shard("first", first).with_shard("second", second)
+}
+
+/// Services over two in-memory shards, reference data in the first one.
+fn create_shared(table: Arc<RoutingTable>, first: Arc<InMemoryStore>, second: Arc<InMemoryStore>) -> SharedService {
+    let accounts: Arc<dyn LedgerAccountRepository + Send + Sync> = Arc::new(ShardedLedgerAccountRepository::new(
+        shards(&table, Arc::new(InMemoryLedgerAccountRepository::new(first.clone())), Arc::new(InMemoryLedgerAccountRepository::new(second.clone()))),
+    ));
+    SharedService::new(
+        Arc::new(InMemoryChartOfAccountRepository::new(first.clone())),
+        Arc::new(ShardedLedgerRepository::new(
+            shards(&table, Arc::new(InMemoryLedgerRepository::new(first.clone())), Arc::new(InMemoryLedgerRepository::new(second.clone()))),
+        )),
+        accounts.clone(),
+        Arc::new(InMemoryNamedRepository::new(first.clone())),
+        Arc::new(ShardedPostingRepository::new(
+            shards(&table, Arc::new(InMemoryPostingRepository::new(first.clone())), Arc::new(InMemoryPostingRepository::new(second.clone()))),
+        )),
+        Arc::new(ShardedAccountStmtRepository::new(
+            shards(&table, Arc::new(InMemoryAccountStmtRepository::new(first.clone())), Arc::new(InMemoryAccountStmtRepository::new(second.clone()))),
+            accounts.clone(),
+        )),
+        Arc::new(ShardedPostingLineRepository::new(
+            shards(&table, Arc::new(InMemoryPostingLineRepository::new(first.clone())), Arc::new(InMemoryPostingLineRepository::new(second.clone()))),
+            accounts.clone(),
+        )),
+        Arc::new(ShardedPostingTraceRepository::new(
+            shards(&table, Arc::new(InMemoryPostingTraceRepository::new(first)), Arc::new(InMemoryPostingTraceRepository::new(second))),
+            accounts,
+        )),
+    )
+}
+
+/// Debit and credit account of a new ledger.
+async fn create_accounts(shared: &SharedService, ledger_id: Uuid) -> (LedgerAccountBO, LedgerAccountBO) {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    shared.coa_repo.save(&coa).await.unwrap();
+    shared.ledger_repo.save(&Ledger { id: ledger_id, coa_id: coa.id, read_only: false, memo: false, closing_summary: false }).await.unwrap();
+    let mut accounts = Vec::new();
+    for balance_side in [BalanceSide::Dr, BalanceSide::Cr] {
+        let id = Uuid::new_v4();
+        shared.ledger_account_repo.save(&LedgerAccount {
+            id,
+            ledger_id,
+            parent_id: None,
+            coa_id: coa.id,
+            balance_side,
+            category: AccountCategory::AS,
+            currency: None,
+        }).await.unwrap();
+        accounts.push(shared.load_ledger_account_bo(id).await.unwrap());
+    }
+    let credit = accounts.pop().unwrap();
+    (accounts.pop().unwrap(), credit)
+}
+
+#[tokio::test]
+async fn test_ledgers_are_stored_in_their_shard() {
+    let first = Arc::new(InMemoryStore::new());
+    let second = Arc::new(InMemoryStore::new());
+    let moved = Uuid::new_v4();
+    let table = Arc::new(RoutingTable::new("first").with_ledger(moved, "second"));
+    let shared = create_shared(table, first.clone(), second.clone());
+    let service = PostingServiceImpl::new(shared.clone());
+
+    let mut accounts = Vec::new();
+    for (opr, ledger_id) in [(1, Uuid::new_v4()), (3, moved)] {
+        let (debit, credit) = create_accounts(&shared, ledger_id).await;
+        let posting = PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(25))
+            .credit(credit, BigDecimal::from(25))
+            .build();
+        service.new_posting(posting).await.unwrap();
+        accounts.push(debit);
+    }
+
+    let moved_account = &accounts[1];
+    assert!(InMemoryLedgerAccountRepository::new(second.clone()).find_by_id(moved_account.id).await.unwrap().is_some());
+    assert!(InMemoryLedgerAccountRepository::new(first.clone()).find_by_id(moved_account.id).await.unwrap().is_none());
+    assert_eq!(InMemoryPostingRepository::new(second).find_by_ledger_id(moved).await.unwrap().len(), 1);
+    assert!(InMemoryPostingRepository::new(first).find_by_ledger_id(moved).await.unwrap().is_empty());
+
+    // Services read across shards unchanged
+    for account in accounts {
+        let balance = shared.account_balance(account, Utc::now()).await.unwrap();
+        assert_eq!(balance.total_debit, BigDecimal::from(25));
+    }
+    let all = shared.posting_repo.find_by_filter_paged(&PostingFilter::default(), PageRequest::new(0, 1)).await.unwrap();
+    assert_eq!(all.total_elements, 2);
+    assert_eq!(all.content.len(), 1);
+}
+
+#[tokio::test]
+async fn test_stmt_is_closed_in_its_shard() {
+    let first = Arc::new(InMemoryStore::new());
+    let second = Arc::new(InMemoryStore::new());
+    let moved = Uuid::new_v4();
+    let table = Arc::new(RoutingTable::new("first").with_ledger(moved, "second"));
+    let shared = create_shared(table.clone(), first.clone(), second.clone());
+    let uow_repo = Arc::new(ShardedUnitOfWorkRepository::new(
+        shards(&table, Arc::new(InMemoryUnitOfWorkRepository::new(first.clone())), Arc::new(InMemoryUnitOfWorkRepository::new(second.clone()))),
+        shared.ledger_account_repo.clone(),
+    ));
+    let shared = shared.with_unit_of_work(uow_repo.clone());
+    let service = PostingServiceImpl::new(shared.clone());
+    let mut accounts = Vec::new();
+    for (opr, ledger_id) in [(1, Uuid::new_v4()), (3, moved)] {
+        let (debit, credit) = create_accounts(&shared, ledger_id).await;
+        let posting = PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(25))
+            .credit(credit, BigDecimal::from(25))
+            .build();
+        service.new_posting(posting).await.unwrap();
+        accounts.push(debit);
+    }
+
+    // The closing posting and the closed statement are committed together in the second shard
+    let stmts = AccountStmtServiceImpl::new(shared.clone());
+    let stmt = stmts.create_stmt(accounts[1].clone(), Utc::now()).await.unwrap();
+    let closed = stmts.close_stmt(stmt).await.unwrap();
+    let stored = InMemoryAccountStmtRepository::new(second.clone()).find_by_id(closed.financial_stmt.id).await.unwrap().unwrap();
+    assert_eq!(stored.stmt_status, StmtStatus::Closed);
+    assert!(InMemoryAccountStmtRepository::new(first.clone()).find_by_id(closed.financial_stmt.id).await.unwrap().is_none());
+    assert_eq!(InMemoryPostingRepository::new(second).find_by_ledger_id(moved).await.unwrap().len(), 2);
+    assert!(InMemoryPostingRepository::new(first).find_by_ledger_id(moved).await.unwrap().is_empty());
+
+    // A unit writing to both shards is not committed
+    let mut work = UnitOfWork::new();
+    for account in accounts.iter() {
+        let lines = shared.line_repo
+            .find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(account.id, Utc::now(), Utc::now())
+            .await
+            .unwrap();
+        work.save_posting_line(lines[0].clone());
+    }
+    assert!(matches!(uow_repo.commit(work).await, Err(DbError::CrossShard)));
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDate, Utc};
+use postings_api::domain::api_key::ApiRole;
+use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_api::domain::privileged_context::PrivilegedContext;
+use postings_api::domain::standing_order::{ExecutionStatus, Frequency, StandingOrder, StandingOrderStatus};
+use postings_api::service::ledger_closure_service::LedgerClosureService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::standing_order_service::StandingOrderService;
+use postings_api::ServiceError;
+use postings_db::models::holiday::Holiday;
+use postings_db::repositories::holiday_repository::HolidayRepository;
+use postings_db_inmemory::repositories::earmark_repository::InMemoryEarmarkRepository;
+use postings_db_inmemory::repositories::holiday_repository::InMemoryHolidayRepository;
+use postings_db_inmemory::repositories::ledger_closure_repository::InMemoryLedgerClosureRepository;
+use postings_db_inmemory::repositories::standing_order_repository::InMemoryStandingOrderRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::hash_utils::hash_serialize;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::earmark_service::EarmarkServiceImpl;
+use postings_logic::services::ledger_closure_service::LedgerClosureServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::standing_order_service::StandingOrderServiceImpl;
+use uuid::Uuid;
+use common::{api_key, create_shared, load_accounts};
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+/// Daily order of 10 from the credit to the debit account, retried once before an occurrence is skipped.
+fn order(source: &LedgerAccountBO, target: &LedgerAccountBO) -> StandingOrder {
+    StandingOrder {
+        id: Uuid::nil(),
+        ledger: source.ledger.clone(),
+        source_account: source.clone(),
+        target_account: target.clone(),
+        amount: BigDecimal::from(10),
+        opr_type: [2; 34],
+        frequency: Frequency::Daily,
+        start_date: date(1),
+        end_date: None,
+        max_executions: None,
+        max_retries: 1,
+        next_execution_date: date(1),
+        occurrence: 0,
+        execution_count: 0,
+        status: StandingOrderStatus::Active,
+        created: Utc::now(),
+    }
+}
+
+/// Funds the credit account with 15, enough for one execution.
+async fn fund(posting_service: &PostingServiceImpl, debit: &LedgerAccountBO, credit: &LedgerAccountBO) {
+    posting_service
+        .new_posting(PostingBuilder::new(debit.ledger.clone(), [1; 34], [2; 34], Utc::now())
+            .debit(debit.clone(), BigDecimal::from(15))
+            .credit(credit.clone(), BigDecimal::from(15))
+            .build())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_insufficient_balance_is_retried_then_skipped() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()));
+    fund(&posting_service, &debit, &credit).await;
+    let earmarks = Arc::new(EarmarkServiceImpl::new(shared.clone(), Arc::new(InMemoryEarmarkRepository::new(store.clone())), posting_service.clone()));
+    let service = StandingOrderServiceImpl::new(shared, Arc::new(InMemoryStandingOrderRepository::new(store)), posting_service.clone(), earmarks);
+    let order = service.create_standing_order(order(&credit, &debit)).await.unwrap();
+
+    let first = service.run_due(date(2)).await.unwrap();
+    assert_eq!(first.iter().map(|e| e.status.clone()).collect::<Vec<_>>(), vec![ExecutionStatus::Executed, ExecutionStatus::Failed]);
+    let executed = posting_service.find_postings_by_operation_id(&hash_serialize(&(order.id, date(1))).unwrap()).await.unwrap();
+    assert_eq!(executed[0].id, first[0].posting_id.unwrap());
+    assert_eq!(executed[0].pst_time, date(1).and_hms_opt(0, 0, 0).unwrap().and_utc());
+    assert_eq!(first[1].execution_date, date(2));
+    assert_eq!(first[1].message.as_deref(), Some("Available balance is insufficient"));
+    let retried = service.find_standing_order_by_id(order.id).await.unwrap().unwrap();
+    assert_eq!(retried.next_execution_date, date(2));
+
+    let second = service.run_due(date(2)).await.unwrap();
+    assert_eq!(second.len(), 1);
+    assert_eq!(second[0].status, ExecutionStatus::Skipped);
+    assert_eq!(second[0].attempt, 2);
+    let skipped = service.find_standing_order_by_id(order.id).await.unwrap().unwrap();
+    assert_eq!(skipped.next_execution_date, date(3));
+    assert_eq!(skipped.execution_count, 1);
+}
+
+#[tokio::test]
+async fn test_other_failures_leave_occurrence_due() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let closure_repo = Arc::new(InMemoryLedgerClosureRepository::new(store.clone()));
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()).with_closure_repo(closure_repo.clone()));
+    fund(&posting_service, &debit, &credit).await;
+    let earmarks = Arc::new(EarmarkServiceImpl::new(shared.clone(), Arc::new(InMemoryEarmarkRepository::new(store.clone())), posting_service.clone()));
+    let service = StandingOrderServiceImpl::new(shared.clone(), Arc::new(InMemoryStandingOrderRepository::new(store)), posting_service, earmarks);
+    let order = service.create_standing_order(order(&credit, &debit)).await.unwrap();
+    let controller = PrivilegedContext::new(&api_key("controller", vec![ApiRole::Admin]), "Audit").unwrap();
+    LedgerClosureServiceImpl::new(shared, closure_repo)
+        .close_ledger(debit.ledger.clone(), Utc::now() + Duration::days(1), false, controller)
+        .await
+        .unwrap();
+
+    for attempt in 1..=3 {
+        let executions = service.run_due(date(1)).await.unwrap();
+        assert_eq!(executions.len(), 1);
+        assert_eq!(executions[0].status, ExecutionStatus::Failed);
+        assert_eq!(executions[0].attempt, attempt);
+        assert_eq!(executions[0].message, Some(ServiceError::PeriodClosed.to_string()));
+    }
+
+    assert_eq!(service.find_executions(order.id).await.unwrap().len(), 3);
+    let unchanged = service.find_standing_order_by_id(order.id).await.unwrap().unwrap();
+    assert_eq!(unchanged.next_execution_date, date(1));
+    assert_eq!(unchanged.status, StandingOrderStatus::Active);
+}
+
+#[tokio::test]
+async fn test_occurrences_on_non_business_days_execute_on_the_following_one() {
+    let store = Arc::new(InMemoryStore::new());
+    let holiday_repo = Arc::new(InMemoryHolidayRepository::new(store.clone()));
+    let shared = create_shared(store.clone()).with_holiday_repo(holiday_repo.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    // Saturday start, with the Monday after it a holiday
+    holiday_repo
+        .save(Holiday { ledger_id: debit.ledger.id, holiday_date: date(10), name: "Holiday".to_string() })
+        .await
+        .unwrap();
+    let posting_service = Arc::new(PostingServiceImpl::new(shared.clone()));
+    fund(&posting_service, &debit, &credit).await;
+    let earmarks = Arc::new(EarmarkServiceImpl::new(shared.clone(), Arc::new(InMemoryEarmarkRepository::new(store.clone())), posting_service.clone()));
+    let service = StandingOrderServiceImpl::new(shared, Arc::new(InMemoryStandingOrderRepository::new(store)), posting_service.clone(), earmarks);
+    let order = service
+        .create_standing_order(StandingOrder { start_date: date(8), max_executions: Some(2), ..order(&credit, &debit) })
+        .await
+        .unwrap();
+    assert_eq!(order.next_execution_date, date(11));
+
+    assert!(service.run_due(date(10)).await.unwrap().is_empty());
+    let executions = service.run_due(date(11)).await.unwrap();
+    assert_eq!(executions.len(), 1);
+    assert_eq!(executions[0].execution_date, date(11));
+    let executed = posting_service.find_postings_by_operation_id(&hash_serialize(&(order.id, date(11))).unwrap()).await.unwrap();
+    assert_eq!(executed[0].pst_time, date(11).and_hms_opt(0, 0, 0).unwrap().and_utc());
+    // The occurrences of Sunday and Monday moved onto Tuesday as well and are merged into it
+    let advanced = service.find_standing_order_by_id(order.id).await.unwrap().unwrap();
+    assert_eq!(advanced.next_execution_date, date(12));
+    assert_eq!(advanced.status, StandingOrderStatus::Active);
+}
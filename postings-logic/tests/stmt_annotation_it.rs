@@ -0,0 +1,97 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::stmt_annotation_repository::InMemoryStmtAnnotationRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn save_account(store: &Arc<InMemoryStore>) -> Uuid {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    let pst_time = Utc::now() - Duration::days(2);
+    InMemoryPostingLineRepository::new(store.clone()).save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id: id,
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        pst_time,
+        record_time: pst_time,
+        ..Default::default()
+    }).await.unwrap();
+    id
+}
+
+#[tokio::test]
+async fn test_annotations_survive_close_and_come_with_reads() {
+    let store = Arc::new(InMemoryStore::new());
+    let account_id = save_account(&store).await;
+    let shared = create_shared(store.clone());
+    let service = AccountStmtServiceImpl::new(shared.clone())
+        .with_annotation_repo(Arc::new(InMemoryStmtAnnotationRepository::new(store.clone())));
+    let account = shared.load_ledger_account_bo(account_id).await.unwrap();
+
+    let stmt = service.create_stmt(account.clone(), Utc::now() - Duration::days(1)).await.unwrap();
+    let stmt_id = stmt.financial_stmt.id;
+    service.annotate_stmt(stmt_id, "alice".to_string(), "Checked against bank statement".to_string()).await.unwrap();
+    let closed = service.close_stmt(stmt).await.unwrap();
+    service.annotate_stmt(stmt_id, "bob".to_string(), "Approved".to_string()).await.unwrap();
+
+    let read = service.read_stmt(account.clone(), closed.financial_stmt.pst_time).await.unwrap();
+    assert_eq!(read.financial_stmt.id, stmt_id);
+    assert_eq!(read.total_debit, closed.total_debit);
+    let authors: Vec<_> = read.annotations.iter().map(|a| a.author.as_str()).collect();
+    assert_eq!(authors, vec!["alice", "bob"]);
+    // The statement continuing after the closed one starts without notes
+    assert!(service.read_stmt(account, Utc::now()).await.unwrap().annotations.is_empty());
+
+    assert!(matches!(
+        service.annotate_stmt(Uuid::new_v4(), "alice".to_string(), "?".to_string()).await,
+        Err(ServiceError::StatementNotFound)
+    ));
+    assert!(matches!(
+        service.annotate_stmt(stmt_id, "alice".to_string(), " ".to_string()).await,
+        Err(ServiceError::NotEnoughInfo)
+    ));
+}
+
+#[tokio::test]
+async fn test_annotations_need_repository() {
+    let store = Arc::new(InMemoryStore::new());
+    let service = AccountStmtServiceImpl::new(create_shared(store));
+    assert!(matches!(
+        service.annotate_stmt(Uuid::new_v4(), "alice".to_string(), "note".to_string()).await,
+        Err(ServiceError::StmtAnnotationsDisabled)
+    ));
+}
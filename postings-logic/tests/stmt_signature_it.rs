@@ -0,0 +1,106 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use ed25519_dalek::SigningKey;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::ServiceError;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::signing::Ed25519Signer;
+use uuid::Uuid;
+use common::create_shared;
+
+async fn save_account(store: &Arc<InMemoryStore>) -> Uuid {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    InMemoryChartOfAccountRepository::new(store.clone()).save(&coa).await.unwrap();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: coa.id, read_only: false, memo: false, closing_summary: false };
+    InMemoryLedgerRepository::new(store.clone()).save(&ledger).await.unwrap();
+    let id = Uuid::new_v4();
+    InMemoryLedgerAccountRepository::new(store.clone()).save(&LedgerAccount {
+        id,
+        ledger_id: ledger.id,
+        parent_id: None,
+        coa_id: coa.id,
+        balance_side: BalanceSide::Dr,
+        category: AccountCategory::AS,
+        currency: None,
+    }).await.unwrap();
+    let pst_time = Utc::now() - Duration::days(2);
+    InMemoryPostingLineRepository::new(store.clone()).save(PostingLine {
+        id: Uuid::new_v4(),
+        account_id: id,
+        debit_amount: BigDecimal::from(10),
+        credit_amount: BigDecimal::from(0),
+        pst_time,
+        record_time: pst_time,
+        ..Default::default()
+    }).await.unwrap();
+    id
+}
+
+#[tokio::test]
+async fn test_closed_stmt_is_signed_and_verifies_until_altered() {
+    let store = Arc::new(InMemoryStore::new());
+    let account_id = save_account(&store).await;
+    let shared = create_shared(store.clone());
+    let signer = Arc::new(Ed25519Signer::new("stmt-2025", SigningKey::from_bytes(&[7; 32])));
+    let service = AccountStmtServiceImpl::new(shared.clone())
+        .with_stmt_signer(signer.clone())
+        .with_verifying_key("stmt-2025", signer.verifying_key());
+    let account = shared.load_ledger_account_bo(account_id).await.unwrap();
+
+    let stmt = service.create_stmt(account.clone(), Utc::now() - Duration::days(1)).await.unwrap();
+    let stmt_id = stmt.financial_stmt.id;
+    assert!(stmt.signature.is_none());
+    assert!(matches!(service.verify_stmt_signature(stmt_id).await, Err(ServiceError::StatementNotSigned)));
+
+    let closed = service.close_stmt(stmt).await.unwrap();
+    let signature = closed.signature.clone().unwrap();
+    assert_eq!(signature.key_id, "stmt-2025");
+    assert_eq!(signature.signature.len(), 64);
+    assert!(service.verify_stmt_signature(stmt_id).await.unwrap());
+    let read = service.read_stmt(account, closed.financial_stmt.pst_time).await.unwrap();
+    assert_eq!(read.signature, Some(signature));
+
+    let stmt_repo = InMemoryAccountStmtRepository::new(store.clone());
+    let mut altered = stmt_repo.find_by_id(stmt_id).await.unwrap().unwrap();
+    altered.total_debit = BigDecimal::from(1000);
+    stmt_repo.save(altered).await.unwrap();
+    assert!(!service.verify_stmt_signature(stmt_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_verification_needs_the_signing_key() {
+    let store = Arc::new(InMemoryStore::new());
+    let account_id = save_account(&store).await;
+    let shared = create_shared(store.clone());
+    let signer = Arc::new(Ed25519Signer::new("stmt-2025", SigningKey::from_bytes(&[7; 32])));
+    let service = AccountStmtServiceImpl::new(shared.clone()).with_stmt_signer(signer);
+    let account = shared.load_ledger_account_bo(account_id).await.unwrap();
+    let stmt = service.create_stmt(account, Utc::now() - Duration::days(1)).await.unwrap();
+    let stmt_id = stmt.financial_stmt.id;
+    service.close_stmt(stmt).await.unwrap();
+
+    assert!(matches!(service.verify_stmt_signature(stmt_id).await, Err(ServiceError::SignerNotConfigured)));
+    let other_key = SigningKey::from_bytes(&[9; 32]).verifying_key();
+    let verifier = AccountStmtServiceImpl::new(shared).with_verifying_key("stmt-2025", other_key);
+    assert!(!verifier.verify_stmt_signature(stmt_id).await.unwrap());
+}
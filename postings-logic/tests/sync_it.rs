@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use postings_api::service::sync_service::SyncService;
+use postings_db::models::ledger_event::LedgerEventOperation;
+use postings_db::repositories::ledger_event_repository::LedgerEventRepository;
+use postings_db_inmemory::repositories::ledger_event_repository::InMemoryLedgerEventRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::sync_service::SyncServiceImpl;
+
+#[tokio::test]
+async fn test_changes_span_archive_and_stop_at_unsettled_event() {
+    let store = Arc::new(InMemoryStore::new());
+    let event_repo = Arc::new(InMemoryLedgerEventRepository::new(store.clone()));
+    let settled = Utc::now() - Duration::minutes(10);
+    for i in 0..4 {
+        store.append_event("posting", &format!("p{i}"), LedgerEventOperation::Insert, "{}".to_string(), settled);
+    }
+    store.append_event("posting", "late", LedgerEventOperation::Insert, "{}".to_string(), Utc::now());
+    event_repo.archive_up_to(2, Utc::now()).await.unwrap();
+    let sync = SyncServiceImpl::new(event_repo);
+
+    let first = sync.changes_since(0, 3, &[]).await.unwrap();
+    let seqs: Vec<i64> = first.changes.iter().map(|c| c.seq).collect();
+    assert_eq!(seqs, vec![1, 2, 3]);
+    assert_eq!(first.next_seq, 3);
+    assert!(first.has_more);
+
+    // The unsettled fifth event is held back until it reaches the settle delay
+    let second = sync.changes_since(first.next_seq, 3, &[]).await.unwrap();
+    assert_eq!(second.changes.len(), 1);
+    assert_eq!(second.next_seq, 4);
+    assert!(!second.has_more);
+}
+
+#[tokio::test]
+async fn test_filtered_changes_advance_position() {
+    let store = Arc::new(InMemoryStore::new());
+    let settled = Utc::now() - Duration::minutes(10);
+    store.append_event("posting_line", "l1", LedgerEventOperation::Insert, "{}".to_string(), settled);
+    store.append_event("posting_line", "l2", LedgerEventOperation::Insert, "{}".to_string(), settled);
+    store.append_event("ledger_account", "a1", LedgerEventOperation::Update, "{}".to_string(), settled);
+    let sync = SyncServiceImpl::new(Arc::new(InMemoryLedgerEventRepository::new(store)));
+
+    let changes = sync.changes_since(0, 10, &["ledger_account".to_string()]).await.unwrap();
+    assert_eq!(changes.changes.len(), 1);
+    assert_eq!(changes.changes[0].entity_id, "a1");
+    assert_eq!(changes.next_seq, 3);
+}
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use mockall::mock;
+use postings_db::models::line_order::LineOrder;
+use postings_db::models::posting_line::{AccountLineStats, AccountLineTotals, PostingLine};
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::page::{Page, PageRequest};
+use postings_db::DbError;
+use postings_logic::archive::posting_line_repository::TieredPostingLineRepository;
+use uuid::Uuid;
+
+mock! {
+    pub PostingLineRepository {}
+
+    #[async_trait]
+    impl PostingLineRepository for PostingLineRepository {
+        async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError>;
+        async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+        async fn find_by_account_and_pst_time_between_paged(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, page: PageRequest) -> Result<Page<PostingLine>, DbError>;
+        async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError>;
+        async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+        async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError>;
+        async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+        async fn find_by_account_and_pst_time_less_than_equal_and_record_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, known_at: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+        async fn find_stats_by_account_id(&self, account_id: Uuid) -> Result<AccountLineStats, DbError>;
+        async fn sum_by_account_ids_and_pst_time_less_than_equal(&self, account_ids: &[Uuid], ref_time: DateTime<Utc>) -> Result<Vec<AccountLineTotals>, DbError>;
+        fn stream_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>, order: LineOrder) -> BoxStream<'static, Result<PostingLine, DbError>>;
+        fn stream_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>, order: LineOrder) -> BoxStream<'static, Result<PostingLine, DbError>>;
+    }
+}
+
+fn create_test_line(id: Uuid, pst_time: DateTime<Utc>) -> PostingLine {
+    PostingLine {
+        id,
+        pst_time,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_recent_period_reads_hot_store_only() {
+    // Arrange
+    let cutoff = Utc::now() - Duration::days(365);
+    let line = create_test_line(Uuid::new_v4(), Utc::now());
+    let line_clone = line.clone();
+
+    let mut hot = MockPostingLineRepository::new();
+    hot.expect_find_by_account_and_pst_time_between()
+        .times(1)
+        .returning(move |_, _, _| Ok(vec![line_clone.clone()]));
+    let mut archive = MockPostingLineRepository::new();
+    archive.expect_find_by_account_and_pst_time_between().times(0);
+
+    let repo = TieredPostingLineRepository::new(Arc::new(hot), Arc::new(archive), cutoff);
+
+    // Act
+    let lines = repo
+        .find_by_account_and_pst_time_between(Uuid::new_v4(), cutoff + Duration::days(1), Utc::now())
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(lines, vec![line]);
+}
+
+#[tokio::test]
+async fn test_historical_period_merges_both_stores() {
+    // Arrange
+    let cutoff = Utc::now() - Duration::days(30);
+    let recent = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(1));
+    let old = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(60));
+    // A line being archived is present in both stores
+    let moving = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(45));
+
+    let (recent_clone, moving_clone) = (recent.clone(), moving.clone());
+    let mut hot = MockPostingLineRepository::new();
+    hot.expect_find_by_account_and_pst_time_between()
+        .times(1)
+        .returning(move |_, _, _| Ok(vec![recent_clone.clone(), moving_clone.clone()]));
+    let (old_clone, moving_clone) = (old.clone(), moving.clone());
+    let mut archive = MockPostingLineRepository::new();
+    archive.expect_find_by_account_and_pst_time_between()
+        .times(1)
+        .returning(move |_, _, _| Ok(vec![moving_clone.clone(), old_clone.clone()]));
+
+    let repo = TieredPostingLineRepository::new(Arc::new(hot), Arc::new(archive), cutoff);
+
+    // Act
+    let lines = repo
+        .find_by_account_and_pst_time_between(Uuid::new_v4(), Utc::now() - Duration::days(90), Utc::now())
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(lines, vec![recent, moving, old]);
+}
+
+#[tokio::test]
+async fn test_historical_page_spans_both_stores() {
+    // Arrange
+    let cutoff = Utc::now() - Duration::days(30);
+    let recent = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(1));
+    let middle = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(20));
+    let old = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(60));
+
+    let (recent_clone, middle_clone) = (recent.clone(), middle.clone());
+    let mut hot = MockPostingLineRepository::new();
+    hot.expect_find_by_account_and_pst_time_between_paged().times(0);
+    hot.expect_find_by_account_and_pst_time_between()
+        .times(1)
+        .returning(move |_, _, _| Ok(vec![recent_clone.clone(), middle_clone.clone()]));
+    let old_clone = old.clone();
+    let mut archive = MockPostingLineRepository::new();
+    archive.expect_find_by_account_and_pst_time_between()
+        .times(1)
+        .returning(move |_, _, _| Ok(vec![old_clone.clone()]));
+
+    let repo = TieredPostingLineRepository::new(Arc::new(hot), Arc::new(archive), cutoff);
+
+    // Act
+    let page = repo
+        .find_by_account_and_pst_time_between_paged(Uuid::new_v4(), Utc::now() - Duration::days(90), Utc::now(), PageRequest::new(1, 2))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(page.content, vec![old]);
+    assert_eq!(page.total_elements, 3);
+    assert_eq!(page.total_pages(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_interleaves_stores_in_posting_time_order() {
+    // Arrange
+    let cutoff = Utc::now() - Duration::days(30);
+    let pst_time = Utc::now() - Duration::days(40);
+    let recent = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(1));
+    let old = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(60));
+    // Same posting time, ordered by record time then id
+    let mut first = create_test_line(Uuid::new_v4(), pst_time);
+    first.record_time = pst_time;
+    let mut second = create_test_line(Uuid::new_v4(), pst_time);
+    second.record_time = pst_time + Duration::seconds(1);
+    // A line being archived is present in both stores
+    let moving = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(45));
+
+    let hot_lines = vec![moving.clone(), first.clone(), recent.clone()];
+    let mut hot = MockPostingLineRepository::new();
+    hot.expect_stream_by_account_and_pst_time_less_than_equal()
+        .withf(|_, _, order| *order == LineOrder::PostingTime)
+        .times(1)
+        .returning(move |_, _, _| stream::iter(hot_lines.clone().into_iter().map(Ok)).boxed());
+    let archived_lines = vec![old.clone(), moving.clone(), second.clone()];
+    let mut archive = MockPostingLineRepository::new();
+    archive.expect_stream_by_account_and_pst_time_less_than_equal()
+        .times(1)
+        .returning(move |_, _, _| stream::iter(archived_lines.clone().into_iter().map(Ok)).boxed());
+
+    let repo = TieredPostingLineRepository::new(Arc::new(hot), Arc::new(archive), cutoff);
+
+    // Act
+    let lines: Vec<PostingLine> = repo
+        .stream_by_account_and_pst_time_less_than_equal(Uuid::new_v4(), Utc::now(), LineOrder::PostingTime)
+        .map(|line| line.unwrap())
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(lines, vec![old, moving, first, second, recent]);
+}
+
+#[tokio::test]
+async fn test_find_by_id_falls_back_to_archive() {
+    // Arrange
+    let line = create_test_line(Uuid::new_v4(), Utc::now() - Duration::days(400));
+    let line_id = line.id;
+    let line_clone = line.clone();
+
+    let mut hot = MockPostingLineRepository::new();
+    hot.expect_find_by_id().times(1).returning(|_| Ok(None));
+    let mut archive = MockPostingLineRepository::new();
+    archive.expect_find_by_id()
+        .withf(move |id| *id == line_id)
+        .times(1)
+        .returning(move |_| Ok(Some(line_clone.clone())));
+
+    let repo = TieredPostingLineRepository::new(Arc::new(hot), Arc::new(archive), Utc::now() - Duration::days(365));
+
+    // Act
+    let result = repo.find_by_id(line_id).await.unwrap();
+
+    // Assert
+    assert_eq!(result, Some(line));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::prepared_posting::PreparedPostingStatus;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::two_phase_posting_service::TwoPhasePostingService;
+use postings_api::ServiceError;
+use postings_db_inmemory::repositories::prepared_posting_repository::InMemoryPreparedPostingRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::posting_builder::PostingBuilder;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use common::{create_shared, load_accounts};
+
+/// Two-phase posting service on a new ledger, with a posting factory for it.
+async fn setup() -> (PostingServiceImpl, impl Fn(u8) -> Posting) {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let (debit, credit) = load_accounts(&store, &shared).await;
+    let service = PostingServiceImpl::new(shared)
+        .with_prepared_posting_repo(Arc::new(InMemoryPreparedPostingRepository::new(store)));
+    let post = move |opr: u8| PostingBuilder::new(debit.ledger.clone(), [opr; 34], [2; 34], Utc::now())
+        .debit(debit.clone(), BigDecimal::from(10))
+        .credit(credit.clone(), BigDecimal::from(10))
+        .build();
+    (service, post)
+}
+
+#[tokio::test]
+async fn test_commit_after_prepare() {
+    let (service, post) = setup().await;
+
+    let prepared = service.prepare_posting(post(1), "saga-1".to_string(), Duration::hours(1)).await.unwrap();
+    assert_eq!(prepared.status, PreparedPostingStatus::Prepared);
+    // Nothing is posted before the commit
+    assert!(service.find_postings_by_operation_id(&[1; 34]).await.unwrap().is_empty());
+
+    let posting = service.commit_prepared(prepared.id).await.unwrap();
+    let committed = service.find_prepared(prepared.id).await.unwrap().unwrap();
+    assert_eq!(committed.status, PreparedPostingStatus::Committed);
+    assert_eq!(committed.posting_id, Some(posting.id));
+    let recorded = service.find_postings_by_operation_id(&[1; 34]).await.unwrap();
+    assert_eq!(recorded.iter().map(|p| p.id).collect::<Vec<_>>(), vec![posting.id]);
+
+    assert!(matches!(service.commit_prepared(prepared.id).await, Err(ServiceError::PreparedPostingResolved)));
+    assert!(matches!(service.abort_prepared(prepared.id).await, Err(ServiceError::PreparedPostingResolved)));
+}
+
+#[tokio::test]
+async fn test_commit_after_abort_is_rejected() {
+    let (service, post) = setup().await;
+    let prepared = service.prepare_posting(post(1), "saga-1".to_string(), Duration::hours(1)).await.unwrap();
+
+    let aborted = service.abort_prepared(prepared.id).await.unwrap();
+    assert_eq!(aborted.status, PreparedPostingStatus::Aborted);
+    // Aborting again is a no-op
+    assert_eq!(service.abort_prepared(prepared.id).await.unwrap().status, PreparedPostingStatus::Aborted);
+
+    assert!(matches!(service.commit_prepared(prepared.id).await, Err(ServiceError::PreparedPostingResolved)));
+    assert!(service.find_postings_by_operation_id(&[1; 34]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_commit_after_expiry_is_rejected() {
+    let (service, post) = setup().await;
+    let prepared = service.prepare_posting(post(1), "saga-1".to_string(), Duration::zero()).await.unwrap();
+
+    assert!(matches!(service.commit_prepared(prepared.id).await, Err(ServiceError::PreparedPostingExpired)));
+    let expired = service.find_prepared(prepared.id).await.unwrap().unwrap();
+    assert_eq!(expired.status, PreparedPostingStatus::Aborted);
+    assert!(service.find_postings_by_operation_id(&[1; 34]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_abort_expired() {
+    let (service, post) = setup().await;
+    let expired = service.prepare_posting(post(1), "saga-1".to_string(), Duration::zero()).await.unwrap();
+    let pending = service.prepare_posting(post(3), "saga-2".to_string(), Duration::hours(1)).await.unwrap();
+
+    assert_eq!(service.abort_expired(Utc::now(), 10).await.unwrap(), 1);
+    assert_eq!(service.abort_expired(Utc::now(), 10).await.unwrap(), 0);
+
+    assert_eq!(service.find_prepared(expired.id).await.unwrap().unwrap().status, PreparedPostingStatus::Aborted);
+    assert_eq!(service.find_prepared(pending.id).await.unwrap().unwrap().status, PreparedPostingStatus::Prepared);
+    service.commit_prepared(pending.id).await.unwrap();
+}
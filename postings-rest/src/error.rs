@@ -0,0 +1,77 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use postings_api::ServiceError;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Body of every error response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+}
+
+/// A failed request: the status code and message sent to the client.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self { status: StatusCode::BAD_REQUEST, message: message.into() }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self { status: StatusCode::NOT_FOUND, message: message.into() }
+    }
+}
+
+/// Status code of a service error. Matched without a wildcard, so a new variant has to be
+/// given a status here.
+pub fn status_of(error: &ServiceError) -> StatusCode {
+    use ServiceError::*;
+    match error {
+        Db | ObjectStore | ExportFailed | EventPublication | UnknownHashAlgorithm { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        NotEnoughInfo | ChartOfAccountMismatch | DoubledEntryViolation { .. } | BaselineTime | PostingTimeMissing
+        | NoCategory | InvalidImportRecord { .. } | CurrencyMismatch => StatusCode::BAD_REQUEST,
+        ChartOfAccountNotFound | LedgerAccountNotFound | LedgerNotFound | PostingNotFound
+        | PostingLineNotFound | StatementNotFound | StatementNotSigned | BatchNotFound | EarmarkNotFound
+        | StandingOrderNotFound | ScheduledPostingNotFound | EscrowNotFound | QuarantinedEntryNotFound
+        | ExternalContentNotFound | ProductNotFound | CategoryRuleNotFound
+        | PreparedPostingNotFound | ApiKeyNotFound | BackfillTaskNotFound
+        | BackfillJobNotFound | ReportScheduleNotFound | ReportArtifactNotFound
+        | AccountGroupNotFound | AccountNameNotFound => StatusCode::NOT_FOUND,
+        StatementAlreadyClosed | StatementNotClosed | BatchStatusInvalid | PostingAlreadyBatched | ReversalNotBatchable
+        | StatementPeriodOverlap | PeriodClosed | RevaluationSuperseded | EarmarkNotActive | StandingOrderNotActive | ScheduledPostingNotActive | EscrowAlreadyResolved | QuarantinedEntryResolved
+        | ReadOnly | PreparedPostingResolved | PostingAlreadyDiscarded
+        | AccountGroupNameTaken | DuplicateOperation { .. } | ExternalRefTaken | AccountNameTaken => StatusCode::CONFLICT,
+        BatchControlMismatch | EarmarkAmountExceeded | InsufficientAvailableBalance
+        | ExternalContentHashMismatch | ReportArtifactHashMismatch | EventLogSegmentHashMismatch | ReversalWindowExpired
+        | LimitExceeded { .. } | FxRateNotFound { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        PreparedPostingExpired => StatusCode::GONE,
+        ApiKeyInvalid => StatusCode::UNAUTHORIZED,
+        Forbidden => StatusCode::FORBIDDEN,
+        QuotaExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
+        StmtJobsDisabled | StmtAnnotationsDisabled | SignerNotConfigured | TwoPhaseDisabled
+        | ReportRendererNotConfigured => StatusCode::NOT_IMPLEMENTED,
+    }
+}
+
+impl From<ServiceError> for ApiError {
+    fn from(error: ServiceError) -> Self {
+        let status = status_of(&error);
+        // Server side failures are logged, the client only gets the generic message
+        if status.is_server_error() {
+            log::error!("Request failed: {error:?}");
+        }
+        Self { status, message: error.to_string() }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(ErrorBody { error: self.message })).into_response()
+    }
+}
@@ -0,0 +1,46 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use postings_api::domain::change_set::ChangeSet;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use crate::error::{ApiError, ErrorBody};
+use crate::AppState;
+
+const DEFAULT_LIMIT: i64 = 500;
+const MAX_LIMIT: i64 = 5000;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ChangesQuery {
+    /// `next_seq` of the previous response, 0 for a full sync.
+    #[serde(default)]
+    pub after_seq: i64,
+    /// Maximum number of changes, 500 by default.
+    pub limit: Option<i64>,
+    /// Comma separated tables to sync, e.g. `posting,account_stmt`. All when omitted.
+    pub entity_types: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes",
+    tag = "sync",
+    params(ChangesQuery),
+    responses(
+        (status = 200, description = "Changes after the position, in sequence order", body = Object),
+        (status = 400, description = "Limit out of range", body = ErrorBody),
+    )
+)]
+pub async fn changes_since(State(state): State<AppState>, Query(query): Query<ChangesQuery>) -> Result<Json<ChangeSet>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(ApiError::bad_request(format!("limit must be between 1 and {MAX_LIMIT}")));
+    }
+    let entity_types: Vec<String> = query.entity_types
+        .iter()
+        .flat_map(|types| types.split(','))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+    Ok(Json(state.sync_service.changes_since(query.after_seq, limit, &entity_types).await?))
+}
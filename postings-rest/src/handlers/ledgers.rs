@@ -0,0 +1,123 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::Named;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::error::{ApiError, ErrorBody};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LedgerWithNames {
+    #[schema(value_type = Object)]
+    pub ledger: Ledger,
+    #[schema(value_type = Vec<Object>)]
+    pub named: Vec<Named>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LedgerAccountWithNames {
+    #[schema(value_type = Object)]
+    pub ledger_account: LedgerAccount,
+    #[schema(value_type = Vec<Object>)]
+    pub named: Vec<Named>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReadOnlyRequest {
+    pub read_only: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/ledgers",
+    tag = "ledgers",
+    request_body = LedgerWithNames,
+    responses(
+        (status = 201, description = "Ledger created", body = LedgerWithNames),
+        (status = 400, description = "Invalid ledger", body = ErrorBody),
+        (status = 404, description = "Chart of account not found", body = ErrorBody),
+    )
+)]
+pub async fn new_ledger(State(state): State<AppState>, Json(request): Json<LedgerWithNames>) -> Result<(StatusCode, Json<LedgerWithNames>), ApiError> {
+    let (ledger, named) = state.ledger_service.new_ledger(request.ledger, request.named).await?;
+    Ok((StatusCode::CREATED, Json(LedgerWithNames { ledger, named })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/ledgers/{id}",
+    tag = "ledgers",
+    params(("id" = Uuid, Path, description = "Ledger id")),
+    responses(
+        (status = 200, description = "The ledger", body = Object),
+        (status = 404, description = "Ledger not found", body = ErrorBody),
+    )
+)]
+pub async fn find_ledger(State(state): State<AppState>, Path(id): Path<Uuid>) -> Result<Json<Ledger>, ApiError> {
+    state.ledger_service
+        .find_ledger_by_id(id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found("Ledger not found"))
+}
+
+#[utoipa::path(
+    put,
+    path = "/ledgers/{id}/read-only",
+    tag = "ledgers",
+    params(("id" = Uuid, Path, description = "Ledger id")),
+    request_body = ReadOnlyRequest,
+    responses(
+        (status = 204, description = "Mode switched"),
+        (status = 404, description = "Ledger not found", body = ErrorBody),
+    )
+)]
+pub async fn set_read_only(State(state): State<AppState>, Path(id): Path<Uuid>, Json(request): Json<ReadOnlyRequest>) -> Result<StatusCode, ApiError> {
+    state.ledger_service.set_read_only(id, request.read_only).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/ledgers/{id}/accounts",
+    tag = "ledgers",
+    params(("id" = Uuid, Path, description = "Ledger id")),
+    request_body = LedgerAccountWithNames,
+    responses(
+        (status = 201, description = "Account created", body = LedgerAccountWithNames),
+        (status = 400, description = "Account belongs to another ledger", body = ErrorBody),
+        (status = 404, description = "Ledger not found", body = ErrorBody),
+    )
+)]
+pub async fn new_ledger_account(State(state): State<AppState>, Path(id): Path<Uuid>, Json(request): Json<LedgerAccountWithNames>) -> Result<(StatusCode, Json<LedgerAccountWithNames>), ApiError> {
+    if request.ledger_account.ledger.id != id {
+        return Err(ApiError::bad_request("Account belongs to another ledger"));
+    }
+    let (ledger_account, named) = state.ledger_service.new_ledger_account(request.ledger_account, request.named).await?;
+    Ok((StatusCode::CREATED, Json(LedgerAccountWithNames { ledger_account, named })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts/{id}",
+    tag = "ledgers",
+    params(("id" = Uuid, Path, description = "Ledger account id")),
+    responses(
+        (status = 200, description = "The account", body = Object),
+        (status = 404, description = "Ledger account not found", body = ErrorBody),
+    )
+)]
+pub async fn find_ledger_account(State(state): State<AppState>, Path(id): Path<Uuid>) -> Result<Json<LedgerAccount>, ApiError> {
+    load_ledger_account(&state, id).await.map(Json)
+}
+
+pub(crate) async fn load_ledger_account(state: &AppState, id: Uuid) -> Result<LedgerAccount, ApiError> {
+    state.ledger_service
+        .find_ledger_account_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Ledger account not found"))
+}
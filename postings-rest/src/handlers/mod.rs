@@ -0,0 +1,4 @@
+pub mod changes;
+pub mod ledgers;
+pub mod postings;
+pub mod stmts;
@@ -0,0 +1,51 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use postings_api::domain::posting::Posting;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use crate::error::{ApiError, ErrorBody};
+use crate::AppState;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OperationQuery {
+    /// Hex encoded 34-byte operation id.
+    pub opr_id: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/postings",
+    tag = "postings",
+    request_body = Object,
+    responses(
+        (status = 201, description = "Posting recorded, or the posting already recorded for the operation", body = Object),
+        (status = 400, description = "Invalid posting", body = ErrorBody),
+        (status = 409, description = "Operation recorded with different content, or ledger read-only", body = ErrorBody),
+        (status = 422, description = "Account limit exceeded", body = ErrorBody),
+        (status = 429, description = "Posting quota exceeded", body = ErrorBody),
+    )
+)]
+pub async fn new_posting(State(state): State<AppState>, Json(posting): Json<Posting>) -> Result<(StatusCode, Json<Posting>), ApiError> {
+    let posting = state.posting_service.new_posting(posting).await?;
+    Ok((StatusCode::CREATED, Json(posting)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/postings",
+    tag = "postings",
+    params(OperationQuery),
+    responses(
+        (status = 200, description = "Postings recorded for the operation, including discarded ones", body = [Object]),
+        (status = 400, description = "Malformed operation id", body = ErrorBody),
+    )
+)]
+pub async fn find_postings_by_operation(State(state): State<AppState>, Query(query): Query<OperationQuery>) -> Result<Json<Vec<Posting>>, ApiError> {
+    let opr_id: [u8; 34] = hex::decode(&query.opr_id)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ApiError::bad_request("opr_id must be 34 hex encoded bytes"))?;
+    Ok(Json(state.posting_service.find_postings_by_operation_id(&opr_id).await?))
+}
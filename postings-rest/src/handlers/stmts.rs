@@ -0,0 +1,69 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_stmt::AccountStmt;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use crate::error::{ApiError, ErrorBody};
+use crate::handlers::ledgers::load_ledger_account;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct StmtRequest {
+    /// Posting time the statement is computed at.
+    pub ref_time: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts/{id}/stmts",
+    tag = "statements",
+    params(("id" = Uuid, Path, description = "Ledger account id"), StmtRequest),
+    responses(
+        (status = 200, description = "Simulated statement, nothing is persisted", body = Object),
+        (status = 404, description = "Ledger account not found", body = ErrorBody),
+    )
+)]
+pub async fn read_stmt(State(state): State<AppState>, Path(id): Path<Uuid>, Query(query): Query<StmtRequest>) -> Result<Json<AccountStmt>, ApiError> {
+    let account = load_ledger_account(&state, id).await?;
+    Ok(Json(state.stmt_service.read_stmt(account, query.ref_time).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/accounts/{id}/stmts",
+    tag = "statements",
+    params(("id" = Uuid, Path, description = "Ledger account id")),
+    request_body = StmtRequest,
+    responses(
+        (status = 201, description = "Statement created", body = Object),
+        (status = 404, description = "Ledger account not found", body = ErrorBody),
+    )
+)]
+pub async fn create_stmt(State(state): State<AppState>, Path(id): Path<Uuid>, Json(request): Json<StmtRequest>) -> Result<(StatusCode, Json<AccountStmt>), ApiError> {
+    let account = load_ledger_account(&state, id).await?;
+    let stmt = state.stmt_service.create_stmt(account, request.ref_time).await?;
+    Ok((StatusCode::CREATED, Json(stmt)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/accounts/{id}/stmts/close",
+    tag = "statements",
+    params(("id" = Uuid, Path, description = "Ledger account id")),
+    request_body = Object,
+    responses(
+        (status = 200, description = "Statement closed", body = Object),
+        (status = 400, description = "Statement of another account", body = ErrorBody),
+        (status = 409, description = "Statement already closed", body = ErrorBody),
+    )
+)]
+pub async fn close_stmt(State(state): State<AppState>, Path(id): Path<Uuid>, Json(stmt): Json<AccountStmt>) -> Result<Json<AccountStmt>, ApiError> {
+    if stmt.account.id != id {
+        return Err(ApiError::bad_request("Statement belongs to another account"));
+    }
+    Ok(Json(state.stmt_service.close_stmt(stmt).await?))
+}
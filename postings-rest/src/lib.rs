@@ -0,0 +1,65 @@
+pub mod error;
+pub mod handlers;
+
+use std::sync::Arc;
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::service::sync_service::SyncService;
+use utoipa::OpenApi;
+use crate::handlers::{changes, ledgers, postings, stmts};
+
+/// Services the routes call. Built once by the host application from `postings-logic` and a
+/// database crate of its choice.
+#[derive(Clone)]
+pub struct AppState {
+    pub ledger_service: Arc<dyn LedgerService + Send + Sync>,
+    pub posting_service: Arc<dyn PostingService + Send + Sync>,
+    pub stmt_service: Arc<dyn AccountStmtService + Send + Sync>,
+    pub sync_service: Arc<dyn SyncService + Send + Sync>,
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Ledger Postings"),
+    paths(
+        ledgers::new_ledger,
+        ledgers::find_ledger,
+        ledgers::set_read_only,
+        ledgers::new_ledger_account,
+        ledgers::find_ledger_account,
+        postings::new_posting,
+        postings::find_postings_by_operation,
+        stmts::read_stmt,
+        stmts::create_stmt,
+        stmts::close_stmt,
+        changes::changes_since,
+    ),
+    components(schemas(
+        error::ErrorBody,
+        ledgers::LedgerWithNames,
+        ledgers::LedgerAccountWithNames,
+        ledgers::ReadOnlyRequest,
+        stmts::StmtRequest,
+    ))
+)]
+pub struct ApiDoc;
+
+/// HTTP/JSON routes over the services, with the OpenAPI document served at `/openapi.json`.
+/// Authentication, TLS and the listener are left to the host application.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/ledgers", post(ledgers::new_ledger))
+        .route("/ledgers/:id", get(ledgers::find_ledger))
+        .route("/ledgers/:id/read-only", put(ledgers::set_read_only))
+        .route("/ledgers/:id/accounts", post(ledgers::new_ledger_account))
+        .route("/accounts/:id", get(ledgers::find_ledger_account))
+        .route("/accounts/:id/stmts", get(stmts::read_stmt).post(stmts::create_stmt))
+        .route("/accounts/:id/stmts/close", post(stmts::close_stmt))
+        .route("/postings", get(postings::find_postings_by_operation).post(postings::new_posting))
+        .route("/changes", get(changes::changes_since))
+        .route("/openapi.json", get(|| async { Json(ApiDoc::openapi()) }))
+        .with_state(state)
+}
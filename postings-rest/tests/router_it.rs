@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use chrono::{Duration, Utc};
+use http_body_util::BodyExt;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_db::models::ledger_event::LedgerEventOperation;
+use postings_db_inmemory::repositories::account_stmt_repository::InMemoryAccountStmtRepository;
+use postings_db_inmemory::repositories::chart_of_account_repository::InMemoryChartOfAccountRepository;
+use postings_db_inmemory::repositories::ledger_account_repository::InMemoryLedgerAccountRepository;
+use postings_db_inmemory::repositories::ledger_event_repository::InMemoryLedgerEventRepository;
+use postings_db_inmemory::repositories::ledger_repository::InMemoryLedgerRepository;
+use postings_db_inmemory::repositories::named_repository::InMemoryNamedRepository;
+use postings_db_inmemory::repositories::posting_line_repository::InMemoryPostingLineRepository;
+use postings_db_inmemory::repositories::posting_repository::InMemoryPostingRepository;
+use postings_db_inmemory::repositories::posting_trace_repository::InMemoryPostingTraceRepository;
+use postings_db_inmemory::store::InMemoryStore;
+use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+use postings_logic::services::ledger_service::LedgerServiceImpl;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+use postings_logic::services::sync_service::SyncServiceImpl;
+use postings_rest::{router, AppState};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_shared(store: Arc<InMemoryStore>) -> SharedService {
+    SharedService::new(
+        Arc::new(InMemoryChartOfAccountRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerRepository::new(store.clone())),
+        Arc::new(InMemoryLedgerAccountRepository::new(store.clone())),
+        Arc::new(InMemoryNamedRepository::new(store.clone())),
+        Arc::new(InMemoryPostingRepository::new(store.clone())),
+        Arc::new(InMemoryAccountStmtRepository::new(store.clone())),
+        Arc::new(InMemoryPostingLineRepository::new(store.clone())),
+        Arc::new(InMemoryPostingTraceRepository::new(store)),
+    )
+}
+
+fn create_router(shared: &SharedService, store: Arc<InMemoryStore>) -> Router {
+    router(AppState {
+        ledger_service: Arc::new(LedgerServiceImpl::new(shared.clone(), ChartOfAccountServiceImpl::new(shared.clone()))),
+        posting_service: Arc::new(PostingServiceImpl::new(shared.clone())),
+        stmt_service: Arc::new(AccountStmtServiceImpl::new(shared.clone())),
+        sync_service: Arc::new(SyncServiceImpl::new(Arc::new(InMemoryLedgerEventRepository::new(store)))),
+    })
+}
+
+async fn send(router: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => request.header("content-type", "application/json").body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }.unwrap();
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_created_ledger_is_found() {
+    let store = Arc::new(InMemoryStore::new());
+    let shared = create_shared(store.clone());
+    let coa_id = Uuid::new_v4();
+    ChartOfAccountServiceImpl::new(shared.clone()).new_chart_of_account(ChartOfAccount { id: coa_id }, vec![]).await.unwrap();
+    let router = create_router(&shared, store);
+    let ledger_id = Uuid::new_v4();
+
+    let (status, _) = send(&router, "POST", "/ledgers", Some(json!({ "ledger": { "id": ledger_id, "coa": { "id": coa_id } }, "named": [] }))).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, ledger) = send(&router, "GET", &format!("/ledgers/{ledger_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ledger["coa"]["id"], json!(coa_id));
+}
+
+#[tokio::test]
+async fn test_service_errors_map_to_status_codes() {
+    let store = Arc::new(InMemoryStore::new());
+    let router = create_router(&create_shared(store.clone()), store);
+
+    let (status, body) = send(&router, "POST", "/ledgers", Some(json!({ "ledger": { "id": Uuid::new_v4(), "coa": { "id": Uuid::new_v4() } }, "named": [] }))).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], json!("Chart of account not found"));
+
+    let (status, _) = send(&router, "GET", &format!("/accounts/{}/stmts?ref_time=2025-08-01T00:00:00Z", Uuid::new_v4()), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&router, "GET", "/postings?opr_id=abcd", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&router, "GET", "/changes?limit=0", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_changes_are_synced_from_position() {
+    let store = Arc::new(InMemoryStore::new());
+    let router = create_router(&create_shared(store.clone()), store.clone());
+    let created = Utc::now() - Duration::minutes(1);
+    store.append_event("posting", "p1", LedgerEventOperation::Insert, "{}".to_string(), created);
+    store.append_event("ledger_account", "a1", LedgerEventOperation::Update, "{}".to_string(), created);
+    store.append_event("account_stmt", "s1", LedgerEventOperation::Insert, "{}".to_string(), created);
+
+    let (status, body) = send(&router, "GET", "/changes?after_seq=1&entity_types=posting,account_stmt", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["changes"].as_array().unwrap().len(), 1);
+    assert_eq!(body["changes"][0]["entity_id"], json!("s1"));
+    assert_eq!(body["next_seq"], json!(3));
+    assert_eq!(body["has_more"], json!(false));
+}
+
+#[tokio::test]
+async fn test_openapi_document_lists_routes() {
+    let store = Arc::new(InMemoryStore::new());
+    let (status, doc) = send(&create_router(&create_shared(store.clone()), store), "GET", "/openapi.json", None).await;
+    assert_eq!(status, StatusCode::OK);
+    for path in ["/ledgers", "/ledgers/{id}", "/postings", "/accounts/{id}/stmts", "/accounts/{id}/stmts/close", "/changes"] {
+        assert!(doc["paths"].get(path).is_some(), "{path} is not documented");
+    }
+}